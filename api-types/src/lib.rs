@@ -0,0 +1,1447 @@
+// Request/response types of the REST API, shared by the server, frontend and cli
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Version of the wire format described by these types
+///
+/// Bump on breaking changes. The server sends it in the `API_VERSION_HEADER`
+/// response header so clients can detect a mismatched server.
+pub const API_VERSION: u32 = 1;
+
+/// Response header carrying `API_VERSION`
+pub const API_VERSION_HEADER: &str = "x-sysrat-api-version";
+
+/// Request header that unlocks a write to a readonly file (see `ReadonlyOverride`)
+pub const OVERRIDE_HEADER: &str = "x-sysrat-override";
+
+/// Replaces each secret value in read responses (see `[secrets]`)
+pub const SECRET_MASK: &str = "********";
+
+/// Request header carrying the role token of an action that requires one
+pub const ROLE_TOKEN_HEADER: &str = "x-sysrat-token";
+
+/// How the server accepts writes to files flagged readonly
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum ReadonlyOverride {
+    /// Readonly files can never be written
+    #[default]
+    Disabled,
+    /// Any non-empty `OVERRIDE_HEADER` value unlocks the write
+    Confirm,
+    /// `OVERRIDE_HEADER` must carry the configured override token
+    Token,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FileInfo {
+    pub name: String,
+    pub description: String,
+    pub readonly: bool,
+    /// Optional theme variant for this file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    /// Optional category label used for grouping/sorting in the UI
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Optional field definitions, the frontend renders them as a form
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<FileSchema>,
+    /// Source the file can be refreshed from (`upstream_url` in sysrat.toml)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upstream_url: Option<String>,
+    /// Filesystem metadata, missing when the file could not be stat'ed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<FileMeta>,
+    /// Line comment leader (`#`, `//`, `;`, ...), None when the format has
+    /// no line comments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Editor settings from the `[hygiene]` rules of the file's category
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editing: Option<EditingOptions>,
+}
+
+/// How the editor indents and saves a file; unset indentation is detected
+/// from the file's content
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EditingOptions {
+    /// Columns of one indent level
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indent_width: Option<u8>,
+    /// Indent with tabs rather than spaces
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indent_tabs: Option<bool>,
+    /// Trim trailing whitespace on save without offering it as a fix
+    #[serde(default)]
+    pub trim_on_save: bool,
+}
+
+/// Size, age and ownership of a managed file on the server
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FileMeta {
+    pub size: u64,
+    /// Last modification, unix seconds
+    pub modified: u64,
+    /// User name of the owner, the numeric uid when it has no name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Permission bits (`0o644`), missing on non-unix servers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<u32>,
+}
+
+/// Value type of a schema field
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum FieldKind {
+    #[default]
+    String,
+    Integer,
+    Float,
+    /// `true` or `false`
+    Boolean,
+    /// One of the field's `options`
+    Enum,
+}
+
+/// One key of a file with a schema (`[[files.schema.fields]]` in sysrat.toml)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SchemaField {
+    /// Key in the file, `section.key` for keys under an ini section
+    pub key: String,
+    #[serde(default)]
+    pub kind: FieldKind,
+    #[serde(default)]
+    pub description: String,
+    /// Allowed values of an `enum` field
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<String>,
+    /// The key must be present with a non-empty value
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// Form definition for a key=value style config file
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FileSchema {
+    pub fields: Vec<SchemaField>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FileListResponse {
+    /// In config order, each directory's files sorted by its own `order`
+    pub files: Vec<FileInfo>,
+    #[serde(default)]
+    pub readonly_override: ReadonlyOverride,
+    /// How clients order the whole list unless the user picked another
+    /// (`file_order` in sysrat.toml)
+    #[serde(default)]
+    pub order: FileOrder,
+}
+
+/// Ordering policy of the file list, for the whole list or one directory's files
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum FileOrder {
+    /// As listed in sysrat.toml: `[[files]]` first, then each directory
+    #[default]
+    Config,
+    /// By name
+    Alphabetical,
+    /// By category, files without one last; config order within a category
+    Category,
+    /// Most recently modified first, files that could not be stat'ed last
+    Mtime,
+}
+
+impl FileOrder {
+    pub const ALL: [FileOrder; 4] = [
+        FileOrder::Config,
+        FileOrder::Alphabetical,
+        FileOrder::Category,
+        FileOrder::Mtime,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FileOrder::Config => "config",
+            FileOrder::Alphabetical => "alphabetical",
+            FileOrder::Category => "category",
+            FileOrder::Mtime => "mtime",
+        }
+    }
+
+    /// The policy after this one, wrapping around
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&o| o == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Sort listed `files` that are in config order. Stable, so ties keep
+    /// config order and the result is the same wherever it is applied.
+    pub fn sort(self, files: &mut [FileInfo]) {
+        self.sort_by(
+            files,
+            |f| &f.name,
+            |f| f.category.as_deref(),
+            |f| f.meta.as_ref().map(|m| m.modified),
+        );
+    }
+
+    /// `sort` for any file type, given its name, category and mtime (unix
+    /// seconds)
+    pub fn sort_by<T>(
+        self,
+        items: &mut [T],
+        name: impl Fn(&T) -> &str,
+        category: impl Fn(&T) -> Option<&str>,
+        modified: impl Fn(&T) -> Option<u64>,
+    ) {
+        match self {
+            FileOrder::Config => {}
+            FileOrder::Alphabetical => items.sort_by(|a, b| name(a).cmp(name(b))),
+            FileOrder::Category => items.sort_by(|a, b| match (category(a), category(b)) {
+                (Some(a), Some(b)) => a.cmp(b),
+                (a, b) => b.is_some().cmp(&a.is_some()),
+            }),
+            // A missing mtime is None, which sorts after every Some here
+            FileOrder::Mtime => items.sort_by_cached_key(|item| std::cmp::Reverse(modified(item))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FileContentResponse {
+    /// Decoded text with `\n` line endings and without a byte order mark
+    pub content: String,
+    /// How the file is stored on disk, written back the same way
+    #[serde(default)]
+    pub format: TextFormat,
+    /// Secret values were replaced by a mask, read with `?reveal=true` to edit
+    #[serde(default)]
+    pub redacted: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WriteConfigRequest {
+    pub content: String,
+    /// Store the file in this format instead of the one it has on disk
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<TextFormat>,
+}
+
+/// Encoding and line endings of a text file, kept across edits
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TextFormat {
+    pub encoding: TextEncoding,
+    pub line_ending: LineEnding,
+}
+
+/// Byte encoding of a text file
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum TextEncoding {
+    #[default]
+    Utf8,
+    /// UTF-8 starting with a byte order mark, as Windows tools often write
+    Utf8Bom,
+    /// ISO-8859-1, one byte per character; only U+0000..U+00FF fit
+    Latin1,
+}
+
+impl TextEncoding {
+    pub const ALL: [TextEncoding; 3] = [
+        TextEncoding::Utf8,
+        TextEncoding::Utf8Bom,
+        TextEncoding::Latin1,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TextEncoding::Utf8 => "utf-8",
+            TextEncoding::Utf8Bom => "utf-8-bom",
+            TextEncoding::Latin1 => "latin-1",
+        }
+    }
+
+    /// The encoding after this one, wrapping around
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&e| e == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum LineEnding {
+    #[default]
+    Lf,
+    /// `\r\n`, for files read on Windows
+    Crlf,
+}
+
+impl LineEnding {
+    pub fn label(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "lf",
+            LineEnding::Crlf => "crlf",
+        }
+    }
+
+    /// The other line ending
+    pub fn toggled(self) -> Self {
+        match self {
+            LineEnding::Lf => LineEnding::Crlf,
+            LineEnding::Crlf => LineEnding::Lf,
+        }
+    }
+}
+
+/// A field of a request body the server did not accept
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FieldError {
+    /// Field name as in the JSON body, e.g. "content"
+    pub field: String,
+    pub message: String,
+}
+
+/// Whitespace fixes for content about to be saved, from the `[hygiene]`
+/// rules of the file's category
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct HygieneResponse {
+    /// The content with every fix applied
+    pub content: String,
+    /// What changed, e.g. "Added a final newline"; empty when nothing did
+    pub fixes: Vec<String>,
+    /// Unified diff of the visible changes, empty when only the final
+    /// newline was added
+    pub diff: String,
+}
+
+/// Body of a 422: the JSON parsed, but fields of it are not acceptable
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ValidationErrorResponse {
+    pub error: String,
+    /// Empty when the body did not fit the expected shape at all
+    pub fields: Vec<FieldError>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WriteConfigResponse {
+    pub success: bool,
+    /// Permission bits of the file after the write, kept from before it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<u32>,
+}
+
+/// How serious a lint warning is
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    /// The file is likely to be rejected by its consumer
+    Error,
+    #[default]
+    Warning,
+}
+
+/// A rule of an enabled lint pack that the content breaks, or a diagnostic
+/// of an external checker
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct LintWarning {
+    /// 1-based line, None for the file as a whole (e.g. a missing directive)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// Rule pack, e.g. "ssh-hardening", or the checker, e.g. "shellcheck"
+    pub pack: String,
+    /// Rule within the pack, e.g. "password-auth", or the checker's severity
+    pub rule: String,
+    #[serde(default)]
+    pub severity: LintSeverity,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct LintResponse {
+    pub warnings: Vec<LintWarning>,
+}
+
+/// Body of a 403 when the server process may not write the file itself
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct WritePermissionError {
+    /// Path of the file on the server
+    pub path: String,
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// Permission bits (`0o644`)
+    #[serde(default)]
+    pub mode: Option<u32>,
+    /// User the server runs as
+    #[serde(default)]
+    pub server_user: Option<String>,
+    /// Whether sudo or capabilities would let the write through, false
+    /// when the server already runs as root
+    pub privileges_help: bool,
+    /// What to change, e.g. "chmod u+w" or `write_via = "sudo"`
+    pub hint: String,
+}
+
+/// Upstream version of a file compared with the local one
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpstreamCheckResponse {
+    pub url: String,
+    /// SHA-256 of the fetched content, sent back to apply exactly this version
+    pub sha256: String,
+    /// Checksum pinned in sysrat.toml, if any
+    #[serde(default)]
+    pub expected_sha256: Option<String>,
+    /// Whether `sha256` matches `expected_sha256`, None without a pinned checksum
+    #[serde(default)]
+    pub checksum_ok: Option<bool>,
+    /// Whether the detached signature verified, None without a signature URL
+    #[serde(default)]
+    pub signature_ok: Option<bool>,
+    pub changed: bool,
+    /// Unified diff from the local file to the upstream one
+    pub diff: String,
+    /// Job that ran the check (see GET /api/jobs)
+    pub job_id: u64,
+}
+
+/// Apply the upstream version that was reviewed
+/// File content with its `${VARS}` substituted, for display only
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RenderedResponse {
+    pub content: String,
+    /// Referenced variables without a value or default, left as written
+    #[serde(default)]
+    pub unresolved: Vec<String>,
+    /// Vars file the values were taken from, if any
+    #[serde(default)]
+    pub vars_file: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpstreamApplyRequest {
+    /// `sha256` of the reviewed check, the apply fails if upstream changed since
+    pub sha256: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    Running,
+    Succeeded,
+    Failed,
+}
+
+/// Server-side task started from the UI, e.g. an upstream check
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct JobInfo {
+    pub id: u64,
+    /// e.g. `upstream-check`, `upstream-apply`, `action`
+    pub kind: String,
+    /// Managed file or action name the job works on
+    pub target: String,
+    pub status: JobStatus,
+    /// Result or error once finished
+    #[serde(default)]
+    pub message: String,
+    /// Command output so far (stdout and stderr interleaved), tail only
+    #[serde(default)]
+    pub output: String,
+    /// Unix timestamps (seconds)
+    pub started: i64,
+    #[serde(default)]
+    pub finished: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct JobListResponse {
+    /// Newest job first
+    pub jobs: Vec<JobInfo>,
+}
+
+/// Scriptable action from sysrat.toml, as listed to clients
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ActionInfo {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Command line for display, e.g. `systemctl reload nginx`
+    pub command: String,
+    #[serde(default)]
+    pub working_dir: Option<String>,
+    pub timeout_secs: u64,
+    /// Role whose token must be sent in `ROLE_TOKEN_HEADER`
+    #[serde(default)]
+    pub role: Option<String>,
+    /// Question to ask before running
+    #[serde(default)]
+    pub confirm: Option<String>,
+    /// Values asked for before running, substituted into `{key}` in the args
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<SchemaField>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ActionListResponse {
+    pub actions: Vec<ActionInfo>,
+}
+
+/// Parameter values of an action run, by parameter key
+#[derive(Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RunActionRequest {
+    #[serde(default)]
+    pub params: HashMap<String, String>,
+}
+
+/// Started action, follow it with GET /api/jobs/{id}
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RunActionResponse {
+    pub job_id: u64,
+}
+
+/// What a runbook step does when it is carried out
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum RunbookStepKind {
+    /// Runs an action, see POST /api/runbooks/{name}/steps/{index}/run
+    Action,
+    /// Opens a managed file for review or editing
+    Edit,
+    /// Manual check confirmed by the user
+    Check,
+}
+
+/// One step of a runbook
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RunbookStep {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    pub kind: RunbookStepKind,
+    /// Action name for action steps, file name for edit steps
+    #[serde(default)]
+    pub target: Option<String>,
+    /// Parameter values the action step runs with
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub params: HashMap<String, String>,
+    /// Role whose token the action step requires
+    #[serde(default)]
+    pub role: Option<String>,
+}
+
+/// Ordered procedure from sysrat.toml, walked through step by step
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RunbookInfo {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub steps: Vec<RunbookStep>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RunbookListResponse {
+    pub runbooks: Vec<RunbookInfo>,
+}
+
+/// One commit touching a file in the git history repository
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CommitInfo {
+    pub id: String,
+    pub short_id: String,
+    pub author: String,
+    /// Commit time as unix seconds
+    pub time: i64,
+    pub message: String,
+    /// Unified diff of the file against the parent commit
+    pub diff: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FileLogResponse {
+    /// Newest commit first
+    pub commits: Vec<CommitInfo>,
+}
+
+/// How often a managed file was changed, counted from the git history
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FileChangeStats {
+    pub name: String,
+    /// Commits within the requested window
+    pub changes: u64,
+    /// Commits since the history began
+    pub total_changes: u64,
+    /// Unix timestamp of the newest commit, None if never changed
+    #[serde(default)]
+    pub last_changed: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChangeStatsResponse {
+    /// Length of the window `changes` counts, in days
+    pub days: u32,
+    /// Most changed within the window first
+    pub files: Vec<FileChangeStats>,
+}
+
+/// Theme TOML found in the server's user theme directory
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ThemeFile {
+    /// File stem, e.g. `my-theme` for my-theme.toml
+    pub name: String,
+    /// Raw TOML, parsed by the frontend
+    pub content: String,
+}
+
+/// Settings of the web UI that come from sysrat.toml instead of the build
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FrontendConfigResponse {
+    /// ASCII art replacing the built-in title, which is kept when None
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub banner: Option<String>,
+    /// In the menu, show the banner in place of the rat instead of the title
+    #[serde(default)]
+    pub replace_rat: bool,
+    /// Message of the day below the banner and the menu
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub motd: Option<String>,
+    /// Frames shown in place of the rat, the built-in rat when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub art_frames: Vec<String>,
+    /// Milliseconds per frame, 0 keeps the first frame
+    #[serde(default)]
+    pub frame_ms: u64,
+    /// How long the splash screen plays before the menu, 0 skips it
+    #[serde(default = "default_splash_ms")]
+    pub splash_ms: u64,
+    /// How failed reads are retried
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    /// How often panes with live data poll the server
+    #[serde(default)]
+    pub refresh: RefreshIntervals,
+}
+
+/// Splash screen length when sysrat.toml does not set one
+pub const DEFAULT_SPLASH_MS: u64 = 2_500;
+
+fn default_splash_ms() -> u64 {
+    DEFAULT_SPLASH_MS
+}
+
+/// Retry with exponential backoff for GETs that failed on the network
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RetryPolicy {
+    /// Attempts including the first, at least 1
+    pub attempts: u32,
+    /// Milliseconds before the first retry, doubled for each one after it
+    pub base_ms: u64,
+    /// Longest wait between two attempts in milliseconds
+    pub max_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            base_ms: 500,
+            max_ms: 4_000,
+        }
+    }
+}
+
+/// Background refresh interval used when sysrat.toml does not set one
+pub const DEFAULT_REFRESH_MS: u64 = 10_000;
+
+/// Polling intervals of the panes with live data
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RefreshIntervals {
+    /// Milliseconds between two refreshes of a pane without its own interval
+    pub default_ms: u64,
+    /// Intervals by pane: container_list, dashboard, container_watch
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub panes: HashMap<String, u64>,
+}
+
+impl RefreshIntervals {
+    /// Interval of the pane named `pane`
+    pub fn for_pane(&self, pane: &str) -> u64 {
+        self.panes.get(pane).copied().unwrap_or(self.default_ms)
+    }
+}
+
+impl Default for RefreshIntervals {
+    fn default() -> Self {
+        Self {
+            default_ms: DEFAULT_REFRESH_MS,
+            panes: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ThemeListResponse {
+    pub themes: Vec<ThemeFile>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SaveThemeRequest {
+    /// Theme TOML as produced by the frontend theme editor
+    pub content: String,
+}
+
+/// Log file from `[[logs]]` in sysrat.toml
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct LogInfo {
+    pub name: String,
+    pub path: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct LogListResponse {
+    pub logs: Vec<LogInfo>,
+}
+
+/// Another sysrat server from `[[hosts]]` in sysrat.toml
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct HostInfo {
+    pub name: String,
+    /// Base URL of its API, API paths are appended to it
+    pub url: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct HostListResponse {
+    pub hosts: Vec<HostInfo>,
+}
+
+/// Agent of a sysrat hub and whether the hub reaches it
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct NodeInfo {
+    pub name: String,
+    /// Base URL of the node's API on the hub, API paths are appended to it
+    pub url: String,
+    #[serde(default)]
+    pub description: String,
+    /// Server version of the agent, None while it cannot be reached and for
+    /// nodes reached over SSH
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Why the hub cannot reach the agent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct NodeListResponse {
+    pub nodes: Vec<NodeInfo>,
+}
+
+/// UI preferences of a user, kept by the server so they follow the user
+/// to other browsers (`GET/PUT /api/profile`)
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UserProfile {
+    /// Theme picked by name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    /// Follow the browser color scheme
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme_auto: Option<bool>,
+    /// Changed bindings by `section.action`
+    #[serde(default)]
+    pub keybind_overrides: BTreeMap<String, String>,
+    /// Width of the left pane of each split in percent, by split
+    #[serde(default)]
+    pub split_ratios: BTreeMap<String, u16>,
+    /// Open pane and file of each server the UI talks to
+    #[serde(default)]
+    pub buffers: Vec<ProfileBuffer>,
+    /// Unix seconds of the last save, set by the server
+    #[serde(default)]
+    pub updated_at: u64,
+}
+
+/// Where the UI was on one server, and the unsaved content of its open file
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ProfileBuffer {
+    /// Host from the host switcher, empty for the server serving the UI
+    #[serde(default)]
+    pub host: String,
+    pub pane: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// Content as typed, None when it was not kept (e.g. revealed secrets)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+/// Server-sent event carrying new lines of a followed log, one line per
+/// line of its data
+pub const LOG_LINES_EVENT: &str = "lines";
+/// Server-sent event sent when the log was truncated or replaced, the
+/// lines after it start at the file's beginning
+pub const LOG_ROTATED_EVENT: &str = "rotated";
+/// Server-sent event with the reason the file could not be read; following
+/// goes on and picks the file up again once it is back. Not "error", the
+/// name EventSource gives its own connection errors.
+pub const LOG_ERROR_EVENT: &str = "failure";
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct StatusLineResponse {
+    /// User statusline.toml, None when there is none and the built-in
+    /// layout stays
+    pub content: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContainerInfo {
+    pub id: String,
+    pub name: String,
+    pub state: String,
+    /// Docker's own status text ("Up 3 hours (healthy)"), the fields below
+    /// carry what it says in structured form
+    pub status: String,
+    /// Unix seconds the container was created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<u64>,
+    /// Unix seconds the container started, while it is running
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<u64>,
+    /// Unix seconds the container exited or last crashed (when restarting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exited_at: Option<u64>,
+    /// Exit code of the last run, for exited and restarting containers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// Health check verdict ("healthy", "unhealthy", "starting"), None
+    /// without a health check
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health: Option<String>,
+    /// Public URL derived from traefik/caddy labels, if the service is proxied
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_url: Option<String>,
+    /// Listed under `[protection]` in sysrat.toml: stopping or restarting it
+    /// takes typing its name
+    #[serde(default)]
+    pub protected: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContainerListResponse {
+    pub containers: Vec<ContainerInfo>,
+    /// The docker daemon is not answering (e.g. restarting); `containers` is
+    /// empty and the server polls until it is back
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outage: Option<DaemonOutage>,
+}
+
+/// Docker daemon lost, as opposed to a docker command that failed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DaemonOutage {
+    /// Unix seconds the daemon was first found unreachable
+    pub since: u64,
+    /// What docker reported, e.g. "Cannot connect to the Docker daemon ..."
+    pub detail: String,
+    /// Seconds between the server's reconnection attempts
+    pub retry_secs: u64,
+}
+
+/// Vulnerabilities found in an image, by severity
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SeverityCounts {
+    pub critical: u64,
+    pub high: u64,
+    pub medium: u64,
+    pub low: u64,
+    /// Negligible or unrated
+    pub unknown: u64,
+}
+
+/// A vulnerability of an installed package
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Vulnerability {
+    /// CVE or advisory id, e.g. "CVE-2024-3094"
+    pub id: String,
+    /// Uppercase as reported, e.g. "CRITICAL"
+    pub severity: String,
+    pub package: String,
+    pub installed_version: String,
+    /// First version with a fix, None if there is none yet
+    #[serde(default)]
+    pub fixed_version: Option<String>,
+    #[serde(default)]
+    pub title: String,
+}
+
+/// Result of the last vulnerability scan of an image
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ImageScan {
+    /// Scanner that ran, "trivy" or "grype"
+    pub scanner: String,
+    /// Unix timestamp the scan finished
+    pub scanned: i64,
+    pub counts: SeverityCounts,
+    /// Most severe vulnerabilities first, at most a handful
+    pub top: Vec<Vulnerability>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ImageInfo {
+    /// Short image id as shown by `docker images`
+    pub id: String,
+    pub repository: String,
+    pub tag: String,
+    pub size: String,
+    /// e.g. "3 weeks ago"
+    pub created: String,
+    /// Last scan since the server started, None if not scanned
+    #[serde(default)]
+    pub scan: Option<ImageScan>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ImageListResponse {
+    pub images: Vec<ImageInfo>,
+    /// Installed scanner scans run with, None if neither trivy nor grype is
+    #[serde(default)]
+    pub scanner: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContainerActionResponse {
+    pub success: bool,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PortMapping {
+    pub container_port: String,
+    pub host_port: String,
+    pub protocol: String,
+    /// Quick-open link for published TCP ports (see `[links]` in sysrat.toml)
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct VolumeMount {
+    pub source: String,
+    pub destination: String,
+    pub mode: String,
+}
+
+/// Where a container environment variable comes from, compared with the
+/// image config
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum EnvSource {
+    /// Inherited from the image unchanged
+    Image,
+    /// Set at run time, the image does not define it
+    Runtime,
+    /// Set at run time over a different value from the image
+    Override,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EnvVar {
+    pub key: String,
+    pub source: EnvSource,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContainerDetails {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    pub state: String,
+    pub status: String,
+    pub created: String,
+    pub started: String,
+    pub ports: Vec<PortMapping>,
+    pub volumes: Vec<VolumeMount>,
+    pub networks: Vec<String>,
+    pub environment: Vec<String>,
+    /// Source of each variable in `environment`, empty if the image could
+    /// not be inspected
+    #[serde(default)]
+    pub env_sources: Vec<EnvVar>,
+    pub restart_policy: String,
+    pub health: Option<String>,
+    /// Public URL derived from traefik/caddy labels, if the service is proxied
+    #[serde(default)]
+    pub service_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContainerDetailsResponse {
+    pub details: ContainerDetails,
+}
+
+/// Resource usage of a container (`docker stats --no-stream`)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContainerStats {
+    pub cpu_percent: f64,
+    pub mem_percent: f64,
+    /// As docker prints it, e.g. "12.5MiB / 1.9GiB"
+    pub mem_usage: String,
+    pub net_io: String,
+    pub block_io: String,
+    pub pids: u64,
+}
+
+/// Something docker reported about a container (`docker events`)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContainerEvent {
+    /// Unix seconds
+    pub time: u64,
+    /// e.g. "start", "die", "health_status: unhealthy"
+    pub action: String,
+}
+
+/// Everything the watch view shows about one container, fetched every second
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContainerWatchResponse {
+    pub name: String,
+    pub state: String,
+    /// Health check status, None without a health check
+    #[serde(default)]
+    pub health: Option<String>,
+    pub restart_count: u64,
+    /// RFC 3339 time of the last start
+    pub started_at: String,
+    /// None while the container is not running
+    #[serde(default)]
+    pub stats: Option<ContainerStats>,
+    /// Last lines of stdout and stderr, oldest first
+    pub logs: Vec<String>,
+    /// Events of the last hour, oldest first
+    pub events: Vec<ContainerEvent>,
+}
+
+/// A container in the dependency/network graph
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct GraphNode {
+    pub id: String,
+    pub name: String,
+    pub state: String,
+    pub networks: Vec<String>,
+    /// Named volumes, or host paths for bind mounts
+    pub volumes: Vec<String>,
+    /// IDs of containers this one depends on (compose `depends_on`)
+    pub depends_on: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compose_project: Option<String>,
+}
+
+/// Containers sharing a network or volume
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct GraphGroup {
+    pub name: String,
+    /// Member container IDs
+    pub members: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContainerGraphResponse {
+    pub nodes: Vec<GraphNode>,
+    /// One group per network, sorted by name
+    pub networks: Vec<GraphGroup>,
+    /// Only volumes mounted by more than one container
+    pub shared_volumes: Vec<GraphGroup>,
+}
+
+/// Host metrics from /proc (Linux only)
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct HostStats {
+    pub hostname: String,
+    pub uptime_secs: u64,
+    /// 1, 5 and 15 minute load averages
+    pub load_avg: Vec<f64>,
+    pub cpu_count: usize,
+    pub mem_total_kb: u64,
+    pub mem_available_kb: u64,
+}
+
+/// A managed config file and when it was last modified
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ConfigChange {
+    pub name: String,
+    /// Unix timestamp (seconds)
+    pub modified: u64,
+}
+
+/// Expiry of a certificate listed under `[dashboard]` in sysrat.toml
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CertExpiry {
+    pub name: String,
+    pub path: String,
+    /// Unix timestamp (seconds) of notAfter
+    #[serde(default)]
+    pub expires: Option<i64>,
+    #[serde(default)]
+    pub days_left: Option<i64>,
+    /// Why the certificate could not be read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum AlertLevel {
+    Warning,
+    Critical,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Alert {
+    pub level: AlertLevel,
+    pub message: String,
+}
+
+/// Operation behind a dashboard quick action
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum QuickActionKind {
+    /// Start the container named by `target`
+    Start,
+    /// Stop the container named by `target`
+    Stop,
+    /// Restart the container named by `target`
+    Restart,
+    /// Open the managed file named by `target` in the editor
+    Edit,
+    /// Run the action from `[[actions]]` named `action` (POST
+    /// /api/actions/{name}/run), asking for its parameters, confirmation and
+    /// role token as in the Actions pane
+    Run { action: String },
+}
+
+/// Numbered dashboard shortcut (`[[dashboard.quick_actions]]` in sysrat.toml)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct QuickAction {
+    pub label: String,
+    pub action: QuickActionKind,
+    /// Container name/id or managed file name, depending on `action`; unused
+    /// by `run`
+    #[serde(default)]
+    pub target: String,
+}
+
+/// Data for all dashboard widgets in one request
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DashboardResponse {
+    #[serde(default)]
+    pub host: Option<HostStats>,
+    pub unhealthy_containers: Vec<ContainerInfo>,
+    /// Most recently modified files first
+    pub recent_changes: Vec<ConfigChange>,
+    pub cert_expiries: Vec<CertExpiry>,
+    /// Derived from the data above, most severe first
+    pub alerts: Vec<Alert>,
+    /// Shortcuts bound to the number keys, in order
+    #[serde(default)]
+    pub quick_actions: Vec<QuickAction>,
+}
+
+/// A managed file changed within the digest period
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DigestChange {
+    pub name: String,
+    /// Commits within the period, None without git versioning (the file was
+    /// only seen modified)
+    #[serde(default)]
+    pub changes: Option<u64>,
+    /// Unix timestamp of the last change
+    pub last_changed: i64,
+}
+
+/// Restarts of one container within the digest period
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ContainerRestarts {
+    pub name: String,
+    pub restarts: u64,
+}
+
+/// Summary of a period of config changes, container restarts and alerts
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DigestReport {
+    /// Unix timestamps bounding the period
+    pub since: i64,
+    pub until: i64,
+    /// Most changed first
+    pub changes: Vec<DigestChange>,
+    /// Most restarted first
+    pub restarts: Vec<ContainerRestarts>,
+    /// Alerts active when the digest was built, most severe first
+    pub alerts: Vec<Alert>,
+    /// Sources that could not be read, e.g. docker being unavailable
+    #[serde(default)]
+    pub notes: Vec<String>,
+}
+
+/// A digest with the renderings the notifiers send
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DigestResponse {
+    pub report: DigestReport,
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+}
+
+/// Outcome of sending a digest through one notifier channel
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DigestDelivery {
+    /// e.g. "webhook https://hooks.example.com/..." or "email ops@example.com"
+    pub channel: String,
+    /// Why sending failed, None once delivered
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DigestSendResponse {
+    pub deliveries: Vec<DigestDelivery>,
+}
+
+/// Format version of `Blueprint`, bumped when older blueprints no longer import
+pub const BLUEPRINT_VERSION: u32 = 1;
+
+/// Everything needed to re-provision a replacement host: managed configs,
+/// container definitions and sysrat.toml. Can contain secrets (env values,
+/// revealed configs and tokens), store it accordingly.
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Blueprint {
+    pub version: u32,
+    /// Host the blueprint was exported from
+    pub host: String,
+    /// Unix seconds
+    pub created: i64,
+    /// sysrat.toml as stored
+    pub settings: String,
+    /// Role and override tokens in `settings` are masked; it cannot be imported
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub settings_redacted: bool,
+    pub configs: Vec<BlueprintConfig>,
+    pub containers: Vec<BlueprintContainer>,
+}
+
+/// A managed config file with its content and metadata
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BlueprintConfig {
+    pub name: String,
+    pub path: String,
+    pub description: String,
+    pub readonly: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// None if the file could not be read as text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Secrets in `content` are masked; importing it would write the masks
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub redacted: bool,
+}
+
+/// A container as a `docker run` command, plus the compose project that
+/// created it if any
+#[derive(Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BlueprintContainer {
+    pub name: String,
+    pub image: String,
+    /// Recreates the container with its ports, volumes, networks, restart
+    /// policy and the variables not inherited from the image
+    pub run: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compose_project: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compose_service: Option<String>,
+    /// Compose files the project was started from
+    #[serde(default)]
+    pub compose_files: Vec<String>,
+}
+
+/// Apply selected parts of a blueprint; containers are never recreated by the
+/// server, their `run` commands are for the operator
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BlueprintImportRequest {
+    pub blueprint: Blueprint,
+    /// Replace sysrat.toml first, so configs it adds can be imported too
+    #[serde(default)]
+    pub settings: bool,
+    /// Names of the configs to write; each must be managed on this host
+    #[serde(default)]
+    pub configs: Vec<String>,
+}
+
+/// Outcome of importing one part of a blueprint
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BlueprintImportResult {
+    /// "sysrat.toml" or the config name
+    pub item: String,
+    /// Why the item was not applied, None once applied
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct BlueprintImportResponse {
+    pub results: Vec<BlueprintImportResult>,
+}
+
+/// Body of `/healthz`: the process is up and serving
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct HealthResponse {
+    /// Always "ok"; a process that is down does not answer
+    pub status: String,
+}
+
+/// One dependency checked by `/readyz`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ReadinessCheck {
+    /// "config", "docker" or "config_dir"
+    pub name: String,
+    pub ok: bool,
+    /// What was found, or why the check failed
+    pub detail: String,
+}
+
+/// Body of `/readyz`, served with 503 unless every check passed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ReadinessResponse {
+    pub ready: bool,
+    pub checks: Vec<ReadinessCheck>,
+}
+
+/// Body of `/api/meta`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MetaResponse {
+    /// Server crate version, e.g. "0.1.0"
+    pub version: String,
+    /// Wire format version, see `API_VERSION`
+    pub api_version: u32,
+    /// Unix seconds when the server started
+    pub started_at: u64,
+    pub uptime_secs: u64,
+}
+
+/// Body of `/api/version`, what the running server was built from
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct VersionResponse {
+    /// Server crate version, e.g. "0.1.0"
+    pub version: String,
+    /// Short commit hash, "unknown" outside a git checkout
+    pub git_commit: String,
+    /// YYYY-MM-DD
+    pub build_date: String,
+    /// e.g. "1.82.0"
+    pub rustc_version: String,
+}
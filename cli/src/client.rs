@@ -0,0 +1,185 @@
+use serde::{Serialize, de::DeserializeOwned};
+use std::cell::Cell;
+use sysrat_api_types::{
+    API_VERSION, API_VERSION_HEADER, Blueprint, BlueprintImportRequest, BlueprintImportResponse,
+    ContainerActionResponse, ContainerDetails, ContainerDetailsResponse, ContainerInfo,
+    ContainerListResponse, FileContentResponse, FileListResponse, OVERRIDE_HEADER,
+    WriteConfigRequest, WriteConfigResponse, WritePermissionError,
+};
+
+/// Blocking client for the sysrat REST API
+pub struct ApiClient {
+    base_url: String,
+    agent: ureq::Agent,
+    version_warned: Cell<bool>,
+}
+
+impl ApiClient {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            agent: ureq::Agent::new(),
+            version_warned: Cell::new(false),
+        }
+    }
+
+    /// GET /api/configs, including the server's readonly override policy
+    pub fn file_list(&self) -> Result<FileListResponse, String> {
+        self.get("/api/configs")
+    }
+
+    /// GET /api/configs/*filename, with `reveal` unmasking secrets
+    pub fn read_config(&self, filename: &str, reveal: bool) -> Result<String, String> {
+        let query = if reveal { "?reveal=true" } else { "" };
+        let data: FileContentResponse = self.get(&format!("/api/configs/{}{}", filename, query))?;
+        Ok(data.content)
+    }
+
+    /// POST /api/configs/*filename
+    ///
+    /// `override_value` is sent as X-Sysrat-Override to unlock a readonly file.
+    pub fn write_config(
+        &self,
+        filename: &str,
+        content: String,
+        override_value: Option<&str>,
+    ) -> Result<(), String> {
+        let path = format!("/api/configs/{}", filename);
+        let payload = WriteConfigRequest {
+            content,
+            format: None,
+        };
+        let mut request = self.agent.post(&self.url(&path));
+        if let Some(value) = override_value {
+            request = request.set(OVERRIDE_HEADER, value);
+        }
+        let data: WriteConfigResponse = self.parse(&path, request.send_json(&payload))?;
+        if data.success {
+            Ok(())
+        } else {
+            Err(format!("Server did not save {}", filename))
+        }
+    }
+
+    /// GET /api/containers
+    pub fn list_containers(&self) -> Result<Vec<ContainerInfo>, String> {
+        let data: ContainerListResponse = self.get("/api/containers")?;
+        match data.outage {
+            Some(outage) => Err(format!(
+                "Docker daemon unreachable, the server retries every {}s: {}",
+                outage.retry_secs, outage.detail
+            )),
+            None => Ok(data.containers),
+        }
+    }
+
+    /// GET /api/containers/:id/details
+    pub fn container_details(&self, id: &str) -> Result<ContainerDetails, String> {
+        let data: ContainerDetailsResponse =
+            self.get(&format!("/api/containers/{}/details", id))?;
+        Ok(data.details)
+    }
+
+    /// POST /api/containers/:id/{start,stop,restart}
+    pub fn container_action(&self, id: &str, action: &str) -> Result<String, String> {
+        let data: ContainerActionResponse =
+            self.post(&format!("/api/containers/{}/{}", id, action), &())?;
+        if data.success {
+            Ok(data.message)
+        } else {
+            Err(data.message)
+        }
+    }
+
+    /// GET /api/export/blueprint
+    ///
+    /// Secrets are masked unless `reveal` is set, which the server audits;
+    /// the tokens in sysrat.toml also need the override `token`.
+    pub fn export_blueprint(&self, reveal: bool, token: Option<&str>) -> Result<Blueprint, String> {
+        let path = if reveal {
+            "/api/export/blueprint?reveal=true"
+        } else {
+            "/api/export/blueprint"
+        };
+        let mut request_builder = self.agent.get(&self.url(path));
+        if let Some(token) = token {
+            request_builder = request_builder.set(OVERRIDE_HEADER, token);
+        }
+        self.parse(path, request_builder.call())
+    }
+
+    /// POST /api/import/blueprint
+    ///
+    /// `override_value` is sent as X-Sysrat-Override to unlock sysrat.toml and
+    /// readonly files.
+    pub fn import_blueprint(
+        &self,
+        request: &BlueprintImportRequest,
+        override_value: Option<&str>,
+    ) -> Result<BlueprintImportResponse, String> {
+        let path = "/api/import/blueprint";
+        let mut request_builder = self.agent.post(&self.url(path));
+        if let Some(value) = override_value {
+            request_builder = request_builder.set(OVERRIDE_HEADER, value);
+        }
+        self.parse(path, request_builder.send_json(request))
+    }
+
+    fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, String> {
+        let response = self.agent.get(&self.url(path)).call();
+        self.parse(path, response)
+    }
+
+    fn post<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T, String> {
+        let response = self.agent.post(&self.url(path)).send_json(body);
+        self.parse(path, response)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn parse<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<T, String> {
+        match response {
+            Ok(resp) => {
+                self.check_version(&resp);
+                resp.into_json()
+                    .map_err(|e| format!("Failed to parse JSON from {}: {}", path, e))
+            }
+            // Handlers return plain-text error bodies alongside the status code,
+            // except for writes the server process may not make
+            Err(ureq::Error::Status(code, resp)) => {
+                let body = resp.into_string().unwrap_or_default();
+                if let Ok(denied) = serde_json::from_str::<WritePermissionError>(&body) {
+                    return Err(format!(
+                        "Server may not write {}: {}",
+                        denied.path, denied.hint
+                    ));
+                }
+                Err(format!("Server returned {}: {}", code, body.trim()))
+            }
+            Err(e) => Err(format!("Request to {} failed: {}", path, e)),
+        }
+    }
+
+    /// Warn once if the server speaks a different API version
+    fn check_version(&self, resp: &ureq::Response) {
+        if self.version_warned.get() {
+            return;
+        }
+        let server_version = resp
+            .header(API_VERSION_HEADER)
+            .and_then(|v| v.parse::<u32>().ok());
+        if let Some(version) = server_version.filter(|v| *v != API_VERSION) {
+            eprintln!(
+                "Warning: server API v{}, sysrat-cli expects v{}",
+                version, API_VERSION
+            );
+            self.version_warned.set(true);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use super::configs::{confirm_override, prompt};
+use crate::client::ApiClient;
+use clap::Subcommand;
+use std::path::{Path, PathBuf};
+use sysrat_api_types::{Blueprint, BlueprintImportRequest, ReadonlyOverride};
+
+#[derive(Subcommand)]
+pub enum BlueprintCommand {
+    /// Save managed configs, containers and sysrat.toml
+    Export {
+        /// Write to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Export secrets unmasked (audit-logged); masked configs cannot be
+        /// imported. The tokens in sysrat.toml also ask for the override token
+        #[arg(long)]
+        reveal: bool,
+    },
+    /// Walk through a blueprint and apply the parts you pick
+    Import {
+        file: PathBuf,
+        /// Pick everything without asking
+        #[arg(long)]
+        all: bool,
+        /// Allow replacing sysrat.toml and readonly files (asks for confirmation)
+        #[arg(long)]
+        unlock: bool,
+    },
+}
+
+pub fn run(client: &ApiClient, cmd: BlueprintCommand) -> Result<(), String> {
+    match cmd {
+        BlueprintCommand::Export { output, reveal } => export(client, output, reveal),
+        BlueprintCommand::Import { file, all, unlock } => import(client, &file, all, unlock),
+    }
+}
+
+fn export(client: &ApiClient, output: Option<PathBuf>, reveal: bool) -> Result<(), String> {
+    // Asked on stderr, stdout may carry the blueprint
+    let mut token = None;
+    if reveal && client.file_list()?.readonly_override == ReadonlyOverride::Token {
+        eprint!("Override token to export sysrat.toml unmasked (empty keeps it masked): ");
+        let mut line = String::new();
+        std::io::stdin()
+            .read_line(&mut line)
+            .map_err(|e| format!("Failed to read answer: {}", e))?;
+        token = Some(line.trim().to_string()).filter(|token| !token.is_empty());
+    }
+    let blueprint = client.export_blueprint(reveal, token.as_deref())?;
+    if blueprint.settings_redacted {
+        eprintln!("Tokens in sysrat.toml are masked, it cannot be imported");
+    }
+    let json = serde_json::to_string_pretty(&blueprint).map_err(|e| e.to_string())?;
+
+    match output {
+        Some(path) => {
+            std::fs::write(&path, json + "\n")
+                .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+            eprintln!(
+                "Saved {} configs and {} containers of {} to {}",
+                blueprint.configs.len(),
+                blueprint.containers.len(),
+                blueprint.host,
+                path.display()
+            );
+        }
+        None => println!("{}", json),
+    }
+    Ok(())
+}
+
+fn import(client: &ApiClient, file: &Path, all: bool, unlock: bool) -> Result<(), String> {
+    let raw = std::fs::read_to_string(file)
+        .map_err(|e| format!("Failed to read {}: {}", file.display(), e))?;
+    let blueprint: Blueprint = serde_json::from_str(&raw)
+        .map_err(|e| format!("{} is not a blueprint: {}", file.display(), e))?;
+
+    println!(
+        "Blueprint of {}: {} configs, {} containers",
+        blueprint.host,
+        blueprint.configs.len(),
+        blueprint.containers.len()
+    );
+    let pick = |question: &str| -> Result<bool, String> {
+        if all {
+            return Ok(true);
+        }
+        let answer = prompt(&format!("{} [y/N] ", question))?;
+        Ok(answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes"))
+    };
+
+    // sysrat.toml always needs the override token, so only offer it when
+    // unlocking
+    if unlock && blueprint.settings_redacted {
+        println!("Skipping sysrat.toml: exported with masked tokens");
+    }
+    let settings = unlock && !blueprint.settings_redacted && pick("Replace sysrat.toml?")?;
+
+    // Readonly on this host is unknown until written; the blueprint's flag is
+    // the best guess, the server decides
+    let mut configs = Vec::new();
+    // What the override prompt names, sysrat.toml or the first readonly file
+    let mut unlocking = settings.then(|| "sysrat.toml".to_string());
+    for config in &blueprint.configs {
+        if config.content.is_none() {
+            println!("Skipping {}: exported without content", config.name);
+            continue;
+        }
+        if config.readonly && !unlock {
+            println!("Skipping {}: read-only (see --unlock)", config.name);
+            continue;
+        }
+        if pick(&format!("Write {} ({})?", config.name, config.path))? {
+            if config.readonly && unlocking.is_none() {
+                unlocking = Some(config.name.clone());
+            }
+            configs.push(config.name.clone());
+        }
+    }
+
+    if settings || !configs.is_empty() {
+        let override_value = match &unlocking {
+            Some(name) => Some(confirm_override(
+                name,
+                client.file_list()?.readonly_override,
+            )?),
+            None => None,
+        };
+
+        let request = BlueprintImportRequest {
+            blueprint: blueprint.clone(),
+            settings,
+            configs,
+        };
+        let response = client.import_blueprint(&request, override_value.as_deref())?;
+        for result in response.results {
+            match result.error {
+                None => println!("Applied {}", result.item),
+                Some(e) => println!("Failed {}: {}", result.item, e),
+            }
+        }
+    } else {
+        println!("Nothing selected");
+    }
+
+    // Containers are recreated by hand, compose projects from their files
+    if !blueprint.containers.is_empty() {
+        println!();
+        println!("Containers:");
+        for container in &blueprint.containers {
+            match (
+                &container.compose_project,
+                container.compose_files.is_empty(),
+            ) {
+                (Some(project), false) => println!(
+                    "  {}: compose project {} ({})",
+                    container.name,
+                    project,
+                    container.compose_files.join(", ")
+                ),
+                _ => println!("  {}: {}", container.name, container.run),
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use crate::{client::ApiClient, editor};
+use clap::{Subcommand, ValueEnum};
+use std::io::{BufRead, Write};
+use sysrat_api_types::{FileOrder, ReadonlyOverride};
+
+#[derive(Subcommand)]
+pub enum ConfigsCommand {
+    /// List managed config files
+    List {
+        /// Order other than the server's `file_order`
+        #[arg(long, value_enum)]
+        order: Option<Order>,
+    },
+    /// Print a config file, secrets masked
+    Show {
+        name: String,
+        /// Print secrets unmasked (audit-logged by the server)
+        #[arg(long)]
+        reveal: bool,
+    },
+    /// Open a config file in $EDITOR and save it back on change
+    Edit {
+        name: String,
+        /// Override the readonly flag (server must allow it, asks for confirmation)
+        #[arg(long)]
+        unlock: bool,
+    },
+}
+
+/// `FileOrder` as a command line value
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Order {
+    Config,
+    Alphabetical,
+    Category,
+    Mtime,
+}
+
+impl From<Order> for FileOrder {
+    fn from(order: Order) -> Self {
+        match order {
+            Order::Config => FileOrder::Config,
+            Order::Alphabetical => FileOrder::Alphabetical,
+            Order::Category => FileOrder::Category,
+            Order::Mtime => FileOrder::Mtime,
+        }
+    }
+}
+
+pub fn run(client: &ApiClient, cmd: ConfigsCommand) -> Result<(), String> {
+    match cmd {
+        ConfigsCommand::List { order } => list(client, order),
+        ConfigsCommand::Show { name, reveal } => {
+            print!("{}", client.read_config(&name, reveal)?);
+            Ok(())
+        }
+        ConfigsCommand::Edit { name, unlock } => edit(client, &name, unlock),
+    }
+}
+
+fn list(client: &ApiClient, order: Option<Order>) -> Result<(), String> {
+    let list = client.file_list()?;
+    let mut files = list.files;
+    order.map_or(list.order, FileOrder::from).sort(&mut files);
+    let width = files.iter().map(|f| f.name.len()).max().unwrap_or(0);
+
+    for file in files {
+        let flag = if file.readonly { "ro" } else { "rw" };
+        let category = file.category.unwrap_or_default();
+        println!(
+            "{:<width$}  {}  {:<10}  {}",
+            file.name,
+            flag,
+            category,
+            file.description,
+            width = width
+        );
+    }
+    Ok(())
+}
+
+fn edit(client: &ApiClient, name: &str, unlock: bool) -> Result<(), String> {
+    // Fail early instead of discarding edits the server would reject
+    let list = client.file_list()?;
+    let readonly = list
+        .files
+        .iter()
+        .find(|f| f.name == name)
+        .map(|f| f.readonly)
+        .ok_or_else(|| format!("{} is not managed by sysrat", name))?;
+
+    let override_value = match (readonly, unlock) {
+        (false, _) => None,
+        (true, false) => return Err(format!("{} is read-only (see --unlock)", name)),
+        (true, true) => Some(confirm_override(name, list.readonly_override)?),
+    };
+
+    // Masked secrets cannot be saved back, editing needs the real values
+    let content = client.read_config(name, true)?;
+    match editor::edit(name, &content)? {
+        Some(edited) => {
+            client.write_config(name, edited, override_value.as_deref())?;
+            println!("Saved {}", name);
+        }
+        None => println!("No changes to {}", name),
+    }
+    Ok(())
+}
+
+/// Sudo-style confirmation before writing a readonly file
+pub(super) fn confirm_override(name: &str, policy: ReadonlyOverride) -> Result<String, String> {
+    match policy {
+        ReadonlyOverride::Disabled => Err(format!(
+            "{} is read-only and the server does not allow overrides",
+            name
+        )),
+        ReadonlyOverride::Confirm => {
+            let answer = prompt(&format!("{} is read-only. Override? [y/N] ", name))?;
+            if answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes") {
+                Ok("confirm".to_string())
+            } else {
+                Err("Aborted".to_string())
+            }
+        }
+        ReadonlyOverride::Token => match prompt(&format!("Override token for {}: ", name))? {
+            token if token.is_empty() => Err("Aborted".to_string()),
+            token => Ok(token),
+        },
+    }
+}
+
+pub(super) fn prompt(question: &str) -> Result<String, String> {
+    print!("{}", question);
+    std::io::stdout().flush().map_err(|e| e.to_string())?;
+
+    let mut line = String::new();
+    std::io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .map_err(|e| format!("Failed to read answer: {}", e))?;
+    Ok(line.trim().to_string())
+}
@@ -0,0 +1,83 @@
+use crate::client::ApiClient;
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub enum ContainersCommand {
+    /// List all containers
+    List,
+    /// Show details for a container
+    Inspect { id: String },
+    /// Start a container
+    Start { id: String },
+    /// Stop a container
+    Stop { id: String },
+    /// Restart a container
+    Restart { id: String },
+}
+
+pub fn run(client: &ApiClient, cmd: ContainersCommand) -> Result<(), String> {
+    match cmd {
+        ContainersCommand::List => list(client),
+        ContainersCommand::Inspect { id } => inspect(client, &id),
+        ContainersCommand::Start { id } => action(client, &id, "start"),
+        ContainersCommand::Stop { id } => action(client, &id, "stop"),
+        ContainersCommand::Restart { id } => action(client, &id, "restart"),
+    }
+}
+
+fn list(client: &ApiClient) -> Result<(), String> {
+    let containers = client.list_containers()?;
+    let width = containers.iter().map(|c| c.name.len()).max().unwrap_or(0);
+
+    for c in containers {
+        println!(
+            "{}  {:<width$}  {:<10}  {}  {}",
+            c.id,
+            c.name,
+            c.state,
+            c.status,
+            c.service_url.unwrap_or_default(),
+            width = width
+        );
+    }
+    Ok(())
+}
+
+fn inspect(client: &ApiClient, id: &str) -> Result<(), String> {
+    let d = client.container_details(id)?;
+
+    println!("Name:     {}", d.name);
+    println!("ID:       {}", d.id);
+    println!("Image:    {}", d.image);
+    if let Some(url) = &d.service_url {
+        println!("URL:      {}", url);
+    }
+    println!("State:    {}", d.state);
+    if let Some(health) = &d.health {
+        println!("Health:   {}", health);
+    }
+    println!("Restart:  {}", d.restart_policy);
+    for port in &d.ports {
+        let link = port.url.as_deref().unwrap_or("");
+        println!(
+            "Port:     {} -> {}/{}  {}",
+            port.host_port, port.container_port, port.protocol, link
+        );
+    }
+    for vol in &d.volumes {
+        println!(
+            "Volume:   {} -> {} ({})",
+            vol.source, vol.destination, vol.mode
+        );
+    }
+    for net in &d.networks {
+        println!("Network:  {}", net);
+    }
+    Ok(())
+}
+
+fn action(client: &ApiClient, id: &str, action: &str) -> Result<(), String> {
+    let message = client.container_action(id, action)?;
+    println!("{}", message);
+    Ok(())
+}
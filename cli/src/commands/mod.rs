@@ -0,0 +1,3 @@
+pub mod blueprint;
+pub mod configs;
+pub mod containers;
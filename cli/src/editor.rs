@@ -0,0 +1,58 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Let the user edit `content` in $VISUAL/$EDITOR
+///
+/// Returns the edited text, or None if the file was left unchanged.
+pub fn edit(filename: &str, content: &str) -> Result<Option<String>, String> {
+    let path = temp_path(filename);
+    // Only this user may read it, and a file or symlink already at the path
+    // (planted in the shared temp directory) fails instead of being followed
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(&path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    let result = run_editor(&path).and_then(|_| {
+        std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+    });
+    let _ = std::fs::remove_file(&path);
+
+    let edited = result?;
+    Ok((edited != content).then_some(edited))
+}
+
+fn run_editor(path: &PathBuf) -> Result<(), String> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+
+    // Allow editors with arguments, e.g. EDITOR="code --wait"
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().ok_or("EDITOR is empty")?;
+
+    let status = Command::new(program)
+        .args(parts)
+        .arg(path)
+        .status()
+        .map_err(|e| format!("Failed to launch {}: {}", program, e))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("{} exited with {}", program, status))
+    }
+}
+
+/// Temp file keeping the original extension so editors pick the right syntax
+fn temp_path(filename: &str) -> PathBuf {
+    let base = filename.rsplit('/').next().unwrap_or(filename);
+    std::env::temp_dir().join(format!("sysrat-{}-{}", std::process::id(), base))
+}
@@ -0,0 +1,52 @@
+mod client;
+mod commands;
+mod editor;
+
+use clap::{Parser, Subcommand};
+use client::ApiClient;
+
+/// Command-line client for the sysrat server
+#[derive(Parser)]
+#[command(name = "sysrat-cli", version)]
+struct Cli {
+    /// Server URL including the base path, if any (e.g., http://nas:3000/sysrat)
+    #[arg(
+        long,
+        env = "SYSRAT_URL",
+        default_value = "http://localhost:3000",
+        global = true
+    )]
+    server: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Export or import the whole host
+    #[command(subcommand)]
+    Blueprint(commands::blueprint::BlueprintCommand),
+    /// Managed config files
+    #[command(subcommand)]
+    Configs(commands::configs::ConfigsCommand),
+    /// Docker containers
+    #[command(subcommand)]
+    Containers(commands::containers::ContainersCommand),
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let client = ApiClient::new(&cli.server);
+
+    let result = match cli.command {
+        Command::Blueprint(cmd) => commands::blueprint::run(&client, cmd),
+        Command::Configs(cmd) => commands::configs::run(&client, cmd),
+        Command::Containers(cmd) => commands::containers::run(&client, cmd),
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
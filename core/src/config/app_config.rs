@@ -1,8 +1,16 @@
-use super::models::{Config, ConfigFile};
-use super::scanner::scan_directory;
+use super::SharedConfig;
+use super::models::{
+    ActionConfig, BrandingSettings, Config, ConfigFile, DashboardSettings, DigestSettings,
+    EventSettings, GitSettings, HostConfig, HygieneSettings, LinkSettings, LintSettings,
+    LogFileConfig, NetworkSettings, NotifierSettings, ProfileSettings, ProtectionSettings,
+    RenderSettings, RunbookConfig, ServerSettings,
+};
+use super::scanner::{scan_directories, sort_found};
 use k_lib::config::Cookbook;
 use k_lib::logger;
+use regex::Regex;
 use std::collections::HashMap;
+use sysrat_api_types::{FileOrder, ReadonlyOverride};
 
 const SCOPE: &str = "CONFIG";
 const APP_NAME: &str = "sysrat";
@@ -18,11 +26,40 @@ pub struct AppConfig {
     files: Vec<ConfigFile>,
     file_index: HashMap<String, usize>,
     allowed_extensions: Vec<String>,
+    readonly_override: ReadonlyOverride,
+    override_token: Option<String>,
+    sudo_helper: String,
+    backup_dir: String,
+    file_order: FileOrder,
+    server: ServerSettings,
+    links: LinkSettings,
+    dashboard: DashboardSettings,
+    protection: ProtectionSettings,
+    branding: BrandingSettings,
+    network: NetworkSettings,
+    lint: LintSettings,
+    hygiene: HygieneSettings,
+    render: RenderSettings,
+    /// Compiled `[secrets] patterns`
+    secrets: Vec<Regex>,
+    git: GitSettings,
+    notifiers: NotifierSettings,
+    digest: DigestSettings,
+    events: EventSettings,
+    profiles: ProfileSettings,
+    actions: Vec<ActionConfig>,
+    runbooks: Vec<RunbookConfig>,
+    logs: Vec<LogFileConfig>,
+    hosts: Vec<HostConfig>,
+    roles: HashMap<String, String>,
 }
 
 impl AppConfig {
     /// Load configuration from file
-    pub fn load() -> Result<Self, String> {
+    ///
+    /// Directories are scanned concurrently on the blocking pool; a directory
+    /// is only walked again when one of its directories has a new mtime.
+    pub async fn load() -> Result<Self, String> {
         let cookbook = Cookbook::load().ok();
         let config_path = Self::config_path();
 
@@ -43,6 +80,65 @@ impl AppConfig {
         // Store allowed extensions
         let allowed_extensions = config.settings.allowed_extensions.clone();
 
+        // Readonly override policy (a configured token doubles as re-auth)
+        let override_token = config
+            .settings
+            .readonly_override_token
+            .clone()
+            .filter(|t| !t.is_empty());
+        let readonly_override = match (config.settings.allow_readonly_override, &override_token) {
+            (false, _) => ReadonlyOverride::Disabled,
+            (true, Some(_)) => ReadonlyOverride::Token,
+            (true, None) => ReadonlyOverride::Confirm,
+        };
+        let sudo_helper = config
+            .settings
+            .sudo_helper
+            .clone()
+            .unwrap_or_else(|| crate::configs::permissions::DEFAULT_SUDO_HELPER.to_string());
+        let backup_dir = config
+            .settings
+            .backup_dir
+            .clone()
+            .unwrap_or_else(|| crate::configs::backup::DEFAULT_BACKUP_DIR.to_string());
+        let file_order = config.settings.file_order;
+        let server = config.server.clone();
+        let links = config.links.clone();
+        let dashboard = config.dashboard.clone();
+        let protection = config.protection.clone();
+        let branding = config.branding.clone();
+        let network = config.network.clone();
+        let lint = config.lint.clone();
+        let hygiene = config.hygiene.clone();
+        let render = config.render.clone();
+        let secrets = config
+            .secrets
+            .patterns
+            .iter()
+            .map(|p| Regex::new(p).map_err(|e| format!("Invalid secret pattern {}: {}", p, e)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let git = config.git.clone();
+        let notifiers = config.notifiers.clone();
+        let digest = config.digest.clone();
+        let events = config.events.clone();
+        let profiles = config.profiles.clone();
+        let actions = config.actions.clone();
+        let runbooks = config.runbooks.clone();
+        let logs = config.logs.iter().cloned().map(Self::name_log).collect();
+        // The browser fetches from these, a bare host name would be a path
+        if let Some(host) = config
+            .hosts
+            .iter()
+            .find(|host| !host.url.starts_with("http://") && !host.url.starts_with("https://"))
+        {
+            return Err(format!(
+                "Host {} needs an http:// or https:// url, got {}",
+                host.name, host.url
+            ));
+        }
+        let hosts = config.hosts.clone();
+        let roles = config.roles.clone();
+
         // Keep ordered list plus name-to-index lookup
         let mut files = Vec::new();
         let mut file_index = HashMap::new();
@@ -56,18 +152,48 @@ impl AppConfig {
         }
 
         // Scan directories and add found files
-        for dir_config in config.directories {
+        let scans = scan_directories(&config.directories).await;
+        for (dir_config, scan) in config.directories.iter().zip(scans) {
             if let Some(ref cb) = cookbook {
                 log(cb, "info", &format!("  [scan] {}", dir_config.path));
             }
-            match scan_directory(&dir_config) {
-                Ok(scanned_files) => {
-                    for file in scanned_files {
+            let scan = scan.map(|mut scan| {
+                sort_found(&mut scan.files, dir_config.order);
+                scan
+            });
+            match scan {
+                Ok(scan) if scan.cached => {
+                    if let Some(ref cb) = cookbook {
+                        log(
+                            cb,
+                            "info",
+                            &format!("    unchanged, {} files", scan.files.len()),
+                        );
+                    }
+                    for file in scan.files {
+                        Self::insert_file(file, &mut files, &mut file_index);
+                    }
+                }
+                Ok(scan) => {
+                    for file in scan.files {
                         if let Some(ref cb) = cookbook {
                             log(cb, "success", &format!("    {}", file.name));
                         }
                         Self::insert_file(file, &mut files, &mut file_index);
                     }
+                    if scan.truncated
+                        && let Some(ref cb) = cookbook
+                    {
+                        log(
+                            cb,
+                            "warn",
+                            &format!(
+                                "Stopped scanning {} after max_files = {}",
+                                dir_config.name,
+                                dir_config.max_files.unwrap_or_default()
+                            ),
+                        );
+                    }
                 }
                 Err(e) => {
                     if let Some(ref cb) = cookbook {
@@ -98,6 +224,31 @@ impl AppConfig {
             files,
             file_index,
             allowed_extensions,
+            readonly_override,
+            override_token,
+            sudo_helper,
+            backup_dir,
+            file_order,
+            server,
+            links,
+            dashboard,
+            protection,
+            branding,
+            network,
+            lint,
+            hygiene,
+            render,
+            secrets,
+            git,
+            notifiers,
+            digest,
+            events,
+            profiles,
+            actions,
+            runbooks,
+            logs,
+            hosts,
+            roles,
         })
     }
 
@@ -122,6 +273,174 @@ impl AppConfig {
         &self.allowed_extensions
     }
 
+    /// Get how writes to readonly files may be unlocked
+    pub fn readonly_override(&self) -> ReadonlyOverride {
+        self.readonly_override
+    }
+
+    /// Check an override header value against the configured policy
+    pub fn accepts_override(&self, value: &str) -> bool {
+        match self.readonly_override {
+            ReadonlyOverride::Disabled => false,
+            ReadonlyOverride::Confirm => !value.trim().is_empty(),
+            ReadonlyOverride::Token => self.override_token.as_deref() == Some(value),
+        }
+    }
+
+    /// Check an override header value against the configured token; a
+    /// confirmation is not enough for what grants more than a write
+    pub fn accepts_token(&self, value: &str) -> bool {
+        self.readonly_override == ReadonlyOverride::Token && self.accepts_override(value)
+    }
+
+    /// Program saves with `write_via = "sudo"` run under sudo
+    pub fn sudo_helper(&self) -> &str {
+        &self.sudo_helper
+    }
+
+    /// Directory backups are mirrored into, empty for sibling `.backup` files
+    pub fn backup_dir(&self) -> &str {
+        &self.backup_dir
+    }
+
+    /// Order of the file list until the user picks another
+    pub fn file_order(&self) -> FileOrder {
+        self.file_order
+    }
+
+    /// Get HTTP server settings
+    pub fn server(&self) -> &ServerSettings {
+        &self.server
+    }
+
+    /// Get port link settings
+    pub fn links(&self) -> &LinkSettings {
+        &self.links
+    }
+
+    /// Get dashboard data sources
+    pub fn dashboard(&self) -> &DashboardSettings {
+        &self.dashboard
+    }
+
+    /// Get the containers guarded against accidental stops
+    pub fn protection(&self) -> &ProtectionSettings {
+        &self.protection
+    }
+
+    /// Get the banner and MOTD of the web UI
+    pub fn branding(&self) -> &BrandingSettings {
+        &self.branding
+    }
+
+    /// Get how the web UI retries failed reads
+    pub fn network(&self) -> &NetworkSettings {
+        &self.network
+    }
+
+    /// Get the rule packs enabled per category
+    pub fn lint(&self) -> &LintSettings {
+        &self.lint
+    }
+
+    /// Get the whitespace rules per category
+    pub fn hygiene(&self) -> &HygieneSettings {
+        &self.hygiene
+    }
+
+    /// Get where the rendered view takes variables from
+    pub fn render(&self) -> &RenderSettings {
+        &self.render
+    }
+
+    /// Get the patterns of secrets masked in read responses
+    pub fn secret_patterns(&self) -> &[Regex] {
+        &self.secrets
+    }
+
+    /// Get git versioning settings
+    pub fn git(&self) -> &GitSettings {
+        &self.git
+    }
+
+    /// Get the notification channels
+    pub fn notifiers(&self) -> &NotifierSettings {
+        &self.notifiers
+    }
+
+    /// Get the weekly digest schedule
+    pub fn digest(&self) -> &DigestSettings {
+        &self.digest
+    }
+
+    /// Get where change events are recorded
+    pub fn events(&self) -> &EventSettings {
+        &self.events
+    }
+
+    /// Get where user profiles are kept and who the user is
+    pub fn profiles(&self) -> &ProfileSettings {
+        &self.profiles
+    }
+
+    /// Get the scriptable actions in configured order
+    pub fn actions(&self) -> &[ActionConfig] {
+        &self.actions
+    }
+
+    /// Get an action by name
+    pub fn get_action(&self, name: &str) -> Option<&ActionConfig> {
+        self.actions.iter().find(|action| action.name == name)
+    }
+
+    /// Get the followed log files in configured order
+    pub fn logs(&self) -> &[LogFileConfig] {
+        &self.logs
+    }
+
+    /// Get a log file by name
+    pub fn get_log(&self, name: &str) -> Option<&LogFileConfig> {
+        self.logs.iter().find(|log| log.name == name)
+    }
+
+    /// Get the other servers the web UI can switch to, in configured order
+    pub fn hosts(&self) -> &[HostConfig] {
+        &self.hosts
+    }
+
+    /// Get the runbooks in configured order
+    pub fn runbooks(&self) -> &[RunbookConfig] {
+        &self.runbooks
+    }
+
+    /// Get a runbook by name
+    pub fn get_runbook(&self, name: &str) -> Option<&RunbookConfig> {
+        self.runbooks.iter().find(|runbook| runbook.name == name)
+    }
+
+    /// Check a token against the role an action requires (unknown roles never match)
+    pub fn accepts_role(&self, role: &str, token: Option<&str>) -> bool {
+        match (self.roles.get(role), token) {
+            (Some(expected), Some(token)) => !expected.is_empty() && expected == token,
+            _ => false,
+        }
+    }
+
+    /// Get the normalized route prefix ("" when served at the root)
+    ///
+    /// SYSRAT_BASE_PATH takes precedence over `server.base_path` in sysrat.toml.
+    /// The result always starts with a slash and never ends with one.
+    pub fn base_path(&self) -> String {
+        let raw =
+            std::env::var("SYSRAT_BASE_PATH").unwrap_or_else(|_| self.server.base_path.clone());
+        let trimmed = raw.trim().trim_matches('/');
+        if trimmed.is_empty() {
+            String::new()
+        } else {
+            format!("/{}", trimmed)
+        }
+    }
+
     /// Get the config file path (XDG-compliant)
     ///
     /// Search order:
@@ -129,7 +448,7 @@ impl AppConfig {
     /// 2. XDG_CONFIG_HOME/sysrat/sysrat.toml
     /// 3. ~/.config/sysrat/sysrat.toml
     /// 4. ./sysrat.toml (fallback)
-    fn config_path() -> String {
+    pub fn config_path() -> String {
         use std::path::Path;
 
         // 1. Explicit override via env var
@@ -157,6 +476,17 @@ impl AppConfig {
         "sysrat.toml".to_string()
     }
 
+    /// Fill in the name of a log left unnamed with its file name
+    fn name_log(mut log: LogFileConfig) -> LogFileConfig {
+        if log.name.is_empty() {
+            log.name = std::path::Path::new(&log.path)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| log.path.clone());
+        }
+        log
+    }
+
     /// Insert or replace a file while preserving user ordering
     fn insert_file(
         file: ConfigFile,
@@ -172,10 +502,35 @@ impl AppConfig {
         }
     }
 
-    /// Reloads the configuration from disk, updating the current instance
-    pub fn refresh(&mut self) -> Result<(), String> {
-        let new_config = Self::load()?;
-        *self = new_config;
+    /// Reloads the configuration from disk, updating the shared instance
+    ///
+    /// The new configuration is loaded before the write lock is taken, so
+    /// readers are not blocked while directories are scanned.
+    pub async fn refresh(config: &SharedConfig) -> Result<(), String> {
+        let new_config = Self::load().await?;
+        *config.write().await = new_config;
         Ok(())
     }
+
+    /// Replace sysrat.toml with `content`, keeping a backup like config
+    /// writes do, and reload it. Content that does not parse is rejected
+    /// before anything is written.
+    pub async fn restore(config: &SharedConfig, content: &str) -> Result<(), String> {
+        toml::from_str::<Config>(content).map_err(|e| format!("Failed to parse config: {}", e))?;
+
+        let path = Self::config_path();
+        let (backup_dir, events) = {
+            let reader = config.read().await;
+            (reader.backup_dir().to_string(), reader.events().clone())
+        };
+        crate::configs::backup::create(&backup_dir, &path)
+            .await
+            .map_err(|e| format!("Failed to back up {}: {}", path, e))?;
+        let written = crate::fsutil::write_atomic(&path, content).await;
+        let error = written.as_ref().err().map(|e| e.to_string());
+        crate::events::publish(&events, "config.restore", &path, error).await;
+        written.map_err(|e| format!("Failed to write {}: {}", path, e))?;
+
+        Self::refresh(config).await
+    }
 }
@@ -3,7 +3,14 @@ mod models;
 mod scanner;
 
 pub use app_config::AppConfig;
-pub use models::{Config, ConfigDirectory, ConfigFile};
+pub use models::{
+    ActionConfig, BrandingSettings, CertFile, Config, ConfigDirectory, ConfigFile, CorsSettings,
+    DashboardSettings, DigestSettings, EmailSettings, EventSettings, GitSettings, HygieneRules,
+    HygieneSettings, IndentStyle, LimitSettings, LinkSettings, LintCommand, LintSettings,
+    LogFileConfig, NetworkSettings, NotifierSettings, ProfileSettings, ProtectionSettings,
+    RenderSettings, RunbookConfig, RunbookStepConfig, SecretSettings, ServerSettings, TlsSettings,
+    Weekday, WriteVia,
+};
 
 use std::sync::Arc;
 use tokio::sync::RwLock;
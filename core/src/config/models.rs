@@ -1,9 +1,31 @@
+use globset::Glob;
 use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::net::IpAddr;
+use sysrat_api_types::{FileOrder, FileSchema, QuickAction, SchemaField};
 
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct Settings {
     #[serde(default = "default_allowed_extensions")]
     pub allowed_extensions: Vec<String>,
+    /// Allow clients to write readonly files after an explicit confirmation
+    #[serde(default)]
+    pub allow_readonly_override: bool,
+    /// Optional secret the client must re-enter to confirm an override
+    #[serde(default)]
+    pub readonly_override_token: Option<String>,
+    /// Program files with `write_via = "sudo"` are written through
+    /// (`sudo -n <helper> <path>`, content on stdin), "tee" if unset
+    #[serde(default)]
+    pub sudo_helper: Option<String>,
+    /// Directory mirroring file paths for the copy taken before each write,
+    /// empty for `<file>.backup` next to the file (default
+    /// ~/.local/share/sysrat/backups)
+    #[serde(default)]
+    pub backup_dir: Option<String>,
+    /// How the file list is ordered until the user picks another order
+    #[serde(default)]
+    pub file_order: FileOrder,
 }
 
 fn default_allowed_extensions() -> Vec<String> {
@@ -14,6 +36,613 @@ fn default_allowed_extensions() -> Vec<String> {
         .collect()
 }
 
+/// HTTP server settings (`[server]` section)
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ServerSettings {
+    /// Path prefix for all routes when running behind a reverse proxy (e.g., "/sysrat")
+    /// Can be overridden with the SYSRAT_BASE_PATH env var
+    #[serde(default)]
+    pub base_path: String,
+    #[serde(default)]
+    pub cors: CorsSettings,
+    #[serde(default)]
+    pub limits: LimitSettings,
+    /// HTTPS instead of plain HTTP, read at startup
+    #[serde(default)]
+    pub tls: Option<TlsSettings>,
+}
+
+/// HTTPS of a server running as an agent of a sysrat hub (`[server.tls]`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct TlsSettings {
+    /// PEM certificate chain of the server
+    pub cert: String,
+    /// PEM private key of the certificate
+    pub key: String,
+    /// PEM CA certificates; clients must present a certificate signed by one
+    /// of them (mTLS), without them any client is accepted
+    #[serde(default)]
+    pub client_ca: Option<String>,
+}
+
+/// Request limits against runaway scripts, read at startup (`[server.limits]`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct LimitSettings {
+    /// Largest request body accepted, e.g. a config write or upload
+    #[serde(default = "default_max_body_bytes")]
+    pub max_body_bytes: usize,
+    /// Requests that change something or run a command (anything but GET
+    /// and HEAD) per client IP and minute, 0 disables the limit
+    #[serde(default = "default_writes_per_minute")]
+    pub writes_per_minute: u32,
+    /// Reverse proxies (and a sysrat hub) whose X-Forwarded-For names the
+    /// client; requests from anyone else count for their own address
+    #[serde(default)]
+    pub trusted_proxies: Vec<IpAddr>,
+}
+
+impl Default for LimitSettings {
+    fn default() -> Self {
+        Self {
+            max_body_bytes: default_max_body_bytes(),
+            writes_per_minute: default_writes_per_minute(),
+            trusted_proxies: Vec::new(),
+        }
+    }
+}
+
+fn default_max_body_bytes() -> usize {
+    4 * 1024 * 1024
+}
+
+fn default_writes_per_minute() -> u32 {
+    60
+}
+
+/// Cross-origin settings for setups where the frontend is served from another origin
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct CorsSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Allowed origins (e.g., "https://dash.example.com"), "*" allows any origin
+    #[serde(default)]
+    pub allowed_origins: Vec<String>,
+}
+
+/// Quick-open links for published container ports (`[links]` section)
+#[derive(Debug, Clone, Deserialize)]
+pub struct LinkSettings {
+    /// Scheme used for port links unless the port is listed in `https_ports`
+    #[serde(default = "default_link_scheme")]
+    pub scheme: String,
+    /// Host used in port links, empty uses the host the UI was requested on
+    #[serde(default)]
+    pub host: String,
+    /// Host ports that are always opened with https
+    #[serde(default)]
+    pub https_ports: Vec<String>,
+}
+
+impl Default for LinkSettings {
+    fn default() -> Self {
+        Self {
+            scheme: default_link_scheme(),
+            host: String::new(),
+            https_ports: Vec::new(),
+        }
+    }
+}
+
+fn default_link_scheme() -> String {
+    "http".to_string()
+}
+
+/// Dashboard data sources (`[dashboard]` section)
+#[derive(Debug, Clone, Deserialize)]
+pub struct DashboardSettings {
+    /// Certificates whose expiry is shown on the dashboard
+    #[serde(default)]
+    pub certs: Vec<CertFile>,
+    /// Raise an alert when a certificate expires within this many days
+    #[serde(default = "default_cert_warn_days")]
+    pub cert_warn_days: i64,
+    /// Shortcuts shown on the dashboard, bound to keys 1-9
+    #[serde(default)]
+    pub quick_actions: Vec<QuickAction>,
+}
+
+impl Default for DashboardSettings {
+    fn default() -> Self {
+        Self {
+            certs: Vec::new(),
+            cert_warn_days: default_cert_warn_days(),
+            quick_actions: Vec::new(),
+        }
+    }
+}
+
+fn default_cert_warn_days() -> i64 {
+    14
+}
+
+/// Containers whose stop/restart must be confirmed by typing their name
+/// (`[protection]` section)
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ProtectionSettings {
+    /// Container names, `*` and `?` wildcards allowed (e.g. "prod-*")
+    #[serde(default)]
+    pub names: Vec<String>,
+    /// Labels as "key" (any value) or "key=value"
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+impl ProtectionSettings {
+    /// Whether a container with this name and these labels is protected
+    pub fn protects(&self, name: &str, labels: &BTreeMap<String, String>) -> bool {
+        let name_matches = self.names.iter().any(|pattern| {
+            Glob::new(pattern)
+                .map(|glob| glob.compile_matcher().is_match(name))
+                .unwrap_or(pattern == name)
+        });
+        name_matches
+            || self.labels.iter().any(|label| match label.split_once('=') {
+                Some((key, value)) => labels.get(key).is_some_and(|v| v == value),
+                None => labels.contains_key(label),
+            })
+    }
+}
+
+/// Banner and message of the day shown by the web UI (`[branding]` section)
+#[derive(Debug, Clone, Deserialize)]
+pub struct BrandingSettings {
+    /// ASCII art shown in place of the built-in title on the splash screen
+    /// and in the menu
+    #[serde(default)]
+    pub banner: Option<String>,
+    /// In the menu, show the banner in place of the rat and keep the title
+    #[serde(default)]
+    pub replace_rat: bool,
+    /// Message shown below the banner and the menu, may span lines
+    #[serde(default)]
+    pub motd: Option<String>,
+    /// Directory of `*.ascii` files shown in place of the rat, one frame
+    /// per file in name order
+    #[serde(default)]
+    pub art_dir: Option<String>,
+    /// Cycle through the frames, only the first is shown when off
+    #[serde(default = "default_animate")]
+    pub animate: bool,
+    /// How long each frame stays on screen
+    #[serde(default = "default_frame_ms")]
+    pub frame_ms: u64,
+    /// Play the intro animation before the menu
+    #[serde(default = "default_splash")]
+    pub splash: bool,
+    /// How long the intro plays unless a key skips it
+    #[serde(default = "default_splash_ms")]
+    pub splash_ms: u64,
+}
+
+impl Default for BrandingSettings {
+    fn default() -> Self {
+        Self {
+            banner: None,
+            replace_rat: false,
+            motd: None,
+            art_dir: None,
+            animate: default_animate(),
+            frame_ms: default_frame_ms(),
+            splash: default_splash(),
+            splash_ms: default_splash_ms(),
+        }
+    }
+}
+
+fn default_animate() -> bool {
+    true
+}
+
+fn default_frame_ms() -> u64 {
+    1_000
+}
+
+fn default_splash() -> bool {
+    true
+}
+
+fn default_splash_ms() -> u64 {
+    crate::types::DEFAULT_SPLASH_MS
+}
+
+/// How the web UI retries reads that failed on the way to the server and
+/// how often it polls (`[network]` section)
+#[derive(Debug, Clone, Deserialize)]
+pub struct NetworkSettings {
+    /// Attempts per GET, including the first; 1 disables retrying
+    #[serde(default = "default_retry_attempts")]
+    pub retry_attempts: u32,
+    /// Wait before the first retry, doubled for each one after it
+    #[serde(default = "default_retry_base_ms")]
+    pub retry_base_ms: u64,
+    /// Longest wait between two attempts
+    #[serde(default = "default_retry_max_ms")]
+    pub retry_max_ms: u64,
+    /// Milliseconds between background refreshes of panes with live data
+    #[serde(default = "default_refresh_ms")]
+    pub refresh_ms: u64,
+    /// Refresh interval by pane (container_list, dashboard, container_watch)
+    #[serde(default)]
+    pub pane_refresh_ms: BTreeMap<String, u64>,
+}
+
+impl Default for NetworkSettings {
+    fn default() -> Self {
+        Self {
+            retry_attempts: default_retry_attempts(),
+            retry_base_ms: default_retry_base_ms(),
+            retry_max_ms: default_retry_max_ms(),
+            refresh_ms: default_refresh_ms(),
+            pane_refresh_ms: BTreeMap::new(),
+        }
+    }
+}
+
+fn default_retry_attempts() -> u32 {
+    3
+}
+
+fn default_retry_base_ms() -> u64 {
+    500
+}
+
+fn default_retry_max_ms() -> u64 {
+    4_000
+}
+
+fn default_refresh_ms() -> u64 {
+    10_000
+}
+
+/// Rule packs checked before a save (`[lint]` section)
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct LintSettings {
+    /// Pack names by file category, "*" applies to every file
+    #[serde(default)]
+    pub packs: BTreeMap<String, Vec<String>>,
+    /// External checkers run against the editor content (`[[lint.commands]]`)
+    #[serde(default)]
+    pub commands: Vec<LintCommand>,
+}
+
+/// External checker such as shellcheck, `nginx -t` or `sshd -t`
+#[derive(Debug, Clone, Deserialize)]
+pub struct LintCommand {
+    /// Managed file names it checks
+    #[serde(default)]
+    pub files: Vec<String>,
+    /// Extensions (without the dot) of the files it checks
+    #[serde(default)]
+    pub extensions: Vec<String>,
+    /// Program to run, executed directly without a shell
+    pub command: String,
+    /// `{file}` is replaced by a temporary copy of the content, which is
+    /// appended when no argument names it
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// The command is killed after this many seconds
+    #[serde(default = "default_lint_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_lint_timeout() -> u64 {
+    10
+}
+
+impl LintSettings {
+    /// Checkers configured for the managed file `name` at `path`
+    pub fn commands_for(&self, name: &str, path: &str) -> Vec<LintCommand> {
+        let extension = std::path::Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        self.commands
+            .iter()
+            .filter(|c| {
+                c.files.iter().any(|f| f == name)
+                    || c.extensions
+                        .iter()
+                        .any(|e| e.eq_ignore_ascii_case(extension))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Packs enabled for a file of `category`, each named once
+    pub fn packs_for(&self, category: Option<&str>) -> Vec<String> {
+        let mut packs: Vec<String> = Vec::new();
+        let keys = [Some(crate::configs::lint::ALL_CATEGORIES), category];
+        for key in keys.into_iter().flatten() {
+            for pack in self.packs.get(key).into_iter().flatten() {
+                if !packs.contains(pack) {
+                    packs.push(pack.clone());
+                }
+            }
+        }
+        packs
+    }
+}
+
+/// Whitespace fixes offered before a save (`[hygiene]` section)
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct HygieneSettings {
+    /// Rules by file category, "*" applies to every file; a category's
+    /// rules override single options of "*"
+    #[serde(default)]
+    pub rules: BTreeMap<String, HygieneRules>,
+}
+
+impl HygieneSettings {
+    /// Rules for a file of `category`, "*" overridden by the category's own
+    pub fn rules_for(&self, category: Option<&str>) -> HygieneRules {
+        let mut rules = HygieneRules::default();
+        let keys = [Some(crate::configs::lint::ALL_CATEGORIES), category];
+        for set in keys
+            .into_iter()
+            .flatten()
+            .filter_map(|key| self.rules.get(key))
+        {
+            rules.final_newline = set.final_newline.or(rules.final_newline);
+            rules.trim_trailing_whitespace = set
+                .trim_trailing_whitespace
+                .or(rules.trim_trailing_whitespace);
+            rules.indent = set.indent.or(rules.indent);
+            rules.tab_width = set.tab_width.or(rules.tab_width);
+            rules.indent_width = set.indent_width.or(rules.indent_width);
+            rules.trim_on_save = set.trim_on_save.or(rules.trim_on_save);
+        }
+        rules
+    }
+}
+
+/// Unset options leave that detail of the file alone
+#[derive(Debug, Clone, Copy, Deserialize, Default, PartialEq)]
+pub struct HygieneRules {
+    /// End the file with a newline
+    #[serde(default)]
+    pub final_newline: Option<bool>,
+    /// Drop spaces and tabs at the end of lines
+    #[serde(default)]
+    pub trim_trailing_whitespace: Option<bool>,
+    /// Indent leading whitespace with tabs or spaces only
+    #[serde(default)]
+    pub indent: Option<IndentStyle>,
+    /// Columns of a tab when converting the indentation, 4 if unset
+    #[serde(default)]
+    pub tab_width: Option<usize>,
+    /// Columns one indent level takes in the editor, detected if unset
+    #[serde(default)]
+    pub indent_width: Option<u8>,
+    /// Trim trailing whitespace in the editor on every save, without
+    /// showing it as a fix
+    #[serde(default)]
+    pub trim_on_save: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum IndentStyle {
+    Tabs,
+    Spaces,
+}
+
+/// Secret values masked in read responses (`[secrets]` section)
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct SecretSettings {
+    /// Regexes of secrets; the first capture group is masked, the whole
+    /// match without one
+    #[serde(default)]
+    pub patterns: Vec<String>,
+}
+
+/// Variables for the rendered view of a file (`[render]` section)
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct RenderSettings {
+    /// Fall back to the server's own environment, which may hold secrets
+    #[serde(default)]
+    pub env: bool,
+    /// KEY=VALUE file for files without a `vars_file` of their own
+    #[serde(default)]
+    pub vars_file: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CertFile {
+    pub name: String,
+    /// PEM certificate path
+    pub path: String,
+}
+
+/// Git-backed versioning of saved files (`[git]` section)
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitSettings {
+    /// Commit every saved file into `repo_path`
+    #[serde(default)]
+    pub enabled: bool,
+    /// Repository holding the file history, created on first save
+    #[serde(default = "default_git_repo_path")]
+    pub repo_path: String,
+    #[serde(default = "default_git_author_name")]
+    pub author_name: String,
+    #[serde(default = "default_git_author_email")]
+    pub author_email: String,
+    /// Commit message, `{file}` is replaced with the file name
+    #[serde(default = "default_git_message")]
+    pub message: String,
+}
+
+impl Default for GitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            repo_path: default_git_repo_path(),
+            author_name: default_git_author_name(),
+            author_email: default_git_author_email(),
+            message: default_git_message(),
+        }
+    }
+}
+
+fn default_git_repo_path() -> String {
+    "~/.local/share/sysrat/history".to_string()
+}
+
+fn default_git_author_name() -> String {
+    "sysrat".to_string()
+}
+
+fn default_git_author_email() -> String {
+    "sysrat@localhost".to_string()
+}
+
+fn default_git_message() -> String {
+    "Update {file}".to_string()
+}
+
+/// Where notifications are sent (`[notifiers]` section)
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct NotifierSettings {
+    /// URLs that receive a JSON POST with `subject`, `text` and `html`
+    #[serde(default)]
+    pub webhooks: Vec<String>,
+    /// Mail handed to a local sendmail, none when unset
+    #[serde(default)]
+    pub email: Option<EmailSettings>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmailSettings {
+    pub to: Vec<String>,
+    #[serde(default = "default_email_from")]
+    pub from: String,
+    /// sendmail-compatible program, reads the message on stdin (`-t -i`)
+    #[serde(default = "default_sendmail")]
+    pub sendmail: String,
+}
+
+fn default_email_from() -> String {
+    "sysrat@localhost".to_string()
+}
+
+fn default_sendmail() -> String {
+    "/usr/sbin/sendmail".to_string()
+}
+
+/// Change events for external automation (`[events]` section)
+#[derive(Debug, Clone, Deserialize)]
+pub struct EventSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// NDJSON file every config write and container action is appended to
+    #[serde(default = "default_events_file")]
+    pub file: String,
+    /// URL each event is POSTed to, retried until it answers 2xx
+    #[serde(default)]
+    pub webhook: Option<String>,
+}
+
+impl Default for EventSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            file: default_events_file(),
+            webhook: None,
+        }
+    }
+}
+
+fn default_events_file() -> String {
+    crate::events::DEFAULT_EVENTS_FILE.to_string()
+}
+
+/// UI preferences kept per user on the server (`[profiles]` section)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProfileSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Request header the authenticating reverse proxy puts the user name in
+    #[serde(default = "default_profile_user_header")]
+    pub user_header: String,
+    /// Directory with one JSON file per user
+    #[serde(default = "default_profile_dir")]
+    pub dir: String,
+}
+
+impl Default for ProfileSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            user_header: default_profile_user_header(),
+            dir: default_profile_dir(),
+        }
+    }
+}
+
+fn default_profile_user_header() -> String {
+    "Remote-User".to_string()
+}
+
+fn default_profile_dir() -> String {
+    crate::profiles::DEFAULT_PROFILE_DIR.to_string()
+}
+
+/// Weekly summary sent through the notifiers (`[digest]` section)
+#[derive(Debug, Clone, Deserialize)]
+pub struct DigestSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Day the digest is sent on
+    #[serde(default)]
+    pub weekday: Weekday,
+    /// Hour (UTC) the digest is sent at
+    #[serde(default = "default_digest_hour")]
+    pub hour: u32,
+}
+
+impl Default for DigestSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            weekday: Weekday::default(),
+            hour: default_digest_hour(),
+        }
+    }
+}
+
+fn default_digest_hour() -> u32 {
+    8
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Weekday {
+    #[default]
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Days after Monday
+    pub fn index(self) -> i64 {
+        self as i64
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ConfigFile {
     pub path: String,
@@ -25,13 +654,48 @@ pub struct ConfigFile {
     /// Optional category label used for grouping/sorting in the UI
     #[serde(default)]
     pub category: Option<String>,
-    /// Optional theme variant name for this file (e.g., "mocha", "latte", "frappe")
-    /// If not specified, the default theme is used
+    /// Optional theme name for this file (e.g., "mocha", "latte", "frappe"),
+    /// shown by the web UI while the file is open in the editor. If not
+    /// specified, the user's theme is used
     #[serde(default)]
     pub theme: Option<String>,
+    /// Optional typed fields, shown as a validated form next to the raw editor
+    #[serde(default)]
+    pub schema: Option<FileSchema>,
+    /// Where the file can be refreshed from (blocklists, geoip configs, ...)
+    #[serde(default)]
+    pub upstream_url: Option<String>,
+    /// Expected SHA-256 (hex) of the upstream content
+    #[serde(default)]
+    pub upstream_sha256: Option<String>,
+    /// Detached signature of the upstream content, checked with `gpg --verify`
+    #[serde(default)]
+    pub upstream_signature_url: Option<String>,
+    /// How saves reach the file, "sudo" for files the server may not write
+    #[serde(default)]
+    pub write_via: WriteVia,
+    /// Comment leader for the editor's comment toggle (e.g. "#", "//", ";"),
+    /// inferred from the path if unset; "" for a file without comments
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// KEY=VALUE file the `${VARS}` of the rendered view are taken from,
+    /// `[render] vars_file` if unset
+    #[serde(default)]
+    pub vars_file: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// How a managed file is written (`write_via` of a file entry)
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum WriteVia {
+    /// By the server process itself
+    #[default]
+    Direct,
+    /// Through the `sudo_helper` under `sudo -n`
+    Sudo,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ConfigDirectory {
     pub path: String,
     pub name: String,
@@ -46,18 +710,191 @@ pub struct ConfigDirectory {
     /// Optional category label applied to all files found in this directory
     #[serde(default)]
     pub category: Option<String>,
+    /// Glob patterns of files and directories to leave out (e.g. "*.dpkg-old");
+    /// a pattern with a `/` matches the path relative to `path` instead of the name
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Include symlinked files and descend into symlinked directories
+    #[serde(default)]
+    pub follow_symlinks: bool,
+    /// Stop after this many files, unlimited if unset
+    #[serde(default)]
+    pub max_files: Option<usize>,
+    /// Order of the files found, kept where the directory sits in the list;
+    /// "config" keeps the order of the directory walk
+    #[serde(default = "default_directory_order")]
+    pub order: FileOrder,
 }
 
 fn default_depth() -> usize {
     3
 }
 
+fn default_directory_order() -> FileOrder {
+    FileOrder::Alphabetical
+}
+
+/// Named shell action run from the Actions pane (`[[actions]]` section)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionConfig {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Program to run, executed directly without a shell
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Working directory, the server's own when unset
+    #[serde(default)]
+    pub working_dir: Option<String>,
+    /// The command is killed after this many seconds
+    #[serde(default = "default_action_timeout")]
+    pub timeout_secs: u64,
+    /// Role from `[roles]` whose token must be given to run the action
+    #[serde(default)]
+    pub role: Option<String>,
+    /// Question the UI asks before running the action
+    #[serde(default)]
+    pub confirm: Option<String>,
+    /// Typed values asked for before running (`[[actions.params]]`),
+    /// substituted into `{key}` placeholders of `args`
+    #[serde(default)]
+    pub params: Vec<SchemaField>,
+}
+
+fn default_action_timeout() -> u64 {
+    60
+}
+
+/// Log file followed from the Logs pane (`[[logs]]` section)
+#[derive(Debug, Clone, Deserialize)]
+pub struct LogFileConfig {
+    /// Name in the UI and the API, the file name when left out
+    #[serde(default)]
+    pub name: String,
+    pub path: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Another sysrat server the web UI can switch to (`[[hosts]]` section)
+#[derive(Debug, Clone, Deserialize)]
+pub struct HostConfig {
+    pub name: String,
+    /// Base URL of its API, e.g. "https://web2.example.com/sysrat"
+    pub url: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Ordered procedure of actions, file edits and manual checks
+/// (`[[runbooks]]` section)
+#[derive(Debug, Clone, Deserialize)]
+pub struct RunbookConfig {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub steps: Vec<RunbookStepConfig>,
+}
+
+/// Step of a runbook: runs `action` if set, else opens `file` if set, else
+/// is a manual check
+#[derive(Debug, Clone, Deserialize)]
+pub struct RunbookStepConfig {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    /// Action from `[[actions]]` to run
+    #[serde(default)]
+    pub action: Option<String>,
+    /// Parameter values the action runs with
+    #[serde(default)]
+    pub params: HashMap<String, String>,
+    /// Managed file to review or edit
+    #[serde(default)]
+    pub file: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Config {
     #[serde(default)]
     pub settings: Settings,
     #[serde(default)]
+    pub server: ServerSettings,
+    #[serde(default)]
+    pub links: LinkSettings,
+    #[serde(default)]
+    pub dashboard: DashboardSettings,
+    #[serde(default)]
+    pub protection: ProtectionSettings,
+    #[serde(default)]
+    pub branding: BrandingSettings,
+    #[serde(default)]
+    pub network: NetworkSettings,
+    #[serde(default)]
+    pub lint: LintSettings,
+    #[serde(default)]
+    pub hygiene: HygieneSettings,
+    #[serde(default)]
+    pub render: RenderSettings,
+    #[serde(default)]
+    pub secrets: SecretSettings,
+    #[serde(default)]
+    pub git: GitSettings,
+    #[serde(default)]
+    pub notifiers: NotifierSettings,
+    #[serde(default)]
+    pub digest: DigestSettings,
+    #[serde(default)]
+    pub events: EventSettings,
+    #[serde(default)]
+    pub profiles: ProfileSettings,
+    #[serde(default)]
     pub files: Vec<ConfigFile>,
     #[serde(default)]
     pub directories: Vec<ConfigDirectory>,
+    #[serde(default)]
+    pub actions: Vec<ActionConfig>,
+    #[serde(default)]
+    pub runbooks: Vec<RunbookConfig>,
+    #[serde(default)]
+    pub logs: Vec<LogFileConfig>,
+    #[serde(default)]
+    pub hosts: Vec<HostConfig>,
+    /// Role name to the token that unlocks actions requiring it
+    #[serde(default)]
+    pub roles: HashMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sysrat_api_types::QuickActionKind;
+
+    #[test]
+    fn quick_action_runs_a_named_action() {
+        let settings: DashboardSettings = toml::from_str(
+            r#"
+            [[quick_actions]]
+            label = "Backup now"
+            action = { run = { action = "backup" } }
+
+            [[quick_actions]]
+            label = "Restart traefik"
+            action = "restart"
+            target = "traefik"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            settings.quick_actions[0].action,
+            QuickActionKind::Run {
+                action: "backup".to_string()
+            }
+        );
+        assert_eq!(settings.quick_actions[0].target, "");
+        assert_eq!(settings.quick_actions[1].action, QuickActionKind::Restart);
+    }
 }
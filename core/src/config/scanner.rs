@@ -1,10 +1,157 @@
 use super::models::{ConfigDirectory, ConfigFile};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use sysrat_api_types::FileOrder;
 use walkdir::WalkDir;
 
-/// Scan a directory and return all matching files
-pub fn scan_directory(dir_config: &ConfigDirectory) -> Result<Vec<ConfigFile>, String> {
+/// Files found in a directory
+#[derive(Clone)]
+pub struct Scan {
+    pub files: Vec<ConfigFile>,
+    /// More files matched than `max_files` allows, the rest were left out
+    pub truncated: bool,
+    /// Reused from the last scan, no directory it read changed since
+    pub cached: bool,
+}
+
+/// Last scan of a directory with the mtime of every directory it read
+struct CachedScan {
+    config: ConfigDirectory,
+    dir_mtimes: Vec<(PathBuf, SystemTime)>,
+    scan: Scan,
+}
+
+/// Last scans by directory name
+fn cache() -> &'static Mutex<HashMap<String, CachedScan>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, CachedScan>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// `exclude` patterns, split by what they are matched against
+struct Excludes {
+    /// Patterns without a `/`, matched against the file or directory name
+    names: GlobSet,
+    /// Patterns with a `/`, matched against the path relative to the directory
+    paths: GlobSet,
+}
+
+impl Excludes {
+    fn new(patterns: &[String]) -> Result<Self, String> {
+        let mut names = GlobSetBuilder::new();
+        let mut paths = GlobSetBuilder::new();
+        for pattern in patterns {
+            let glob = GlobBuilder::new(pattern.trim_start_matches('/'))
+                // `*` stays within one path component, `**` crosses them
+                .literal_separator(true)
+                .build()
+                .map_err(|e| format!("Invalid exclude pattern {}: {}", pattern, e))?;
+            if pattern.contains('/') {
+                paths.add(glob);
+            } else {
+                names.add(glob);
+            }
+        }
+        let build = |set: GlobSetBuilder| set.build().map_err(|e| e.to_string());
+        Ok(Self {
+            names: build(names)?,
+            paths: build(paths)?,
+        })
+    }
+
+    fn is_excluded(&self, relative: &Path) -> bool {
+        relative
+            .file_name()
+            .is_some_and(|name| self.names.is_match(name))
+            || self.paths.is_match(relative)
+    }
+}
+
+/// Scan all directories concurrently on the blocking pool, results in the
+/// order of `dirs`. Cached scans of directories no longer configured are dropped.
+pub async fn scan_directories(dirs: &[ConfigDirectory]) -> Vec<Result<Scan, String>> {
+    let handles: Vec<_> = dirs
+        .iter()
+        .cloned()
+        .map(|dir_config| tokio::task::spawn_blocking(move || scan_directory(&dir_config)))
+        .collect();
+
+    let mut scans = Vec::with_capacity(handles.len());
+    for handle in handles {
+        scans.push(
+            handle
+                .await
+                .unwrap_or_else(|e| Err(format!("Scan task failed: {}", e))),
+        );
+    }
+
+    cache()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .retain(|name, _| dirs.iter().any(|d| d.name == *name));
+    scans
+}
+
+/// Scan a directory and return all matching files, reusing the last scan when
+/// no directory it read has a new mtime. Adding, removing or renaming an entry
+/// bumps the mtime of its parent directory, so the same files would be found.
+pub fn scan_directory(dir_config: &ConfigDirectory) -> Result<Scan, String> {
+    {
+        let cache = cache().lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(cached) = cache.get(&dir_config.name)
+            && cached.config == *dir_config
+            && is_unchanged(&cached.dir_mtimes)
+        {
+            return Ok(Scan {
+                cached: true,
+                ..cached.scan.clone()
+            });
+        }
+    }
+
+    let (scan, dir_mtimes) = walk(dir_config)?;
+    cache().lock().unwrap_or_else(|e| e.into_inner()).insert(
+        dir_config.name.clone(),
+        CachedScan {
+            config: dir_config.clone(),
+            dir_mtimes,
+            scan: scan.clone(),
+        },
+    );
+    Ok(scan)
+}
+
+/// Put the files of one directory in its `order`; they come in walk order
+pub fn sort_found(files: &mut [ConfigFile], order: FileOrder) {
+    order.sort_by(
+        files,
+        |f| &f.name,
+        |f| f.category.as_deref(),
+        |f| {
+            let modified = std::fs::metadata(&f.path).and_then(|m| m.modified()).ok()?;
+            Some(modified.duration_since(UNIX_EPOCH).ok()?.as_secs())
+        },
+    );
+}
+
+/// Whether every directory still exists with the recorded mtime
+fn is_unchanged(dir_mtimes: &[(PathBuf, SystemTime)]) -> bool {
+    dir_mtimes.iter().all(|(path, mtime)| {
+        std::fs::metadata(path)
+            .and_then(|m| m.modified())
+            .is_ok_and(|modified| modified == *mtime)
+    })
+}
+
+/// Walk a directory for matching files, recording the mtime of every
+/// directory whose entries were read
+fn walk(dir_config: &ConfigDirectory) -> Result<(Scan, Vec<(PathBuf, SystemTime)>), String> {
     let mut found_files = Vec::new();
+    let mut dir_mtimes = Vec::new();
+    let mut truncated = false;
+    let excludes = Excludes::new(&dir_config.exclude)?;
     let base_path = Path::new(&dir_config.path);
 
     // Normalize directory name (strip leading slash for consistent naming)
@@ -26,13 +173,28 @@ pub fn scan_directory(dir_config: &ConfigDirectory) -> Result<Vec<ConfigFile>, S
         ));
     }
 
-    // Walk directory with depth limit
+    // Walk directory with depth limit, in name order so `max_files` keeps the
+    // same files on every scan. Excluded directories are not descended into,
+    // symlink loops are reported as errors by walkdir and skipped.
     for entry in WalkDir::new(&expanded_path)
         .max_depth(dir_config.depth)
-        .follow_links(false)
+        .follow_links(dir_config.follow_symlinks)
+        .sort_by_file_name()
         .into_iter()
+        .filter_entry(|e| {
+            e.depth() == 0
+                || !excludes.is_excluded(e.path().strip_prefix(&expanded_path).unwrap_or(e.path()))
+        })
         .filter_map(|e| e.ok())
     {
+        if entry.file_type().is_dir() && entry.depth() < dir_config.depth {
+            // Without an mtime the scan can not be reused, force the next one
+            let mtime = std::fs::metadata(entry.path())
+                .and_then(|m| m.modified())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            dir_mtimes.push((entry.path().to_path_buf(), mtime));
+        }
+
         if !entry.file_type().is_file() {
             continue;
         }
@@ -70,6 +232,14 @@ pub fn scan_directory(dir_config: &ConfigDirectory) -> Result<Vec<ConfigFile>, S
             format!("{}/{}", dir_name, file_name)
         };
 
+        if dir_config
+            .max_files
+            .is_some_and(|max| found_files.len() >= max)
+        {
+            truncated = true;
+            break;
+        }
+
         found_files.push(ConfigFile {
             path: path.to_string_lossy().to_string(),
             name: display_name,
@@ -77,11 +247,20 @@ pub fn scan_directory(dir_config: &ConfigDirectory) -> Result<Vec<ConfigFile>, S
             readonly: dir_config.readonly,
             category: dir_config.category.clone(),
             theme: None,
+            schema: None,
+            upstream_url: None,
+            upstream_sha256: None,
+            upstream_signature_url: None,
+            write_via: Default::default(),
+            comment: None,
+            vars_file: None,
         });
     }
 
-    // Sort by path for consistent ordering
-    found_files.sort_by(|a, b| a.name.cmp(&b.name));
-
-    Ok(found_files)
+    let scan = Scan {
+        files: found_files,
+        truncated,
+        cached: false,
+    };
+    Ok((scan, dir_mtimes))
 }
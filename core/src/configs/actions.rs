@@ -1,9 +1,20 @@
+use super::audit;
+use super::backup;
+use super::comments;
+use super::encoding;
+use super::history;
+use super::hygiene;
+use super::metadata;
+use super::permissions;
+use super::secrets;
 use super::validation::validate_filename;
-use crate::config::SharedConfig;
-use crate::types::FileInfo;
+use crate::config::{AppConfig, SharedConfig, WriteVia};
+use crate::types::{CommitInfo, FileChangeStats, FileInfo, TextFormat};
+use crate::{events, fsutil};
 use k_lib::config::Cookbook;
 use k_lib::logger;
 use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 const SCOPE: &str = "API";
 const APP_NAME: &str = "sysrat";
@@ -23,19 +34,17 @@ pub async fn list_files(config: &SharedConfig) -> Vec<FileInfo> {
     }
 
     // Attempt to refresh configuration
-    {
-        let mut writer = config.write().await;
-        if let Err(e) = writer.refresh() {
-            if let Some(ref cb) = cookbook {
-                log(cb, "warn", &format!("Failed to refresh config: {}", e));
-            }
-        } else if let Some(ref cb) = cookbook {
-            log(cb, "success", "Config refreshed");
+    if let Err(e) = AppConfig::refresh(config).await {
+        if let Some(ref cb) = cookbook {
+            log(cb, "warn", &format!("Failed to refresh config: {}", e));
         }
+    } else if let Some(ref cb) = cookbook {
+        log(cb, "success", "Config refreshed");
     }
 
     let reader = config.read().await;
-    let files: Vec<FileInfo> = reader
+    let paths: Vec<String> = reader.files().iter().map(|f| f.path.clone()).collect();
+    let mut files: Vec<FileInfo> = reader
         .files()
         .iter()
         .map(|file_cfg| FileInfo {
@@ -44,9 +53,24 @@ pub async fn list_files(config: &SharedConfig) -> Vec<FileInfo> {
             readonly: file_cfg.readonly,
             category: file_cfg.category.clone(),
             theme: file_cfg.theme.clone(),
+            schema: file_cfg.schema.clone(),
+            upstream_url: file_cfg.upstream_url.clone(),
+            meta: None,
+            comment: comments::leader(file_cfg),
+            editing: hygiene::editing_options(
+                &reader.hygiene().rules_for(file_cfg.category.as_deref()),
+            ),
         })
         .collect();
 
+    // Drop lock before async IO
+    drop(reader);
+
+    let owners = metadata::Owners::load().await;
+    for (file, path) in files.iter_mut().zip(&paths) {
+        file.meta = metadata::file_meta(path, &owners).await;
+    }
+
     if let Some(ref cb) = cookbook {
         log(cb, "success", &format!("Returning {} files", files.len()));
     }
@@ -54,8 +78,9 @@ pub async fn list_files(config: &SharedConfig) -> Vec<FileInfo> {
     files
 }
 
-/// Read a managed config file
-pub async fn read_file(filename: &str, config: &SharedConfig) -> io::Result<String> {
+/// Read a managed config file as text normalized for editing, with the
+/// format it is stored in. Binary files fail with `InvalidData`.
+pub async fn read_file(filename: &str, config: &SharedConfig) -> io::Result<(String, TextFormat)> {
     let cookbook = Cookbook::load().ok();
 
     if let Some(ref cb) = cookbook {
@@ -85,11 +110,18 @@ pub async fn read_file(filename: &str, config: &SharedConfig) -> io::Result<Stri
     // Drop lock before async IO
     drop(reader);
 
-    let result = tokio::fs::read_to_string(&path).await;
+    let result = tokio::fs::read(&path).await.and_then(|bytes| {
+        encoding::decode(&bytes).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} is not a text file", filename),
+            )
+        })
+    });
 
     if let Some(ref cb) = cookbook {
         match &result {
-            Ok(content) => log(cb, "success", &format!("Read {} bytes", content.len())),
+            Ok((content, _)) => log(cb, "success", &format!("Read {} bytes", content.len())),
             Err(e) => log(cb, "error", &format!("Read failed: {}", e)),
         }
     }
@@ -97,8 +129,78 @@ pub async fn read_file(filename: &str, config: &SharedConfig) -> io::Result<Stri
     result
 }
 
+/// Read up to `max_len` bytes of a managed file starting at `offset`
+/// (the rest of the file when `max_len` is None), for binary files and
+/// ranged downloads. Returns the bytes and the total file size.
+pub async fn read_file_range(
+    filename: &str,
+    config: &SharedConfig,
+    offset: u64,
+    max_len: Option<u64>,
+    reveal: bool,
+) -> io::Result<(Vec<u8>, u64)> {
+    use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+    let cookbook = Cookbook::load().ok();
+
+    if let Some(ref cb) = cookbook {
+        log(cb, "info", &format!("GET /api/configs/{}/raw", filename));
+    }
+
+    let reader = config.read().await;
+    validate_filename(filename, &reader)?;
+
+    let path = reader
+        .get_file(filename)
+        .map(|f| f.path.clone())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("File not found in config: {}", filename),
+            )
+        })?;
+    let secret_patterns = reader.secret_patterns().to_vec();
+    drop(reader);
+
+    secrets::check_raw(filename, &path, reveal, &secret_patterns).await?;
+
+    let mut file = tokio::fs::File::open(&path).await?;
+    let size = file.metadata().await?.len();
+    file.seek(io::SeekFrom::Start(offset.min(size))).await?;
+
+    let mut data = Vec::new();
+    let result = match max_len {
+        Some(len) => file.take(len).read_to_end(&mut data).await,
+        None => file.read_to_end(&mut data).await,
+    };
+
+    if let Some(ref cb) = cookbook {
+        match &result {
+            Ok(n) => log(
+                cb,
+                "success",
+                &format!("Read {} of {} bytes at offset {}", n, size, offset),
+            ),
+            Err(e) => log(cb, "error", &format!("Read failed: {}", e)),
+        }
+    }
+
+    result.map(|_| (data, size))
+}
+
 /// Write a managed config file (with backup)
-pub async fn write_file(filename: &str, content: &str, config: &SharedConfig) -> io::Result<()> {
+///
+/// `content` is stored in `format`, or in the format the file has on disk
+/// when None. `override_value` is the client's X-Sysrat-Override header,
+/// which unlocks readonly files when sysrat.toml allows it. Returns the
+/// permission bits the file has after the write, which are kept from before it.
+pub async fn write_file(
+    filename: &str,
+    content: &str,
+    format: Option<TextFormat>,
+    config: &SharedConfig,
+    override_value: Option<&str>,
+) -> io::Result<Option<u32>> {
     let cookbook = Cookbook::load().ok();
 
     if let Some(ref cb) = cookbook {
@@ -119,24 +221,76 @@ pub async fn write_file(filename: &str, content: &str, config: &SharedConfig) ->
     })?;
 
     if file_config.readonly {
-        if let Some(ref cb) = cookbook {
-            log(cb, "error", &format!("File is read-only: {}", filename));
+        match override_value {
+            Some(value) if reader.accepts_override(value) => {
+                audit::record(
+                    "warn",
+                    &format!("Readonly override: writing {}", file_config.path),
+                );
+            }
+            Some(_) => {
+                audit::record(
+                    "error",
+                    &format!("Rejected readonly override for {}", file_config.path),
+                );
+                return Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    format!("Readonly override rejected: {}", filename),
+                ));
+            }
+            None => {
+                if let Some(ref cb) = cookbook {
+                    log(cb, "error", &format!("File is read-only: {}", filename));
+                }
+                return Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    format!("File is read-only: {}", filename),
+                ));
+            }
         }
-        return Err(io::Error::new(
-            io::ErrorKind::PermissionDenied,
-            format!("File is read-only: {}", filename),
-        ));
     }
 
     let path = file_config.path.clone();
+    let write_via = file_config.write_via;
+    let sudo_helper = reader.sudo_helper().to_string();
+    let backup_dir = reader.backup_dir().to_string();
+    let git = reader.git().clone();
+    let events = reader.events().clone();
+    let secret_patterns = reader.secret_patterns().to_vec();
     drop(reader); // Release lock before IO operations
 
-    // Create backup
-    let backup_path = format!("{}.backup", path);
+    if secrets::holds_mask(content, &path, &secret_patterns).await {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{} still holds masked secrets, reveal them before saving",
+                filename
+            ),
+        ));
+    }
+
+    let format = match format {
+        Some(format) => format,
+        None => encoding::detect(&path).await,
+    };
+    let content = encoding::encode(content, format)?;
+
+    // A failed backup is logged but does not block the save
+    let backup = backup::create(&backup_dir, &path).await;
     if let Some(ref cb) = cookbook {
-        log(cb, "info", &format!("Creating backup: {}", backup_path));
+        match &backup {
+            Ok(Some(backup_path)) => log(
+                cb,
+                "info",
+                &format!("Created backup: {}", backup_path.display()),
+            ),
+            Ok(None) => {}
+            Err(e) => log(cb, "warn", &format!("Backup of {} failed: {}", path, e)),
+        }
     }
-    let _ = tokio::fs::copy(&path, &backup_path).await;
+
+    // Both write paths keep mode and owner, checked below for the audit log
+    let before = fsutil::ownership(&path).await;
 
     if let Some(ref cb) = cookbook {
         log(
@@ -146,7 +300,16 @@ pub async fn write_file(filename: &str, content: &str, config: &SharedConfig) ->
         );
     }
 
-    let result = tokio::fs::write(&path, content.as_bytes()).await;
+    let result = match write_via {
+        WriteVia::Direct => match fsutil::write_atomic(&path, content.as_slice()).await {
+            Err(e) => Err(permissions::explain(e, &path).await),
+            ok => ok,
+        },
+        WriteVia::Sudo => {
+            audit::record("info", &format!("Writing {} via sudo", path));
+            permissions::write_via_sudo(&sudo_helper, &path, &content).await
+        }
+    };
 
     if let Some(ref cb) = cookbook {
         match &result {
@@ -155,5 +318,144 @@ pub async fn write_file(filename: &str, content: &str, config: &SharedConfig) ->
         }
     }
 
+    let result = match result {
+        Ok(()) => {
+            let after = fsutil::ownership(&path).await;
+            record_ownership(&path, before, after);
+            Ok(after.map(|o| o.mode))
+        }
+        Err(e) => Err(e),
+    };
+
+    let error = result.as_ref().err().map(|e| e.to_string());
+    events::publish(&events, "config.write", filename, error).await;
+
+    // Versioning is best effort: a failed commit must not fail the save
+    if result.is_ok() && git.enabled {
+        let name = filename.to_string();
+        let committed =
+            tokio::task::spawn_blocking(move || history::commit_file(&git, &name, &content)).await;
+
+        if let Some(ref cb) = cookbook {
+            match committed {
+                Ok(Ok(true)) => log(cb, "success", &format!("Committed {}", filename)),
+                Ok(Ok(false)) => log(cb, "info", &format!("No changes to commit: {}", filename)),
+                Ok(Err(e)) => log(cb, "warn", &format!("Git commit failed: {}", e)),
+                Err(e) => log(cb, "warn", &format!("Git commit task failed: {}", e)),
+            }
+        }
+    }
+
+    result
+}
+
+/// Audit entry with the mode and owner a write left, a warning if they moved
+fn record_ownership(
+    path: &str,
+    before: Option<fsutil::Ownership>,
+    after: Option<fsutil::Ownership>,
+) {
+    match (before, after) {
+        (Some(before), Some(after)) if before != after => audit::record(
+            "warn",
+            &format!("Wrote {}, {} changed to {}", path, before, after),
+        ),
+        (Some(_), Some(after)) => audit::record("info", &format!("Wrote {}, kept {}", path, after)),
+        (None, Some(after)) => audit::record("info", &format!("Created {}, {}", path, after)),
+        (_, None) => audit::record("info", &format!("Wrote {}", path)),
+    }
+}
+
+/// Commit history of a managed file from the git versioning repository,
+/// secrets in the diffs masked unless `reveal` is set
+pub async fn file_log(
+    filename: &str,
+    reveal: bool,
+    config: &SharedConfig,
+) -> io::Result<Vec<CommitInfo>> {
+    let cookbook = Cookbook::load().ok();
+
+    if let Some(ref cb) = cookbook {
+        log(cb, "info", &format!("GET /api/configs/{}/log", filename));
+    }
+
+    let reader = config.read().await;
+    validate_filename(filename, &reader)?;
+
+    if reader.get_file(filename).is_none() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("File not found in config: {}", filename),
+        ));
+    }
+
+    let git = reader.git().clone();
+    let secret_patterns = reader.secret_patterns().to_vec();
+    drop(reader);
+
+    if !git.enabled {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Git versioning is disabled",
+        ));
+    }
+
+    let name = filename.to_string();
+    let mut result = tokio::task::spawn_blocking(move || history::file_log(&git, &name))
+        .await
+        .map_err(io::Error::other)?;
+    if let Ok(commits) = &mut result {
+        secrets::mask_diffs(
+            filename,
+            commits.iter_mut().map(|commit| &mut commit.diff),
+            reveal,
+            &secret_patterns,
+        );
+    }
+
+    if let Some(ref cb) = cookbook {
+        match &result {
+            Ok(commits) => log(
+                cb,
+                "success",
+                &format!("Returning {} commits", commits.len()),
+            ),
+            Err(e) => log(cb, "error", &format!("Git log failed: {}", e)),
+        }
+    }
+
     result
 }
+
+/// How often each managed file changed, from the git versioning repository.
+/// `changes` counts the last `days` days; the most changed files come first.
+pub async fn change_stats(days: u32, config: &SharedConfig) -> io::Result<Vec<FileChangeStats>> {
+    let reader = config.read().await;
+    let names: Vec<String> = reader.files().iter().map(|f| f.name.clone()).collect();
+    let git = reader.git().clone();
+    drop(reader);
+
+    if !git.enabled {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Git versioning is disabled",
+        ));
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    let since = now - i64::from(days) * 86_400;
+
+    let mut stats = tokio::task::spawn_blocking(move || history::change_stats(&git, &names, since))
+        .await
+        .map_err(io::Error::other)??;
+    stats.sort_by(|a, b| {
+        b.changes
+            .cmp(&a.changes)
+            .then(b.total_changes.cmp(&a.total_changes))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    Ok(stats)
+}
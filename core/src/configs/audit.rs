@@ -0,0 +1,18 @@
+use k_lib::config::Cookbook;
+use k_lib::logger;
+
+const SCOPE: &str = "AUDIT";
+const APP_NAME: &str = "sysrat";
+
+/// Record a security-relevant event in the log file (and terminal)
+///
+/// Falls back to stderr so audit entries are never silently dropped.
+pub fn record(level: &str, msg: &str) {
+    match Cookbook::load() {
+        Ok(cookbook) => {
+            logger::log_to_terminal(&cookbook, level, SCOPE, msg);
+            let _ = logger::log_to_file(&cookbook, level, SCOPE, msg, Some(APP_NAME));
+        }
+        Err(_) => eprintln!("[{}] {}", SCOPE, msg),
+    }
+}
@@ -0,0 +1,44 @@
+//! Copy of a file taken before it is overwritten. With a backup directory
+//! the copy mirrors the file's path under it (`/etc/nginx/nginx.conf` goes
+//! to `<backup_dir>/etc/nginx/nginx.conf`), so nothing is added next to the
+//! original; an empty `backup_dir` keeps the old `<file>.backup` siblings.
+
+use crate::fsutil::expand_tilde;
+use std::io;
+use std::path::{Component, PathBuf};
+
+/// Used when sysrat.toml sets no `backup_dir`
+pub const DEFAULT_BACKUP_DIR: &str = "~/.local/share/sysrat/backups";
+
+/// Where the backup of `path` is written
+pub fn backup_path(backup_dir: &str, path: &str) -> PathBuf {
+    if backup_dir.is_empty() {
+        return PathBuf::from(format!("{}.backup", path));
+    }
+
+    let original = std::fs::canonicalize(path).unwrap_or_else(|_| PathBuf::from(path));
+    // Only plain components, so neither a root nor ".." leaves the directory
+    let relative: PathBuf = original
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part),
+            _ => None,
+        })
+        .collect();
+    expand_tilde(backup_dir).join(relative)
+}
+
+/// Copy `path` to its backup, creating the mirrored directories. Returns
+/// None when there is no file yet to back up.
+pub async fn create(backup_dir: &str, path: &str) -> io::Result<Option<PathBuf>> {
+    if tokio::fs::metadata(path).await.is_err() {
+        return Ok(None);
+    }
+
+    let target = backup_path(backup_dir, path);
+    if let Some(parent) = target.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::copy(path, &target).await?;
+    Ok(Some(target))
+}
@@ -0,0 +1,36 @@
+//! Comment leader of a managed file, for the editor's comment toggle. The
+//! `comment` setting of a file entry wins, otherwise it is inferred from
+//! the file's name.
+
+use crate::config::ConfigFile;
+use std::path::Path;
+
+/// Leader set for `file` or inferred from its path, None for formats
+/// without line comments (e.g. JSON)
+pub fn leader(file: &ConfigFile) -> Option<String> {
+    if let Some(comment) = &file.comment {
+        return Some(comment.clone()).filter(|comment| !comment.is_empty());
+    }
+    infer(Path::new(&file.path)).map(str::to_string)
+}
+
+fn infer(path: &Path) -> Option<&'static str> {
+    let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("json" | "xml" | "html" | "htm" | "md") => None,
+        Some("ini" | "reg" | "zone" | "asm" | "el" | "lisp") => Some(";"),
+        Some(
+            "js" | "mjs" | "ts" | "jsonc" | "json5" | "rs" | "go" | "c" | "h" | "cpp" | "hpp"
+            | "java" | "kt" | "scala" | "swift" | "php" | "proto" | "kdl",
+        ) => Some("//"),
+        Some("lua" | "sql" | "hs") => Some("--"),
+        Some("vim") => Some("\""),
+        _ if name == ".vimrc" || name == "vimrc" => Some("\""),
+        // Most config formats: conf, toml, yaml, env, shell, systemd units...
+        _ => Some("#"),
+    }
+}
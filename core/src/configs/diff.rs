@@ -0,0 +1,137 @@
+//! Line-based unified diff for previews of incoming file versions
+
+/// Lines of context around each change
+const CONTEXT: usize = 3;
+
+/// Above this many line pairs the changed middle of the files is shown as
+/// removed and re-added instead of running the quadratic LCS
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Op {
+    Same,
+    Removed,
+    Added,
+}
+
+/// Unified diff (`@@` hunks, no file headers) from `old` to `new`,
+/// empty when the contents have the same lines
+pub fn unified(old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Shared head and tail are cheap to find and usually most of the file
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut ops: Vec<(Op, &str)> = old[..prefix].iter().map(|l| (Op::Same, *l)).collect();
+    ops.extend(middle(
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    ));
+    ops.extend(old[old.len() - suffix..].iter().map(|l| (Op::Same, *l)));
+
+    hunks(&ops)
+}
+
+/// Edit script of the differing middle part
+fn middle<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(Op, &'a str)> {
+    if old.len() * new.len() > MAX_LCS_CELLS {
+        return old
+            .iter()
+            .map(|l| (Op::Removed, *l))
+            .chain(new.iter().map(|l| (Op::Added, *l)))
+            .collect();
+    }
+
+    // lcs[i][j]: longest common subsequence of old[i..] and new[j..]
+    let width = new.len() + 1;
+    let mut lcs = vec![0usize; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i * width + j] = if old[i] == new[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut ops = Vec::new();
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            ops.push((Op::Same, old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len()
+            && (j == new.len() || lcs[(i + 1) * width + j] >= lcs[i * width + j + 1])
+        {
+            ops.push((Op::Removed, old[i]));
+            i += 1;
+        } else {
+            ops.push((Op::Added, new[j]));
+            j += 1;
+        }
+    }
+    ops
+}
+
+/// Group the edit script into hunks with `CONTEXT` lines around changes
+fn hunks(ops: &[(Op, &str)]) -> String {
+    let changes: Vec<usize> = (0..ops.len()).filter(|&i| ops[i].0 != Op::Same).collect();
+    let mut out = String::new();
+    let mut k = 0;
+    while k < changes.len() {
+        // Extend the hunk while the next change is within reach of its context
+        let start = changes[k].saturating_sub(CONTEXT);
+        let mut last = changes[k];
+        while k + 1 < changes.len() && changes[k + 1] <= last + 2 * CONTEXT {
+            k += 1;
+            last = changes[k];
+        }
+        let end = (last + CONTEXT + 1).min(ops.len());
+        k += 1;
+
+        // 1-based line numbers where the hunk starts in either file
+        let old_start = ops[..start]
+            .iter()
+            .filter(|(op, _)| *op != Op::Added)
+            .count()
+            + 1;
+        let new_start = ops[..start]
+            .iter()
+            .filter(|(op, _)| *op != Op::Removed)
+            .count()
+            + 1;
+        let old_len = ops[start..end]
+            .iter()
+            .filter(|(op, _)| *op != Op::Added)
+            .count();
+        let new_len = ops[start..end]
+            .iter()
+            .filter(|(op, _)| *op != Op::Removed)
+            .count();
+
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            old_start, old_len, new_start, new_len
+        ));
+        for (op, line) in &ops[start..end] {
+            let marker = match op {
+                Op::Same => ' ',
+                Op::Removed => '-',
+                Op::Added => '+',
+            };
+            out.push(marker);
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
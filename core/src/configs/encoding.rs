@@ -0,0 +1,87 @@
+//! Text files as stored on disk versus as edited. Editing works on UTF-8
+//! with `\n` line endings; the encoding and line endings a file had are
+//! detected on read and applied again on write, so files consumed by
+//! Windows tools keep their BOM and CRLF.
+
+use std::io;
+use sysrat_api_types::{LineEnding, TextEncoding, TextFormat};
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Text of `bytes` normalized for editing, with the format it was stored
+/// in. None for binary data: NUL or control bytes that text does not have.
+pub fn decode(bytes: &[u8]) -> Option<(String, TextFormat)> {
+    let (text, encoding) = if let Some(rest) = bytes.strip_prefix(UTF8_BOM) {
+        (
+            std::str::from_utf8(rest).ok()?.to_string(),
+            TextEncoding::Utf8Bom,
+        )
+    } else if let Ok(text) = std::str::from_utf8(bytes) {
+        (text.to_string(), TextEncoding::Utf8)
+    } else if bytes
+        .iter()
+        .all(|&b| b >= 0x20 || matches!(b, b'\t' | b'\n' | b'\r' | 0x0C))
+    {
+        // Not UTF-8 but no control bytes either: a legacy 8-bit text file
+        (
+            bytes.iter().map(|&b| char::from(b)).collect(),
+            TextEncoding::Latin1,
+        )
+    } else {
+        return None;
+    };
+
+    // The more common style wins for files that mix both
+    let crlf = text.matches("\r\n").count();
+    let line_ending = if crlf > 0 && crlf * 2 >= text.matches('\n').count() {
+        LineEnding::Crlf
+    } else {
+        LineEnding::Lf
+    };
+    let text = if crlf > 0 {
+        text.replace("\r\n", "\n")
+    } else {
+        text
+    };
+    Some((
+        text,
+        TextFormat {
+            encoding,
+            line_ending,
+        },
+    ))
+}
+
+/// Bytes of `text` stored in `format`. Fails for characters the encoding
+/// cannot hold, e.g. "€" in latin-1.
+pub fn encode(text: &str, format: TextFormat) -> io::Result<Vec<u8>> {
+    let text = text.replace("\r\n", "\n");
+    let text = match format.line_ending {
+        LineEnding::Lf => text,
+        LineEnding::Crlf => text.replace('\n', "\r\n"),
+    };
+
+    match format.encoding {
+        TextEncoding::Utf8 => Ok(text.into_bytes()),
+        TextEncoding::Utf8Bom => Ok([UTF8_BOM, text.as_bytes()].concat()),
+        TextEncoding::Latin1 => text
+            .chars()
+            .map(|c| {
+                u8::try_from(u32::from(c)).map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("'{}' cannot be stored in latin-1", c),
+                    )
+                })
+            })
+            .collect(),
+    }
+}
+
+/// Format of the file at `path`, the default for new or unreadable files
+pub async fn detect(path: &str) -> TextFormat {
+    match tokio::fs::read(path).await {
+        Ok(bytes) => decode(&bytes).map(|(_, format)| format).unwrap_or_default(),
+        Err(_) => TextFormat::default(),
+    }
+}
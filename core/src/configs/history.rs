@@ -0,0 +1,242 @@
+// Git-backed versioning of saved config files
+//
+// Every save is committed into `[git].repo_path` under the file's config name,
+// so the repository mirrors the managed files independently of where they live.
+
+use crate::config::GitSettings;
+use crate::types::{CommitInfo, FileChangeStats};
+use std::io;
+
+#[cfg(feature = "git")]
+mod imp {
+    use super::*;
+    use git2::{DiffFormat, DiffOptions, Repository, Signature, Sort};
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
+    /// Upper bound on commits returned by `file_log`
+    const MAX_LOG_ENTRIES: usize = 100;
+
+    /// Upper bound on commits counted by `change_stats`
+    const MAX_STATS_COMMITS: usize = 10_000;
+
+    fn to_io(e: git2::Error) -> io::Error {
+        io::Error::other(e.message().to_string())
+    }
+
+    fn repo_dir(settings: &GitSettings) -> io::Result<PathBuf> {
+        match settings.repo_path.strip_prefix("~/") {
+            Some(rest) => {
+                let home = std::env::var("HOME").map_err(|_| {
+                    io::Error::new(io::ErrorKind::NotFound, "HOME environment variable not set")
+                })?;
+                Ok(PathBuf::from(home).join(rest))
+            }
+            None => Ok(PathBuf::from(&settings.repo_path)),
+        }
+    }
+
+    /// Path of a managed file inside the history repository
+    fn entry_path(filename: &str) -> &str {
+        filename.trim_start_matches(['~', '/'])
+    }
+
+    pub fn commit_file(settings: &GitSettings, filename: &str, content: &[u8]) -> io::Result<bool> {
+        let dir = repo_dir(settings)?;
+        let repo = Repository::open(&dir)
+            .or_else(|_| Repository::init(&dir))
+            .map_err(to_io)?;
+
+        let entry = entry_path(filename);
+        let target = dir.join(entry);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&target, content)?;
+
+        let mut index = repo.index().map_err(to_io)?;
+        index.add_path(Path::new(entry)).map_err(to_io)?;
+        index.write().map_err(to_io)?;
+        let tree_id = index.write_tree().map_err(to_io)?;
+
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        if parent.as_ref().is_some_and(|p| p.tree_id() == tree_id) {
+            return Ok(false);
+        }
+
+        let tree = repo.find_tree(tree_id).map_err(to_io)?;
+        let sig = Signature::now(&settings.author_name, &settings.author_email).map_err(to_io)?;
+        let message = settings.message.replace("{file}", filename);
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, &message, &tree, &parents)
+            .map_err(to_io)?;
+
+        Ok(true)
+    }
+
+    pub fn file_log(settings: &GitSettings, filename: &str) -> io::Result<Vec<CommitInfo>> {
+        let dir = repo_dir(settings)?;
+        // No repository yet means nothing has been saved
+        let Ok(repo) = Repository::open(&dir) else {
+            return Ok(Vec::new());
+        };
+
+        let mut revwalk = repo.revwalk().map_err(to_io)?;
+        if revwalk.push_head().is_err() {
+            return Ok(Vec::new());
+        }
+        revwalk.set_sorting(Sort::TIME).map_err(to_io)?;
+
+        let entry = entry_path(filename);
+        let mut commits = Vec::new();
+
+        for oid in revwalk {
+            if commits.len() >= MAX_LOG_ENTRIES {
+                break;
+            }
+            let commit = repo.find_commit(oid.map_err(to_io)?).map_err(to_io)?;
+            let tree = commit.tree().map_err(to_io)?;
+            let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+
+            let mut opts = DiffOptions::new();
+            opts.pathspec(entry).disable_pathspec_match(true);
+            let diff = repo
+                .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut opts))
+                .map_err(to_io)?;
+            if diff.deltas().len() == 0 {
+                continue;
+            }
+
+            let mut patch = String::new();
+            diff.print(DiffFormat::Patch, |_, _, line| {
+                if matches!(line.origin(), '+' | '-' | ' ') {
+                    patch.push(line.origin());
+                }
+                patch.push_str(&String::from_utf8_lossy(line.content()));
+                true
+            })
+            .map_err(to_io)?;
+
+            let id = commit.id().to_string();
+            commits.push(CommitInfo {
+                short_id: id.chars().take(7).collect(),
+                id,
+                author: commit.author().name().unwrap_or_default().to_string(),
+                time: commit.time().seconds(),
+                message: commit.message().unwrap_or_default().trim_end().to_string(),
+                diff: patch,
+            });
+        }
+
+        Ok(commits)
+    }
+
+    pub fn change_stats(
+        settings: &GitSettings,
+        names: &[String],
+        since: i64,
+    ) -> io::Result<Vec<FileChangeStats>> {
+        let mut stats: Vec<FileChangeStats> = names
+            .iter()
+            .map(|name| FileChangeStats {
+                name: name.clone(),
+                changes: 0,
+                total_changes: 0,
+                last_changed: None,
+            })
+            .collect();
+        let by_entry: HashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (entry_path(name), i))
+            .collect();
+
+        let dir = repo_dir(settings)?;
+        // No repository yet means nothing has been saved
+        let Ok(repo) = Repository::open(&dir) else {
+            return Ok(stats);
+        };
+
+        let mut revwalk = repo.revwalk().map_err(to_io)?;
+        if revwalk.push_head().is_err() {
+            return Ok(stats);
+        }
+        revwalk.set_sorting(Sort::TIME).map_err(to_io)?;
+
+        for oid in revwalk.take(MAX_STATS_COMMITS) {
+            let commit = repo.find_commit(oid.map_err(to_io)?).map_err(to_io)?;
+            let time = commit.time().seconds();
+            let tree = commit.tree().map_err(to_io)?;
+            let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+            let diff = repo
+                .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+                .map_err(to_io)?;
+
+            for delta in diff.deltas() {
+                let Some(&i) = delta
+                    .new_file()
+                    .path()
+                    .and_then(|path| path.to_str())
+                    .and_then(|path| by_entry.get(path))
+                else {
+                    continue;
+                };
+                let entry = &mut stats[i];
+                entry.total_changes += 1;
+                if time >= since {
+                    entry.changes += 1;
+                }
+                entry.last_changed = Some(entry.last_changed.map_or(time, |t| t.max(time)));
+            }
+        }
+
+        Ok(stats)
+    }
+}
+
+#[cfg(not(feature = "git"))]
+mod imp {
+    use super::*;
+
+    fn unsupported() -> io::Error {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            "sysrat was built without the `git` feature",
+        )
+    }
+
+    pub fn commit_file(_: &GitSettings, _: &str, _: &[u8]) -> io::Result<bool> {
+        Err(unsupported())
+    }
+
+    pub fn file_log(_: &GitSettings, _: &str) -> io::Result<Vec<CommitInfo>> {
+        Err(unsupported())
+    }
+
+    pub fn change_stats(_: &GitSettings, _: &[String], _: i64) -> io::Result<Vec<FileChangeStats>> {
+        Err(unsupported())
+    }
+}
+
+/// Commit `content` as `filename` into the history repository
+///
+/// Creates the repository on first use. Returns false if the content did not
+/// change since the last commit.
+pub fn commit_file(settings: &GitSettings, filename: &str, content: &[u8]) -> io::Result<bool> {
+    imp::commit_file(settings, filename, content)
+}
+
+/// Commits touching `filename`, newest first, each with its diff
+pub fn file_log(settings: &GitSettings, filename: &str) -> io::Result<Vec<CommitInfo>> {
+    imp::file_log(settings, filename)
+}
+
+/// Commit counts of the managed files `names`, in the same order: overall
+/// and since the unix timestamp `since`. Files never committed count zero.
+pub fn change_stats(
+    settings: &GitSettings,
+    names: &[String],
+    since: i64,
+) -> io::Result<Vec<FileChangeStats>> {
+    imp::change_stats(settings, names, since)
+}
@@ -0,0 +1,128 @@
+//! Whitespace details some daemons are picky about: a final newline,
+//! trailing whitespace and tabs versus spaces in indentation. Rules are set
+//! per file category in the `[hygiene]` section of sysrat.toml, e.g.
+//! `rules = { "*" = { final_newline = true } }`. Nothing is written here:
+//! the editor shows the fixes as a diff and saves them once accepted.
+
+use super::diff;
+use super::validation::validate_filename;
+use crate::config::{HygieneRules, IndentStyle, SharedConfig};
+use crate::types::{EditingOptions, HygieneResponse};
+use std::io;
+
+/// Columns of a tab when `tab_width` is not set
+const DEFAULT_TAB_WIDTH: usize = 4;
+
+/// Fixes the rules of `filename`'s category make to `content`
+pub async fn check_file(
+    filename: &str,
+    content: &str,
+    config: &SharedConfig,
+) -> io::Result<HygieneResponse> {
+    let reader = config.read().await;
+    validate_filename(filename, &reader)?;
+
+    let file = reader.get_file(filename).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("File not found in config: {}", filename),
+        )
+    })?;
+    let rules = reader.hygiene().rules_for(file.category.as_deref());
+    drop(reader);
+
+    let (fixed, fixes) = apply(content, &rules);
+    Ok(HygieneResponse {
+        diff: diff::unified(content, &fixed),
+        content: fixed,
+        fixes,
+    })
+}
+
+/// `content` with the rules applied, and what changed in words
+pub fn apply(content: &str, rules: &HygieneRules) -> (String, Vec<String>) {
+    let mut fixes = Vec::new();
+    if content.is_empty() {
+        return (String::new(), fixes);
+    }
+
+    let body = content.strip_suffix('\n').unwrap_or(content);
+    let tab_width = rules.tab_width.unwrap_or(DEFAULT_TAB_WIDTH).max(1);
+    let (mut trimmed, mut reindented) = (0, 0);
+    let lines: Vec<String> = body
+        .split('\n')
+        .map(|line| {
+            let mut line = line.to_string();
+            if rules.trim_trailing_whitespace == Some(true) {
+                let end = line.trim_end_matches([' ', '\t']).len();
+                if end < line.len() {
+                    line.truncate(end);
+                    trimmed += 1;
+                }
+            }
+            if let Some(style) = rules.indent {
+                let converted = reindent(&line, style, tab_width);
+                if converted != line {
+                    line = converted;
+                    reindented += 1;
+                }
+            }
+            line
+        })
+        .collect();
+
+    if trimmed > 0 {
+        fixes.push(format!("Trimmed trailing whitespace on {}", count(trimmed)));
+    }
+    if reindented > 0 {
+        let style = match rules.indent {
+            Some(IndentStyle::Tabs) => "tabs",
+            _ => "spaces",
+        };
+        fixes.push(format!("Indented with {} on {}", style, count(reindented)));
+    }
+
+    let mut fixed = lines.join("\n");
+    if content.ends_with('\n') {
+        fixed.push('\n');
+    } else if rules.final_newline == Some(true) {
+        fixed.push('\n');
+        fixes.push("Added a final newline".to_string());
+    }
+    (fixed, fixes)
+}
+
+/// What the editor needs of `rules`, None when they leave it to defaults
+pub fn editing_options(rules: &HygieneRules) -> Option<EditingOptions> {
+    let options = EditingOptions {
+        indent_width: rules.indent_width.filter(|width| *width > 0),
+        indent_tabs: rules.indent.map(|style| style == IndentStyle::Tabs),
+        trim_on_save: rules.trim_on_save == Some(true),
+    };
+    (options != EditingOptions::default()).then_some(options)
+}
+
+/// `line` with its leading whitespace rewritten in `style`, keeping the
+/// column the text starts at
+fn reindent(line: &str, style: IndentStyle, tab_width: usize) -> String {
+    let rest = line.trim_start_matches([' ', '\t']);
+    let indent = &line[..line.len() - rest.len()];
+    let columns = indent.chars().fold(0, |column, c| match c {
+        '\t' => (column / tab_width + 1) * tab_width,
+        _ => column + 1,
+    });
+
+    let indent = match style {
+        IndentStyle::Tabs => "\t".repeat(columns / tab_width) + &" ".repeat(columns % tab_width),
+        IndentStyle::Spaces => " ".repeat(columns),
+    };
+    indent + rest
+}
+
+fn count(lines: usize) -> String {
+    if lines == 1 {
+        "1 line".to_string()
+    } else {
+        format!("{} lines", lines)
+    }
+}
@@ -0,0 +1,233 @@
+//! Rule packs checked against a file's content before it is saved. Packs are
+//! enabled per file category in the `[lint]` section of sysrat.toml, e.g.
+//! `packs = { ssh = ["ssh-hardening"] }`, and report warnings, never errors:
+//! a file that breaks a rule can still be saved. External checkers from
+//! `[[lint.commands]]` report through the same warnings (see `lint_runner`).
+
+use super::lint_runner;
+use super::validation::validate_filename;
+use crate::config::SharedConfig;
+use crate::types::{LintSeverity, LintWarning};
+use std::io;
+
+/// Category key whose packs apply to every file
+pub const ALL_CATEGORIES: &str = "*";
+
+/// What a rule looks for, matched against `key value` directives
+/// (`key = value` and a trailing `;` are accepted too)
+pub enum Check {
+    /// The directive is set to one of these values (case-insensitive)
+    Forbid {
+        key: &'static str,
+        values: &'static [&'static str],
+    },
+    /// The directive does not appear anywhere in the file
+    Require { key: &'static str },
+}
+
+pub struct Rule {
+    pub id: &'static str,
+    pub check: Check,
+    pub message: &'static str,
+}
+
+pub struct RulePack {
+    pub name: &'static str,
+    pub rules: &'static [Rule],
+}
+
+/// Packs shipped with sysrat
+pub const PACKS: &[RulePack] = &[
+    RulePack {
+        name: "ssh-hardening",
+        rules: &[
+            Rule {
+                id: "password-auth",
+                check: Check::Forbid {
+                    key: "PasswordAuthentication",
+                    values: &["yes"],
+                },
+                message: "Password logins are enabled, prefer keys (PasswordAuthentication no)",
+            },
+            Rule {
+                id: "root-login",
+                check: Check::Forbid {
+                    key: "PermitRootLogin",
+                    values: &["yes"],
+                },
+                message: "Root may log in with a password (PermitRootLogin prohibit-password or no)",
+            },
+            Rule {
+                id: "empty-passwords",
+                check: Check::Forbid {
+                    key: "PermitEmptyPasswords",
+                    values: &["yes"],
+                },
+                message: "Accounts without a password may log in",
+            },
+            Rule {
+                id: "x11-forwarding",
+                check: Check::Forbid {
+                    key: "X11Forwarding",
+                    values: &["yes"],
+                },
+                message: "X11 forwarding exposes the client display to the server",
+            },
+        ],
+    },
+    RulePack {
+        name: "nginx-perf",
+        rules: &[
+            Rule {
+                id: "gzip-missing",
+                check: Check::Require { key: "gzip" },
+                message: "gzip is not configured, responses are sent uncompressed",
+            },
+            Rule {
+                id: "gzip-off",
+                check: Check::Forbid {
+                    key: "gzip",
+                    values: &["off"],
+                },
+                message: "gzip is off, responses are sent uncompressed",
+            },
+            Rule {
+                id: "sendfile-off",
+                check: Check::Forbid {
+                    key: "sendfile",
+                    values: &["off"],
+                },
+                message: "sendfile is off, static files are copied through user space",
+            },
+        ],
+    },
+    RulePack {
+        name: "nginx-security",
+        rules: &[
+            Rule {
+                id: "server-tokens",
+                check: Check::Forbid {
+                    key: "server_tokens",
+                    values: &["on"],
+                },
+                message: "The nginx version is sent in headers and error pages (server_tokens off)",
+            },
+            Rule {
+                id: "server-tokens-missing",
+                check: Check::Require {
+                    key: "server_tokens",
+                },
+                message: "server_tokens defaults to on, set it to off to hide the version",
+            },
+        ],
+    },
+];
+
+/// Warnings for `content` of a managed file, from the packs enabled for its
+/// category (and for every file)
+pub async fn lint_file(
+    filename: &str,
+    content: &str,
+    config: &SharedConfig,
+) -> io::Result<Vec<LintWarning>> {
+    let reader = config.read().await;
+    validate_filename(filename, &reader)?;
+
+    let file = reader.get_file(filename).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("File not found in config: {}", filename),
+        )
+    })?;
+    let packs = reader.lint().packs_for(file.category.as_deref());
+    let commands = reader.lint().commands_for(filename, &file.path);
+    drop(reader);
+
+    let mut warnings = lint(content, &packs);
+    for command in &commands {
+        warnings.extend(lint_runner::run(command, filename, content).await);
+    }
+    warnings.sort_by_key(|warning| warning.line);
+    Ok(warnings)
+}
+
+/// Check `content` against the named packs; unknown names are reported as
+/// warnings so a typo in sysrat.toml does not go unnoticed
+pub fn lint(content: &str, packs: &[String]) -> Vec<LintWarning> {
+    let directives: Vec<(usize, &str, &str)> = content
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let (key, value) = directive(line)?;
+            Some((index + 1, key, value))
+        })
+        .collect();
+
+    let mut warnings = Vec::new();
+    for name in packs {
+        let Some(pack) = PACKS.iter().find(|pack| pack.name == name) else {
+            warnings.push(LintWarning {
+                line: None,
+                pack: name.clone(),
+                rule: "unknown-pack".to_string(),
+                severity: LintSeverity::Warning,
+                message: format!("No rule pack named {}", name),
+            });
+            continue;
+        };
+
+        for rule in pack.rules {
+            let warning = |line| LintWarning {
+                line,
+                pack: pack.name.to_string(),
+                rule: rule.id.to_string(),
+                severity: LintSeverity::Warning,
+                message: rule.message.to_string(),
+            };
+            match &rule.check {
+                Check::Forbid { key, values } => {
+                    warnings.extend(
+                        directives
+                            .iter()
+                            .filter(|(_, k, v)| {
+                                k.eq_ignore_ascii_case(key)
+                                    && values.iter().any(|value| v.eq_ignore_ascii_case(value))
+                            })
+                            .map(|(line, _, _)| warning(Some(*line))),
+                    );
+                }
+                Check::Require { key } => {
+                    if !directives
+                        .iter()
+                        .any(|(_, k, _)| k.eq_ignore_ascii_case(key))
+                    {
+                        warnings.push(warning(None));
+                    }
+                }
+            }
+        }
+    }
+
+    warnings.sort_by_key(|warning| warning.line);
+    warnings
+}
+
+/// Key and first value word of a `key value`, `key = value` or `key value;`
+/// line; comments and blank lines have none
+fn directive(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with(['#', ';']) {
+        return None;
+    }
+    let end = line
+        .find(|c: char| c.is_whitespace() || c == '=')
+        .unwrap_or(line.len());
+    let (key, rest) = line.split_at(end);
+    let value = rest
+        .trim_start_matches(|c: char| c.is_whitespace() || c == '=')
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches(';');
+    Some((key.trim_end_matches(';'), value))
+}
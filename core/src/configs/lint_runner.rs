@@ -0,0 +1,190 @@
+//! External checkers (`[[lint.commands]]` in sysrat.toml) run against the
+//! editor content, written to a temporary file first. Their output is read
+//! in the common formats: `path:line:col: message` (gcc, shellcheck -f gcc),
+//! `... in path:line` (nginx -t) and `path line N: message` (sshd -t).
+
+use crate::config::LintCommand;
+use crate::fsutil;
+use crate::types::{LintSeverity, LintWarning};
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::process::Command;
+
+/// Placeholder in `args` for the temporary copy of the content
+const FILE_PLACEHOLDER: &str = "{file}";
+
+/// Warnings of `command` for `content` of the managed file `filename`; a
+/// checker that cannot run is reported as a warning too
+pub async fn run(command: &LintCommand, filename: &str, content: &str) -> Vec<LintWarning> {
+    let program = Path::new(&command.command)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(&command.command)
+        .to_string();
+    let warning = |line, rule: &str, message: String| LintWarning {
+        line,
+        pack: program.clone(),
+        rule: rule.to_string(),
+        severity: severity_of(rule),
+        message,
+    };
+
+    let path = temp_path(filename);
+    if let Err(e) = fsutil::write_private(&path, content.as_bytes()).await {
+        return vec![warning(
+            None,
+            "error",
+            format!("Writing a temporary copy failed: {}", e),
+        )];
+    }
+    let output = execute(command, &path).await;
+    let _ = tokio::fs::remove_file(&path).await;
+
+    let output = match output {
+        Ok(output) => output,
+        Err(e) => return vec![warning(None, "error", e)],
+    };
+    let path = path.to_string_lossy();
+    let text = format!(
+        "{}\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let formats = Formats::new(&path);
+    let mut warnings: Vec<LintWarning> = text
+        .lines()
+        .filter_map(|line| formats.as_ref()?.parse(line))
+        .map(|(line, rule, message)| warning(line, &rule, message.replace(&*path, filename)))
+        .collect();
+    // A failure without a recognized location still has to show
+    if warnings.is_empty() && !output.status.success() {
+        let message = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .replace(&*path, filename);
+        let message = if message.is_empty() {
+            format!("{} failed with {}", program, output.status)
+        } else {
+            message
+        };
+        warnings.push(warning(None, "error", message));
+    }
+    warnings
+}
+
+/// Temporary file named like the original, some checkers go by the name
+fn temp_path(filename: &str) -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let base = Path::new(filename)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("file");
+    std::env::temp_dir().join(format!(
+        "sysrat-lint-{}-{}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed),
+        base
+    ))
+}
+
+async fn execute(command: &LintCommand, path: &Path) -> Result<std::process::Output, String> {
+    let file = path.to_string_lossy();
+    let mut args: Vec<String> = command
+        .args
+        .iter()
+        .map(|arg| arg.replace(FILE_PLACEHOLDER, &file))
+        .collect();
+    if !command
+        .args
+        .iter()
+        .any(|arg| arg.contains(FILE_PLACEHOLDER))
+    {
+        args.push(file.to_string());
+    }
+
+    let timeout = Duration::from_secs(command.timeout_secs);
+    tokio::time::timeout(
+        timeout,
+        Command::new(&command.command)
+            .args(&args)
+            .stdin(Stdio::null())
+            .kill_on_drop(true)
+            .output(),
+    )
+    .await
+    .map_err(|_| format!("{} timed out after {}s", command.command, timeout.as_secs()))?
+    .map_err(|e| format!("Failed to start {}: {}", command.command, e))
+}
+
+/// Output formats that locate a message in the checked file
+struct Formats {
+    gcc: Regex,
+    nginx: Regex,
+    sshd: Regex,
+}
+
+impl Formats {
+    fn new(path: &str) -> Option<Self> {
+        let path = regex::escape(path);
+        Some(Self {
+            gcc: Regex::new(&format!(r"{}:(\d+):(?:\d+:)?\s*(.*)$", path)).ok()?,
+            nginx: Regex::new(&format!(r"^(.*?)\s+in\s+{}:(\d+)", path)).ok()?,
+            sshd: Regex::new(&format!(r"{}\s+line\s+(\d+):\s*(.*)$", path)).ok()?,
+        })
+    }
+
+    /// Line, severity and message of an output line naming the checked file
+    fn parse(&self, line: &str) -> Option<(Option<usize>, String, String)> {
+        let (number, message) = if let Some(caps) = self.gcc.captures(line) {
+            (caps[1].to_string(), caps[2].to_string())
+        } else if let Some(caps) = self.nginx.captures(line) {
+            (caps[2].to_string(), caps[1].to_string())
+        } else if let Some(caps) = self.sshd.captures(line) {
+            (caps[1].to_string(), caps[2].to_string())
+        } else {
+            return None;
+        };
+        let (rule, message) = severity(message.trim());
+        Some((number.parse().ok(), rule, message))
+    }
+}
+
+/// Split a leading `warning:` or `[emerg]` off the message, "error" if none
+fn severity(message: &str) -> (String, String) {
+    for prefix in ["error", "warning", "note", "info"] {
+        if let Some(rest) = message
+            .strip_prefix(prefix)
+            .and_then(|r| r.strip_prefix(':'))
+        {
+            return (prefix.to_string(), rest.trim().to_string());
+        }
+    }
+    let bracketed = message
+        .find('[')
+        .zip(message.find(']'))
+        .filter(|(open, close)| open < close);
+    if let Some((open, close)) = bracketed
+        && message[..open].trim_end().ends_with(':')
+    {
+        let level = message[open + 1..close].to_string();
+        let rest = message[close + 1..].trim().to_string();
+        return (level, rest);
+    }
+    ("error".to_string(), message.to_string())
+}
+
+/// Checker levels that mean the file would be rejected; anything milder is a
+/// warning
+fn severity_of(level: &str) -> LintSeverity {
+    match level.to_ascii_lowercase().as_str() {
+        "error" | "fatal" | "emerg" | "alert" | "crit" => LintSeverity::Error,
+        _ => LintSeverity::Warning,
+    }
+}
@@ -0,0 +1,64 @@
+// Filesystem metadata shown next to each file in the list
+
+use crate::types::FileMeta;
+use std::collections::HashMap;
+use std::time::UNIX_EPOCH;
+
+/// User names by uid, read from /etc/passwd
+pub struct Owners(HashMap<u32, String>);
+
+impl Owners {
+    /// Empty when /etc/passwd is missing, owners are then shown by uid
+    pub async fn load() -> Self {
+        let passwd = tokio::fs::read_to_string("/etc/passwd")
+            .await
+            .unwrap_or_default();
+        Self(
+            passwd
+                .lines()
+                .filter_map(|line| {
+                    let mut fields = line.split(':');
+                    let name = fields.next()?;
+                    let uid = fields.nth(1)?.parse().ok()?;
+                    Some((uid, name.to_string()))
+                })
+                .collect(),
+        )
+    }
+
+    pub(super) fn name(&self, uid: u32) -> String {
+        self.0.get(&uid).cloned().unwrap_or_else(|| uid.to_string())
+    }
+}
+
+/// Size, mtime and ownership of a file, None when it cannot be stat'ed
+pub async fn file_meta(path: &str, owners: &Owners) -> Option<FileMeta> {
+    let metadata = tokio::fs::metadata(path).await.ok()?;
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    #[cfg(unix)]
+    let (owner, mode) = {
+        use std::os::unix::fs::MetadataExt;
+        (
+            Some(owners.name(metadata.uid())),
+            Some(metadata.mode() & 0o7777),
+        )
+    };
+    #[cfg(not(unix))]
+    let (owner, mode) = {
+        let _ = owners;
+        (None, None)
+    };
+
+    Some(FileMeta {
+        size: metadata.len(),
+        modified,
+        owner,
+        mode,
+    })
+}
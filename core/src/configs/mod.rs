@@ -1,2 +1,16 @@
 pub mod actions;
+pub mod audit;
+pub mod backup;
+pub mod comments;
+pub mod diff;
+pub mod encoding;
+pub mod history;
+pub mod hygiene;
+pub mod lint;
+pub mod lint_runner;
+pub mod metadata;
+pub mod permissions;
+pub mod render;
+pub mod secrets;
+pub mod upstream;
 pub mod validation;
@@ -0,0 +1,131 @@
+//! Writes the server process is not allowed to make: explain them with the
+//! file's owner and mode, and optionally go through sudo (`write_via = "sudo"`).
+
+use super::metadata::Owners;
+use crate::types::WritePermissionError;
+use std::fmt;
+use std::io;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+
+/// Helper run through `sudo -n` when no `sudo_helper` is configured; it
+/// writes its stdin to the file named as its argument
+pub const DEFAULT_SUDO_HELPER: &str = "tee";
+
+/// io::Error payload of a denied write, the server returns it as the body
+#[derive(Debug)]
+pub struct WriteDenied(pub WritePermissionError);
+
+impl fmt::Display for WriteDenied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Permission denied: {} ({})", self.0.path, self.0.hint)
+    }
+}
+
+impl std::error::Error for WriteDenied {}
+
+/// The explanation attached to `e`, if it is a denied write
+pub fn write_denied(e: &io::Error) -> Option<&WritePermissionError> {
+    e.get_ref()?
+        .downcast_ref::<WriteDenied>()
+        .map(|denied| &denied.0)
+}
+
+/// Write `content` to `path` as root through `sudo -n <helper> <path>`
+///
+/// `-n` never prompts, so a missing sudoers rule fails right away.
+pub async fn write_via_sudo(helper: &str, path: &str, content: &[u8]) -> io::Result<()> {
+    let mut child = tokio::process::Command::new("sudo")
+        .arg("-n")
+        .arg(helper)
+        .arg(path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // sudo exits before reading stdin when it refuses, so a broken pipe here
+    // is decided by the exit status below
+    let mut written = Ok(());
+    if let Some(mut stdin) = child.stdin.take() {
+        written = stdin.write_all(content).await;
+        // Closing stdin lets the helper finish
+        drop(stdin);
+    }
+
+    let output = child.wait_with_output().await?;
+    if output.status.success() {
+        return written;
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    let owners = Owners::load().await;
+    let mut details = describe(path, &owners).await;
+    details.hint = format!(
+        "sudo refused to run {} ({}), allow it for this file in sudoers with NOPASSWD",
+        helper, stderr
+    );
+    Err(denied(details))
+}
+
+/// Replace an OS permission error on `path` with one that says why and
+/// what would help; other errors pass through unchanged
+pub async fn explain(e: io::Error, path: &str) -> io::Error {
+    // Readonly rejections are PermissionDenied too, but carry no OS error
+    if e.kind() != io::ErrorKind::PermissionDenied || e.raw_os_error().is_none() {
+        return e;
+    }
+
+    let owners = Owners::load().await;
+    let mut details = describe(path, &owners).await;
+    details.hint = hint(&details);
+    denied(details)
+}
+
+fn denied(details: WritePermissionError) -> io::Error {
+    io::Error::new(io::ErrorKind::PermissionDenied, WriteDenied(details))
+}
+
+/// Owner and mode of the file and the user the server runs as
+async fn describe(path: &str, owners: &Owners) -> WritePermissionError {
+    let meta = super::metadata::file_meta(path, owners).await;
+    WritePermissionError {
+        path: path.to_string(),
+        owner: meta.as_ref().and_then(|m| m.owner.clone()),
+        mode: meta.as_ref().and_then(|m| m.mode),
+        server_user: server_uid().map(|uid| owners.name(uid)),
+        privileges_help: server_uid() != Some(0),
+        hint: String::new(),
+    }
+}
+
+/// uid of this process, the owner of its /proc entry
+fn server_uid() -> Option<u32> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        std::fs::metadata("/proc/self").ok().map(|m| m.uid())
+    }
+    #[cfg(not(unix))]
+    {
+        None
+    }
+}
+
+fn hint(details: &WritePermissionError) -> String {
+    if !details.privileges_help {
+        return "even root may not write it, check for an immutable attribute (lsattr)".to_string();
+    }
+    let own_file = details.owner.is_some() && details.owner == details.server_user;
+    match details.mode {
+        Some(mode) if own_file && mode & 0o200 == 0 => {
+            "the file is not writable by its owner, chmod u+w would fix it".to_string()
+        }
+        _ if own_file => "a parent directory or security policy blocks the write".to_string(),
+        _ => format!(
+            "owned by {}, run the server as that user, give it group write access, \
+             grant CAP_DAC_OVERRIDE or set write_via = \"sudo\" for this file",
+            details.owner.as_deref().unwrap_or("another user")
+        ),
+    }
+}
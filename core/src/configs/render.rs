@@ -0,0 +1,107 @@
+//! Rendered view of a managed file: `${NAME}` and `${NAME:-default}`
+//! references substituted from a KEY=VALUE vars file and, if enabled in
+//! `[render]`, the server's environment. Nothing is written back, and
+//! `[secrets]` are masked in the result.
+
+use super::{actions, secrets};
+use crate::config::SharedConfig;
+use crate::fsutil::expand_tilde;
+use crate::types::RenderedResponse;
+use std::collections::{BTreeSet, HashMap};
+use std::io;
+
+/// Render `filename` as saved on disk
+pub async fn render_file(filename: &str, config: &SharedConfig) -> io::Result<RenderedResponse> {
+    let (content, _) = actions::read_file(filename, config).await?;
+    let (vars_file, env) = {
+        let reader = config.read().await;
+        let own = reader.get_file(filename).and_then(|f| f.vars_file.clone());
+        (
+            own.or_else(|| reader.render().vars_file.clone()),
+            reader.render().env,
+        )
+    };
+
+    let vars = match &vars_file {
+        Some(path) => {
+            let text = tokio::fs::read_to_string(expand_tilde(path))
+                .await
+                .map_err(|e| io::Error::new(e.kind(), format!("Vars file {}: {}", path, e)))?;
+            parse_vars(&text)
+        }
+        None => HashMap::new(),
+    };
+
+    let (content, unresolved) = substitute(&content, |name| {
+        vars.get(name)
+            .cloned()
+            .or_else(|| if env { std::env::var(name).ok() } else { None })
+    });
+    // Substituted values are masked like the file's own secrets, always
+    let content =
+        secrets::redact(&content, config.read().await.secret_patterns()).unwrap_or(content);
+    Ok(RenderedResponse {
+        content,
+        unresolved,
+        vars_file,
+    })
+}
+
+/// Parse KEY=VALUE lines, skipping blanks and `#` comments; an `export `
+/// prefix and matching quotes around the value are dropped
+pub fn parse_vars(text: &str) -> HashMap<String, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line.split_once('=')?;
+            let value = value.trim();
+            let value = ['"', '\'']
+                .iter()
+                .find_map(|q| value.strip_prefix(*q)?.strip_suffix(*q))
+                .unwrap_or(value);
+            Some((key.trim().to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Replace `${NAME}` and `${NAME:-default}` using `lookup`. References
+/// without a value or default stay as written and are returned by name.
+pub fn substitute(text: &str, lookup: impl Fn(&str) -> Option<String>) -> (String, Vec<String>) {
+    let mut out = String::with_capacity(text.len());
+    let mut unresolved = BTreeSet::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find('}') else {
+            out.push_str(&rest[start..]);
+            rest = "";
+            break;
+        };
+        let inner = &after[..end];
+        let (name, default) = match inner.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (inner, None),
+        };
+        let valid = !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        let value = valid
+            .then(|| lookup(name).or_else(|| default.map(str::to_string)))
+            .flatten();
+        match value {
+            Some(value) => out.push_str(&value),
+            None => {
+                if valid {
+                    unresolved.insert(name.to_string());
+                }
+                out.push_str(&rest[start..start + 3 + end]);
+            }
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+
+    (out, unresolved.into_iter().collect())
+}
@@ -0,0 +1,215 @@
+//! Masking of secret values (`[secrets] patterns` in sysrat.toml) in read
+//! responses. A masked value cannot be saved back by accident: content
+//! that still has a line as the masked read showed it is refused.
+
+use super::{audit, encoding};
+use crate::config::SharedConfig;
+use regex::Regex;
+use std::collections::HashSet;
+use std::io;
+
+/// Replaces each secret; of fixed length so it does not hint at the value
+pub const MASK: &str = sysrat_api_types::SECRET_MASK;
+
+/// `text` with every secret replaced by `MASK`, None if nothing matched
+pub fn redact(text: &str, patterns: &[Regex]) -> Option<String> {
+    let mut ranges: Vec<(usize, usize)> = patterns
+        .iter()
+        .flat_map(|pattern| pattern.captures_iter(text))
+        .filter_map(|caps| caps.get(1).or_else(|| caps.get(0)))
+        .filter(|m| !m.is_empty())
+        .map(|m| (m.start(), m.end()))
+        .collect();
+    if ranges.is_empty() {
+        return None;
+    }
+    ranges.sort_unstable();
+
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (start, end) in ranges {
+        // Overlapping matches of different patterns are masked once
+        if start < last {
+            last = last.max(end);
+            continue;
+        }
+        out.push_str(&text[last..start]);
+        out.push_str(MASK);
+        last = end;
+    }
+    out.push_str(&text[last..]);
+    Some(out)
+}
+
+/// `diff` with the secrets on its lines masked, None if nothing matched.
+/// Lines are matched without their `+`, `-` or ` ` marker, as in the file
+pub fn redact_diff(diff: &str, patterns: &[Regex]) -> Option<String> {
+    let mut masked = false;
+    let mut out = String::with_capacity(diff.len());
+    for line in diff.split_inclusive('\n') {
+        let (marker, rest) = match line.as_bytes().first() {
+            Some(b'+' | b'-' | b' ') => line.split_at(1),
+            _ => ("", line),
+        };
+        match redact(rest, patterns) {
+            Some(redacted) => {
+                out.push_str(marker);
+                out.push_str(&redacted);
+                masked = true;
+            }
+            None => out.push_str(line),
+        }
+    }
+    masked.then_some(out)
+}
+
+/// Mask the secrets in `diffs` of a file in place; an explicit `reveal`
+/// keeps them and is audited
+pub fn mask_diffs<'a>(
+    filename: &str,
+    diffs: impl IntoIterator<Item = &'a mut String>,
+    reveal: bool,
+    patterns: &[Regex],
+) {
+    let mut found = false;
+    for diff in diffs {
+        if let Some(masked) = redact_diff(diff, patterns) {
+            found = true;
+            if !reveal {
+                *diff = masked;
+            }
+        }
+    }
+    if found && reveal {
+        audit::record("warn", &format!("Revealed secrets of {} (diff)", filename));
+    }
+}
+
+/// sysrat.toml with the `[roles]` tokens and `readonly_override_token`
+/// masked, None if it sets neither
+pub fn mask_settings(text: &str) -> Option<String> {
+    let mut table = String::new();
+    let mut masked = false;
+    let mut out = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if let Some(header) = trimmed.strip_prefix('[') {
+            table = header
+                .trim_start_matches('[')
+                .split(']')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+        }
+        let key = match line.split_once('=') {
+            Some((key, _)) if !trimmed.starts_with(['#', '[']) => key,
+            _ => {
+                out.push_str(line);
+                continue;
+            }
+        };
+        let name = key.trim();
+        let secret = match table.as_str() {
+            "" => {
+                name == "settings.readonly_override_token"
+                    || name == "roles"
+                    || name.starts_with("roles.")
+            }
+            "settings" => name == "readonly_override_token",
+            "roles" => true,
+            _ => false,
+        };
+        if !secret {
+            out.push_str(line);
+            continue;
+        }
+        out.push_str(key);
+        out.push_str("= \"");
+        out.push_str(MASK);
+        out.push('"');
+        if line.ends_with('\n') {
+            out.push('\n');
+        }
+        masked = true;
+    }
+    masked.then_some(out)
+}
+
+/// Whether `text` still has a line as a masked read of the file at `path`
+/// shows it, i.e. a secret would be overwritten by the mask
+pub async fn holds_mask(text: &str, path: &str, patterns: &[Regex]) -> bool {
+    if !text.contains(MASK) {
+        return false;
+    }
+    let Some((current, _)) = tokio::fs::read(path)
+        .await
+        .ok()
+        .and_then(|bytes| encoding::decode(&bytes))
+    else {
+        return false;
+    };
+    keeps_mask(text, &current, patterns)
+}
+
+/// Whether `text` still has a line as a masked read of `current` shows it
+pub fn keeps_mask(text: &str, current: &str, patterns: &[Regex]) -> bool {
+    if !text.contains(MASK) {
+        return false;
+    }
+    let Some(masked) = redact(current, patterns) else {
+        return false;
+    };
+    let masked_lines: HashSet<&str> = masked.lines().filter(|l| l.contains(MASK)).collect();
+    text.lines().any(|line| masked_lines.contains(line))
+}
+
+/// Raw bytes cannot be masked, so a file holding secrets is only served
+/// with an explicit `reveal`, which is audited
+pub async fn check_raw(
+    filename: &str,
+    path: &str,
+    reveal: bool,
+    patterns: &[Regex],
+) -> io::Result<()> {
+    if patterns.is_empty() {
+        return Ok(());
+    }
+    let bytes = tokio::fs::read(path).await?;
+    let text = match encoding::decode(&bytes) {
+        Some((text, _)) => text,
+        None => String::from_utf8_lossy(&bytes).into_owned(),
+    };
+    if redact(&text, patterns).is_none() {
+        return Ok(());
+    }
+    if reveal {
+        audit::record("warn", &format!("Revealed secrets of {} (raw)", filename));
+        return Ok(());
+    }
+    Err(io::Error::new(
+        io::ErrorKind::PermissionDenied,
+        format!(
+            "{} holds secrets, pass reveal=true to read it raw",
+            filename
+        ),
+    ))
+}
+
+/// Content for a read response and whether it was masked; an explicit
+/// `reveal` skips the masking and is audited
+pub async fn for_read(
+    filename: &str,
+    content: String,
+    reveal: bool,
+    config: &SharedConfig,
+) -> (String, bool) {
+    if reveal {
+        audit::record("warn", &format!("Revealed secrets of {}", filename));
+        return (content, false);
+    }
+    match redact(&content, config.read().await.secret_patterns()) {
+        Some(masked) => (masked, true),
+        None => (content, false),
+    }
+}
@@ -0,0 +1,241 @@
+//! Refresh managed files from their upstream source (`upstream_url` in
+//! sysrat.toml). A check fetches and verifies the upstream content and diffs
+//! it against the local file; an apply writes exactly the reviewed version.
+
+use super::{actions, audit, diff, encoding, secrets};
+use crate::config::SharedConfig;
+use crate::fsutil;
+use crate::jobs;
+use crate::types::{TextFormat, UpstreamCheckResponse};
+use sha2::{Digest, Sha256};
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Upstream files and signatures larger than this are refused
+const MAX_DOWNLOAD_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Upstream settings of a managed file
+struct Source {
+    url: String,
+    sha256: Option<String>,
+    signature_url: Option<String>,
+}
+
+/// Fetched upstream content with its verification results
+struct Fetched {
+    content: String,
+    sha256: String,
+    checksum_ok: Option<bool>,
+    signature_ok: Option<bool>,
+}
+
+async fn source(filename: &str, config: &SharedConfig) -> io::Result<Source> {
+    let reader = config.read().await;
+    let file = reader.get_file(filename).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("File not found in config: {}", filename),
+        )
+    })?;
+    let url = file.upstream_url.clone().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("No upstream_url configured for {}", filename),
+        )
+    })?;
+    Ok(Source {
+        url,
+        sha256: file.upstream_sha256.as_ref().map(|s| s.to_lowercase()),
+        signature_url: file.upstream_signature_url.clone(),
+    })
+}
+
+/// Download `url` into memory, at most `MAX_DOWNLOAD_BYTES`
+async fn download(url: &str) -> io::Result<Vec<u8>> {
+    let url = url.to_string();
+    tokio::task::spawn_blocking(move || {
+        let response = ureq::get(&url)
+            .call()
+            .map_err(|e| io::Error::other(format!("Fetching {} failed: {}", url, e)))?;
+        let mut bytes = Vec::new();
+        response
+            .into_reader()
+            .take(MAX_DOWNLOAD_BYTES + 1)
+            .read_to_end(&mut bytes)?;
+        if bytes.len() as u64 > MAX_DOWNLOAD_BYTES {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} is larger than {} bytes", url, MAX_DOWNLOAD_BYTES),
+            ));
+        }
+        Ok(bytes)
+    })
+    .await
+    .map_err(io::Error::other)?
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Verify a detached signature with the server's gpg keyring
+async fn verify_signature(content: &[u8], signature: &[u8]) -> io::Result<bool> {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let stem = format!(
+        "sysrat-upstream-{}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    );
+    let dir = std::env::temp_dir();
+    let data_path: PathBuf = dir.join(format!("{}.data", stem));
+    let sig_path: PathBuf = dir.join(format!("{}.sig", stem));
+
+    fsutil::write_private(&data_path, content).await?;
+    if let Err(e) = fsutil::write_private(&sig_path, signature).await {
+        let _ = tokio::fs::remove_file(&data_path).await;
+        return Err(e);
+    }
+
+    let status = tokio::process::Command::new("gpg")
+        .arg("--batch")
+        .arg("--verify")
+        .arg(&sig_path)
+        .arg(&data_path)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .await;
+
+    let _ = tokio::fs::remove_file(&data_path).await;
+    let _ = tokio::fs::remove_file(&sig_path).await;
+
+    Ok(status?.success())
+}
+
+async fn fetch_verified(source: &Source) -> io::Result<Fetched> {
+    let bytes = download(&source.url).await?;
+    let sha256 = sha256_hex(&bytes);
+    let checksum_ok = source.sha256.as_ref().map(|expected| *expected == sha256);
+
+    let signature_ok = match &source.signature_url {
+        Some(url) => {
+            let signature = download(url).await?;
+            Some(verify_signature(&bytes, &signature).await?)
+        }
+        None => None,
+    };
+
+    let content = String::from_utf8(bytes).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} is not UTF-8 text", source.url),
+        )
+    })?;
+
+    Ok(Fetched {
+        content,
+        sha256,
+        checksum_ok,
+        signature_ok,
+    })
+}
+
+/// Fetch the upstream version of a file, verify it and diff it against the
+/// local content, secrets masked unless `reveal` is set. Runs as an
+/// `upstream-check` job.
+pub async fn check(
+    filename: &str,
+    reveal: bool,
+    config: &SharedConfig,
+) -> io::Result<UpstreamCheckResponse> {
+    let source = source(filename, config).await?;
+    let job = jobs::start("upstream-check", filename);
+
+    let result = async {
+        let fetched = fetch_verified(&source).await?;
+        // Compared as edited text, a changed line ending or encoding still
+        // counts as a change
+        let (local, local_format) = actions::read_file(filename, config).await?;
+        let (upstream, upstream_format) = encoding::decode(fetched.content.as_bytes())
+            .unwrap_or_else(|| (fetched.content.clone(), TextFormat::default()));
+        let mut diff = diff::unified(&local, &upstream);
+        let secret_patterns = config.read().await.secret_patterns().to_vec();
+        secrets::mask_diffs(filename, [&mut diff], reveal, &secret_patterns);
+        Ok::<_, io::Error>(UpstreamCheckResponse {
+            url: source.url.clone(),
+            sha256: fetched.sha256,
+            expected_sha256: source.sha256.clone(),
+            checksum_ok: fetched.checksum_ok,
+            signature_ok: fetched.signature_ok,
+            changed: local != upstream || local_format != upstream_format,
+            diff,
+            job_id: job,
+        })
+    }
+    .await;
+
+    jobs::finish(
+        job,
+        match &result {
+            Ok(check) if !check.changed => Ok("Up to date".to_string()),
+            Ok(check) => Ok(format!("Upstream differs ({})", short(&check.sha256))),
+            Err(e) => Err(e.to_string()),
+        },
+    );
+    result
+}
+
+/// Write the upstream version reviewed with `check` to the managed file.
+/// Refused when verification fails or upstream changed since the review.
+/// Runs as an `upstream-apply` job.
+pub async fn apply(filename: &str, reviewed_sha256: &str, config: &SharedConfig) -> io::Result<()> {
+    let source = source(filename, config).await?;
+    let job = jobs::start("upstream-apply", filename);
+
+    let result = async {
+        let fetched = fetch_verified(&source).await?;
+        if fetched.checksum_ok == Some(false) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Checksum mismatch: got {}", fetched.sha256),
+            ));
+        }
+        if fetched.signature_ok == Some(false) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Signature verification failed",
+            ));
+        }
+        if !fetched.sha256.eq_ignore_ascii_case(reviewed_sha256) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Upstream changed since the review, check again",
+            ));
+        }
+        // Stored as upstream has it, not in the local file's format
+        let format = encoding::decode(fetched.content.as_bytes()).map(|(_, format)| format);
+        actions::write_file(filename, &fetched.content, format, config, None).await?;
+        Ok(fetched.sha256)
+    }
+    .await;
+
+    match &result {
+        Ok(sha256) => {
+            audit::record(
+                "success",
+                &format!("Applied upstream {} to {}", short(sha256), filename),
+            );
+            jobs::finish(job, Ok(format!("Applied {}", short(sha256))));
+        }
+        Err(e) => jobs::finish(job, Err(e.to_string())),
+    }
+    result.map(|_| ())
+}
+
+fn short(sha256: &str) -> &str {
+    &sha256[..sha256.len().min(12)]
+}
@@ -0,0 +1,222 @@
+//! Change events for external consumers from `[events]` in sysrat.toml
+//!
+//! Every config write and container action is appended to an NDJSON file
+//! and synced before the request answers. With a webhook set, a forwarder
+//! POSTs the lines in order and remembers how far it got in `<file>.offset`,
+//! only after a 2xx. A crash between the POST and the offset write sends the
+//! line again, so delivery is at-least-once and consumers dedupe by `id`.
+//! Once everything is forwarded and the sent part passed 1 MiB, the file is
+//! cut back to the events not sent yet.
+
+use crate::config::{EventSettings, SharedConfig};
+use crate::configs::audit;
+use crate::fsutil;
+use serde::Serialize;
+use std::io::{self, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio::sync::Mutex;
+
+/// Default NDJSON file the events are appended to
+pub const DEFAULT_EVENTS_FILE: &str = "~/.local/share/sysrat/events.ndjson";
+
+/// A webhook gets this long to accept one event
+const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+/// How often undelivered events are retried, also picks up a reloaded `[events]`
+const RETRY_INTERVAL: Duration = Duration::from_secs(30);
+/// Forwarded events beyond this size are cut from the file once all are sent
+const COMPACT_BYTES: u64 = 1024 * 1024;
+
+/// Serializes appends and holds the id of the last event, ids only grow
+static APPEND: Mutex<u128> = Mutex::const_new(0);
+/// One forwarder at a time, so each line is posted once per attempt
+static FORWARD: Mutex<()> = Mutex::const_new(());
+
+/// One line of the events file
+#[derive(Debug, Serialize)]
+pub struct ChangeEvent {
+    /// Unique and increasing, nanoseconds since the epoch as a string
+    pub id: String,
+    /// Unix seconds
+    pub time: u64,
+    /// e.g. "config.write", "config.restore", "container.restart"
+    pub kind: String,
+    /// File name or container the change was made to
+    pub subject: String,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Record a change when `[events]` is enabled. Failing to record it is
+/// audited but does not fail the change, which already happened.
+pub async fn publish(settings: &EventSettings, kind: &str, subject: &str, error: Option<String>) {
+    if !settings.enabled {
+        return;
+    }
+
+    let file = fsutil::expand_tilde(&settings.file);
+    if let Err(e) = append(&file, kind, subject, error).await {
+        audit::record(
+            "error",
+            &format!(
+                "Event {} {} not recorded in {}: {}",
+                kind,
+                subject,
+                file.display(),
+                e
+            ),
+        );
+        return;
+    }
+
+    if let Some(url) = settings.webhook.clone() {
+        tokio::spawn(async move {
+            let _ = forward(&file, &url).await;
+        });
+    }
+}
+
+async fn append(file: &Path, kind: &str, subject: &str, error: Option<String>) -> io::Result<()> {
+    let mut last_id = APPEND.lock().await;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let id = now.as_nanos().max(*last_id + 1);
+    let event = ChangeEvent {
+        id: id.to_string(),
+        time: now.as_secs(),
+        kind: kind.to_string(),
+        subject: subject.to_string(),
+        success: error.is_none(),
+        error,
+    };
+    let mut line = serde_json::to_string(&event).map_err(io::Error::other)?;
+    line.push('\n');
+
+    if let Some(parent) = file.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let mut out = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(file)
+        .await?;
+    out.write_all(line.as_bytes()).await?;
+    out.sync_data().await?;
+
+    *last_id = id;
+    Ok(())
+}
+
+fn offset_path(file: &Path) -> PathBuf {
+    let mut name = file.as_os_str().to_owned();
+    name.push(".offset");
+    PathBuf::from(name)
+}
+
+/// POST every event past the stored offset to `url`, oldest first. Stops at
+/// the first failure, which is retried later. Returns how many were sent.
+pub async fn forward(file: &Path, url: &str) -> io::Result<usize> {
+    let _guard = FORWARD.lock().await;
+
+    let offset_file = offset_path(file);
+    let mut offset: u64 = match tokio::fs::read_to_string(&offset_file).await {
+        Ok(text) => text.trim().parse().unwrap_or(0),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+        Err(e) => return Err(e),
+    };
+    let mut tail = match read_from(file, &mut offset).await {
+        Ok(tail) => tail,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e),
+    };
+
+    let mut sent = 0;
+    let mut start = 0;
+    // Only whole lines, an append may be in progress
+    while let Some(end) = tail[start..].iter().position(|&b| b == b'\n') {
+        let line = &tail[start..start + end];
+        if !line.is_empty()
+            && let Err(e) = post(url, line.to_vec()).await
+        {
+            audit::record(
+                "warn",
+                &format!("Forwarding events to {} stopped: {}", url, e),
+            );
+            return Err(e);
+        }
+        start += end + 1;
+        sent += 1;
+        fsutil::write_atomic(&offset_file, (offset + start as u64).to_string()).await?;
+    }
+
+    offset += start as u64;
+    tail.drain(..start);
+    if tail.is_empty() && offset >= COMPACT_BYTES {
+        compact(file, &offset_file, offset).await?;
+    }
+    Ok(sent)
+}
+
+/// What `file` holds past `offset`, read from there on; an offset past the
+/// end (the file was replaced by a shorter one) starts over at 0
+async fn read_from(file: &Path, offset: &mut u64) -> io::Result<Vec<u8>> {
+    let mut input = tokio::fs::File::open(file).await?;
+    if *offset > input.metadata().await?.len() {
+        *offset = 0;
+    }
+    input.seek(SeekFrom::Start(*offset)).await?;
+    let mut tail = Vec::new();
+    input.read_to_end(&mut tail).await?;
+    Ok(tail)
+}
+
+/// Drop the `forwarded` bytes from the front of `file`, keeping what was
+/// appended since. The offset is reset first: a crash in between sends the
+/// kept events again rather than skipping any.
+async fn compact(file: &Path, offset_file: &Path, forwarded: u64) -> io::Result<()> {
+    let _appends = APPEND.lock().await;
+    let mut offset = forwarded;
+    let rest = read_from(file, &mut offset).await?;
+    if offset != forwarded {
+        return Ok(());
+    }
+    fsutil::write_atomic(offset_file, "0").await?;
+    fsutil::write_atomic(file, rest).await
+}
+
+async fn post(url: &str, body: Vec<u8>) -> io::Result<()> {
+    let url = url.to_string();
+    tokio::task::spawn_blocking(move || {
+        ureq::post(&url)
+            .timeout(SEND_TIMEOUT)
+            .set("Content-Type", "application/json")
+            .send_bytes(&body)
+            .map(|_| ())
+            .map_err(|e| io::Error::other(format!("Posting to {} failed: {}", url, e)))
+    })
+    .await
+    .map_err(io::Error::other)?
+}
+
+/// Retry undelivered events in the background while a webhook is set
+pub fn spawn_forwarder(config: SharedConfig) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(RETRY_INTERVAL);
+
+        loop {
+            ticker.tick().await;
+
+            let settings = config.read().await.events().clone();
+            if !settings.enabled {
+                continue;
+            }
+            if let Some(url) = &settings.webhook {
+                let _ = forward(&fsutil::expand_tilde(&settings.file), url).await;
+            }
+        }
+    });
+}
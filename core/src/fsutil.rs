@@ -0,0 +1,195 @@
+//! Crash-safe file replacement: the new content goes to a temp file next to
+//! the target, is synced, and then renamed over it, so readers and a crash
+//! mid-write only ever see the old or the new file.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// `~/...` relative to $HOME, other paths unchanged
+pub fn expand_tilde(path: &str) -> PathBuf {
+    if let Some(stripped) = path.strip_prefix("~/")
+        && let Ok(home) = std::env::var("HOME")
+    {
+        return Path::new(&home).join(stripped);
+    }
+    PathBuf::from(path)
+}
+
+/// Permission bits and owner of a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ownership {
+    pub mode: u32,
+    pub uid: u32,
+    pub gid: u32,
+}
+
+impl std::fmt::Display for Ownership {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "mode {:04o}, owner {}:{}", self.mode, self.uid, self.gid)
+    }
+}
+
+/// Mode and owner of `path`, None when it cannot be stat'ed (or off unix)
+pub async fn ownership(path: impl AsRef<Path>) -> Option<Ownership> {
+    let metadata = tokio::fs::metadata(path).await.ok()?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        Some(Ownership {
+            mode: metadata.mode() & 0o7777,
+            uid: metadata.uid(),
+            gid: metadata.gid(),
+        })
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = metadata;
+        None
+    }
+}
+
+/// Write `content` to a new file only the server's user can read; an
+/// existing file or symlink at `path` (planted in the shared temp directory)
+/// fails the write instead of being followed
+pub async fn write_private(path: impl AsRef<Path>, content: &[u8]) -> io::Result<()> {
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(path).await?;
+    tokio::io::AsyncWriteExt::write_all(&mut file, content).await?;
+    tokio::io::AsyncWriteExt::flush(&mut file).await
+}
+
+/// Replace `path` with `content` atomically, keeping its mode and owner
+///
+/// Symlinks are followed so the link itself stays in place. When the temp
+/// file cannot be created (directory not writable) or the owner cannot be
+/// kept (server is not root), the file is overwritten in place instead.
+pub async fn write_atomic(path: impl AsRef<Path>, content: impl Into<Vec<u8>>) -> io::Result<()> {
+    let path = path.as_ref().to_path_buf();
+    let content = content.into();
+    tokio::task::spawn_blocking(move || write_atomic_blocking(&path, &content))
+        .await
+        .map_err(io::Error::other)?
+}
+
+fn write_atomic_blocking(path: &Path, content: &[u8]) -> io::Result<()> {
+    let target = match fs::canonicalize(path) {
+        Ok(resolved) => resolved,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => path.to_path_buf(),
+        Err(e) => return Err(e),
+    };
+    let original = fs::metadata(&target).ok();
+
+    let temp = temp_path(&target);
+    let mut file = match temp_options(original.as_ref()).open(&temp) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
+            return write_in_place(&target, content);
+        }
+        Err(e) => return Err(e),
+    };
+
+    let result = (|| {
+        // Mode and owner are settled before the content goes in
+        if let Some(original) = &original {
+            file.set_permissions(original.permissions())?;
+            if !keep_owner(&temp, original)? {
+                return Ok(false);
+            }
+        }
+        file.write_all(content)?;
+        file.sync_all()?;
+        fs::rename(&temp, &target)?;
+        Ok(true)
+    })();
+
+    match result {
+        Ok(true) => {
+            sync_parent(&target);
+            Ok(())
+        }
+        Ok(false) => {
+            let _ = fs::remove_file(&temp);
+            write_in_place(&target, content)
+        }
+        Err(e) => {
+            let _ = fs::remove_file(&temp);
+            Err(e)
+        }
+    }
+}
+
+/// Options creating the temp file with the original's mode (umask only
+/// narrows it), so the content is never readable to more users than before
+fn temp_options(original: Option<&fs::Metadata>) -> OpenOptions {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(original.map_or(0o666, |original| original.permissions().mode() & 0o7777));
+    }
+    #[cfg(not(unix))]
+    let _ = original;
+    options
+}
+
+/// Hidden temp file in the target's directory, so the rename never
+/// crosses filesystems
+fn temp_path(target: &Path) -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let name = target
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let unique = format!(
+        ".{}.sysrat-{}-{}.tmp",
+        name,
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    );
+    target.with_file_name(unique)
+}
+
+/// Give the temp file the original owner, false when that is not permitted
+fn keep_owner(temp: &Path, original: &fs::Metadata) -> io::Result<bool> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        match std::os::unix::fs::chown(temp, Some(original.uid()), Some(original.gid())) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == io::ErrorKind::PermissionDenied => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = (temp, original);
+        Ok(true)
+    }
+}
+
+/// Truncate and rewrite the file itself, for when a rename is not possible
+fn write_in_place(target: &Path, content: &[u8]) -> io::Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(target)?;
+    file.write_all(content)?;
+    file.sync_all()
+}
+
+/// Persist the rename itself; best effort, not every platform can open
+/// a directory
+fn sync_parent(target: &Path) {
+    if let Some(dir) = target.parent()
+        && let Ok(dir) = File::open(dir)
+    {
+        let _ = dir.sync_all();
+    }
+}
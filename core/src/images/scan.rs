@@ -0,0 +1,223 @@
+//! Vulnerability scans of container images with trivy or grype, whichever is
+//! installed. Scans run as `image-scan` jobs; the last result per image is
+//! kept in memory.
+
+use crate::jobs;
+use crate::types::{ImageScan, SeverityCounts, Vulnerability};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::process::Command;
+
+/// Scanners tried in order
+const SCANNERS: [&str; 2] = ["trivy", "grype"];
+
+/// Scans may download a vulnerability database first
+const SCAN_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Vulnerabilities kept per scan, most severe first
+const TOP_VULNERABILITIES: usize = 10;
+
+fn results() -> &'static Mutex<HashMap<String, ImageScan>> {
+    static RESULTS: OnceLock<Mutex<HashMap<String, ImageScan>>> = OnceLock::new();
+    RESULTS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// First of `SCANNERS` found on PATH
+pub fn scanner() -> Option<&'static str> {
+    let path = std::env::var_os("PATH")?;
+    SCANNERS
+        .into_iter()
+        .find(|name| std::env::split_paths(&path).any(|dir| dir.join(name).is_file()))
+}
+
+/// Last scan of an image by id
+pub fn result(image_id: &str) -> Option<ImageScan> {
+    let results = results().lock().unwrap_or_else(|e| e.into_inner());
+    results.get(image_id).cloned()
+}
+
+/// Start scanning the image `reference` (`repo:tag` or id) as a job and return
+/// the job id right away. The result is stored under `image_id`.
+pub fn start(image_id: &str, reference: &str) -> io::Result<u64> {
+    if !is_valid_reference(reference) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Invalid image reference: {}", reference),
+        ));
+    }
+    let scanner = scanner().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Neither trivy nor grype is installed",
+        )
+    })?;
+
+    let job = jobs::start("image-scan", reference);
+    let image_id = image_id.to_string();
+    let reference = reference.to_string();
+    tokio::spawn(async move {
+        let result = run(scanner, &reference).await.map(|scan| {
+            let summary = summary(&scan.counts);
+            results()
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(image_id, scan);
+            summary
+        });
+        jobs::finish(job, result.map_err(|e| e.to_string()));
+    });
+    Ok(job)
+}
+
+/// Whether `reference` is passed to the scanner as an image, never as an option
+/// or a path
+fn is_valid_reference(reference: &str) -> bool {
+    !reference.starts_with(['-', '/', '.'])
+        && !reference.is_empty()
+        && reference
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "._-/:@".contains(c))
+}
+
+/// e.g. "2 critical, 5 high, 12 medium, 30 low"
+fn summary(counts: &SeverityCounts) -> String {
+    format!(
+        "{} critical, {} high, {} medium, {} low",
+        counts.critical, counts.high, counts.medium, counts.low
+    )
+}
+
+async fn run(scanner: &str, reference: &str) -> io::Result<ImageScan> {
+    let args: Vec<&str> = match scanner {
+        "trivy" => vec!["image", "--quiet", "--format", "json", reference],
+        _ => vec![reference, "--quiet", "-o", "json"],
+    };
+
+    let output = tokio::time::timeout(
+        SCAN_TIMEOUT,
+        Command::new(scanner)
+            .args(&args)
+            .kill_on_drop(true)
+            .output(),
+    )
+    .await
+    .map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("Timed out after {}s", SCAN_TIMEOUT.as_secs()),
+        )
+    })?
+    .map_err(|e| io::Error::other(format!("Failed to start {}: {}", scanner, e)))?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "{} failed: {}",
+            scanner,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    let report: Value = serde_json::from_slice(&output.stdout).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Unreadable {} report: {}", scanner, e),
+        )
+    })?;
+    let mut vulnerabilities = if scanner == "trivy" {
+        parse_trivy(&report)
+    } else {
+        parse_grype(&report)
+    };
+
+    let mut counts = SeverityCounts::default();
+    for vulnerability in &vulnerabilities {
+        match vulnerability.severity.as_str() {
+            "CRITICAL" => counts.critical += 1,
+            "HIGH" => counts.high += 1,
+            "MEDIUM" => counts.medium += 1,
+            "LOW" => counts.low += 1,
+            _ => counts.unknown += 1,
+        }
+    }
+
+    vulnerabilities.sort_by(|a, b| {
+        severity_rank(&a.severity)
+            .cmp(&severity_rank(&b.severity))
+            // Newer advisories first within a severity
+            .then_with(|| b.id.cmp(&a.id))
+    });
+    vulnerabilities.truncate(TOP_VULNERABILITIES);
+
+    Ok(ImageScan {
+        scanner: scanner.to_string(),
+        scanned: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0),
+        counts,
+        top: vulnerabilities,
+    })
+}
+
+/// Lower is more severe
+fn severity_rank(severity: &str) -> u8 {
+    match severity {
+        "CRITICAL" => 0,
+        "HIGH" => 1,
+        "MEDIUM" => 2,
+        "LOW" => 3,
+        _ => 4,
+    }
+}
+
+fn str_at(value: &Value, path: &[&str]) -> String {
+    path.iter()
+        .try_fold(value, |v, key| v.get(key))
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// `Results[].Vulnerabilities[]` of `trivy image --format json`
+fn parse_trivy(report: &Value) -> Vec<Vulnerability> {
+    let results = report["Results"].as_array().map_or(&[][..], Vec::as_slice);
+    results
+        .iter()
+        .flat_map(|result| {
+            result["Vulnerabilities"]
+                .as_array()
+                .map_or(&[][..], Vec::as_slice)
+        })
+        .map(|v| Vulnerability {
+            id: str_at(v, &["VulnerabilityID"]),
+            severity: str_at(v, &["Severity"]).to_uppercase(),
+            package: str_at(v, &["PkgName"]),
+            installed_version: str_at(v, &["InstalledVersion"]),
+            fixed_version: Some(str_at(v, &["FixedVersion"])).filter(|s| !s.is_empty()),
+            title: str_at(v, &["Title"]),
+        })
+        .collect()
+}
+
+/// `matches[]` of `grype -o json`
+fn parse_grype(report: &Value) -> Vec<Vulnerability> {
+    let matches = report["matches"].as_array().map_or(&[][..], Vec::as_slice);
+    matches
+        .iter()
+        .map(|m| Vulnerability {
+            id: str_at(m, &["vulnerability", "id"]),
+            severity: str_at(m, &["vulnerability", "severity"]).to_uppercase(),
+            package: str_at(m, &["artifact", "name"]),
+            installed_version: str_at(m, &["artifact", "version"]),
+            fixed_version: m["vulnerability"]["fix"]["versions"]
+                .as_array()
+                .and_then(|versions| versions.first())
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            title: str_at(m, &["vulnerability", "description"]),
+        })
+        .collect()
+}
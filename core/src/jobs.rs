@@ -0,0 +1,131 @@
+//! Record of server-side jobs started from the UI (upstream checks and
+//! applies, scriptable actions), kept in memory and mirrored to the audit log.
+
+use crate::config::AppConfig;
+use crate::configs::audit;
+use crate::types::{JobInfo, JobStatus};
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Finished jobs beyond this many are forgotten (oldest first)
+const MAX_JOBS: usize = 50;
+
+/// Output kept per job, older output is dropped from the front
+const MAX_OUTPUT_BYTES: usize = 64 * 1024;
+
+fn jobs() -> &'static Mutex<VecDeque<JobInfo>> {
+    static JOBS: OnceLock<Mutex<VecDeque<JobInfo>>> = OnceLock::new();
+    JOBS.get_or_init(|| Mutex::new(VecDeque::new()))
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Register a running job, returns its id
+pub fn start(kind: &str, target: &str) -> u64 {
+    let mut jobs = jobs().lock().unwrap_or_else(|e| e.into_inner());
+    let id = jobs.front().map_or(1, |job| job.id + 1);
+    jobs.push_front(JobInfo {
+        id,
+        kind: kind.to_string(),
+        target: target.to_string(),
+        status: JobStatus::Running,
+        message: String::new(),
+        output: String::new(),
+        started: now(),
+        finished: None,
+    });
+    // Running jobs are never forgotten, the oldest finished ones go first
+    while jobs.len() > MAX_JOBS {
+        match jobs
+            .iter()
+            .rposition(|job| job.status != JobStatus::Running)
+        {
+            Some(oldest) => jobs.remove(oldest),
+            None => break,
+        };
+    }
+    drop(jobs);
+
+    audit::record(
+        "info",
+        &format!("Job #{} {} started for {}", id, kind, target),
+    );
+    id
+}
+
+/// Add a line of output to a running job
+pub fn append_output(id: u64, line: &str) {
+    let mut jobs = jobs().lock().unwrap_or_else(|e| e.into_inner());
+    let Some(job) = jobs.iter_mut().find(|job| job.id == id) else {
+        return;
+    };
+    job.output.push_str(line);
+    job.output.push('\n');
+    if job.output.len() > MAX_OUTPUT_BYTES {
+        // Cut at a line start so the tail stays readable
+        let mut excess = job.output.len() - MAX_OUTPUT_BYTES;
+        while !job.output.is_char_boundary(excess) {
+            excess += 1;
+        }
+        let cut = job.output[excess..]
+            .find('\n')
+            .map_or(job.output.len(), |i| excess + i + 1);
+        job.output.drain(..cut);
+    }
+}
+
+/// Mark a job as done with its outcome
+pub fn finish(id: u64, result: Result<String, String>) {
+    let mut jobs = jobs().lock().unwrap_or_else(|e| e.into_inner());
+    let Some(job) = jobs.iter_mut().find(|job| job.id == id) else {
+        return;
+    };
+    let (status, level, message) = match result {
+        Ok(message) => (JobStatus::Succeeded, "success", message),
+        Err(message) => (JobStatus::Failed, "error", message),
+    };
+    job.status = status;
+    job.message = message;
+    job.finished = Some(now());
+
+    audit::record(
+        level,
+        &format!(
+            "Job #{} {} for {}: {}",
+            id, job.kind, job.target, job.message
+        ),
+    );
+}
+
+/// Whether `token` may read the output of `job`: runs of an action that
+/// requires a role are only shown to holders of its token
+pub fn readable(job: &JobInfo, token: Option<&str>, config: &AppConfig) -> bool {
+    if job.kind != "action" {
+        return true;
+    }
+    match config
+        .get_action(&job.target)
+        .and_then(|action| action.role.as_deref())
+    {
+        Some(role) => config.accepts_role(role, token),
+        None => true,
+    }
+}
+
+/// All remembered jobs, newest first
+pub fn list() -> Vec<JobInfo> {
+    let jobs = jobs().lock().unwrap_or_else(|e| e.into_inner());
+    jobs.iter().cloned().collect()
+}
+
+/// A single job, None once it has been forgotten
+pub fn get(id: u64) -> Option<JobInfo> {
+    let jobs = jobs().lock().unwrap_or_else(|e| e.into_inner());
+    jobs.iter().find(|job| job.id == id).cloned()
+}
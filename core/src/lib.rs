@@ -1,4 +1,16 @@
 pub mod config;
 pub mod configs;
 pub mod containers;
+pub mod events;
+pub mod fsutil;
+pub mod images;
+pub mod jobs;
+pub mod logs;
+pub mod notify;
+pub mod profiles;
+pub mod runbook;
+#[cfg(feature = "serve")]
+pub mod serve;
+#[cfg(feature = "ssh")]
+pub mod ssh;
 pub mod types;
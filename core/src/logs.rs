@@ -0,0 +1,120 @@
+//! Reading the `[[logs]]` files: the last lines once, then whatever is
+//! appended. Follow works by polling the size, which also notices a file
+//! that was truncated or replaced by logrotate.
+
+use std::io::SeekFrom;
+use std::path::Path;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+/// Bytes read from the end of the file for the first lines
+const TAIL_BYTES: u64 = 256 * 1024;
+
+/// Bytes read per follow step; a file growing faster skips ahead
+const MAX_READ_BYTES: u64 = 1024 * 1024;
+
+/// Where following a file stands
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TailPosition {
+    /// Bytes read so far, always at the end of a line
+    pub offset: u64,
+    /// Inode of the file read from, a new one means it was replaced
+    pub inode: Option<u64>,
+}
+
+/// New lines since the last step
+pub struct TailChunk {
+    pub lines: Vec<String>,
+    /// The file was truncated or replaced, `lines` start at its beginning
+    pub rotated: bool,
+}
+
+/// Last `max_lines` complete lines of `path` and the position after them
+pub async fn tail(path: &Path, max_lines: usize) -> std::io::Result<(Vec<String>, TailPosition)> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let metadata = file.metadata().await?;
+    let start = metadata.len().saturating_sub(TAIL_BYTES);
+
+    file.seek(SeekFrom::Start(start)).await?;
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer).await?;
+
+    // The first line is cut unless reading started at the beginning
+    let skip = if start == 0 {
+        0
+    } else {
+        buffer
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(buffer.len(), |i| i + 1)
+    };
+    let (mut lines, consumed) = complete_lines(&buffer[skip..]);
+    let first = lines.len().saturating_sub(max_lines);
+
+    let position = TailPosition {
+        offset: start + (skip + consumed) as u64,
+        inode: inode(&metadata),
+    };
+    Ok((lines.split_off(first), position))
+}
+
+/// Lines appended since `position`, which is moved past them
+pub async fn follow(path: &Path, position: &mut TailPosition) -> std::io::Result<TailChunk> {
+    let metadata = tokio::fs::metadata(path).await?;
+    let current = inode(&metadata);
+    let rotated = metadata.len() < position.offset || current != position.inode;
+    if rotated {
+        *position = TailPosition {
+            offset: 0,
+            inode: current,
+        };
+    }
+    if metadata.len() == position.offset {
+        return Ok(TailChunk {
+            lines: Vec::new(),
+            rotated,
+        });
+    }
+
+    // Too far behind to catch up, continue close to the end
+    if metadata.len() - position.offset > MAX_READ_BYTES {
+        let (lines, tail_position) = tail(path, usize::MAX).await?;
+        *position = tail_position;
+        return Ok(TailChunk { lines, rotated });
+    }
+
+    let mut file = tokio::fs::File::open(path).await?;
+    file.seek(SeekFrom::Start(position.offset)).await?;
+    let mut buffer = Vec::new();
+    file.take(metadata.len() - position.offset)
+        .read_to_end(&mut buffer)
+        .await?;
+
+    // A line still being written is read once it is complete
+    let (lines, consumed) = complete_lines(&buffer);
+    position.offset += consumed as u64;
+    Ok(TailChunk { lines, rotated })
+}
+
+/// Lines ending in a newline, and the bytes they take up
+fn complete_lines(buffer: &[u8]) -> (Vec<String>, usize) {
+    let consumed = buffer
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1);
+    let lines = String::from_utf8_lossy(&buffer[..consumed])
+        .lines()
+        .map(str::to_string)
+        .collect();
+    (lines, consumed)
+}
+
+#[cfg(unix)]
+fn inode(metadata: &std::fs::Metadata) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+    Some(metadata.ino())
+}
+
+#[cfg(not(unix))]
+fn inode(_metadata: &std::fs::Metadata) -> Option<u64> {
+    None
+}
@@ -0,0 +1,142 @@
+//! Notification channels from `[notifiers]` in sysrat.toml
+
+use crate::config::{EmailSettings, NotifierSettings};
+use std::io;
+use std::process::Stdio;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// A channel gets this long to accept a message
+const SEND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A message rendered for both plaintext and HTML readers
+pub struct Message {
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+}
+
+/// Outcome of sending to one channel, e.g. "webhook https://..." or
+/// "email ops@example.com"
+pub struct Delivery {
+    pub channel: String,
+    pub result: io::Result<()>,
+}
+
+/// Whether any channel is configured
+pub fn has_channels(settings: &NotifierSettings) -> bool {
+    !settings.webhooks.is_empty() || settings.email.as_ref().is_some_and(|e| !e.to.is_empty())
+}
+
+/// Send `message` through every configured channel; one failing channel does
+/// not keep the message from the others
+pub async fn send(message: &Message, settings: &NotifierSettings) -> Vec<Delivery> {
+    let mut deliveries = Vec::new();
+
+    for url in &settings.webhooks {
+        deliveries.push(Delivery {
+            channel: format!("webhook {}", url),
+            result: post_webhook(url, message).await,
+        });
+    }
+
+    if let Some(email) = settings.email.as_ref().filter(|e| !e.to.is_empty()) {
+        deliveries.push(Delivery {
+            channel: format!("email {}", email.to.join(", ")),
+            result: send_email(email, message).await,
+        });
+    }
+
+    deliveries
+}
+
+/// POST the message as JSON; `text` doubles as the body Slack-style hooks show
+async fn post_webhook(url: &str, message: &Message) -> io::Result<()> {
+    let url = url.to_string();
+    let body = serde_json::json!({
+        "subject": message.subject,
+        "text": message.text,
+        "html": message.html,
+    })
+    .to_string();
+
+    tokio::task::spawn_blocking(move || {
+        ureq::post(&url)
+            .timeout(SEND_TIMEOUT)
+            .set("Content-Type", "application/json")
+            .send_string(&body)
+            .map(|_| ())
+            .map_err(|e| io::Error::other(format!("Posting to {} failed: {}", url, e)))
+    })
+    .await
+    .map_err(io::Error::other)?
+}
+
+/// Hand a multipart/alternative mail to sendmail, which reads the recipients
+/// from the headers
+async fn send_email(settings: &EmailSettings, message: &Message) -> io::Result<()> {
+    let mail = mime_message(settings, message);
+
+    let mut child = Command::new(&settings.sendmail)
+        .args(["-t", "-i"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", settings.sendmail, e)))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(mail.as_bytes()).await?;
+        // Dropping stdin closes it, sendmail sends once it sees the end
+    }
+
+    let output = tokio::time::timeout(SEND_TIMEOUT, child.wait_with_output())
+        .await
+        .map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!("{} timed out", settings.sendmail),
+            )
+        })??;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!(
+            "{} failed: {}",
+            settings.sendmail,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+fn mime_message(settings: &EmailSettings, message: &Message) -> String {
+    let boundary = format!(
+        "sysrat-{}",
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0)
+    );
+    let part = |content_type: &str, body: &str| {
+        format!(
+            "--{}\r\nContent-Type: {}; charset=utf-8\r\nContent-Transfer-Encoding: 8bit\r\n\r\n{}\r\n",
+            boundary,
+            content_type,
+            body.replace('\n', "\r\n")
+        )
+    };
+
+    format!(
+        "From: {}\r\nTo: {}\r\nSubject: {}\r\nMIME-Version: 1.0\r\nContent-Type: multipart/alternative; boundary=\"{}\"\r\n\r\n{}{}--{}--\r\n",
+        settings.from,
+        settings.to.join(", "),
+        message.subject,
+        boundary,
+        part("text/plain", &message.text),
+        part("text/html", &message.html),
+        boundary
+    )
+}
@@ -0,0 +1,49 @@
+//! UI preferences of each user from `[profiles]` in sysrat.toml
+//!
+//! The user is whoever the authenticating reverse proxy names in
+//! `user_header`; sysrat trusts that header, so it must only be reachable
+//! through a proxy that sets it. Each profile is one JSON file named after
+//! a hash of the user name, which keeps any name a safe file name.
+
+use crate::fsutil;
+use crate::types::UserProfile;
+use sha2::{Digest, Sha256};
+use std::io;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Default directory of the profile files
+pub const DEFAULT_PROFILE_DIR: &str = "~/.local/share/sysrat/profiles";
+
+fn profile_path(dir: &str, user: &str) -> PathBuf {
+    let hash: String = Sha256::digest(user.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    fsutil::expand_tilde(dir).join(format!("{}.json", hash))
+}
+
+/// The user's profile, an empty one until the first save
+pub async fn load(dir: &str, user: &str) -> io::Result<UserProfile> {
+    match tokio::fs::read(profile_path(dir, user)).await {
+        Ok(bytes) => serde_json::from_slice(&bytes)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(UserProfile::default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Replace the user's profile, stamped with the time of the save
+pub async fn save(dir: &str, user: &str, mut profile: UserProfile) -> io::Result<UserProfile> {
+    profile.updated_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let path = profile_path(dir, user);
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let json = serde_json::to_vec_pretty(&profile).map_err(io::Error::other)?;
+    fsutil::write_atomic(&path, json).await?;
+    Ok(profile)
+}
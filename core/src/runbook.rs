@@ -0,0 +1,325 @@
+//! Scriptable actions from sysrat.toml (`[[actions]]`): named commands run
+//! as jobs, with their output collected for the UI and every run audited.
+//! Commands are executed directly, never through a shell; parameter values
+//! are substituted into single arguments and cannot add arguments of their own.
+//!
+//! Runbooks (`[[runbooks]]`) order actions, file edits and manual checks into
+//! a procedure; the UI walks through it and runs action steps here.
+
+use crate::config::{ActionConfig, AppConfig, RunbookConfig, RunbookStepConfig, SharedConfig};
+use crate::configs::audit;
+use crate::jobs;
+use crate::types::{ActionInfo, FieldKind, RunbookInfo, RunbookStep, RunbookStepKind, SchemaField};
+use std::collections::HashMap;
+use std::io;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::Command;
+
+/// All configured actions in sysrat.toml order
+pub async fn list(config: &SharedConfig) -> Vec<ActionInfo> {
+    let reader = config.read().await;
+    reader.actions().iter().map(info).collect()
+}
+
+fn info(action: &ActionConfig) -> ActionInfo {
+    let command = std::iter::once(action.command.as_str())
+        .chain(action.args.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join(" ");
+    ActionInfo {
+        name: action.name.clone(),
+        description: action.description.clone(),
+        command,
+        working_dir: action.working_dir.clone(),
+        timeout_secs: action.timeout_secs,
+        role: action.role.clone(),
+        confirm: action.confirm.clone(),
+        params: action.params.clone(),
+    }
+}
+
+/// All configured runbooks in sysrat.toml order
+pub async fn list_runbooks(config: &SharedConfig) -> Vec<RunbookInfo> {
+    let reader = config.read().await;
+    reader
+        .runbooks()
+        .iter()
+        .map(|runbook| runbook_info(runbook, &reader))
+        .collect()
+}
+
+fn runbook_info(runbook: &RunbookConfig, config: &AppConfig) -> RunbookInfo {
+    RunbookInfo {
+        name: runbook.name.clone(),
+        description: runbook.description.clone(),
+        steps: runbook
+            .steps
+            .iter()
+            .map(|step| step_info(step, config))
+            .collect(),
+    }
+}
+
+fn step_info(step: &RunbookStepConfig, config: &AppConfig) -> RunbookStep {
+    let (kind, target) = match (&step.action, &step.file) {
+        (Some(action), _) => (RunbookStepKind::Action, Some(action.clone())),
+        (None, Some(file)) => (RunbookStepKind::Edit, Some(file.clone())),
+        (None, None) => (RunbookStepKind::Check, None),
+    };
+    let role = step
+        .action
+        .as_deref()
+        .and_then(|name| config.get_action(name))
+        .and_then(|action| action.role.clone());
+    RunbookStep {
+        title: step.title.clone(),
+        description: step.description.clone(),
+        kind,
+        target,
+        params: step.params.clone(),
+        role,
+    }
+}
+
+/// Run the action of a runbook step with the parameter values from
+/// sysrat.toml, returns the job id like `start`. `index` counts from 0.
+pub async fn start_step(
+    name: &str,
+    index: usize,
+    token: Option<&str>,
+    config: &SharedConfig,
+) -> io::Result<u64> {
+    let reader = config.read().await;
+    let runbook = reader.get_runbook(name).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Runbook not found in config: {}", name),
+        )
+    })?;
+    let total = runbook.steps.len();
+    let step = runbook.steps.get(index).cloned().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Runbook {} has no step {}", name, index + 1),
+        )
+    })?;
+    drop(reader);
+
+    let action = step.action.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Step {} of {} runs no action", index + 1, name),
+        )
+    })?;
+    let job = start(&action, &step.params, token, config).await?;
+    audit::record(
+        "info",
+        &format!(
+            "Job #{} is step {}/{} of runbook {}: {}",
+            job,
+            index + 1,
+            total,
+            name,
+            step.title
+        ),
+    );
+    Ok(job)
+}
+
+/// Start an action as a job and return the job id right away; the command
+/// keeps running in the background until it exits or times out.
+///
+/// `params` are the values of the action's parameters by key, `token` is the
+/// client's role token, checked when the action requires a role.
+pub async fn start(
+    name: &str,
+    params: &HashMap<String, String>,
+    token: Option<&str>,
+    config: &SharedConfig,
+) -> io::Result<u64> {
+    let reader = config.read().await;
+    let action = reader.get_action(name).cloned().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Action not found in config: {}", name),
+        )
+    })?;
+
+    if let Some(role) = &action.role
+        && !reader.accepts_role(role, token)
+    {
+        audit::record(
+            "error",
+            &format!(
+                "Rejected action {}: missing or wrong token for role {}",
+                name, role
+            ),
+        );
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("Action {} requires role {}", name, role),
+        ));
+    }
+    drop(reader);
+
+    let args = bind_params(&action, params)?;
+
+    let job = jobs::start("action", name);
+    if let Some(role) = &action.role {
+        audit::record("info", &format!("Job #{} runs as role {}", job, role));
+    }
+    if !params.is_empty() {
+        let mut values: Vec<String> = params.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+        values.sort();
+        audit::record(
+            "info",
+            &format!("Job #{} parameters: {}", job, values.join(", ")),
+        );
+    }
+
+    tokio::spawn(async move {
+        let result = run(job, &action, &args).await.map_err(|e| e.to_string());
+        jobs::finish(job, result);
+    });
+    Ok(job)
+}
+
+/// Check `params` against the action's parameter definitions and substitute
+/// them into its args
+fn bind_params(action: &ActionConfig, params: &HashMap<String, String>) -> io::Result<Vec<String>> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidInput, message);
+
+    if let Some(unknown) = params
+        .keys()
+        .find(|key| !action.params.iter().any(|field| field.key == **key))
+    {
+        return Err(invalid(format!("Unknown parameter {}", unknown)));
+    }
+    for field in &action.params {
+        let value = params.get(&field.key).map_or("", String::as_str);
+        if let Some(problem) = check_param(field, value) {
+            return Err(invalid(format!("Parameter {}: {}", field.key, problem)));
+        }
+    }
+
+    Ok(action
+        .args
+        .iter()
+        .map(|arg| (arg, substitute(arg, &action.params, params)))
+        // An arg made only of placeholders of unset values is left out
+        .filter(|(arg, bound)| !bound.is_empty() || arg.is_empty())
+        .map(|(_, bound)| bound)
+        .collect())
+}
+
+/// Validation error of a parameter value, None if it is acceptable
+fn check_param(field: &SchemaField, value: &str) -> Option<String> {
+    if value.is_empty() {
+        return field.required.then(|| "required".to_string());
+    }
+    if value.chars().any(char::is_control) {
+        return Some("contains control characters".to_string());
+    }
+    // Would be read as an option by most commands, a negative number too
+    if value.starts_with('-') {
+        return Some("may not start with -".to_string());
+    }
+    match field.kind {
+        FieldKind::String => None,
+        FieldKind::Integer => value
+            .parse::<i64>()
+            .err()
+            .map(|_| "not an integer".to_string()),
+        FieldKind::Float => value
+            .parse::<f64>()
+            .err()
+            .map(|_| "not a number".to_string()),
+        FieldKind::Boolean => {
+            (!matches!(value, "true" | "false")).then(|| "true or false".to_string())
+        }
+        FieldKind::Enum => (!field.options.iter().any(|o| o == value))
+            .then(|| format!("one of {}", field.options.join(", "))),
+    }
+}
+
+/// Replace `{key}` placeholders of known parameters in one arg, in a single
+/// pass so values are never expanded again
+fn substitute(arg: &str, fields: &[SchemaField], params: &HashMap<String, String>) -> String {
+    let mut out = String::new();
+    let mut rest = arg;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        match after.find('}') {
+            Some(close) if fields.iter().any(|field| field.key == after[..close]) => {
+                out.push_str(params.get(&after[..close]).map_or("", String::as_str));
+                rest = &after[close + 1..];
+            }
+            _ => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Run the command with its bound args, feeding its output lines into the job
+async fn run(job: u64, action: &ActionConfig, args: &[String]) -> io::Result<String> {
+    let mut command = Command::new(&action.command);
+    command
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        // A timed-out run must not leave the process behind
+        .kill_on_drop(true);
+    if let Some(dir) = &action.working_dir {
+        command.current_dir(dir);
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| io::Error::other(format!("Failed to start {}: {}", action.command, e)))?;
+    let stdout = child
+        .stdout
+        .take()
+        .map(|out| tokio::spawn(forward(job, out)));
+    let stderr = child
+        .stderr
+        .take()
+        .map(|err| tokio::spawn(forward(job, err)));
+
+    let timeout = Duration::from_secs(action.timeout_secs);
+    let status = match tokio::time::timeout(timeout, child.wait()).await {
+        Ok(status) => status?,
+        Err(_) => {
+            let _ = child.kill().await;
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!("Timed out after {}s", action.timeout_secs),
+            ));
+        }
+    };
+
+    // Let the readers drain what the command wrote before it exited
+    for reader in [stdout, stderr].into_iter().flatten() {
+        let _ = reader.await;
+    }
+
+    match status.code() {
+        Some(0) => Ok("Exited with 0".to_string()),
+        Some(code) => Err(io::Error::other(format!("Exited with {}", code))),
+        None => Err(io::Error::other("Killed by a signal")),
+    }
+}
+
+async fn forward(job: u64, stream: impl AsyncRead + Unpin) {
+    let mut lines = BufReader::new(stream).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        jobs::append_output(job, &line);
+    }
+}
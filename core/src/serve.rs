@@ -0,0 +1,132 @@
+//! Listening side of the sysrat server and the hub: HTTPS from a
+//! `TlsSettings` (with client certificates for mTLS) and a graceful shutdown
+//! on SIGINT or SIGTERM that lets in-flight requests drain.
+
+use crate::config::TlsSettings;
+use crate::fsutil::expand_tilde;
+use axum::Router;
+use axum::extract::connect_info::IntoMakeServiceWithConnectInfo;
+use k_lib::config::Cookbook;
+use k_lib::logger;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject};
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::signal::unix::{SignalKind, signal};
+
+const SCOPE: &str = "SIGNAL";
+
+/// Build the rustls config from `settings`; with `client_ca` only clients
+/// presenting a certificate it signed complete the handshake
+pub fn tls_config(settings: &TlsSettings) -> Result<ServerConfig, String> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let certs = read_certs(&settings.cert)?;
+    let key = PrivateKeyDer::from_pem_file(expand_tilde(&settings.key))
+        .map_err(|e| format!("Failed to read key {}: {}", settings.key, e))?;
+
+    let builder = ServerConfig::builder_with_provider(Arc::clone(&provider))
+        .with_safe_default_protocol_versions()
+        .map_err(|e| format!("Unsupported TLS setup: {}", e))?;
+    let builder = match &settings.client_ca {
+        Some(client_ca) => {
+            let mut roots = RootCertStore::empty();
+            for cert in read_certs(client_ca)? {
+                roots
+                    .add(cert)
+                    .map_err(|e| format!("Invalid CA certificate in {}: {}", client_ca, e))?;
+            }
+            let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                .build()
+                .map_err(|e| format!("Invalid client CA {}: {}", client_ca, e))?;
+            builder.with_client_cert_verifier(verifier)
+        }
+        None => builder.with_no_client_auth(),
+    };
+
+    let mut config = builder
+        .with_single_cert(certs, key)
+        .map_err(|e| format!("Certificate {} does not fit its key: {}", settings.cert, e))?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(config)
+}
+
+/// All certificates of a PEM file, an empty file is an error
+fn read_certs(path: &str) -> Result<Vec<CertificateDer<'static>>, String> {
+    let certs = CertificateDer::pem_file_iter(expand_tilde(path))
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| format!("Failed to read certificates {}: {}", path, e))?;
+    if certs.is_empty() {
+        return Err(format!("No certificates in {}", path));
+    }
+    Ok(certs)
+}
+
+/// Serve on the bound listener, over HTTPS with a `tls` config, until a
+/// shutdown signal drained the in-flight requests. `app_name` names the log
+/// file the signal is recorded in.
+pub async fn serve(
+    listener: TcpListener,
+    tls: Option<ServerConfig>,
+    service: IntoMakeServiceWithConnectInfo<Router, SocketAddr>,
+    app_name: &'static str,
+) -> std::io::Result<()> {
+    let Some(tls) = tls else {
+        return axum::serve(listener, service)
+            .with_graceful_shutdown(shutdown_signal(app_name))
+            .await;
+    };
+
+    let handle = axum_server::Handle::new();
+    tokio::spawn({
+        let handle = handle.clone();
+        async move {
+            shutdown_signal(app_name).await;
+            handle.graceful_shutdown(None);
+        }
+    });
+    let config = axum_server::tls_rustls::RustlsConfig::from_config(Arc::new(tls));
+    axum_server::from_tcp_rustls(listener.into_std()?, config)
+        .handle(handle)
+        .serve(service)
+        .await
+}
+
+/// Resolves once SIGINT (Ctrl+C) or SIGTERM is received
+async fn shutdown_signal(app_name: &'static str) {
+    let cookbook = Cookbook::load().ok();
+
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            eprintln!("Failed to install Ctrl+C handler: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    let terminate = async {
+        match signal(SignalKind::terminate()) {
+            Ok(mut stream) => {
+                stream.recv().await;
+            }
+            Err(e) => {
+                eprintln!("Failed to install SIGTERM handler: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    let name = tokio::select! {
+        _ = ctrl_c => "SIGINT",
+        _ = terminate => "SIGTERM",
+    };
+
+    match cookbook {
+        Some(ref cb) => {
+            let msg = format!("{} received, draining in-flight requests...", name);
+            logger::log_to_terminal(cb, "info", SCOPE, &msg);
+            let _ = logger::log_to_file(cb, "info", SCOPE, &msg, Some(app_name));
+        }
+        None => println!("{} received, shutting down...", name),
+    }
+}
@@ -0,0 +1,174 @@
+//! Config files and containers of a host reached over SSH, for machines
+//! that cannot run sysrat. Goes through the system's ssh client, so keys,
+//! known_hosts and `~/.ssh/config` (ProxyJump, ports) apply as in a shell.
+
+use crate::types::ContainerInfo;
+use openssh::{KnownHosts, Session, Stdio};
+use std::io;
+use std::process::Output;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+
+/// Longest a docker action may take, as for the local docker
+const ACTION_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Remote half of `write_file`, with the path as `$1` and the content on
+/// stdin. A new file has no content, mode or owner to keep, so it is written
+/// directly; symlinks are followed so the link stays in place.
+const WRITE_SCRIPT: &str = r#"set -e
+f=$1
+[ -e "$f" ] || exec cat > "$f"
+f=$(readlink -f -- "$f")
+cp -p -- "$f" "$f.backup"
+tmp=$(mktemp "$(dirname -- "$f")/.sysrat.XXXXXX" 2>/dev/null) || exec cat > "$f"
+trap 'rm -f -- "$tmp"' EXIT
+cat > "$tmp"
+if { chmod --reference="$f" -- "$tmp" && chown --reference="$f" -- "$tmp"; } 2>/dev/null; then
+    mv -f -- "$tmp" "$f"
+else
+    cat -- "$tmp" > "$f"
+fi
+"#;
+
+/// An open SSH connection, reused for every command until it drops
+pub struct SshHost {
+    session: Session,
+}
+
+impl SshHost {
+    /// Connect to `destination` (`[user@]host[:port]` or a Host of the ssh
+    /// config); unknown host keys are refused
+    pub async fn connect(destination: &str) -> io::Result<Self> {
+        let session = Session::connect(destination, KnownHosts::Strict)
+            .await
+            .map_err(|e| io::Error::other(format!("ssh {} failed: {}", destination, e)))?;
+        Ok(Self { session })
+    }
+
+    /// The connection still works
+    pub async fn is_alive(&self) -> bool {
+        self.session.check().await.is_ok()
+    }
+
+    pub async fn read_file(&self, path: &str) -> io::Result<String> {
+        let output = self
+            .session
+            .command("cat")
+            .arg("--")
+            .arg(path)
+            .output()
+            .await
+            .map_err(io::Error::other)?;
+        let bytes = succeeded("cat", output)?;
+        String::from_utf8(bytes).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} is not valid UTF-8", path),
+            )
+        })
+    }
+
+    /// Replace the file like a local save: a `<file>.backup` copy first,
+    /// then the content goes to a temp file next to it that takes over the
+    /// file's mode and owner and is renamed over it. When that cannot be done
+    /// (directory not writable, owner not keepable) it is overwritten in place
+    pub async fn write_file(&self, path: &str, content: &str) -> io::Result<()> {
+        let mut child = self
+            .session
+            .command("sh")
+            .arg("-c")
+            .arg(WRITE_SCRIPT)
+            .arg("sh")
+            .arg(path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .await
+            .map_err(io::Error::other)?;
+        // The script exits before reading stdin when it fails early, so a
+        // broken pipe here is decided by the exit status below
+        let mut written = Ok(());
+        if let Some(mut stdin) = child.stdin().take() {
+            written = stdin.write_all(content.as_bytes()).await;
+            let _ = stdin.shutdown().await;
+        }
+        let output = child.wait_with_output().await.map_err(io::Error::other)?;
+        succeeded("write", output)?;
+        written
+    }
+
+    /// `docker ps -a` of the host. Only id, name, state and status are
+    /// filled; times, health and service URLs need the agent.
+    pub async fn list_containers(&self) -> io::Result<Vec<ContainerInfo>> {
+        let output = self
+            .session
+            .command("docker")
+            .args([
+                "ps",
+                "-a",
+                "--format",
+                "{{.ID}}\t{{.Names}}\t{{.State}}\t{{.Status}}",
+            ])
+            .output()
+            .await
+            .map_err(io::Error::other)?;
+        let stdout = succeeded("docker ps", output)?;
+
+        let containers = String::from_utf8_lossy(&stdout)
+            .lines()
+            .filter_map(|line| {
+                let parts: Vec<&str> = line.split('\t').collect();
+                (parts.len() >= 4).then(|| ContainerInfo {
+                    id: parts[0].to_string(),
+                    name: parts[1].to_string(),
+                    state: parts[2].to_string(),
+                    status: parts[3].to_string(),
+                    created_at: None,
+                    started_at: None,
+                    exited_at: None,
+                    exit_code: None,
+                    health: None,
+                    service_url: None,
+                    protected: false,
+                })
+            })
+            .collect();
+        Ok(containers)
+    }
+
+    /// docker start/stop/restart of a container
+    pub async fn container_action(&self, container_id: &str, action: &str) -> io::Result<()> {
+        let mut command = self.session.command("docker");
+        command.args([action, "--", container_id]);
+        let output = tokio::time::timeout(ACTION_TIMEOUT, command.output())
+            .await
+            .map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("docker {} timed out", action),
+                )
+            })?
+            .map_err(io::Error::other)?;
+        succeeded(&format!("docker {}", action), output).map(|_| ())
+    }
+}
+
+/// Stdout of a finished remote command, its stderr as the error otherwise
+fn succeeded(what: &str, output: Output) -> io::Result<Vec<u8>> {
+    if output.status.success() {
+        return Ok(output.stdout);
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let kind = if stderr.contains("No such file") {
+        io::ErrorKind::NotFound
+    } else if stderr.contains("Permission denied") {
+        io::ErrorKind::PermissionDenied
+    } else {
+        io::ErrorKind::Other
+    };
+    Err(io::Error::new(
+        kind,
+        format!("{} failed: {}", what, stderr.trim()),
+    ))
+}
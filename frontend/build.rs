@@ -1,6 +1,6 @@
 mod build_helpers;
 
-use build_helpers::{ascii, date, hash, keybinds, statusline, theme, version};
+use build_helpers::{ascii, dashboard, date, hash, keybinds, statusline, theme, version};
 
 fn main() {
     // Load environment from sys/env/.env
@@ -29,6 +29,9 @@ fn main() {
     // Load status line configuration
     statusline::load_statusline_config();
 
+    // Load dashboard layout configuration
+    dashboard::load_dashboard_config();
+
     // Rerun if files change
     println!("cargo:rerun-if-changed=Cargo.toml");
     println!("cargo:rerun-if-changed=../sys/theme/theme.toml");
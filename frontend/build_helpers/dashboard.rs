@@ -0,0 +1,59 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Select the dashboard layout embedded into the WASM binary.
+///
+/// Order of precedence:
+/// 1. User layout (USER_DASHBOARD_FILE, default ~/.config/sysrat/dashboard.toml)
+/// 2. Built-in sys/layout/dashboard.toml
+///
+/// The selected path is set as DASHBOARD_CONFIG_PATH for include_str!.
+pub fn load_dashboard_config() {
+    const PEACH: &str = "\x1b[38;2;250;179;135m"; // Warning color
+    const BLUE: &str = "\x1b[38;2;137;180;250m";
+    const NC: &str = "\x1b[0m";
+    const INFO_ICON: &str = "\u{f05a}"; //
+
+    let repo_root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let builtin_path = repo_root.join("sys").join("layout").join("dashboard.toml");
+
+    let user_dashboard_file = env::var("USER_DASHBOARD_FILE")
+        .unwrap_or_else(|_| "~/.config/sysrat/dashboard.toml".to_string());
+
+    // Expand tilde in path manually (avoid shellexpand dependency)
+    let user_config_path = match user_dashboard_file.strip_prefix("~/") {
+        Some(stripped) => match env::var("HOME") {
+            Ok(home) => Path::new(&home).join(stripped),
+            Err(_) => Path::new(&user_dashboard_file).to_path_buf(),
+        },
+        None => Path::new(&user_dashboard_file).to_path_buf(),
+    };
+
+    let config_path = if user_config_path.exists() {
+        println!(
+            "cargo:warning={}[dashboard]{} {}{}  {}using user custom layout: {:?}",
+            PEACH, NC, BLUE, INFO_ICON, NC, user_config_path
+        );
+        user_config_path
+    } else {
+        builtin_path
+    };
+
+    // Validate TOML syntax (widget types are checked at runtime)
+    let content = fs::read_to_string(&config_path).unwrap_or_else(|e| {
+        panic!(
+            "{}[dashboard]{} failed to read layout at {:?}: {}",
+            PEACH, NC, config_path, e
+        )
+    });
+    toml::from_str::<toml::Value>(&content).unwrap_or_else(|e| {
+        panic!("invalid dashboard TOML configuration: {}", e);
+    });
+
+    println!(
+        "cargo:rustc-env=DASHBOARD_CONFIG_PATH={}",
+        config_path.display()
+    );
+    println!("cargo:rerun-if-changed={}", config_path.display());
+}
@@ -1,4 +1,5 @@
 pub mod ascii;
+pub mod dashboard;
 pub mod date;
 pub mod hash;
 pub mod keybinds;
@@ -0,0 +1,107 @@
+use super::types::{
+    ActionInfo, ActionListResponse, JobInfo, ROLE_TOKEN_HEADER, RunActionRequest, RunActionResponse,
+};
+use super::{base, retry, timing};
+use gloo_net::http::Request;
+use std::collections::HashMap;
+use wasm_bindgen::JsValue;
+
+pub async fn fetch_actions() -> Result<Vec<ActionInfo>, JsValue> {
+    timing::timed("GET /api/actions".to_string(), async move {
+        let response = retry::get(|| Request::get(&base::url("/api/actions")))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch actions: {}", e)))?;
+
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        let data: ActionListResponse = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(data.actions)
+    })
+    .await
+}
+
+/// Start an action with its parameter values, returns the id of the job running it
+pub async fn run_action(
+    name: &str,
+    params: HashMap<String, String>,
+    token: Option<String>,
+) -> Result<u64, JsValue> {
+    timing::timed(format!("POST /api/actions/{}/run", name), async move {
+        let url = base::url(&format!("/api/actions/{}/run", name));
+        let payload = RunActionRequest { params };
+
+        let mut request = Request::post(&url);
+        if let Some(token) = &token {
+            request = request.header(ROLE_TOKEN_HEADER, token);
+        }
+
+        let response = request
+            .json(&payload)
+            .map_err(|e| JsValue::from_str(&format!("Failed to serialize JSON: {}", e)))?
+            .send()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to run action: {}", e)))?;
+
+        if !response.ok() {
+            // Body explains e.g. a missing role token (403) or a bad parameter (422)
+            let body = response.text().await.unwrap_or_default();
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {} {}",
+                response.status(),
+                body
+            )));
+        }
+
+        let data: RunActionResponse = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(data.job_id)
+    })
+    .await
+}
+
+/// A job with its output so far; `token` is the role token the job's action
+/// was started with
+pub async fn fetch_job(id: u64, token: Option<String>) -> Result<Option<JobInfo>, JsValue> {
+    timing::timed(format!("GET /api/jobs/{}", id), async move {
+        let url = base::url(&format!("/api/jobs/{}", id));
+        let response = retry::get(|| {
+            let request = Request::get(&url);
+            match &token {
+                Some(token) => request.header(ROLE_TOKEN_HEADER, token),
+                None => request,
+            }
+        })
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to fetch job: {}", e)))?;
+
+        // Only the most recent jobs are kept
+        if response.status() == 404 {
+            return Ok(None);
+        }
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map(Some)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+    })
+    .await
+}
@@ -0,0 +1,68 @@
+use crate::storage;
+use web_sys::window;
+
+/// Build a URL for an API path, honoring the deployment base path
+///
+/// Resolution order:
+/// 1. The host picked in the host switcher
+/// 2. `<meta name="sysrat-api-base" content="...">` in index.html (cross-origin setups)
+/// 3. Directory of the current page (e.g., `/sysrat/` behind a reverse proxy)
+pub(super) fn url(path: &str) -> String {
+    match storage::hosts::active() {
+        Some(host) => format!("{}{}", host.url.trim_end_matches('/'), path),
+        None => home_url(path),
+    }
+}
+
+/// URL of an API path on the server that served the page, whichever host
+/// is picked
+pub(super) fn home_url(path: &str) -> String {
+    format!("{}{}", home_base(), path)
+}
+
+fn home_base() -> String {
+    if let Some(meta) = meta_base() {
+        return meta.trim_end_matches('/').to_string();
+    }
+
+    let pathname = window()
+        .and_then(|w| w.location().pathname().ok())
+        .unwrap_or_default();
+
+    // Strip the document name (if any) and trailing slash: "/sysrat/" -> "/sysrat"
+    match pathname.rfind('/') {
+        Some(idx) => pathname[..idx].to_string(),
+        None => String::new(),
+    }
+}
+
+/// Host serving the API (`host[:port]`), used to tell apart docker hosts
+pub fn host() -> String {
+    let page_host = || {
+        window()
+            .and_then(|w| w.location().host().ok())
+            .unwrap_or_default()
+    };
+    let active = storage::hosts::active().map(|host| host.url);
+
+    // Nodes of a sysrat hub share its address and differ by path
+    if let Some(path) = active.as_deref().filter(|url| url.starts_with('/')) {
+        return format!("{}{}", page_host(), path.trim_end_matches('/'));
+    }
+
+    if let Some(base) = active.or_else(meta_base)
+        && let Some((_, rest)) = base.split_once("://")
+    {
+        return rest.split('/').next().unwrap_or_default().to_string();
+    }
+
+    page_host()
+}
+
+fn meta_base() -> Option<String> {
+    let doc = window()?.document()?;
+    let meta = doc
+        .query_selector("meta[name=\"sysrat-api-base\"]")
+        .ok()??;
+    meta.get_attribute("content").filter(|c| !c.is_empty())
+}
@@ -1,67 +1,451 @@
-use super::types::{FileContentResponse, FileInfo, FileListResponse, WriteConfigRequest};
-use gloo_net::http::Request;
+use super::types::{
+    CommitInfo, FileContentResponse, FileListResponse, FileLogResponse, HygieneResponse,
+    LintResponse, LintWarning, OVERRIDE_HEADER, RenderedResponse, TextFormat, UpstreamApplyRequest,
+    UpstreamCheckResponse, WriteConfigRequest, WritePermissionError,
+};
+use super::{base, retry, timing};
+use gloo_net::http::{Request, Response};
 use wasm_bindgen::JsValue;
+use web_sys::{File, FormData};
 
-pub async fn fetch_file_list() -> Result<Vec<FileInfo>, JsValue> {
-    let response = Request::get("/api/configs")
-        .send()
-        .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to fetch file list: {}", e)))?;
-
-    if !response.ok() {
-        return Err(JsValue::from_str(&format!(
-            "Server returned error: {}",
-            response.status()
-        )));
+/// Response to a GET sent with the ETag of an earlier response
+pub enum Conditional<T> {
+    /// 304 Not Modified, what the ETag was taken from is still current
+    NotModified,
+    Modified {
+        data: T,
+        etag: Option<String>,
+    },
+}
+
+/// GET `url`, with If-None-Match when an ETag is known
+async fn get_if_none_match(url: &str, etag: Option<&str>) -> Result<Response, gloo_net::Error> {
+    let response = retry::get(|| {
+        let request = Request::get(url);
+        match etag {
+            Some(etag) => request.header("If-None-Match", etag),
+            None => request,
+        }
+    })
+    .await;
+    if let (Some(_), Ok(response)) = (etag, &response) {
+        crate::perf::record_etag(response.status() == 304);
     }
+    response
+}
+
+/// The file list, or NotModified if it still matches `etag`
+pub async fn fetch_file_list(
+    etag: Option<String>,
+) -> Result<Conditional<FileListResponse>, JsValue> {
+    timing::timed("GET /api/configs".to_string(), async move {
+        let response = get_if_none_match(&base::url("/api/configs"), etag.as_deref())
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch file list: {}", e)))?;
 
-    let data: FileListResponse = response
-        .json()
-        .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+        if response.status() == 304 {
+            return Ok(Conditional::NotModified);
+        }
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
 
-    Ok(data.files)
+        let etag = response.headers().get("etag");
+        let data: FileListResponse = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(Conditional::Modified { data, etag })
+    })
+    .await
 }
 
-pub async fn fetch_file_content(filename: &str) -> Result<String, JsValue> {
-    let url = format!("/api/configs/{}", filename);
-    let response = Request::get(&url)
-        .send()
-        .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to fetch file: {}", e)))?;
+/// Error of `fetch_file_content` for files that are not UTF-8 text
+const BINARY_FILE_ERROR: &str = "binary file";
+
+/// Start of the error of a save the server process may not make, followed
+/// by the server's explanation as JSON
+const WRITE_DENIED_ERROR: &str = "write denied: ";
+
+/// Why the server could not write the file, if that is what a save or
+/// upload error says
+pub fn write_denied(e: &JsValue) -> Option<WritePermissionError> {
+    serde_json::from_str(e.as_string()?.strip_prefix(WRITE_DENIED_ERROR)?).ok()
+}
 
-    if !response.ok() {
-        return Err(JsValue::from_str(&format!(
-            "Server returned error: {}",
-            response.status()
-        )));
+/// Error of a rejected save or upload, keeping a denied write's explanation
+async fn write_error(response: Response) -> JsValue {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    if status == 403 && serde_json::from_str::<WritePermissionError>(&body).is_ok() {
+        return JsValue::from_str(&format!("{}{}", WRITE_DENIED_ERROR, body));
     }
+    JsValue::from_str(&format!("Server returned error: {} {}", status, body))
+}
+
+/// Whether a `fetch_file_content` error means the file must be opened in the hex viewer
+pub fn is_binary_error(e: &JsValue) -> bool {
+    e.as_string().as_deref() == Some(BINARY_FILE_ERROR)
+}
+
+/// Content and format of a text file, or NotModified if it still matches `etag`;
+/// secrets are masked unless `reveal` is set, which the server audits
+pub async fn fetch_file_content(
+    filename: &str,
+    etag: Option<String>,
+    reveal: bool,
+) -> Result<Conditional<FileContentResponse>, JsValue> {
+    timing::timed(format!("GET /api/configs/{}", filename), async move {
+        let query = if reveal { "?reveal=true" } else { "" };
+        let url = base::url(&format!("/api/configs/{}{}", filename, query));
+        let response = get_if_none_match(&url, etag.as_deref())
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch file: {}", e)))?;
 
-    let data: FileContentResponse = response
-        .json()
-        .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+        if response.status() == 304 {
+            return Ok(Conditional::NotModified);
+        }
+        if response.status() == 415 {
+            return Err(JsValue::from_str(BINARY_FILE_ERROR));
+        }
 
-    Ok(data.content)
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        let etag = response.headers().get("etag");
+        let data: FileContentResponse = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(Conditional::Modified { data, etag })
+    })
+    .await
 }
 
-pub async fn save_file_content(filename: &str, content: String) -> Result<(), JsValue> {
-    let url = format!("/api/configs/{}", filename);
-    let payload = WriteConfigRequest { content };
+/// URL serving a file as a download (Content-Disposition: attachment). A
+/// file holding secrets is only served with `reveal`, which is audit-logged
+pub fn raw_file_url(filename: &str, reveal: bool) -> String {
+    let query = if reveal { "?reveal=true" } else { "" };
+    base::url(&format!("/api/configs/{}/raw{}", filename, query))
+}
 
-    let response = Request::post(&url)
-        .json(&payload)
-        .map_err(|e| JsValue::from_str(&format!("Failed to serialize JSON: {}", e)))?
-        .send()
-        .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to save file: {}", e)))?;
+/// Bytes `offset..offset + len` of a file (fewer at the end of the file),
+/// together with the total file size
+pub async fn fetch_file_range(
+    filename: &str,
+    offset: u64,
+    len: u64,
+) -> Result<(Vec<u8>, u64), JsValue> {
+    timing::timed(format!("GET /api/configs/{}/raw", filename), async move {
+        let url = raw_file_url(filename, false);
+        let range = format!("bytes={}-{}", offset, offset + len.max(1) - 1);
+        let response = retry::get(|| Request::get(&url).header("Range", &range))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch file: {}", e)))?;
 
-    if !response.ok() {
-        return Err(JsValue::from_str(&format!(
-            "Server returned error: {}",
-            response.status()
-        )));
-    }
+        // Past the end (e.g. the file shrank): no bytes, size from the header
+        if response.status() == 416 {
+            let size = response
+                .headers()
+                .get("content-range")
+                .and_then(|v| v.strip_prefix("bytes */").and_then(|s| s.parse().ok()))
+                .unwrap_or(0);
+            return Ok((Vec::new(), size));
+        }
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        // "bytes a-b/size"; a server ignoring the range sends the whole file
+        let size = response
+            .headers()
+            .get("content-range")
+            .and_then(|v| v.rsplit_once('/').and_then(|(_, s)| s.parse().ok()));
+        let data = response
+            .binary()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to read body: {}", e)))?;
+
+        match size {
+            Some(size) => Ok((data, size)),
+            None => {
+                let size = data.len() as u64;
+                let start = (offset as usize).min(data.len());
+                let end = (start + len as usize).min(data.len());
+                Ok((data[start..end].to_vec(), size))
+            }
+        }
+    })
+    .await
+}
+
+/// Replace a file with a browser-picked file (multipart upload)
+pub async fn upload_file_content(
+    filename: &str,
+    file: File,
+    override_value: Option<String>,
+) -> Result<(), JsValue> {
+    timing::timed(
+        format!("POST /api/configs/{}/upload", filename),
+        async move {
+            let url = base::url(&format!("/api/configs/{}/upload", filename));
+            let form = FormData::new()?;
+            form.append_with_blob_and_filename("file", &file, &file.name())?;
+
+            let mut request = Request::post(&url);
+            if let Some(value) = &override_value {
+                request = request.header(OVERRIDE_HEADER, value);
+            }
+
+            let response = request
+                .body(form)
+                .map_err(|e| JsValue::from_str(&format!("Failed to build upload: {}", e)))?
+                .send()
+                .await
+                .map_err(|e| JsValue::from_str(&format!("Failed to upload file: {}", e)))?;
+
+            if !response.ok() {
+                return Err(write_error(response).await);
+            }
+
+            Ok(())
+        },
+    )
+    .await
+}
+
+/// Git history of a file, newest commit first
+pub async fn fetch_file_log(filename: &str) -> Result<Vec<CommitInfo>, JsValue> {
+    timing::timed(format!("GET /api/configs/{}/log", filename), async move {
+        let url = base::url(&format!("/api/configs/{}/log", filename));
+        let response = retry::get(|| Request::get(&url))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch history: {}", e)))?;
+
+        if !response.ok() {
+            // Body explains e.g. that git versioning is disabled (501)
+            let body = response.text().await.unwrap_or_default();
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {} {}",
+                response.status(),
+                body
+            )));
+        }
+
+        let data: FileLogResponse = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(data.commits)
+    })
+    .await
+}
+
+/// Save a file, `override_value` unlocks readonly files (X-Sysrat-Override).
+/// Without `format` the server keeps the encoding and line endings on disk
+pub async fn save_file_content(
+    filename: &str,
+    content: String,
+    format: Option<TextFormat>,
+    override_value: Option<String>,
+) -> Result<(), JsValue> {
+    timing::timed(format!("POST /api/configs/{}", filename), async move {
+        let url = base::url(&format!("/api/configs/{}", filename));
+        let payload = WriteConfigRequest { content, format };
+
+        let mut request = Request::post(&url);
+        if let Some(value) = &override_value {
+            request = request.header(OVERRIDE_HEADER, value);
+        }
+
+        let response = request
+            .json(&payload)
+            .map_err(|e| JsValue::from_str(&format!("Failed to serialize JSON: {}", e)))?
+            .send()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to save file: {}", e)))?;
+
+        if !response.ok() {
+            return Err(write_error(response).await);
+        }
+
+        Ok(())
+    })
+    .await
+}
+
+/// Check unsaved content against the rule packs enabled for the file
+pub async fn lint_file_content(
+    filename: &str,
+    content: String,
+) -> Result<Vec<LintWarning>, JsValue> {
+    timing::timed(format!("POST /api/configs/{}/lint", filename), async move {
+        let url = base::url(&format!("/api/configs/{}/lint", filename));
+        let payload = WriteConfigRequest {
+            content,
+            format: None,
+        };
+
+        let response = Request::post(&url)
+            .json(&payload)
+            .map_err(|e| JsValue::from_str(&format!("Failed to serialize JSON: {}", e)))?
+            .send()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to lint file: {}", e)))?;
+
+        if !response.ok() {
+            // Body explains e.g. the write limit (429) or an oversized file (413)
+            let body = response.text().await.unwrap_or_default();
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {} {}",
+                response.status(),
+                body
+            )));
+        }
+
+        let data: LintResponse = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(data.warnings)
+    })
+    .await
+}
+
+/// Whitespace fixes the `[hygiene]` rules make to content about to be saved
+pub async fn check_hygiene(filename: &str, content: String) -> Result<HygieneResponse, JsValue> {
+    timing::timed(
+        format!("POST /api/configs/{}/hygiene", filename),
+        async move {
+            let url = base::url(&format!("/api/configs/{}/hygiene", filename));
+            let payload = WriteConfigRequest {
+                content,
+                format: None,
+            };
+
+            let response = Request::post(&url)
+                .json(&payload)
+                .map_err(|e| JsValue::from_str(&format!("Failed to serialize JSON: {}", e)))?
+                .send()
+                .await
+                .map_err(|e| JsValue::from_str(&format!("Failed to check file: {}", e)))?;
+
+            if !response.ok() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(JsValue::from_str(&format!(
+                    "Server returned error: {} {}",
+                    response.status(),
+                    body
+                )));
+            }
+
+            response
+                .json()
+                .await
+                .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+        },
+    )
+    .await
+}
+
+/// Fetch the saved content of a file with its variables substituted
+pub async fn fetch_rendered(filename: &str) -> Result<RenderedResponse, JsValue> {
+    timing::timed(
+        format!("GET /api/configs/{}/rendered", filename),
+        async move {
+            let url = base::url(&format!("/api/configs/{}/rendered", filename));
+            let response = retry::get(|| Request::get(&url))
+                .await
+                .map_err(|e| JsValue::from_str(&format!("Failed to render file: {}", e)))?;
+
+            if !response.ok() {
+                // Body explains e.g. a missing vars file
+                let body = response.text().await.unwrap_or_default();
+                return Err(JsValue::from_str(&format!(
+                    "Server returned error: {} {}",
+                    response.status(),
+                    body
+                )));
+            }
+
+            response
+                .json()
+                .await
+                .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+        },
+    )
+    .await
+}
+
+/// Fetch and verify the upstream version of a file, with a diff against the local one
+pub async fn check_upstream(filename: &str) -> Result<UpstreamCheckResponse, JsValue> {
+    timing::timed(
+        format!("GET /api/configs/{}/upstream", filename),
+        async move {
+            let url = base::url(&format!("/api/configs/{}/upstream", filename));
+            let response = retry::get(|| Request::get(&url))
+                .await
+                .map_err(|e| JsValue::from_str(&format!("Failed to check upstream: {}", e)))?;
+
+            if !response.ok() {
+                // Body explains e.g. a failed download or missing upstream_url
+                let body = response.text().await.unwrap_or_default();
+                return Err(JsValue::from_str(&format!(
+                    "Server returned error: {} {}",
+                    response.status(),
+                    body
+                )));
+            }
+
+            response
+                .json()
+                .await
+                .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+        },
+    )
+    .await
+}
+
+/// Write the reviewed upstream version, identified by its SHA-256
+pub async fn apply_upstream(filename: &str, sha256: String) -> Result<(), JsValue> {
+    timing::timed(
+        format!("POST /api/configs/{}/upstream", filename),
+        async move {
+            let url = base::url(&format!("/api/configs/{}/upstream", filename));
+            let payload = UpstreamApplyRequest { sha256 };
+
+            let response = Request::post(&url)
+                .json(&payload)
+                .map_err(|e| JsValue::from_str(&format!("Failed to serialize JSON: {}", e)))?
+                .send()
+                .await
+                .map_err(|e| JsValue::from_str(&format!("Failed to apply upstream: {}", e)))?;
+
+            if !response.ok() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(JsValue::from_str(&format!(
+                    "Server returned error: {} {}",
+                    response.status(),
+                    body
+                )));
+            }
 
-    Ok(())
+            Ok(())
+        },
+    )
+    .await
 }
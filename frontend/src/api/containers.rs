@@ -1,51 +1,104 @@
 use super::types::{
-    ContainerActionResponse, ContainerDetails, ContainerDetailsResponse, ContainerInfo,
-    ContainerListResponse,
+    ContainerActionResponse, ContainerDetails, ContainerDetailsResponse, ContainerGraphResponse,
+    ContainerListResponse, ContainerWatchResponse,
 };
+use super::{base, retry, timing};
 use gloo_net::http::Request;
 use wasm_bindgen::JsValue;
 
-pub async fn fetch_container_list() -> Result<Vec<ContainerInfo>, JsValue> {
-    let response = Request::get("/api/containers")
-        .send()
-        .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to fetch containers: {}", e)))?;
-
-    if !response.ok() {
-        return Err(JsValue::from_str(&format!(
-            "Server returned error: {}",
-            response.status()
-        )));
-    }
-
-    let data: ContainerListResponse = response
-        .json()
-        .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
-
-    Ok(data.containers)
+/// Containers, or the outage while the server cannot reach the docker daemon
+pub async fn fetch_container_list() -> Result<ContainerListResponse, JsValue> {
+    timing::timed("GET /api/containers".to_string(), async move {
+        let response = retry::get(|| Request::get(&base::url("/api/containers")))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch containers: {}", e)))?;
+
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+    })
+    .await
 }
 
 pub async fn fetch_container_details(container_id: &str) -> Result<ContainerDetails, JsValue> {
-    let url = format!("/api/containers/{}/details", container_id);
-    let response = Request::get(&url)
-        .send()
-        .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to fetch container details: {}", e)))?;
-
-    if !response.ok() {
-        return Err(JsValue::from_str(&format!(
-            "Server returned error: {}",
-            response.status()
-        )));
-    }
-
-    let data: ContainerDetailsResponse = response
-        .json()
-        .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
-
-    Ok(data.details)
+    timing::timed(
+        format!("GET /api/containers/{}/details", container_id),
+        async move {
+            let url = base::url(&format!("/api/containers/{}/details", container_id));
+            let response = retry::get(|| Request::get(&url)).await.map_err(|e| {
+                JsValue::from_str(&format!("Failed to fetch container details: {}", e))
+            })?;
+
+            if !response.ok() {
+                return Err(JsValue::from_str(&format!(
+                    "Server returned error: {}",
+                    response.status()
+                )));
+            }
+
+            let data: ContainerDetailsResponse = response
+                .json()
+                .await
+                .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+            Ok(data.details)
+        },
+    )
+    .await
+}
+
+pub async fn fetch_container_watch(container_id: &str) -> Result<ContainerWatchResponse, JsValue> {
+    timing::timed(
+        format!("GET /api/containers/{}/watch", container_id),
+        async move {
+            let url = base::url(&format!("/api/containers/{}/watch", container_id));
+            let response = retry::get(|| Request::get(&url)).await.map_err(|e| {
+                JsValue::from_str(&format!("Failed to fetch container watch: {}", e))
+            })?;
+
+            if !response.ok() {
+                return Err(JsValue::from_str(&format!(
+                    "Server returned error: {}",
+                    response.status()
+                )));
+            }
+
+            response
+                .json()
+                .await
+                .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+        },
+    )
+    .await
+}
+
+pub async fn fetch_container_graph() -> Result<ContainerGraphResponse, JsValue> {
+    timing::timed("GET /api/containers/graph".to_string(), async move {
+        let response = retry::get(|| Request::get(&base::url("/api/containers/graph")))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch container graph: {}", e)))?;
+
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+    })
+    .await
 }
 
 pub async fn start_container(container_id: &str) -> Result<String, JsValue> {
@@ -61,30 +114,38 @@ pub async fn restart_container(container_id: &str) -> Result<String, JsValue> {
 }
 
 async fn execute_container_action(container_id: &str, action: &str) -> Result<String, JsValue> {
-    let url = format!("/api/containers/{}/{}", container_id, action);
-    let response = Request::post(&url)
-        .send()
-        .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to {} container: {}", action, e)))?;
-
-    if !response.ok() {
-        return Err(JsValue::from_str(&format!(
-            "Server returned error: {}",
-            response.status()
-        )));
-    }
-
-    let data: ContainerActionResponse = response
-        .json()
-        .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
-
-    if !data.success {
-        return Err(JsValue::from_str(&format!(
-            "Action failed: {}",
-            data.message
-        )));
-    }
-
-    Ok(data.message)
+    timing::timed(
+        format!("POST /api/containers/{}/{}", container_id, action),
+        async move {
+            let url = base::url(&format!("/api/containers/{}/{}", container_id, action));
+            let response = Request::post(&url).send().await.map_err(|e| {
+                JsValue::from_str(&format!("Failed to {} container: {}", action, e))
+            })?;
+
+            if !response.ok() {
+                // Body explains e.g. the write limit (429) or a docker failure
+                let body = response.text().await.unwrap_or_default();
+                return Err(JsValue::from_str(&format!(
+                    "Server returned error: {} {}",
+                    response.status(),
+                    body
+                )));
+            }
+
+            let data: ContainerActionResponse = response
+                .json()
+                .await
+                .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+            if !data.success {
+                return Err(JsValue::from_str(&format!(
+                    "Action failed: {}",
+                    data.message
+                )));
+            }
+
+            Ok(data.message)
+        },
+    )
+    .await
 }
@@ -0,0 +1,25 @@
+use super::types::DashboardResponse;
+use super::{base, retry, timing};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+pub async fn fetch_dashboard() -> Result<DashboardResponse, JsValue> {
+    timing::timed("GET /api/dashboard".to_string(), async move {
+        let response = retry::get(|| Request::get(&base::url("/api/dashboard")))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch dashboard: {}", e)))?;
+
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+    })
+    .await
+}
@@ -0,0 +1,26 @@
+use super::types::FrontendConfigResponse;
+use super::{base, retry, timing};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+/// Banner and MOTD from the server's sysrat.toml
+pub async fn fetch_frontend_config() -> Result<FrontendConfigResponse, JsValue> {
+    timing::timed("GET /api/frontend-config".to_string(), async move {
+        let response = retry::get(|| Request::get(&base::url("/api/frontend-config")))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch frontend config: {}", e)))?;
+
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+    })
+    .await
+}
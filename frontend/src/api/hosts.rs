@@ -0,0 +1,29 @@
+use super::types::{HostInfo, HostListResponse};
+use super::{base, retry, timing};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+/// Hosts of the server that served the page, whichever host is picked, so
+/// the way back is always listed
+pub async fn fetch_hosts() -> Result<Vec<HostInfo>, JsValue> {
+    timing::timed("GET /api/hosts".to_string(), async move {
+        let response = retry::get(|| Request::get(&base::home_url("/api/hosts")))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch hosts: {}", e)))?;
+
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        let data: HostListResponse = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(data.hosts)
+    })
+    .await
+}
@@ -0,0 +1,57 @@
+use super::types::{ImageListResponse, RunActionResponse};
+use super::{base, retry, timing};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+/// Local images with their last scan, and the scanner installed on the server
+pub async fn fetch_images() -> Result<ImageListResponse, JsValue> {
+    timing::timed("GET /api/images".to_string(), async move {
+        let response = retry::get(|| Request::get(&base::url("/api/images")))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch images: {}", e)))?;
+
+        if !response.ok() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {} {}",
+                response.status(),
+                body
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+    })
+    .await
+}
+
+/// Start a vulnerability scan of an image, returns the id of the job running it
+pub async fn scan_image(id: &str) -> Result<u64, JsValue> {
+    let path = format!("/api/images/{}/scan", id);
+    timing::timed(format!("POST {}", path), async move {
+        let response = Request::post(&base::url(&path))
+            .send()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to start scan: {}", e)))?;
+
+        if !response.ok() {
+            // Body explains e.g. that no scanner is installed (501)
+            let body = response.text().await.unwrap_or_default();
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {} {}",
+                response.status(),
+                body
+            )));
+        }
+
+        let data: RunActionResponse = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(data.job_id)
+    })
+    .await
+}
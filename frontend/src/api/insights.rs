@@ -0,0 +1,30 @@
+use super::types::ChangeStatsResponse;
+use super::{base, retry, timing};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+/// Change counts of the managed files, `changes` counting the last `days` days
+pub async fn fetch_change_stats(days: u32) -> Result<ChangeStatsResponse, JsValue> {
+    let path = format!("/api/insights/changes?days={}", days);
+    timing::timed(format!("GET {}", path), async move {
+        let response = retry::get(|| Request::get(&base::url(&path)))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch insights: {}", e)))?;
+
+        if !response.ok() {
+            // Body explains e.g. that git versioning is disabled (501)
+            let body = response.text().await.unwrap_or_default();
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {} {}",
+                response.status(),
+                body
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+    })
+    .await
+}
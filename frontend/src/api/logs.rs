@@ -0,0 +1,126 @@
+use super::types::{LOG_ERROR_EVENT, LOG_LINES_EVENT, LOG_ROTATED_EVENT, LogInfo, LogListResponse};
+use super::{base, retry, timing};
+use gloo_net::http::Request;
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use web_sys::{Event, EventSource, MessageEvent};
+
+pub async fn fetch_logs() -> Result<Vec<LogInfo>, JsValue> {
+    timing::timed("GET /api/logs".to_string(), async move {
+        let response = retry::get(|| Request::get(&base::url("/api/logs")))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch logs: {}", e)))?;
+
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        let data: LogListResponse = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(data.logs)
+    })
+    .await
+}
+
+/// What a followed log sends
+pub enum LogEvent {
+    Lines(Vec<String>),
+    /// The file was truncated or replaced, these are its first lines
+    Rotated(Vec<String>),
+    /// The file cannot be read right now
+    Failure(String),
+    /// The connection dropped, EventSource reconnects by itself and the
+    /// server sends the last lines again
+    Disconnected,
+}
+
+/// Open `/api/logs/{name}/tail` stream; dropping it closes the connection
+pub struct LogStream {
+    source: EventSource,
+    _listeners: Vec<Closure<dyn FnMut(MessageEvent)>>,
+    _on_error: Closure<dyn FnMut(Event)>,
+}
+
+impl LogStream {
+    /// The browser gave up reconnecting, e.g. after an error response
+    pub fn is_closed(&self) -> bool {
+        self.source.ready_state() == EventSource::CLOSED
+    }
+}
+
+impl Drop for LogStream {
+    fn drop(&mut self) {
+        self.source.close();
+    }
+}
+
+/// Follow the log `name`, starting with its last `lines` lines
+pub fn follow_log(
+    name: &str,
+    lines: usize,
+    on_event: impl Fn(LogEvent) + 'static,
+) -> Result<LogStream, JsValue> {
+    let path = format!(
+        "/api/logs/{}/tail?lines={}",
+        String::from(js_sys::encode_uri_component(name)),
+        lines
+    );
+    let source = EventSource::new(&base::url(&path))?;
+    let on_event = Rc::new(on_event);
+
+    let mut listeners = Vec::new();
+    for (event, kind) in [
+        (
+            LOG_LINES_EVENT,
+            LogEvent::Lines as fn(Vec<String>) -> LogEvent,
+        ),
+        (LOG_ROTATED_EVENT, LogEvent::Rotated),
+    ] {
+        let on_event = Rc::clone(&on_event);
+        listeners.push(listen(&source, event, move |data| {
+            on_event(kind(split_lines(&data)))
+        })?);
+    }
+    let on_failure = Rc::clone(&on_event);
+    listeners.push(listen(&source, LOG_ERROR_EVENT, move |data| {
+        on_failure(LogEvent::Failure(data))
+    })?);
+
+    let on_error = Closure::<dyn FnMut(Event)>::new(move |_: Event| {
+        on_event(LogEvent::Disconnected);
+    });
+    source.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+    Ok(LogStream {
+        source,
+        _listeners: listeners,
+        _on_error: on_error,
+    })
+}
+
+fn listen(
+    source: &EventSource,
+    event: &str,
+    handler: impl Fn(String) + 'static,
+) -> Result<Closure<dyn FnMut(MessageEvent)>, JsValue> {
+    let callback = Closure::<dyn FnMut(MessageEvent)>::new(move |message: MessageEvent| {
+        handler(message.data().as_string().unwrap_or_default());
+    });
+    source.add_event_listener_with_callback(event, callback.as_ref().unchecked_ref())?;
+    Ok(callback)
+}
+
+/// An event without lines still carries one empty data line
+fn split_lines(data: &str) -> Vec<String> {
+    if data.is_empty() {
+        Vec::new()
+    } else {
+        data.split('\n').map(str::to_string).collect()
+    }
+}
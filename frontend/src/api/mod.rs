@@ -1,10 +1,52 @@
+mod actions;
+mod base;
 mod configs;
 mod containers;
+mod dashboard;
+mod frontend_config;
+mod hosts;
+mod images;
+mod insights;
+mod logs;
+mod profile;
+mod retry;
+mod runbooks;
+mod statusline;
+mod themes;
+pub mod timing;
 mod types;
+mod version;
 
-pub use configs::{fetch_file_content, fetch_file_list, save_file_content};
+pub use actions::{fetch_actions, fetch_job, run_action};
+pub use base::host;
+pub use configs::{
+    Conditional, apply_upstream, check_hygiene, check_upstream, fetch_file_content,
+    fetch_file_list, fetch_file_log, fetch_file_range, fetch_rendered, is_binary_error,
+    lint_file_content, raw_file_url, save_file_content, upload_file_content, write_denied,
+};
 pub use containers::{
-    fetch_container_details, fetch_container_list, restart_container, start_container,
-    stop_container,
+    fetch_container_details, fetch_container_graph, fetch_container_list, fetch_container_watch,
+    restart_container, start_container, stop_container,
+};
+pub use dashboard::fetch_dashboard;
+pub use frontend_config::fetch_frontend_config;
+pub use hosts::fetch_hosts;
+pub use images::{fetch_images, scan_image};
+pub use insights::fetch_change_stats;
+pub use logs::{LogEvent, LogStream, fetch_logs, follow_log};
+pub use profile::{fetch_profile, save_profile};
+pub use retry::{is_online, set_retry_policy};
+pub use runbooks::{fetch_runbooks, run_runbook_step};
+pub use statusline::fetch_statusline;
+pub use themes::{fetch_themes, save_theme};
+pub use types::{
+    ActionInfo, AlertLevel, CommitInfo, ContainerDetails, ContainerGraphResponse, ContainerInfo,
+    ContainerListResponse, ContainerWatchResponse, DEFAULT_SPLASH_MS, DaemonOutage,
+    DashboardResponse, EditingOptions, EnvSource, FieldKind, FileChangeStats, FileInfo, FileMeta,
+    FileOrder, FileSchema, FrontendConfigResponse, GraphNode, HostInfo, HygieneResponse, ImageInfo,
+    JobInfo, JobStatus, LintSeverity, LintWarning, LogInfo, ProfileBuffer, QuickAction,
+    QuickActionKind, ReadonlyOverride, RenderedResponse, RunbookInfo, RunbookStep, RunbookStepKind,
+    SchemaField, SeverityCounts, TextEncoding, TextFormat, ThemeFile, UpstreamCheckResponse,
+    UserProfile, ValidationErrorResponse, VersionResponse, WritePermissionError,
 };
-pub use types::{ContainerDetails, ContainerInfo, FileInfo};
+pub use version::fetch_server_version;
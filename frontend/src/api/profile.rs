@@ -0,0 +1,60 @@
+use super::types::UserProfile;
+use super::{base, retry, timing};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+/// The signed-in user's profile on the server that served the page, None
+/// when that server keeps no profiles or knows no user
+pub async fn fetch_profile() -> Result<Option<UserProfile>, JsValue> {
+    timing::timed("GET /api/profile".to_string(), async move {
+        let response = retry::get(|| Request::get(&base::home_url("/api/profile")))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch profile: {}", e)))?;
+
+        // Profiles disabled (404) or no user at the proxy (401)
+        if matches!(response.status(), 401 | 404) {
+            return Ok(None);
+        }
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        let data: UserProfile = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(Some(data))
+    })
+    .await
+}
+
+/// Replace the profile, returning it as saved with its new `updated_at`
+pub async fn save_profile(profile: &UserProfile) -> Result<UserProfile, JsValue> {
+    timing::timed("PUT /api/profile".to_string(), async move {
+        let response = Request::put(&base::home_url("/api/profile"))
+            .json(profile)
+            .map_err(|e| JsValue::from_str(&format!("Failed to serialize JSON: {}", e)))?
+            .send()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to save profile: {}", e)))?;
+
+        if !response.ok() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {} {}",
+                response.status(),
+                body
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+    })
+    .await
+}
@@ -0,0 +1,64 @@
+use super::types::RetryPolicy;
+use gloo_net::http::{RequestBuilder, Response};
+use std::cell::Cell;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::window;
+
+thread_local! {
+    /// From `[network]` in the server's sysrat.toml once the frontend config
+    /// is loaded, the built-in policy until then
+    static POLICY: Cell<RetryPolicy> = Cell::new(RetryPolicy::default());
+}
+
+pub fn set_retry_policy(policy: RetryPolicy) {
+    POLICY.with(|p| p.set(policy));
+}
+
+/// Whether the browser thinks it has a network connection
+pub fn is_online() -> bool {
+    window().is_none_or(|w| w.navigator().on_line())
+}
+
+/// Send the GET built by `build`, again after a growing pause while it fails
+/// on the network or a proxy answers 502-504 (the server is restarting).
+/// Offline the failure is returned right away, retrying cannot help.
+pub(super) async fn get(build: impl Fn() -> RequestBuilder) -> Result<Response, gloo_net::Error> {
+    let policy = POLICY.with(Cell::get);
+    let mut attempt = 1;
+    loop {
+        let result = build().send().await;
+        let transient = match &result {
+            Ok(response) => matches!(response.status(), 502..=504),
+            Err(_) => true,
+        };
+        if !transient || attempt >= policy.attempts || !is_online() {
+            return result;
+        }
+        sleep(delay_ms(&policy, attempt)).await;
+        attempt += 1;
+    }
+}
+
+/// Wait before retry number `attempt` (1 for the first retry)
+fn delay_ms(policy: &RetryPolicy, attempt: u32) -> u64 {
+    let factor = 1u64 << (attempt - 1).min(16);
+    policy.base_ms.saturating_mul(factor).min(policy.max_ms)
+}
+
+async fn sleep(ms: u64) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        let scheduled = window().is_some_and(|w| {
+            w.set_timeout_with_callback_and_timeout_and_arguments_0(
+                &resolve,
+                ms.min(i32::MAX as u64) as i32,
+            )
+            .is_ok()
+        });
+        // Without a timer, retry right away instead of never
+        if !scheduled {
+            let _ = resolve.call0(&JsValue::NULL);
+        }
+    });
+    let _ = JsFuture::from(promise).await;
+}
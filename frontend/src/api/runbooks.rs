@@ -0,0 +1,65 @@
+use super::types::{ROLE_TOKEN_HEADER, RunActionResponse, RunbookInfo, RunbookListResponse};
+use super::{base, retry, timing};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+pub async fn fetch_runbooks() -> Result<Vec<RunbookInfo>, JsValue> {
+    timing::timed("GET /api/runbooks".to_string(), async move {
+        let response = retry::get(|| Request::get(&base::url("/api/runbooks")))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch runbooks: {}", e)))?;
+
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        let data: RunbookListResponse = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(data.runbooks)
+    })
+    .await
+}
+
+/// Run the action of a runbook step, returns the id of the job running it
+pub async fn run_runbook_step(
+    name: &str,
+    index: usize,
+    token: Option<String>,
+) -> Result<u64, JsValue> {
+    let path = format!("/api/runbooks/{}/steps/{}/run", name, index);
+    timing::timed(format!("POST {}", path), async move {
+        let mut request = Request::post(&base::url(&path));
+        if let Some(token) = &token {
+            request = request.header(ROLE_TOKEN_HEADER, token);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to run step: {}", e)))?;
+
+        if !response.ok() {
+            // Body explains e.g. a missing role token (403) or a bad parameter (422)
+            let body = response.text().await.unwrap_or_default();
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {} {}",
+                response.status(),
+                body
+            )));
+        }
+
+        let data: RunActionResponse = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(data.job_id)
+    })
+    .await
+}
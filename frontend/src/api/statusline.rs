@@ -0,0 +1,31 @@
+use super::types::StatusLineResponse;
+use super::{base, retry, timing};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+/// User status line layout, None when the server has none
+pub async fn fetch_statusline() -> Result<Option<String>, JsValue> {
+    timing::timed("GET /api/statusline".to_string(), async move {
+        let response = retry::get(|| Request::get(&base::url("/api/statusline")))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch status line: {}", e)))?;
+
+        if !response.ok() {
+            // Body names the file and the TOML error (422)
+            let body = response.text().await.unwrap_or_default();
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {} {}",
+                response.status(),
+                body
+            )));
+        }
+
+        let data: StatusLineResponse = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(data.content)
+    })
+    .await
+}
@@ -0,0 +1,55 @@
+use super::types::{SaveThemeRequest, ThemeFile, ThemeListResponse};
+use super::{base, retry, timing};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+pub async fn fetch_themes() -> Result<Vec<ThemeFile>, JsValue> {
+    timing::timed("GET /api/themes".to_string(), async move {
+        let response = retry::get(|| Request::get(&base::url("/api/themes")))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch themes: {}", e)))?;
+
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        let data: ThemeListResponse = response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+        Ok(data.themes)
+    })
+    .await
+}
+
+/// Persist a theme as `<name>.toml` in the server's user theme directory
+pub async fn save_theme(name: &str, content: String) -> Result<(), JsValue> {
+    timing::timed(format!("POST /api/themes/{}", name), async move {
+        let url = base::url(&format!("/api/themes/{}", name));
+        let payload = SaveThemeRequest { content };
+
+        let response = Request::post(&url)
+            .json(&payload)
+            .map_err(|e| JsValue::from_str(&format!("Failed to serialize JSON: {}", e)))?
+            .send()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to save theme: {}", e)))?;
+
+        if !response.ok() {
+            // Body explains a rejected name or TOML (400)
+            let body = response.text().await.unwrap_or_default();
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {} {}",
+                response.status(),
+                body
+            )));
+        }
+
+        Ok(())
+    })
+    .await
+}
@@ -0,0 +1,113 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    future::Future,
+};
+use wasm_bindgen::JsValue;
+
+/// Actions slower than this get a "took Ns" notice
+pub const SLOW_THRESHOLD_MS: f64 = 3_000.0;
+
+/// Number of timings kept for display
+const HISTORY_LEN: usize = 50;
+
+/// Client-observed latency of a single API call
+#[derive(Clone)]
+pub struct ApiTiming {
+    pub label: String,
+    pub duration_ms: f64,
+    pub ok: bool,
+    /// `Date.now()` when the response arrived
+    pub finished_at: f64,
+}
+
+thread_local! {
+    static HISTORY: RefCell<VecDeque<ApiTiming>> = const { RefCell::new(VecDeque::new()) };
+    static IN_FLIGHT: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Run an API request and record how long it took (including failures)
+pub(super) async fn timed<T>(
+    label: String,
+    request: impl Future<Output = Result<T, JsValue>>,
+) -> Result<T, JsValue> {
+    let started = js_sys::Date::now();
+    IN_FLIGHT.with(|n| n.set(n.get() + 1));
+    let result = request.await;
+    IN_FLIGHT.with(|n| n.set(n.get().saturating_sub(1)));
+    let finished_at = js_sys::Date::now();
+    let duration_ms = finished_at - started;
+
+    if is_slow(duration_ms) {
+        web_sys::console::warn_1(&JsValue::from_str(&format!(
+            "[timing] {} took {:.0}ms",
+            label, duration_ms
+        )));
+    }
+
+    HISTORY.with(|history| {
+        let mut history = history.borrow_mut();
+        if history.len() == HISTORY_LEN {
+            history.pop_front();
+        }
+        history.push_back(ApiTiming {
+            label,
+            duration_ms,
+            ok: result.is_ok(),
+            finished_at,
+        });
+    });
+
+    result
+}
+
+/// Most recent API timing
+pub fn last() -> Option<ApiTiming> {
+    HISTORY.with(|history| history.borrow().back().cloned())
+}
+
+/// The last `count` timings, newest first
+pub fn recent(count: usize) -> Vec<ApiTiming> {
+    HISTORY.with(|history| history.borrow().iter().rev().take(count).cloned().collect())
+}
+
+/// Requests that were sent and have not answered yet
+pub fn in_flight() -> u32 {
+    IN_FLIGHT.with(Cell::get)
+}
+
+/// Timings of the requests that finished after `started`, oldest first
+pub fn since(started: f64) -> Vec<ApiTiming> {
+    HISTORY.with(|history| {
+        history
+            .borrow()
+            .iter()
+            .filter(|timing| timing.finished_at >= started)
+            .cloned()
+            .collect()
+    })
+}
+
+pub fn is_slow(duration_ms: f64) -> bool {
+    duration_ms >= SLOW_THRESHOLD_MS
+}
+
+/// Suffix for status messages when an action was slow, e.g. " (restart took 12s)"
+/// Returns an empty string for fast actions
+pub fn slow_note(action: &str, started: f64) -> String {
+    let elapsed = js_sys::Date::now() - started;
+    if is_slow(elapsed) {
+        format!(" ({} took {})", action, format_duration(elapsed))
+    } else {
+        String::new()
+    }
+}
+
+/// Human-readable duration (e.g., "850ms", "12s")
+pub fn format_duration(duration_ms: f64) -> String {
+    if duration_ms < 1000.0 {
+        format!("{:.0}ms", duration_ms)
+    } else {
+        format!("{:.0}s", duration_ms / 1000.0)
+    }
+}
@@ -0,0 +1,26 @@
+use super::types::VersionResponse;
+use super::{base, retry, timing};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+/// Version and build of the running server
+pub async fn fetch_server_version() -> Result<VersionResponse, JsValue> {
+    timing::timed("GET /api/version".to_string(), async move {
+        let response = retry::get(|| Request::get(&base::url("/api/version")))
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to fetch server version: {}", e)))?;
+
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "Server returned error: {}",
+                response.status()
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+    })
+    .await
+}
@@ -71,3 +71,90 @@ pub fn update_dom_for_theme(theme: &theme::ThemeConfig) -> Result<(), JsValue> {
 
     Ok(())
 }
+
+const PREVIEW_ID: &str = "sysrat-preview";
+
+/// CSS `rgb()` of a theme color (black for non-RGB colors)
+fn css_color(color: ratzilla::ratatui::style::Color) -> String {
+    match color {
+        ratzilla::ratatui::style::Color::Rgb(r, g, b) => format!("rgb({}, {}, {})", r, g, b),
+        _ => "rgb(0, 0, 0)".to_string(),
+    }
+}
+
+/// Standalone page for the preview frame: no scripts, no network requests,
+/// styled with the theme colors
+pub fn preview_document(body: &str, theme: &theme::ThemeConfig) -> String {
+    format!(
+        "<!doctype html><html><head><meta charset=\"utf-8\">\
+         <meta http-equiv=\"Content-Security-Policy\" \
+         content=\"default-src 'none'; style-src 'unsafe-inline'; img-src data:\">\
+         <style>body {{ font-family: '{}', {}; background: {}; color: {}; \
+         margin: 1em; line-height: 1.4; }} a {{ color: {}; }} \
+         pre, code {{ background: {}; }}</style></head><body>{}</body></html>",
+        theme.font.family,
+        theme.font.fallback,
+        css_color(theme.mantle()),
+        css_color(theme.text()),
+        css_color(theme.accent()),
+        css_color(theme.surface1()),
+        body
+    )
+}
+
+/// Show `document` in the preview frame, creating it on first use.
+/// The frame is sandboxed without scripts, so the file cannot act on the app.
+pub fn show_preview(document: &str) -> Result<(), JsValue> {
+    let doc = window()
+        .and_then(|win| win.document())
+        .ok_or_else(|| JsValue::from_str("No document"))?;
+    let frame = match doc.get_element_by_id(PREVIEW_ID) {
+        Some(frame) => frame,
+        None => {
+            let frame = doc.create_element("iframe")?;
+            frame.set_id(PREVIEW_ID);
+            frame.set_attribute("sandbox", "")?;
+            frame.set_attribute("referrerpolicy", "no-referrer")?;
+            doc.body()
+                .ok_or_else(|| JsValue::from_str("No body element found"))?
+                .append_child(&frame)?;
+            frame
+        }
+    };
+    frame.set_attribute("srcdoc", document)
+}
+
+/// Lay the preview frame over a region of the terminal grid, given as
+/// fractions of the screen (left, top, width, height)
+pub fn place_preview(region: (f64, f64, f64, f64), visible: bool) -> Result<(), JsValue> {
+    let Some(frame) = window()
+        .and_then(|win| win.document())
+        .and_then(|doc| doc.get_element_by_id(PREVIEW_ID))
+    else {
+        return Ok(());
+    };
+    let (left, top, width, height) = region;
+    let style = format!(
+        "position: fixed; left: {:.3}vw; top: {:.3}vh; width: {:.3}vw; height: {:.3}vh; \
+         border: 0; z-index: 10; visibility: {};",
+        left * 100.0,
+        top * 100.0,
+        width * 100.0,
+        height * 100.0,
+        if visible { "visible" } else { "hidden" }
+    );
+    // Called on every frame, only touch the DOM when the layout changed
+    if frame.get_attribute("style").as_deref() != Some(style.as_str()) {
+        frame.set_attribute("style", &style)?;
+    }
+    Ok(())
+}
+
+pub fn hide_preview() {
+    if let Some(frame) = window()
+        .and_then(|win| win.document())
+        .and_then(|doc| doc.get_element_by_id(PREVIEW_ID))
+    {
+        frame.remove();
+    }
+}
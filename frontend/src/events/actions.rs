@@ -0,0 +1,215 @@
+use crate::api::{self, ActionInfo, JobInfo, JobStatus};
+use crate::state::{AppState, ConfirmAction, Pane, VimMode, notifications, refresh};
+use crate::utils;
+use ratzilla::event::KeyEvent;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::window;
+
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    if state.actions.params.is_some() {
+        handle_params_keys(state, state_rc, key_event);
+        return;
+    }
+
+    let keybinds = &state.keybinds.actions;
+
+    // Scroll bindings use modifiers on the navigation keys, so check them first
+    if super::key_matches(&key_event, &keybinds.scroll_down) {
+        state.actions.scroll_down();
+    } else if super::key_matches(&key_event, &keybinds.scroll_up) {
+        state.actions.scroll_up();
+    } else if super::key_matches(&key_event, &keybinds.navigate_down)
+        || super::key_matches(&key_event, &keybinds.navigate_down_alt)
+    {
+        state.actions.next();
+    } else if super::key_matches(&key_event, &keybinds.navigate_up)
+        || super::key_matches(&key_event, &keybinds.navigate_up_alt)
+    {
+        state.actions.previous();
+    } else if super::key_matches(&key_event, &keybinds.refresh) {
+        refresh::refresh_pane(Pane::Actions, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.back_to_menu) {
+        state.set_focus(Pane::Menu, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.run)
+        && let Some(action) = state.actions.selected().cloned()
+    {
+        start(state, action, state_rc);
+    }
+}
+
+/// Ask for the parameters of `action`, or go on to run it if it has none
+fn start(state: &mut AppState, action: ActionInfo, state_rc: &Rc<RefCell<AppState>>) {
+    if action.params.is_empty() {
+        request_run(state, action.name, HashMap::new(), state_rc);
+    } else {
+        state.actions.prompt_params(&action);
+    }
+}
+
+/// Run the action `name` for a dashboard quick action. It is run from the
+/// Actions pane, so its parameters, confirmation, role token and output work
+/// as when started there; the list is fetched first as it may not be yet.
+pub(super) fn run_named(state: &mut AppState, name: String, state_rc: &Rc<RefCell<AppState>>) {
+    state.set_focus(Pane::Actions, state_rc);
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let actions = match api::fetch_actions().await {
+            Ok(actions) => actions,
+            Err(e) => {
+                notifications::notify_error(
+                    &state_clone,
+                    format!("Loading actions failed: {}", utils::error::format_error(&e)),
+                );
+                return;
+            }
+        };
+        let mut st = state_clone.borrow_mut();
+        st.actions.set_actions(actions);
+        let Some(index) = st.actions.actions.iter().position(|a| a.name == name) else {
+            st.set_status(format!("[No action named {}]", name));
+            return;
+        };
+        st.actions.selected_index = index;
+        let action = st.actions.actions[index].clone();
+        start(&mut st, action, &state_clone);
+    });
+}
+
+/// Keys of the parameter form: the form toolkit's keys plus submit and cancel
+fn handle_params_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.actions;
+    // Submit is a modified Enter, so check it before the form sees the Enter
+    let submit = super::key_matches(&key_event, &keybinds.submit_params);
+    let cancel = super::key_matches(&key_event, &keybinds.back_to_menu);
+    let Some(prompt) = state.actions.params.as_mut() else {
+        return;
+    };
+
+    if submit {
+        prompt.form.commit_edit();
+        state.vim_mode = VimMode::Normal;
+        submit_params(state, state_rc);
+    } else if cancel && prompt.form.edit_buffer.is_none() {
+        state.actions.params = None;
+        state.set_status("[run cancelled]");
+    } else {
+        super::editor::handle_form_keys(&mut prompt.form, &mut state.vim_mode, key_event);
+    }
+}
+
+/// Run the action of the parameter form once all values are valid
+fn submit_params(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(prompt) = state.actions.params.as_ref() else {
+        return;
+    };
+    let errors = prompt.form.error_count();
+    if errors > 0 {
+        state.set_status(format!("[Not run: {} invalid parameters]", errors));
+        return;
+    }
+    let name = prompt.action.clone();
+    let params = prompt.values();
+    state.actions.params = None;
+    request_run(state, name, params, state_rc);
+}
+
+/// Run an action, behind a confirmation if it asks for one
+fn request_run(
+    state: &mut AppState,
+    name: String,
+    params: HashMap<String, String>,
+    state_rc: &Rc<RefCell<AppState>>,
+) {
+    let Some(question) = state.actions.get(&name).and_then(|a| a.confirm.clone()) else {
+        run_action(state, name, params, state_rc);
+        return;
+    };
+
+    // Show the values, the question is the same for every run
+    let mut values: Vec<String> = params.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+    values.sort();
+    let message = if values.is_empty() {
+        question
+    } else {
+        format!("{} ({})", question, values.join(", "))
+    };
+    state.confirms.push(
+        format!("Run {}", name),
+        message,
+        ConfirmAction::RunAction { name, params },
+    );
+}
+
+/// Ask for the token of `role` before running `name`, None when cancelled
+pub(super) fn prompt_token(role: &str, name: &str) -> Option<String> {
+    window()
+        .and_then(|win| {
+            win.prompt_with_message(&format!("Token of role {} to run {}:", role, name))
+                .ok()
+                .flatten()
+        })
+        .filter(|token| !token.is_empty())
+}
+
+/// Start an action, asking for the role token first when it requires one
+pub(super) fn run_action(
+    state: &mut AppState,
+    name: String,
+    params: HashMap<String, String>,
+    state_rc: &Rc<RefCell<AppState>>,
+) {
+    let Some(action) = state.actions.get(&name) else {
+        return;
+    };
+    if state.actions.is_running() {
+        state.set_status("[An action is still running]");
+        return;
+    }
+
+    let token = match &action.role {
+        Some(role) => {
+            let Some(token) = prompt_token(role, &name) else {
+                state.set_status("[run cancelled]");
+                return;
+            };
+            Some(token)
+        }
+        None => None,
+    };
+
+    state.set_status(format!("Starting {}...", name));
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match api::run_action(&name, params, token.clone()).await {
+            Ok(id) => {
+                let mut st = state_clone.borrow_mut();
+                st.actions.job_token = token;
+                // Shown right away, the poll fills in the output
+                st.actions.job = Some(JobInfo {
+                    id,
+                    kind: "action".to_string(),
+                    target: name.clone(),
+                    status: JobStatus::Running,
+                    message: String::new(),
+                    output: String::new(),
+                    started: utils::time::now_secs() as i64,
+                    finished: None,
+                });
+                st.actions.output_scroll = 0;
+                st.set_status(format!("Running {} (job #{})", name, id));
+                if st.focus == Pane::Actions {
+                    refresh::follow_job(&mut st, &state_clone, id);
+                }
+            }
+            Err(e) => notifications::notify_error(
+                &state_clone,
+                format!(
+                    "Running {} failed: {}",
+                    name,
+                    utils::error::format_error(&e)
+                ),
+            ),
+        }
+    });
+}
@@ -0,0 +1,105 @@
+use super::file_list;
+use crate::{
+    state::{AppState, Pane},
+    utils::command::{self, Command, Completion, EditorOption},
+};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{
+    cell::{RefCell, RefMut},
+    rc::Rc,
+};
+
+/// Keys while the editor's `:` line is open: type the command, complete its
+/// argument with Tab, run it with Enter or close the line with Esc
+pub fn handle_keys(
+    mut state_mut: RefMut<'_, AppState>,
+    state_rc: &Rc<RefCell<AppState>>,
+    key_event: &KeyEvent,
+) {
+    let Some(mut line) = state_mut.command_line.take() else {
+        return;
+    };
+    match key_event.code {
+        KeyCode::Char(c) if !key_event.ctrl && !key_event.alt => line.push(c),
+        // Deleting the `:` itself closes the line, as in vim
+        KeyCode::Backspace if !line.pop() => return,
+        KeyCode::Tab => line.complete(|kind| candidates(&state_mut, kind)),
+        KeyCode::Enter => {
+            run(state_mut, state_rc, &line.input);
+            return;
+        }
+        KeyCode::Esc => return,
+        _ => {}
+    }
+    state_mut.command_line = Some(line);
+}
+
+/// Names Tab cycles through for an argument of `kind`
+fn candidates(state: &AppState, kind: Completion) -> Vec<String> {
+    match kind {
+        Completion::File => state
+            .file_list
+            .all
+            .iter()
+            .map(|file| file.name.clone())
+            .collect(),
+        Completion::Theme => crate::theme::available_themes(),
+        Completion::Option => command::OPTIONS
+            .iter()
+            .map(|option| option.to_string())
+            .collect(),
+    }
+}
+
+/// Run the typed command, reporting mistakes on the status line
+fn run(mut state_mut: RefMut<'_, AppState>, state_rc: &Rc<RefCell<AppState>>, input: &str) {
+    let command = match command::parse(input) {
+        Ok(command) => command,
+        Err(e) => {
+            state_mut.set_status(format!("[{}]", e));
+            return;
+        }
+    };
+    match command {
+        Command::Write => {
+            super::save_open_file(state_mut, state_rc);
+        }
+        Command::Quit => quit(&mut state_mut, state_rc),
+        Command::WriteQuit => {
+            // The save runs in the background, the borrow is free again
+            if super::save_open_file(state_mut, state_rc) {
+                quit(&mut state_rc.borrow_mut(), state_rc);
+            }
+        }
+        Command::Edit(name) => {
+            if state_mut.file_list.all.iter().any(|file| file.name == name) {
+                file_list::open_file_guarded(&mut state_mut, name, state_rc);
+            } else {
+                state_mut.set_status(format!("[No file named {}]", name));
+            }
+        }
+        Command::Set(EditorOption::Wrap, wrap) => {
+            state_mut.editor.set_wrap(wrap);
+            state_mut.set_status(if wrap {
+                "Long lines wrapped"
+            } else {
+                "Long lines scroll"
+            });
+        }
+        Command::Set(EditorOption::Numbers, shown) => {
+            state_mut.editor.set_line_numbers(shown);
+            state_mut.set_status(if shown {
+                "Line numbers shown"
+            } else {
+                "Line numbers hidden"
+            });
+        }
+        Command::Theme(name) => state_mut.set_theme(&name),
+    }
+}
+
+/// Back to the file list; the file stays open in the editor
+fn quit(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    state.set_focus(Pane::FileList, state_rc);
+    state.save_to_storage(state_rc);
+}
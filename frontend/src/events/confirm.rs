@@ -0,0 +1,134 @@
+use super::key_matches;
+use crate::state::{AppState, Confirm, ConfirmAction};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Keys while a confirmation dialog is shown: yes runs its action, no drops it.
+/// Unsaved-changes prompts take save, discard or cancel instead.
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.confirm;
+    let unsaved = state.confirms.current().is_some_and(|c| c.unsaved);
+
+    if state
+        .confirms
+        .current()
+        .is_some_and(|c| c.expected.is_some())
+    {
+        handle_typed(state, state_rc, key_event);
+        return;
+    }
+
+    if key_matches(&key_event, &keybinds.no) || key_matches(&key_event, &keybinds.no_alt) {
+        state.confirms.pop();
+        state.set_status("[cancelled]");
+    } else if unsaved && key_matches(&key_event, &keybinds.save) {
+        if let Some(confirm) = state.confirms.pop() {
+            save_then_run(state, confirm, state_rc);
+        }
+    } else if unsaved && key_matches(&key_event, &keybinds.discard) {
+        if let Some(confirm) = state.confirms.pop() {
+            discard_edits(state);
+            run(confirm.action, state, state_rc);
+        }
+    } else if !unsaved
+        && (key_matches(&key_event, &keybinds.yes) || key_matches(&key_event, &keybinds.yes_alt))
+        && let Some(confirm) = state.confirms.pop()
+    {
+        run(confirm.action, state, state_rc);
+    }
+}
+
+/// Typed confirmations: letters go into the input, so only the alternative
+/// yes/no keys answer them
+fn handle_typed(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.confirm;
+
+    if key_matches(&key_event, &keybinds.no_alt) {
+        state.confirms.pop();
+        state.set_status("[cancelled]");
+    } else if key_matches(&key_event, &keybinds.yes_alt) {
+        if state.confirms.current().is_some_and(|c| c.accepted()) {
+            if let Some(confirm) = state.confirms.pop() {
+                run(confirm.action, state, state_rc);
+            }
+        } else {
+            state.set_status("[Name does not match]");
+        }
+    } else if let Some(confirm) = state.confirms.current_mut() {
+        match key_event.code {
+            KeyCode::Char(c) if !key_event.ctrl && !key_event.alt => confirm.input.push(c),
+            KeyCode::Backspace => {
+                confirm.input.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn run(action: ConfirmAction, state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    match action {
+        ConfirmAction::StopContainer { id, name } => {
+            super::container_list::run_stop(id, name, state_rc)
+        }
+        ConfirmAction::RestartContainer { id, name } => {
+            super::container_list::run_restart(id, name, state_rc)
+        }
+        ConfirmAction::QuickAction(action) => super::dashboard::run_quick_action(action, state_rc),
+        ConfirmAction::OpenFile(filename) => super::file_list::open_file(filename, state_rc),
+        ConfirmAction::Focus(pane) => {
+            state.set_focus(pane, state_rc);
+            state.save_to_storage(state_rc);
+        }
+        ConfirmAction::RunAction { name, params } => {
+            super::actions::run_action(state, name, params, state_rc)
+        }
+        ConfirmAction::RunbookStep { runbook, index } => {
+            super::runbooks::run_step(state, runbook, index, state_rc)
+        }
+        ConfirmAction::AbandonRunbook(runbook) => super::runbooks::abandon(state, runbook),
+        ConfirmAction::ApplyUpstream { filename, sha256 } => {
+            super::upstream::run_apply(filename, sha256, state_rc)
+        }
+    }
+}
+
+/// Put the open file back to its last loaded or saved content
+fn discard_edits(state: &mut AppState) {
+    if let Some(filename) = state.editor.current_file.clone() {
+        let content = state.editor.original_content.clone();
+        let format = state.editor.original_format;
+        let (redacted, revealed) = (state.editor.redacted, state.editor.revealed);
+        state.editor.load_content(filename, content);
+        state.editor.set_format(format);
+        state.editor.redacted = redacted;
+        state.editor.revealed = revealed;
+    }
+    state.dirty = false;
+}
+
+/// Save the open file and continue with the prompt's action only if that worked
+fn save_then_run(state: &mut AppState, confirm: Confirm, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(filename) = state.editor.current_file.clone() else {
+        return;
+    };
+    let form_errors = state
+        .editor
+        .form
+        .as_ref()
+        .map_or(0, |form| form.error_count());
+    if form_errors > 0 {
+        state.set_status(format!("[Not saved: {} invalid form fields]", form_errors));
+        return;
+    }
+    let content = state.editor.get_content();
+    let override_value = state.editor.override_value.clone();
+
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        if super::menu::save(&state_clone, filename, content, override_value).await {
+            let mut st = state_clone.borrow_mut();
+            run(confirm.action, &mut st, &state_clone);
+        }
+    });
+}
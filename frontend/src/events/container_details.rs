@@ -0,0 +1,83 @@
+use crate::{
+    api,
+    state::{AppState, SubPane, notifications},
+    utils,
+};
+use ratzilla::event::KeyEvent;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Keys while the container details have the focus: scroll by a line or by
+/// the visible height, fetch failed details again, or give the keys back to
+/// the list or graph
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.container_details;
+    let (page, max) = {
+        let layout = state.layout.borrow();
+        let height = layout
+            .sub_pane_area(SubPane::ContainerDetails)
+            .map_or(1, |area| area.height.saturating_sub(2));
+        (height.max(1), layout.details_max_offset)
+    };
+
+    if super::key_matches(&key_event, &keybinds.back) {
+        state.sub_focus = SubPane::Main;
+    } else if super::key_matches(&key_event, &keybinds.refresh) {
+        if state.details_view.error.is_some()
+            && let Some(container_id) = state.details_view.container_id.clone()
+        {
+            load(container_id, state_rc);
+        }
+    } else if super::key_matches(&key_event, &keybinds.scroll_down)
+        || super::key_matches(&key_event, &keybinds.scroll_down_alt)
+    {
+        state.details_view.scroll_down(1, max);
+    } else if super::key_matches(&key_event, &keybinds.scroll_up)
+        || super::key_matches(&key_event, &keybinds.scroll_up_alt)
+    {
+        state.details_view.scroll_up(1);
+    } else if super::key_matches(&key_event, &keybinds.page_down) {
+        state.details_view.scroll_down(page, max);
+    } else if super::key_matches(&key_event, &keybinds.page_up) {
+        state.details_view.scroll_up(page);
+    }
+}
+
+/// Fetch the details of a container for the details panel of the list and
+/// the graph
+pub(super) fn load(container_id: String, state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        {
+            let mut st = state_clone.borrow_mut();
+            st.details_view.loading = true;
+            st.details_view.container_id = Some(container_id.clone());
+        }
+        let result = api::fetch_container_details(&container_id).await;
+        state_clone.borrow_mut().details_view.loading = false;
+        match result {
+            Ok(details) => {
+                {
+                    let mut st = state_clone.borrow_mut();
+                    st.container_details = Some(details);
+                    st.details_view.offset = 0;
+                    st.details_view.error = None;
+                }
+                notifications::notify_info(&state_clone, "Details loaded");
+            }
+            Err(e) => {
+                let message = utils::error::format_error(&e);
+                {
+                    // Details of the previously selected container would mislead
+                    let mut st = state_clone.borrow_mut();
+                    st.container_details = None;
+                    st.details_view.error = Some(message.clone());
+                }
+                notifications::notify_error(
+                    &state_clone,
+                    format!("Loading details failed: {}", message),
+                );
+            }
+        }
+    });
+}
@@ -0,0 +1,31 @@
+use crate::state::{AppState, Pane, refresh};
+use ratzilla::event::KeyEvent;
+use std::{cell::RefCell, rc::Rc};
+
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.container_graph;
+
+    if super::key_matches(&key_event, &keybinds.navigate_down)
+        || super::key_matches(&key_event, &keybinds.navigate_down_alt)
+    {
+        state.container_graph.next();
+    } else if super::key_matches(&key_event, &keybinds.navigate_up)
+        || super::key_matches(&key_event, &keybinds.navigate_up_alt)
+    {
+        state.container_graph.previous();
+    } else if super::key_matches(&key_event, &keybinds.select) {
+        load_details(state, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.refresh) {
+        refresh::refresh_pane(Pane::ContainerGraph, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.back_to_menu) {
+        state.set_focus(Pane::Menu, state_rc);
+    }
+}
+
+/// Open the selected node in the details panel
+fn load_details(state: &AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(node) = state.container_graph.selected_node() else {
+        return;
+    };
+    super::container_details::load(node.id.clone(), state_rc);
+}
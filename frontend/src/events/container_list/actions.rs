@@ -1,6 +1,6 @@
 use crate::{
     api,
-    state::{AppState, Pane, refresh, status_helper},
+    state::{AppState, ConfirmAction, Pane, notifications, refresh},
 };
 use std::{cell::RefCell, rc::Rc};
 use wasm_bindgen_futures::spawn_local;
@@ -11,18 +11,29 @@ pub(super) fn start_container(state: &AppState, state_rc: &Rc<RefCell<AppState>>
         let container_name = container.name.clone();
         let state_clone = Rc::clone(state_rc);
         spawn_local(async move {
+            let started = js_sys::Date::now();
             match api::start_container(&container_id).await {
                 Ok(msg) => {
-                    status_helper::set_status_timed(
+                    notifications::notify_success(
                         &state_clone,
-                        format!("Started {}: {}", container_name, msg),
+                        format!(
+                            "Started {}: {}{}",
+                            container_name,
+                            msg,
+                            api::timing::slow_note("start", started)
+                        ),
                     );
                     refresh::refresh_pane(Pane::ContainerList, &state_clone);
                 }
                 Err(e) => {
-                    status_helper::set_status_timed(
+                    notifications::notify_error(
                         &state_clone,
-                        format!("Failed to start {}: {:?}", container_name, e),
+                        format!(
+                            "Failed to start {}: {:?}{}",
+                            container_name,
+                            e,
+                            api::timing::slow_note("start", started)
+                        ),
                     );
                     refresh::refresh_pane(Pane::ContainerList, &state_clone);
                 }
@@ -31,54 +42,113 @@ pub(super) fn start_container(state: &AppState, state_rc: &Rc<RefCell<AppState>>
     }
 }
 
-pub(super) fn stop_container(state: &AppState, state_rc: &Rc<RefCell<AppState>>) {
+/// Ask before stopping the selected container
+pub(super) fn stop_container(state: &mut AppState) {
     if let Some(container) = state.container_list._selected() {
-        let container_id = container.id.clone();
-        let container_name = container.name.clone();
-        let state_clone = Rc::clone(state_rc);
-        spawn_local(async move {
-            match api::stop_container(&container_id).await {
-                Ok(msg) => {
-                    status_helper::set_status_timed(
-                        &state_clone,
-                        format!("Stopped {}: {}", container_name, msg),
-                    );
-                    refresh::refresh_pane(Pane::ContainerList, &state_clone);
-                }
-                Err(e) => {
-                    status_helper::set_status_timed(
-                        &state_clone,
-                        format!("Failed to stop {}: {:?}", container_name, e),
-                    );
-                    refresh::refresh_pane(Pane::ContainerList, &state_clone);
-                }
-            }
-        });
+        let (id, name) = (container.id.clone(), container.name.clone());
+        let protected = container.protected;
+        let action = ConfirmAction::StopContainer {
+            id,
+            name: name.clone(),
+        };
+        ask(state, "Stop", &name, protected, action);
     }
 }
 
-pub(super) fn restart_container(state: &AppState, state_rc: &Rc<RefCell<AppState>>) {
-    if let Some(container) = state.container_list._selected() {
-        let container_id = container.id.clone();
-        let container_name = container.name.clone();
-        let state_clone = Rc::clone(state_rc);
-        spawn_local(async move {
-            match api::restart_container(&container_id).await {
-                Ok(msg) => {
-                    status_helper::set_status_timed(
-                        &state_clone,
-                        format!("Restarted {}: {}", container_name, msg),
-                    );
-                    refresh::refresh_pane(Pane::ContainerList, &state_clone);
-                }
-                Err(e) => {
-                    status_helper::set_status_timed(
-                        &state_clone,
-                        format!("Failed to restart {}: {:?}", container_name, e),
-                    );
-                    refresh::refresh_pane(Pane::ContainerList, &state_clone);
-                }
+/// Yes/no dialog, or one that takes the typed name for protected containers
+fn ask(state: &mut AppState, verb: &str, name: &str, protected: bool, action: ConfirmAction) {
+    let title = format!("{} container", verb);
+    if protected {
+        let message = format!(
+            "{} is protected, type its name to {} it:",
+            name,
+            verb.to_lowercase()
+        );
+        state.confirms.push_typed(title, message, name, action);
+    } else {
+        state
+            .confirms
+            .push(title, format!("{} {}?", verb, name), action);
+    }
+}
+
+/// Stop a container once the dialog is confirmed
+pub fn run_stop(container_id: String, container_name: String, state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let started = js_sys::Date::now();
+        match api::stop_container(&container_id).await {
+            Ok(msg) => {
+                notifications::notify_success(
+                    &state_clone,
+                    format!(
+                        "Stopped {}: {}{}",
+                        container_name,
+                        msg,
+                        api::timing::slow_note("stop", started)
+                    ),
+                );
+                refresh::refresh_pane(Pane::ContainerList, &state_clone);
             }
-        });
+            Err(e) => {
+                notifications::notify_error(
+                    &state_clone,
+                    format!(
+                        "Failed to stop {}: {:?}{}",
+                        container_name,
+                        e,
+                        api::timing::slow_note("stop", started)
+                    ),
+                );
+                refresh::refresh_pane(Pane::ContainerList, &state_clone);
+            }
+        }
+    });
+}
+
+/// Ask before restarting the selected container
+pub(super) fn restart_container(state: &mut AppState) {
+    if let Some(container) = state.container_list._selected() {
+        let (id, name) = (container.id.clone(), container.name.clone());
+        let protected = container.protected;
+        let action = ConfirmAction::RestartContainer {
+            id,
+            name: name.clone(),
+        };
+        ask(state, "Restart", &name, protected, action);
     }
 }
+
+/// Restart a container once the dialog is confirmed
+pub fn run_restart(container_id: String, container_name: String, state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let started = js_sys::Date::now();
+        match api::restart_container(&container_id).await {
+            Ok(msg) => {
+                notifications::notify_success(
+                    &state_clone,
+                    format!(
+                        "Restarted {}: {}{}",
+                        container_name,
+                        msg,
+                        api::timing::slow_note("restart", started)
+                    ),
+                );
+                refresh::refresh_pane(Pane::ContainerList, &state_clone);
+            }
+            Err(e) => {
+                notifications::notify_error(
+                    &state_clone,
+                    format!(
+                        "Failed to restart {}: {:?}{}",
+                        container_name,
+                        e,
+                        api::timing::slow_note("restart", started)
+                    ),
+                );
+                refresh::refresh_pane(Pane::ContainerList, &state_clone);
+            }
+        }
+    });
+}
@@ -1,34 +1,8 @@
-use crate::{
-    api,
-    state::{AppState, status_helper},
-    utils,
-};
+use crate::state::AppState;
 use std::{cell::RefCell, rc::Rc};
-use wasm_bindgen_futures::spawn_local;
 
 pub(super) fn load_details(state: &AppState, state_rc: &Rc<RefCell<AppState>>) {
     if let Some(container) = state.container_list._selected() {
-        let container_id = container.id.clone();
-        let state_clone = Rc::clone(state_rc);
-        spawn_local(async move {
-            match api::fetch_container_details(&container_id).await {
-                Ok(details) => {
-                    {
-                        let mut st = state_clone.borrow_mut();
-                        st.container_details = Some(details);
-                    }
-                    status_helper::set_status_timed(&state_clone, "[loaded]");
-                }
-                Err(e) => {
-                    status_helper::set_status_timed(
-                        &state_clone,
-                        format!(
-                            "[ERROR loading details: {}]",
-                            utils::error::format_error(&e)
-                        ),
-                    );
-                }
-            }
-        });
+        super::super::container_details::load(container.id.clone(), state_rc);
     }
 }
@@ -0,0 +1,60 @@
+use crate::state::AppState;
+use web_sys::window;
+
+/// Open the selected container's proxied URL, falling back to its first port
+pub(super) fn open_service(state: &mut AppState) {
+    let service_url = state
+        .container_list
+        ._selected()
+        .and_then(|c| c.service_url.clone());
+
+    match service_url {
+        Some(url) => open_url(state, &url),
+        None => open_port(state, 0),
+    }
+}
+
+/// Open the nth published port of the selected container in a new tab
+pub(super) fn open_port(state: &mut AppState, index: usize) {
+    let Some(container) = state.container_list._selected() else {
+        return;
+    };
+
+    // Links come from the details pane, which must belong to the selection
+    let details = match &state.container_details {
+        Some(details) if details.id == container.id => details,
+        _ => {
+            state.set_status("[press Enter to load details first]");
+            return;
+        }
+    };
+
+    let urls: Vec<String> = details
+        .ports
+        .iter()
+        .filter_map(|port| port.url.clone())
+        .collect();
+    let Some(url) = urls.get(index) else {
+        let message = if urls.is_empty() {
+            format!("[{} has no published ports]", details.name)
+        } else {
+            format!("[no port link #{}]", index + 1)
+        };
+        state.set_status(message);
+        return;
+    };
+
+    open_url(state, url);
+}
+
+fn open_url(state: &mut AppState, url: &str) {
+    let opened = window()
+        .and_then(|w| w.open_with_url_and_target(url, "_blank").ok())
+        .flatten()
+        .is_some();
+    if opened {
+        state.set_status(format!("Opened {}", url));
+    } else {
+        state.set_status(format!("[popup blocked: {}]", url));
+    }
+}
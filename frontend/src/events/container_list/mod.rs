@@ -1,9 +1,12 @@
 mod actions;
 mod details;
+mod links;
 mod navigation;
 
-use crate::state::{AppState, Pane};
-use ratzilla::event::KeyEvent;
+use crate::state::{AppState, Pane, marks, refresh};
+
+pub use actions::{run_restart, run_stop};
+use ratzilla::event::{KeyCode, KeyEvent};
 use std::{cell::RefCell, rc::Rc};
 
 pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
@@ -20,15 +23,68 @@ pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_e
     } else if super::key_matches(&key_event, &keybinds.start_container) {
         actions::start_container(state, state_rc);
     } else if super::key_matches(&key_event, &keybinds.stop_container) {
-        actions::stop_container(state, state_rc);
+        actions::stop_container(state);
     } else if super::key_matches(&key_event, &keybinds.restart_container) {
-        actions::restart_container(state, state_rc);
+        // Nothing to restart in a list that failed to load, fetch it again
+        if state.container_list.error.is_some() && state.container_list.all.is_empty() {
+            refresh::refresh_pane(Pane::ContainerList, state_rc);
+        } else {
+            actions::restart_container(state);
+        }
+    } else if super::key_matches(&key_event, &keybinds.open_port) {
+        links::open_service(state);
+    } else if super::key_matches(&key_event, &keybinds.toggle_pin) {
+        if let Some((name, pinned)) = state.container_list.toggle_pin_selected() {
+            refresh::save_selection(Pane::ContainerList, state);
+            state.set_status(marks::pin_status(&name, pinned));
+        }
+    } else if super::key_matches(&key_event, &keybinds.toggle_hide) {
+        if let Some((name, hidden)) = state.container_list.toggle_hide_selected() {
+            refresh::save_selection(Pane::ContainerList, state);
+            state.set_status(marks::hide_status(&name, hidden));
+        }
+    } else if super::key_matches(&key_event, &keybinds.toggle_show_hidden) {
+        let show = state.container_list.toggle_show_hidden();
+        state.set_status(marks::show_hidden_status(show));
+    } else if super::key_matches(&key_event, &keybinds.toggle_unhealthy_first) {
+        let first = state.container_list.toggle_unhealthy_first();
+        state.set_status(if first {
+            "[unhealthy first]"
+        } else {
+            "[unhealthy in place]"
+        });
+    } else if super::key_matches(&key_event, &keybinds.watch) {
+        super::container_watch::open_selected(state, state_rc);
     } else if super::key_matches(&key_event, &keybinds.back_to_menu) {
-        state.focus = Pane::Menu;
+        state.set_focus(Pane::Menu, state_rc);
     } else {
         // Enter to view details (not configurable for now)
         if super::match_key_without_mods(&key_event, "Enter") {
             details::load_details(state, state_rc);
+        } else if let Some(index) = port_digit(&key_event) {
+            // 1-9 open the matching port link from the details pane
+            links::open_port(state, index);
         }
     }
 }
+
+/// Mouse click on a container: select it, or load its details when it is
+/// already selected
+pub(super) fn click(state: &mut AppState, index: usize, state_rc: &Rc<RefCell<AppState>>) {
+    if index >= state.container_list.containers.len() {
+        return;
+    }
+    if index != state.container_list.selected_index {
+        state.container_list.selected_index = index;
+        refresh::save_selection(Pane::ContainerList, state);
+    } else {
+        details::load_details(state, state_rc);
+    }
+}
+
+fn port_digit(key_event: &KeyEvent) -> Option<usize> {
+    match key_event.code {
+        KeyCode::Char(c @ '1'..='9') => c.to_digit(10).map(|d| d as usize - 1),
+        _ => None,
+    }
+}
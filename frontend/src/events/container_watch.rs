@@ -0,0 +1,38 @@
+use crate::state::{AppState, Pane, refresh};
+use ratzilla::event::KeyEvent;
+use std::{cell::RefCell, rc::Rc};
+
+/// Keys of the watch view: scroll the log tail, pause polling, or go back
+/// to the list
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.container_watch;
+
+    if super::key_matches(&key_event, &keybinds.scroll_down)
+        || super::key_matches(&key_event, &keybinds.scroll_down_alt)
+    {
+        state.container_watch.scroll_down();
+    } else if super::key_matches(&key_event, &keybinds.scroll_up)
+        || super::key_matches(&key_event, &keybinds.scroll_up_alt)
+    {
+        state.container_watch.scroll_up();
+    } else if super::key_matches(&key_event, &keybinds.toggle_pause) {
+        if state.container_watch.toggle_pause() {
+            state.set_status("Watch paused");
+        } else {
+            state.set_status("Watch resumed");
+            refresh::refresh_pane(Pane::ContainerWatch, state_rc);
+        }
+    } else if super::key_matches(&key_event, &keybinds.back) {
+        state.set_focus(Pane::ContainerList, state_rc);
+    }
+}
+
+/// Open the watch view on the container selected in the list
+pub(super) fn open_selected(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(container) = state.container_list._selected() else {
+        return;
+    };
+    let (id, name) = (container.id.clone(), container.name.clone());
+    state.container_watch.open(id, name);
+    state.set_focus(Pane::ContainerWatch, state_rc);
+}
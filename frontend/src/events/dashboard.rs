@@ -0,0 +1,80 @@
+use crate::api::{self, QuickAction, QuickActionKind};
+use crate::state::{AppState, ConfirmAction, Pane, notifications, refresh};
+use crate::utils;
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.dashboard;
+
+    if super::key_matches(&key_event, &keybinds.refresh) {
+        refresh::refresh_pane(Pane::Dashboard, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.back_to_menu) {
+        state.set_focus(Pane::Menu, state_rc);
+    } else if let KeyCode::Char(c) = key_event.code
+        && let Some(n) = c.to_digit(10)
+        && let Some(action) = state.dashboard.quick_action(n as usize).cloned()
+    {
+        match action.action {
+            QuickActionKind::Edit => {
+                super::file_list::open_file_guarded(state, action.target, state_rc)
+            }
+            QuickActionKind::Stop | QuickActionKind::Restart => {
+                // Protection is known once the container list was fetched
+                let protected = state
+                    .container_list
+                    .all
+                    .iter()
+                    .any(|c| c.protected && c.name == action.target);
+                if protected {
+                    state.confirms.push_typed(
+                        action.label.clone(),
+                        format!("{} is protected, type its name to go on:", action.target),
+                        action.target.clone(),
+                        ConfirmAction::QuickAction(action),
+                    );
+                } else {
+                    state.confirms.push(
+                        action.label.clone(),
+                        format!("{} ({})?", action.label, action.target),
+                        ConfirmAction::QuickAction(action),
+                    );
+                }
+            }
+            QuickActionKind::Start => run_quick_action(action, state_rc),
+            QuickActionKind::Run { action } => super::actions::run_named(state, action, state_rc),
+        }
+    }
+}
+
+/// Dispatch a container quick action to the matching API call
+pub(super) fn run_quick_action(action: QuickAction, state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let result = match action.action {
+            QuickActionKind::Start => api::start_container(&action.target).await,
+            QuickActionKind::Stop => api::stop_container(&action.target).await,
+            QuickActionKind::Restart => api::restart_container(&action.target).await,
+            QuickActionKind::Edit | QuickActionKind::Run { .. } => {
+                unreachable!("edit and run are handled by their panes")
+            }
+        };
+
+        match result {
+            Ok(msg) => {
+                notifications::notify_success(&state_clone, format!("{}: {}", action.label, msg))
+            }
+            Err(e) => notifications::notify_error(
+                &state_clone,
+                format!(
+                    "{} failed: {}",
+                    action.label,
+                    utils::error::format_error(&e)
+                ),
+            ),
+        }
+        // Container state feeds the unhealthy/alerts widgets
+        refresh::refresh_pane(Pane::Dashboard, &state_clone);
+    });
+}
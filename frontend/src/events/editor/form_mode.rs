@@ -0,0 +1,67 @@
+use crate::api::FieldKind;
+use crate::state::{AppState, VimMode, schema_form::SchemaForm};
+use ratzilla::event::{KeyCode, KeyEvent};
+
+/// Keys of the form view of the open file
+pub(super) fn handle_form_mode(state: &mut AppState, key_event: KeyEvent) {
+    let Some(form) = state.editor.form.as_mut() else {
+        return;
+    };
+    if handle_form_keys(form, &mut state.vim_mode, key_event) {
+        state.editor.sync_from_form();
+    }
+}
+
+/// Navigate fields, edit text, toggle booleans, cycle enums. Editing a text
+/// field switches `vim_mode` to insert (shown with the insert mode border).
+/// Returns true if a value changed.
+pub(in crate::events) fn handle_form_keys(
+    form: &mut SchemaForm,
+    vim_mode: &mut VimMode,
+    key_event: KeyEvent,
+) -> bool {
+    // Typing into a field
+    if let Some(buffer) = form.edit_buffer.as_mut() {
+        match key_event.code {
+            KeyCode::Char(c) => buffer.push(c),
+            KeyCode::Backspace => {
+                buffer.pop();
+            }
+            KeyCode::Enter | KeyCode::Tab => {
+                form.commit_edit();
+                *vim_mode = VimMode::Normal;
+                return true;
+            }
+            KeyCode::Esc => {
+                form.cancel_edit();
+                *vim_mode = VimMode::Normal;
+            }
+            _ => {}
+        }
+        return false;
+    }
+
+    let kind = form.selected_field().map(|field| field.kind);
+    match (key_event.code, kind) {
+        (KeyCode::Char('j') | KeyCode::Down | KeyCode::Tab, _) => form.next(),
+        (KeyCode::Char('k') | KeyCode::Up, _) => form.previous(),
+        (KeyCode::Enter | KeyCode::Char(' '), Some(FieldKind::Boolean)) => {
+            form.toggle();
+            return true;
+        }
+        (KeyCode::Char('l') | KeyCode::Right | KeyCode::Enter, Some(FieldKind::Enum)) => {
+            form.cycle(true);
+            return true;
+        }
+        (KeyCode::Char('h') | KeyCode::Left, Some(FieldKind::Enum)) => {
+            form.cycle(false);
+            return true;
+        }
+        (KeyCode::Char('i') | KeyCode::Enter, Some(_)) => {
+            form.start_edit();
+            *vim_mode = VimMode::Insert;
+        }
+        _ => {}
+    }
+    false
+}
@@ -1,15 +1,41 @@
 use super::input::convert_key_event_to_input;
 use crate::state::{AppState, VimMode};
 use ratzilla::event::{KeyCode, KeyEvent};
+use tui_textarea::{Input, Key};
 
 pub(super) fn handle_insert_mode(state: &mut AppState, key_event: KeyEvent) {
     match key_event.code {
         KeyCode::Esc => {
             state.vim_mode = VimMode::Normal;
         }
-        _ => {
-            let input = convert_key_event_to_input(key_event);
-            state.editor.textarea.input(input);
-        }
+        _ => match convert_key_event_to_input(key_event) {
+            Input {
+                key: Key::PageDown, ..
+            }
+            | Input {
+                key: Key::Char('v'),
+                ctrl: true,
+                alt: false,
+                ..
+            } => state.editor.page(true),
+            Input {
+                key: Key::PageUp, ..
+            }
+            | Input {
+                key: Key::Char('v'),
+                ctrl: false,
+                alt: true,
+                ..
+            } => state.editor.page(false),
+            Input {
+                key: Key::Enter,
+                ctrl: false,
+                alt: false,
+                ..
+            } => state.editor.newline_with_indent(),
+            input => {
+                state.editor.textarea.input(input);
+            }
+        },
     }
 }
@@ -0,0 +1,142 @@
+use crate::state::{AppState, MacroKey, MacroPrefix, Macros, VimMode};
+use ratzilla::event::{KeyCode, KeyEvent};
+
+/// Macros replaying others nested deeper than this stop, so one that replays
+/// itself ends
+const MAX_DEPTH: usize = 20;
+
+/// Keys one replay gives the editor at most, nested replays included
+const MAX_KEYS: usize = 10_000;
+
+/// Keys other than characters and function keys, by their names in
+/// keybinds.toml
+const NAMED_KEYS: &[(&str, KeyCode)] = &[
+    ("Enter", KeyCode::Enter),
+    ("Esc", KeyCode::Esc),
+    ("Tab", KeyCode::Tab),
+    ("Backspace", KeyCode::Backspace),
+    ("Delete", KeyCode::Delete),
+    ("Left", KeyCode::Left),
+    ("Right", KeyCode::Right),
+    ("Up", KeyCode::Up),
+    ("Down", KeyCode::Down),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PageUp", KeyCode::PageUp),
+    ("PageDown", KeyCode::PageDown),
+];
+
+/// `q{register}` records the keys given to the editor until the next `q`,
+/// `@{register}` replays them and `@@` the macro replayed last. True if the
+/// key was taken
+pub(super) fn handle_macro_keys(state: &mut AppState, key_event: &KeyEvent) -> bool {
+    if let Some(prefix) = state.macros.pending.take() {
+        // A replay is recorded as its `@` and register, not the keys it gives
+        if prefix == MacroPrefix::Replay
+            && let Some(key) = macro_key(key_event)
+        {
+            state.macros.record(key);
+        }
+        match (prefix, &key_event.code) {
+            (_, KeyCode::Esc) => {}
+            (MacroPrefix::Record, KeyCode::Char(register)) if Macros::is_register(*register) => {
+                state.macros.start(*register);
+                state.set_status(format!("Recording @{}, q stops", register));
+            }
+            (MacroPrefix::Replay, KeyCode::Char('@')) => match state.macros.last {
+                Some(register) => replay(state, register),
+                None => state.set_status("[No macro replayed yet]"),
+            },
+            (MacroPrefix::Replay, KeyCode::Char(register)) if Macros::is_register(*register) => {
+                replay(state, *register)
+            }
+            _ => state.set_status("[Registers are letters and digits]"),
+        }
+        return true;
+    }
+
+    let plain = state.vim_mode == VimMode::Normal && !key_event.ctrl && !key_event.alt;
+    if plain && key_event.code == KeyCode::Char('q') {
+        match state.macros.stop() {
+            Some((register, count)) => {
+                state.set_status(format!("Recorded {} keys into @{}", count, register))
+            }
+            None => state.macros.pending = Some(MacroPrefix::Record),
+        }
+        return true;
+    }
+    // What is typed on the `:` line never reaches the editor, so its `:` is
+    // left out too
+    if plain && key_event.code == KeyCode::Char(':') {
+        return false;
+    }
+    if let Some(key) = macro_key(key_event) {
+        state.macros.record(key);
+    }
+    if plain && key_event.code == KeyCode::Char('@') {
+        state.macros.pending = Some(MacroPrefix::Replay);
+        return true;
+    }
+    false
+}
+
+/// Give the editor the keys of `register` as if they were typed
+fn replay(state: &mut AppState, register: char) {
+    let Some(keys) = state.macros.get(register).map(<[MacroKey]>::to_vec) else {
+        state.set_status(format!("[@{} is empty]", register));
+        return;
+    };
+    if state.macros.depth == 0 {
+        state.macros.replayed = 0;
+    } else if state.macros.depth >= MAX_DEPTH {
+        state.set_status("[Macro stopped, it replays itself too deep]");
+        return;
+    }
+    state.macros.last = Some(register);
+    state.macros.depth += 1;
+    for key in keys.iter().filter_map(key_event) {
+        if state.macros.replayed >= MAX_KEYS {
+            state.set_status(format!("[Macro stopped after {} keys]", MAX_KEYS));
+            break;
+        }
+        state.macros.replayed += 1;
+        super::handle_keys(state, key);
+    }
+    state.macros.depth -= 1;
+}
+
+/// The key as a macro stores it, None for keys it cannot name
+fn macro_key(event: &KeyEvent) -> Option<MacroKey> {
+    let key = match &event.code {
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        code => NAMED_KEYS
+            .iter()
+            .find(|(_, named)| named == code)?
+            .0
+            .to_string(),
+    };
+    Some(MacroKey {
+        key,
+        ctrl: event.ctrl,
+        alt: event.alt,
+        shift: event.shift,
+    })
+}
+
+/// The key press a stored macro key stands for
+fn key_event(key: &MacroKey) -> Option<KeyEvent> {
+    let mut chars = key.key.chars();
+    let function = key.key.strip_prefix('F').and_then(|n| n.parse().ok());
+    let code = match (chars.next(), chars.next(), function) {
+        (Some(c), None, _) => KeyCode::Char(c),
+        (_, _, Some(n)) => KeyCode::F(n),
+        _ => NAMED_KEYS.iter().find(|(name, _)| *name == key.key)?.1,
+    };
+    Some(KeyEvent {
+        code,
+        ctrl: key.ctrl,
+        alt: key.alt,
+        shift: key.shift,
+    })
+}
@@ -1,17 +1,46 @@
+mod form_mode;
 mod input;
 mod insert_mode;
+mod macros;
 mod normal_mode;
+mod secrets;
+mod table_mode;
+mod unlock;
 
 use crate::state::{AppState, VimMode};
+use form_mode::handle_form_mode;
 use insert_mode::handle_insert_mode;
+use macros::handle_macro_keys;
 use normal_mode::handle_normal_mode;
 use ratzilla::event::KeyEvent;
+use table_mode::handle_table_mode;
+
+pub(super) use form_mode::handle_form_keys;
+pub use secrets::toggle_secrets;
+pub use unlock::unlock_readonly;
 
 pub fn handle_keys(state: &mut AppState, key_event: KeyEvent) {
+    if state.editor.form.is_some() {
+        handle_form_mode(state, key_event);
+        state.check_dirty();
+        return;
+    }
+
+    if state.editor.table.is_some() {
+        handle_table_mode(state, key_event);
+        state.check_dirty();
+        return;
+    }
+
+    if handle_macro_keys(state, &key_event) {
+        return;
+    }
+
     match state.vim_mode {
         VimMode::Normal => handle_normal_mode(state, key_event),
         VimMode::Insert => handle_insert_mode(state, key_event),
     }
 
     state.check_dirty();
+    state.editor.refresh_preview(&state.current_theme);
 }
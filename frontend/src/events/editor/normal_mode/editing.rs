@@ -15,6 +15,23 @@ pub(super) fn handle_editing(state: &mut AppState, key_event: &KeyEvent) -> bool
             state.editor.textarea.redo();
             true
         }
+        // Select lines for the comment toggle, cursor moves extend it
+        KeyCode::Char('v') => {
+            let textarea = &mut state.editor.textarea;
+            if textarea.is_selecting() {
+                textarea.cancel_selection();
+            } else {
+                textarea.start_selection();
+            }
+            true
+        }
+        KeyCode::Char('=') => {
+            match state.editor.pretty_print() {
+                Ok(language) => state.set_status(format!("Formatted as {}", language.label())),
+                Err(e) => state.set_status(format!("[ERROR formatting: {}]", e)),
+            }
+            true
+        }
         _ => false,
     }
 }
@@ -2,14 +2,32 @@ mod editing;
 mod insert_commands;
 mod navigation;
 
-use crate::state::AppState;
+use crate::state::{AppState, CommandLine};
 use editing::handle_editing;
 use insert_commands::handle_insert_commands;
 use navigation::handle_navigation;
-use ratzilla::event::KeyEvent;
+use ratzilla::event::{KeyCode, KeyEvent};
 
 pub(super) fn handle_normal_mode(state: &mut AppState, key_event: KeyEvent) {
+    // Commands like :w and :e <file>, typed on a line above the status line
+    if key_event.code == KeyCode::Char(':') && !key_event.ctrl && !key_event.alt {
+        state.command_line = Some(CommandLine::default());
+        return;
+    }
+    // The masks would be saved in place of the secrets
+    if state.editor.redacted {
+        if !handle_navigation(state, &key_event) && !key_event.alt && !key_event.ctrl {
+            let reveal = state.keybinds.global.toggle_secrets.clone();
+            state.set_status(format!(
+                "[Secrets masked, {} reveals them for editing]",
+                reveal
+            ));
+        }
+        return;
+    }
     if handle_insert_commands(state, &key_event) {
+        // Typing would replace the selected text
+        state.editor.textarea.cancel_selection();
         return;
     }
     if handle_navigation(state, &key_event) {
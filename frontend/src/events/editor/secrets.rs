@@ -0,0 +1,57 @@
+use crate::{api, state::AppState, utils};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Reload the open file with its secrets revealed, or masked again
+///
+/// Revealing is audit-logged by the server. Either way the file is loaded
+/// anew, so unsaved edits have to be saved or discarded first.
+pub fn toggle_secrets(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(filename) = state.editor.current_file.clone() else {
+        return;
+    };
+    if !state.editor.redacted && !state.editor.revealed {
+        state.set_status("[No masked secrets in this file]");
+        return;
+    }
+    if state.dirty {
+        state.set_status("[Save or discard the edits first]");
+        return;
+    }
+
+    let reveal = state.editor.redacted;
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let result = api::fetch_file_content(&filename, None, reveal).await;
+        let mut st = state_clone.borrow_mut();
+        // Another file was opened or edited meanwhile
+        if st.editor.current_file.as_deref() != Some(filename.as_str()) || st.dirty {
+            return;
+        }
+        match result {
+            Ok(api::Conditional::Modified { data, etag }) => {
+                let (row, col) = st.editor.textarea.cursor();
+                st.editor.load_content(filename, data.content);
+                st.editor.set_format(data.format);
+                st.editor.etag = etag;
+                st.editor.redacted = data.redacted;
+                st.editor.revealed = reveal;
+                st.editor
+                    .textarea
+                    .move_cursor(tui_textarea::CursorMove::Jump(row as u16, col as u16));
+                st.sync_file_settings();
+                st.set_status(if reveal {
+                    "Secrets revealed"
+                } else {
+                    "Secrets masked"
+                });
+            }
+            // Not asked conditionally
+            Ok(api::Conditional::NotModified) => {}
+            Err(e) => st.set_status(format!(
+                "[ERROR loading secrets: {}]",
+                utils::error::format_error(&e)
+            )),
+        }
+    });
+}
@@ -0,0 +1,54 @@
+use crate::state::{AppState, VimMode};
+use ratzilla::event::{KeyCode, KeyEvent};
+
+/// Keys of the table editing mode: navigate rows/columns, edit cells, add/remove rows
+pub(super) fn handle_table_mode(state: &mut AppState, key_event: KeyEvent) {
+    let Some(table) = state.editor.table.as_mut() else {
+        return;
+    };
+
+    // Typing into a cell (shown with the insert mode border)
+    if let Some(buffer) = table.edit_buffer.as_mut() {
+        match key_event.code {
+            KeyCode::Char(c) => buffer.push(c),
+            KeyCode::Backspace => {
+                buffer.pop();
+            }
+            KeyCode::Enter | KeyCode::Tab => {
+                table.commit_edit();
+                state.vim_mode = VimMode::Normal;
+                state.editor.sync_from_table();
+            }
+            KeyCode::Esc => {
+                table.cancel_edit();
+                state.vim_mode = VimMode::Normal;
+            }
+            _ => {}
+        }
+        return;
+    }
+
+    match key_event.code {
+        KeyCode::Char('j') | KeyCode::Down => table.next(),
+        KeyCode::Char('k') | KeyCode::Up => table.previous(),
+        KeyCode::Char('h') | KeyCode::Char('l') | KeyCode::Left | KeyCode::Right | KeyCode::Tab => {
+            table.toggle_column()
+        }
+        KeyCode::Char('i') | KeyCode::Enter => {
+            table.start_edit();
+            state.vim_mode = VimMode::Insert;
+        }
+        KeyCode::Char('o') => {
+            table.add_row();
+            state.vim_mode = VimMode::Insert;
+            state.editor.sync_from_table();
+        }
+        KeyCode::Char('d') => {
+            let deleted = table.delete_row();
+            if deleted {
+                state.editor.sync_from_table();
+            }
+        }
+        _ => {}
+    }
+}
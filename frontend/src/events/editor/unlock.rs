@@ -0,0 +1,56 @@
+use crate::{api::ReadonlyOverride, state::AppState};
+use web_sys::window;
+
+/// Unlock the open readonly file after an explicit confirmation
+///
+/// The server decides whether overrides are allowed and audits each one;
+/// this only collects the X-Sysrat-Override value sent with the next saves.
+pub fn unlock_readonly(state: &mut AppState) {
+    let Some(filename) = state.editor.current_file.clone() else {
+        return;
+    };
+
+    let readonly = state
+        .file_list
+        .all
+        .iter()
+        .any(|f| f.name == filename && f.readonly);
+    if !readonly {
+        state.set_status(format!("[{} is not read-only]", filename));
+        return;
+    }
+    if state.editor.override_value.is_some() {
+        state.set_status(format!("[{} already unlocked]", filename));
+        return;
+    }
+
+    let Some(win) = window() else {
+        return;
+    };
+    let value = match state.file_list.readonly_override {
+        ReadonlyOverride::Disabled => {
+            state.set_status("[readonly override disabled on server]");
+            return;
+        }
+        ReadonlyOverride::Confirm => win
+            .confirm_with_message(&format!(
+                "{} is read-only.\nUnlock it for writing? The override is logged.",
+                filename
+            ))
+            .unwrap_or(false)
+            .then(|| "confirm".to_string()),
+        ReadonlyOverride::Token => win
+            .prompt_with_message(&format!("Override token to unlock {}:", filename))
+            .ok()
+            .flatten()
+            .filter(|token| !token.is_empty()),
+    };
+
+    match value {
+        Some(value) => {
+            state.editor.override_value = Some(value);
+            state.set_status(format!("[UNLOCKED {} - saves override readonly]", filename));
+        }
+        None => state.set_status("[unlock cancelled]"),
+    }
+}
@@ -0,0 +1,26 @@
+use crate::state::{AppState, Pane, refresh};
+use ratzilla::event::KeyEvent;
+use std::{cell::RefCell, rc::Rc};
+
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.file_history;
+
+    // Scroll bindings use modifiers on the navigation keys, so check them first
+    if super::key_matches(&key_event, &keybinds.scroll_down) {
+        state.file_history.scroll_down();
+    } else if super::key_matches(&key_event, &keybinds.scroll_up) {
+        state.file_history.scroll_up();
+    } else if super::key_matches(&key_event, &keybinds.navigate_down)
+        || super::key_matches(&key_event, &keybinds.navigate_down_alt)
+    {
+        state.file_history.next();
+    } else if super::key_matches(&key_event, &keybinds.navigate_up)
+        || super::key_matches(&key_event, &keybinds.navigate_up_alt)
+    {
+        state.file_history.previous();
+    } else if super::key_matches(&key_event, &keybinds.refresh) {
+        refresh::refresh_pane(Pane::FileHistory, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.back) {
+        state.set_focus(Pane::Editor, state_rc);
+    }
+}
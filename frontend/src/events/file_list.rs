@@ -1,5 +1,5 @@
 use crate::api;
-use crate::state::{AppState, Pane, refresh, status_helper};
+use crate::state::{AppState, ConfirmAction, Pane, marks, notifications, refresh};
 use crate::utils;
 use ratzilla::event::KeyEvent;
 use std::{cell::RefCell, rc::Rc};
@@ -9,8 +9,8 @@ pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_e
     let keybinds = &state.keybinds.file_list;
 
     if super::key_matches(&key_event, &keybinds.back_to_menu) {
-        state.focus = Pane::Menu;
         state.status_message = None;
+        leave_guarded(state, Pane::Menu, state_rc);
     } else if super::key_matches(&key_event, &keybinds.navigate_down)
         || super::key_matches(&key_event, &keybinds.navigate_down_alt)
     {
@@ -21,33 +21,143 @@ pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_e
     {
         state.file_list.previous();
         refresh::save_selection(Pane::FileList, state);
+    } else if super::key_matches(&key_event, &keybinds.toggle_pin) {
+        if let Some((name, pinned)) = state.file_list.toggle_pin_selected() {
+            refresh::save_selection(Pane::FileList, state);
+            state.set_status(marks::pin_status(&name, pinned));
+        }
+    } else if super::key_matches(&key_event, &keybinds.toggle_hide) {
+        if let Some((name, hidden)) = state.file_list.toggle_hide_selected() {
+            refresh::save_selection(Pane::FileList, state);
+            state.set_status(marks::hide_status(&name, hidden));
+        }
+    } else if super::key_matches(&key_event, &keybinds.toggle_show_hidden) {
+        let show = state.file_list.toggle_show_hidden();
+        state.set_status(marks::show_hidden_status(show));
+    } else if super::key_matches(&key_event, &keybinds.toggle_details) {
+        let show = state.file_list.toggle_details();
+        state.set_status(if show {
+            "Showing file details"
+        } else {
+            "Hiding file details"
+        });
+    } else if super::key_matches(&key_event, &keybinds.cycle_order) {
+        let order = state.file_list.cycle_order();
+        state.set_status(format!("Files ordered by {}", order.label()));
+    } else if super::key_matches(&key_event, &keybinds.refresh) {
+        refresh::refresh_pane(Pane::FileList, state_rc);
     } else if super::key_matches(&key_event, &keybinds.select)
         && let Some(fileinfo) = state.file_list.selected().cloned()
     {
-        let state_clone = Rc::clone(state_rc);
-        spawn_local(async move {
-            match api::fetch_file_content(&fileinfo.name).await {
-                Ok(content) => {
-                    {
-                        let mut st = state_clone.borrow_mut();
-                        st.editor.load_content(fileinfo.name.clone(), content);
-                        st.dirty = false;
-                        st.focus = Pane::Editor;
-                    }
-                    status_helper::set_status_timed(&state_clone, "[loaded]");
+        open_file_guarded(state, fileinfo.name, state_rc);
+    }
+}
+
+/// Mouse click on a file: select it, or open it when it is already selected
+pub(super) fn click(state: &mut AppState, index: usize, state_rc: &Rc<RefCell<AppState>>) {
+    if index >= state.file_list.files.len() {
+        return;
+    }
+    if index != state.file_list.selected_index {
+        state.file_list.selected_index = index;
+        refresh::save_selection(Pane::FileList, state);
+    } else if let Some(fileinfo) = state.file_list.selected().cloned() {
+        open_file_guarded(state, fileinfo.name, state_rc);
+    }
+}
+
+/// Open a file, asking to save or discard unsaved edits of the open one first
+pub(super) fn open_file_guarded(
+    state: &mut AppState,
+    filename: String,
+    state_rc: &Rc<RefCell<AppState>>,
+) {
+    let switching = state.editor.current_file.as_deref() != Some(filename.as_str());
+    if let Some(current) = state.editor.current_file.clone()
+        && state.dirty
+        && switching
+    {
+        state
+            .confirms
+            .push_unsaved(&current, ConfirmAction::OpenFile(filename));
+        return;
+    }
+    open_file(filename, state_rc);
+}
+
+/// Leave the config file panes, asking to save or discard unsaved edits first
+pub(super) fn leave_guarded(state: &mut AppState, pane: Pane, state_rc: &Rc<RefCell<AppState>>) {
+    if let Some(current) = state.editor.current_file.clone()
+        && state.dirty
+    {
+        state
+            .confirms
+            .push_unsaved(&current, ConfirmAction::Focus(pane));
+        return;
+    }
+    state.set_focus(pane, state_rc);
+}
+
+/// Load a file into the editor and focus it
+pub(super) fn open_file(filename: String, state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        // The file still open and unedited in the editor needs no download
+        // if it did not change on the server
+        let etag = {
+            let st = state_clone.borrow();
+            let reopened = !st.dirty && st.editor.current_file.as_deref() == Some(&filename);
+            reopened.then(|| st.editor.etag.clone()).flatten()
+        };
+        match api::fetch_file_content(&filename, etag, false).await {
+            Ok(api::Conditional::NotModified) => {
+                let mut st = state_clone.borrow_mut();
+                st.set_focus(Pane::Editor, &state_clone);
+                jump_to_problem(&mut st, &filename);
+            }
+            Ok(api::Conditional::Modified { data, etag }) => {
+                {
+                    let mut st = state_clone.borrow_mut();
+                    st.editor.load_content(filename.clone(), data.content);
+                    st.editor.set_format(data.format);
+                    st.editor.etag = etag;
+                    st.editor.redacted = data.redacted;
+                    st.dirty = false;
+                    st.set_focus(Pane::Editor, &state_clone);
+                    jump_to_problem(&mut st, &filename);
                 }
-                Err(e) => {
-                    {
-                        let mut st = state_clone.borrow_mut();
-                        st.editor.clear();
-                        st.dirty = false;
-                    }
-                    status_helper::set_status_timed(
-                        &state_clone,
-                        format!("[ERROR loading: {}]", utils::error::format_error(&e)),
-                    );
+                notifications::notify_info(&state_clone, format!("Loaded {}", filename));
+            }
+            // Binary files cannot be edited, show their bytes instead
+            Err(e) if api::is_binary_error(&e) => {
+                let mut st = state_clone.borrow_mut();
+                st.editor.clear();
+                st.dirty = false;
+                st.hex_viewer.open(filename);
+                st.set_focus(Pane::HexViewer, &state_clone);
+            }
+            Err(e) => {
+                {
+                    let mut st = state_clone.borrow_mut();
+                    st.editor.clear();
+                    st.dirty = false;
+                    st.sync_file_settings();
                 }
+                notifications::notify_error(
+                    &state_clone,
+                    format!("Loading failed: {}", utils::error::format_error(&e)),
+                );
             }
-        });
+        }
+    });
+}
+
+/// Move the cursor to the line of the problem the file was opened for
+fn jump_to_problem(state: &mut AppState, filename: &str) {
+    if let Some(row) = state.problems.take_jump(filename) {
+        state
+            .editor
+            .textarea
+            .move_cursor(tui_textarea::CursorMove::Jump(row as u16, 0));
     }
 }
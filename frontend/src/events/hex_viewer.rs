@@ -0,0 +1,37 @@
+use crate::state::{AppState, Pane, refresh};
+use ratzilla::event::KeyEvent;
+use std::{cell::RefCell, rc::Rc};
+
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.hex_viewer;
+    let viewer = &state.hex_viewer;
+
+    // Paging fetches the page, keeping the current one on screen until it arrives
+    let page = if super::key_matches(&key_event, &keybinds.next_page) {
+        viewer.next_page()
+    } else if super::key_matches(&key_event, &keybinds.previous_page) {
+        viewer.previous_page()
+    } else if super::key_matches(&key_event, &keybinds.first_page) {
+        Some(0)
+    } else if super::key_matches(&key_event, &keybinds.last_page) {
+        Some(viewer.last_page())
+    } else {
+        None
+    };
+    if let Some(offset) = page {
+        refresh::load_hex_page(state_rc, Some(offset));
+        return;
+    }
+
+    if super::key_matches(&key_event, &keybinds.navigate_down)
+        || super::key_matches(&key_event, &keybinds.navigate_down_alt)
+    {
+        state.hex_viewer.scroll_down();
+    } else if super::key_matches(&key_event, &keybinds.navigate_up)
+        || super::key_matches(&key_event, &keybinds.navigate_up_alt)
+    {
+        state.hex_viewer.scroll_up();
+    } else if super::key_matches(&key_event, &keybinds.back) {
+        state.set_focus(Pane::FileList, state_rc);
+    }
+}
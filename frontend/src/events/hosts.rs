@@ -0,0 +1,59 @@
+use crate::{
+    api,
+    state::{AppState, HostPicker, Overlay},
+    storage, utils,
+};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Open the host switcher and fetch the hosts of the serving server
+pub(super) fn open(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    state.vim_mode = crate::state::VimMode::Normal;
+    state.host_picker = Some(HostPicker::new());
+    state.overlay.open(Overlay::Hosts);
+
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let hosts = api::fetch_hosts()
+            .await
+            .map_err(|e| utils::error::format_error(&e));
+        let active = storage::hosts::active().map(|host| host.name);
+        if let Some(picker) = state_clone.borrow_mut().host_picker.as_mut() {
+            picker.set_hosts(hosts, active.as_deref());
+        }
+    });
+}
+
+/// Pick a host with j/k and switch to it with Enter
+pub(super) fn picker_keys(state: &mut AppState, key_event: KeyEvent) {
+    let Some(picker) = state.host_picker.as_mut() else {
+        return;
+    };
+    match key_event.code {
+        KeyCode::Char('j') | KeyCode::Down => picker.next(),
+        KeyCode::Char('k') | KeyCode::Up => picker.previous(),
+        KeyCode::Enter => {
+            let Some(host) = picker.selected().map(|host| host.cloned()) else {
+                return;
+            };
+            let active = storage::hosts::active();
+            if host.as_ref().map(|h| &h.name) == active.as_ref().map(|h| &h.name) {
+                state.overlay.close();
+                state.host_picker = None;
+                return;
+            }
+            // Switching reloads the page, the edits would only live on in
+            // the buffer of the host they were made on
+            if state.dirty {
+                state.set_status("[Save or discard your edits before switching hosts]");
+                return;
+            }
+            storage::hosts::pick(host.as_ref());
+            if let Some(window) = web_sys::window() {
+                let _ = window.location().reload();
+            }
+        }
+        _ => {}
+    }
+}
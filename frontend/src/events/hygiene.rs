@@ -0,0 +1,63 @@
+use crate::api;
+use crate::state::{AppState, HygieneReview, Overlay};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Run the `[hygiene]` check on content about to be saved. Returns what to
+/// save right away, or None when the fixes change visible lines and wait
+/// in the hygiene overlay for a decision.
+pub async fn check(
+    state_rc: &Rc<RefCell<AppState>>,
+    filename: &str,
+    content: String,
+    override_value: &Option<String>,
+) -> Option<String> {
+    let check = match api::check_hygiene(filename, content.clone()).await {
+        Ok(check) => check,
+        // A failed check does not stand in the way of the save
+        Err(_) => return Some(content),
+    };
+    // Only a final newline, which the editor does not show anyway
+    if check.diff.is_empty() {
+        return Some(check.content);
+    }
+
+    let mut st = state_rc.borrow_mut();
+    st.hygiene = Some(HygieneReview {
+        filename: filename.to_string(),
+        content,
+        override_value: override_value.clone(),
+        check,
+    });
+    st.overlay.open(Overlay::Hygiene);
+    None
+}
+
+/// Save the reviewed file with the fixes, which the editor then shows too,
+/// or exactly as it was written
+pub fn finish(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, apply_fixes: bool) {
+    let Some(review) = state.hygiene.take() else {
+        return;
+    };
+    state.overlay.close();
+
+    let content = if apply_fixes {
+        if state.editor.current_file.as_deref() == Some(&review.filename) {
+            state.editor.replace_text(&review.check.content);
+        }
+        review.check.content
+    } else {
+        review.content
+    };
+
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        super::menu::save(
+            &state_clone,
+            review.filename,
+            content,
+            review.override_value,
+        )
+        .await;
+    });
+}
@@ -0,0 +1,69 @@
+use crate::api;
+use crate::state::{AppState, Pane, images::RunningScan, notifications, refresh};
+use crate::utils;
+use ratzilla::event::KeyEvent;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.images;
+
+    if super::key_matches(&key_event, &keybinds.navigate_down)
+        || super::key_matches(&key_event, &keybinds.navigate_down_alt)
+    {
+        state.images.next();
+    } else if super::key_matches(&key_event, &keybinds.navigate_up)
+        || super::key_matches(&key_event, &keybinds.navigate_up_alt)
+    {
+        state.images.previous();
+    } else if super::key_matches(&key_event, &keybinds.refresh) {
+        refresh::refresh_pane(Pane::Images, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.back_to_menu) {
+        state.set_focus(Pane::Menu, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.scan) {
+        scan_selected(state, state_rc);
+    }
+}
+
+/// Start a scan of the selected image, one scan at a time
+fn scan_selected(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(image) = state.images.selected() else {
+        return;
+    };
+    if state.images.scanner.is_none() {
+        state.set_status("[Neither trivy nor grype is installed on the server]");
+        return;
+    }
+    if state.images.scan.is_some() {
+        state.set_status("[A scan is still running]");
+        return;
+    }
+
+    let id = image.id.clone();
+    let name = format!("{}:{}", image.repository, image.tag);
+    state.set_status(format!("Starting scan of {}...", name));
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match api::scan_image(&id).await {
+            Ok(job_id) => {
+                let mut st = state_clone.borrow_mut();
+                st.images.scan = Some(RunningScan {
+                    image_id: id,
+                    job_id,
+                });
+                st.set_status(format!("Scanning {} (job #{})", name, job_id));
+                if st.focus == Pane::Images {
+                    refresh::follow_scan(&mut st, &state_clone, job_id);
+                }
+            }
+            Err(e) => notifications::notify_error(
+                &state_clone,
+                format!(
+                    "Scanning {} failed: {}",
+                    name,
+                    utils::error::format_error(&e)
+                ),
+            ),
+        }
+    });
+}
@@ -0,0 +1,30 @@
+use crate::state::{AppState, Pane, refresh};
+use ratzilla::event::KeyEvent;
+use std::{cell::RefCell, rc::Rc};
+
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.insights;
+
+    if super::key_matches(&key_event, &keybinds.navigate_down)
+        || super::key_matches(&key_event, &keybinds.navigate_down_alt)
+    {
+        state.insights.next();
+    } else if super::key_matches(&key_event, &keybinds.navigate_up)
+        || super::key_matches(&key_event, &keybinds.navigate_up_alt)
+    {
+        state.insights.previous();
+    } else if super::key_matches(&key_event, &keybinds.cycle_window) {
+        let days = state.insights.cycle_window();
+        state.set_status(format!("Counting changes of the last {} days", days));
+        refresh::refresh_pane(Pane::Insights, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.refresh) {
+        refresh::refresh_pane(Pane::Insights, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.back_to_menu) {
+        state.set_focus(Pane::Menu, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.open)
+        && let Some(file) = state.insights.selected()
+    {
+        let name = file.name.clone();
+        super::file_list::open_file_guarded(state, name, state_rc);
+    }
+}
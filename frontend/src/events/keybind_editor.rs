@@ -0,0 +1,70 @@
+use crate::{
+    keybinds::{Keybinds, overrides},
+    state::{AppState, Pane},
+};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.keybind_editor;
+
+    if super::key_matches(&key_event, &keybinds.navigate_down)
+        || super::key_matches(&key_event, &keybinds.navigate_down_alt)
+    {
+        state.keybind_editor.next();
+    } else if super::key_matches(&key_event, &keybinds.navigate_up)
+        || super::key_matches(&key_event, &keybinds.navigate_up_alt)
+    {
+        state.keybind_editor.previous();
+    } else if super::key_matches(&key_event, &keybinds.rebind) {
+        if state.keybind_editor.selected().is_some() {
+            state.keybind_editor.capturing = true;
+        }
+    } else if super::key_matches(&key_event, &keybinds.reset) {
+        if let Some(entry) = state.keybind_editor.selected()
+            && entry.overridden
+        {
+            let id = entry.id();
+            overrides::remove(&id);
+            reload(state);
+            state.set_status(format!("Reset {}", id));
+        }
+    } else if super::key_matches(&key_event, &keybinds.reset_all) {
+        overrides::clear();
+        reload(state);
+        state.set_status("Reset all keybinds");
+    } else if super::key_matches(&key_event, &keybinds.back_to_menu) {
+        state.set_focus(Pane::Menu, state_rc);
+    }
+}
+
+/// Bind the selected action to the pressed key, unless it clashes with another binding
+pub fn capture_key(state: &mut AppState, key_event: &KeyEvent) {
+    state.keybind_editor.capturing = false;
+    if key_event.code == KeyCode::Esc {
+        return;
+    }
+
+    let Some(key) = super::binding_for(key_event) else {
+        state.set_status("[Key cannot be bound]");
+        return;
+    };
+    let Some(id) = state.keybind_editor.selected().map(|entry| entry.id()) else {
+        return;
+    };
+    if let Some(other) = state.keybind_editor.conflict_for(&key) {
+        let other = other.id();
+        state.set_status(format!("[{} is already bound to {}]", key, other));
+        return;
+    }
+
+    overrides::set(&id, &key);
+    reload(state);
+    state.set_status(format!("Bound {} to {}", id, key));
+}
+
+/// Apply the changed overrides to the live keybinds and the list
+fn reload(state: &mut AppState) {
+    state.keybinds = Keybinds::load();
+    state.keybind_editor.open(&state.keybinds);
+}
@@ -0,0 +1,70 @@
+use crate::state::{AppState, Pane, refresh};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+
+/// Lines moved by one scroll key
+const SCROLL_STEP: usize = 10;
+
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.logs;
+
+    if super::key_matches(&key_event, &keybinds.navigate_down)
+        || super::key_matches(&key_event, &keybinds.navigate_down_alt)
+    {
+        state.logs.next();
+    } else if super::key_matches(&key_event, &keybinds.navigate_up)
+        || super::key_matches(&key_event, &keybinds.navigate_up_alt)
+    {
+        state.logs.previous();
+    } else if super::key_matches(&key_event, &keybinds.open) {
+        refresh::follow_log(state, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.toggle_pause) {
+        if state.logs.following.is_none() {
+            return;
+        }
+        let paused = state.logs.toggle_pause();
+        state.set_status(if paused { "[paused]" } else { "[following]" });
+    } else if super::key_matches(&key_event, &keybinds.search) {
+        state.logs.search_input = true;
+        state.logs.search.get_or_insert_with(String::new);
+    } else if super::key_matches(&key_event, &keybinds.scroll_up) {
+        state.logs.scroll_up(SCROLL_STEP);
+    } else if super::key_matches(&key_event, &keybinds.scroll_down) {
+        state.logs.scroll_down(SCROLL_STEP);
+    } else if super::key_matches(&key_event, &keybinds.refresh) {
+        refresh::refresh_pane(Pane::Logs, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.back_to_menu) {
+        if state.logs.search.take().is_some() {
+            state.logs.scroll = 0;
+        } else {
+            state.set_focus(Pane::Menu, state_rc);
+        }
+    }
+}
+
+/// Typing the search, taking every key until it is applied or cleared
+pub fn type_search(state: &mut AppState, key_event: &KeyEvent) {
+    let logs = &mut state.logs;
+    match key_event.code {
+        KeyCode::Char(c) if !key_event.ctrl && !key_event.alt => {
+            logs.search.get_or_insert_with(String::new).push(c);
+        }
+        KeyCode::Backspace => {
+            if let Some(search) = logs.search.as_mut() {
+                search.pop();
+            }
+        }
+        KeyCode::Enter => {
+            logs.search_input = false;
+            if logs.search.as_deref() == Some("") {
+                logs.search = None;
+            }
+        }
+        KeyCode::Esc => {
+            logs.search_input = false;
+            logs.search = None;
+        }
+        _ => return,
+    }
+    logs.scroll = 0;
+}
@@ -1,6 +1,6 @@
 use crate::{
     api,
-    state::{AppState, Pane, refresh, status_helper},
+    state::{AppState, Pane, notifications, refresh},
     utils,
 };
 use ratzilla::event::KeyEvent;
@@ -18,41 +18,153 @@ pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_e
         || super::key_matches(&key_event, &keybinds.navigate_up_alt)
     {
         state.menu.previous();
-    } else if super::key_matches(&key_event, &keybinds.select)
-        && let Some(selected) = state.menu.selected()
-    {
-        match selected.as_str() {
-            "Config Files" => {
-                state.focus = Pane::FileList;
-                // Always refresh to get latest files from server
-                refresh::refresh_pane(Pane::FileList, state_rc);
-            }
-            "Container" => {
-                state.focus = Pane::ContainerList;
-                refresh::refresh_pane(Pane::ContainerList, state_rc);
-            }
-            _ => {}
+    } else if super::key_matches(&key_event, &keybinds.select) {
+        open_selected(state, state_rc);
+    }
+}
+
+/// Mouse click on a menu item: select and open it
+pub(super) fn click(state: &mut AppState, index: usize, state_rc: &Rc<RefCell<AppState>>) {
+    if index < state.menu.items.len() {
+        state.menu.selected_index = index;
+        open_selected(state, state_rc);
+    }
+}
+
+/// Enter the pane of the selected menu item
+fn open_selected(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(selected) = state.menu.selected() else {
+        return;
+    };
+    match selected.as_str() {
+        "Dashboard" => {
+            // Entering the pane refreshes immediately and starts polling
+            state.set_focus(Pane::Dashboard, state_rc);
+        }
+        "Config Files" => {
+            state.set_focus(Pane::FileList, state_rc);
+            // Always refresh to get latest files from server
+            refresh::refresh_pane(Pane::FileList, state_rc);
+        }
+        "Container" => {
+            // Entering the pane refreshes immediately and starts polling
+            state.set_focus(Pane::ContainerList, state_rc);
+        }
+        "Container Graph" => {
+            // Entering the pane loads the graph once (no polling)
+            state.set_focus(Pane::ContainerGraph, state_rc);
+        }
+        "Images" => {
+            // Entering the pane lists the images with their last scan
+            state.set_focus(Pane::Images, state_rc);
+        }
+        "Theme Editor" => {
+            // Entering the pane opens the active theme for editing
+            state.set_focus(Pane::ThemeEditor, state_rc);
+        }
+        "Actions" => {
+            // Entering the pane loads the configured actions
+            state.set_focus(Pane::Actions, state_rc);
+        }
+        "Runbooks" => {
+            // Entering the pane loads the configured runbooks
+            state.set_focus(Pane::Runbooks, state_rc);
         }
+        "Insights" => {
+            // Entering the pane counts the changes of the managed files
+            state.set_focus(Pane::Insights, state_rc);
+        }
+        "Logs" => {
+            // Entering the pane lists the log files from sysrat.toml
+            state.set_focus(Pane::Logs, state_rc);
+        }
+        "Keybinds" => {
+            // Entering the pane lists the current (merged) bindings
+            state.set_focus(Pane::KeybindEditor, state_rc);
+        }
+        _ => {}
     }
 }
 
-pub fn save_file(state: Rc<RefCell<AppState>>, filename: String, content: String) {
+/// Save the editor content once the `[hygiene]` fixes are settled
+pub fn save_file(
+    state: Rc<RefCell<AppState>>,
+    filename: String,
+    content: String,
+    override_value: Option<String>,
+) {
     spawn_local(async move {
-        match api::save_file_content(&filename, content.clone()).await {
-            Ok(_) => {
-                {
-                    let mut st = state.borrow_mut();
-                    st.editor.original_content = content;
-                    st.dirty = false;
-                }
-                status_helper::set_status_timed(&state, format!("Saved: {}", filename));
-            }
-            Err(e) => {
-                status_helper::set_status_timed(
-                    &state,
-                    format!("[ERROR saving: {}]", utils::error::format_error(&e)),
-                );
-            }
+        if let Some(content) =
+            super::hygiene::check(&state, &filename, content, &override_value).await
+        {
+            save(&state, filename, content, override_value).await;
         }
     });
 }
+
+/// Save the editor content and report the outcome, true on success
+pub async fn save(
+    state: &Rc<RefCell<AppState>>,
+    filename: String,
+    content: String,
+    override_value: Option<String>,
+) -> bool {
+    let started = js_sys::Date::now();
+    let (format, change) = {
+        let st = state.borrow();
+        (st.editor.format, st.editor.format_change())
+    };
+    match api::save_file_content(&filename, content.clone(), change, override_value).await {
+        Ok(_) => {
+            {
+                let mut st = state.borrow_mut();
+                // As the editor holds it, without the final newline
+                st.editor.original_content = content.lines().collect::<Vec<_>>().join("\n");
+                st.editor.original_format = format;
+                // Describes the content before the save
+                st.editor.etag = None;
+                st.editor.write_denied = None;
+                st.dirty = false;
+            }
+            refresh::refresh_pane(Pane::Editor, state);
+            notifications::notify_success(
+                state,
+                format!(
+                    "Saved: {}{}",
+                    filename,
+                    api::timing::slow_note("save", started)
+                ),
+            );
+            true
+        }
+        Err(e) => {
+            report_write_error(state, "Saving", &filename, &e);
+            false
+        }
+    }
+}
+
+/// Toast for a failed save or upload; a write the server may not make is
+/// also explained under the editor when the file is open
+pub(super) fn report_write_error(
+    state: &Rc<RefCell<AppState>>,
+    what: &str,
+    filename: &str,
+    e: &wasm_bindgen::JsValue,
+) {
+    let Some(denied) = api::write_denied(e) else {
+        notifications::notify_error(
+            state,
+            format!("{} failed: {}", what, utils::error::format_error(e)),
+        );
+        return;
+    };
+    let message = format!("{} failed: no permission to write {}", what, denied.path);
+    {
+        let mut st = state.borrow_mut();
+        if st.editor.current_file.as_deref() == Some(filename) {
+            st.editor.write_denied = Some(denied);
+        }
+    }
+    notifications::notify_error(state, message);
+}
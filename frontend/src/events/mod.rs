@@ -1,11 +1,40 @@
+mod actions;
+mod command_line;
+mod confirm;
+mod container_details;
+mod container_graph;
 mod container_list;
+mod container_watch;
+mod dashboard;
 mod editor;
+mod file_history;
 mod file_list;
+mod hex_viewer;
+mod hosts;
+mod hygiene;
+mod images;
+mod insights;
+mod keybind_editor;
+mod logs;
 mod menu;
+mod mouse;
+mod overlay;
+mod problems;
+mod refresh;
+mod runbooks;
+mod theme_editor;
+mod transfer;
+mod upstream;
 
-use crate::state::{AppState, Pane};
+pub use mouse::{handle_mouse_event, handle_wheel};
+
+use crate::api::TextEncoding;
+use crate::state::{AppState, Pane, SubPane};
 use ratzilla::event::{KeyCode, KeyEvent};
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::{RefCell, RefMut},
+    rc::Rc,
+};
 
 /// Check if a key event matches a keybind string from keybinds.toml.
 /// Supports: single chars, special keys, and modifier combinations.
@@ -36,6 +65,39 @@ pub fn key_matches(event: &KeyEvent, binding: &str) -> bool {
     match_key_without_mods(event, binding)
 }
 
+/// Binding string for a key press, the inverse of `key_matches`
+/// (None for keys bindings cannot express)
+pub fn binding_for(event: &KeyEvent) -> Option<String> {
+    let key = match event.code {
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Delete => "Delete".to_string(),
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::PageUp => "PageUp".to_string(),
+        KeyCode::PageDown => "PageDown".to_string(),
+        _ => return None,
+    };
+
+    // Bindings take a single modifier; shift is part of the character itself
+    let prefix = if event.ctrl {
+        "Ctrl-"
+    } else if event.alt {
+        "Alt-"
+    } else if event.shift && !matches!(event.code, KeyCode::Char(_)) {
+        "Shift-"
+    } else {
+        ""
+    };
+    Some(format!("{}{}", prefix, key))
+}
+
 /// Match key code without modifier check
 pub fn match_key_without_mods(event: &KeyEvent, key_str: &str) -> bool {
     match key_str {
@@ -48,6 +110,8 @@ pub fn match_key_without_mods(event: &KeyEvent, key_str: &str) -> bool {
         "Right" => event.code == KeyCode::Right,
         "Up" => event.code == KeyCode::Up,
         "Down" => event.code == KeyCode::Down,
+        "PageUp" => event.code == KeyCode::PageUp,
+        "PageDown" => event.code == KeyCode::PageDown,
         s if s.starts_with('F') && s.len() > 1 => {
             // Function keys: F1, F2, etc.
             if let Ok(num) = s[1..].parse::<u8>() {
@@ -68,26 +132,328 @@ pub fn match_key_without_mods(event: &KeyEvent, key_str: &str) -> bool {
 pub fn handle_key_event(state: Rc<RefCell<AppState>>, key_event: KeyEvent) {
     let mut state_mut = state.borrow_mut();
 
+    // Any user action retries immediately if the server was unreachable
+    crate::state::refresh::retry_if_backing_off(&mut state_mut, &state);
+
+    // A key being captured for the keybind editor must not trigger its binding
+    if state_mut.focus == Pane::KeybindEditor && state_mut.keybind_editor.capturing {
+        keybind_editor::capture_key(&mut state_mut, &key_event);
+        return;
+    }
+
+    // The log search being typed takes letters that are bindings elsewhere
+    if state_mut.focus == Pane::Logs && state_mut.logs.search_input {
+        logs::type_search(&mut state_mut, &key_event);
+        return;
+    }
+
+    // The editor's `:` line takes every key until it is run or closed
+    if state_mut.focus == Pane::Editor && state_mut.command_line.is_some() {
+        command_line::handle_keys(state_mut, &state, &key_event);
+        return;
+    }
+
+    // A confirmation dialog must be answered before anything else
+    if state_mut.confirms.current().is_some() {
+        confirm::handle_keys(&mut state_mut, &state, key_event);
+        return;
+    }
+
+    // An open overlay takes every key until it is closed
+    if state_mut.overlay.is_open() {
+        overlay::handle_keys(&mut state_mut, &state, key_event);
+        return;
+    }
+
     // Global keybindings (work in any pane/mode)
     let keybinds = &state_mut.keybinds.global;
 
+    // Keybind help for the focused pane (not while typing text)
+    if key_matches(&key_event, &keybinds.show_help)
+        && state_mut.vim_mode == crate::state::VimMode::Normal
+        && state_mut.focus != Pane::Splash
+    {
+        state_mut.overlay.open(crate::state::Overlay::Help);
+        return;
+    }
+
+    // Debug overlay, drawn over any pane without taking keys
+    if key_matches(&key_event, &keybinds.toggle_perf_hud) {
+        state_mut.perf_hud = !state_mut.perf_hud;
+        return;
+    }
+
+    // Pane transition effects
+    if key_matches(&key_event, &keybinds.toggle_animations) {
+        let enabled = state_mut.transition.toggle();
+        state_mut.set_status(if enabled {
+            "Animations on"
+        } else {
+            "Animations off"
+        });
+        return;
+    }
+
+    // Re-fetch the focused pane; the letter is text while the editor has focus
+    let refresh_key = key_matches(&key_event, &keybinds.refresh)
+        || (key_matches(&key_event, &keybinds.refresh_alt) && state_mut.focus != Pane::Editor);
+    if refresh_key && state_mut.focus != Pane::Splash {
+        refresh::refresh_focused(&mut state_mut, &state);
+        return;
+    }
+
     // Save file
     if key_matches(&key_event, &keybinds.save) {
+        save_open_file(state_mut, &state);
+        return;
+    }
+
+    // Unlock a readonly file for writing (sudo-style confirm)
+    if key_matches(&key_event, &keybinds.unlock_readonly) && state_mut.focus == Pane::Editor {
+        editor::unlock_readonly(&mut state_mut);
+        return;
+    }
+
+    // Show git history of the open file
+    if key_matches(&key_event, &keybinds.show_history)
+        && matches!(state_mut.focus, Pane::FileList | Pane::Editor)
+    {
         if let Some(filename) = state_mut.editor.current_file.clone() {
-            let content = state_mut.editor.get_content();
-            drop(state_mut); // Release borrow before async
+            state_mut.file_history.open(filename);
+            state_mut.set_focus(Pane::FileHistory, &state);
+        }
+        return;
+    }
+
+    // Download/upload the open or selected file through the browser
+    if key_matches(&key_event, &keybinds.download_file)
+        && matches!(state_mut.focus, Pane::FileList | Pane::Editor)
+    {
+        transfer::download_file(&mut state_mut);
+        return;
+    }
+    if key_matches(&key_event, &keybinds.upload_file)
+        && matches!(state_mut.focus, Pane::FileList | Pane::Editor)
+    {
+        transfer::upload_file(&mut state_mut, &state);
+        return;
+    }
+
+    // Compare the open or selected file with its upstream source
+    if key_matches(&key_event, &keybinds.check_upstream)
+        && matches!(state_mut.focus, Pane::FileList | Pane::Editor)
+    {
+        upstream::check(&mut state_mut, &state);
+        return;
+    }
+
+    // Resize the split between list and editor/details
+    if let Some(split) = crate::state::Split::of(state_mut.focus) {
+        let grow = key_matches(&key_event, &keybinds.grow_split);
+        if grow || key_matches(&key_event, &keybinds.shrink_split) {
+            if grow {
+                state_mut.splits.grow(split);
+            } else {
+                state_mut.splits.shrink(split);
+            }
+            state_mut.splits.save();
+            let width = state_mut.splits.get(split);
+            state_mut.set_status(format!("{} width {}%", split.label(), width));
+            return;
+        }
+    }
+
+    // Switch the editor between raw text and key=value table editing
+    if key_matches(&key_event, &keybinds.toggle_table) && state_mut.focus == Pane::Editor {
+        state_mut.vim_mode = crate::state::VimMode::Normal;
+        match state_mut.editor.toggle_table() {
+            Ok(true) => state_mut.set_status("Table mode"),
+            Ok(false) => state_mut.set_status("Text mode"),
+            Err(e) => state_mut.set_status(format!("[Table mode unavailable: {}]", e)),
+        }
+        return;
+    }
+
+    // Render Markdown/HTML files next to the raw text
+    if key_matches(&key_event, &keybinds.toggle_preview) && state_mut.focus == Pane::Editor {
+        state_mut.vim_mode = crate::state::VimMode::Normal;
+        let state_ref = &mut *state_mut;
+        match state_ref.editor.toggle_preview(&state_ref.current_theme) {
+            Ok(true) => state_ref.set_status("Preview"),
+            Ok(false) => state_ref.set_status("Text mode"),
+            Err(e) => state_ref.set_status(format!("[Preview unavailable: {}]", e)),
+        }
+        return;
+    }
+
+    // Show the saved file with its variables substituted next to the raw text
+    if key_matches(&key_event, &keybinds.toggle_rendered)
+        && state_mut.focus == Pane::Editor
+        && state_mut.editor.current_file.is_some()
+    {
+        state_mut.vim_mode = crate::state::VimMode::Normal;
+        if state_mut.editor.toggle_rendered() {
+            state_mut.set_status("Rendered view");
+            crate::state::refresh::render_editor(&state);
+        } else {
+            state_mut.set_status("Text mode");
+        }
+        return;
+    }
+
+    // Reveal masked secrets for editing (audited by the server) or mask them again
+    if key_matches(&key_event, &keybinds.toggle_secrets) && state_mut.focus == Pane::Editor {
+        editor::toggle_secrets(&mut state_mut, &state);
+        return;
+    }
+
+    // Convert the open file's encoding or line endings, applied on save
+    let cycle_encoding = key_matches(&key_event, &keybinds.cycle_encoding);
+    if (cycle_encoding || key_matches(&key_event, &keybinds.toggle_line_ending))
+        && state_mut.focus == Pane::Editor
+        && state_mut.editor.current_file.is_some()
+    {
+        let editor = &mut state_mut.editor;
+        if cycle_encoding {
+            editor.format.encoding = editor.format.encoding.next();
+        } else {
+            editor.format.line_ending = editor.format.line_ending.toggled();
+        }
+        let format = editor.format;
+        let unfit = (format.encoding == TextEncoding::Latin1)
+            .then(|| editor.get_content().chars().find(|c| u32::from(*c) > 0xFF))
+            .flatten();
+        state_mut.check_dirty();
+        match unfit {
+            Some(c) => state_mut.set_status(format!("[latin-1 cannot store '{}']", c)),
+            None => state_mut.set_status(format!(
+                "Saving as {}, {}",
+                format.encoding.label(),
+                format.line_ending.label()
+            )),
+        }
+        return;
+    }
+
+    // Draw spaces and tabs of the open file as marks
+    if key_matches(&key_event, &keybinds.toggle_whitespace) && state_mut.focus == Pane::Editor {
+        let shown = state_mut.editor.toggle_whitespace();
+        state_mut.set_status(if shown {
+            "Whitespace shown"
+        } else {
+            "Whitespace hidden"
+        });
+        return;
+    }
+
+    // Headings of the open file to jump to
+    if key_matches(&key_event, &keybinds.show_outline)
+        && state_mut.focus == Pane::Editor
+        && state_mut.editor.table.is_none()
+        && state_mut.editor.form.is_none()
+        && let Some(name) = state_mut.editor.current_file.as_deref()
+    {
+        let sections = crate::utils::outline::sections(name, state_mut.editor.textarea.lines());
+        let row = state_mut.editor.textarea.cursor().0;
+        state_mut.vim_mode = crate::state::VimMode::Normal;
+        state_mut.outline = Some(crate::state::Outline::new(sections, row));
+        state_mut.overlay.open(crate::state::Overlay::Outline);
+        return;
+    }
+
+    // Problems drawer below the file list and editor, focused when shown
+    if key_matches(&key_event, &keybinds.show_problems)
+        && matches!(state_mut.focus, Pane::FileList | Pane::Editor)
+    {
+        state_mut.vim_mode = crate::state::VimMode::Normal;
+        state_mut.problems.open = !state_mut.problems.open;
+        if state_mut.problems.open {
+            state_mut.focus_sub(SubPane::Problems);
+        } else {
+            state_mut.sub_focus = SubPane::Main;
+        }
+        return;
+    }
+
+    // Comment out the selected or cursor line with the file's comment leader
+    if key_matches(&key_event, &keybinds.toggle_comment)
+        && state_mut.focus == Pane::Editor
+        && state_mut.vim_mode == crate::state::VimMode::Normal
+        && !state_mut.editor.redacted
+        && state_mut.editor.table.is_none()
+        && state_mut.editor.form.is_none()
+        && let Some(name) = state_mut.editor.current_file.clone()
+    {
+        let leader = state_mut
+            .file_list
+            .all
+            .iter()
+            .find(|file| file.name == name)
+            .and_then(|file| file.comment.clone());
+        match leader {
+            Some(leader) => {
+                let commented = state_mut.editor.toggle_comment(&leader);
+                state_mut.check_dirty();
+                state_mut.set_status(if commented {
+                    "Commented"
+                } else {
+                    "Uncommented"
+                });
+            }
+            None => state_mut
+                .set_status("[No line comments known for this file, set `comment` in sysrat.toml]"),
+        }
+        return;
+    }
+
+    // Indent with 2, 4 or 8 spaces, or tabs, until another file is loaded
+    if key_matches(&key_event, &keybinds.cycle_indent)
+        && state_mut.focus == Pane::Editor
+        && state_mut.editor.current_file.is_some()
+    {
+        let indent = state_mut.editor.indent.next();
+        state_mut.editor.set_indent(indent);
+        state_mut.set_status(format!("Indent: {}", indent.label()));
+        return;
+    }
 
-            menu::save_file(state, filename, content);
+    // Show the schema form of the open file next to the raw text
+    if key_matches(&key_event, &keybinds.toggle_form) && state_mut.focus == Pane::Editor {
+        state_mut.vim_mode = crate::state::VimMode::Normal;
+        let schema = state_mut
+            .editor
+            .current_file
+            .as_ref()
+            .and_then(|name| state_mut.file_list.schema_of(name).cloned());
+        match state_mut.editor.toggle_form(schema.as_ref()) {
+            Ok(true) => state_mut.set_status("Form mode"),
+            Ok(false) => state_mut.set_status("Text mode"),
+            Err(e) => state_mut.set_status(format!("[Form mode unavailable: {}]", e)),
         }
         return;
     }
 
     // Cycle theme
     if key_matches(&key_event, &keybinds.cycle_theme) {
-        let current_name =
-            crate::theme::load_theme_preference().unwrap_or_else(|| "mocha".to_string());
+        let current_name = crate::theme::current_theme_name();
         let next_name = crate::theme::next_theme_name(&current_name);
         state_mut.set_theme(&next_name);
+        // Edit the newly selected theme instead
+        if state_mut.focus == Pane::ThemeEditor {
+            state_mut.open_theme_editor();
+        }
+        return;
+    }
+
+    // Talk to another sysrat server from `[[hosts]]`
+    if key_matches(&key_event, &keybinds.switch_host) {
+        hosts::open(&mut state_mut, &state);
+        return;
+    }
+
+    // Follow the browser color scheme, or keep the current theme regardless
+    if key_matches(&key_event, &keybinds.toggle_auto_theme) {
+        state_mut.toggle_auto_theme();
         return;
     }
 
@@ -95,8 +461,27 @@ pub fn handle_key_event(state: Rc<RefCell<AppState>>, key_event: KeyEvent) {
     if key_matches(&key_event, &keybinds.back_to_files)
         && matches!(state_mut.focus, Pane::FileList | Pane::Editor)
     {
-        state_mut.focus = Pane::FileList;
-        state_mut.save_to_storage();
+        state_mut.set_focus(Pane::FileList, &state);
+        state_mut.save_to_storage(&state);
+        return;
+    }
+
+    // Tab moves the keys between the side panes of views that have some
+    let focus_direction = if key_matches(&key_event, &keybinds.focus_next) {
+        Some(true)
+    } else if key_matches(&key_event, &keybinds.focus_previous) {
+        Some(false)
+    } else {
+        None
+    };
+    // Tab is typed as text in insert mode
+    let typing = state_mut.focus == Pane::Editor
+        && state_mut.sub_focus == SubPane::Main
+        && state_mut.vim_mode == crate::state::VimMode::Insert;
+    if let Some(forward) = focus_direction
+        && !typing
+        && state_mut.cycle_focus(forward)
+    {
         return;
     }
 
@@ -105,9 +490,9 @@ pub fn handle_key_event(state: Rc<RefCell<AppState>>, key_event: KeyEvent) {
         && key_event.code == KeyCode::Right
         && matches!(state_mut.focus, Pane::FileList | Pane::Editor)
     {
-        state_mut.focus = Pane::Editor;
+        state_mut.set_focus(Pane::Editor, &state);
         state_mut.vim_mode = crate::state::VimMode::Normal;
-        state_mut.save_to_storage();
+        state_mut.save_to_storage(&state);
         return;
     }
 
@@ -116,37 +501,75 @@ pub fn handle_key_event(state: Rc<RefCell<AppState>>, key_event: KeyEvent) {
         key_event
     )));
 
+    // Side panes handle their own keys, whichever view shows them
+    if state_mut.sub_focus == SubPane::ContainerDetails {
+        container_details::handle_keys(&mut state_mut, &state, key_event);
+        state_mut.save_to_storage(&state);
+        return;
+    }
+    if state_mut.sub_focus == SubPane::Problems {
+        problems::handle_keys(&mut state_mut, &state, key_event);
+        state_mut.save_to_storage(&state);
+        return;
+    }
+
     match state_mut.focus {
         Pane::Menu => menu::handle_keys(&mut state_mut, &state, key_event),
         Pane::Splash => {
-            // Check if enough time has passed (e.g., 500ms)
-            if js_sys::Date::now() - state_mut.splash.start_time > 500.0 {
-                // Check if we have a saved state to restore
-                if let Some(saved) = state_mut.restored_state.take() {
-                    if let Some(pane) = Pane::from_str(&saved.pane) {
-                        state_mut.focus = pane;
-
-                        // If we were in the editor, restore the file
-                        if pane == Pane::Editor
-                            && let (Some(filename), Some(content)) = (saved.filename, saved.content)
-                        {
-                            state_mut.editor.load_content(filename, content);
-                            state_mut.dirty = false;
-                        }
-                    } else {
-                        state_mut.focus = Pane::Menu;
-                    }
-                } else {
-                    // Default to Menu if no saved state
-                    state_mut.focus = Pane::Menu;
-                }
+            // Any key skips the intro
+            if state_mut.splash.can_skip() {
+                state_mut.finish_splash(&state);
             }
         }
         Pane::FileList => file_list::handle_keys(&mut state_mut, &state, key_event),
-        Pane::Editor => editor::handle_keys(&mut state_mut, key_event),
+        Pane::Editor => {
+            let was_insert = state_mut.vim_mode == crate::state::VimMode::Insert;
+            editor::handle_keys(&mut state_mut, key_event);
+            // Leaving insert mode checks the edits against the lint packs
+            if was_insert && state_mut.vim_mode == crate::state::VimMode::Normal {
+                crate::state::refresh::refresh_pane(Pane::Editor, &state);
+            }
+        }
         Pane::ContainerList => container_list::handle_keys(&mut state_mut, &state, key_event),
+        Pane::ContainerGraph => container_graph::handle_keys(&mut state_mut, &state, key_event),
+        Pane::ContainerWatch => container_watch::handle_keys(&mut state_mut, &state, key_event),
+        Pane::Dashboard => dashboard::handle_keys(&mut state_mut, &state, key_event),
+        Pane::FileHistory => file_history::handle_keys(&mut state_mut, &state, key_event),
+        Pane::Actions => actions::handle_keys(&mut state_mut, &state, key_event),
+        Pane::Runbooks => runbooks::handle_keys(&mut state_mut, &state, key_event),
+        Pane::Insights => insights::handle_keys(&mut state_mut, &state, key_event),
+        Pane::Images => images::handle_keys(&mut state_mut, &state, key_event),
+        Pane::Logs => logs::handle_keys(&mut state_mut, &state, key_event),
+        Pane::HexViewer => hex_viewer::handle_keys(&mut state_mut, &state, key_event),
+        Pane::ThemeEditor => theme_editor::handle_keys(&mut state_mut, &state, key_event),
+        Pane::KeybindEditor => keybind_editor::handle_keys(&mut state_mut, &state, key_event),
     }
 
     // Save state after any key event
-    state_mut.save_to_storage();
+    state_mut.save_to_storage(&state);
+}
+
+/// Save the open file, false if its form view has invalid fields
+fn save_open_file(mut state_mut: RefMut<'_, AppState>, state: &Rc<RefCell<AppState>>) -> bool {
+    // The form view checks values the server cannot know about
+    let form_errors = state_mut
+        .editor
+        .form
+        .as_ref()
+        .map_or(0, |form| form.error_count());
+    if form_errors > 0 {
+        state_mut.set_status(format!("[Not saved: {} invalid form fields]", form_errors));
+        return false;
+    }
+    if let Some(filename) = state_mut.editor.current_file.clone() {
+        if state_mut.editor.trim_on_save && state_mut.editor.trim_trailing_whitespace() {
+            state_mut.check_dirty();
+        }
+        let content = state_mut.editor.get_content();
+        let override_value = state_mut.editor.override_value.clone();
+        drop(state_mut); // Release borrow before async
+
+        menu::save_file(state.clone(), filename, content, override_value);
+    }
+    true
 }
@@ -0,0 +1,161 @@
+use crate::state::{AppState, Pane, Split, SubPane, VimMode, refresh};
+use ratzilla::event::{MouseButton, MouseEvent, MouseEventKind};
+use ratzilla::ratatui::layout::Position;
+use std::{cell::RefCell, rc::Rc};
+
+/// Lines the container details scroll per wheel step
+const WHEEL_DETAILS_LINES: u16 = 3;
+
+/// Left clicks focus the pane under the pointer and select the list item
+/// there; pressing on the gap between two panes drags the split
+pub fn handle_mouse_event(state: Rc<RefCell<AppState>>, mouse_event: MouseEvent) {
+    let mut state_mut = state.borrow_mut();
+    let Some(position) = cell_at(&state_mut, mouse_event.x as f64, mouse_event.y as f64) else {
+        return;
+    };
+
+    match mouse_event.event {
+        MouseEventKind::Moved => {
+            if let Some(split) = state_mut.split_drag {
+                drag_split(&mut state_mut, split, position);
+            }
+            return;
+        }
+        MouseEventKind::Released => {
+            if state_mut.split_drag.take().is_some() {
+                state_mut.splits.save();
+            }
+            return;
+        }
+        MouseEventKind::Pressed if mouse_event.button == MouseButton::Left => {}
+        _ => return,
+    }
+    if !accepts_mouse(&state_mut) {
+        return;
+    }
+
+    let (divider, pane, sub, item) = {
+        let layout = state_mut.layout.borrow();
+        (
+            layout.divider_at(position),
+            layout.pane_at(position),
+            layout.sub_pane_at(position),
+            layout.item_at(position),
+        )
+    };
+    if let Some(split) = divider {
+        state_mut.split_drag = Some(split);
+        return;
+    }
+
+    if let Some(pane) = pane
+        && pane != state_mut.focus
+    {
+        state_mut.vim_mode = VimMode::Normal;
+        state_mut.set_focus(pane, &state);
+    }
+    // Clicking a side pane hands it the keys, clicking elsewhere takes them back
+    if let Some(sub) = sub {
+        state_mut.focus_sub(sub);
+    } else if pane.is_some() {
+        state_mut.focus_sub(SubPane::Main);
+    }
+
+    match item {
+        Some((Pane::Menu, index)) => super::menu::click(&mut state_mut, index, &state),
+        Some((Pane::FileList, index)) => super::file_list::click(&mut state_mut, index, &state),
+        Some((Pane::ContainerList, index)) => {
+            super::container_list::click(&mut state_mut, index, &state)
+        }
+        _ => {}
+    }
+}
+
+/// The wheel over a list moves its selection, like the navigation keys.
+/// `x`/`y` are the pointer position in CSS pixels.
+pub fn handle_wheel(state: Rc<RefCell<AppState>>, x: f64, y: f64, down: bool) {
+    let mut state_mut = state.borrow_mut();
+    if !accepts_mouse(&state_mut) {
+        return;
+    }
+    let Some(position) = cell_at(&state_mut, x, y) else {
+        return;
+    };
+    let (list, details_max) = {
+        let layout = state_mut.layout.borrow();
+        let over_details = layout.sub_pane_at(position) == Some(SubPane::ContainerDetails);
+        (
+            layout.list_at(position),
+            over_details.then_some(layout.details_max_offset),
+        )
+    };
+
+    // Details scroll by a few lines per wheel step, whether focused or not
+    if let Some(max) = details_max {
+        if down {
+            state_mut.details_view.scroll_down(WHEEL_DETAILS_LINES, max);
+        } else {
+            state_mut.details_view.scroll_up(WHEEL_DETAILS_LINES);
+        }
+        return;
+    }
+
+    match list {
+        Some(Pane::Menu) if down => state_mut.menu.next(),
+        Some(Pane::Menu) => state_mut.menu.previous(),
+        Some(Pane::FileList) => {
+            if down {
+                state_mut.file_list.next();
+            } else {
+                state_mut.file_list.previous();
+            }
+            refresh::save_selection(Pane::FileList, &state_mut);
+        }
+        Some(Pane::ContainerList) => {
+            if down {
+                state_mut.container_list.next();
+            } else {
+                state_mut.container_list.previous();
+            }
+            refresh::save_selection(Pane::ContainerList, &state_mut);
+        }
+        _ => {}
+    }
+}
+
+/// Put the divider of a split under the pointer
+fn drag_split(state: &mut AppState, split: Split, position: Position) {
+    let screen = state.layout.borrow().screen;
+    if screen.width == 0 {
+        return;
+    }
+    let percent = (position.x - screen.x) as u32 * 100 / screen.width as u32;
+    state.splits.set(split, percent as u16);
+}
+
+/// Modals take all input, and a key being captured must not be interrupted
+fn accepts_mouse(state: &AppState) -> bool {
+    state.confirms.current().is_none()
+        && !state.overlay.is_open()
+        && !state.keybind_editor.capturing
+        && state.focus != Pane::Splash
+}
+
+/// Cell under a point given in CSS pixels, assuming the terminal grid fills
+/// the window like the DOM backend sizes it
+fn cell_at(state: &AppState, x: f64, y: f64) -> Option<Position> {
+    let screen = state.layout.borrow().screen;
+    let window = web_sys::window()?;
+    let width = window.inner_width().ok()?.as_f64()?;
+    let height = window.inner_height().ok()?.as_f64()?;
+    if screen.width == 0 || screen.height == 0 || width <= 0.0 || height <= 0.0 {
+        return None;
+    }
+
+    let col = (x / width * screen.width as f64) as u16;
+    let row = (y / height * screen.height as f64) as u16;
+    Some(Position::new(
+        screen.x + col.min(screen.width - 1),
+        screen.y + row.min(screen.height - 1),
+    ))
+}
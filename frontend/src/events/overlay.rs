@@ -0,0 +1,64 @@
+use super::key_matches;
+use crate::state::{AppState, Overlay};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+
+/// Keys while an overlay is open: scroll it or close it
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    if key_event.code == KeyCode::Esc || key_matches(&key_event, &state.keybinds.global.show_help) {
+        // Closing the hygiene review cancels the save
+        state.overlay.close();
+        state.upstream = None;
+        state.hygiene = None;
+        state.outline = None;
+        state.host_picker = None;
+        return;
+    }
+
+    if state.overlay.current == Some(Overlay::Outline) {
+        outline_keys(state, key_event);
+        return;
+    }
+    if state.overlay.current == Some(Overlay::Hosts) {
+        super::hosts::picker_keys(state, key_event);
+        return;
+    }
+
+    match key_event.code {
+        KeyCode::Char('j') | KeyCode::Down => state.overlay.scroll_down(),
+        KeyCode::Char('k') | KeyCode::Up => state.overlay.scroll_up(),
+        KeyCode::Char('a') if state.overlay.current == Some(Overlay::Upstream) => {
+            super::upstream::request_apply(state)
+        }
+        KeyCode::Char('a') if state.overlay.current == Some(Overlay::Hygiene) => {
+            super::hygiene::finish(state, state_rc, true)
+        }
+        KeyCode::Char('s') if state.overlay.current == Some(Overlay::Hygiene) => {
+            super::hygiene::finish(state, state_rc, false)
+        }
+        _ => {}
+    }
+}
+
+/// Pick a heading with j/k and jump to it with Enter
+fn outline_keys(state: &mut AppState, key_event: KeyEvent) {
+    let Some(outline) = state.outline.as_mut() else {
+        return;
+    };
+    match key_event.code {
+        KeyCode::Char('j') | KeyCode::Down => outline.next(),
+        KeyCode::Char('k') | KeyCode::Up => outline.previous(),
+        KeyCode::Enter => {
+            if let Some(section) = outline.selected() {
+                let row = section.row as u16;
+                state
+                    .editor
+                    .textarea
+                    .move_cursor(tui_textarea::CursorMove::Jump(row, 0));
+            }
+            state.overlay.close();
+            state.outline = None;
+        }
+        _ => {}
+    }
+}
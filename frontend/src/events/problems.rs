@@ -0,0 +1,47 @@
+use super::file_list;
+use crate::state::{AppState, Pane, SubPane};
+use ratzilla::event::KeyEvent;
+use std::{cell::RefCell, rc::Rc};
+
+/// Keys while the problems drawer has the focus: pick a problem, open its
+/// file at its line, or give the keys back to the file list or editor
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.problems;
+    if super::key_matches(&key_event, &keybinds.back) {
+        state.sub_focus = SubPane::Main;
+    } else if super::key_matches(&key_event, &keybinds.navigate_down)
+        || super::key_matches(&key_event, &keybinds.navigate_down_alt)
+    {
+        state.problems.next();
+    } else if super::key_matches(&key_event, &keybinds.navigate_up)
+        || super::key_matches(&key_event, &keybinds.navigate_up_alt)
+    {
+        state.problems.previous();
+    } else if super::key_matches(&key_event, &keybinds.open) {
+        open(state, state_rc);
+    }
+}
+
+/// Show the selected problem in the editor, loading its file unless it is
+/// the open one
+fn open(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(problem) = state.problems.selected() else {
+        return;
+    };
+    let file = problem.file.clone();
+    // Problems of the file as a whole have no line to go to
+    let row = problem.line.map_or(0, |line| line.saturating_sub(1));
+
+    if state.editor.current_file.as_deref() == Some(file.as_str()) {
+        state.set_focus(Pane::Editor, state_rc);
+        state.sub_focus = SubPane::Main;
+        state.vim_mode = crate::state::VimMode::Normal;
+        state
+            .editor
+            .textarea
+            .move_cursor(tui_textarea::CursorMove::Jump(row as u16, 0));
+        return;
+    }
+    state.problems.pending_jump = Some((file.clone(), row));
+    file_list::open_file_guarded(state, file, state_rc);
+}
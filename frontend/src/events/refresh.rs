@@ -0,0 +1,28 @@
+use super::container_details;
+use crate::state::{AppState, SubPane, refresh};
+use std::{cell::RefCell, rc::Rc};
+
+/// Re-fetch what the focused pane or side pane shows; the status line spins
+/// until the requests answered and then sums them up
+pub(super) fn refresh_focused(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let started = js_sys::Date::now();
+    let refreshed = match state.sub_focus {
+        SubPane::ContainerDetails => match state.details_view.container_id.clone() {
+            Some(container_id) => {
+                container_details::load(container_id, state_rc);
+                true
+            }
+            None => false,
+        },
+        SubPane::Main | SubPane::Problems => refresh::refresh_pane(state.focus, state_rc),
+    };
+
+    if refreshed {
+        state.manual_refresh = Some(refresh::ManualRefresh {
+            pane: state.focus,
+            started,
+        });
+    } else {
+        state.set_status(format!("Nothing to refresh in {}", state.focus.as_str()));
+    }
+}
@@ -0,0 +1,284 @@
+use crate::api::{self, JobInfo, JobStatus, RunbookStepKind};
+use crate::state::runbooks::StepStatus;
+use crate::state::{AppState, ConfirmAction, Pane, notifications, refresh};
+use crate::utils;
+use ratzilla::event::KeyEvent;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    if state.runbooks.walk.is_some() {
+        handle_walk_keys(state, state_rc, key_event);
+        return;
+    }
+
+    let keybinds = &state.keybinds.runbooks;
+
+    if super::key_matches(&key_event, &keybinds.navigate_down)
+        || super::key_matches(&key_event, &keybinds.navigate_down_alt)
+    {
+        state.runbooks.next();
+    } else if super::key_matches(&key_event, &keybinds.navigate_up)
+        || super::key_matches(&key_event, &keybinds.navigate_up_alt)
+    {
+        state.runbooks.previous();
+    } else if super::key_matches(&key_event, &keybinds.refresh) {
+        refresh::refresh_pane(Pane::Runbooks, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.back_to_menu) {
+        state.set_focus(Pane::Menu, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.run)
+        && let Some(walk) = state.runbooks.start_walk()
+    {
+        let message = format!(
+            "Runbook {}: {} steps",
+            walk.runbook.name,
+            walk.runbook.steps.len()
+        );
+        state.set_status(message);
+    }
+}
+
+/// Keys while walking through a runbook
+fn handle_walk_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.runbooks;
+
+    // Scroll bindings use modifiers, so check them first
+    if super::key_matches(&key_event, &keybinds.scroll_down) {
+        state.runbooks.scroll_down();
+    } else if super::key_matches(&key_event, &keybinds.scroll_up) {
+        state.runbooks.scroll_up();
+    } else if super::key_matches(&key_event, &keybinds.back_to_menu) {
+        leave(state);
+    } else if super::key_matches(&key_event, &keybinds.skip) {
+        skip_step(state);
+    } else if super::key_matches(&key_event, &keybinds.run) {
+        request_step(state, state_rc);
+    }
+}
+
+/// Ask before carrying out the current step, or close a finished walk
+fn request_step(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(walk) = state.runbooks.walk.as_ref() else {
+        return;
+    };
+    if walk.is_running() {
+        state.set_status("[Step is still running]");
+        return;
+    }
+    let Some(step) = walk.current_step() else {
+        state.runbooks.end_walk();
+        refresh::refresh_pane(Pane::Runbooks, state_rc);
+        return;
+    };
+
+    let target = step.target.clone().unwrap_or_default();
+    let question = match step.kind {
+        RunbookStepKind::Action if step.params.is_empty() => format!("Run {}?", target),
+        RunbookStepKind::Action => {
+            let mut values: Vec<String> = step
+                .params
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect();
+            values.sort();
+            format!("Run {} ({})?", target, values.join(", "))
+        }
+        RunbookStepKind::Edit => format!("Open {} in the editor?", target),
+        RunbookStepKind::Check => "Mark as done?".to_string(),
+    };
+    let message = if step.description.is_empty() {
+        question
+    } else {
+        format!("{} {}", step.description, question)
+    };
+    let title = format!(
+        "Step {}/{}: {}",
+        walk.current + 1,
+        walk.runbook.steps.len(),
+        step.title
+    );
+    let action = ConfirmAction::RunbookStep {
+        runbook: walk.runbook.name.clone(),
+        index: walk.current,
+    };
+    state.confirms.push(title, message, action);
+}
+
+/// Carry out a confirmed step: run its action, open its file or tick it off
+pub(super) fn run_step(
+    state: &mut AppState,
+    runbook: String,
+    index: usize,
+    state_rc: &Rc<RefCell<AppState>>,
+) {
+    // The walk may have been left while the dialog was shown
+    let Some(step) = state
+        .runbooks
+        .walk
+        .as_ref()
+        .filter(|w| w.runbook.name == runbook && w.current == index)
+        .and_then(|w| w.current_step().cloned())
+    else {
+        return;
+    };
+    let target = step.target.unwrap_or_default();
+
+    match step.kind {
+        RunbookStepKind::Action => {
+            let token = match &step.role {
+                Some(role) => {
+                    let Some(token) = super::actions::prompt_token(role, &target) else {
+                        state.set_status("[run cancelled]");
+                        return;
+                    };
+                    Some(token)
+                }
+                None => None,
+            };
+            mark_step(state, StepStatus::Running);
+            state.set_status(format!("Starting {}...", target));
+            start_action(runbook, index, target, token, state_rc);
+        }
+        RunbookStepKind::Edit => {
+            advance_step(state, StepStatus::Done);
+            let message = progress_status(state, &format!("Opened {}", target));
+            state.set_status(message);
+            super::file_list::open_file_guarded(state, target, state_rc);
+        }
+        RunbookStepKind::Check => {
+            advance_step(state, StepStatus::Done);
+            let message = progress_status(state, &format!("Checked {}", step.title));
+            state.set_status(message);
+        }
+    }
+}
+
+fn mark_step(state: &mut AppState, status: StepStatus) {
+    if let Some(walk) = state.runbooks.walk.as_mut() {
+        walk.mark(status);
+    }
+}
+
+fn advance_step(state: &mut AppState, status: StepStatus) {
+    if let Some(walk) = state.runbooks.walk.as_mut() {
+        walk.advance(status);
+    }
+}
+
+/// Start the action of a step and follow its job
+fn start_action(
+    runbook: String,
+    index: usize,
+    action: String,
+    token: Option<String>,
+    state_rc: &Rc<RefCell<AppState>>,
+) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let result = api::run_runbook_step(&runbook, index, token.clone()).await;
+        let mut st = state_clone.borrow_mut();
+        // Left while the request was on its way, the job runs regardless
+        let Some(walk) = st
+            .runbooks
+            .walk
+            .as_mut()
+            .filter(|w| w.runbook.name == runbook && w.current == index)
+        else {
+            return;
+        };
+
+        match result {
+            Ok(id) => {
+                walk.job_token = token;
+                // Shown right away, the poll fills in the output
+                walk.job = Some(JobInfo {
+                    id,
+                    kind: "action".to_string(),
+                    target: action.clone(),
+                    status: JobStatus::Running,
+                    message: String::new(),
+                    output: String::new(),
+                    started: utils::time::now_secs() as i64,
+                    finished: None,
+                });
+                st.runbooks.output_scroll = 0;
+                st.set_status(format!("Running {} (job #{})", action, id));
+                if st.focus == Pane::Runbooks {
+                    refresh::follow_step(&mut st, &state_clone, id);
+                }
+            }
+            Err(e) => {
+                walk.mark(StepStatus::Failed);
+                drop(st);
+                notifications::notify_error(
+                    &state_clone,
+                    format!(
+                        "Running {} failed: {}",
+                        action,
+                        utils::error::format_error(&e)
+                    ),
+                );
+            }
+        }
+    });
+}
+
+/// Move past the current step without carrying it out
+fn skip_step(state: &mut AppState) {
+    let Some(walk) = state.runbooks.walk.as_ref() else {
+        return;
+    };
+    if walk.is_running() {
+        state.set_status("[Step is still running]");
+        return;
+    }
+    let Some(title) = walk.current_step().map(|step| step.title.clone()) else {
+        return;
+    };
+    advance_step(state, StepStatus::Skipped);
+    let message = progress_status(state, &format!("Skipped {}", title));
+    state.set_status(message);
+}
+
+/// Status after a step, or the summary once it was the last one
+fn progress_status(state: &AppState, message: &str) -> String {
+    match state.runbooks.walk.as_ref() {
+        Some(walk) if walk.is_finished() => walk.summary(),
+        _ => message.to_string(),
+    }
+}
+
+/// Leave the walk, asking first when it is partly through
+fn leave(state: &mut AppState) {
+    let Some(walk) = state.runbooks.walk.as_ref() else {
+        return;
+    };
+    if !walk.is_started() || walk.is_finished() {
+        state.runbooks.end_walk();
+        return;
+    }
+
+    let name = walk.runbook.name.clone();
+    let message = format!(
+        "{} of {} steps are through. Leave the runbook?",
+        walk.current,
+        walk.runbook.steps.len()
+    );
+    state.confirms.push(
+        format!("Leave {}", name),
+        message,
+        ConfirmAction::AbandonRunbook(name),
+    );
+}
+
+pub(super) fn abandon(state: &mut AppState, runbook: String) {
+    if state
+        .runbooks
+        .walk
+        .as_ref()
+        .is_some_and(|walk| walk.runbook.name == runbook)
+    {
+        state.runbooks.end_walk();
+        state.set_status(format!("Left runbook {}", runbook));
+    }
+}
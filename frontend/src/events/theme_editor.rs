@@ -0,0 +1,124 @@
+use crate::{
+    api,
+    state::{AppState, Pane, notifications},
+    theme, utils,
+};
+use ratzilla::event::KeyEvent;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Channel step of the increase/decrease keys
+const STEP: i16 = 1;
+/// Channel step of the fast increase/decrease keys
+const FAST_STEP: i16 = 16;
+
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let keybinds = &state.keybinds.theme_editor;
+
+    if super::key_matches(&key_event, &keybinds.navigate_down)
+        || super::key_matches(&key_event, &keybinds.navigate_down_alt)
+    {
+        state.theme_editor.next();
+    } else if super::key_matches(&key_event, &keybinds.navigate_up)
+        || super::key_matches(&key_event, &keybinds.navigate_up_alt)
+    {
+        state.theme_editor.previous();
+    } else if super::key_matches(&key_event, &keybinds.next_channel)
+        || super::key_matches(&key_event, &keybinds.next_channel_alt)
+    {
+        state.theme_editor.next_channel();
+    } else if super::key_matches(&key_event, &keybinds.previous_channel)
+        || super::key_matches(&key_event, &keybinds.previous_channel_alt)
+    {
+        state.theme_editor.previous_channel();
+    } else if super::key_matches(&key_event, &keybinds.increase) {
+        adjust_selected(state, STEP);
+    } else if super::key_matches(&key_event, &keybinds.decrease) {
+        adjust_selected(state, -STEP);
+    } else if super::key_matches(&key_event, &keybinds.increase_fast) {
+        adjust_selected(state, FAST_STEP);
+    } else if super::key_matches(&key_event, &keybinds.decrease_fast) {
+        adjust_selected(state, -FAST_STEP);
+    } else if super::key_matches(&key_event, &keybinds.save) {
+        save_locally(state);
+    } else if super::key_matches(&key_event, &keybinds.save_to_server) {
+        save_to_server(state, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.reset) {
+        reset_theme(state);
+    } else if super::key_matches(&key_event, &keybinds.back_to_menu) {
+        // Leaving the pane discards unsaved edits
+        state.set_focus(Pane::Menu, state_rc);
+    }
+}
+
+/// Change the active channel of the selected color, previewing it live
+fn adjust_selected(state: &mut AppState, delta: i16) {
+    let Some(name) = state.theme_editor.selected().map(str::to_string) else {
+        return;
+    };
+    let channel = state.theme_editor.channel;
+    let mut rgb = state.current_theme.base.get(&name);
+    rgb[channel] = (rgb[channel] as i16 + delta).clamp(0, 255) as u8;
+
+    state.current_theme.base.set(&name, rgb);
+    state.theme_editor.dirty = true;
+    state.apply_theme_to_dom();
+}
+
+/// Store the edited theme in localStorage and make it the active theme
+fn save_locally(state: &mut AppState) -> Option<String> {
+    let name = state.theme_editor.name.clone();
+    match theme::theme_to_toml(&state.current_theme) {
+        Ok(content) => {
+            theme::save_local_theme(&name, content.clone());
+            theme::save_theme_preference(&name);
+            state.theme_editor.original = Some(state.current_theme.clone());
+            state.theme_editor.dirty = false;
+            state.set_status(format!("Theme saved: {}", name));
+            Some(content)
+        }
+        Err(e) => {
+            state.set_status(format!("[ERROR saving theme: {}]", e));
+            None
+        }
+    }
+}
+
+/// Save locally, then write `<name>.toml` to the server's theme directory
+fn save_to_server(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(content) = save_locally(state) else {
+        return;
+    };
+    let name = state.theme_editor.name.clone();
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match api::save_theme(&name, content).await {
+            Ok(()) => notifications::notify_success(
+                &state_clone,
+                format!("Theme saved to server: {}", name),
+            ),
+            Err(e) => notifications::notify_error(
+                &state_clone,
+                format!(
+                    "Saving theme to server failed: {}",
+                    utils::error::format_error(&e)
+                ),
+            ),
+        }
+    });
+}
+
+/// Drop local edits of the theme and reload the server or embedded version
+fn reset_theme(state: &mut AppState) {
+    let name = state.theme_editor.name.clone();
+    theme::remove_local_theme(&name);
+    match theme::load_theme_by_name(&name) {
+        Ok(reloaded) => {
+            state.current_theme = reloaded;
+            state.apply_theme_to_dom();
+            state.open_theme_editor();
+            state.set_status(format!("Theme reset: {}", name));
+        }
+        Err(e) => state.set_status(format!("[ERROR resetting theme: {}]", e)),
+    }
+}
@@ -0,0 +1,116 @@
+use crate::state::{AppState, Pane, notifications};
+use crate::{api, utils};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlAnchorElement, HtmlInputElement, window};
+
+/// File the transfer keybinds act on: the open file in the editor,
+/// the selected file in the file list
+pub(super) fn target_file(state: &AppState) -> Option<String> {
+    match state.focus {
+        Pane::Editor => state.editor.current_file.clone(),
+        Pane::FileList => state.file_list.selected().map(|f| f.name.clone()),
+        _ => None,
+    }
+}
+
+/// Let the browser save the file via the raw download endpoint
+pub fn download_file(state: &mut AppState) {
+    let Some(filename) = target_file(state) else {
+        return;
+    };
+    let basename = filename.rsplit('/').next().unwrap_or(&filename).to_string();
+    // The server only serves a file holding secrets once they were revealed
+    let is_open = state.editor.current_file.as_deref() == Some(filename.as_str());
+    if is_open && state.editor.redacted {
+        let reveal = state.keybinds.global.toggle_secrets.clone();
+        state.set_status(format!(
+            "[Secrets masked, {} reveals them for downloading]",
+            reveal
+        ));
+        return;
+    }
+    let reveal = is_open && state.editor.revealed;
+
+    let result = (|| -> Result<(), JsValue> {
+        let doc = window()
+            .and_then(|w| w.document())
+            .ok_or_else(|| JsValue::from_str("No document"))?;
+        let anchor: HtmlAnchorElement = doc.create_element("a")?.dyn_into()?;
+        anchor.set_href(&api::raw_file_url(&filename, reveal));
+        anchor.set_download(&basename);
+        anchor.click();
+        Ok(())
+    })();
+
+    match result {
+        Ok(()) => state.set_status(format!("Downloading {}", filename)),
+        Err(e) => state.set_status(format!(
+            "[ERROR downloading: {}]",
+            utils::error::format_error(&e)
+        )),
+    }
+}
+
+/// Open the browser file picker and replace the file with the chosen one
+pub fn upload_file(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(filename) = target_file(state) else {
+        return;
+    };
+    let is_open = state.editor.current_file.as_deref() == Some(filename.as_str());
+    if is_open && state.dirty {
+        state.set_status(format!("[{} has unsaved changes]", filename));
+        return;
+    }
+    let override_value = if is_open {
+        state.editor.override_value.clone()
+    } else {
+        None
+    };
+
+    let input = window()
+        .and_then(|w| w.document())
+        .and_then(|doc| doc.create_element("input").ok())
+        .and_then(|el| el.dyn_into::<HtmlInputElement>().ok());
+    let Some(input) = input else {
+        return;
+    };
+    input.set_type("file");
+
+    let state_clone = Rc::clone(state_rc);
+    let picker = input.clone();
+    let on_change = Closure::once_into_js(move || {
+        let Some(file) = picker.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        spawn_local(async move {
+            match api::upload_file_content(&filename, file, override_value).await {
+                Ok(()) => {
+                    if is_open {
+                        reload_editor(&state_clone, filename.clone()).await;
+                    }
+                    notifications::notify_success(&state_clone, format!("Uploaded {}", filename));
+                }
+                Err(e) => super::menu::report_write_error(&state_clone, "Uploading", &filename, &e),
+            }
+        });
+    });
+    input.set_onchange(Some(on_change.unchecked_ref()));
+    input.click();
+}
+
+/// Show the new content if the file is open in the editor
+pub(super) async fn reload_editor(state_rc: &Rc<RefCell<AppState>>, filename: String) {
+    let etag = state_rc.borrow().editor.etag.clone();
+    if let Ok(api::Conditional::Modified { data, etag }) =
+        api::fetch_file_content(&filename, etag, false).await
+    {
+        let mut st = state_rc.borrow_mut();
+        st.editor.load_content(filename, data.content);
+        st.editor.set_format(data.format);
+        st.editor.etag = etag;
+        st.editor.redacted = data.redacted;
+        st.dirty = false;
+    }
+}
@@ -0,0 +1,98 @@
+use super::transfer::{reload_editor, target_file};
+use crate::state::{AppState, ConfirmAction, Overlay, UpstreamReview, notifications};
+use crate::{api, utils};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Fetch the upstream version of the open or selected file and show the
+/// verification results and diff in the upstream overlay
+pub fn check(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(filename) = target_file(state) else {
+        return;
+    };
+    let has_upstream = state
+        .file_list
+        .all
+        .iter()
+        .any(|file| file.name == filename && file.upstream_url.is_some());
+    if !has_upstream {
+        state.set_status(format!("[{} has no upstream_url]", filename));
+        return;
+    }
+
+    state.upstream = Some(UpstreamReview::new(filename.clone()));
+    state.overlay.open(Overlay::Upstream);
+
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let result = api::check_upstream(&filename)
+            .await
+            .map_err(|e| utils::error::format_error(&e));
+        let mut st = state_clone.borrow_mut();
+        // Ignore the answer if the overlay moved on to another file meanwhile
+        if let Some(review) = st.upstream.as_mut()
+            && review.filename == filename
+        {
+            review.result = Some(result);
+        }
+    });
+}
+
+/// Ask before replacing the file with the reviewed upstream version
+pub fn request_apply(state: &mut AppState) {
+    let Some(review) = state.upstream.as_ref() else {
+        return;
+    };
+    let Some(check) = review.applicable() else {
+        state.set_status("[Nothing to apply]");
+        return;
+    };
+    let filename = review.filename.clone();
+    let sha256 = check.sha256.clone();
+    let dirty_note = if state.dirty && state.editor.current_file.as_deref() == Some(&filename) {
+        " Unsaved edits in the editor are kept but no longer match the file."
+    } else {
+        ""
+    };
+    state.confirms.push(
+        "Apply upstream",
+        format!(
+            "Replace {} with the upstream version {}?{}",
+            filename,
+            &sha256[..sha256.len().min(12)],
+            dirty_note
+        ),
+        ConfirmAction::ApplyUpstream { filename, sha256 },
+    );
+}
+
+/// Write the reviewed version on the server, then show it if the file is open
+pub fn run_apply(filename: String, sha256: String, state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match api::apply_upstream(&filename, sha256).await {
+            Ok(()) => {
+                let reload = {
+                    let mut st = state_clone.borrow_mut();
+                    st.overlay.close();
+                    st.upstream = None;
+                    st.editor.current_file.as_deref() == Some(&filename) && !st.dirty
+                };
+                if reload {
+                    reload_editor(&state_clone, filename.clone()).await;
+                }
+                notifications::notify_success(
+                    &state_clone,
+                    format!("Applied upstream to {}", filename),
+                );
+            }
+            Err(e) => notifications::notify_error(
+                &state_clone,
+                format!(
+                    "Applying upstream failed: {}",
+                    utils::error::format_error(&e)
+                ),
+            ),
+        }
+    });
+}
@@ -34,10 +34,317 @@ pub fn setup_theme(app_state: &Rc<RefCell<AppState>>) {
     }
 }
 
-/// Load cached data from browser storage
-pub fn load_cache(app_state: &mut AppState) {
-    crate::state::refresh::load_pane_cache(Pane::FileList, app_state);
-    crate::state::refresh::load_pane_cache(Pane::ContainerList, app_state);
+/// Fetch the banner, MOTD, splash length, retry policy and refresh
+/// intervals; the built-in ones stay when this fails
+pub fn load_frontend_config(app_state: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(app_state);
+    spawn_local(async move {
+        match api::fetch_frontend_config().await {
+            Ok(config) => {
+                api::set_retry_policy(config.retry);
+                storage::save_splash_ms(config.splash_ms);
+                let splash_ms = config.splash_ms;
+                let on_splash = {
+                    let mut st = state_clone.borrow_mut();
+                    let intervals_changed = st.branding.refresh != config.refresh;
+                    st.branding = config;
+                    if intervals_changed {
+                        crate::state::refresh::restart_polls(&mut st, &state_clone);
+                    }
+                    st.focus == Pane::Splash
+                };
+                if on_splash {
+                    start_splash(&state_clone, splash_ms);
+                }
+            }
+            Err(e) => web_sys::console::error_1(&JsValue::from_str(&format!(
+                "Failed to load frontend config: {}",
+                utils::error::format_error(&e)
+            ))),
+        }
+    });
+}
+
+/// Take over the preferences another browser saved to the user's profile.
+/// They are read from storage at startup, so the page reloads to apply
+/// them, unless there are unsaved edits by then.
+pub fn sync_profile(app_state: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(app_state);
+    spawn_local(async move {
+        if storage::profile::pull().await
+            && !state_clone.borrow().dirty
+            && let Some(win) = window()
+        {
+            let _ = win.location().reload();
+        }
+    });
+}
+
+/// Time the splash screen with the length the server sent last
+pub fn setup_splash(app_state: &Rc<RefCell<AppState>>) {
+    if app_state.borrow().focus == Pane::Splash {
+        let duration_ms = storage::load_splash_ms().unwrap_or(api::DEFAULT_SPLASH_MS);
+        start_splash(app_state, duration_ms);
+    }
+}
+
+/// End the splash screen after `duration_ms`, right away when it is 0
+fn start_splash(app_state: &Rc<RefCell<AppState>>, duration_ms: u64) {
+    if duration_ms == 0 {
+        let mut st = app_state.borrow_mut();
+        st.finish_splash(app_state);
+        st.save_to_storage(app_state);
+        return;
+    }
+    crate::state::splash::schedule_end(app_state, duration_ms);
+}
+
+/// Ask the server what it was built from, the status line warns when its
+/// version differs from the frontend's
+pub fn load_server_version(app_state: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(app_state);
+    spawn_local(async move {
+        match api::fetch_server_version().await {
+            Ok(version) => state_clone.borrow_mut().server_version = Some(version),
+            Err(e) => web_sys::console::error_1(&JsValue::from_str(&format!(
+                "Failed to load server version: {}",
+                utils::error::format_error(&e)
+            ))),
+        }
+    });
+}
+
+/// Fetch custom themes from the server and re-apply the saved preference,
+/// which may name a theme that was not embedded at build time
+pub fn load_runtime_themes(app_state: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(app_state);
+    spawn_local(async move {
+        match api::fetch_themes().await {
+            Ok(themes) if !themes.is_empty() => {
+                crate::theme::set_runtime_themes(themes);
+                let mut st = state_clone.borrow_mut();
+                st.current_theme = crate::theme::load_current_theme();
+                if let Err(e) = crate::update_dom_for_theme(&st.current_theme) {
+                    web_sys::console::error_1(&JsValue::from_str(&format!(
+                        "Failed to update DOM for theme: {:?}",
+                        e
+                    )));
+                }
+            }
+            Ok(_) => {}
+            Err(e) => web_sys::console::error_1(&JsValue::from_str(&format!(
+                "Failed to load runtime themes: {}",
+                utils::error::format_error(&e)
+            ))),
+        }
+    });
+}
+
+/// Fetch the user's status line layout, the embedded one stays when there is
+/// none or it does not parse
+pub fn load_runtime_statusline() {
+    spawn_local(async move {
+        let result = match api::fetch_statusline().await {
+            Ok(Some(content)) => crate::ui::set_runtime_layout(&content),
+            Ok(None) => Ok(()),
+            Err(e) => Err(utils::error::format_error(&e)),
+        };
+        if let Err(e) = result {
+            web_sys::console::error_1(&JsValue::from_str(&format!(
+                "Failed to load status line layout: {}",
+                e
+            )));
+        }
+    });
+}
+
+/// Re-pick the theme when the browser switches between light and dark mode
+pub fn setup_color_scheme_listener(app_state: &Rc<RefCell<AppState>>) {
+    let Some(query) =
+        window().and_then(|w| w.match_media("(prefers-color-scheme: dark)").ok().flatten())
+    else {
+        return;
+    };
+
+    let state_clone = Rc::clone(app_state);
+    let callback = Closure::<dyn FnMut()>::new(move || {
+        state_clone.borrow_mut().apply_auto_theme();
+    });
+
+    let _ = query.add_event_listener_with_callback("change", callback.as_ref().unchecked_ref());
+
+    // Listener lives for the whole session
+    callback.forget();
+}
+
+/// Follow the browser's online/offline events: offline shows a banner over
+/// the cached data, back online refreshes the focused pane right away
+pub fn setup_online_listener(app_state: &Rc<RefCell<AppState>>) {
+    let Some(win) = window() else {
+        return;
+    };
+
+    let state_clone = Rc::clone(app_state);
+    let on_offline = Closure::<dyn FnMut()>::new(move || {
+        state_clone.borrow_mut().offline = true;
+    });
+
+    let state_clone = Rc::clone(app_state);
+    let on_online = Closure::<dyn FnMut()>::new(move || {
+        let focus = {
+            let mut st = state_clone.borrow_mut();
+            st.offline = false;
+            // Failures while offline were expected, "Back online" replaces
+            // the "Reconnected" of a recovering refresh
+            st.refresh_backoff.record_success();
+            st.focus
+        };
+        crate::state::notifications::notify_success(&state_clone, "Back online");
+        crate::state::refresh::refresh_pane(focus, &state_clone);
+    });
+
+    let _ = win.add_event_listener_with_callback("offline", on_offline.as_ref().unchecked_ref());
+    let _ = win.add_event_listener_with_callback("online", on_online.as_ref().unchecked_ref());
+
+    // Listeners live for the whole session
+    on_offline.forget();
+    on_online.forget();
+}
+
+/// Refresh the focused pane immediately when the tab is shown again, polls
+/// pause while it is hidden
+pub fn setup_visibility_listener(app_state: &Rc<RefCell<AppState>>) {
+    let Some(doc) = window().and_then(|w| w.document()) else {
+        return;
+    };
+
+    let state_clone = Rc::clone(app_state);
+    let doc_clone = doc.clone();
+    let callback = Closure::<dyn FnMut()>::new(move || {
+        if !doc_clone.hidden() {
+            crate::state::refresh::refresh_on_visible(&mut state_clone.borrow_mut(), &state_clone);
+        }
+    });
+
+    let _ =
+        doc.add_event_listener_with_callback("visibilitychange", callback.as_ref().unchecked_ref());
+
+    // Listener lives for the whole session
+    callback.forget();
+}
+
+/// Scroll the list under the pointer with the mouse wheel
+pub fn setup_wheel_listener(app_state: &Rc<RefCell<AppState>>) {
+    // Pixels of wheel movement per list step; a mouse notch is about 100
+    const PIXELS_PER_STEP: f64 = 50.0;
+
+    let Some(win) = window() else {
+        return;
+    };
+
+    let state_clone = Rc::clone(app_state);
+    // Trackpads send many small deltas, collect them into whole steps
+    let mut pending = 0.0;
+    let callback =
+        Closure::<dyn FnMut(web_sys::WheelEvent)>::new(move |event: web_sys::WheelEvent| {
+            let delta = if event.delta_mode() == web_sys::WheelEvent::DOM_DELTA_PIXEL {
+                event.delta_y()
+            } else {
+                // Line or page deltas: one step per event
+                event.delta_y().signum() * PIXELS_PER_STEP
+            };
+            pending += delta;
+            while pending.abs() >= PIXELS_PER_STEP {
+                let down = pending > 0.0;
+                pending -= PIXELS_PER_STEP.copysign(pending);
+                crate::events::handle_wheel(
+                    Rc::clone(&state_clone),
+                    event.client_x() as f64,
+                    event.client_y() as f64,
+                    down,
+                );
+            }
+        });
+
+    let _ = win.add_event_listener_with_callback("wheel", callback.as_ref().unchecked_ref());
+
+    // Listener lives for the whole session
+    callback.forget();
+}
+
+/// Let the browser warn before closing or reloading the tab with unsaved edits
+pub fn setup_unload_guard(app_state: &Rc<RefCell<AppState>>) {
+    let Some(win) = window() else {
+        return;
+    };
+
+    let state_clone = Rc::clone(app_state);
+    let callback = Closure::<dyn FnMut(web_sys::BeforeUnloadEvent)>::new(
+        move |event: web_sys::BeforeUnloadEvent| {
+            // Never panic while the page unloads
+            let dirty = match state_clone.try_borrow_mut() {
+                Ok(mut st) => {
+                    st.flush_session();
+                    st.dirty
+                }
+                Err(_) => false,
+            };
+            if dirty {
+                event.prevent_default();
+                // Older browsers only prompt when a return value is set
+                event.set_return_value("unsaved changes");
+            }
+        },
+    );
+
+    let _ = win.add_event_listener_with_callback("beforeunload", callback.as_ref().unchecked_ref());
+
+    // Listener lives for the whole session
+    callback.forget();
+}
+
+/// Load cached lists from browser storage in the background
+pub fn load_cache(app_state: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(app_state);
+    spawn_local(async move {
+        crate::state::refresh::load_pane_cache(Pane::FileList, &state_clone).await;
+        crate::state::refresh::load_pane_cache(Pane::ContainerList, &state_clone).await;
+    });
+}
+
+/// Read the editor buffer of the restored session from IndexedDB and open it
+/// once it arrives: in the editor if it is already showing, else when the
+/// splash screen hands over
+pub fn restore_editor_buffer(app_state: &Rc<RefCell<AppState>>) {
+    let filename = {
+        let state = app_state.borrow();
+        state
+            .pending_buffer
+            .clone()
+            .or_else(|| state.restored_state.as_ref()?.filename.clone())
+    };
+    let Some(filename) = filename else {
+        return;
+    };
+
+    let state_clone = Rc::clone(app_state);
+    spawn_local(async move {
+        let content = storage::load_buffer(&filename).await;
+        let mut st = state_clone.borrow_mut();
+        if let Some(saved) = st.restored_state.as_mut()
+            && saved.filename.as_deref() == Some(filename.as_str())
+        {
+            saved.content = content;
+        } else if st.pending_buffer.take().as_deref() == Some(filename.as_str())
+            && st.focus == Pane::Editor
+            && st.editor.current_file.is_none()
+            && let Some(content) = content
+        {
+            st.editor.load_content(filename, content);
+            st.dirty = false;
+            st.sync_file_settings();
+        }
+    });
 }
 
 /// Load data based on current pane
@@ -51,42 +358,71 @@ pub fn load_pane_data(app_state: &Rc<RefCell<AppState>>) {
             // Load file list if we restored to FileList or Editor
             let state_clone = Rc::clone(app_state);
             spawn_local(async move {
-                match api::fetch_file_list().await {
-                    Ok(files) => {
+                state_clone.borrow_mut().file_list.loading = true;
+                let result = api::fetch_file_list(None).await;
+                state_clone.borrow_mut().file_list.loading = false;
+                match result {
+                    Ok(api::Conditional::Modified { data: list, etag }) => {
                         {
                             let mut st = state_clone.borrow_mut();
-                            // Only save to cache if data changed
-                            if st.file_list.files != files {
-                                storage::generic::save("file-list", &files);
-                            }
-                            st.file_list.set_files(files);
+                            st.file_list.readonly_override = list.readonly_override;
+                            st.file_list.etag = etag;
+                            st.file_list.error = None;
+                            storage::idb::put("file-list", &list.files);
+                            storage::idb::put("file-list-order", &list.order);
+                            st.file_list.set_files(list.files, list.order);
+                            // The restored file may declare a theme and editing options
+                            st.sync_file_settings();
                         }
-                        crate::state::status_helper::set_status_timed(
-                            &state_clone,
-                            "Restored session",
-                        );
+                        crate::state::notifications::notify_info(&state_clone, "Restored session");
                     }
+                    // Not sent without an ETag
+                    Ok(api::Conditional::NotModified) => {}
+                    // Keep showing the cached list, the offline banner explains it
+                    Err(_) if state_clone.borrow().offline => {}
                     Err(e) => {
-                        storage::generic::clear("file-list");
-                        crate::state::status_helper::set_status_timed(
+                        let message = utils::error::format_error(&e);
+                        storage::idb::delete("file-list");
+                        state_clone.borrow_mut().file_list.error = Some(message.clone());
+                        crate::state::notifications::notify_error(
                             &state_clone,
-                            format!("[ERROR loading files: {}]", utils::error::format_error(&e)),
+                            format!("Loading files failed: {}", message),
                         );
                     }
                 }
             });
         }
         Pane::ContainerList => {
-            // Load container list if we restored to ContainerList
+            // Load container list if we restored to ContainerList and keep it fresh
             crate::state::refresh::refresh_pane(Pane::ContainerList, app_state);
-            crate::state::status_helper::set_status_timed(app_state, "Restored session");
+            crate::state::refresh::start_background_refresh(&mut app_state.borrow_mut(), app_state);
+            crate::state::notifications::notify_info(app_state, "Restored session");
+        }
+        Pane::Dashboard => {
+            crate::state::refresh::refresh_pane(Pane::Dashboard, app_state);
+            crate::state::refresh::start_background_refresh(&mut app_state.borrow_mut(), app_state);
+            crate::state::notifications::notify_info(app_state, "Restored session");
+        }
+        Pane::ContainerGraph
+        | Pane::FileHistory
+        | Pane::Actions
+        | Pane::Runbooks
+        | Pane::Insights
+        | Pane::Images
+        | Pane::Logs => {
+            crate::state::refresh::refresh_pane(current_pane, app_state);
+            crate::state::notifications::notify_info(app_state, "Restored session");
         }
         Pane::Menu => {
             let mut state = app_state.borrow_mut();
             state.set_status("Welcome to Config Manager");
         }
-        Pane::Splash => {
-            // No data to load for splash
+        Pane::Splash
+        | Pane::ThemeEditor
+        | Pane::KeybindEditor
+        | Pane::HexViewer
+        | Pane::ContainerWatch => {
+            // No data to load (the editor panes, hex viewer and watch are never restored)
         }
     }
 }
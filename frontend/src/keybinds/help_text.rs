@@ -1,14 +1,24 @@
 use super::types::*;
 
 impl FileListKeybinds {
-    pub fn help_text(&self, _global: &GlobalKeybinds) -> String {
+    pub fn help_text(&self, global: &GlobalKeybinds) -> String {
         format!(
-            "{},{}/{},{}:navigate {}:load {}:menu {}:editor",
+            "{},{}/{},{}:navigate {}:load {}:pin {}:hide {}:show hidden {}:details {}:order {}:refresh {}/{}:download/upload {}:upstream {}:hosts {}:menu {}:editor",
             self.navigate_down,
             self.navigate_down_alt,
             self.navigate_up,
             self.navigate_up_alt,
             self.select,
+            self.toggle_pin,
+            self.toggle_hide,
+            self.toggle_show_hidden,
+            self.toggle_details,
+            self.cycle_order,
+            self.refresh,
+            global.download_file,
+            global.upload_file,
+            global.check_upstream,
+            global.switch_host,
             self.back_to_menu,
             self.go_to_editor
         )
@@ -16,9 +26,9 @@ impl FileListKeybinds {
 }
 
 impl ContainerListKeybinds {
-    pub fn help_text(&self, _global: &GlobalKeybinds) -> String {
+    pub fn help_text(&self, global: &GlobalKeybinds) -> String {
         format!(
-            "{},{}/{},{}:navigate {}:start {}:stop {}:restart {}:menu",
+            "{},{}/{},{}:navigate {}:start {}:stop {}:restart {}:open {}:pin {}:hide {}:show hidden {}:unhealthy first {}:watch {}:details {}:menu",
             self.navigate_down,
             self.navigate_down_alt,
             self.navigate_up,
@@ -26,14 +36,351 @@ impl ContainerListKeybinds {
             self.start_container,
             self.stop_container,
             self.restart_container,
+            self.open_port,
+            self.toggle_pin,
+            self.toggle_hide,
+            self.toggle_show_hidden,
+            self.toggle_unhealthy_first,
+            self.watch,
+            global.focus_next,
+            self.back_to_menu
+        )
+    }
+}
+
+impl ProblemsKeybinds {
+    pub fn help_text(&self, global: &GlobalKeybinds) -> String {
+        format!(
+            "{},{}/{},{}:select {}:open {}:hide {}/{}:back",
+            self.navigate_down,
+            self.navigate_down_alt,
+            self.navigate_up,
+            self.navigate_up_alt,
+            self.open,
+            global.show_problems,
+            global.focus_next,
+            self.back
+        )
+    }
+}
+
+impl ContainerDetailsKeybinds {
+    pub fn help_text(&self, global: &GlobalKeybinds) -> String {
+        format!(
+            "{},{}/{},{}:scroll {}/{}:page {}:retry {}/{}:back",
+            self.scroll_down,
+            self.scroll_down_alt,
+            self.scroll_up,
+            self.scroll_up_alt,
+            self.page_down,
+            self.page_up,
+            self.refresh,
+            global.focus_next,
+            self.back
+        )
+    }
+}
+
+impl ContainerWatchKeybinds {
+    pub fn help_text(&self) -> String {
+        format!(
+            "{},{}/{},{}:scroll logs {}:pause {}:back",
+            self.scroll_down,
+            self.scroll_down_alt,
+            self.scroll_up,
+            self.scroll_up_alt,
+            self.toggle_pause,
+            self.back
+        )
+    }
+}
+
+impl ContainerGraphKeybinds {
+    pub fn help_text(&self, global: &GlobalKeybinds) -> String {
+        format!(
+            "{},{}/{},{}:navigate {}:load details {}:details {}:refresh {}:menu",
+            self.navigate_down,
+            self.navigate_down_alt,
+            self.navigate_up,
+            self.navigate_up_alt,
+            self.select,
+            global.focus_next,
+            self.refresh,
+            self.back_to_menu
+        )
+    }
+}
+
+impl DashboardKeybinds {
+    pub fn help_text(&self, _global: &GlobalKeybinds) -> String {
+        format!(
+            "1-9:quick action {}:refresh {}:menu",
+            self.refresh, self.back_to_menu
+        )
+    }
+}
+
+impl FileHistoryKeybinds {
+    pub fn help_text(&self, _global: &GlobalKeybinds) -> String {
+        format!(
+            "{},{}/{},{}:commits {}/{}:scroll diff {}:refresh {}:editor",
+            self.navigate_down,
+            self.navigate_down_alt,
+            self.navigate_up,
+            self.navigate_up_alt,
+            self.scroll_down,
+            self.scroll_up,
+            self.refresh,
+            self.back
+        )
+    }
+}
+
+impl ThemeEditorKeybinds {
+    pub fn help_text(&self, _global: &GlobalKeybinds) -> String {
+        format!(
+            "{},{}/{},{}:color {},{}/{},{}:channel {}/{}:adjust {}/{}:adjust x16 {}:save {}:save to server {}:reset {}:menu",
+            self.navigate_down,
+            self.navigate_down_alt,
+            self.navigate_up,
+            self.navigate_up_alt,
+            self.previous_channel,
+            self.previous_channel_alt,
+            self.next_channel,
+            self.next_channel_alt,
+            self.increase,
+            self.decrease,
+            self.increase_fast,
+            self.decrease_fast,
+            self.save,
+            self.save_to_server,
+            self.reset,
+            self.back_to_menu
+        )
+    }
+}
+
+impl KeybindEditorKeybinds {
+    pub fn help_text(&self, _global: &GlobalKeybinds) -> String {
+        format!(
+            "{},{}/{},{}:navigate {}:rebind {}:reset {}:reset all {}:menu",
+            self.navigate_down,
+            self.navigate_down_alt,
+            self.navigate_up,
+            self.navigate_up_alt,
+            self.rebind,
+            self.reset,
+            self.reset_all,
+            self.back_to_menu
+        )
+    }
+}
+
+impl HexViewerKeybinds {
+    pub fn help_text(&self, _global: &GlobalKeybinds) -> String {
+        format!(
+            "{},{}/{},{}:scroll {}/{}:page {}/{}:first/last {}:files",
+            self.navigate_down,
+            self.navigate_down_alt,
+            self.navigate_up,
+            self.navigate_up_alt,
+            self.next_page,
+            self.previous_page,
+            self.first_page,
+            self.last_page,
+            self.back
+        )
+    }
+}
+
+impl ActionsKeybinds {
+    pub fn help_text(&self, _global: &GlobalKeybinds) -> String {
+        format!(
+            "{},{}/{},{}:navigate {}:run {}/{}:scroll output {}:refresh {}:menu",
+            self.navigate_down,
+            self.navigate_down_alt,
+            self.navigate_up,
+            self.navigate_up_alt,
+            self.run,
+            self.scroll_down,
+            self.scroll_up,
+            self.refresh,
+            self.back_to_menu
+        )
+    }
+
+    /// Help while the parameters of an action are asked for
+    pub fn params_help_text(&self) -> String {
+        format!(
+            "j/k:field Enter:edit/toggle h/l:option {}:run {}:cancel",
+            self.submit_params, self.back_to_menu
+        )
+    }
+}
+
+impl RunbooksKeybinds {
+    pub fn help_text(&self, _global: &GlobalKeybinds) -> String {
+        format!(
+            "{},{}/{},{}:navigate {}:start {}:refresh {}:menu",
+            self.navigate_down,
+            self.navigate_down_alt,
+            self.navigate_up,
+            self.navigate_up_alt,
+            self.run,
+            self.refresh,
+            self.back_to_menu
+        )
+    }
+
+    /// Help while walking through a runbook
+    pub fn walk_help_text(&self) -> String {
+        format!(
+            "{}:run step {}:skip step {}/{}:scroll output {}:leave runbook",
+            self.run, self.skip, self.scroll_down, self.scroll_up, self.back_to_menu
+        )
+    }
+}
+
+impl InsightsKeybinds {
+    pub fn help_text(&self, _global: &GlobalKeybinds) -> String {
+        format!(
+            "{},{}/{},{}:navigate {}:open {}:window {}:refresh {}:menu",
+            self.navigate_down,
+            self.navigate_down_alt,
+            self.navigate_up,
+            self.navigate_up_alt,
+            self.open,
+            self.cycle_window,
+            self.refresh,
+            self.back_to_menu
+        )
+    }
+}
+
+impl LogsKeybinds {
+    pub fn help_text(&self, _global: &GlobalKeybinds) -> String {
+        format!(
+            "{},{}/{},{}:navigate {}:follow {}:pause {}:search {}/{}:scroll {}:refresh {}:menu",
+            self.navigate_down,
+            self.navigate_down_alt,
+            self.navigate_up,
+            self.navigate_up_alt,
+            self.open,
+            self.toggle_pause,
+            self.search,
+            self.scroll_up,
+            self.scroll_down,
+            self.refresh,
+            self.back_to_menu
+        )
+    }
+
+    /// While a search is typed
+    pub fn search_help_text(&self) -> String {
+        "type to filter ENTER:apply ESC:clear".to_string()
+    }
+}
+
+impl ImagesKeybinds {
+    pub fn help_text(&self, _global: &GlobalKeybinds) -> String {
+        format!(
+            "{},{}/{},{}:navigate {}:scan {}:refresh {}:menu",
+            self.navigate_down,
+            self.navigate_down_alt,
+            self.navigate_up,
+            self.navigate_up_alt,
+            self.scan,
+            self.refresh,
             self.back_to_menu
         )
     }
 }
 
+impl ConfirmKeybinds {
+    pub fn help_text(&self) -> String {
+        format!(
+            "{}/{}:yes {}/{}:no",
+            self.yes, self.yes_alt, self.no, self.no_alt
+        )
+    }
+
+    /// Typed confirmations take letters as input, only the alt keys answer
+    pub fn typed_help_text(&self) -> String {
+        format!("{}:confirm {}:cancel", self.yes_alt, self.no_alt)
+    }
+
+    pub fn unsaved_help_text(&self) -> String {
+        format!(
+            "{}:save {}:discard {}/{}:cancel",
+            self.save, self.discard, self.no, self.no_alt
+        )
+    }
+}
+
 impl GlobalKeybinds {
     pub fn editor_normal_help_text(&self) -> String {
-        format!("i:insert {}:save {}:files", self.save, self.back_to_files)
+        format!(
+            "i:insert v:select {}:comment =:format ::command q:record @:replay {}:outline {}:problems {}:save {}:unlock {}:history {}:table {}:form {}:preview {}:rendered {}:secrets {}:encoding {}:eol {}:whitespace {}:indent {}:files",
+            self.toggle_comment,
+            self.show_outline,
+            self.show_problems,
+            self.save,
+            self.unlock_readonly,
+            self.show_history,
+            self.toggle_table,
+            self.toggle_form,
+            self.toggle_preview,
+            self.toggle_rendered,
+            self.toggle_secrets,
+            self.cycle_encoding,
+            self.toggle_line_ending,
+            self.toggle_whitespace,
+            self.cycle_indent,
+            self.back_to_files
+        )
+    }
+
+    pub fn editor_table_help_text(&self) -> String {
+        format!(
+            "j/k:row h/l:column Enter:edit o:add row d:delete row {}:save {}:text",
+            self.save, self.toggle_table
+        )
+    }
+
+    pub fn editor_command_help_text(&self) -> String {
+        "w q wq e:<file> set:[no]wrap/[no]number theme:<name> Tab:complete Enter:run ESC:cancel"
+            .to_string()
+    }
+
+    pub fn editor_table_edit_help_text(&self) -> String {
+        "Enter:apply ESC:cancel".to_string()
+    }
+
+    pub fn editor_form_help_text(&self) -> String {
+        format!(
+            "j/k:field Enter:edit/toggle h/l:option {}:save {}:text",
+            self.save, self.toggle_form
+        )
+    }
+
+    pub fn overlay_help_text(&self) -> String {
+        format!("j/k:scroll {}/ESC:close", self.show_help)
+    }
+
+    pub fn upstream_help_text(&self) -> String {
+        "j/k:scroll a:apply ESC:close".to_string()
+    }
+
+    pub fn hosts_help_text(&self) -> String {
+        "j/k:select Enter:switch ESC:close".to_string()
+    }
+
+    pub fn outline_help_text(&self) -> String {
+        "j/k:select Enter:jump ESC:close".to_string()
+    }
+
+    pub fn hygiene_help_text(&self) -> String {
+        "j/k:scroll a:save with fixes s:save as is ESC:cancel".to_string()
     }
 
     pub fn editor_insert_help_text(&self) -> String {
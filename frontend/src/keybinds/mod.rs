@@ -1,14 +1,134 @@
 mod help_text;
+pub mod overrides;
 mod types;
 
+use std::collections::HashSet;
 pub use types::*;
 
+/// One binding as listed in the keybind editor
+pub struct KeybindEntry {
+    /// Table in keybinds.toml, e.g. `file_list`
+    pub section: String,
+    pub action: String,
+    pub key: String,
+    /// Changed in the keybind editor (stored in localStorage)
+    pub overridden: bool,
+}
+
+impl KeybindEntry {
+    /// `section.action`, the key of the override map
+    pub fn id(&self) -> String {
+        format!("{}.{}", self.section, self.action)
+    }
+}
+
 impl Keybinds {
+    /// Keybinds from keybinds.toml with the runtime overrides applied
     pub fn load() -> Self {
+        let Some(mut table) = Self::from_file().to_table() else {
+            return Self::from_file();
+        };
+        for (id, key) in overrides::load() {
+            if let Some((section, action)) = id.split_once('.')
+                && let Some(toml::Value::Table(bindings)) = table.get_mut(section)
+                && bindings.contains_key(action)
+            {
+                bindings.insert(action.to_string(), toml::Value::String(key));
+            }
+        }
+        toml::Value::Table(table)
+            .try_into()
+            .unwrap_or_else(|_| Self::from_file())
+    }
+
+    fn to_table(&self) -> Option<toml::Table> {
+        match toml::Value::try_from(self).ok()? {
+            toml::Value::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+
+    fn from_file() -> Self {
         // Keybinds file is selected at build time from:
         // 1. User config: ~/.config/sysrat/keybinds.toml
         // 2. Default: frontend/keybinds.toml
         const KEYBINDS_TOML: &str = include_str!(env!("KEYBINDS_FILE"));
         toml::from_str(KEYBINDS_TOML).expect("Failed to parse keybinds.toml")
     }
+
+    /// Every binding, grouped by section
+    pub fn entries(&self) -> Vec<KeybindEntry> {
+        let overridden = overrides::load();
+        let Some(table) = self.to_table() else {
+            return Vec::new();
+        };
+
+        let mut entries = Vec::new();
+        for (section, bindings) in table {
+            let toml::Value::Table(bindings) = bindings else {
+                continue;
+            };
+            for (action, key) in bindings {
+                let toml::Value::String(key) = key else {
+                    continue;
+                };
+                let mut entry = KeybindEntry {
+                    section: section.clone(),
+                    action,
+                    key,
+                    overridden: false,
+                };
+                entry.overridden = overridden.contains_key(&entry.id());
+                entries.push(entry);
+            }
+        }
+        entries
+    }
+
+    /// Bindings of one keybinds.toml section followed by the global ones,
+    /// as listed in the help overlay
+    pub fn help_entries(&self, section: Option<&str>) -> Vec<KeybindEntry> {
+        let (mut own, global): (Vec<_>, Vec<_>) = self
+            .entries()
+            .into_iter()
+            .filter(|entry| Some(entry.section.as_str()) == section || entry.section == "global")
+            .partition(|entry| entry.section != "global");
+        own.extend(global);
+        own
+    }
+}
+
+/// Whether two bindings share a key within a section, or with a global binding
+/// (global bindings are checked before the pane's own)
+pub fn clashes(a: &KeybindEntry, b: &KeybindEntry) -> bool {
+    let shared_scope = a.section == b.section || a.section == "global" || b.section == "global";
+    shared_scope && normalize(&a.key) == normalize(&b.key)
+}
+
+/// Indices of entries that clash with another entry
+pub fn conflicts(entries: &[KeybindEntry]) -> HashSet<usize> {
+    let mut conflicting = HashSet::new();
+    for (i, a) in entries.iter().enumerate() {
+        for (j, b) in entries.iter().enumerate().skip(i + 1) {
+            if clashes(a, b) {
+                conflicting.insert(i);
+                conflicting.insert(j);
+            }
+        }
+    }
+    conflicting
+}
+
+/// Binding as `key_matches` compares it: single characters are case-insensitive
+fn normalize(binding: &str) -> String {
+    let (prefix, key) = ["Ctrl-", "Alt-", "Shift-"]
+        .iter()
+        .find_map(|prefix| binding.strip_prefix(prefix).map(|key| (*prefix, key)))
+        .unwrap_or(("", binding));
+    let key = if key == "Escape" { "Esc" } else { key };
+    if key.chars().count() == 1 {
+        format!("{}{}", prefix, key.to_lowercase())
+    } else {
+        format!("{}{}", prefix, key)
+    }
 }
@@ -0,0 +1,25 @@
+use crate::storage;
+use std::collections::BTreeMap;
+
+pub fn load() -> BTreeMap<String, String> {
+    storage::generic::load(storage::keys::KEYBIND_OVERRIDES).unwrap_or_default()
+}
+
+pub fn set(id: &str, key: &str) {
+    let mut overrides = load();
+    overrides.insert(id.to_string(), key.to_string());
+    storage::generic::save(storage::keys::KEYBIND_OVERRIDES, &overrides);
+    storage::profile::changed();
+}
+
+pub fn remove(id: &str) {
+    let mut overrides = load();
+    overrides.remove(id);
+    storage::generic::save(storage::keys::KEYBIND_OVERRIDES, &overrides);
+    storage::profile::changed();
+}
+
+pub fn clear() {
+    storage::generic::clear(storage::keys::KEYBIND_OVERRIDES);
+    storage::profile::changed();
+}
@@ -1,14 +1,45 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 pub struct Keybinds {
     pub menu: MenuKeybinds,
     pub file_list: FileListKeybinds,
     pub container_list: ContainerListKeybinds,
+    // Older user keybinds files predate the graph pane
+    #[serde(default)]
+    pub container_graph: ContainerGraphKeybinds,
+    #[serde(default)]
+    pub container_details: ContainerDetailsKeybinds,
+    #[serde(default)]
+    pub container_watch: ContainerWatchKeybinds,
+    #[serde(default)]
+    pub dashboard: DashboardKeybinds,
+    #[serde(default)]
+    pub file_history: FileHistoryKeybinds,
+    #[serde(default)]
+    pub hex_viewer: HexViewerKeybinds,
+    #[serde(default)]
+    pub theme_editor: ThemeEditorKeybinds,
+    #[serde(default)]
+    pub keybind_editor: KeybindEditorKeybinds,
+    #[serde(default)]
+    pub actions: ActionsKeybinds,
+    #[serde(default)]
+    pub runbooks: RunbooksKeybinds,
+    #[serde(default)]
+    pub insights: InsightsKeybinds,
+    #[serde(default)]
+    pub images: ImagesKeybinds,
+    #[serde(default)]
+    pub logs: LogsKeybinds,
+    #[serde(default)]
+    pub problems: ProblemsKeybinds,
+    #[serde(default)]
+    pub confirm: ConfirmKeybinds,
     pub global: GlobalKeybinds,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 pub struct MenuKeybinds {
     pub navigate_down: String,
     pub navigate_down_alt: String,
@@ -17,7 +48,7 @@ pub struct MenuKeybinds {
     pub select: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 pub struct FileListKeybinds {
     pub navigate_down: String,
     pub navigate_down_alt: String,
@@ -26,9 +57,22 @@ pub struct FileListKeybinds {
     pub select: String,
     pub back_to_menu: String,
     pub go_to_editor: String,
+    #[serde(default = "default_toggle_pin")]
+    pub toggle_pin: String,
+    #[serde(default = "default_toggle_hide")]
+    pub toggle_hide: String,
+    #[serde(default = "default_toggle_show_hidden")]
+    pub toggle_show_hidden: String,
+    #[serde(default = "default_refresh")]
+    pub refresh: String,
+    #[serde(default = "default_toggle_details")]
+    pub toggle_details: String,
+    /// Config order, alphabetical, by category, by mtime, and around
+    #[serde(default = "default_cycle_order")]
+    pub cycle_order: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 pub struct ContainerListKeybinds {
     pub navigate_down: String,
     pub navigate_down_alt: String,
@@ -37,12 +81,693 @@ pub struct ContainerListKeybinds {
     pub start_container: String,
     pub stop_container: String,
     pub restart_container: String,
+    #[serde(default = "default_open_port")]
+    pub open_port: String,
+    #[serde(default = "default_toggle_pin")]
+    pub toggle_pin: String,
+    #[serde(default = "default_toggle_hide")]
+    pub toggle_hide: String,
+    #[serde(default = "default_toggle_show_hidden")]
+    pub toggle_show_hidden: String,
+    #[serde(default = "default_watch")]
+    pub watch: String,
+    #[serde(default = "default_toggle_unhealthy_first")]
+    pub toggle_unhealthy_first: String,
     pub back_to_menu: String,
 }
 
-#[derive(Deserialize)]
+// Older user keybinds files predate port links
+fn default_open_port() -> String {
+    "o".to_string()
+}
+
+fn default_watch() -> String {
+    "w".to_string()
+}
+
+fn default_toggle_unhealthy_first() -> String {
+    "u".to_string()
+}
+
+fn default_toggle_pin() -> String {
+    "*".to_string()
+}
+
+fn default_toggle_hide() -> String {
+    "-".to_string()
+}
+
+fn default_toggle_show_hidden() -> String {
+    ".".to_string()
+}
+
+fn default_toggle_details() -> String {
+    "i".to_string()
+}
+
+fn default_cycle_order() -> String {
+    "s".to_string()
+}
+
+fn default_refresh() -> String {
+    "r".to_string()
+}
+
+/// Scrolling the container details once Tab gave them the keys
+#[derive(Deserialize, Serialize)]
+pub struct ContainerDetailsKeybinds {
+    pub scroll_down: String,
+    pub scroll_down_alt: String,
+    pub scroll_up: String,
+    pub scroll_up_alt: String,
+    pub page_down: String,
+    pub page_up: String,
+    /// Fetch the details again after loading them failed
+    #[serde(default = "default_refresh")]
+    pub refresh: String,
+    /// Give the keys back to the list or graph
+    pub back: String,
+}
+
+impl Default for ContainerDetailsKeybinds {
+    fn default() -> Self {
+        Self {
+            scroll_down: "j".to_string(),
+            scroll_down_alt: "Down".to_string(),
+            scroll_up: "k".to_string(),
+            scroll_up_alt: "Up".to_string(),
+            page_down: "PageDown".to_string(),
+            page_up: "PageUp".to_string(),
+            refresh: "r".to_string(),
+            back: "Esc".to_string(),
+        }
+    }
+}
+
+/// Problems drawer below the editor
+#[derive(Deserialize, Serialize)]
+pub struct ProblemsKeybinds {
+    pub navigate_down: String,
+    pub navigate_down_alt: String,
+    pub navigate_up: String,
+    pub navigate_up_alt: String,
+    /// Open the file of the selected problem at its line
+    pub open: String,
+    /// Give the keys back to the file list or editor
+    pub back: String,
+}
+
+impl Default for ProblemsKeybinds {
+    fn default() -> Self {
+        Self {
+            navigate_down: "j".to_string(),
+            navigate_down_alt: "Down".to_string(),
+            navigate_up: "k".to_string(),
+            navigate_up_alt: "Up".to_string(),
+            open: "Enter".to_string(),
+            back: "Esc".to_string(),
+        }
+    }
+}
+
+/// Live view of a single container
+#[derive(Deserialize, Serialize)]
+pub struct ContainerWatchKeybinds {
+    /// Scroll the log tail, up moves away from the newest lines
+    pub scroll_down: String,
+    pub scroll_down_alt: String,
+    pub scroll_up: String,
+    pub scroll_up_alt: String,
+    /// Stop or resume polling
+    pub toggle_pause: String,
+    pub back: String,
+}
+
+impl Default for ContainerWatchKeybinds {
+    fn default() -> Self {
+        Self {
+            scroll_down: "j".to_string(),
+            scroll_down_alt: "Down".to_string(),
+            scroll_up: "k".to_string(),
+            scroll_up_alt: "Up".to_string(),
+            toggle_pause: "p".to_string(),
+            back: "Esc".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ContainerGraphKeybinds {
+    pub navigate_down: String,
+    pub navigate_down_alt: String,
+    pub navigate_up: String,
+    pub navigate_up_alt: String,
+    pub select: String,
+    pub refresh: String,
+    pub back_to_menu: String,
+}
+
+impl Default for ContainerGraphKeybinds {
+    fn default() -> Self {
+        Self {
+            navigate_down: "j".to_string(),
+            navigate_down_alt: "Down".to_string(),
+            navigate_up: "k".to_string(),
+            navigate_up_alt: "Up".to_string(),
+            select: "Enter".to_string(),
+            refresh: "r".to_string(),
+            back_to_menu: "Esc".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct DashboardKeybinds {
+    pub refresh: String,
+    pub back_to_menu: String,
+}
+
+impl Default for DashboardKeybinds {
+    fn default() -> Self {
+        Self {
+            refresh: "r".to_string(),
+            back_to_menu: "Esc".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct FileHistoryKeybinds {
+    pub navigate_down: String,
+    pub navigate_down_alt: String,
+    pub navigate_up: String,
+    pub navigate_up_alt: String,
+    pub scroll_down: String,
+    pub scroll_up: String,
+    pub refresh: String,
+    pub back: String,
+}
+
+impl Default for FileHistoryKeybinds {
+    fn default() -> Self {
+        Self {
+            navigate_down: "j".to_string(),
+            navigate_down_alt: "Down".to_string(),
+            navigate_up: "k".to_string(),
+            navigate_up_alt: "Up".to_string(),
+            scroll_down: "Ctrl-Down".to_string(),
+            scroll_up: "Ctrl-Up".to_string(),
+            refresh: "r".to_string(),
+            back: "Esc".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct HexViewerKeybinds {
+    pub navigate_down: String,
+    pub navigate_down_alt: String,
+    pub navigate_up: String,
+    pub navigate_up_alt: String,
+    pub next_page: String,
+    pub previous_page: String,
+    pub first_page: String,
+    pub last_page: String,
+    pub back: String,
+}
+
+impl Default for HexViewerKeybinds {
+    fn default() -> Self {
+        Self {
+            navigate_down: "j".to_string(),
+            navigate_down_alt: "Down".to_string(),
+            navigate_up: "k".to_string(),
+            navigate_up_alt: "Up".to_string(),
+            next_page: "n".to_string(),
+            previous_page: "p".to_string(),
+            first_page: "g".to_string(),
+            last_page: "e".to_string(),
+            back: "Esc".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ThemeEditorKeybinds {
+    pub navigate_down: String,
+    pub navigate_down_alt: String,
+    pub navigate_up: String,
+    pub navigate_up_alt: String,
+    pub next_channel: String,
+    pub next_channel_alt: String,
+    pub previous_channel: String,
+    pub previous_channel_alt: String,
+    pub increase: String,
+    pub decrease: String,
+    pub increase_fast: String,
+    pub decrease_fast: String,
+    pub save: String,
+    pub save_to_server: String,
+    pub reset: String,
+    pub back_to_menu: String,
+}
+
+impl Default for ThemeEditorKeybinds {
+    fn default() -> Self {
+        Self {
+            navigate_down: "j".to_string(),
+            navigate_down_alt: "Down".to_string(),
+            navigate_up: "k".to_string(),
+            navigate_up_alt: "Up".to_string(),
+            next_channel: "l".to_string(),
+            next_channel_alt: "Right".to_string(),
+            previous_channel: "h".to_string(),
+            previous_channel_alt: "Left".to_string(),
+            increase: "+".to_string(),
+            decrease: "-".to_string(),
+            increase_fast: "]".to_string(),
+            decrease_fast: "[".to_string(),
+            save: "s".to_string(),
+            save_to_server: "p".to_string(),
+            reset: "x".to_string(),
+            back_to_menu: "Esc".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct KeybindEditorKeybinds {
+    pub navigate_down: String,
+    pub navigate_down_alt: String,
+    pub navigate_up: String,
+    pub navigate_up_alt: String,
+    pub rebind: String,
+    pub reset: String,
+    pub reset_all: String,
+    pub back_to_menu: String,
+}
+
+impl Default for KeybindEditorKeybinds {
+    fn default() -> Self {
+        Self {
+            navigate_down: "j".to_string(),
+            navigate_down_alt: "Down".to_string(),
+            navigate_up: "k".to_string(),
+            navigate_up_alt: "Up".to_string(),
+            rebind: "Enter".to_string(),
+            reset: "x".to_string(),
+            reset_all: "Delete".to_string(),
+            back_to_menu: "Esc".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ActionsKeybinds {
+    pub navigate_down: String,
+    pub navigate_down_alt: String,
+    pub navigate_up: String,
+    pub navigate_up_alt: String,
+    pub run: String,
+    pub scroll_down: String,
+    pub scroll_up: String,
+    pub refresh: String,
+    pub back_to_menu: String,
+    /// Run the action once its parameters are filled in
+    #[serde(default = "default_submit_params")]
+    pub submit_params: String,
+}
+
+impl Default for ActionsKeybinds {
+    fn default() -> Self {
+        Self {
+            navigate_down: "j".to_string(),
+            navigate_down_alt: "Down".to_string(),
+            navigate_up: "k".to_string(),
+            navigate_up_alt: "Up".to_string(),
+            run: "Enter".to_string(),
+            scroll_down: "Ctrl-Down".to_string(),
+            scroll_up: "Ctrl-Up".to_string(),
+            refresh: "r".to_string(),
+            back_to_menu: "Esc".to_string(),
+            submit_params: default_submit_params(),
+        }
+    }
+}
+
+fn default_submit_params() -> String {
+    "Ctrl-Enter".to_string()
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct RunbooksKeybinds {
+    pub navigate_down: String,
+    pub navigate_down_alt: String,
+    pub navigate_up: String,
+    pub navigate_up_alt: String,
+    /// Start the selected runbook, then carry out the current step
+    pub run: String,
+    /// Move past the current step without carrying it out
+    pub skip: String,
+    pub scroll_down: String,
+    pub scroll_up: String,
+    pub refresh: String,
+    /// Leave the runbook being walked through, else back to the menu
+    pub back_to_menu: String,
+}
+
+impl Default for RunbooksKeybinds {
+    fn default() -> Self {
+        Self {
+            navigate_down: "j".to_string(),
+            navigate_down_alt: "Down".to_string(),
+            navigate_up: "k".to_string(),
+            navigate_up_alt: "Up".to_string(),
+            run: "Enter".to_string(),
+            skip: "s".to_string(),
+            scroll_down: "Ctrl-Down".to_string(),
+            scroll_up: "Ctrl-Up".to_string(),
+            refresh: "r".to_string(),
+            back_to_menu: "Esc".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct InsightsKeybinds {
+    pub navigate_down: String,
+    pub navigate_down_alt: String,
+    pub navigate_up: String,
+    pub navigate_up_alt: String,
+    /// Open the selected file in the editor
+    pub open: String,
+    /// Count changes over the next longer window (7, 30, 90, 365 days)
+    pub cycle_window: String,
+    pub refresh: String,
+    pub back_to_menu: String,
+}
+
+impl Default for InsightsKeybinds {
+    fn default() -> Self {
+        Self {
+            navigate_down: "j".to_string(),
+            navigate_down_alt: "Down".to_string(),
+            navigate_up: "k".to_string(),
+            navigate_up_alt: "Up".to_string(),
+            open: "Enter".to_string(),
+            cycle_window: "w".to_string(),
+            refresh: "r".to_string(),
+            back_to_menu: "Esc".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct LogsKeybinds {
+    pub navigate_down: String,
+    pub navigate_down_alt: String,
+    pub navigate_up: String,
+    pub navigate_up_alt: String,
+    /// Follow the selected log
+    pub open: String,
+    /// Hold new lines back, or show them again
+    pub toggle_pause: String,
+    /// Type a text only matching lines are shown for
+    pub search: String,
+    pub scroll_up: String,
+    pub scroll_down: String,
+    pub refresh: String,
+    /// Clear the search, else back to the menu
+    pub back_to_menu: String,
+}
+
+impl Default for LogsKeybinds {
+    fn default() -> Self {
+        Self {
+            navigate_down: "j".to_string(),
+            navigate_down_alt: "Down".to_string(),
+            navigate_up: "k".to_string(),
+            navigate_up_alt: "Up".to_string(),
+            open: "Enter".to_string(),
+            toggle_pause: "p".to_string(),
+            search: "/".to_string(),
+            scroll_up: "PageUp".to_string(),
+            scroll_down: "PageDown".to_string(),
+            refresh: "r".to_string(),
+            back_to_menu: "Esc".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ImagesKeybinds {
+    pub navigate_down: String,
+    pub navigate_down_alt: String,
+    pub navigate_up: String,
+    pub navigate_up_alt: String,
+    /// Scan the selected image for vulnerabilities (trivy or grype)
+    pub scan: String,
+    pub refresh: String,
+    pub back_to_menu: String,
+}
+
+impl Default for ImagesKeybinds {
+    fn default() -> Self {
+        Self {
+            navigate_down: "j".to_string(),
+            navigate_down_alt: "Down".to_string(),
+            navigate_up: "k".to_string(),
+            navigate_up_alt: "Up".to_string(),
+            scan: "s".to_string(),
+            refresh: "r".to_string(),
+            back_to_menu: "Esc".to_string(),
+        }
+    }
+}
+
+/// Answers of the confirmation dialog
+#[derive(Deserialize, Serialize)]
+pub struct ConfirmKeybinds {
+    pub yes: String,
+    pub yes_alt: String,
+    pub no: String,
+    pub no_alt: String,
+    /// Unsaved-changes prompts: save the open file, then continue
+    #[serde(default = "default_confirm_save")]
+    pub save: String,
+    /// Unsaved-changes prompts: drop the edits, then continue
+    #[serde(default = "default_confirm_discard")]
+    pub discard: String,
+}
+
+impl Default for ConfirmKeybinds {
+    fn default() -> Self {
+        Self {
+            yes: "y".to_string(),
+            yes_alt: "Enter".to_string(),
+            no: "n".to_string(),
+            no_alt: "Esc".to_string(),
+            save: default_confirm_save(),
+            discard: default_confirm_discard(),
+        }
+    }
+}
+
+fn default_confirm_save() -> String {
+    "s".to_string()
+}
+
+fn default_confirm_discard() -> String {
+    "d".to_string()
+}
+
+#[derive(Deserialize, Serialize)]
 pub struct GlobalKeybinds {
     pub save: String,
     pub back_to_files: String,
     pub cycle_theme: String,
+    #[serde(default = "default_unlock_readonly")]
+    pub unlock_readonly: String,
+    #[serde(default = "default_show_history")]
+    pub show_history: String,
+    #[serde(default = "default_download_file")]
+    pub download_file: String,
+    #[serde(default = "default_upload_file")]
+    pub upload_file: String,
+    #[serde(default = "default_toggle_table")]
+    pub toggle_table: String,
+    #[serde(default = "default_toggle_form")]
+    pub toggle_form: String,
+    #[serde(default = "default_toggle_auto_theme")]
+    pub toggle_auto_theme: String,
+    #[serde(default = "default_toggle_preview")]
+    pub toggle_preview: String,
+    /// Show the saved file with its `${VARS}` substituted next to the raw text
+    #[serde(default = "default_toggle_rendered")]
+    pub toggle_rendered: String,
+    /// Reveal the masked secrets of the open file, or mask them again
+    #[serde(default = "default_toggle_secrets")]
+    pub toggle_secrets: String,
+    /// Convert the open file to the next encoding on save
+    #[serde(default = "default_cycle_encoding")]
+    pub cycle_encoding: String,
+    #[serde(default = "default_toggle_line_ending")]
+    pub toggle_line_ending: String,
+    /// Draw spaces and tabs in the editor as marks
+    #[serde(default = "default_toggle_whitespace")]
+    pub toggle_whitespace: String,
+    /// Sections and blocks of the open file, Enter jumps to one
+    #[serde(default = "default_show_outline")]
+    pub show_outline: String,
+    /// Show or hide the problems drawer with the diagnostics of all checked files
+    #[serde(default = "default_show_problems")]
+    pub show_problems: String,
+    /// Pick another sysrat server from `[[hosts]]` to manage
+    #[serde(default = "default_switch_host")]
+    pub switch_host: String,
+    /// Comment out the cursor line or `v` selection, or uncomment it
+    #[serde(default = "default_toggle_comment")]
+    pub toggle_comment: String,
+    /// Indent the open file with 2, 4 or 8 spaces, or tabs
+    #[serde(default = "default_cycle_indent")]
+    pub cycle_indent: String,
+    #[serde(default = "default_show_help")]
+    pub show_help: String,
+    #[serde(default = "default_check_upstream")]
+    pub check_upstream: String,
+    /// Widen the left pane of the file and container views
+    #[serde(default = "default_grow_split")]
+    pub grow_split: String,
+    #[serde(default = "default_shrink_split")]
+    pub shrink_split: String,
+    /// Move the keys to the next side pane of the view (e.g. the container
+    /// details), wrapping back to the main widget
+    #[serde(default = "default_focus_next")]
+    pub focus_next: String,
+    #[serde(default = "default_focus_previous")]
+    pub focus_previous: String,
+    /// Re-fetch whatever the focused pane shows
+    #[serde(default = "default_global_refresh")]
+    pub refresh: String,
+    /// Ignored in the editor, where it is text
+    #[serde(default = "default_global_refresh_alt")]
+    pub refresh_alt: String,
+    /// Frame times, API latencies and memory, for reports of a slow UI
+    #[serde(default = "default_toggle_perf_hud")]
+    pub toggle_perf_hud: String,
+    /// Pane transition effects, remembered across reloads
+    #[serde(default = "default_toggle_animations")]
+    pub toggle_animations: String,
+}
+
+fn default_toggle_animations() -> String {
+    "Alt-M".to_string()
+}
+
+fn default_toggle_perf_hud() -> String {
+    "F9".to_string()
+}
+
+fn default_global_refresh() -> String {
+    "F5".to_string()
+}
+
+fn default_global_refresh_alt() -> String {
+    "Shift-R".to_string()
+}
+
+fn default_focus_next() -> String {
+    "Tab".to_string()
+}
+
+fn default_focus_previous() -> String {
+    "Shift-Tab".to_string()
+}
+
+fn default_grow_split() -> String {
+    "Alt-]".to_string()
+}
+
+fn default_shrink_split() -> String {
+    "Alt-[".to_string()
+}
+
+fn default_unlock_readonly() -> String {
+    "Alt-U".to_string()
+}
+
+fn default_show_history() -> String {
+    "Alt-H".to_string()
+}
+
+fn default_download_file() -> String {
+    "Alt-D".to_string()
+}
+
+fn default_upload_file() -> String {
+    "Alt-O".to_string()
+}
+
+fn default_toggle_table() -> String {
+    "Alt-K".to_string()
+}
+
+fn default_toggle_form() -> String {
+    "Alt-F".to_string()
+}
+
+fn default_toggle_auto_theme() -> String {
+    "Alt-A".to_string()
+}
+
+fn default_toggle_preview() -> String {
+    "Alt-P".to_string()
+}
+
+fn default_toggle_rendered() -> String {
+    "Alt-R".to_string()
+}
+
+fn default_toggle_secrets() -> String {
+    "Alt-S".to_string()
+}
+
+fn default_cycle_encoding() -> String {
+    "Alt-E".to_string()
+}
+
+fn default_toggle_line_ending() -> String {
+    "Alt-L".to_string()
+}
+
+fn default_toggle_whitespace() -> String {
+    "Alt-V".to_string()
+}
+
+fn default_show_outline() -> String {
+    "Alt-J".to_string()
+}
+
+fn default_show_problems() -> String {
+    "Alt-X".to_string()
+}
+
+fn default_switch_host() -> String {
+    "Alt-N".to_string()
+}
+
+fn default_toggle_comment() -> String {
+    "Alt-C".to_string()
+}
+
+fn default_cycle_indent() -> String {
+    "Alt-I".to_string()
+}
+
+fn default_show_help() -> String {
+    "?".to_string()
+}
+
+fn default_check_upstream() -> String {
+    "Alt-W".to_string()
 }
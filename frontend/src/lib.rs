@@ -3,6 +3,7 @@ mod dom;
 mod events;
 mod init;
 mod keybinds;
+mod perf;
 mod state;
 mod storage;
 mod theme;
@@ -22,14 +23,41 @@ pub fn main() -> Result<(), JsValue> {
     // Set up panic hook for better error messages
     console_error_panic_hook::set_once();
 
+    // Move data saved under old key names before anything is loaded
+    storage::migrate_keys();
+
     // Initialize app state
     let app_state = Rc::new(RefCell::new(AppState::new()));
 
     // Set up theme in DOM
     init::setup_theme(&app_state);
 
+    // Merge custom themes served by the backend
+    init::load_runtime_themes(&app_state);
+
+    // Status line layout from the server, without a rebuild
+    init::load_runtime_statusline();
+
+    // End the intro on its own, until the frontend config says when
+    init::setup_splash(&app_state);
+
+    // Banner, MOTD and splash length from sysrat.toml
+    init::load_frontend_config(&app_state);
+
+    // Server build for the status line, flags a version mismatch
+    init::load_server_version(&app_state);
+
+    // Preferences saved from other browsers, when the server keeps profiles
+    init::sync_profile(&app_state);
+
+    // Follow light/dark mode changes of the browser
+    init::setup_color_scheme_listener(&app_state);
+
     // Load cached lists from storage
-    init::load_cache(&mut app_state.borrow_mut());
+    init::load_cache(&app_state);
+
+    // Editor content of the restored session
+    init::restore_editor_buffer(&app_state);
 
     // Initialize Ratzilla backend and terminal
     let backend = DomBackend::new().map_err(|e| JsValue::from_str(&e.to_string()))?;
@@ -43,8 +71,14 @@ pub fn main() -> Result<(), JsValue> {
     )));
     init::load_pane_data(&app_state);
 
-    // Start background refresh for container list (every 10 seconds)
-    state::refresh::start_background_refresh(&app_state);
+    // Retry failed background refreshes when the tab regains focus
+    init::setup_visibility_listener(&app_state);
+
+    // Show cached data under a banner while the browser is offline
+    init::setup_online_listener(&app_state);
+
+    // Warn before closing the tab with unsaved edits
+    init::setup_unload_guard(&app_state);
 
     // Set up key event handler
     terminal.on_key_event({
@@ -54,10 +88,23 @@ pub fn main() -> Result<(), JsValue> {
         }
     });
 
+    // Clicks select list items and focus panes
+    terminal.on_mouse_event({
+        let state_clone = Rc::clone(&app_state);
+        move |mouse_event| {
+            events::handle_mouse_event(Rc::clone(&state_clone), mouse_event);
+        }
+    });
+
+    // Ratzilla reports no wheel events, listen on the window instead
+    init::setup_wheel_listener(&app_state);
+
     // Set up drawing loop
     terminal.draw_web(move |f| {
+        let started = perf::now();
         let state = app_state.borrow();
         ui::render(f, &state);
+        perf::record_frame(started, perf::now() - started);
     });
 
     Ok(())
@@ -0,0 +1,140 @@
+//! Numbers behind "the UI feels slow", shown by the performance HUD
+//!
+//! Kept outside `AppState` like the API timings: frames are measured around
+//! the render that only borrows the state, and cache lookups happen in
+//! storage helpers that never see it.
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use wasm_bindgen::JsCast;
+use web_sys::window;
+
+/// Frames averaged for the HUD, about two seconds at 60 fps
+const FRAME_HISTORY: usize = 120;
+
+struct Frame {
+    /// `performance.now()` when the frame started
+    started: f64,
+    /// Time spent in `ui::render`
+    render_ms: f64,
+}
+
+thread_local! {
+    static FRAMES: RefCell<VecDeque<Frame>> = const { RefCell::new(VecDeque::new()) };
+    static STORAGE: Cell<HitRate> = const { Cell::new(HitRate::new()) };
+    static ETAG: Cell<HitRate> = const { Cell::new(HitRate::new()) };
+}
+
+/// Lookups that were answered from a cache versus those that were not
+#[derive(Clone, Copy)]
+pub struct HitRate {
+    pub hits: u32,
+    pub misses: u32,
+}
+
+impl HitRate {
+    const fn new() -> Self {
+        Self { hits: 0, misses: 0 }
+    }
+
+    fn record(mut self, hit: bool) -> Self {
+        if hit {
+            self.hits = self.hits.saturating_add(1);
+        } else {
+            self.misses = self.misses.saturating_add(1);
+        }
+        self
+    }
+
+    /// Share of hits in percent, None before the first lookup
+    pub fn percent(&self) -> Option<u32> {
+        let total = u64::from(self.hits) + u64::from(self.misses);
+        (total > 0).then(|| (u64::from(self.hits) * 100 / total) as u32)
+    }
+}
+
+/// Render time and rate over the last frames
+pub struct FrameStats {
+    pub avg_ms: f64,
+    pub max_ms: f64,
+    pub fps: f64,
+}
+
+/// High resolution clock in milliseconds, `Date.now()` where unavailable
+pub fn now() -> f64 {
+    window()
+        .and_then(|w| w.performance())
+        .map_or_else(js_sys::Date::now, |p| p.now())
+}
+
+/// Record a frame that started at `started` and took `render_ms` to draw
+pub fn record_frame(started: f64, render_ms: f64) {
+    FRAMES.with(|frames| {
+        let mut frames = frames.borrow_mut();
+        if frames.len() == FRAME_HISTORY {
+            frames.pop_front();
+        }
+        frames.push_back(Frame { started, render_ms });
+    });
+}
+
+pub fn frame_stats() -> Option<FrameStats> {
+    FRAMES.with(|frames| {
+        let frames = frames.borrow();
+        let (first, last) = (frames.front()?, frames.back()?);
+        let total: f64 = frames.iter().map(|f| f.render_ms).sum();
+        let span = last.started - first.started;
+        Some(FrameStats {
+            avg_ms: total / frames.len() as f64,
+            max_ms: frames.iter().map(|f| f.render_ms).fold(0.0, f64::max),
+            fps: if span > 0.0 {
+                (frames.len() - 1) as f64 * 1000.0 / span
+            } else {
+                0.0
+            },
+        })
+    })
+}
+
+/// A localStorage cache lookup, hit when the key held usable data
+pub fn record_storage(hit: bool) {
+    STORAGE.with(|rate| rate.set(rate.get().record(hit)));
+}
+
+pub fn storage_rate() -> HitRate {
+    STORAGE.with(Cell::get)
+}
+
+/// A GET sent with an ETag, hit when the server answered 304
+pub fn record_etag(hit: bool) {
+    ETAG.with(|rate| rate.set(rate.get().record(hit)));
+}
+
+pub fn etag_rate() -> HitRate {
+    ETAG.with(Cell::get)
+}
+
+/// Linear memory of the wasm module in bytes; it only grows, so this is
+/// the high water mark of the heap
+pub fn wasm_memory_bytes() -> Option<u32> {
+    let memory = wasm_bindgen::memory()
+        .dyn_into::<js_sys::WebAssembly::Memory>()
+        .ok()?;
+    let buffer = memory.buffer().dyn_into::<js_sys::ArrayBuffer>().ok()?;
+    Some(buffer.byte_length())
+}
+
+/// Bytes of localStorage used by the app (UTF-16, two bytes per unit)
+pub fn local_storage_bytes() -> Option<u32> {
+    let storage = window()?.local_storage().ok()??;
+    let mut units = 0u32;
+    for i in 0..storage.length().ok()? {
+        let Some(key) = storage.key(i).ok().flatten() else {
+            continue;
+        };
+        let value = storage.get_item(&key).ok().flatten().unwrap_or_default();
+        units = units
+            .saturating_add((key.encode_utf16().count() + value.encode_utf16().count()) as u32);
+    }
+    Some(units.saturating_mul(2))
+}
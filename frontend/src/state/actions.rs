@@ -0,0 +1,123 @@
+use super::schema_form::SchemaForm;
+use crate::api::{ActionInfo, FileSchema, JobInfo, JobStatus};
+use gloo_timers::callback::Interval;
+use std::collections::HashMap;
+
+/// Parameter form of an action about to run
+pub struct ParamsPrompt {
+    pub action: String,
+    pub form: SchemaForm,
+}
+
+impl ParamsPrompt {
+    /// Values entered so far by parameter key, unset ones left out
+    pub fn values(&self) -> HashMap<String, String> {
+        self.form
+            .fields
+            .iter()
+            .filter_map(|field| {
+                let value = self.form.value(field)?;
+                (!value.is_empty()).then(|| (field.key.clone(), value))
+            })
+            .collect()
+    }
+}
+
+pub struct ActionsState {
+    pub actions: Vec<ActionInfo>,
+    pub selected_index: usize,
+    pub loaded: bool,
+    /// Last started action run, updated while it is polled
+    pub job: Option<JobInfo>,
+    /// Role token `job` was started with, its output is only served with it
+    pub job_token: Option<String>,
+    /// Polls the running job, dropped once it finishes or the pane is left
+    pub poll: Option<Interval>,
+    /// Lines scrolled up from the end of the output, 0 follows new output
+    pub output_scroll: u16,
+    /// Parameters being asked for, shown in place of the output
+    pub params: Option<ParamsPrompt>,
+}
+
+impl ActionsState {
+    pub fn new() -> Self {
+        Self {
+            actions: Vec::new(),
+            selected_index: 0,
+            loaded: false,
+            job: None,
+            job_token: None,
+            poll: None,
+            output_scroll: 0,
+            params: None,
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.actions.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.actions.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.actions.is_empty() {
+            self.selected_index = if self.selected_index == 0 {
+                self.actions.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+        }
+    }
+
+    pub fn selected(&self) -> Option<&ActionInfo> {
+        self.actions.get(self.selected_index)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ActionInfo> {
+        self.actions.iter().find(|action| action.name == name)
+    }
+
+    pub fn set_actions(&mut self, actions: Vec<ActionInfo>) {
+        // Preserve selection by name
+        let selected_name = self.selected().map(|a| a.name.clone());
+
+        self.actions = actions;
+        self.loaded = true;
+        self.selected_index = selected_name
+            .and_then(|name| self.actions.iter().position(|a| a.name == name))
+            .unwrap_or(0);
+    }
+
+    /// Ask for the parameters of an action before it runs
+    pub fn prompt_params(&mut self, action: &ActionInfo) {
+        let schema = FileSchema {
+            fields: action.params.clone(),
+        };
+        self.params = SchemaForm::new(&schema, "", "").map(|form| ParamsPrompt {
+            action: action.name.clone(),
+            form,
+        });
+    }
+
+    /// Whether the last run is still going
+    pub fn is_running(&self) -> bool {
+        self.job
+            .as_ref()
+            .is_some_and(|job| job.status == JobStatus::Running)
+    }
+
+    /// Scroll back through the output (towards its start)
+    pub fn scroll_up(&mut self) {
+        let lines = self
+            .job
+            .as_ref()
+            .map_or(0, |job| job.output.lines().count());
+        if (self.output_scroll as usize) + 1 < lines {
+            self.output_scroll += 1;
+        }
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.output_scroll = self.output_scroll.saturating_sub(1);
+    }
+}
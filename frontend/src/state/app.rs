@@ -1,46 +1,168 @@
 use super::{
-    ContainerListState, EditorState, FileListState, MenuState, Pane, SplashState, VimMode, refresh,
+    ActionsState, CommandLine, ConfirmQueue, ContainerDetailsView, ContainerGraphState,
+    ContainerListState, ContainerWatchState, DashboardState, EditorState, FileHistoryState,
+    FileListState, HexViewerState, HostPicker, HygieneReview, ImagesState, InsightsState,
+    KeybindEditorState, LayoutRects, LogsState, Macros, MenuState, Notifications, Outline,
+    OverlayState, Pane, Problems, RunbooksState, SplashState, Split, SplitRatios, SubPane,
+    ThemeEditorState, TransitionState, UpstreamReview, VimMode, refresh,
+    refresh::{ManualRefresh, RefreshBackoff},
 };
 use crate::storage::SavedState;
+use crate::utils::debounce::Debounce;
 use crate::{
-    api::ContainerDetails,
+    api::{ContainerDetails, FrontendConfigResponse, VersionResponse},
     keybinds::Keybinds,
     storage,
     theme::{ThemeConfig, load_current_theme},
 };
+use gloo_timers::callback::Interval;
+use std::{cell::RefCell, rc::Rc};
+
+/// Quiet time after the last key before the session is persisted
+const SESSION_SAVE_DELAY_MS: u32 = 500;
 
 pub struct AppState {
     pub focus: Pane,
+    /// Part of the focused pane's view with the keys, back to the main
+    /// widget whenever the pane changes
+    pub sub_focus: SubPane,
     pub vim_mode: VimMode,
     pub menu: MenuState,
     pub splash: SplashState,
+    /// Effect after switching between the menu and the lists
+    pub transition: TransitionState,
+    /// Banner and MOTD from sysrat.toml, the built-in art until loaded
+    pub branding: FrontendConfigResponse,
+    /// Build of the server, None until `/api/version` answered
+    pub server_version: Option<VersionResponse>,
     pub file_list: FileListState,
     pub container_list: ContainerListState,
+    pub container_graph: ContainerGraphState,
     pub container_details: Option<ContainerDetails>,
+    pub details_view: ContainerDetailsView,
+    pub container_watch: ContainerWatchState,
+    pub dashboard: DashboardState,
     pub editor: EditorState,
+    pub file_history: FileHistoryState,
+    pub hex_viewer: HexViewerState,
+    pub theme_editor: ThemeEditorState,
+    pub keybind_editor: KeybindEditorState,
+    pub actions: ActionsState,
+    pub runbooks: RunbooksState,
+    pub insights: InsightsState,
+    pub logs: LogsState,
+    pub images: ImagesState,
+    pub overlay: OverlayState,
+    /// Yes/no dialogs guarding destructive actions, shown above any overlay
+    pub confirms: ConfirmQueue,
+    /// Upstream check shown in the upstream overlay
+    pub upstream: Option<UpstreamReview>,
+    /// Whitespace fixes waiting for a decision in the hygiene overlay
+    pub hygiene: Option<HygieneReview>,
+    /// Headings of the open file listed in the outline overlay
+    pub outline: Option<Outline>,
+    /// Servers listed in the host switcher overlay
+    pub host_picker: Option<HostPicker>,
+    /// The editor's `:` line while it is typed
+    pub command_line: Option<CommandLine>,
+    /// Recorded editor macros and the one being recorded
+    pub macros: Macros,
+    /// Lint warnings and schema errors of the checked files, listed in the
+    /// problems drawer
+    pub problems: Problems,
+    /// Theme of the open file shown in place of the preference, see
+    /// `sync_file_theme`
+    pub file_theme: Option<String>,
+    /// Areas drawn in the last frame; written while rendering (which only
+    /// sees `&AppState`) and read to hit-test mouse events
+    pub layout: RefCell<LayoutRects>,
+    /// Widths of the two-pane views, resized by keys or by dragging
+    pub splits: SplitRatios,
+    /// Split whose divider is being dragged with the mouse
+    pub split_drag: Option<Split>,
+    /// Toasts reporting the outcome of background requests
+    pub notifications: Notifications,
     pub dirty: bool,
     pub status_message: Option<String>,
     pub keybinds: Keybinds,
     pub current_theme: ThemeConfig,
     pub restored_state: Option<SavedState>,
+    /// Waits for the keys to settle before `save_session` runs
+    session_save: Debounce,
+    /// Session as last persisted, to skip writes of unchanged data
+    persisted: Option<SavedState>,
+    /// File the restored editor shows once its buffer is read from IndexedDB
+    pub pending_buffer: Option<String>,
+    /// Background refresh timer, only alive while a polling pane is focused
+    pub background_refresh: Option<Interval>,
+    /// Interval of the running background refresh timer
+    pub background_refresh_ms: u32,
+    pub refresh_backoff: RefreshBackoff,
+    /// The browser reports no network; cached data stays on screen and
+    /// failures are not reported one by one
+    pub offline: bool,
+    /// Last refresh asked for with the global refresh key
+    pub manual_refresh: Option<ManualRefresh>,
+    /// Frame times, API latencies and memory drawn over the panes
+    pub perf_hud: bool,
 }
 
 impl AppState {
     pub fn new() -> Self {
         let mut state = Self {
             focus: Pane::Splash,
+            sub_focus: SubPane::Main,
             vim_mode: VimMode::Normal,
             menu: MenuState::new(),
             splash: SplashState::new(),
+            transition: TransitionState::new(),
+            branding: FrontendConfigResponse::default(),
+            server_version: None,
             file_list: FileListState::new(),
             container_list: ContainerListState::new(),
+            container_graph: ContainerGraphState::new(),
             container_details: None,
+            details_view: ContainerDetailsView::new(),
+            container_watch: ContainerWatchState::new(),
+            dashboard: DashboardState::new(),
             editor: EditorState::new(),
+            file_history: FileHistoryState::new(),
+            hex_viewer: HexViewerState::new(),
+            theme_editor: ThemeEditorState::new(),
+            keybind_editor: KeybindEditorState::new(),
+            actions: ActionsState::new(),
+            runbooks: RunbooksState::new(),
+            insights: InsightsState::new(),
+            logs: LogsState::new(),
+            images: ImagesState::new(),
+            overlay: OverlayState::new(),
+            confirms: ConfirmQueue::new(),
+            upstream: None,
+            hygiene: None,
+            outline: None,
+            host_picker: None,
+            command_line: None,
+            macros: Macros::load(),
+            problems: Problems::default(),
+            file_theme: None,
+            layout: RefCell::new(LayoutRects::default()),
+            splits: SplitRatios::load(),
+            split_drag: None,
+            notifications: Notifications::new(),
             dirty: false,
             status_message: None,
             keybinds: Keybinds::load(),
             current_theme: load_current_theme(),
             restored_state: None,
+            session_save: Debounce::new(SESSION_SAVE_DELAY_MS),
+            persisted: None,
+            pending_buffer: None,
+            background_refresh: None,
+            background_refresh_ms: 0,
+            refresh_backoff: RefreshBackoff::new(),
+            offline: !crate::api::is_online(),
+            manual_refresh: None,
+            perf_hud: false,
         };
 
         // Check if we've already shown the splash screen in this session
@@ -59,19 +181,24 @@ impl AppState {
             false
         };
 
+        // With the splash turned off the session is restored right away
+        let splash_off = storage::load_splash_ms() == Some(0);
+        if splash_off {
+            state.focus = Pane::Menu;
+        }
+
         // Try to restore from localStorage
         if let Some(saved) = storage::load_state() {
-            if splash_seen {
+            state.editor.positions = saved.positions.clone();
+            if splash_seen || splash_off {
                 // Restore immediately if we've already seen the splash
                 if let Some(pane) = Pane::from_str(&saved.pane) {
                     state.focus = pane;
 
-                    // If we were in the editor, restore the file
-                    if pane == Pane::Editor
-                        && let (Some(filename), Some(content)) = (saved.filename, saved.content)
-                    {
-                        state.editor.load_content(filename, content);
-                        state.dirty = false;
+                    // If we were in the editor, restore the file once its
+                    // buffer is read
+                    if pane == Pane::Editor {
+                        state.pending_buffer = saved.filename;
                     }
                 }
             } else {
@@ -83,20 +210,177 @@ impl AppState {
         state
     }
 
-    pub fn save_to_storage(&self) {
-        let filename = self.editor.current_file.as_deref();
-        let content = if self.editor.current_file.is_some() {
-            Some(self.editor.textarea.lines().join("\n"))
-        } else {
-            None
+    /// Persist the session once the keys have been quiet for a moment
+    pub fn save_to_storage(&mut self, state_rc: &Rc<RefCell<AppState>>) {
+        let state_clone = Rc::clone(state_rc);
+        self.session_save.schedule(move || {
+            // Busy with a key event: that one schedules the next save
+            if let Ok(mut st) = state_clone.try_borrow_mut() {
+                st.save_session();
+            }
+        });
+    }
+
+    /// Persist a session save still waiting, e.g. before the page unloads.
+    /// A save that already ran finds nothing changed.
+    pub fn flush_session(&mut self) {
+        if self.session_save.cancel() {
+            self.save_session();
+        }
+    }
+
+    /// Runs from the debounce timer, which must not be dropped while it runs
+    fn save_session(&mut self) {
+        let filename = self.editor.current_file.clone();
+        // A clean buffer is the file as loaded, no need to join the lines
+        // Revealed secrets are not written to the browser's storage
+        let content = filename
+            .as_ref()
+            .filter(|_| !self.editor.revealed)
+            .map(|_| {
+                if self.dirty {
+                    self.editor.textarea.lines().join("\n")
+                } else {
+                    self.editor.original_content.clone()
+                }
+            });
+
+        // History is a view on the open file, a reload returns to the editor.
+        // Binary files are not kept, the hex viewer reloads to the file list.
+        // Theme edits are only kept once saved, a reload returns to the menu.
+        // A watch is only meant for the visit, it reloads to the container list
+        let pane = match self.focus {
+            Pane::FileHistory => Pane::Editor,
+            Pane::HexViewer => Pane::FileList,
+            Pane::ContainerWatch => Pane::ContainerList,
+            Pane::ThemeEditor | Pane::KeybindEditor => Pane::Menu,
+            pane => pane,
+        };
+        self.editor.remember_position();
+        let session = SavedState {
+            pane: pane.as_str().to_string(),
+            filename,
+            content,
+            positions: self.editor.positions.clone(),
         };
 
-        storage::save_state(self.focus.as_str(), filename, content.as_deref());
+        // Only what changed since the last save is written
+        let persisted = self.persisted.as_ref();
+        let state_changed = persisted.is_none_or(|p| {
+            p.pane != session.pane
+                || p.filename != session.filename
+                || p.positions != session.positions
+        });
+        let buffer_changed = persisted
+            .is_none_or(|p| p.filename != session.filename || p.content != session.content);
+        if state_changed || buffer_changed {
+            storage::save_state(&SavedState {
+                content: session.content.clone().filter(|_| buffer_changed),
+                ..session.clone()
+            });
+        }
+        self.persisted = Some(session);
 
         // Also save current selection for lists
         refresh::save_selection(self.focus, self);
     }
 
+    /// Leave the splash screen for the restored session, or the menu when
+    /// there is none
+    pub fn finish_splash(&mut self, state_rc: &Rc<RefCell<AppState>>) {
+        let Some(saved) = self.restored_state.take() else {
+            self.set_focus(Pane::Menu, state_rc);
+            return;
+        };
+        let Some(pane) = Pane::from_str(&saved.pane) else {
+            self.set_focus(Pane::Menu, state_rc);
+            return;
+        };
+        self.set_focus(pane, state_rc);
+
+        // If we were in the editor, restore the file, or wait for its
+        // buffer if it is still being read
+        if pane == Pane::Editor
+            && let Some(filename) = saved.filename
+        {
+            if let Some(content) = saved.content {
+                self.editor.load_content(filename, content);
+                self.dirty = false;
+                self.sync_file_settings();
+            } else {
+                self.pending_buffer = Some(filename);
+            }
+        }
+    }
+
+    /// Switch focus to another pane, running pane lifecycle hooks
+    pub fn set_focus(&mut self, pane: Pane, state_rc: &Rc<RefCell<AppState>>) {
+        let previous = self.focus;
+        self.focus = pane;
+        if previous != pane {
+            self.sub_focus = SubPane::Main;
+            self.transition
+                .start(previous, pane, self.current_theme.mantle());
+            refresh::on_pane_change(previous, pane, self, state_rc);
+        }
+        // Also when the editor stays focused but shows another file
+        self.sync_file_settings();
+    }
+
+    /// Whether `sub` of `pane` has the keys
+    pub fn has_focus(&self, pane: Pane, sub: SubPane) -> bool {
+        self.focus == pane && self.sub_focus == sub
+    }
+
+    /// Whether the main widget of `pane` has the keys, i.e. draws its
+    /// border focused
+    pub fn is_focused(&self, pane: Pane) -> bool {
+        self.has_focus(pane, SubPane::Main)
+    }
+
+    /// Move the keys to `sub` if the focused pane shows it
+    pub fn focus_sub(&mut self, sub: SubPane) {
+        if self.focus.focus_ring().contains(&sub) && self.shows(sub) {
+            self.sub_focus = sub;
+        }
+    }
+
+    /// Whether `sub` is drawn when its pane is; the problems drawer can be
+    /// hidden
+    fn shows(&self, sub: SubPane) -> bool {
+        sub != SubPane::Problems || self.problems.open
+    }
+
+    /// Move the keys to the next (or previous) sub-pane of the focused pane's
+    /// ring, wrapping around. Returns false if the pane shows no side panes.
+    pub fn cycle_focus(&mut self, forward: bool) -> bool {
+        let ring: Vec<SubPane> = self
+            .focus
+            .focus_ring()
+            .iter()
+            .copied()
+            .filter(|sub| self.shows(*sub))
+            .collect();
+        if ring.len() < 2 {
+            return false;
+        }
+        let current = ring.iter().position(|s| *s == self.sub_focus).unwrap_or(0);
+        let next = if forward {
+            (current + 1) % ring.len()
+        } else {
+            (current + ring.len() - 1) % ring.len()
+        };
+        self.sub_focus = ring[next];
+        true
+    }
+
+    /// Keybinds section of whatever has the keys, for the help overlay
+    pub fn keybind_section(&self) -> Option<&'static str> {
+        self.sub_focus
+            .keybind_section()
+            .or_else(|| self.focus.keybind_section())
+    }
+
     pub fn set_status(&mut self, message: impl Into<String>) {
         self.status_message = Some(message.into());
     }
@@ -108,37 +392,14 @@ impl AppState {
 
     pub fn check_dirty(&mut self) {
         let current_content = self.editor.textarea.lines().join("\n");
-        self.dirty = current_content != self.editor.original_content;
+        self.dirty = current_content != self.editor.original_content
+            || self.editor.format_change().is_some();
     }
 
     pub fn set_theme(&mut self, theme_name: &str) {
-        // [DEBUG_START] set_theme diagnostics
-        // web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!(
-        //     "[DEBUG] set_theme called with: '{}'",
-        //     theme_name
-        // )));
-        // [DEBUG_END]
-
-        match crate::theme::load_theme_by_name(theme_name) {
-            Ok(theme) => {
-                // [DEBUG_START] Successful theme load diagnostics
-                // web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!(
-                //     "[DEBUG] Theme '{}' loaded successfully in set_theme",
-                //     theme_name
-                // )));
-                // [DEBUG_END]
-
-                self.current_theme = theme;
+        match self.switch_theme(theme_name) {
+            Ok(()) => {
                 crate::theme::save_theme_preference(theme_name);
-
-                // Update DOM elements
-                if let Err(e) = crate::update_dom_for_theme(&self.current_theme) {
-                    web_sys::console::error_1(&wasm_bindgen::JsValue::from_str(&format!(
-                        "Failed to update DOM for theme: {:?}",
-                        e
-                    )));
-                }
-
                 self.set_status(format!("Theme changed to: {}", theme_name));
             }
             Err(e) => {
@@ -150,4 +411,125 @@ impl AppState {
             }
         }
     }
+
+    /// Show `theme_name` without touching the saved preference
+    fn switch_theme(&mut self, theme_name: &str) -> Result<(), String> {
+        self.current_theme = crate::theme::load_theme_by_name(theme_name)?;
+        self.apply_theme_to_dom();
+        self.editor.refresh_preview(&self.current_theme);
+        Ok(())
+    }
+
+    /// Apply what sysrat.toml declares for the open file: its editing
+    /// options and, while the editor is in view, its theme
+    pub fn sync_file_settings(&mut self) {
+        self.sync_file_options();
+        self.sync_file_theme();
+    }
+
+    /// Editing options once per loaded file, as soon as the file list has it
+    fn sync_file_options(&mut self) {
+        let Some(name) = self.editor.current_file.as_ref() else {
+            return;
+        };
+        if self.editor.options_file.as_ref() == Some(name) {
+            return;
+        }
+        if let Some(file) = self.file_list.all.iter().find(|file| &file.name == name) {
+            let options = file.editing;
+            self.editor.apply_options(options);
+        }
+    }
+
+    /// Use the theme the open file declares in sysrat.toml while the editor
+    /// is in view, and the preferred theme again once it is not
+    fn sync_file_theme(&mut self) {
+        let wanted = matches!(self.focus, Pane::Editor | Pane::FileList)
+            .then(|| {
+                let name = self.editor.current_file.as_ref()?;
+                let file = self.file_list.all.iter().find(|file| &file.name == name)?;
+                file.theme.clone()
+            })
+            .flatten();
+        if wanted == self.file_theme {
+            return;
+        }
+
+        match &wanted {
+            Some(name) => {
+                if let Err(e) = self.switch_theme(name) {
+                    self.set_status(format!("[Theme of the file not applied: {}]", e));
+                }
+            }
+            None => {
+                self.current_theme = crate::theme::load_current_theme();
+                self.apply_theme_to_dom();
+                self.editor.refresh_preview(&self.current_theme);
+            }
+        }
+        self.file_theme = wanted;
+    }
+
+    /// Update DOM elements (background + font) for the current theme
+    pub fn apply_theme_to_dom(&self) {
+        if let Err(e) = crate::update_dom_for_theme(&self.current_theme) {
+            web_sys::console::error_1(&wasm_bindgen::JsValue::from_str(&format!(
+                "Failed to update DOM for theme: {:?}",
+                e
+            )));
+        }
+    }
+
+    /// Switch to the theme fitting the browser color scheme, if auto mode is on
+    pub fn apply_auto_theme(&mut self) {
+        // Scheme changes must not throw away edits previewed in the theme editor
+        // Nor replace the theme of the open file, it returns when that closes
+        if !crate::theme::auto_theme_enabled()
+            || self.focus == Pane::ThemeEditor
+            || self.file_theme.is_some()
+        {
+            return;
+        }
+        self.current_theme = crate::theme::load_current_theme();
+        self.apply_theme_to_dom();
+    }
+
+    /// Turn auto mode on (follow the color scheme) or off (keep the current theme)
+    pub fn toggle_auto_theme(&mut self) {
+        let name = crate::theme::current_theme_name();
+        if crate::theme::auto_theme_enabled() {
+            crate::theme::set_auto_theme(false);
+            crate::theme::save_theme_preference(&name);
+            self.set_status(format!("Theme: {} (fixed)", name));
+        } else {
+            crate::theme::set_auto_theme(true);
+            self.apply_auto_theme();
+            let variant = match crate::theme::system_variant() {
+                crate::theme::ThemeVariant::Light => "light",
+                crate::theme::ThemeVariant::Dark => "dark",
+            };
+            self.set_status(format!(
+                "Theme: {} (auto, {})",
+                crate::theme::current_theme_name(),
+                variant
+            ));
+        }
+    }
+
+    /// Start editing the active theme in the theme editor
+    pub fn open_theme_editor(&mut self) {
+        let name = crate::theme::current_theme_name();
+        self.theme_editor.open(name, &self.current_theme);
+    }
+
+    /// Restore the theme as it was before unsaved theme editor changes
+    pub fn discard_theme_edits(&mut self) {
+        if self.theme_editor.dirty
+            && let Some(original) = self.theme_editor.original.clone()
+        {
+            self.current_theme = original;
+            self.theme_editor.dirty = false;
+            self.apply_theme_to_dom();
+        }
+    }
 }
@@ -0,0 +1,48 @@
+use crate::utils::command::{self, Completion};
+
+/// The editor's `:` line being typed, with the Tab completions of its
+/// argument
+#[derive(Default)]
+pub struct CommandLine {
+    pub input: String,
+    /// Candidates for the argument as typed before the first Tab
+    completions: Vec<String>,
+    /// Candidate shown in `input`
+    completion: usize,
+}
+
+impl CommandLine {
+    pub fn push(&mut self, c: char) {
+        self.input.push(c);
+        self.completions.clear();
+    }
+
+    /// Delete the last character, false if there was none
+    pub fn pop(&mut self) -> bool {
+        self.completions.clear();
+        self.input.pop().is_some()
+    }
+
+    /// Replace the argument with the first of `candidates` it starts, then
+    /// with the next one on each further call
+    pub fn complete(&mut self, candidates: impl FnOnce(Completion) -> Vec<String>) {
+        let Some((kind, typed)) = command::completing(&self.input) else {
+            return;
+        };
+        let start = self.input.len() - typed.len();
+        if self.completions.is_empty() {
+            let typed = typed.to_string();
+            self.completions = candidates(kind)
+                .into_iter()
+                .filter(|candidate| candidate.starts_with(&typed))
+                .collect();
+            self.completion = 0;
+        } else {
+            self.completion = (self.completion + 1) % self.completions.len();
+        }
+        if let Some(candidate) = self.completions.get(self.completion) {
+            self.input.truncate(start);
+            self.input.push_str(candidate);
+        }
+    }
+}
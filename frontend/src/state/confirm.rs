@@ -0,0 +1,133 @@
+use super::Pane;
+use crate::api::QuickAction;
+use std::collections::{HashMap, VecDeque};
+
+/// What runs once a confirmation dialog is answered with yes
+#[derive(Debug, Clone)]
+pub enum ConfirmAction {
+    StopContainer {
+        id: String,
+        name: String,
+    },
+    RestartContainer {
+        id: String,
+        name: String,
+    },
+    /// Dashboard quick action that stops or restarts a container
+    QuickAction(QuickAction),
+    /// Open another file in the editor
+    OpenFile(String),
+    /// Leave the config file panes for another pane
+    Focus(Pane),
+    /// Run a scriptable action from sysrat.toml with its parameter values
+    RunAction {
+        name: String,
+        params: HashMap<String, String>,
+    },
+    /// Carry out step `index` of the runbook being walked through
+    RunbookStep {
+        runbook: String,
+        index: usize,
+    },
+    /// Leave a runbook before all its steps are through
+    AbandonRunbook(String),
+    /// Replace a file with the reviewed upstream version
+    ApplyUpstream {
+        filename: String,
+        sha256: String,
+    },
+}
+
+/// A yes/no question shown as a modal
+#[derive(Debug, Clone)]
+pub struct Confirm {
+    pub title: String,
+    pub message: String,
+    pub action: ConfirmAction,
+    /// Unsaved-changes prompt: answered with save, discard or cancel
+    /// instead of yes/no
+    pub unsaved: bool,
+    /// Text that must be typed before yes is accepted (protected containers)
+    pub expected: Option<String>,
+    /// What has been typed so far
+    pub input: String,
+}
+
+impl Confirm {
+    /// Whether yes runs the action, typed confirmations need an exact match
+    pub fn accepted(&self) -> bool {
+        self.expected.as_ref().is_none_or(|e| *e == self.input)
+    }
+}
+
+/// Pending confirmations, the front one is shown and takes all keys
+pub struct ConfirmQueue {
+    pending: VecDeque<Confirm>,
+}
+
+impl ConfirmQueue {
+    pub fn new() -> Self {
+        Self {
+            pending: VecDeque::new(),
+        }
+    }
+
+    pub fn push(
+        &mut self,
+        title: impl Into<String>,
+        message: impl Into<String>,
+        action: ConfirmAction,
+    ) {
+        self.pending.push_back(Confirm {
+            title: title.into(),
+            message: message.into(),
+            action,
+            unsaved: false,
+            expected: None,
+            input: String::new(),
+        });
+    }
+
+    /// Ask for `expected` to be typed out before running `action`
+    pub fn push_typed(
+        &mut self,
+        title: impl Into<String>,
+        message: impl Into<String>,
+        expected: impl Into<String>,
+        action: ConfirmAction,
+    ) {
+        self.pending.push_back(Confirm {
+            title: title.into(),
+            message: message.into(),
+            action,
+            unsaved: false,
+            expected: Some(expected.into()),
+            input: String::new(),
+        });
+    }
+
+    /// Ask to save or discard the edits of `filename` before running `action`
+    pub fn push_unsaved(&mut self, filename: &str, action: ConfirmAction) {
+        self.pending.push_back(Confirm {
+            title: "Unsaved changes".to_string(),
+            message: format!("{} has unsaved changes.", filename),
+            action,
+            unsaved: true,
+            expected: None,
+            input: String::new(),
+        });
+    }
+
+    pub fn current(&self) -> Option<&Confirm> {
+        self.pending.front()
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut Confirm> {
+        self.pending.front_mut()
+    }
+
+    /// Remove the shown dialog, returning it so its action can run
+    pub fn pop(&mut self) -> Option<Confirm> {
+        self.pending.pop_front()
+    }
+}
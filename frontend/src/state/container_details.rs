@@ -0,0 +1,32 @@
+/// Scroll position of the container details shown next to the container
+/// list and the graph
+pub struct ContainerDetailsView {
+    /// First visible line (after wrapping)
+    pub offset: u16,
+    /// Details of a container are being fetched
+    pub loading: bool,
+    /// Container the details were last requested for, fetched again on retry
+    pub container_id: Option<String>,
+    /// Why the last fetch failed, cleared by the next successful one
+    pub error: Option<String>,
+}
+
+impl ContainerDetailsView {
+    pub fn new() -> Self {
+        Self {
+            offset: 0,
+            loading: false,
+            container_id: None,
+            error: None,
+        }
+    }
+
+    /// Scroll towards the end; `max` is the furthest offset of the last frame
+    pub fn scroll_down(&mut self, lines: u16, max: u16) {
+        self.offset = self.offset.saturating_add(lines).min(max);
+    }
+
+    pub fn scroll_up(&mut self, lines: u16) {
+        self.offset = self.offset.saturating_sub(lines);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::api::{ContainerGraphResponse, GraphNode};
+
+/// Fallback group for containers without any network (e.g. `network_mode: none`)
+pub const NO_NETWORK: &str = "(no network)";
+
+/// A selectable row in the graph: a container listed under one of its networks
+#[derive(Clone, PartialEq)]
+pub struct GraphEntry {
+    pub network: String,
+    pub node_id: String,
+}
+
+pub struct ContainerGraphState {
+    pub graph: Option<ContainerGraphResponse>,
+    /// Containers in display order, once per network they are attached to
+    pub entries: Vec<GraphEntry>,
+    pub selected_index: usize,
+}
+
+impl ContainerGraphState {
+    pub fn new() -> Self {
+        Self {
+            graph: None,
+            entries: Vec::new(),
+            selected_index: 0,
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.entries.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected_index = if self.selected_index == 0 {
+                self.entries.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+        }
+    }
+
+    pub fn selected_node(&self) -> Option<&GraphNode> {
+        let entry = self.entries.get(self.selected_index)?;
+        self.node(&entry.node_id)
+    }
+
+    pub fn node(&self, id: &str) -> Option<&GraphNode> {
+        self.graph.as_ref()?.nodes.iter().find(|n| n.id == id)
+    }
+
+    /// Names of containers sharing a volume with `id`, with the volume name
+    pub fn shared_volume_peers(&self, id: &str) -> Vec<(String, String)> {
+        let Some(graph) = &self.graph else {
+            return Vec::new();
+        };
+        graph
+            .shared_volumes
+            .iter()
+            .filter(|group| group.members.iter().any(|m| m == id))
+            .flat_map(|group| {
+                group
+                    .members
+                    .iter()
+                    .filter(|m| *m != id)
+                    .filter_map(|m| self.node(m))
+                    .map(|peer| (group.name.clone(), peer.name.clone()))
+            })
+            .collect()
+    }
+
+    pub fn set_graph(&mut self, graph: ContainerGraphResponse) {
+        // Preserve selection by network + container
+        let selected = self.entries.get(self.selected_index).cloned();
+
+        let mut entries: Vec<GraphEntry> = graph
+            .networks
+            .iter()
+            .flat_map(|group| {
+                group.members.iter().map(|id| GraphEntry {
+                    network: group.name.clone(),
+                    node_id: id.clone(),
+                })
+            })
+            .collect();
+        entries.extend(
+            graph
+                .nodes
+                .iter()
+                .filter(|n| n.networks.is_empty())
+                .map(|n| GraphEntry {
+                    network: NO_NETWORK.to_string(),
+                    node_id: n.id.clone(),
+                }),
+        );
+
+        self.entries = entries;
+        self.graph = Some(graph);
+        self.selected_index = selected
+            .and_then(|sel| self.entries.iter().position(|e| *e == sel))
+            .unwrap_or(0);
+    }
+}
@@ -1,18 +1,81 @@
-use crate::api::ContainerInfo;
+use super::marks::MarkedNames;
+use crate::api::{self, ContainerInfo, DaemonOutage};
+use crate::storage;
 
 pub struct ContainerListState {
+    /// Every container from the server
+    pub all: Vec<ContainerInfo>,
+    /// Containers shown in the list: pinned first, hidden ones filtered out,
+    /// unhealthy ones next when `unhealthy_first` is set
     pub containers: Vec<ContainerInfo>,
     pub selected_index: usize,
+    /// Host the containers run on, scopes the stable identities below
+    pub host: String,
+    /// Starred containers by identity (ids change when a container is recreated)
+    pub pinned: MarkedNames,
+    /// Containers left out of the list unless `show_hidden` is set
+    pub hidden: MarkedNames,
+    pub show_hidden: bool,
+    /// Sort containers with a failing health check to the top
+    pub unhealthy_first: bool,
+    /// A fetch of the list is in flight
+    pub loading: bool,
+    /// Why the last fetch failed, cleared by the next successful one
+    pub error: Option<String>,
+    /// The server cannot reach the docker daemon; the last containers stay
+    /// listed under a banner until it is back
+    pub outage: Option<DaemonOutage>,
 }
 
 impl ContainerListState {
     pub fn new() -> Self {
+        let host = api::host();
+        let mut pinned = MarkedNames::load(storage::keys::PINNED_CONTAINERS);
+        let mut hidden = MarkedNames::load(storage::keys::HIDDEN_CONTAINERS);
+        pinned.qualify(&host);
+        hidden.qualify(&host);
+
         Self {
+            all: Vec::new(),
             containers: Vec::new(),
             selected_index: 0,
+            host,
+            pinned,
+            hidden,
+            show_hidden: false,
+            unhealthy_first: storage::generic::load(storage::keys::UNHEALTHY_FIRST)
+                .unwrap_or(false),
+            loading: false,
+            error: None,
+            outage: None,
         }
     }
 
+    /// Stable identity of a container: `host/name` survives the new docker id
+    /// a container gets when it is recreated (e.g. after an image update)
+    pub fn identity(&self, container: &ContainerInfo) -> String {
+        format!("{}/{}", self.host, container.name)
+    }
+
+    pub fn is_pinned(&self, container: &ContainerInfo) -> bool {
+        self.pinned.contains(&self.identity(container))
+    }
+
+    pub fn is_hidden(&self, container: &ContainerInfo) -> bool {
+        self.hidden.contains(&self.identity(container))
+    }
+
+    /// Names of the containers pinned on this host
+    pub fn pinned_names(&self) -> Vec<String> {
+        let prefix = format!("{}/", self.host);
+        self.pinned
+            .names()
+            .iter()
+            .filter_map(|id| id.strip_prefix(&prefix))
+            .map(str::to_string)
+            .collect()
+    }
+
     pub fn next(&mut self) {
         if !self.containers.is_empty() {
             self.selected_index = (self.selected_index + 1) % self.containers.len();
@@ -33,17 +96,87 @@ impl ContainerListState {
         self.containers.get(self.selected_index)
     }
 
+    /// Identity of the selected container, persisted across reloads
+    pub fn selected_identity(&self) -> Option<String> {
+        self._selected().map(|c| self.identity(c))
+    }
+
+    /// Select the container with `identity`, returns whether it is listed
+    pub fn select_identity(&mut self, identity: &str) -> bool {
+        let pos = self
+            .containers
+            .iter()
+            .position(|c| self.identity(c) == identity);
+        if let Some(pos) = pos {
+            self.selected_index = pos;
+        }
+        pos.is_some()
+    }
+
     pub fn set_containers(&mut self, containers: Vec<ContainerInfo>) {
-        // Preserve selection by container ID
-        let selected_id = self._selected().map(|c| c.id.clone());
+        self.all = containers;
+        self.apply_marks();
+    }
 
-        self.containers = containers;
+    /// Pin or unpin the selected container, returns its name and new pin state
+    pub fn toggle_pin_selected(&mut self) -> Option<(String, bool)> {
+        let container = self._selected()?;
+        let (name, identity) = (container.name.clone(), self.identity(container));
+        let pinned = self.pinned.toggle(&identity);
+        self.apply_marks();
+        Some((name, pinned))
+    }
 
-        // Try to restore previous selection
-        if let Some(id) = selected_id
-            && let Some(pos) = self.containers.iter().position(|c| c.id == id)
+    /// Hide or unhide the selected container, returns its name and new hide state
+    pub fn toggle_hide_selected(&mut self) -> Option<(String, bool)> {
+        let container = self._selected()?;
+        let (name, identity) = (container.name.clone(), self.identity(container));
+        let hidden = self.hidden.toggle(&identity);
+        self.apply_marks();
+        Some((name, hidden))
+    }
+
+    /// List hidden containers too (dimmed), returns the new setting
+    pub fn toggle_show_hidden(&mut self) -> bool {
+        self.show_hidden = !self.show_hidden;
+        self.apply_marks();
+        self.show_hidden
+    }
+
+    /// Sort unhealthy containers to the top or not, returns the new setting
+    pub fn toggle_unhealthy_first(&mut self) -> bool {
+        self.unhealthy_first = !self.unhealthy_first;
+        storage::generic::save(storage::keys::UNHEALTHY_FIRST, &self.unhealthy_first);
+        self.apply_marks();
+        self.unhealthy_first
+    }
+
+    /// Rebuild `containers` from `all`, keeping the selection on the same container
+    fn apply_marks(&mut self) {
+        let selected = self.selected_identity();
+
+        self.containers = self
+            .all
+            .iter()
+            .filter(|c| self.show_hidden || !self.is_hidden(c))
+            .cloned()
+            .collect();
+        // Pinned first, like `MarkedNames::sort` but keyed by identity, then
+        // failing health checks if asked for (the sort is stable)
+        let (pinned, host) = (&self.pinned, &self.host);
+        let unhealthy_first = self.unhealthy_first;
+        self.containers.sort_by_key(|c| {
+            (
+                !pinned.contains(&format!("{}/{}", host, c.name)),
+                !(unhealthy_first && c.health.as_deref() == Some("unhealthy")),
+            )
+        });
+
+        // Try to restore previous selection (by identity, so it follows a
+        // recreated container to its new id)
+        if let Some(identity) = selected
+            && self.select_identity(&identity)
         {
-            self.selected_index = pos;
             return;
         }
 
@@ -0,0 +1,62 @@
+use crate::api::ContainerWatchResponse;
+use gloo_timers::callback::Interval;
+
+/// Focused live view of one container, polled every second while shown
+pub struct ContainerWatchState {
+    /// Container being watched, by id
+    pub container_id: Option<String>,
+    /// Name from the list, shown until the first poll answers
+    pub name: String,
+    pub data: Option<ContainerWatchResponse>,
+    /// Why the last poll failed, cleared by the next successful one
+    pub error: Option<String>,
+    /// Log lines scrolled up from the tail, 0 follows new lines
+    pub log_scroll: usize,
+    /// Polls stop while paused, the last answer stays on screen
+    pub paused: bool,
+    /// A poll is on its way, the next tick is skipped instead of piling up
+    pub in_flight: bool,
+    /// Poll timer, only alive while the pane is focused
+    pub poll: Option<Interval>,
+}
+
+impl ContainerWatchState {
+    pub fn new() -> Self {
+        Self {
+            container_id: None,
+            name: String::new(),
+            data: None,
+            error: None,
+            log_scroll: 0,
+            paused: false,
+            in_flight: false,
+            poll: None,
+        }
+    }
+
+    /// Start watching a container, dropping what was shown for the last one
+    pub fn open(&mut self, container_id: String, name: String) {
+        self.container_id = Some(container_id);
+        self.name = name;
+        self.data = None;
+        self.error = None;
+        self.log_scroll = 0;
+        self.paused = false;
+        self.in_flight = false;
+    }
+
+    pub fn scroll_up(&mut self) {
+        let lines = self.data.as_ref().map_or(0, |d| d.logs.len());
+        self.log_scroll = (self.log_scroll + 1).min(lines.saturating_sub(1));
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.log_scroll = self.log_scroll.saturating_sub(1);
+    }
+
+    /// Pause or resume polling, returns whether it is paused now
+    pub fn toggle_pause(&mut self) -> bool {
+        self.paused = !self.paused;
+        self.paused
+    }
+}
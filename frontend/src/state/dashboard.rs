@@ -0,0 +1,32 @@
+use crate::api::{DashboardResponse, QuickAction};
+
+/// Quick actions are bound to the number keys 1-9
+pub const MAX_QUICK_ACTIONS: usize = 9;
+
+pub struct DashboardState {
+    pub data: Option<DashboardResponse>,
+    /// When `data` was fetched (ms since epoch), for relative times
+    pub fetched_at: f64,
+}
+
+impl DashboardState {
+    pub fn new() -> Self {
+        Self {
+            data: None,
+            fetched_at: 0.0,
+        }
+    }
+
+    pub fn set_data(&mut self, data: DashboardResponse, now: f64) {
+        self.data = Some(data);
+        self.fetched_at = now;
+    }
+
+    /// Quick action bound to number key `n` (1-based)
+    pub fn quick_action(&self, n: usize) -> Option<&QuickAction> {
+        if n == 0 || n > MAX_QUICK_ACTIONS {
+            return None;
+        }
+        self.data.as_ref()?.quick_actions.get(n - 1)
+    }
+}
@@ -1,22 +1,123 @@
+use super::{kv_table::KvTable, rendered::RenderedView, schema_form::SchemaForm};
+use crate::{
+    api::{EditingOptions, FileSchema, LintWarning, TextFormat, WritePermissionError},
+    dom,
+    storage::{self, FilePosition},
+    theme::ThemeConfig,
+    utils::{
+        indent::{self, Indent},
+        markdown,
+        pretty::{self, Language},
+    },
+};
+use std::cell::Cell;
 use tui_textarea::TextArea;
 
 pub struct EditorState {
     pub textarea: TextArea<'static>,
     pub current_file: Option<String>,
     pub original_content: String,
+    /// Encoding and line endings the file is saved with
+    pub format: TextFormat,
+    /// Format of the file as loaded or last saved
+    pub original_format: TextFormat,
+    /// X-Sysrat-Override value once a readonly file was unlocked
+    pub override_value: Option<String>,
+    /// Table editing mode for key=value files, None while editing raw text
+    pub table: Option<KvTable>,
+    /// Form view next to the raw text for files with a schema
+    pub form: Option<SchemaForm>,
+    /// Page shown in the rendered preview of Markdown/HTML files, None while hidden
+    pub preview: Option<String>,
+    /// Variables substituted into the saved content, None while hidden
+    pub rendered: Option<RenderedView>,
+    /// Secrets were masked by the server, the text is read-only until revealed
+    pub redacted: bool,
+    /// Loaded with its secrets revealed; kept out of the browser's storage
+    pub revealed: bool,
+    /// ETag of the loaded content, reopening the file skips it if unchanged
+    pub etag: Option<String>,
+    /// Why the server could not write the file on the last save
+    pub write_denied: Option<WritePermissionError>,
+    /// Broken rules of the enabled lint packs, from the last check
+    pub lint: Vec<LintWarning>,
+    /// First text row on screen, mirrored from the textarea's scrolling so
+    /// the lint gutter lines up (written while rendering)
+    pub scroll_top: Cell<u16>,
+    /// Text rows on screen at the last render, the size of a page
+    pub view_height: Cell<u16>,
+    /// First text column on screen, mirrored like `scroll_top`
+    pub scroll_left: Cell<u16>,
+    /// What Tab inserts and how wide a tab is drawn
+    pub indent: Indent,
+    /// Spaces and tabs drawn as marks, remembered across reloads
+    pub show_whitespace: bool,
+    /// Long lines continue on the next rows, remembered across reloads
+    pub wrap: bool,
+    /// Line numbers drawn in the gutter, remembered across reloads
+    pub line_numbers: bool,
+    /// Trailing whitespace is trimmed on every save
+    pub trim_on_save: bool,
+    /// File the `[hygiene]` editing options were applied to; later changes
+    /// of the indentation are kept until another file is loaded
+    pub options_file: Option<String>,
+    /// Cursor and view of the files opened this session and before, most
+    /// recent first, saved with the session
+    pub positions: Vec<FilePosition>,
 }
 
+/// Files whose position is remembered
+const MAX_POSITIONS: usize = 50;
+
 impl EditorState {
     pub fn new() -> Self {
         Self {
             textarea: TextArea::default(),
             current_file: None,
             original_content: String::new(),
+            format: TextFormat::default(),
+            original_format: TextFormat::default(),
+            override_value: None,
+            table: None,
+            form: None,
+            preview: None,
+            rendered: None,
+            redacted: false,
+            revealed: false,
+            etag: None,
+            write_denied: None,
+            lint: Vec::new(),
+            scroll_top: Cell::new(0),
+            view_height: Cell::new(0),
+            scroll_left: Cell::new(0),
+            indent: Indent::default(),
+            show_whitespace: storage::generic::load(storage::keys::SHOW_WHITESPACE)
+                .unwrap_or(false),
+            wrap: storage::generic::load(storage::keys::WRAP_LINES).unwrap_or(false),
+            line_numbers: storage::generic::load(storage::keys::LINE_NUMBERS).unwrap_or(false),
+            trim_on_save: false,
+            options_file: None,
+            positions: Vec::new(),
         }
     }
 
     pub fn load_content(&mut self, filename: String, content: String) {
+        self.remember_position();
         self.current_file = Some(filename);
+        self.format = TextFormat::default();
+        self.original_format = TextFormat::default();
+        self.override_value = None;
+        self.redacted = false;
+        self.revealed = false;
+        self.etag = None;
+        self.write_denied = None;
+        self.lint.clear();
+        self.scroll_top.set(0);
+        self.view_height.set(0);
+        self.table = None;
+        self.form = None;
+        self.rendered = None;
+        self.close_preview();
 
         // Normalize content: split into lines and rejoin
         // This ensures original_content matches what textarea.lines().join("\n") produces
@@ -24,6 +125,256 @@ impl EditorState {
         self.original_content = lines.join("\n");
 
         self.textarea = TextArea::new(lines);
+        self.scroll_left.set(0);
+        self.trim_on_save = false;
+        self.options_file = None;
+        self.set_indent(indent::detect(self.textarea.lines()).unwrap_or_default());
+        self.restore_position();
+    }
+
+    /// Note where the cursor and view are in the open file
+    pub fn remember_position(&mut self) {
+        let Some(filename) = self.current_file.clone() else {
+            return;
+        };
+        let (row, col) = self.textarea.cursor();
+        let position = FilePosition {
+            filename,
+            row,
+            col,
+            top: self.scroll_top.get(),
+            left: self.scroll_left.get(),
+        };
+        self.positions
+            .retain(|known| known.filename != position.filename);
+        self.positions.insert(0, position);
+        self.positions.truncate(MAX_POSITIONS);
+    }
+
+    /// Put the cursor and view of the just loaded file back where they were
+    fn restore_position(&mut self) {
+        let Some(position) = self
+            .positions
+            .iter()
+            .find(|known| self.current_file.as_ref() == Some(&known.filename))
+            .cloned()
+        else {
+            return;
+        };
+        // Scrolling a textarea that was never drawn sets its view as is and
+        // takes the cursor along; the jump then stays on that screen as long
+        // as the file did not shrink
+        let top = position.top.min(i16::MAX as u16);
+        let left = position.left.min(i16::MAX as u16);
+        self.textarea.scroll((top as i16, left as i16));
+        self.textarea.move_cursor(tui_textarea::CursorMove::Jump(
+            position.row.min(u16::MAX as usize) as u16,
+            position.col.min(u16::MAX as usize) as u16,
+        ));
+        let (row, col) = self.textarea.cursor();
+        self.scroll_top.set(top.min(row as u16));
+        self.scroll_left.set(left.min(col as u16));
+    }
+
+    /// Indent with `indent` from now on
+    pub fn set_indent(&mut self, indent: Indent) {
+        self.indent = indent;
+        self.textarea.set_hard_tab_indent(indent.tabs);
+        self.textarea.set_tab_length(indent.width);
+    }
+
+    /// Follow the `[hygiene]` editing options of the loaded file; what they
+    /// leave unset is detected from its content
+    pub fn apply_options(&mut self, options: Option<EditingOptions>) {
+        let options = options.unwrap_or_default();
+        let detected = indent::detect(self.textarea.lines()).unwrap_or_default();
+        self.set_indent(Indent {
+            tabs: options.indent_tabs.unwrap_or(detected.tabs),
+            width: options.indent_width.unwrap_or(detected.width),
+        });
+        self.trim_on_save = options.trim_on_save;
+        self.options_file = self.current_file.clone();
+    }
+
+    /// Show or hide whitespace marks, returns whether they are shown
+    pub fn toggle_whitespace(&mut self) -> bool {
+        self.show_whitespace = !self.show_whitespace;
+        storage::generic::save(storage::keys::SHOW_WHITESPACE, &self.show_whitespace);
+        self.show_whitespace
+    }
+
+    /// Wrap long lines or scroll them sideways
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+        storage::generic::save(storage::keys::WRAP_LINES, &self.wrap);
+    }
+
+    /// Show or hide line numbers
+    pub fn set_line_numbers(&mut self, shown: bool) {
+        self.line_numbers = shown;
+        storage::generic::save(storage::keys::LINE_NUMBERS, &self.line_numbers);
+    }
+
+    /// Break the line at the cursor, indenting the new one like the old
+    pub fn newline_with_indent(&mut self) {
+        let (row, col) = self.textarea.cursor();
+        let line = &self.textarea.lines()[row];
+        let lead: String = indent::leading(line).chars().take(col).collect();
+        self.textarea.insert_newline();
+        self.textarea.insert_str(lead);
+    }
+
+    /// Pretty-print a JSON, TOML or YAML file, undoable like an edit
+    pub fn pretty_print(&mut self) -> Result<Language, String> {
+        let language = self
+            .current_file
+            .as_deref()
+            .and_then(Language::of)
+            .ok_or("only JSON, TOML and YAML files")?;
+        let content = self.get_content();
+        let formatted = pretty::format(language, &content, self.indent)
+            .map_err(|e| format!("{} does not parse: {}", language.label(), e))?;
+        if formatted != content {
+            let (row, col) = self.textarea.cursor();
+            self.textarea.select_all();
+            self.textarea.insert_str(formatted);
+            self.textarea
+                .move_cursor(tui_textarea::CursorMove::Jump(row as u16, col as u16));
+        }
+        Ok(language)
+    }
+
+    /// Comment out the selected lines, or the cursor line, with `leader`,
+    /// or uncomment them if all are commented. Returns whether they are
+    /// commented now.
+    pub fn toggle_comment(&mut self, leader: &str) -> bool {
+        let (row, col) = self.textarea.cursor();
+        let (first, last) = match self.textarea.selection_range() {
+            Some(((start, _), (end, _))) => (start, end),
+            None => (row, row),
+        };
+        let lines = &self.textarea.lines()[first..=last];
+        let filled = || lines.iter().filter(|line| !line.trim().is_empty());
+        let commented =
+            filled().count() > 0 && filled().all(|line| line.trim_start().starts_with(leader));
+
+        // New comments line up at the smallest indentation of the block
+        let column = filled()
+            .map(|line| indent::leading(line).len())
+            .min()
+            .unwrap_or(0);
+        let block: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                if line.trim().is_empty() {
+                    line.clone()
+                } else if commented {
+                    let lead = indent::leading(line);
+                    let rest = &line[lead.len() + leader.len()..];
+                    format!("{}{}", lead, rest.strip_prefix(' ').unwrap_or(rest))
+                } else {
+                    format!("{}{} {}", &line[..column], leader, &line[column..])
+                }
+            })
+            .collect();
+        let end = self.textarea.lines()[last].chars().count();
+
+        // Replaced as one edit, so it can be undone
+        self.textarea.cancel_selection();
+        self.textarea
+            .move_cursor(tui_textarea::CursorMove::Jump(first as u16, 0));
+        self.textarea.start_selection();
+        self.textarea
+            .move_cursor(tui_textarea::CursorMove::Jump(last as u16, end as u16));
+        self.textarea.insert_str(block.join("\n"));
+        self.textarea
+            .move_cursor(tui_textarea::CursorMove::Jump(row as u16, col as u16));
+        !commented
+    }
+
+    /// Drop spaces and tabs at line ends, returns whether any were
+    pub fn trim_trailing_whitespace(&mut self) -> bool {
+        let content = self.get_content();
+        let trimmed: Vec<&str> = content
+            .split('\n')
+            .map(|line| line.trim_end_matches([' ', '\t']))
+            .collect();
+        let trimmed = trimmed.join("\n");
+        if trimmed == content {
+            return false;
+        }
+        self.replace_text(&trimmed);
+        true
+    }
+
+    /// First text column the textarea shows in a view `width` columns wide,
+    /// following the cursor the way tui-textarea does
+    pub fn visible_left(&self, width: u16) -> u16 {
+        let prev = self.scroll_left.get();
+        let cursor = self.textarea.cursor().1 as u16;
+        let left = if cursor < prev {
+            cursor
+        } else if prev + width <= cursor {
+            cursor + 1 - width
+        } else {
+            prev
+        };
+        self.scroll_left.set(left);
+        left
+    }
+
+    /// Scroll position the textarea takes for a view of `height` rows.
+    /// It only follows the cursor, the same way tui-textarea does
+    pub fn visible_top(&self, height: u16) -> u16 {
+        let prev = self.scroll_top.get();
+        let cursor = self.textarea.cursor().0 as u16;
+        let top = if cursor < prev {
+            cursor
+        } else if prev + height <= cursor {
+            cursor + 1 - height
+        } else {
+            prev
+        };
+        self.scroll_top.set(top);
+        self.view_height.set(height);
+        top
+    }
+
+    /// Move the cursor a page up or down. The textarea's own page scrolling
+    /// shifts the view without the cursor, which the gutter cannot follow
+    pub fn page(&mut self, down: bool) {
+        let (row, col) = self.textarea.cursor();
+        let page = self.view_height.get().max(1) as usize;
+        let last = self.textarea.lines().len().saturating_sub(1);
+        let row = if down {
+            (row + page).min(last)
+        } else {
+            row.saturating_sub(page)
+        };
+        self.textarea
+            .move_cursor(tui_textarea::CursorMove::Jump(row as u16, col as u16));
+    }
+
+    /// Swap the text for `content` (e.g. after whitespace fixes), keeping the
+    /// file and the cursor where it was as far as the new text allows
+    pub fn replace_text(&mut self, content: &str) {
+        let (row, col) = self.textarea.cursor();
+        let lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
+        self.textarea = TextArea::new(lines);
+        self.set_indent(self.indent);
+        self.textarea
+            .move_cursor(tui_textarea::CursorMove::Jump(row as u16, col as u16));
+    }
+
+    /// Format the loaded file has on disk
+    pub fn set_format(&mut self, format: TextFormat) {
+        self.format = format;
+        self.original_format = format;
+    }
+
+    /// Format to send with a save, None keeps the one on disk
+    pub fn format_change(&self) -> Option<TextFormat> {
+        (self.format != self.original_format).then_some(self.format)
     }
 
     pub fn get_content(&self) -> String {
@@ -31,8 +382,149 @@ impl EditorState {
     }
 
     pub fn clear(&mut self) {
+        self.remember_position();
         self.current_file = None;
         self.original_content = String::new();
+        self.format = TextFormat::default();
+        self.original_format = TextFormat::default();
+        self.override_value = None;
+        self.redacted = false;
+        self.revealed = false;
+        self.etag = None;
+        self.write_denied = None;
+        self.lint.clear();
+        self.scroll_top.set(0);
+        self.view_height.set(0);
+        self.table = None;
+        self.form = None;
+        self.rendered = None;
+        self.close_preview();
         self.textarea = TextArea::default();
+        self.scroll_left.set(0);
+        self.trim_on_save = false;
+        self.options_file = None;
+        self.set_indent(Indent::default());
+    }
+
+    /// Switch between raw text and table mode, returns whether table mode is on
+    pub fn toggle_table(&mut self) -> Result<bool, String> {
+        if self.table.take().is_some() {
+            return Ok(false);
+        }
+        if self.redacted {
+            return Err("secrets are masked".to_string());
+        }
+        match KvTable::parse(&self.get_content()) {
+            Some(table) => {
+                self.form = None;
+                self.rendered = None;
+                self.close_preview();
+                self.table = Some(table);
+                Ok(true)
+            }
+            None => Err("not a key=value file".to_string()),
+        }
+    }
+
+    /// Show or hide the form view, returns whether the form is shown
+    pub fn toggle_form(&mut self, schema: Option<&FileSchema>) -> Result<bool, String> {
+        if self.form.take().is_some() {
+            return Ok(false);
+        }
+        if self.redacted {
+            return Err("secrets are masked".to_string());
+        }
+        let Some(schema) = schema else {
+            return Err("no schema for this file".to_string());
+        };
+        let filename = self.current_file.as_deref().unwrap_or_default();
+        match SchemaForm::new(schema, filename, &self.get_content()) {
+            Some(form) => {
+                self.table = None;
+                self.rendered = None;
+                self.close_preview();
+                self.form = Some(form);
+                Ok(true)
+            }
+            None => Err("not a key=value file".to_string()),
+        }
+    }
+
+    /// Show or hide the view with substituted variables, returns whether it
+    /// is shown; the caller fetches its content
+    pub fn toggle_rendered(&mut self) -> bool {
+        if self.rendered.take().is_some() {
+            return false;
+        }
+        self.table = None;
+        self.form = None;
+        self.close_preview();
+        self.rendered = Some(RenderedView::default());
+        true
+    }
+
+    /// Show or hide the rendered preview, returns whether it is shown
+    pub fn toggle_preview(&mut self, theme: &ThemeConfig) -> Result<bool, String> {
+        if self.preview.is_some() {
+            self.close_preview();
+            return Ok(false);
+        }
+        if self.preview_body().is_none() {
+            return Err("only Markdown and HTML files".to_string());
+        }
+        self.table = None;
+        self.form = None;
+        self.preview = Some(String::new());
+        self.refresh_preview(theme);
+        Ok(true)
+    }
+
+    /// Re-render the preview after edits or a theme change
+    pub fn refresh_preview(&mut self, theme: &ThemeConfig) {
+        if self.preview.is_none() {
+            return;
+        }
+        let Some(body) = self.preview_body() else {
+            return;
+        };
+        let document = dom::preview_document(&body, theme);
+        if self.preview.as_ref() != Some(&document) {
+            if let Err(e) = dom::show_preview(&document) {
+                web_sys::console::error_1(&e);
+            }
+            self.preview = Some(document);
+        }
+    }
+
+    pub fn close_preview(&mut self) {
+        if self.preview.take().is_some() {
+            dom::hide_preview();
+        }
+    }
+
+    /// HTML of the open file for the preview, None for other file types
+    fn preview_body(&self) -> Option<String> {
+        let name = self.current_file.as_deref()?.to_ascii_lowercase();
+        if name.ends_with(".md") || name.ends_with(".markdown") {
+            Some(markdown::to_html(&self.get_content()))
+        } else if name.ends_with(".html") || name.ends_with(".htm") {
+            Some(self.get_content())
+        } else {
+            None
+        }
+    }
+
+    /// Write form edits back into the text buffer
+    pub fn sync_from_form(&mut self) {
+        if let Some(form) = &self.form {
+            self.textarea = TextArea::new(form.table.to_lines());
+        }
+    }
+
+    /// Write table edits back into the text buffer (used for saving and dirty checks)
+    pub fn sync_from_table(&mut self) {
+        if let Some(table) = &self.table {
+            self.textarea = TextArea::new(table.to_lines());
+        }
     }
 }
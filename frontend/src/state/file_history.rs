@@ -0,0 +1,78 @@
+use crate::api::CommitInfo;
+
+pub struct FileHistoryState {
+    /// File whose history is shown (the file open in the editor)
+    pub filename: Option<String>,
+    pub commits: Vec<CommitInfo>,
+    pub selected_index: usize,
+    pub loaded: bool,
+    /// Vertical scroll of the diff panel
+    pub diff_scroll: u16,
+}
+
+impl FileHistoryState {
+    pub fn new() -> Self {
+        Self {
+            filename: None,
+            commits: Vec::new(),
+            selected_index: 0,
+            loaded: false,
+            diff_scroll: 0,
+        }
+    }
+
+    /// Start viewing the history of `filename`
+    pub fn open(&mut self, filename: String) {
+        if self.filename.as_deref() != Some(filename.as_str()) {
+            self.commits.clear();
+            self.selected_index = 0;
+            self.loaded = false;
+        }
+        self.filename = Some(filename);
+        self.diff_scroll = 0;
+    }
+
+    pub fn next(&mut self) {
+        if !self.commits.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.commits.len();
+            self.diff_scroll = 0;
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.commits.is_empty() {
+            self.selected_index = if self.selected_index == 0 {
+                self.commits.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+            self.diff_scroll = 0;
+        }
+    }
+
+    pub fn scroll_down(&mut self) {
+        let lines = self.selected().map_or(0, |c| c.diff.lines().count());
+        if (self.diff_scroll as usize) + 1 < lines {
+            self.diff_scroll += 1;
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.diff_scroll = self.diff_scroll.saturating_sub(1);
+    }
+
+    pub fn selected(&self) -> Option<&CommitInfo> {
+        self.commits.get(self.selected_index)
+    }
+
+    pub fn set_commits(&mut self, commits: Vec<CommitInfo>) {
+        // Preserve selection by commit id
+        let selected_id = self.selected().map(|c| c.id.clone());
+
+        self.commits = commits;
+        self.loaded = true;
+        self.selected_index = selected_id
+            .and_then(|id| self.commits.iter().position(|c| c.id == id))
+            .unwrap_or(0);
+    }
+}
@@ -1,15 +1,51 @@
-use crate::api::FileInfo;
+use super::marks::MarkedNames;
+use crate::api::{FileInfo, FileOrder, FileSchema, ReadonlyOverride};
+use crate::storage;
 
 pub struct FileListState {
+    /// Every file from the server
+    pub all: Vec<FileInfo>,
+    /// Files shown in the list: pinned first, then in `order()`, hidden
+    /// ones filtered out
     pub files: Vec<FileInfo>,
     pub selected_index: usize,
+    /// Server policy for unlocking readonly files
+    pub readonly_override: ReadonlyOverride,
+    /// ETag of the last list from the server, sent to skip unchanged lists
+    pub etag: Option<String>,
+    /// Starred files, listed first
+    pub pinned: MarkedNames,
+    /// Files left out of the list unless `show_hidden` is set
+    pub hidden: MarkedNames,
+    pub show_hidden: bool,
+    /// Size, age and ownership columns next to the names
+    pub show_details: bool,
+    /// `file_order` of the server's sysrat.toml
+    pub server_order: FileOrder,
+    /// Order picked in the UI, kept across reloads
+    pub order_override: Option<FileOrder>,
+    /// A fetch of the list is in flight
+    pub loading: bool,
+    /// Why the last fetch failed, cleared by the next successful one
+    pub error: Option<String>,
 }
 
 impl FileListState {
     pub fn new() -> Self {
         Self {
+            all: Vec::new(),
             files: Vec::new(),
             selected_index: 0,
+            readonly_override: ReadonlyOverride::Disabled,
+            etag: None,
+            pinned: MarkedNames::load(storage::keys::PINNED_FILES),
+            hidden: MarkedNames::load(storage::keys::HIDDEN_FILES),
+            show_hidden: false,
+            show_details: false,
+            server_order: FileOrder::Config,
+            order_override: storage::generic::load(storage::keys::FILE_ORDER),
+            loading: false,
+            error: None,
         }
     }
 
@@ -33,11 +69,76 @@ impl FileListState {
         self.files.get(self.selected_index)
     }
 
-    pub fn set_files(&mut self, files: Vec<FileInfo>) {
-        // Preserve selection by filename
+    /// Schema of the managed file `name`, if it has one
+    pub fn schema_of(&self, name: &str) -> Option<&FileSchema> {
+        self.all
+            .iter()
+            .find(|file| file.name == name)
+            .and_then(|file| file.schema.as_ref())
+    }
+
+    /// Files in config order, and the order the server asks for
+    pub fn set_files(&mut self, files: Vec<FileInfo>, server_order: FileOrder) {
+        self.all = files;
+        self.server_order = server_order;
+        self.apply_marks();
+    }
+
+    /// The picked order, else the server's
+    pub fn order(&self) -> FileOrder {
+        self.order_override.unwrap_or(self.server_order)
+    }
+
+    /// Switch to the next order and remember it, returns the new order
+    pub fn cycle_order(&mut self) -> FileOrder {
+        let order = self.order().next();
+        self.order_override = Some(order);
+        storage::generic::save(storage::keys::FILE_ORDER, &order);
+        self.apply_marks();
+        order
+    }
+
+    /// Pin or unpin the selected file, returns its name and new pin state
+    pub fn toggle_pin_selected(&mut self) -> Option<(String, bool)> {
+        let name = self.selected()?.name.clone();
+        let pinned = self.pinned.toggle(&name);
+        self.apply_marks();
+        Some((name, pinned))
+    }
+
+    /// Hide or unhide the selected file, returns its name and new hide state
+    pub fn toggle_hide_selected(&mut self) -> Option<(String, bool)> {
+        let name = self.selected()?.name.clone();
+        let hidden = self.hidden.toggle(&name);
+        self.apply_marks();
+        Some((name, hidden))
+    }
+
+    /// List hidden files too (dimmed), returns the new setting
+    pub fn toggle_show_hidden(&mut self) -> bool {
+        self.show_hidden = !self.show_hidden;
+        self.apply_marks();
+        self.show_hidden
+    }
+
+    /// Show or hide the metadata columns, returns the new setting
+    pub fn toggle_details(&mut self) -> bool {
+        self.show_details = !self.show_details;
+        self.show_details
+    }
+
+    /// Rebuild `files` from `all`, keeping the selection on the same file
+    fn apply_marks(&mut self) {
         let selected_name = self.selected().map(|f| f.name.clone());
 
-        self.files = files;
+        self.files = self
+            .all
+            .iter()
+            .filter(|f| self.show_hidden || !self.hidden.contains(&f.name))
+            .cloned()
+            .collect();
+        self.order().sort(&mut self.files);
+        self.pinned.sort(&mut self.files, |f| &f.name);
 
         // Try to restore previous selection
         if let Some(name) = selected_name
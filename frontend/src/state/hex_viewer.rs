@@ -0,0 +1,78 @@
+/// Bytes fetched per page
+pub const PAGE_SIZE: u64 = 4096;
+/// Bytes shown per row
+pub const BYTES_PER_ROW: usize = 16;
+
+pub struct HexViewerState {
+    /// Binary file being viewed
+    pub filename: Option<String>,
+    /// File offset of the loaded page
+    pub offset: u64,
+    pub data: Vec<u8>,
+    /// Total file size as reported by the server
+    pub size: u64,
+    pub loaded: bool,
+    /// First row of the page shown
+    pub scroll: usize,
+}
+
+impl HexViewerState {
+    pub fn new() -> Self {
+        Self {
+            filename: None,
+            offset: 0,
+            data: Vec::new(),
+            size: 0,
+            loaded: false,
+            scroll: 0,
+        }
+    }
+
+    /// Start viewing `filename` from its first page
+    pub fn open(&mut self, filename: String) {
+        self.filename = Some(filename);
+        self.offset = 0;
+        self.data.clear();
+        self.size = 0;
+        self.loaded = false;
+        self.scroll = 0;
+    }
+
+    pub fn set_page(&mut self, offset: u64, data: Vec<u8>, size: u64) {
+        self.offset = offset;
+        self.data = data;
+        self.size = size;
+        self.loaded = true;
+        self.scroll = 0;
+    }
+
+    pub fn rows(&self) -> usize {
+        self.data.len().div_ceil(BYTES_PER_ROW)
+    }
+
+    pub fn scroll_down(&mut self) {
+        if self.scroll + 1 < self.rows() {
+            self.scroll += 1;
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    /// Offset of the page after the loaded one, None on the last page
+    pub fn next_page(&self) -> Option<u64> {
+        let next = self.offset + PAGE_SIZE;
+        (next < self.size).then_some(next)
+    }
+
+    /// Offset of the page before the loaded one, None on the first page
+    pub fn previous_page(&self) -> Option<u64> {
+        (self.offset > 0).then(|| self.offset.saturating_sub(PAGE_SIZE))
+    }
+
+    /// Offset of the last page of the file
+    pub fn last_page(&self) -> u64 {
+        self.size.saturating_sub(1) / PAGE_SIZE * PAGE_SIZE
+    }
+}
@@ -0,0 +1,60 @@
+use crate::api::HostInfo;
+
+/// Servers listed in the host switcher overlay: the serving one first, then
+/// its `[[hosts]]`
+pub struct HostPicker {
+    /// None until the list arrived, Err when it could not be fetched
+    pub hosts: Option<Result<Vec<HostInfo>, String>>,
+    /// Index into the rows, 0 is the serving server
+    pub selected: usize,
+}
+
+impl HostPicker {
+    pub fn new() -> Self {
+        Self {
+            hosts: None,
+            selected: 0,
+        }
+    }
+
+    /// Rows of the list including the serving server
+    fn rows(&self) -> usize {
+        match &self.hosts {
+            Some(Ok(hosts)) => hosts.len() + 1,
+            _ => 1,
+        }
+    }
+
+    /// Selects the picked host once the list arrived
+    pub fn set_hosts(&mut self, hosts: Result<Vec<HostInfo>, String>, active: Option<&str>) {
+        self.selected = match (&hosts, active) {
+            (Ok(list), Some(name)) => list
+                .iter()
+                .position(|host| host.name == name)
+                .map_or(0, |index| index + 1),
+            _ => 0,
+        };
+        self.hosts = Some(hosts);
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1) % self.rows();
+    }
+
+    pub fn previous(&mut self) {
+        let rows = self.rows();
+        self.selected = (self.selected + rows - 1) % rows;
+    }
+
+    /// The selected row: Some(None) for the serving server, None while the
+    /// list is missing and only the serving server is known
+    pub fn selected(&self) -> Option<Option<&HostInfo>> {
+        if self.selected == 0 {
+            return Some(None);
+        }
+        match &self.hosts {
+            Some(Ok(hosts)) => hosts.get(self.selected - 1).map(Some),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::api::HygieneResponse;
+
+/// Whitespace fixes offered before a save, reviewed in the hygiene overlay
+pub struct HygieneReview {
+    pub filename: String,
+    /// Content as the user saved it, kept for "save as is"
+    pub content: String,
+    pub override_value: Option<String>,
+    pub check: HygieneResponse,
+}
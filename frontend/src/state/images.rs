@@ -0,0 +1,68 @@
+use crate::api::ImageInfo;
+use gloo_timers::callback::Interval;
+
+/// A scan started from the pane, polled until its job finishes
+pub struct RunningScan {
+    pub image_id: String,
+    pub job_id: u64,
+}
+
+pub struct ImagesState {
+    pub images: Vec<ImageInfo>,
+    /// Scanner installed on the server, None disables scanning
+    pub scanner: Option<String>,
+    pub selected_index: usize,
+    pub loaded: bool,
+    pub scan: Option<RunningScan>,
+    /// Polls the job of `scan`, dropped once it finishes or the pane is left
+    pub poll: Option<Interval>,
+}
+
+impl ImagesState {
+    pub fn new() -> Self {
+        Self {
+            images: Vec::new(),
+            scanner: None,
+            selected_index: 0,
+            loaded: false,
+            scan: None,
+            poll: None,
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.images.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.images.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.images.is_empty() {
+            self.selected_index = if self.selected_index == 0 {
+                self.images.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+        }
+    }
+
+    pub fn selected(&self) -> Option<&ImageInfo> {
+        self.images.get(self.selected_index)
+    }
+
+    pub fn set_images(&mut self, images: Vec<ImageInfo>, scanner: Option<String>) {
+        // Preserve selection by id
+        let selected_id = self.selected().map(|i| i.id.clone());
+
+        self.images = images;
+        self.scanner = scanner;
+        self.loaded = true;
+        self.selected_index = selected_id
+            .and_then(|id| self.images.iter().position(|i| i.id == id))
+            .unwrap_or(0);
+    }
+
+    pub fn is_scanning(&self, image_id: &str) -> bool {
+        self.scan.as_ref().is_some_and(|s| s.image_id == image_id)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::api::FileChangeStats;
+
+/// Windows the change counts cycle through, in days
+const WINDOWS: [u32; 4] = [7, 30, 90, 365];
+
+pub struct InsightsState {
+    /// Most changed within the window first
+    pub files: Vec<FileChangeStats>,
+    /// Window `changes` counts, in days
+    pub days: u32,
+    pub selected_index: usize,
+    pub loaded: bool,
+    /// Why the stats could not be loaded, e.g. git versioning is disabled
+    pub error: Option<String>,
+}
+
+impl InsightsState {
+    pub fn new() -> Self {
+        Self {
+            files: Vec::new(),
+            days: 30,
+            selected_index: 0,
+            loaded: false,
+            error: None,
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.files.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.files.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.files.is_empty() {
+            self.selected_index = if self.selected_index == 0 {
+                self.files.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+        }
+    }
+
+    pub fn selected(&self) -> Option<&FileChangeStats> {
+        self.files.get(self.selected_index)
+    }
+
+    pub fn set_stats(&mut self, files: Vec<FileChangeStats>) {
+        // Preserve selection by name, the order changes with the counts
+        let selected_name = self.selected().map(|f| f.name.clone());
+
+        self.files = files;
+        self.loaded = true;
+        self.error = None;
+        self.selected_index = selected_name
+            .and_then(|name| self.files.iter().position(|f| f.name == name))
+            .unwrap_or(0);
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.files.clear();
+        self.loaded = true;
+        self.error = Some(error);
+    }
+
+    /// Switch to the next longer window, wrapping to the shortest
+    pub fn cycle_window(&mut self) -> u32 {
+        self.days = WINDOWS
+            .iter()
+            .copied()
+            .find(|days| *days > self.days)
+            .unwrap_or(WINDOWS[0]);
+        self.days
+    }
+
+    /// Most changes of any file in the window, scales the bars
+    pub fn max_changes(&self) -> u64 {
+        self.files.iter().map(|f| f.changes).max().unwrap_or(0)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::keybinds::{self, KeybindEntry, Keybinds};
+use std::collections::HashSet;
+
+pub struct KeybindEditorState {
+    pub entries: Vec<KeybindEntry>,
+    /// Indices of entries whose key is bound twice in the same scope
+    pub conflicts: HashSet<usize>,
+    pub selected_index: usize,
+    /// Waiting for the key to bind to the selected action
+    pub capturing: bool,
+}
+
+impl KeybindEditorState {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            conflicts: HashSet::new(),
+            selected_index: 0,
+            capturing: false,
+        }
+    }
+
+    /// List the bindings of `keybinds`, keeping the selection
+    pub fn open(&mut self, keybinds: &Keybinds) {
+        self.entries = keybinds.entries();
+        self.conflicts = keybinds::conflicts(&self.entries);
+        self.selected_index = self
+            .selected_index
+            .min(self.entries.len().saturating_sub(1));
+        self.capturing = false;
+    }
+
+    pub fn next(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.entries.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected_index = if self.selected_index == 0 {
+                self.entries.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+        }
+    }
+
+    pub fn selected(&self) -> Option<&KeybindEntry> {
+        self.entries.get(self.selected_index)
+    }
+
+    /// Entry that would clash with binding the selected action to `key`
+    pub fn conflict_for(&self, key: &str) -> Option<&KeybindEntry> {
+        let selected = self.selected()?;
+        let candidate = KeybindEntry {
+            section: selected.section.clone(),
+            action: selected.action.clone(),
+            key: key.to_string(),
+            overridden: true,
+        };
+        self.entries
+            .iter()
+            .enumerate()
+            .find(|(i, entry)| *i != self.selected_index && keybinds::clashes(&candidate, entry))
+            .map(|(_, entry)| entry)
+    }
+}
@@ -0,0 +1,319 @@
+use std::collections::HashSet;
+
+/// Column of a table row the cursor is on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KvColumn {
+    Key,
+    Value,
+}
+
+/// One line of a key=value (env/ini style) file
+#[derive(Debug, Clone)]
+pub enum KvLine {
+    /// `KEY=value`, keeping the text around the `=` so untouched lines round-trip
+    Entry {
+        indent: String,
+        key: String,
+        separator: String,
+        value: String,
+    },
+    /// `[section]` header of ini files, `raw` is the line as written
+    Section { name: String, raw: String },
+    /// Comments and blank lines, written back verbatim
+    Verbatim(String),
+}
+
+/// Table editing mode of the editor: the file as key/value rows
+pub struct KvTable {
+    pub lines: Vec<KvLine>,
+    /// Index into `rows()`
+    pub selected_row: usize,
+    pub column: KvColumn,
+    /// Text of the cell being edited, None while navigating
+    pub edit_buffer: Option<String>,
+}
+
+impl KvTable {
+    /// Parse env/ini style content, None if any line is not a comment,
+    /// blank, `[section]` or `key=value` (or there are no entries at all)
+    pub fn parse(content: &str) -> Option<Self> {
+        let table = Self::parse_lines(content)?;
+        if table.rows().is_empty() {
+            return None;
+        }
+        Some(table)
+    }
+
+    /// Like `parse`, but a file without entries (e.g. empty) is accepted
+    pub fn parse_lines(content: &str) -> Option<Self> {
+        let lines = content
+            .lines()
+            .map(parse_line)
+            .collect::<Option<Vec<KvLine>>>()?;
+        Some(Self {
+            lines,
+            selected_row: 0,
+            column: KvColumn::Value,
+            edit_buffer: None,
+        })
+    }
+
+    /// Value of `key`, or `section.key` for keys under an ini section
+    pub fn get(&self, path: &str) -> Option<&str> {
+        self.find(path).and_then(|line| match &self.lines[line] {
+            KvLine::Entry { value, .. } => Some(value.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Set the value of `path`, adding the entry (and its section) if missing
+    pub fn set(&mut self, path: &str, new_value: String) {
+        if let Some(line) = self.find(path)
+            && let KvLine::Entry { value, .. } = &mut self.lines[line]
+        {
+            *value = new_value;
+            return;
+        }
+
+        let (section, key) = split_path(path);
+        let entry = KvLine::Entry {
+            indent: String::new(),
+            key: key.to_string(),
+            separator: "=".to_string(),
+            value: new_value,
+        };
+        match self.section_end(section) {
+            Some(at) => self.lines.insert(at, entry),
+            None => {
+                self.lines.push(KvLine::Section {
+                    name: section.to_string(),
+                    raw: format!("[{}]", section),
+                });
+                self.lines.push(entry);
+            }
+        }
+    }
+
+    /// Line index of the entry at `path`, which is also its row in the file
+    pub fn find(&self, path: &str) -> Option<usize> {
+        let (section, key) = split_path(path);
+        let mut current = "";
+        for (i, line) in self.lines.iter().enumerate() {
+            match line {
+                KvLine::Section { name, .. } => current = name,
+                KvLine::Entry { key: k, .. } if current == section && k == key => {
+                    return Some(i);
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Where a new entry of `section` goes: after its last entry, None if
+    /// the section does not exist yet (the top level always exists)
+    fn section_end(&self, section: &str) -> Option<usize> {
+        let mut current = "";
+        let mut found = section.is_empty();
+        let mut end = if found { Some(0) } else { None };
+        for (i, line) in self.lines.iter().enumerate() {
+            match line {
+                KvLine::Section { name, .. } => {
+                    current = name;
+                    if current == section {
+                        found = true;
+                        end = Some(i + 1);
+                    }
+                }
+                KvLine::Entry { .. } if found && current == section => end = Some(i + 1),
+                _ => {}
+            }
+        }
+        end
+    }
+
+    /// Lines of the file with the edits applied
+    pub fn to_lines(&self) -> Vec<String> {
+        self.lines
+            .iter()
+            .map(|line| match line {
+                KvLine::Entry {
+                    indent,
+                    key,
+                    separator,
+                    value,
+                } => format!("{}{}{}{}", indent, key, separator, value),
+                KvLine::Section { raw, .. } | KvLine::Verbatim(raw) => raw.clone(),
+            })
+            .collect()
+    }
+
+    /// Indices into `lines` of the editable entries
+    pub fn rows(&self) -> Vec<usize> {
+        self.lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| matches!(line, KvLine::Entry { .. }))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Index into `lines` of the selected entry
+    pub fn selected_line(&self) -> Option<usize> {
+        self.rows().get(self.selected_row).copied()
+    }
+
+    pub fn next(&mut self) {
+        let count = self.rows().len();
+        if count > 0 {
+            self.selected_row = (self.selected_row + 1) % count;
+        }
+    }
+
+    pub fn previous(&mut self) {
+        let count = self.rows().len();
+        if count > 0 {
+            self.selected_row = if self.selected_row == 0 {
+                count - 1
+            } else {
+                self.selected_row - 1
+            };
+        }
+    }
+
+    pub fn toggle_column(&mut self) {
+        self.column = match self.column {
+            KvColumn::Key => KvColumn::Value,
+            KvColumn::Value => KvColumn::Key,
+        };
+    }
+
+    /// Start editing the selected cell with its current text
+    pub fn start_edit(&mut self) {
+        if let Some(line) = self.selected_line()
+            && let KvLine::Entry { key, value, .. } = &self.lines[line]
+        {
+            let text = match self.column {
+                KvColumn::Key => key,
+                KvColumn::Value => value,
+            };
+            self.edit_buffer = Some(text.clone());
+        }
+    }
+
+    /// Write the edit buffer into the selected cell
+    pub fn commit_edit(&mut self) {
+        let Some(text) = self.edit_buffer.take() else {
+            return;
+        };
+        let column = self.column;
+        if let Some(line) = self.selected_line()
+            && let KvLine::Entry { key, value, .. } = &mut self.lines[line]
+        {
+            match column {
+                // Keys cannot hold surrounding whitespace or an `=`
+                KvColumn::Key => *key = text.trim().replace('=', ""),
+                KvColumn::Value => *value = text,
+            }
+        }
+    }
+
+    pub fn cancel_edit(&mut self) {
+        self.edit_buffer = None;
+    }
+
+    /// Insert an empty entry below the selected one and start editing its key
+    pub fn add_row(&mut self) {
+        let (at, indent, separator) = match self.selected_line() {
+            Some(line) => match &self.lines[line] {
+                KvLine::Entry {
+                    indent, separator, ..
+                } => (line + 1, indent.clone(), separator.clone()),
+                _ => (line + 1, String::new(), "=".to_string()),
+            },
+            None => (self.lines.len(), String::new(), "=".to_string()),
+        };
+        self.lines.insert(
+            at,
+            KvLine::Entry {
+                indent,
+                key: String::new(),
+                separator,
+                value: String::new(),
+            },
+        );
+        self.selected_row = self.rows().iter().position(|&l| l == at).unwrap_or(0);
+        self.column = KvColumn::Key;
+        self.edit_buffer = Some(String::new());
+    }
+
+    /// Remove the selected entry (the last one is kept so the table stays usable)
+    pub fn delete_row(&mut self) -> bool {
+        if self.rows().len() <= 1 {
+            return false;
+        }
+        let Some(line) = self.selected_line() else {
+            return false;
+        };
+        self.lines.remove(line);
+        self.selected_row = self.selected_row.min(self.rows().len() - 1);
+        true
+    }
+
+    /// Lines whose key is empty or already used earlier in the same section
+    pub fn invalid_lines(&self) -> HashSet<usize> {
+        let mut seen: HashSet<(&str, &str)> = HashSet::new();
+        let mut invalid = HashSet::new();
+        let mut section = "";
+        for (i, line) in self.lines.iter().enumerate() {
+            match line {
+                KvLine::Section { name, .. } => section = name,
+                KvLine::Entry { key, .. } => {
+                    if key.is_empty() || !seen.insert((section, key)) {
+                        invalid.insert(i);
+                    }
+                }
+                KvLine::Verbatim(_) => {}
+            }
+        }
+        invalid
+    }
+}
+
+/// `section.key` into its section ("" for top level keys) and key
+fn split_path(path: &str) -> (&str, &str) {
+    path.rsplit_once('.').unwrap_or(("", path))
+}
+
+fn parse_line(line: &str) -> Option<KvLine> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with(';') {
+        return Some(KvLine::Verbatim(line.to_string()));
+    }
+    if let Some(name) = trimmed
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+    {
+        return Some(KvLine::Section {
+            name: name.to_string(),
+            raw: line.to_string(),
+        });
+    }
+
+    let (key_part, value_part) = line.split_once('=')?;
+    let key = key_part.trim();
+    if key.is_empty() {
+        return None;
+    }
+    let indent = &key_part[..key_part.len() - key_part.trim_start().len()];
+    let key_trailing = &key_part[key_part.trim_end().len()..];
+    let value = value_part.trim_start();
+    let value_leading = &value_part[..value_part.len() - value.len()];
+
+    Some(KvLine::Entry {
+        indent: indent.to_string(),
+        key: key.to_string(),
+        separator: format!("{}={}", key_trailing, value_leading),
+        value: value.to_string(),
+    })
+}
@@ -0,0 +1,113 @@
+use super::{Pane, Split, SubPane};
+use ratzilla::ratatui::layout::{Position, Rect};
+
+/// List drawn in the last frame that can be clicked and scrolled
+pub struct ListHitArea {
+    pub pane: Pane,
+    /// Inside of the border, one row per list line
+    pub inner: Rect,
+    /// Index of the first visible line
+    pub offset: usize,
+    /// Item index of every list line, None for headers and decoration
+    pub rows: Vec<Option<usize>>,
+}
+
+/// Screen areas of the last rendered frame, for hit-testing mouse events
+#[derive(Default)]
+pub struct LayoutRects {
+    /// The whole frame, in cells
+    pub screen: Rect,
+    /// Focusable panes on screen
+    pub panes: Vec<(Pane, Rect)>,
+    pub lists: Vec<ListHitArea>,
+    /// Gaps between the panes of a split, with the borders next to them
+    pub dividers: Vec<(Split, Rect)>,
+    /// Side panes on screen, clicking one gives it the keys
+    pub sub_panes: Vec<(SubPane, Rect)>,
+    /// Furthest scroll offset of the container details (0 when they fit)
+    pub details_max_offset: u16,
+}
+
+impl LayoutRects {
+    /// Forget the previous frame before drawing a new one
+    pub fn reset(&mut self, screen: Rect) {
+        self.screen = screen;
+        self.panes.clear();
+        self.lists.clear();
+        self.dividers.clear();
+        self.sub_panes.clear();
+    }
+
+    pub fn add_pane(&mut self, pane: Pane, area: Rect) {
+        self.panes.push((pane, area));
+    }
+
+    pub fn add_list(&mut self, pane: Pane, inner: Rect, offset: usize, rows: Vec<Option<usize>>) {
+        self.lists.push(ListHitArea {
+            pane,
+            inner,
+            offset,
+            rows,
+        });
+    }
+
+    /// Record the gap between the panes of a split; the border column on
+    /// either side is part of the grab area
+    pub fn add_divider(&mut self, split: Split, gap: Rect) {
+        let x = gap.x.saturating_sub(1);
+        let area = Rect::new(x, gap.y, gap.right() + 1 - x, gap.height);
+        self.dividers.push((split, area));
+    }
+
+    pub fn divider_at(&self, position: Position) -> Option<Split> {
+        self.dividers
+            .iter()
+            .find(|(_, area)| area.contains(position))
+            .map(|(split, _)| *split)
+    }
+
+    pub fn add_sub_pane(&mut self, sub: SubPane, area: Rect) {
+        self.sub_panes.push((sub, area));
+    }
+
+    pub fn sub_pane_at(&self, position: Position) -> Option<SubPane> {
+        self.sub_panes
+            .iter()
+            .find(|(_, area)| area.contains(position))
+            .map(|(sub, _)| *sub)
+    }
+
+    /// Area of a side pane drawn in the last frame
+    pub fn sub_pane_area(&self, sub: SubPane) -> Option<Rect> {
+        self.sub_panes
+            .iter()
+            .find(|(s, _)| *s == sub)
+            .map(|(_, area)| *area)
+    }
+
+    pub fn pane_at(&self, position: Position) -> Option<Pane> {
+        self.panes
+            .iter()
+            .find(|(_, area)| area.contains(position))
+            .map(|(pane, _)| *pane)
+    }
+
+    /// The list under the pointer, for scrolling
+    pub fn list_at(&self, position: Position) -> Option<Pane> {
+        self.lists
+            .iter()
+            .find(|list| list.inner.contains(position))
+            .map(|list| list.pane)
+    }
+
+    /// The list item under the pointer, for clicking
+    pub fn item_at(&self, position: Position) -> Option<(Pane, usize)> {
+        let list = self
+            .lists
+            .iter()
+            .find(|list| list.inner.contains(position))?;
+        let line = list.offset + (position.y - list.inner.y) as usize;
+        let index = (*list.rows.get(line)?)?;
+        Some((list.pane, index))
+    }
+}
@@ -0,0 +1,227 @@
+use crate::api::{LogInfo, LogStream};
+use std::collections::VecDeque;
+
+/// Lines kept of the followed log, older ones are dropped
+const MAX_LINES: usize = 5_000;
+
+/// Lines asked for when following starts
+pub const BACKLOG_LINES: usize = 200;
+
+/// How loud a log line is, guessed from its words
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Critical,
+    Error,
+    Warning,
+    Debug,
+    Normal,
+}
+
+impl Severity {
+    pub fn of(line: &str) -> Self {
+        let line = line.to_ascii_lowercase();
+        let has = |words: &[&str]| words.iter().any(|word| line.contains(word));
+        if has(&["emerg", "alert", "crit", "fatal", "panic"]) {
+            Severity::Critical
+        } else if has(&["error", "[err", " err ", "failed"]) {
+            Severity::Error
+        } else if has(&["warn"]) {
+            Severity::Warning
+        } else if has(&["debug", "trace"]) {
+            Severity::Debug
+        } else {
+            Severity::Normal
+        }
+    }
+}
+
+pub struct LogsState {
+    /// Log files from `[[logs]]` in sysrat.toml
+    pub logs: Vec<LogInfo>,
+    pub selected_index: usize,
+    pub loaded: bool,
+    /// Why the list could not be loaded
+    pub error: Option<String>,
+    /// Name of the followed log
+    pub following: Option<String>,
+    /// Connection of the followed log, dropping it stops following
+    pub stream: Option<LogStream>,
+    pub lines: VecDeque<String>,
+    /// Lines received while paused, shown once following resumes
+    pub pending: Vec<String>,
+    pub paused: bool,
+    /// Lines scrolled back from the newest
+    pub scroll: usize,
+    /// Only lines containing this are shown (case-insensitive)
+    pub search: Option<String>,
+    /// The search is being typed
+    pub search_input: bool,
+    /// Why the followed file cannot be read right now
+    pub failure: Option<String>,
+    /// The connection dropped; the lines sent on reconnect replace the
+    /// shown ones instead of repeating them
+    pub reconnecting: bool,
+}
+
+impl LogsState {
+    pub fn new() -> Self {
+        Self {
+            logs: Vec::new(),
+            selected_index: 0,
+            loaded: false,
+            error: None,
+            following: None,
+            stream: None,
+            lines: VecDeque::new(),
+            pending: Vec::new(),
+            paused: false,
+            scroll: 0,
+            search: None,
+            search_input: false,
+            failure: None,
+            reconnecting: false,
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.logs.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.logs.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.logs.is_empty() {
+            self.selected_index = if self.selected_index == 0 {
+                self.logs.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+        }
+    }
+
+    pub fn selected(&self) -> Option<&LogInfo> {
+        self.logs.get(self.selected_index)
+    }
+
+    pub fn set_logs(&mut self, logs: Vec<LogInfo>) {
+        // Preserve selection by name
+        let selected_name = self.selected().map(|log| log.name.clone());
+
+        self.logs = logs;
+        self.loaded = true;
+        self.error = None;
+        self.selected_index = selected_name
+            .and_then(|name| self.logs.iter().position(|log| log.name == name))
+            .unwrap_or(0);
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.logs.clear();
+        self.loaded = true;
+        self.error = Some(error);
+    }
+
+    /// Start showing `name` with `stream`, replacing the followed log
+    pub fn start(&mut self, name: String, stream: LogStream) {
+        self.following = Some(name);
+        self.stream = Some(stream);
+        self.lines.clear();
+        self.pending.clear();
+        self.paused = false;
+        self.scroll = 0;
+        self.failure = None;
+        self.reconnecting = false;
+    }
+
+    /// Stop following, the lines stay on screen
+    pub fn stop(&mut self) {
+        self.stream = None;
+    }
+
+    /// Lines appended to the followed log
+    pub fn push_lines(&mut self, lines: Vec<String>) {
+        self.failure = None;
+        if self.reconnecting {
+            // The server starts over with the last lines
+            self.reconnecting = false;
+            self.lines.clear();
+            self.pending.clear();
+        }
+        if self.paused {
+            self.pending.extend(lines);
+        } else {
+            self.append(lines);
+        }
+    }
+
+    /// The connection dropped: reconnecting, or given up when `closed`
+    pub fn disconnected(&mut self, closed: bool) {
+        if closed {
+            self.stream = None;
+            self.failure = Some("Connection closed, open the log again to retry".to_string());
+        } else {
+            self.reconnecting = true;
+        }
+    }
+
+    /// The followed log was truncated or replaced
+    pub fn rotated(&mut self, lines: Vec<String>) {
+        self.push_lines(vec!["--- log rotated ---".to_string()]);
+        self.push_lines(lines);
+    }
+
+    /// Hold new lines back (or show them again), returns whether paused
+    pub fn toggle_pause(&mut self) -> bool {
+        self.paused = !self.paused;
+        if !self.paused {
+            let pending = std::mem::take(&mut self.pending);
+            self.append(pending);
+            self.scroll = 0;
+        }
+        self.paused
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        let max = self.visible_lines().len().saturating_sub(1);
+        self.scroll = (self.scroll + lines).min(max);
+    }
+
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    /// Lines matching the search, oldest first
+    pub fn visible_lines(&self) -> Vec<&String> {
+        let query = self.query();
+        self.lines
+            .iter()
+            .filter(|line| line_matches(line, query.as_deref()))
+            .collect()
+    }
+
+    /// Lowercased search, None when there is nothing to filter by
+    fn query(&self) -> Option<String> {
+        self.search
+            .as_deref()
+            .filter(|query| !query.is_empty())
+            .map(str::to_lowercase)
+    }
+
+    fn append(&mut self, lines: Vec<String>) {
+        // Keep the view on the same lines while scrolled back
+        if self.scroll > 0 {
+            let query = self.query();
+            self.scroll += lines
+                .iter()
+                .filter(|line| line_matches(line, query.as_deref()))
+                .count();
+        }
+        self.lines.extend(lines);
+        let excess = self.lines.len().saturating_sub(MAX_LINES);
+        self.lines.drain(..excess);
+    }
+}
+
+fn line_matches(line: &str, query: Option<&str>) -> bool {
+    query.is_none_or(|query| line.to_lowercase().contains(query))
+}
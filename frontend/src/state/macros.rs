@@ -0,0 +1,95 @@
+use crate::storage;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A key press of a macro: the key's name as in keybinds.toml and its
+/// modifiers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MacroKey {
+    pub key: String,
+    #[serde(default)]
+    pub ctrl: bool,
+    #[serde(default)]
+    pub alt: bool,
+    #[serde(default)]
+    pub shift: bool,
+}
+
+/// What the next key names the register for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MacroPrefix {
+    /// `q`, start recording
+    Record,
+    /// `@`, replay
+    Replay,
+}
+
+/// Keys given to the editor recorded with `q{register}`, replayed with
+/// `@{register}`; the registers are kept in localStorage
+pub struct Macros {
+    registers: BTreeMap<char, Vec<MacroKey>>,
+    /// Register being recorded into and the keys so far
+    recording: Option<(char, Vec<MacroKey>)>,
+    /// `q` or `@` was pressed, waiting for the register
+    pub pending: Option<MacroPrefix>,
+    /// Register `@@` replays
+    pub last: Option<char>,
+    /// Macros being replayed, nested when a macro replays another
+    pub depth: usize,
+    /// Keys given to the editor by the current replay
+    pub replayed: usize,
+}
+
+impl Macros {
+    pub fn load() -> Self {
+        Self {
+            registers: storage::generic::load(storage::keys::MACROS).unwrap_or_default(),
+            recording: None,
+            pending: None,
+            last: None,
+            depth: 0,
+            replayed: 0,
+        }
+    }
+
+    /// Letters and digits name registers
+    pub fn is_register(register: char) -> bool {
+        register.is_ascii_alphanumeric()
+    }
+
+    /// Register being recorded into
+    pub fn recording(&self) -> Option<char> {
+        self.recording.as_ref().map(|(register, _)| *register)
+    }
+
+    pub fn start(&mut self, register: char) {
+        self.recording = Some((register, Vec::new()));
+    }
+
+    /// Add a key typed while recording; keys a replay gives the editor are
+    /// left out, the `@` that started it is recorded instead
+    pub fn record(&mut self, key: MacroKey) {
+        if self.depth == 0
+            && let Some((_, keys)) = self.recording.as_mut()
+        {
+            keys.push(key);
+        }
+    }
+
+    /// Store the recorded keys in their register, returns it and how many
+    /// keys it holds
+    pub fn stop(&mut self) -> Option<(char, usize)> {
+        let (register, keys) = self.recording.take()?;
+        let count = keys.len();
+        self.registers.insert(register, keys);
+        storage::generic::save(storage::keys::MACROS, &self.registers);
+        Some((register, count))
+    }
+
+    pub fn get(&self, register: char) -> Option<&[MacroKey]> {
+        self.registers
+            .get(&register)
+            .filter(|keys| !keys.is_empty())
+            .map(Vec::as_slice)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::storage;
+
+/// Header of the section holding pinned items at the top of a list
+pub const PINNED_HEADER: &str = "Pinned";
+
+/// Item names of one list marked as pinned or hidden, persisted in localStorage
+pub struct MarkedNames {
+    key: &'static str,
+    names: Vec<String>,
+}
+
+impl MarkedNames {
+    pub fn load(key: &'static str) -> Self {
+        Self {
+            key,
+            names: storage::generic::load(key).unwrap_or_default(),
+        }
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.iter().any(|n| n == name)
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Mark or unmark `name`, returns whether it is marked now
+    pub fn toggle(&mut self, name: &str) -> bool {
+        let marked = if let Some(pos) = self.names.iter().position(|n| n == name) {
+            self.names.remove(pos);
+            false
+        } else {
+            self.names.push(name.to_string());
+            true
+        };
+        storage::generic::save(self.key, &self.names);
+        marked
+    }
+
+    /// Prefix entries stored before they were scoped (e.g. bare container
+    /// names from before host-qualified identities) with `prefix/`
+    pub fn qualify(&mut self, prefix: &str) {
+        if self.names.iter().all(|n| n.contains('/')) {
+            return;
+        }
+        for name in self.names.iter_mut().filter(|n| !n.contains('/')) {
+            *name = format!("{}/{}", prefix, name);
+        }
+        storage::generic::save(self.key, &self.names);
+    }
+
+    /// Move marked items to the front, keeping the order within both groups
+    pub fn sort<T>(&self, items: &mut [T], name: impl Fn(&T) -> &str) {
+        items.sort_by_key(|item| !self.contains(name(item)));
+    }
+}
+
+/// Status line message after toggling a pin
+pub fn pin_status(name: &str, pinned: bool) -> String {
+    if pinned {
+        format!("Pinned {}", name)
+    } else {
+        format!("Unpinned {}", name)
+    }
+}
+
+/// Status line message after toggling a hide mark
+pub fn hide_status(name: &str, hidden: bool) -> String {
+    if hidden {
+        format!("Hid {}", name)
+    } else {
+        format!("Unhid {}", name)
+    }
+}
+
+/// Status line message after toggling whether hidden items are listed
+pub fn show_hidden_status(show: bool) -> String {
+    if show {
+        "[showing hidden]".to_string()
+    } else {
+        "[hiding hidden]".to_string()
+    }
+}
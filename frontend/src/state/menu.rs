@@ -6,7 +6,19 @@ pub struct MenuState {
 impl MenuState {
     pub fn new() -> Self {
         Self {
-            items: vec!["Config Files".to_string(), "Container".to_string()],
+            items: vec![
+                "Dashboard".to_string(),
+                "Config Files".to_string(),
+                "Container".to_string(),
+                "Container Graph".to_string(),
+                "Images".to_string(),
+                "Theme Editor".to_string(),
+                "Actions".to_string(),
+                "Runbooks".to_string(),
+                "Insights".to_string(),
+                "Logs".to_string(),
+                "Keybinds".to_string(),
+            ],
             selected_index: 0,
         }
     }
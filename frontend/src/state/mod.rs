@@ -1,17 +1,72 @@
+pub mod actions;
 pub mod app;
+pub mod command_line;
+pub mod confirm;
+pub mod container_details;
+pub mod container_graph;
 pub mod container_list;
+pub mod container_watch;
+pub mod dashboard;
 pub mod editor;
+pub mod file_history;
 pub mod file_list;
+pub mod hex_viewer;
+pub mod hosts;
+pub mod hygiene;
+pub mod images;
+pub mod insights;
+pub mod keybind_editor;
+pub mod kv_table;
+pub mod layout;
+pub mod logs;
+pub mod macros;
+pub mod marks;
 pub mod menu;
+pub mod notifications;
+pub mod outline;
+pub mod overlay;
 pub mod pane;
+pub mod problems;
 pub mod refresh;
+pub mod rendered;
+pub mod runbooks;
+pub mod schema_form;
 pub mod splash;
-pub mod status_helper;
+pub mod split;
+pub mod theme_editor;
+pub mod transition;
+pub mod upstream;
 
+pub use actions::ActionsState;
 pub use app::AppState;
+pub use command_line::CommandLine;
+pub use confirm::{Confirm, ConfirmAction, ConfirmQueue};
+pub use container_details::ContainerDetailsView;
+pub use container_graph::ContainerGraphState;
 pub use container_list::ContainerListState;
+pub use container_watch::ContainerWatchState;
+pub use dashboard::DashboardState;
 pub use editor::EditorState;
+pub use file_history::FileHistoryState;
 pub use file_list::FileListState;
+pub use hex_viewer::HexViewerState;
+pub use hosts::HostPicker;
+pub use hygiene::HygieneReview;
+pub use images::ImagesState;
+pub use insights::InsightsState;
+pub use keybind_editor::KeybindEditorState;
+pub use layout::LayoutRects;
+pub use logs::LogsState;
+pub use macros::{MacroKey, MacroPrefix, Macros};
 pub use menu::MenuState;
-pub use pane::{Pane, VimMode};
+pub use notifications::Notifications;
+pub use outline::Outline;
+pub use overlay::{Overlay, OverlayState};
+pub use pane::{Pane, SubPane, VimMode};
+pub use problems::{Problem, Problems};
+pub use runbooks::RunbooksState;
 pub use splash::SplashState;
+pub use split::{Split, SplitRatios};
+pub use theme_editor::ThemeEditorState;
+pub use transition::TransitionState;
+pub use upstream::UpstreamReview;
@@ -0,0 +1,120 @@
+use super::AppState;
+use gloo_timers::callback::Timeout;
+use std::collections::VecDeque;
+use std::{cell::RefCell, rc::Rc};
+
+/// At most this many toasts are stacked, older ones make room for new ones
+const MAX_TOASTS: usize = 5;
+
+/// How a toast is colored and how long it stays
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn label(self) -> &'static str {
+        match self {
+            Severity::Info => "Info",
+            Severity::Success => "Success",
+            Severity::Warning => "Warning",
+            Severity::Error => "Error",
+        }
+    }
+
+    /// Milliseconds until the toast is dismissed, problems stay longer
+    fn ttl_ms(self) -> u32 {
+        match self {
+            Severity::Info | Severity::Success => 3_000,
+            Severity::Warning => 5_000,
+            Severity::Error => 8_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub id: u64,
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// Toasts shown stacked in a corner, newest at the bottom
+pub struct Notifications {
+    toasts: VecDeque<Toast>,
+    next_id: u64,
+}
+
+impl Notifications {
+    pub fn new() -> Self {
+        Self {
+            toasts: VecDeque::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Add a toast and return its id. The same message again (e.g. a failing
+    /// background refresh) replaces the earlier toast instead of stacking.
+    pub fn push(&mut self, severity: Severity, message: String) -> u64 {
+        self.toasts
+            .retain(|t| t.severity != severity || t.message != message);
+        while self.toasts.len() >= MAX_TOASTS {
+            self.toasts.pop_front();
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.toasts.push_back(Toast {
+            id,
+            severity,
+            message,
+        });
+        id
+    }
+
+    pub fn dismiss(&mut self, id: u64) {
+        self.toasts.retain(|t| t.id != id);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter()
+    }
+}
+
+/// Show a toast that dismisses itself after its severity's TTL. Offline,
+/// warnings and errors are dropped: every request fails and the offline
+/// banner already says why.
+pub fn notify(state_rc: &Rc<RefCell<AppState>>, severity: Severity, message: impl Into<String>) {
+    let id = {
+        let mut st = state_rc.borrow_mut();
+        if st.offline && matches!(severity, Severity::Warning | Severity::Error) {
+            return;
+        }
+        st.notifications.push(severity, message.into())
+    };
+
+    let state_clone = Rc::clone(state_rc);
+    Timeout::new(severity.ttl_ms(), move || {
+        state_clone.borrow_mut().notifications.dismiss(id);
+    })
+    .forget();
+}
+
+pub fn notify_info(state_rc: &Rc<RefCell<AppState>>, message: impl Into<String>) {
+    notify(state_rc, Severity::Info, message);
+}
+
+pub fn notify_success(state_rc: &Rc<RefCell<AppState>>, message: impl Into<String>) {
+    notify(state_rc, Severity::Success, message);
+}
+
+pub fn notify_warning(state_rc: &Rc<RefCell<AppState>>, message: impl Into<String>) {
+    notify(state_rc, Severity::Warning, message);
+}
+
+pub fn notify_error(state_rc: &Rc<RefCell<AppState>>, message: impl Into<String>) {
+    notify(state_rc, Severity::Error, message);
+}
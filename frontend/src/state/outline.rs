@@ -0,0 +1,38 @@
+use crate::utils::outline::Section;
+
+/// Headings of the open file, listed in the outline overlay
+pub struct Outline {
+    pub sections: Vec<Section>,
+    pub selected: usize,
+}
+
+impl Outline {
+    /// Selects the heading the cursor at `row` is under
+    pub fn new(sections: Vec<Section>, row: usize) -> Self {
+        let selected = sections
+            .iter()
+            .rposition(|section| section.row <= row)
+            .unwrap_or(0);
+        Self { sections, selected }
+    }
+
+    pub fn next(&mut self) {
+        if !self.sections.is_empty() {
+            self.selected = (self.selected + 1) % self.sections.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.sections.is_empty() {
+            self.selected = if self.selected == 0 {
+                self.sections.len() - 1
+            } else {
+                self.selected - 1
+            };
+        }
+    }
+
+    pub fn selected(&self) -> Option<&Section> {
+        self.sections.get(self.selected)
+    }
+}
@@ -0,0 +1,50 @@
+/// Modal content drawn above the current pane
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlay {
+    /// Keybinds of the focused pane plus the global ones
+    Help,
+    /// Verification and diff of the upstream version of a file
+    Upstream,
+    /// Whitespace fixes to a file about to be saved
+    Hygiene,
+    /// Headings of the open file to jump to
+    Outline,
+    /// Servers to switch the UI to
+    Hosts,
+}
+
+/// The open overlay, if any; it takes all keys until closed
+pub struct OverlayState {
+    pub current: Option<Overlay>,
+    pub scroll: u16,
+}
+
+impl OverlayState {
+    pub fn new() -> Self {
+        Self {
+            current: None,
+            scroll: 0,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.current.is_some()
+    }
+
+    pub fn open(&mut self, overlay: Overlay) {
+        self.current = Some(overlay);
+        self.scroll = 0;
+    }
+
+    pub fn close(&mut self) {
+        self.current = None;
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_add(1);
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+}
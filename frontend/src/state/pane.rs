@@ -4,6 +4,18 @@ pub enum Pane {
     FileList,
     Editor,
     ContainerList,
+    ContainerGraph,
+    ContainerWatch,
+    Dashboard,
+    FileHistory,
+    HexViewer,
+    ThemeEditor,
+    KeybindEditor,
+    Actions,
+    Runbooks,
+    Insights,
+    Images,
+    Logs,
     Splash,
 }
 
@@ -14,22 +26,108 @@ impl Pane {
             Pane::FileList => "FileList",
             Pane::Editor => "Editor",
             Pane::ContainerList => "ContainerList",
+            Pane::ContainerGraph => "ContainerGraph",
+            Pane::ContainerWatch => "ContainerWatch",
+            Pane::Dashboard => "Dashboard",
+            Pane::FileHistory => "FileHistory",
+            Pane::HexViewer => "HexViewer",
+            Pane::ThemeEditor => "ThemeEditor",
+            Pane::KeybindEditor => "KeybindEditor",
+            Pane::Actions => "Actions",
+            Pane::Runbooks => "Runbooks",
+            Pane::Insights => "Insights",
+            Pane::Images => "Images",
+            Pane::Logs => "Logs",
             Pane::Splash => "Splash",
         }
     }
 
+    /// Section of keybinds.toml with this pane's own bindings
+    pub fn keybind_section(&self) -> Option<&'static str> {
+        match self {
+            Pane::Menu => Some("menu"),
+            Pane::FileList => Some("file_list"),
+            Pane::ContainerList => Some("container_list"),
+            Pane::ContainerGraph => Some("container_graph"),
+            Pane::ContainerWatch => Some("container_watch"),
+            Pane::Dashboard => Some("dashboard"),
+            Pane::FileHistory => Some("file_history"),
+            Pane::HexViewer => Some("hex_viewer"),
+            Pane::ThemeEditor => Some("theme_editor"),
+            Pane::KeybindEditor => Some("keybind_editor"),
+            Pane::Actions => Some("actions"),
+            Pane::Runbooks => Some("runbooks"),
+            Pane::Insights => Some("insights"),
+            Pane::Images => Some("images"),
+            Pane::Logs => Some("logs"),
+            Pane::Editor | Pane::Splash => None,
+        }
+    }
+
+    /// Sub-panes the focus cycles through while this pane is shown, in order;
+    /// panes without side panes only have their main widget
+    pub fn focus_ring(&self) -> &'static [SubPane] {
+        match self {
+            Pane::ContainerList | Pane::ContainerGraph => {
+                &[SubPane::Main, SubPane::ContainerDetails]
+            }
+            Pane::FileList | Pane::Editor => &[SubPane::Main, SubPane::Problems],
+            _ => &[SubPane::Main],
+        }
+    }
+
+    /// Whether this pane polls the server while focused
+    pub fn has_background_refresh(&self) -> bool {
+        matches!(self, Pane::ContainerList | Pane::Dashboard)
+    }
+
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "Menu" => Some(Pane::Menu),
             "FileList" => Some(Pane::FileList),
             "Editor" => Some(Pane::Editor),
             "ContainerList" => Some(Pane::ContainerList),
+            "ContainerGraph" => Some(Pane::ContainerGraph),
+            "ContainerWatch" => Some(Pane::ContainerWatch),
+            "Dashboard" => Some(Pane::Dashboard),
+            "FileHistory" => Some(Pane::FileHistory),
+            "HexViewer" => Some(Pane::HexViewer),
+            "ThemeEditor" => Some(Pane::ThemeEditor),
+            "KeybindEditor" => Some(Pane::KeybindEditor),
+            "Actions" => Some(Pane::Actions),
+            "Runbooks" => Some(Pane::Runbooks),
+            "Insights" => Some(Pane::Insights),
+            "Logs" => Some(Pane::Logs),
+            "Images" => Some(Pane::Images),
             "Splash" => Some(Pane::Splash),
             _ => None,
         }
     }
 }
 
+/// Part of a pane's view that can take the keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubPane {
+    /// The widget the pane is named after (the list, the graph, ...)
+    Main,
+    /// Details of the selected container, next to the list or the graph
+    ContainerDetails,
+    /// Problems drawer below the file list and editor, while it is open
+    Problems,
+}
+
+impl SubPane {
+    /// Section of keybinds.toml with this sub-pane's own bindings, None for
+    /// the main widget whose bindings are the pane's
+    pub fn keybind_section(&self) -> Option<&'static str> {
+        match self {
+            SubPane::Main => None,
+            SubPane::ContainerDetails => Some("container_details"),
+            SubPane::Problems => Some("problems"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VimMode {
     Normal,
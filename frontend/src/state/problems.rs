@@ -0,0 +1,105 @@
+use crate::api::{LintSeverity, LintWarning};
+use std::collections::BTreeMap;
+
+/// A lint warning or schema validation error of a file
+#[derive(Debug, Clone)]
+pub struct Problem {
+    pub file: String,
+    /// 1-based line, None for the file as a whole
+    pub line: Option<usize>,
+    pub severity: LintSeverity,
+    /// Lint pack or checker and its rule, or "schema"
+    pub source: String,
+    pub message: String,
+}
+
+impl Problem {
+    pub fn from_lint(file: &str, warning: &LintWarning) -> Self {
+        Self {
+            file: file.to_string(),
+            line: warning.line,
+            severity: warning.severity,
+            source: format!("{}/{}", warning.pack, warning.rule),
+            message: warning.message.clone(),
+        }
+    }
+}
+
+/// Problems of every file checked this session, by file name, listed in the
+/// problems drawer below the editor
+#[derive(Default)]
+pub struct Problems {
+    by_file: BTreeMap<String, Vec<Problem>>,
+    /// The drawer is shown
+    pub open: bool,
+    /// Index into `all()`
+    pub selected: usize,
+    /// File and 0-based line to move the cursor to once the file opened from
+    /// the drawer is loaded
+    pub pending_jump: Option<(String, usize)>,
+}
+
+impl Problems {
+    /// Replace the problems of `file`, sorted by line
+    pub fn set(&mut self, file: &str, mut problems: Vec<Problem>) {
+        if problems.is_empty() {
+            self.by_file.remove(file);
+        } else {
+            problems.sort_by_key(|problem| problem.line);
+            self.by_file.insert(file.to_string(), problems);
+        }
+        self.selected = self.selected.min(self.len().saturating_sub(1));
+    }
+
+    /// All problems, file by file
+    pub fn all(&self) -> impl Iterator<Item = &Problem> {
+        self.by_file.values().flatten()
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_file.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_file.is_empty()
+    }
+
+    /// Number of errors and warnings over all files
+    pub fn counts(&self) -> (usize, usize) {
+        let errors = self
+            .all()
+            .filter(|problem| problem.severity == LintSeverity::Error)
+            .count();
+        (errors, self.len() - errors)
+    }
+
+    pub fn next(&mut self) {
+        let len = self.len();
+        if len > 0 {
+            self.selected = (self.selected + 1) % len;
+        }
+    }
+
+    pub fn previous(&mut self) {
+        let len = self.len();
+        if len > 0 {
+            self.selected = if self.selected == 0 {
+                len - 1
+            } else {
+                self.selected - 1
+            };
+        }
+    }
+
+    pub fn selected(&self) -> Option<&Problem> {
+        self.all().nth(self.selected)
+    }
+
+    /// The line to jump to in `file`, if the drawer opened it
+    pub fn take_jump(&mut self, file: &str) -> Option<usize> {
+        match &self.pending_jump {
+            Some((name, _)) if name == file => self.pending_jump.take().map(|(_, line)| line),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::api::JobStatus;
+use crate::state::{AppState, Pane, notifications};
+use crate::utils;
+use gloo_timers::callback::Interval;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Fetch the configured actions, and resume following a run still in progress
+pub fn refresh_actions(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match crate::api::fetch_actions().await {
+            Ok(actions) => {
+                let mut st = state_clone.borrow_mut();
+                st.actions.set_actions(actions);
+                if let Some(job) = st.actions.job.as_ref()
+                    && job.status == JobStatus::Running
+                    && st.actions.poll.is_none()
+                {
+                    let id = job.id;
+                    follow_job(&mut st, &state_clone, id);
+                }
+            }
+            Err(e) => {
+                state_clone.borrow_mut().actions.loaded = true;
+                notifications::notify_error(
+                    &state_clone,
+                    format!("Loading actions failed: {}", utils::error::format_error(&e)),
+                );
+            }
+        }
+    });
+}
+
+/// Poll a job every second while the Actions pane is focused, until it finishes
+pub fn follow_job(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, id: u64) {
+    let state_clone = Rc::clone(state_rc);
+    let token = state.actions.job_token.clone();
+    let interval = Interval::new(1_000, move || {
+        let state_clone = Rc::clone(&state_clone);
+        let token = token.clone();
+        spawn_local(async move {
+            let job = crate::api::fetch_job(id, token).await;
+            let mut st = state_clone.borrow_mut();
+            // A newer run replaced this one, its own poll follows it
+            if st.actions.job.as_ref().map(|j| j.id) != Some(id) {
+                return;
+            }
+            // The pane was left (following resumes on return), or the server
+            // no longer keeps the job
+            if st.focus != Pane::Actions || matches!(job, Ok(None)) {
+                st.actions.poll = None;
+                return;
+            }
+            let Ok(Some(job)) = job else {
+                return;
+            };
+            let status = job.status;
+            let message = format!("{}: {}", job.target, job.message);
+            st.actions.job = Some(job);
+            if status == JobStatus::Running {
+                return;
+            }
+            st.actions.poll = None;
+            drop(st);
+            if status == JobStatus::Succeeded {
+                notifications::notify_success(&state_clone, message);
+            } else {
+                notifications::notify_error(&state_clone, message);
+            }
+        });
+    });
+
+    // Keep the handle in state: dropping it cancels the interval
+    state.actions.poll = Some(interval);
+}
@@ -0,0 +1,57 @@
+/// Base retry delay (the default background refresh interval)
+const BASE_DELAY_MS: f64 = 10_000.0;
+
+/// Upper bound for the retry delay while the server is unreachable
+const MAX_DELAY_MS: f64 = 300_000.0;
+
+/// Exponential backoff for background refresh failures
+pub struct RefreshBackoff {
+    /// Consecutive failed refreshes (0 = healthy)
+    pub failures: u32,
+    /// Timestamp (ms) before which background ticks are skipped
+    pub next_retry_at: f64,
+    /// A refresh request is currently pending
+    pub in_flight: bool,
+}
+
+impl RefreshBackoff {
+    pub fn new() -> Self {
+        Self {
+            failures: 0,
+            next_retry_at: 0.0,
+            in_flight: false,
+        }
+    }
+
+    pub fn is_backing_off(&self) -> bool {
+        self.failures > 0
+    }
+
+    /// Whether a background tick at `now` should hit the server
+    pub fn should_refresh(&self, now: f64) -> bool {
+        !self.in_flight && now >= self.next_retry_at
+    }
+
+    /// Record a failed refresh and schedule the next attempt
+    pub fn record_failure(&mut self, now: f64) {
+        self.in_flight = false;
+        self.failures = self.failures.saturating_add(1);
+        let exponent = self.failures.min(16) as i32;
+        let delay = (BASE_DELAY_MS * 2f64.powi(exponent)).min(MAX_DELAY_MS);
+        self.next_retry_at = now + delay;
+    }
+
+    /// Record a successful refresh, returns true if we recovered from failures
+    pub fn record_success(&mut self) -> bool {
+        let recovered = self.failures > 0;
+        self.in_flight = false;
+        self.failures = 0;
+        self.next_retry_at = 0.0;
+        recovered
+    }
+
+    /// Seconds until the next scheduled retry (rounded up)
+    pub fn seconds_until_retry(&self, now: f64) -> u64 {
+        ((self.next_retry_at - now).max(0.0) / 1000.0).ceil() as u64
+    }
+}
@@ -1,44 +1,59 @@
+use crate::api::{ContainerInfo, FileInfo};
 use crate::state::{AppState, Pane};
+use crate::storage::{generic, idb, keys};
+use std::{cell::RefCell, rc::Rc};
 
 /// Save selection index for a pane
 pub fn save_selection(pane: Pane, state: &AppState) {
     match pane {
         Pane::FileList => {
-            crate::storage::generic::save("file-list-selection", &state.file_list.selected_index);
+            generic::save(keys::FILE_LIST_SELECTION, &state.file_list.selected_index);
         }
         Pane::ContainerList => {
-            crate::storage::generic::save(
-                "container-list-selection",
-                &state.container_list.selected_index,
+            // By identity: the index shifts as containers come and go
+            generic::save(
+                keys::CONTAINER_LIST_SELECTION,
+                &state.container_list.selected_identity(),
             );
         }
         _ => {}
     }
 }
 
-/// Load cached data for a pane from storage
-pub fn load_pane_cache(pane: Pane, state: &mut AppState) {
+/// Load cached data for a pane from storage. The lists come from IndexedDB
+/// and are only applied while the pane has nothing newer from the server.
+pub async fn load_pane_cache(pane: Pane, state_rc: &Rc<RefCell<AppState>>) {
     match pane {
         Pane::FileList => {
-            if let Some(files) = crate::storage::generic::load("file-list") {
-                state.file_list.set_files(files);
+            let files: Option<Vec<FileInfo>> = idb::get("file-list").await;
+            crate::perf::record_storage(files.is_some());
+            // Ordered like the live list, so it does not jump once fetched
+            let order = idb::get("file-list-order").await.unwrap_or_default();
+            let mut state = state_rc.borrow_mut();
+            if let Some(files) = files
+                && state.file_list.all.is_empty()
+            {
+                state.file_list.set_files(files, order);
             }
             // Restore selection index
-            if let Some(index) = crate::storage::generic::load::<usize>("file-list-selection")
+            if let Some(index) = generic::load::<usize>(keys::FILE_LIST_SELECTION)
                 && index < state.file_list.files.len()
             {
                 state.file_list.selected_index = index;
             }
         }
         Pane::ContainerList => {
-            if let Some(containers) = crate::storage::generic::load("container-list") {
+            let containers: Option<Vec<ContainerInfo>> = idb::get("container-list").await;
+            crate::perf::record_storage(containers.is_some());
+            let mut state = state_rc.borrow_mut();
+            if let Some(containers) = containers
+                && state.container_list.all.is_empty()
+            {
                 state.container_list.set_containers(containers);
             }
-            // Restore selection index
-            if let Some(index) = crate::storage::generic::load::<usize>("container-list-selection")
-                && index < state.container_list.containers.len()
-            {
-                state.container_list.selected_index = index;
+            // Restore selection by identity
+            if let Some(identity) = generic::load::<String>(keys::CONTAINER_LIST_SELECTION) {
+                state.container_list.select_identity(&identity);
             }
         }
         _ => {}
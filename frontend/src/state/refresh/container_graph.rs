@@ -0,0 +1,24 @@
+use crate::state::{AppState, notifications};
+use crate::utils;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+pub fn refresh_container_graph(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match crate::api::fetch_container_graph().await {
+            Ok(graph) => {
+                state_clone.borrow_mut().container_graph.set_graph(graph);
+            }
+            Err(e) => {
+                notifications::notify_error(
+                    &state_clone,
+                    format!(
+                        "Loading container graph failed: {}",
+                        utils::error::format_error(&e)
+                    ),
+                );
+            }
+        }
+    });
+}
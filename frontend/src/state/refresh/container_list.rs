@@ -1,28 +1,84 @@
-use crate::state::{AppState, status_helper};
+use crate::api::ContainerListResponse;
+use crate::state::{AppState, notifications};
 use crate::utils;
-use gloo_timers::callback::Interval;
 use std::{cell::RefCell, rc::Rc};
 use wasm_bindgen_futures::spawn_local;
 
 pub fn refresh_container_list(state_rc: &Rc<RefCell<AppState>>) {
     let state_clone = Rc::clone(state_rc);
     spawn_local(async move {
-        match crate::api::fetch_container_list().await {
-            Ok(containers) => {
+        {
+            let mut st = state_clone.borrow_mut();
+            st.refresh_backoff.in_flight = true;
+            st.container_list.loading = true;
+        }
+
+        let result = crate::api::fetch_container_list().await;
+        state_clone.borrow_mut().container_list.loading = false;
+        match result {
+            // The server answered, it is the daemon that is gone: keep the
+            // cached list and let the banner explain
+            Ok(ContainerListResponse {
+                outage: Some(outage),
+                ..
+            }) => {
+                let started = {
+                    let mut st = state_clone.borrow_mut();
+                    st.refresh_backoff.record_success();
+                    st.container_list.error = None;
+                    st.container_list.outage.replace(outage).is_none()
+                };
+                if started {
+                    notifications::notify_warning(
+                        &state_clone,
+                        "Docker daemon unreachable, reconnecting",
+                    );
+                }
+            }
+            Ok(ContainerListResponse {
+                containers,
+                outage: None,
+            }) => {
+                let recovered = state_clone.borrow_mut().refresh_backoff.record_success();
+                if recovered {
+                    notifications::notify_success(&state_clone, "Reconnected");
+                }
+                let daemon_back = state_clone.borrow_mut().container_list.outage.take();
+                if daemon_back.is_some() {
+                    notifications::notify_success(&state_clone, "Docker daemon is back");
+                }
+
                 let mut st = state_clone.borrow_mut();
+                st.container_list.error = None;
                 // Only save to cache if data changed (important for background refresh!)
-                if st.container_list.containers != containers {
-                    crate::storage::generic::save("container-list", &containers);
+                if st.container_list.all != containers {
+                    crate::storage::idb::put("container-list", &containers);
                 }
                 st.container_list.set_containers(containers);
                 // Don't overwrite status on success - let action messages show
             }
+            // Keep showing the cached list, the offline banner explains it
+            Err(_) if state_clone.borrow().offline => {
+                state_clone.borrow_mut().refresh_backoff.in_flight = false;
+            }
             Err(e) => {
-                crate::storage::generic::clear("container-list");
-                status_helper::set_status_timed(
+                crate::storage::idb::delete("container-list");
+
+                let first_failure = {
+                    let mut st = state_clone.borrow_mut();
+                    st.container_list.error = Some(utils::error::format_error(&e));
+                    st.refresh_backoff.record_failure(js_sys::Date::now());
+                    st.refresh_backoff.failures == 1
+                };
+
+                // Report once; the status line shows the retry countdown afterwards
+                if !first_failure {
+                    return;
+                }
+                notifications::notify_warning(
                     &state_clone,
                     format!(
-                        "[ERROR loading containers: {}]",
+                        "Loading containers failed, retrying: {}",
                         utils::error::format_error(&e)
                     ),
                 );
@@ -30,17 +86,3 @@ pub fn refresh_container_list(state_rc: &Rc<RefCell<AppState>>) {
         }
     });
 }
-
-/// Start background refresh timer for container list
-/// Refreshes every 10 seconds to keep container status up-to-date
-pub fn start_background_refresh(state_rc: &Rc<RefCell<AppState>>) {
-    let state_clone = Rc::clone(state_rc);
-
-    // Create interval that fires every 10 seconds
-    let interval = Interval::new(10_000, move || {
-        refresh_container_list(&state_clone);
-    });
-
-    // Prevent interval from being dropped (it needs to keep running)
-    interval.forget();
-}
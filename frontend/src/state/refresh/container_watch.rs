@@ -0,0 +1,60 @@
+use crate::state::{AppState, Pane};
+use crate::utils;
+use gloo_timers::callback::Interval;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Fetch one watch update; skipped while paused or while the last one is
+/// still on its way, so a slow docker does not stack up requests
+///
+/// Entering the pane and resuming call this with the state borrowed, so the
+/// watch is only checked once the task runs.
+pub fn refresh_container_watch(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let container_id = {
+            let mut st = state_clone.borrow_mut();
+            let watch = &mut st.container_watch;
+            if watch.paused || watch.in_flight {
+                return;
+            }
+            let Some(container_id) = watch.container_id.clone() else {
+                return;
+            };
+            watch.in_flight = true;
+            container_id
+        };
+        let result = crate::api::fetch_container_watch(&container_id).await;
+        let mut st = state_clone.borrow_mut();
+        let watch = &mut st.container_watch;
+        watch.in_flight = false;
+        // Another container was opened meanwhile
+        if watch.container_id.as_deref() != Some(container_id.as_str()) {
+            return;
+        }
+        // Failures stay in the pane instead of a toast every second
+        match result {
+            Ok(data) => {
+                watch.name = data.name.clone();
+                watch.data = Some(data);
+                watch.error = None;
+            }
+            Err(e) => watch.error = Some(utils::error::format_error(&e)),
+        }
+    });
+}
+
+/// Poll the watched container (every second by default) while the pane is
+/// focused and the tab visible
+pub fn start_watch_poll(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    let interval_ms = super::interval_ms(state, Pane::ContainerWatch);
+    let interval = Interval::new(interval_ms, move || {
+        if state_clone.borrow().focus == Pane::ContainerWatch && !super::page_hidden() {
+            refresh_container_watch(&state_clone);
+        }
+    });
+
+    // Keep the handle in state: dropping it cancels the interval
+    state.container_watch.poll = Some(interval);
+}
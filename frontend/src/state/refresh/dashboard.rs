@@ -0,0 +1,44 @@
+use crate::state::{AppState, notifications};
+use crate::utils;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+pub fn refresh_dashboard(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        state_clone.borrow_mut().refresh_backoff.in_flight = true;
+
+        match crate::api::fetch_dashboard().await {
+            Ok(data) => {
+                let recovered = state_clone.borrow_mut().refresh_backoff.record_success();
+                if recovered {
+                    notifications::notify_success(&state_clone, "Reconnected");
+                }
+
+                state_clone
+                    .borrow_mut()
+                    .dashboard
+                    .set_data(data, js_sys::Date::now());
+            }
+            Err(e) => {
+                let first_failure = {
+                    let mut st = state_clone.borrow_mut();
+                    st.refresh_backoff.record_failure(js_sys::Date::now());
+                    st.refresh_backoff.failures == 1
+                };
+
+                // Report once; the status line shows the retry countdown afterwards
+                if !first_failure {
+                    return;
+                }
+                notifications::notify_warning(
+                    &state_clone,
+                    format!(
+                        "Loading dashboard failed, retrying: {}",
+                        utils::error::format_error(&e)
+                    ),
+                );
+            }
+        }
+    });
+}
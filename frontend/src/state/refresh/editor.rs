@@ -0,0 +1,86 @@
+use crate::api::LintSeverity;
+use crate::state::{AppState, Problem, schema_form::SchemaForm};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Check the editor content against the lint packs and the schema of the
+/// open file, and file the results in the problems drawer
+///
+/// Runs when the editor is entered, after a save and when insert mode is
+/// left. Linting is advisory, so failures only reach the console.
+pub fn lint_editor(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let (filename, content) = {
+            let st = state_clone.borrow();
+            let Some(filename) = st.editor.current_file.clone() else {
+                return;
+            };
+            (filename, st.editor.get_content())
+        };
+
+        match crate::api::lint_file_content(&filename, content.clone()).await {
+            Ok(warnings) => {
+                let mut st = state_clone.borrow_mut();
+                // Another file was opened meanwhile
+                if st.editor.current_file.as_deref() == Some(filename.as_str()) {
+                    let mut problems: Vec<Problem> = warnings
+                        .iter()
+                        .map(|warning| Problem::from_lint(&filename, warning))
+                        .collect();
+                    problems.extend(schema_problems(&st, &filename, &content));
+                    st.problems.set(&filename, problems);
+                    st.editor.lint = warnings;
+                }
+            }
+            Err(e) => web_sys::console::warn_1(&e),
+        }
+    });
+}
+
+/// Fields of `content` that break the schema of `filename`, as problems
+fn schema_problems(state: &AppState, filename: &str, content: &str) -> Vec<Problem> {
+    let Some(schema) = state.file_list.schema_of(filename) else {
+        return Vec::new();
+    };
+    let Some(form) = SchemaForm::new(schema, filename, content) else {
+        return Vec::new();
+    };
+    form.problems()
+        .into_iter()
+        .map(|(line, message)| Problem {
+            file: filename.to_string(),
+            line,
+            severity: LintSeverity::Error,
+            source: "schema".to_string(),
+            message,
+        })
+        .collect()
+}
+
+/// Fetch the rendered view of the open file while it is shown
+///
+/// Runs when the view is opened and after a save, since the server renders
+/// the content as saved.
+pub fn render_editor(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let filename = {
+            let st = state_clone.borrow();
+            match (&st.editor.current_file, &st.editor.rendered) {
+                (Some(filename), Some(_)) => filename.clone(),
+                _ => return,
+            }
+        };
+        let result = crate::api::fetch_rendered(&filename)
+            .await
+            .map_err(|e| crate::utils::error::format_error(&e));
+        let mut st = state_clone.borrow_mut();
+        // Another file was opened or the view closed meanwhile
+        if st.editor.current_file.as_deref() == Some(filename.as_str())
+            && let Some(rendered) = st.editor.rendered.as_mut()
+        {
+            rendered.result = Some(result);
+        }
+    });
+}
@@ -0,0 +1,33 @@
+use crate::state::{AppState, notifications};
+use crate::utils;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Fetch the commits of the file being viewed
+///
+/// Entering the pane calls this with the state borrowed, so the file name is
+/// only read once the task runs.
+pub fn refresh_file_history(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let Some(filename) = state_clone.borrow().file_history.filename.clone() else {
+            return;
+        };
+        match crate::api::fetch_file_log(&filename).await {
+            Ok(commits) => {
+                let mut st = state_clone.borrow_mut();
+                // Ignore responses for a file that is no longer shown
+                if st.file_history.filename.as_deref() == Some(filename.as_str()) {
+                    st.file_history.set_commits(commits);
+                }
+            }
+            Err(e) => {
+                state_clone.borrow_mut().file_history.loaded = true;
+                notifications::notify_error(
+                    &state_clone,
+                    format!("Loading history failed: {}", utils::error::format_error(&e)),
+                );
+            }
+        }
+    });
+}
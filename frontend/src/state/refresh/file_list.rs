@@ -1,4 +1,5 @@
-use crate::state::{AppState, status_helper};
+use crate::api::Conditional;
+use crate::state::{AppState, notifications};
 use crate::utils;
 use std::{cell::RefCell, rc::Rc};
 use wasm_bindgen_futures::spawn_local;
@@ -6,21 +7,35 @@ use wasm_bindgen_futures::spawn_local;
 pub fn refresh_file_list(state_rc: &Rc<RefCell<AppState>>) {
     let state_clone = Rc::clone(state_rc);
     spawn_local(async move {
-        match crate::api::fetch_file_list().await {
-            Ok(files) => {
+        let etag = {
+            let mut st = state_clone.borrow_mut();
+            st.file_list.loading = true;
+            st.file_list.etag.clone()
+        };
+        let result = crate::api::fetch_file_list(etag).await;
+        state_clone.borrow_mut().file_list.loading = false;
+        match result {
+            Ok(Conditional::Modified { data: list, etag }) => {
                 let mut st = state_clone.borrow_mut();
-                // Only save to cache if data changed
-                if st.file_list.files != files {
-                    crate::storage::generic::save("file-list", &files);
-                }
-                st.file_list.set_files(files);
+                st.file_list.readonly_override = list.readonly_override;
+                st.file_list.etag = etag;
+                st.file_list.error = None;
+                crate::storage::idb::put("file-list", &list.files);
+                crate::storage::idb::put("file-list-order", &list.order);
+                st.file_list.set_files(list.files, list.order);
                 // Don't overwrite status on success - let action messages show
             }
+            // Unchanged since the last fetch, nothing to update or cache
+            Ok(Conditional::NotModified) => state_clone.borrow_mut().file_list.error = None,
+            // Keep showing the cached list, the offline banner explains it
+            Err(_) if state_clone.borrow().offline => {}
             Err(e) => {
-                crate::storage::generic::clear("file-list");
-                status_helper::set_status_timed(
+                let message = utils::error::format_error(&e);
+                crate::storage::idb::delete("file-list");
+                state_clone.borrow_mut().file_list.error = Some(message.clone());
+                notifications::notify_error(
                     &state_clone,
-                    format!("[ERROR loading files: {}]", utils::error::format_error(&e)),
+                    format!("Loading files failed: {}", message),
                 );
             }
         }
@@ -0,0 +1,44 @@
+use crate::state::{AppState, notifications};
+use crate::utils;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Reload the page of the viewed file at the current offset
+pub fn refresh_hex_viewer(state_rc: &Rc<RefCell<AppState>>) {
+    load_hex_page(state_rc, None);
+}
+
+/// Fetch the page at `offset` (the current one if None) of the viewed file
+///
+/// Callers usually hold the state borrowed, so it is only read once the
+/// task runs.
+pub fn load_hex_page(state_rc: &Rc<RefCell<AppState>>, offset: Option<u64>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let (filename, offset) = {
+            let st = state_clone.borrow();
+            let Some(filename) = st.hex_viewer.filename.clone() else {
+                return;
+            };
+            (filename, offset.unwrap_or(st.hex_viewer.offset))
+        };
+
+        let page = crate::state::hex_viewer::PAGE_SIZE;
+        match crate::api::fetch_file_range(&filename, offset, page).await {
+            Ok((data, size)) => {
+                let mut st = state_clone.borrow_mut();
+                // Ignore responses for a file that is no longer shown
+                if st.hex_viewer.filename.as_deref() == Some(filename.as_str()) {
+                    st.hex_viewer.set_page(offset, data, size);
+                }
+            }
+            Err(e) => {
+                state_clone.borrow_mut().hex_viewer.loaded = true;
+                notifications::notify_error(
+                    &state_clone,
+                    format!("Loading bytes failed: {}", utils::error::format_error(&e)),
+                );
+            }
+        }
+    });
+}
@@ -0,0 +1,67 @@
+use crate::api::JobStatus;
+use crate::state::{AppState, Pane, notifications};
+use crate::utils;
+use gloo_timers::callback::Interval;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Fetch the images, and resume following a scan still in progress
+pub fn refresh_images(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match crate::api::fetch_images().await {
+            Ok(response) => {
+                let mut st = state_clone.borrow_mut();
+                st.images.set_images(response.images, response.scanner);
+                if let Some(job_id) = st.images.scan.as_ref().map(|s| s.job_id)
+                    && st.images.poll.is_none()
+                {
+                    follow_scan(&mut st, &state_clone, job_id);
+                }
+            }
+            Err(e) => {
+                state_clone.borrow_mut().images.loaded = true;
+                notifications::notify_error(
+                    &state_clone,
+                    format!("Loading images failed: {}", utils::error::format_error(&e)),
+                );
+            }
+        }
+    });
+}
+
+/// Poll a scan's job every second while the Images pane is focused; once it
+/// finishes the list is fetched again to show the result
+pub fn follow_scan(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, job_id: u64) {
+    let state_clone = Rc::clone(state_rc);
+    let interval = Interval::new(1_000, move || {
+        let state_clone = Rc::clone(&state_clone);
+        spawn_local(async move {
+            let Ok(Some(job)) = crate::api::fetch_job(job_id, None).await else {
+                return;
+            };
+            let mut st = state_clone.borrow_mut();
+            // The pane was left, following resumes on return
+            if st.images.scan.as_ref().map(|s| s.job_id) != Some(job_id)
+                || st.focus != Pane::Images
+                || job.status == JobStatus::Running
+            {
+                return;
+            }
+            st.images.scan = None;
+            st.images.poll = None;
+            drop(st);
+
+            let message = format!("Scan of {}: {}", job.target, job.message);
+            if job.status == JobStatus::Succeeded {
+                notifications::notify_success(&state_clone, message);
+            } else {
+                notifications::notify_error(&state_clone, message);
+            }
+            refresh_images(&state_clone);
+        });
+    });
+
+    // Keep the handle in state: dropping it cancels the interval
+    state.images.poll = Some(interval);
+}
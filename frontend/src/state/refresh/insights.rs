@@ -0,0 +1,26 @@
+use crate::state::AppState;
+use crate::utils;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Fetch the change counts for the selected window
+///
+/// Entering the pane calls this with the state borrowed, so the window is
+/// only read once the task runs.
+pub fn refresh_insights(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let days = state_clone.borrow().insights.days;
+        let result = crate::api::fetch_change_stats(days).await;
+        let mut st = state_clone.borrow_mut();
+        // Ignore responses for a window that is no longer shown
+        if st.insights.days != days {
+            return;
+        }
+        match result {
+            Ok(stats) => st.insights.set_stats(stats.files),
+            // Shown in the pane: without git versioning there is nothing to count
+            Err(e) => st.insights.set_error(utils::error::format_error(&e)),
+        }
+    });
+}
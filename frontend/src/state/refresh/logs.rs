@@ -0,0 +1,58 @@
+use crate::api::{self, LogEvent};
+use crate::state::{AppState, logs::BACKLOG_LINES};
+use crate::utils;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Fetch the configured log files
+pub fn refresh_logs(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let result = api::fetch_logs().await;
+        let mut st = state_clone.borrow_mut();
+        match result {
+            Ok(logs) => st.logs.set_logs(logs),
+            Err(e) => st.logs.set_error(utils::error::format_error(&e)),
+        }
+    });
+}
+
+/// Follow the selected log, replacing the one followed before
+pub fn follow_selected(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(name) = state.logs.selected().map(|log| log.name.clone()) else {
+        return;
+    };
+
+    let state_clone = Rc::clone(state_rc);
+    let followed = name.clone();
+    let stream = api::follow_log(&name, BACKLOG_LINES, move |event| {
+        let Ok(mut st) = state_clone.try_borrow_mut() else {
+            return;
+        };
+        // A late event of a log that is no longer followed
+        if st.logs.following.as_deref() != Some(followed.as_str()) {
+            return;
+        }
+        match event {
+            LogEvent::Lines(lines) => st.logs.push_lines(lines),
+            LogEvent::Rotated(lines) => st.logs.rotated(lines),
+            LogEvent::Failure(reason) => st.logs.failure = Some(reason),
+            LogEvent::Disconnected => {
+                let closed = st.logs.stream.as_ref().is_some_and(|s| s.is_closed());
+                st.logs.disconnected(closed);
+            }
+        }
+    });
+
+    match stream {
+        Ok(stream) => {
+            state.logs.start(name.clone(), stream);
+            state.set_status(format!("Following {}", name));
+        }
+        Err(e) => state.set_status(format!(
+            "[ERROR following {}: {}]",
+            name,
+            utils::error::format_error(&e)
+        )),
+    }
+}
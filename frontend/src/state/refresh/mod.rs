@@ -1,21 +1,248 @@
+mod actions;
+mod backoff;
 mod cache;
+mod container_graph;
 mod container_list;
+mod container_watch;
+mod dashboard;
+mod editor;
+mod file_history;
 mod file_list;
+mod hex_viewer;
+mod images;
+mod insights;
+mod logs;
+mod runbooks;
 
 use crate::state::{AppState, Pane};
+use gloo_timers::callback::Interval;
 use std::{cell::RefCell, rc::Rc};
+use web_sys::window;
+
+pub use backoff::RefreshBackoff;
 
 // Re-export cache functions
+pub use actions::follow_job;
 pub use cache::{load_pane_cache, save_selection};
+pub use editor::render_editor;
+pub use hex_viewer::load_hex_page;
+pub use images::follow_scan;
+pub use logs::follow_selected as follow_log;
+pub use runbooks::follow_step;
+
+/// A refresh asked for with the global refresh key, summarized in the
+/// status line once its requests answered
+#[derive(Debug, Clone, Copy)]
+pub struct ManualRefresh {
+    pub pane: Pane,
+    /// `Date.now()` when the key was pressed
+    pub started: f64,
+}
+
+/// The container watch polls this often unless sysrat.toml says otherwise
+const WATCH_INTERVAL_MS: u64 = 1_000;
+
+/// Milliseconds between background refreshes of `pane`, from the
+/// `[network]` section of sysrat.toml
+pub fn interval_ms(state: &AppState, pane: Pane) -> u32 {
+    let intervals = &state.branding.refresh;
+    let ms = match pane {
+        Pane::ContainerList => intervals.for_pane("container_list"),
+        Pane::Dashboard => intervals.for_pane("dashboard"),
+        Pane::ContainerWatch => intervals
+            .panes
+            .get("container_watch")
+            .copied()
+            .unwrap_or(WATCH_INTERVAL_MS),
+        _ => intervals.default_ms,
+    };
+    ms.min(u32::MAX as u64) as u32
+}
 
-// Re-export background refresh
-pub use container_list::start_background_refresh;
+/// The tab is in the background; polls skip their ticks until it is back
+pub fn page_hidden() -> bool {
+    window()
+        .and_then(|w| w.document())
+        .is_some_and(|doc| doc.hidden())
+}
 
-/// Refresh data for a specific pane
-pub fn refresh_pane(pane: Pane, state_rc: &Rc<RefCell<AppState>>) {
+/// Refresh data for a specific pane, returns false for panes without server
+/// data behind them
+pub fn refresh_pane(pane: Pane, state_rc: &Rc<RefCell<AppState>>) -> bool {
     match pane {
         Pane::FileList => file_list::refresh_file_list(state_rc),
         Pane::ContainerList => container_list::refresh_container_list(state_rc),
-        _ => {}
+        Pane::ContainerGraph => container_graph::refresh_container_graph(state_rc),
+        Pane::ContainerWatch => container_watch::refresh_container_watch(state_rc),
+        Pane::Dashboard => dashboard::refresh_dashboard(state_rc),
+        Pane::Editor => {
+            editor::lint_editor(state_rc);
+            editor::render_editor(state_rc);
+        }
+        Pane::FileHistory => file_history::refresh_file_history(state_rc),
+        Pane::HexViewer => hex_viewer::refresh_hex_viewer(state_rc),
+        Pane::Actions => actions::refresh_actions(state_rc),
+        Pane::Runbooks => runbooks::refresh_runbooks(state_rc),
+        Pane::Insights => insights::refresh_insights(state_rc),
+        Pane::Images => images::refresh_images(state_rc),
+        Pane::Logs => logs::refresh_logs(state_rc),
+        _ => return false,
+    }
+    true
+}
+
+/// Pane lifecycle hook: start polling when entering a pane that needs live data,
+/// stop when leaving it. Entering also triggers an immediate refresh.
+pub fn on_pane_change(
+    from: Pane,
+    to: Pane,
+    state: &mut AppState,
+    state_rc: &Rc<RefCell<AppState>>,
+) {
+    // The theme editor previews on the live theme, so unsaved edits must not
+    // outlive the pane
+    if from == Pane::ThemeEditor {
+        state.discard_theme_edits();
+    }
+    if to == Pane::ThemeEditor {
+        state.open_theme_editor();
+    }
+    // The preview frame lives in the DOM, outside the editor's own rendering
+    if !matches!(to, Pane::FileList | Pane::Editor) {
+        state.editor.close_preview();
+    }
+    if to == Pane::KeybindEditor {
+        state.keybind_editor.open(&state.keybinds);
+    }
+    // A run keeps going on the server, following it resumes on return
+    if from == Pane::Actions {
+        state.actions.poll = None;
+        state.actions.params = None;
+    }
+    // Same for a runbook step, the walk itself is kept until it is left
+    if from == Pane::Runbooks {
+        state.runbooks.poll = None;
+    }
+    if from == Pane::Images {
+        state.images.poll = None;
+    }
+    // A followed log is an open connection, closed with the pane
+    if from == Pane::Logs {
+        state.logs.stop();
+    }
+    // The watch polls every second, so only while it is on screen
+    if from == Pane::ContainerWatch {
+        state.container_watch.poll = None;
+    }
+    if to == Pane::ContainerWatch {
+        container_watch::start_watch_poll(state, state_rc);
+    }
+
+    if from.has_background_refresh() && !to.has_background_refresh() {
+        stop_background_refresh(state);
+    }
+
+    // Polling follows the focused pane, so switching between polling panes
+    // keeps the timer (unless their intervals differ) and only fetches the
+    // new pane's data
+    if to.has_background_refresh() {
+        refresh_pane(to, state_rc);
+        start_background_refresh(state, state_rc);
     }
+
+    // The graph runs a docker inspect per container and history walks the git
+    // log, so fetch once per visit (the hex viewer loads its first page, the
+    // editor checks its content against the lint packs)
+    if matches!(
+        to,
+        Pane::ContainerGraph
+            | Pane::FileHistory
+            | Pane::HexViewer
+            | Pane::Actions
+            | Pane::Runbooks
+            | Pane::Insights
+            | Pane::Images
+            | Pane::Logs
+            | Pane::ContainerWatch
+            | Pane::Editor
+    ) {
+        refresh_pane(to, state_rc);
+    }
+}
+
+/// Start background refresh timer for the focused pane, at the pane's
+/// interval. A running timer is kept unless its interval differs.
+pub fn start_background_refresh(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let interval_ms = interval_ms(state, state.focus);
+    if state.background_refresh.is_some() && state.background_refresh_ms == interval_ms {
+        return;
+    }
+
+    let state_clone = Rc::clone(state_rc);
+    let interval = Interval::new(interval_ms, move || {
+        // Skip ticks while backing off from a server that is unreachable,
+        // while offline until the browser says the network is back, and
+        // while the tab is hidden (it refreshes when shown again)
+        let (focus, due) = {
+            let st = state_clone.borrow();
+            (
+                st.focus,
+                !st.offline
+                    && !page_hidden()
+                    && st.refresh_backoff.should_refresh(js_sys::Date::now()),
+            )
+        };
+        if due {
+            refresh_pane(focus, &state_clone);
+        }
+    });
+
+    // Keep the handle in state: dropping it cancels the interval
+    state.background_refresh = Some(interval);
+    state.background_refresh_ms = interval_ms;
+}
+
+/// Restart the running polls after the intervals changed
+pub fn restart_polls(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    if state.background_refresh.is_some() {
+        start_background_refresh(state, state_rc);
+    }
+    if state.container_watch.poll.is_some() {
+        container_watch::start_watch_poll(state, state_rc);
+    }
+}
+
+/// Refresh the focused pane right away when the tab is shown again, its
+/// polls skipped their ticks while it was hidden
+pub fn refresh_on_visible(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    if state.offline {
+        return;
+    }
+    if state.focus == Pane::ContainerWatch {
+        container_watch::refresh_container_watch(state_rc);
+        return;
+    }
+    let backoff = &mut state.refresh_backoff;
+    if !state.focus.has_background_refresh() || backoff.in_flight {
+        return;
+    }
+    backoff.next_retry_at = 0.0;
+    refresh_pane(state.focus, state_rc);
+}
+
+/// Stop background refresh timer (no-op if not running)
+pub fn stop_background_refresh(state: &mut AppState) {
+    state.background_refresh = None;
+}
+
+/// Retry immediately if background refresh is backing off
+/// Triggered by user input
+pub fn retry_if_backing_off(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let backoff = &mut state.refresh_backoff;
+    if !backoff.is_backing_off() || backoff.in_flight || !state.focus.has_background_refresh() {
+        return;
+    }
+
+    backoff.next_retry_at = 0.0;
+    refresh_pane(state.focus, state_rc);
 }
@@ -0,0 +1,89 @@
+use crate::api::JobStatus;
+use crate::state::{AppState, Pane, notifications};
+use crate::utils;
+use gloo_timers::callback::Interval;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Fetch the configured runbooks, and resume following a step still running
+pub fn refresh_runbooks(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match crate::api::fetch_runbooks().await {
+            Ok(runbooks) => {
+                let mut st = state_clone.borrow_mut();
+                st.runbooks.set_runbooks(runbooks);
+                if let Some(job) = st.runbooks.walk.as_ref().and_then(|w| w.job.as_ref())
+                    && job.status == JobStatus::Running
+                    && st.runbooks.poll.is_none()
+                {
+                    let id = job.id;
+                    follow_step(&mut st, &state_clone, id);
+                }
+            }
+            Err(e) => {
+                state_clone.borrow_mut().runbooks.loaded = true;
+                notifications::notify_error(
+                    &state_clone,
+                    format!(
+                        "Loading runbooks failed: {}",
+                        utils::error::format_error(&e)
+                    ),
+                );
+            }
+        }
+    });
+}
+
+/// Poll the job of an action step every second while the Runbooks pane is
+/// focused; the walk moves on to the next step once it succeeded
+pub fn follow_step(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, id: u64) {
+    let state_clone = Rc::clone(state_rc);
+    let token = state
+        .runbooks
+        .walk
+        .as_ref()
+        .and_then(|walk| walk.job_token.clone());
+    let interval = Interval::new(1_000, move || {
+        let state_clone = Rc::clone(&state_clone);
+        let token = token.clone();
+        spawn_local(async move {
+            let Ok(Some(job)) = crate::api::fetch_job(id, token).await else {
+                return;
+            };
+            let mut st = state_clone.borrow_mut();
+            if st.focus != Pane::Runbooks {
+                return;
+            }
+            // The walk was left, or the job belongs to an earlier attempt
+            let Some(walk) = st
+                .runbooks
+                .walk
+                .as_mut()
+                .filter(|w| w.job.as_ref().map(|j| j.id) == Some(id))
+            else {
+                return;
+            };
+            let status = job.status;
+            let message = format!("{}: {}", job.target, job.message);
+            walk.update_job(job);
+            if status == JobStatus::Running {
+                return;
+            }
+            let summary = walk.is_finished().then(|| walk.summary());
+            st.runbooks.poll = None;
+            drop(st);
+            if status == JobStatus::Succeeded {
+                notifications::notify_success(&state_clone, message);
+            } else {
+                notifications::notify_error(&state_clone, message);
+            }
+            if let Some(summary) = summary {
+                notifications::notify_success(&state_clone, summary);
+            }
+        });
+    });
+
+    // Keep the handle in state: dropping it cancels the interval
+    state.runbooks.poll = Some(interval);
+}
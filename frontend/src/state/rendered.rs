@@ -0,0 +1,9 @@
+use crate::api::RenderedResponse;
+
+/// Saved content of the open file with its `${VARS}` substituted, shown
+/// read-only next to the raw text
+#[derive(Default)]
+pub struct RenderedView {
+    /// None while the server renders the file
+    pub result: Option<Result<RenderedResponse, String>>,
+}
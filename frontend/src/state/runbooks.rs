@@ -0,0 +1,196 @@
+use crate::api::{JobInfo, JobStatus, RunbookInfo, RunbookStep};
+use gloo_timers::callback::Interval;
+
+/// Progress of one step of a runbook being walked through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepStatus {
+    Pending,
+    Running,
+    Done,
+    Failed,
+    Skipped,
+}
+
+impl StepStatus {
+    /// Marker in front of the step in the step list
+    pub fn marker(self) -> &'static str {
+        match self {
+            StepStatus::Pending => "[ ]",
+            StepStatus::Running => "[~]",
+            StepStatus::Done => "[x]",
+            StepStatus::Failed => "[!]",
+            StepStatus::Skipped => "[-]",
+        }
+    }
+}
+
+/// A runbook being carried out, one step at a time in order
+pub struct RunbookWalk {
+    pub runbook: RunbookInfo,
+    /// Step up next, the step count once all steps are through
+    pub current: usize,
+    pub statuses: Vec<StepStatus>,
+    /// Job of the last action step, updated while it is polled
+    pub job: Option<JobInfo>,
+    /// Role token `job` was started with, its output is only served with it
+    pub job_token: Option<String>,
+}
+
+impl RunbookWalk {
+    pub fn new(runbook: RunbookInfo) -> Self {
+        Self {
+            statuses: vec![StepStatus::Pending; runbook.steps.len()],
+            runbook,
+            current: 0,
+            job: None,
+            job_token: None,
+        }
+    }
+
+    pub fn current_step(&self) -> Option<&RunbookStep> {
+        self.runbook.steps.get(self.current)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current >= self.runbook.steps.len()
+    }
+
+    /// Whether anything was carried out yet, leaving then asks first
+    pub fn is_started(&self) -> bool {
+        self.statuses.iter().any(|s| *s != StepStatus::Pending)
+    }
+
+    /// Whether the current step's action is being started or still runs
+    pub fn is_running(&self) -> bool {
+        self.statuses.get(self.current) == Some(&StepStatus::Running)
+    }
+
+    /// Steps with this status so far
+    pub fn count(&self, status: StepStatus) -> usize {
+        self.statuses.iter().filter(|s| **s == status).count()
+    }
+
+    /// Set the status of the current step without moving on, e.g. a failed
+    /// action stays current to be retried or skipped
+    pub fn mark(&mut self, status: StepStatus) {
+        if let Some(slot) = self.statuses.get_mut(self.current) {
+            *slot = status;
+        }
+    }
+
+    /// Settle the current step and move on to the next
+    pub fn advance(&mut self, status: StepStatus) {
+        if self.current < self.statuses.len() {
+            self.statuses[self.current] = status;
+            self.current += 1;
+        }
+    }
+
+    /// Take in a polled job of the current step, moving on once it succeeded
+    pub fn update_job(&mut self, job: JobInfo) {
+        match job.status {
+            JobStatus::Running => {}
+            JobStatus::Succeeded => self.advance(StepStatus::Done),
+            JobStatus::Failed => self.mark(StepStatus::Failed),
+        }
+        self.job = Some(job);
+    }
+
+    /// Summary shown once the last step is through
+    pub fn summary(&self) -> String {
+        format!(
+            "Runbook {} completed: {} done, {} skipped",
+            self.runbook.name,
+            self.count(StepStatus::Done),
+            self.count(StepStatus::Skipped)
+        )
+    }
+}
+
+pub struct RunbooksState {
+    pub runbooks: Vec<RunbookInfo>,
+    pub selected_index: usize,
+    pub loaded: bool,
+    /// Runbook being carried out, shown in place of the list
+    pub walk: Option<RunbookWalk>,
+    /// Polls the job of the current step, dropped once it finishes or the
+    /// pane is left
+    pub poll: Option<Interval>,
+    /// Lines scrolled up from the end of the output, 0 follows new output
+    pub output_scroll: u16,
+}
+
+impl RunbooksState {
+    pub fn new() -> Self {
+        Self {
+            runbooks: Vec::new(),
+            selected_index: 0,
+            loaded: false,
+            walk: None,
+            poll: None,
+            output_scroll: 0,
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.runbooks.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.runbooks.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.runbooks.is_empty() {
+            self.selected_index = if self.selected_index == 0 {
+                self.runbooks.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+        }
+    }
+
+    pub fn selected(&self) -> Option<&RunbookInfo> {
+        self.runbooks.get(self.selected_index)
+    }
+
+    /// Replace the list; a walk in progress keeps the steps it started with
+    pub fn set_runbooks(&mut self, runbooks: Vec<RunbookInfo>) {
+        // Preserve selection by name
+        let selected_name = self.selected().map(|r| r.name.clone());
+
+        self.runbooks = runbooks;
+        self.loaded = true;
+        self.selected_index = selected_name
+            .and_then(|name| self.runbooks.iter().position(|r| r.name == name))
+            .unwrap_or(0);
+    }
+
+    /// Start walking through the selected runbook from its first step
+    pub fn start_walk(&mut self) -> Option<&RunbookWalk> {
+        let runbook = self.selected()?.clone();
+        self.poll = None;
+        self.output_scroll = 0;
+        self.walk = Some(RunbookWalk::new(runbook));
+        self.walk.as_ref()
+    }
+
+    pub fn end_walk(&mut self) {
+        self.walk = None;
+        self.poll = None;
+    }
+
+    /// Scroll back through the output (towards its start)
+    pub fn scroll_up(&mut self) {
+        let lines = self
+            .walk
+            .as_ref()
+            .and_then(|walk| walk.job.as_ref())
+            .map_or(0, |job| job.output.lines().count());
+        if (self.output_scroll as usize) + 1 < lines {
+            self.output_scroll += 1;
+        }
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.output_scroll = self.output_scroll.saturating_sub(1);
+    }
+}
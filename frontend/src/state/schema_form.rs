@@ -0,0 +1,169 @@
+use super::kv_table::KvTable;
+use crate::api::{FieldKind, FileSchema, SchemaField};
+
+/// Form view of a file with a schema, backed by the key=value lines of the file
+pub struct SchemaForm {
+    pub fields: Vec<SchemaField>,
+    pub table: KvTable,
+    pub selected_index: usize,
+    /// Text of the field being edited, None while navigating
+    pub edit_buffer: Option<String>,
+    /// New string values are written quoted (TOML files)
+    quote_strings: bool,
+}
+
+impl SchemaForm {
+    /// None if the content is not a key=value file
+    pub fn new(schema: &FileSchema, filename: &str, content: &str) -> Option<Self> {
+        Some(Self {
+            fields: schema.fields.clone(),
+            table: KvTable::parse_lines(content)?,
+            selected_index: 0,
+            edit_buffer: None,
+            quote_strings: filename.ends_with(".toml"),
+        })
+    }
+
+    pub fn selected_field(&self) -> Option<&SchemaField> {
+        self.fields.get(self.selected_index)
+    }
+
+    /// Current value of a field without TOML quotes, None if the key is missing
+    pub fn value(&self, field: &SchemaField) -> Option<String> {
+        self.table.get(&field.key).map(|v| unquote(v).to_string())
+    }
+
+    /// Validation error of a field's current value
+    pub fn error(&self, field: &SchemaField) -> Option<String> {
+        let value = self.value(field).unwrap_or_default();
+        validate(field, &value)
+    }
+
+    /// Number of fields with a validation error
+    pub fn error_count(&self) -> usize {
+        self.fields
+            .iter()
+            .filter(|field| self.error(field).is_some())
+            .count()
+    }
+
+    /// 1-based line (None for a missing key) and message of each field with
+    /// a validation error
+    pub fn problems(&self) -> Vec<(Option<usize>, String)> {
+        self.fields
+            .iter()
+            .filter_map(|field| {
+                let error = self.error(field)?;
+                let line = self.table.find(&field.key).map(|index| index + 1);
+                Some((line, format!("{}: {}", field.key, error)))
+            })
+            .collect()
+    }
+
+    pub fn next(&mut self) {
+        if !self.fields.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.fields.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.fields.is_empty() {
+            self.selected_index = if self.selected_index == 0 {
+                self.fields.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+        }
+    }
+
+    pub fn start_edit(&mut self) {
+        if let Some(field) = self.selected_field() {
+            self.edit_buffer = Some(self.value(field).unwrap_or_default());
+        }
+    }
+
+    pub fn commit_edit(&mut self) {
+        if let Some(text) = self.edit_buffer.take() {
+            self.set_selected(text);
+        }
+    }
+
+    pub fn cancel_edit(&mut self) {
+        self.edit_buffer = None;
+    }
+
+    /// Flip a boolean field
+    pub fn toggle(&mut self) {
+        if let Some(field) = self.selected_field() {
+            let on = self.value(field).is_some_and(|v| v == "true");
+            self.set_selected((!on).to_string());
+        }
+    }
+
+    /// Step an enum field through its options
+    pub fn cycle(&mut self, forward: bool) {
+        let Some(field) = self.selected_field() else {
+            return;
+        };
+        if field.options.is_empty() {
+            return;
+        }
+        let count = field.options.len();
+        let current = self
+            .value(field)
+            .and_then(|v| field.options.iter().position(|o| *o == v));
+        let next = match (current, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(i), true) => (i + 1) % count,
+            (Some(i), false) => (i + count - 1) % count,
+        };
+        let option = field.options[next].clone();
+        self.set_selected(option);
+    }
+
+    /// Write a value into the selected field's line, keeping existing quoting
+    fn set_selected(&mut self, text: String) {
+        let Some(field) = self.selected_field() else {
+            return;
+        };
+        let key = field.key.clone();
+        let quoted = match self.table.get(&key) {
+            Some(existing) => existing.starts_with('"'),
+            None => self.quote_strings && matches!(field.kind, FieldKind::String | FieldKind::Enum),
+        };
+        let value = if quoted {
+            format!("\"{}\"", text.replace('"', "\\\""))
+        } else {
+            text
+        };
+        self.table.set(&key, value);
+    }
+}
+
+/// Check a value against its field definition
+pub fn validate(field: &SchemaField, value: &str) -> Option<String> {
+    if value.is_empty() {
+        return field.required.then(|| "required".to_string());
+    }
+    let invalid = match field.kind {
+        FieldKind::String => false,
+        FieldKind::Integer => value.parse::<i64>().is_err(),
+        FieldKind::Float => value.parse::<f64>().is_err(),
+        FieldKind::Boolean => !matches!(value, "true" | "false"),
+        FieldKind::Enum => !field.options.iter().any(|o| o == value),
+    };
+    invalid.then(|| match field.kind {
+        FieldKind::Integer => "not an integer".to_string(),
+        FieldKind::Float => "not a number".to_string(),
+        FieldKind::Boolean => "true or false".to_string(),
+        _ => format!("one of {}", field.options.join(", ")),
+    })
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+}
@@ -1,22 +1,71 @@
+use super::{AppState, Pane};
+use crate::utils::frame_clock::FrameClock;
+use gloo_timers::callback::Timeout;
 use std::cell::RefCell;
-
+use std::rc::Rc;
 use tachyonfx::{Effect, EffectTimer, Interpolation, fx};
 
+/// Keys pressed this soon after the start do not skip, they were most
+/// likely meant for the page before the reload
+const SKIP_GUARD_MS: f64 = 500.0;
+
 pub struct SplashState {
     pub effect: RefCell<Effect>,
     pub start_time: f64,
+    /// Time the effect advances by on each frame
+    pub clock: FrameClock,
+    /// Bumped whenever the end is rescheduled, older timers find it changed
+    generation: u32,
 }
 
 impl SplashState {
     pub fn new() -> Self {
-        // Rainbow effect using HSL shift
-        let timer = EffectTimer::from_ms(3000, Interpolation::Linear);
+        // The banner assembles itself, then cycles through the rainbow
+        let coalesce = fx::coalesce(EffectTimer::from_ms(800, Interpolation::QuadOut));
         // Shift hue by 360 degrees (full circle)
-        let effect = fx::ping_pong(fx::hsl_shift_fg([360.0, 0.0, 0.0], timer));
+        let rainbow = fx::ping_pong(fx::hsl_shift_fg(
+            [360.0, 0.0, 0.0],
+            EffectTimer::from_ms(3000, Interpolation::Linear),
+        ));
+        let effect = fx::sequence(&[coalesce, rainbow]);
 
         Self {
             effect: RefCell::new(effect),
             start_time: js_sys::Date::now(),
+            clock: FrameClock::new(),
+            generation: 0,
         }
     }
+
+    /// Whether a key press ends the splash screen
+    pub fn can_skip(&self) -> bool {
+        js_sys::Date::now() - self.start_time > SKIP_GUARD_MS
+    }
+}
+
+/// Hand over to the menu (or the restored session) `duration_ms` after the
+/// splash screen started, replacing an end scheduled before
+pub fn schedule_end(state_rc: &Rc<RefCell<AppState>>, duration_ms: u64) {
+    let (generation, remaining) = {
+        let mut st = state_rc.borrow_mut();
+        st.splash.generation += 1;
+        let elapsed = js_sys::Date::now() - st.splash.start_time;
+        (
+            st.splash.generation,
+            (duration_ms as f64 - elapsed).max(0.0) as u32,
+        )
+    };
+
+    let state_clone = Rc::clone(state_rc);
+    // Never cancelled: an outdated timer finds the generation changed
+    Timeout::new(remaining, move || {
+        let Ok(mut st) = state_clone.try_borrow_mut() else {
+            return;
+        };
+        if st.splash.generation == generation && st.focus == Pane::Splash {
+            st.finish_splash(&state_clone);
+            st.save_to_storage(&state_clone);
+        }
+    })
+    .forget();
 }
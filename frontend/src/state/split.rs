@@ -0,0 +1,100 @@
+use super::Pane;
+use crate::storage;
+use serde::{Deserialize, Serialize};
+
+/// Percent the resize keys move a split by
+const STEP: u16 = 5;
+
+/// Two-pane view whose split can be resized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Split {
+    /// File list | editor
+    FileList,
+    /// Container list | container details
+    ContainerList,
+}
+
+impl Split {
+    /// The split a pane is shown in, None for single-pane views
+    pub fn of(pane: Pane) -> Option<Self> {
+        match pane {
+            Pane::FileList | Pane::Editor => Some(Split::FileList),
+            Pane::ContainerList => Some(Split::ContainerList),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Split::FileList => "File list",
+            Split::ContainerList => "Container list",
+        }
+    }
+
+    /// Narrowest and widest the left pane may get, in percent
+    fn bounds(self) -> (u16, u16) {
+        match self {
+            Split::FileList => (10, 60),
+            Split::ContainerList => (20, 75),
+        }
+    }
+}
+
+/// Width of the left pane of each split in percent, kept in localStorage
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SplitRatios {
+    pub file_list: u16,
+    pub container_list: u16,
+}
+
+impl Default for SplitRatios {
+    fn default() -> Self {
+        Self {
+            file_list: 25,
+            container_list: 40,
+        }
+    }
+}
+
+impl SplitRatios {
+    pub fn load() -> Self {
+        let mut ratios: Self =
+            storage::generic::load(storage::keys::SPLIT_RATIOS).unwrap_or_default();
+        // Values edited by hand may be out of range
+        for split in [Split::FileList, Split::ContainerList] {
+            ratios.set(split, ratios.get(split));
+        }
+        ratios
+    }
+
+    pub fn save(&self) {
+        storage::generic::save(storage::keys::SPLIT_RATIOS, self);
+        storage::profile::changed();
+    }
+
+    pub fn get(&self, split: Split) -> u16 {
+        match split {
+            Split::FileList => self.file_list,
+            Split::ContainerList => self.container_list,
+        }
+    }
+
+    /// Set the left pane width, clamped to the split's bounds
+    pub fn set(&mut self, split: Split, percent: u16) {
+        let (min, max) = split.bounds();
+        let percent = percent.clamp(min, max);
+        match split {
+            Split::FileList => self.file_list = percent,
+            Split::ContainerList => self.container_list = percent,
+        }
+    }
+
+    pub fn grow(&mut self, split: Split) {
+        self.set(split, self.get(split) + STEP);
+    }
+
+    pub fn shrink(&mut self, split: Split) {
+        self.set(split, self.get(split).saturating_sub(STEP));
+    }
+}
@@ -0,0 +1,71 @@
+use crate::theme::ThemeConfig;
+
+/// Labels of the RGB channels, indexed by `ThemeEditorState::channel`
+pub const CHANNELS: [&str; 3] = ["R", "G", "B"];
+
+pub struct ThemeEditorState {
+    /// Name the edited theme is saved under
+    pub name: String,
+    /// Base color names of the edited theme, in display order
+    pub colors: Vec<String>,
+    pub selected_index: usize,
+    /// RGB channel adjusted by the increase/decrease keys (0..3)
+    pub channel: usize,
+    /// Theme as it was before editing, restored when leaving without saving
+    pub original: Option<ThemeConfig>,
+    pub dirty: bool,
+}
+
+impl ThemeEditorState {
+    pub fn new() -> Self {
+        Self {
+            name: String::new(),
+            colors: Vec::new(),
+            selected_index: 0,
+            channel: 0,
+            original: None,
+            dirty: false,
+        }
+    }
+
+    /// Start editing `theme`; edits are applied to the live theme for preview
+    pub fn open(&mut self, name: String, theme: &ThemeConfig) {
+        self.colors = theme.base.names();
+        if self.name != name {
+            self.selected_index = 0;
+            self.channel = 0;
+        }
+        self.selected_index = self.selected_index.min(self.colors.len().saturating_sub(1));
+        self.name = name;
+        self.original = Some(theme.clone());
+        self.dirty = false;
+    }
+
+    pub fn next(&mut self) {
+        if !self.colors.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.colors.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.colors.is_empty() {
+            self.selected_index = if self.selected_index == 0 {
+                self.colors.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+        }
+    }
+
+    pub fn next_channel(&mut self) {
+        self.channel = (self.channel + 1) % CHANNELS.len();
+    }
+
+    pub fn previous_channel(&mut self) {
+        self.channel = (self.channel + CHANNELS.len() - 1) % CHANNELS.len();
+    }
+
+    pub fn selected(&self) -> Option<&str> {
+        self.colors.get(self.selected_index).map(String::as_str)
+    }
+}
@@ -0,0 +1,94 @@
+use super::Pane;
+use crate::utils::frame_clock::FrameClock;
+use ratzilla::ratatui::style::Color;
+use std::cell::RefCell;
+use tachyonfx::{Effect, EffectTimer, Interpolation, Motion, fx};
+use web_sys::window;
+
+const SLIDE_MS: u32 = 250;
+const FADE_MS: u32 = 200;
+
+/// Effect played over the main content after a pane switch, drawn by
+/// `ui::render` until it is done
+pub struct TransitionState {
+    /// Off when the user turned animations off or asks the browser for
+    /// reduced motion
+    pub enabled: bool,
+    pub effect: RefCell<Option<Effect>>,
+    pub clock: FrameClock,
+}
+
+impl TransitionState {
+    pub fn new() -> Self {
+        let enabled =
+            crate::storage::load_animations().unwrap_or_else(|| !prefers_reduced_motion());
+        Self {
+            enabled,
+            effect: RefCell::new(None),
+            clock: FrameClock::new(),
+        }
+    }
+
+    /// Start the effect for a switch from `from` to `to`: the menu and the
+    /// lists slide in the direction of travel, the lists fade between each
+    /// other. Other panes switch without one.
+    pub fn start(&mut self, from: Pane, to: Pane, background: Color) {
+        if !self.enabled {
+            return;
+        }
+        let (Some(from_depth), Some(to_depth)) = (depth(from), depth(to)) else {
+            return;
+        };
+
+        let effect = if from_depth == to_depth {
+            fx::fade_from_fg(
+                background,
+                EffectTimer::from_ms(FADE_MS, Interpolation::QuadOut),
+            )
+        } else {
+            let motion = if to_depth > from_depth {
+                Motion::RightToLeft
+            } else {
+                Motion::LeftToRight
+            };
+            fx::slide_in(
+                motion,
+                10,
+                0,
+                background,
+                EffectTimer::from_ms(SLIDE_MS, Interpolation::QuadOut),
+            )
+        };
+        self.clock.reset();
+        *self.effect.get_mut() = Some(effect);
+    }
+
+    /// Turn animations on or off and remember the choice
+    pub fn toggle(&mut self) -> bool {
+        self.enabled = !self.enabled;
+        if !self.enabled {
+            *self.effect.get_mut() = None;
+        }
+        crate::storage::save_animations(self.enabled);
+        self.enabled
+    }
+}
+
+/// Level of `pane` in the navigation, None for panes without a transition
+fn depth(pane: Pane) -> Option<u8> {
+    match pane {
+        Pane::Menu => Some(0),
+        Pane::FileList | Pane::ContainerList => Some(1),
+        _ => None,
+    }
+}
+
+fn prefers_reduced_motion() -> bool {
+    window()
+        .and_then(|w| {
+            w.match_media("(prefers-reduced-motion: reduce)")
+                .ok()
+                .flatten()
+        })
+        .is_some_and(|query| query.matches())
+}
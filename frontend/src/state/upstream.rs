@@ -0,0 +1,31 @@
+use crate::api::UpstreamCheckResponse;
+
+/// Upstream check of one file, reviewed in the upstream overlay
+pub struct UpstreamReview {
+    pub filename: String,
+    /// None while the server fetches and verifies upstream
+    pub result: Option<Result<UpstreamCheckResponse, String>>,
+}
+
+impl UpstreamReview {
+    pub fn new(filename: String) -> Self {
+        Self {
+            filename,
+            result: None,
+        }
+    }
+
+    /// The checked version, if it differs and nothing failed verification
+    pub fn applicable(&self) -> Option<&UpstreamCheckResponse> {
+        match &self.result {
+            Some(Ok(check))
+                if check.changed
+                    && check.checksum_ok != Some(false)
+                    && check.signature_ok != Some(false) =>
+            {
+                Some(check)
+            }
+            _ => None,
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use web_sys::window;
 
 fn get_local_storage() -> Option<web_sys::Storage> {
@@ -5,7 +8,7 @@ fn get_local_storage() -> Option<web_sys::Storage> {
 }
 
 /// Save any serializable data to localStorage
-pub fn save<T: serde::Serialize>(key: &str, value: &T) {
+pub fn save<T: Serialize>(key: &str, value: &T) {
     if let Some(storage) = get_local_storage()
         && let Ok(json) = serde_json::to_string(value)
     {
@@ -14,7 +17,7 @@ pub fn save<T: serde::Serialize>(key: &str, value: &T) {
 }
 
 /// Load any deserializable data from localStorage
-pub fn load<T: serde::de::DeserializeOwned>(key: &str) -> Option<T> {
+pub fn load<T: DeserializeOwned>(key: &str) -> Option<T> {
     let storage = get_local_storage()?;
     let json = storage.get_item(key).ok()??;
     serde_json::from_str(&json).ok()
@@ -26,3 +29,65 @@ pub fn clear(key: &str) {
         let _ = storage.remove_item(key);
     }
 }
+
+/// Stored text as is, for values written before everything was JSON
+pub(super) fn load_raw(key: &str) -> Option<String> {
+    get_local_storage()?.get_item(key).ok()?
+}
+
+/// Store `text` as is
+pub(super) fn save_raw(key: &str, text: &str) {
+    if let Some(storage) = get_local_storage() {
+        let _ = storage.set_item(key, text);
+    }
+}
+
+/// All stored keys starting with `prefix`
+pub(super) fn keys_with_prefix(prefix: &str) -> Vec<String> {
+    let Some(storage) = get_local_storage() else {
+        return Vec::new();
+    };
+    (0..storage.length().unwrap_or(0))
+        .filter_map(|i| storage.key(i).ok().flatten())
+        .filter(|key| key.starts_with(prefix))
+        .collect()
+}
+
+/// Data whose shape may change between builds, with the version it was
+/// written in
+#[derive(Serialize, Deserialize)]
+struct Versioned<T> {
+    version: u32,
+    data: T,
+}
+
+/// Save `value` as `version` of its format
+pub fn save_versioned<T: Serialize>(key: &str, version: u32, value: &T) {
+    save(
+        key,
+        &Versioned {
+            version,
+            data: value,
+        },
+    );
+}
+
+/// Load data saved under `key`, bringing older versions up to `version`:
+/// `migrate(v, data)` turns the data of version `v` into that of `v + 1`.
+/// None when nothing is stored, a migration gives up, or the data comes
+/// from a newer build.
+pub fn load_versioned<T: DeserializeOwned>(
+    key: &str,
+    version: u32,
+    migrate: impl Fn(u32, Value) -> Option<Value>,
+) -> Option<T> {
+    let stored: Versioned<Value> = load(key)?;
+    if stored.version > version {
+        return None;
+    }
+    let mut data = stored.data;
+    for from in stored.version..version {
+        data = migrate(from, data)?;
+    }
+    serde_json::from_value(data).ok()
+}
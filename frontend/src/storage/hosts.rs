@@ -0,0 +1,38 @@
+//! The sysrat server the UI talks to: the one that served the page, or a
+//! host picked from its `[[hosts]]`. Picking reloads the page, so the choice
+//! is read once. Caches and the session of each host are kept apart by
+//! suffixing their keys with the host name.
+
+use super::{generic, keys};
+use crate::api::HostInfo;
+use std::cell::OnceCell;
+
+thread_local! {
+    static ACTIVE: OnceCell<Option<HostInfo>> = const { OnceCell::new() };
+}
+
+/// The picked host, None for the server that served the page
+pub fn active() -> Option<HostInfo> {
+    ACTIVE.with(|active| {
+        active
+            .get_or_init(|| generic::load(keys::ACTIVE_HOST))
+            .clone()
+    })
+}
+
+/// Talk to `host` (None: the serving server) after the next page load
+pub fn pick(host: Option<&HostInfo>) {
+    match host {
+        Some(host) => generic::save(keys::ACTIVE_HOST, host),
+        None => generic::clear(keys::ACTIVE_HOST),
+    }
+}
+
+/// `key` of the picked host, `key` itself for the serving server so data
+/// saved before hosts existed stays in place
+pub(super) fn scoped(key: &str) -> String {
+    match active() {
+        Some(host) => format!("{}@{}", key, host.name),
+        None => key.to_string(),
+    }
+}
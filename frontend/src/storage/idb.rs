@@ -0,0 +1,114 @@
+//! IndexedDB key-value store for data too large for localStorage: editor
+//! buffers and cached lists. Writes happen off the keypress path, values are
+//! stored as JSON strings like in `generic`, under keys of the picked host.
+
+use js_sys::Promise;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::cell::RefCell;
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use wasm_bindgen_futures::{JsFuture, spawn_local};
+use web_sys::{IdbDatabase, IdbObjectStore, IdbRequest, IdbTransactionMode, window};
+
+const DB_NAME: &str = "sysrat";
+const DB_VERSION: u32 = 1;
+const STORE: &str = "cache";
+
+thread_local! {
+    /// Opened on first use, kept for the session
+    static DB: RefCell<Option<IdbDatabase>> = const { RefCell::new(None) };
+}
+
+/// Store `value` under `key` in the background; the JSON is built right away
+pub fn put<T: Serialize>(key: &str, value: &T) {
+    let Ok(json) = serde_json::to_string(value) else {
+        return;
+    };
+    let key = super::hosts::scoped(key);
+    spawn_local(async move {
+        if let Some(store) = store(IdbTransactionMode::Readwrite).await
+            && let Ok(request) = store.put_with_key(&JsValue::from_str(&json), &key.into())
+        {
+            let _ = wait(&request).await;
+        }
+    });
+}
+
+/// Value stored under `key`, None when missing, unreadable or IndexedDB is
+/// unavailable (e.g. private windows of some browsers)
+pub async fn get<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let store = store(IdbTransactionMode::Readonly).await?;
+    let request = store
+        .get(&JsValue::from_str(&super::hosts::scoped(key)))
+        .ok()?;
+    let json = wait(&request).await.ok()?.as_string()?;
+    serde_json::from_str(&json).ok()
+}
+
+/// Remove `key` in the background
+pub fn delete(key: &str) {
+    let key = super::hosts::scoped(key);
+    spawn_local(async move {
+        if let Some(store) = store(IdbTransactionMode::Readwrite).await
+            && let Ok(request) = store.delete(&key.into())
+        {
+            let _ = wait(&request).await;
+        }
+    });
+}
+
+/// The object store in a transaction of its own
+async fn store(mode: IdbTransactionMode) -> Option<IdbObjectStore> {
+    database()
+        .await?
+        .transaction_with_str_and_mode(STORE, mode)
+        .ok()?
+        .object_store(STORE)
+        .ok()
+}
+
+async fn database() -> Option<IdbDatabase> {
+    if let Some(db) = DB.with(|db| db.borrow().clone()) {
+        return Some(db);
+    }
+
+    let request = window()?
+        .indexed_db()
+        .ok()??
+        .open_with_u32(DB_NAME, DB_VERSION)
+        .ok()?;
+    // First open of this version: create the store
+    let upgrade = Closure::<dyn FnMut()>::new({
+        let request = request.clone();
+        move || {
+            if let Ok(db) = request.result() {
+                let _ = db
+                    .unchecked_into::<IdbDatabase>()
+                    .create_object_store(STORE);
+            }
+        }
+    });
+    request.set_onupgradeneeded(Some(upgrade.as_ref().unchecked_ref()));
+    let result = wait(&request).await;
+    request.set_onupgradeneeded(None);
+
+    let db: IdbDatabase = result.ok()?.unchecked_into();
+    DB.with(|cell| *cell.borrow_mut() = Some(db.clone()));
+    Some(db)
+}
+
+/// Result of `request` once it succeeded or failed
+async fn wait(request: &IdbRequest) -> Result<JsValue, JsValue> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let done = request.clone();
+        let on_success = Closure::once_into_js(move || {
+            let _ = resolve.call1(&JsValue::NULL, &done.result().unwrap_or(JsValue::UNDEFINED));
+        });
+        let on_error = Closure::once_into_js(move || {
+            let _ = reject.call0(&JsValue::NULL);
+        });
+        request.set_onsuccess(Some(on_success.unchecked_ref()));
+        request.set_onerror(Some(on_error.unchecked_ref()));
+    });
+    JsFuture::from(promise).await
+}
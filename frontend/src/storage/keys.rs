@@ -0,0 +1,32 @@
+//! localStorage keys, all in the `sysrat.` namespace. Renaming one means a
+//! new step in `schema::RENAMES` so stored data follows the new name.
+
+pub const STATE: &str = "sysrat.state";
+pub const PREFERENCES: &str = "sysrat.preferences";
+/// Themes saved from the theme editor, checked before server and embedded ones
+pub const LOCAL_THEMES: &str = "sysrat.local-themes";
+/// Bindings changed in the keybind editor, by `section.action`
+pub const KEYBIND_OVERRIDES: &str = "sysrat.keybind-overrides";
+pub const SPLIT_RATIOS: &str = "sysrat.split-ratios";
+/// Order picked with the file list cycle key
+pub const FILE_ORDER: &str = "sysrat.file-order";
+pub const FILE_LIST_SELECTION: &str = "sysrat.file-list-selection";
+pub const CONTAINER_LIST_SELECTION: &str = "sysrat.container-list-selection";
+pub const PINNED_FILES: &str = "sysrat.pinned-files";
+pub const HIDDEN_FILES: &str = "sysrat.hidden-files";
+pub const PINNED_CONTAINERS: &str = "sysrat.pinned-containers";
+pub const HIDDEN_CONTAINERS: &str = "sysrat.hidden-containers";
+/// Failing health checks listed right after the pinned containers
+pub const UNHEALTHY_FIRST: &str = "sysrat.unhealthy-first";
+/// Server picked in the host switcher, missing for the one serving the page
+pub const ACTIVE_HOST: &str = "sysrat.active-host";
+/// `updated_at` of the server profile this browser last pulled or pushed
+pub const PROFILE_SYNCED: &str = "sysrat.profile-synced";
+/// Spaces and tabs drawn as visible marks in the editor
+pub const SHOW_WHITESPACE: &str = "sysrat.show-whitespace";
+/// Long lines wrapped in the editor, set with `:set wrap`
+pub const WRAP_LINES: &str = "sysrat.wrap-lines";
+/// Line numbers in the editor gutter, set with `:set number`
+pub const LINE_NUMBERS: &str = "sysrat.line-numbers";
+/// Editor macros recorded with `q{register}`, by register
+pub const MACROS: &str = "sysrat.macros";
@@ -1,6 +1,25 @@
+//! Everything kept in browser storage, as JSON through serde. Small values
+//! live in localStorage: session state and preferences are versioned and
+//! migrated on load, selections and marks use the plain `generic` helpers.
+//! Editor buffers and cached lists can grow past its quota and go to
+//! IndexedDB (`idb`) instead. Key names are in `keys`, `schema` moves data
+//! saved under older names, `hosts` keeps the data of each server apart,
+//! `profile` syncs the preferences with the user's profile on the server.
+
 pub mod generic;
-mod local;
+pub mod hosts;
+pub mod idb;
+pub mod keys;
+mod preferences;
+pub mod profile;
+mod schema;
+mod session;
 mod types;
 
-pub use local::{load_state, load_theme_preference, save_state, save_theme_preference};
-pub use types::SavedState;
+pub use preferences::{
+    load_animations, load_splash_ms, load_theme_auto, load_theme_preference, save_animations,
+    save_splash_ms, save_theme_auto, save_theme_preference,
+};
+pub use schema::migrate as migrate_keys;
+pub use session::{load_buffer, load_state, save_state};
+pub use types::{FilePosition, SavedState};
@@ -0,0 +1,90 @@
+use super::types::Preferences;
+use super::{generic, keys, profile};
+use serde_json::Value;
+
+const PREFERENCES_VERSION: u32 = 1;
+
+/// Plain strings the theme choices were kept in before `Preferences`
+const LEGACY_THEME_KEY: &str = "sysrat-theme";
+const LEGACY_THEME_AUTO_KEY: &str = "sysrat-theme-auto";
+
+pub(super) fn load() -> Preferences {
+    generic::load_versioned(keys::PREFERENCES, PREFERENCES_VERSION, migrate)
+        .or_else(load_legacy)
+        .unwrap_or_default()
+}
+
+pub(super) fn save(preferences: &Preferences) {
+    generic::save_versioned(keys::PREFERENCES, PREFERENCES_VERSION, preferences);
+}
+
+/// Bring the preferences of `version` to the next version, see
+/// `session::migrate`
+fn migrate(_version: u32, _preferences: Value) -> Option<Value> {
+    None
+}
+
+/// Move the plain-string theme keys into `Preferences`, None if neither is set
+fn load_legacy() -> Option<Preferences> {
+    let theme = generic::load_raw(LEGACY_THEME_KEY);
+    let theme_auto = generic::load_raw(LEGACY_THEME_AUTO_KEY).map(|value| value == "true");
+    if theme.is_none() && theme_auto.is_none() {
+        return None;
+    }
+
+    let preferences = Preferences {
+        theme,
+        theme_auto,
+        splash_ms: None,
+        animations: None,
+    };
+    save(&preferences);
+    generic::clear(LEGACY_THEME_KEY);
+    generic::clear(LEGACY_THEME_AUTO_KEY);
+    Some(preferences)
+}
+
+pub fn save_theme_preference(theme_name: &str) {
+    let mut preferences = load();
+    preferences.theme = Some(theme_name.to_string());
+    save(&preferences);
+    profile::changed();
+}
+
+pub fn load_theme_preference() -> Option<String> {
+    load().theme
+}
+
+/// Whether the theme follows the browser color scheme, next to the preference
+pub fn save_theme_auto(enabled: bool) {
+    let mut preferences = load();
+    preferences.theme_auto = Some(enabled);
+    save(&preferences);
+    profile::changed();
+}
+
+pub fn load_theme_auto() -> Option<bool> {
+    load().theme_auto
+}
+
+pub fn save_splash_ms(splash_ms: u64) {
+    let mut preferences = load();
+    if preferences.splash_ms != Some(splash_ms) {
+        preferences.splash_ms = Some(splash_ms);
+        save(&preferences);
+    }
+}
+
+pub fn load_splash_ms() -> Option<u64> {
+    load().splash_ms
+}
+
+pub fn save_animations(enabled: bool) {
+    let mut preferences = load();
+    preferences.animations = Some(enabled);
+    save(&preferences);
+}
+
+pub fn load_animations() -> Option<bool> {
+    load().animations
+}
@@ -0,0 +1,145 @@
+//! Preferences synced with the user's profile on the server that served the
+//! page (`[profiles]` in its sysrat.toml). localStorage stays the copy the
+//! UI reads and works offline: a profile saved from another browser is
+//! written into it on start, local changes are pushed once they settle.
+//! Nothing is pushed while the server keeps no profiles.
+
+use super::{SavedState, generic, hosts, keys, preferences, session};
+use crate::api::{self, ProfileBuffer, UserProfile};
+use crate::utils::debounce::Debounce;
+use std::cell::{Cell, RefCell};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+
+/// Quiet time before changes are pushed, typing in the editor saves the
+/// session every few keys
+const PUSH_DELAY_MS: u32 = 5_000;
+
+thread_local! {
+    /// The server answered with a profile
+    static ENABLED: Cell<bool> = const { Cell::new(false) };
+    /// Profile as last pulled or pushed, keeps the buffers of other hosts
+    static REMOTE: RefCell<UserProfile> = RefCell::new(UserProfile::default());
+    static PUSH: RefCell<Debounce> = RefCell::new(Debounce::new(PUSH_DELAY_MS));
+}
+
+/// Fetch the profile and write it into localStorage if another browser
+/// saved it since this one synced; true when it did, the UI then has to
+/// reload to read it. Local changes the profile misses are pushed.
+pub async fn pull() -> bool {
+    let profile = match api::fetch_profile().await {
+        Ok(Some(profile)) => profile,
+        Ok(None) => return false,
+        Err(e) => {
+            web_sys::console::warn_1(&e);
+            return false;
+        }
+    };
+    ENABLED.set(true);
+    REMOTE.replace(profile.clone());
+
+    let synced: u64 = generic::load(keys::PROFILE_SYNCED).unwrap_or_default();
+    if profile.updated_at > synced {
+        apply(&profile);
+        generic::save(keys::PROFILE_SYNCED, &profile.updated_at);
+        return true;
+    }
+
+    // Changed while offline, or before a push got through
+    if collect().await != profile {
+        spawn_local(push());
+    }
+    false
+}
+
+/// Push the preferences a moment after the last change
+pub fn changed() {
+    if !ENABLED.get() {
+        return;
+    }
+    PUSH.with(|timer| timer.borrow_mut().schedule(|| spawn_local(push())));
+}
+
+async fn push() {
+    let profile = collect().await;
+    // Session saves for cursor moves change nothing kept in the profile
+    if REMOTE.with(|remote| *remote.borrow() == profile) {
+        return;
+    }
+    match api::save_profile(&profile).await {
+        Ok(saved) => {
+            generic::save(keys::PROFILE_SYNCED, &saved.updated_at);
+            REMOTE.replace(saved);
+        }
+        Err(e) => web_sys::console::warn_1(&JsValue::from_str(&format!(
+            "Profile not saved, kept in this browser: {}",
+            crate::utils::error::format_error(&e)
+        ))),
+    }
+}
+
+/// Host name the buffers are kept under, empty for the serving server
+fn host_name() -> String {
+    hosts::active().map(|host| host.name).unwrap_or_default()
+}
+
+/// The profile as this browser has it
+async fn collect() -> UserProfile {
+    let mut profile = REMOTE.with(|remote| remote.borrow().clone());
+    let stored = preferences::load();
+    profile.theme = stored.theme;
+    profile.theme_auto = stored.theme_auto;
+    profile.keybind_overrides = generic::load(keys::KEYBIND_OVERRIDES).unwrap_or_default();
+    profile.split_ratios = generic::load(keys::SPLIT_RATIOS).unwrap_or_default();
+
+    let host = host_name();
+    let Some(state) = session::load_state() else {
+        profile.buffers.retain(|buffer| buffer.host != host);
+        return profile;
+    };
+    let content = match &state.filename {
+        Some(filename) => session::load_buffer(filename).await,
+        None => None,
+    };
+    let buffer = ProfileBuffer {
+        host,
+        pane: state.pane,
+        filename: state.filename,
+        content,
+    };
+    // In place, so an unchanged profile compares equal
+    match profile
+        .buffers
+        .iter_mut()
+        .find(|existing| existing.host == buffer.host)
+    {
+        Some(existing) => *existing = buffer,
+        None => profile.buffers.push(buffer),
+    }
+    profile
+}
+
+/// Write the profile into localStorage. The open file is only taken over
+/// when none is open here, unsaved edits of this browser win.
+fn apply(profile: &UserProfile) {
+    let mut stored = preferences::load();
+    stored.theme = profile.theme.clone();
+    stored.theme_auto = profile.theme_auto;
+    preferences::save(&stored);
+    generic::save(keys::KEYBIND_OVERRIDES, &profile.keybind_overrides);
+    if !profile.split_ratios.is_empty() {
+        generic::save(keys::SPLIT_RATIOS, &profile.split_ratios);
+    }
+
+    let host = host_name();
+    let local = session::load_state();
+    let open_here = local.as_ref().is_some_and(|state| state.filename.is_some());
+    if !open_here && let Some(buffer) = profile.buffers.iter().find(|buffer| buffer.host == host) {
+        session::write_state(&SavedState {
+            pane: buffer.pane.clone(),
+            filename: buffer.filename.clone(),
+            content: buffer.content.clone(),
+            positions: local.map(|state| state.positions).unwrap_or_default(),
+        });
+    }
+}
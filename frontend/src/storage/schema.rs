@@ -0,0 +1,105 @@
+//! Names of the localStorage keys over time. Runs once per schema version at
+//! startup, before anything is loaded, and moves data saved under old names
+//! to the current ones.
+
+use super::{generic, keys};
+use serde_json::Value;
+
+/// Schema version stored data was last migrated to
+const SCHEMA_KEY: &str = "sysrat.schema";
+
+/// Prefix of keys written by builds from before the project was renamed
+const LEGACY_PREFIX: &str = "config-manager-";
+
+/// Keys renamed by each version, `RENAMES[v]` brings version `v` to `v + 1`.
+/// Add a step (never edit an old one) to rename keys again.
+const RENAMES: &[&[(&str, &str)]] = &[
+    // 1: everything in the `sysrat.` namespace
+    &[
+        ("sysrat-state", keys::STATE),
+        ("sysrat-preferences", keys::PREFERENCES),
+        ("sysrat-local-themes", keys::LOCAL_THEMES),
+        ("sysrat-keybind-overrides", keys::KEYBIND_OVERRIDES),
+        ("split-ratios", keys::SPLIT_RATIOS),
+        ("file-order", keys::FILE_ORDER),
+        ("file-list-selection", keys::FILE_LIST_SELECTION),
+        ("container-list-selected", keys::CONTAINER_LIST_SELECTION),
+        ("pinned-files", keys::PINNED_FILES),
+        ("hidden-files", keys::HIDDEN_FILES),
+        ("pinned-containers", keys::PINNED_CONTAINERS),
+        ("hidden-containers", keys::HIDDEN_CONTAINERS),
+    ],
+];
+
+/// Bring the key names up to the current schema
+pub fn migrate() {
+    let current = RENAMES.len() as u32;
+    let version: u32 = generic::load(SCHEMA_KEY).unwrap_or(0);
+    if version >= current {
+        return;
+    }
+
+    if version == 0 {
+        for old in generic::keys_with_prefix(LEGACY_PREFIX) {
+            let new = format!("sysrat.{}", &old[LEGACY_PREFIX.len()..]);
+            rename(&old, &new);
+        }
+    }
+    for step in &RENAMES[version as usize..] {
+        for (old, new) in *step {
+            rename(old, new);
+        }
+    }
+    generic::save(SCHEMA_KEY, &current);
+}
+
+/// Move the data of `old` to `new`, merged into what `new` already holds
+fn rename(old: &str, new: &str) {
+    let Some(old_json) = generic::load_raw(old) else {
+        return;
+    };
+    let json = match generic::load_raw(new) {
+        Some(new_json) => {
+            match (
+                serde_json::from_str(&old_json),
+                serde_json::from_str(&new_json),
+            ) {
+                (Ok(old_value), Ok(new_value)) => merge(old_value, new_value).to_string(),
+                // Not JSON, e.g. a plain string: the newer data wins
+                _ => new_json,
+            }
+        }
+        None => old_json,
+    };
+    generic::save_raw(new, &json);
+    generic::clear(old);
+}
+
+/// Data of both keys, `new` winning where they disagree: objects keep the
+/// fields only `old` has, lists gain the items only `old` has. Versioned
+/// data of different versions is not mixed.
+fn merge(old: Value, new: Value) -> Value {
+    match (old, new) {
+        (Value::Object(old), Value::Object(mut new))
+            if old.get("version") == new.get("version") =>
+        {
+            for (key, old_value) in old {
+                let merged = match new.remove(&key) {
+                    Some(new_value) => merge(old_value, new_value),
+                    None => old_value,
+                };
+                new.insert(key, merged);
+            }
+            Value::Object(new)
+        }
+        (Value::Array(old), Value::Array(mut new)) => {
+            for item in old {
+                if !new.contains(&item) {
+                    new.push(item);
+                }
+            }
+            Value::Array(new)
+        }
+        (_, new) => new,
+    }
+}
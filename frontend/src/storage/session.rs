@@ -0,0 +1,83 @@
+use super::types::{EditorBuffer, SavedState};
+use super::{generic, hosts, idb, keys, profile};
+use serde_json::Value;
+
+const STATE_VERSION: u32 = 3;
+
+/// Written by a hand-rolled serializer before the state was versioned
+const LEGACY_STATE_KEY: &str = "sysrat-state-v4-manual";
+
+/// IndexedDB key of the open file's content
+const BUFFER_KEY: &str = "editor-buffer";
+
+/// Save the pane, open file and file positions; the file's content goes to
+/// IndexedDB and is left alone when None
+pub fn save_state(state: &SavedState) {
+    write_state(state);
+    profile::changed();
+}
+
+/// `save_state` without pushing to the profile, for the profile itself
+pub(super) fn write_state(state: &SavedState) {
+    generic::save_versioned(&hosts::scoped(keys::STATE), STATE_VERSION, state);
+
+    // A stale buffer is harmless: it is only restored for its own file
+    if let (Some(filename), Some(content)) = (&state.filename, &state.content) {
+        write_buffer(&EditorBuffer {
+            filename: filename.clone(),
+            content: content.clone(),
+        });
+    }
+}
+
+/// Pane and file of the last save on the picked host; the content is read with `load_buffer`
+pub fn load_state() -> Option<SavedState> {
+    generic::load_versioned(&hosts::scoped(keys::STATE), STATE_VERSION, migrate)
+        .or_else(load_legacy)
+}
+
+/// Content saved for `filename`, None when the buffer belongs to another file
+pub async fn load_buffer(filename: &str) -> Option<String> {
+    let buffer: EditorBuffer = idb::get(BUFFER_KEY).await?;
+    (buffer.filename == filename).then_some(buffer.content)
+}
+
+fn write_buffer(buffer: &EditorBuffer) {
+    idb::put(BUFFER_KEY, buffer);
+}
+
+/// Bring the state of `version` to the next version; bump STATE_VERSION and
+/// convert from the old shape here whenever SavedState changes.
+fn migrate(version: u32, mut state: Value) -> Option<Value> {
+    match version {
+        // Version 1 kept the editor content in localStorage
+        1 => {
+            let object = state.as_object_mut()?;
+            let content = object.remove("content");
+            if let (Some(Value::String(filename)), Some(Value::String(content))) =
+                (object.get("filename"), content)
+            {
+                write_buffer(&EditorBuffer {
+                    filename: filename.clone(),
+                    content,
+                });
+            }
+            Some(state)
+        }
+        // Version 3 added the file positions, which default to none
+        2 => Some(state),
+        _ => None,
+    }
+}
+
+/// Move a state saved before versioning to the versioned key. It has the
+/// shape of version 1. The old serializer left tabs and other control
+/// characters unescaped, such a state is not valid JSON and is dropped.
+fn load_legacy() -> Option<SavedState> {
+    let json = generic::load_raw(LEGACY_STATE_KEY)?;
+    generic::clear(LEGACY_STATE_KEY);
+    let state = migrate(1, serde_json::from_str(&json).ok()?)?;
+    let state: SavedState = serde_json::from_value(state).ok()?;
+    generic::save_versioned(keys::STATE, STATE_VERSION, &state);
+    Some(state)
+}
@@ -1,6 +1,50 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+/// Pane and open file at the last save, restored on reload
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SavedState {
     pub pane: String,
     pub filename: Option<String>,
+    /// Kept in IndexedDB as an `EditorBuffer`, filled in once read
+    #[serde(skip)]
     pub content: Option<String>,
+    /// Cursor and view of the files opened before, most recent first
+    #[serde(default)]
+    pub positions: Vec<FilePosition>,
+}
+
+/// Where the cursor and the view were in a file, restored on reopening it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilePosition {
+    pub filename: String,
+    pub row: usize,
+    pub col: usize,
+    /// First line and column on screen
+    pub top: u16,
+    pub left: u16,
+}
+
+/// Content of the open file, saved as typed so a reload keeps unsaved edits
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EditorBuffer {
+    pub filename: String,
+    pub content: String,
+}
+
+/// Theme choices, kept apart from the session so a reset of one keeps the other
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Preferences {
+    /// Theme picked by name, None until one is picked
+    pub theme: Option<String>,
+    /// Follow the browser color scheme; None when never set, which
+    /// `auto_theme_enabled` decides from `theme`
+    pub theme_auto: Option<bool>,
+    /// Splash length the server sent last, so a splash turned off stays
+    /// off before the frontend config arrives
+    #[serde(default)]
+    pub splash_ms: Option<u64>,
+    /// Pane transitions; None when never set, which follows the browser's
+    /// reduced motion setting
+    #[serde(default)]
+    pub animations: Option<bool>,
 }
@@ -0,0 +1,59 @@
+use super::ThemeConfig;
+use crate::api::JobStatus;
+use ratzilla::ratatui::style::{Modifier, Style};
+
+/// Theme styles for the Actions pane
+pub struct ActionsTheme;
+
+impl ActionsTheme {
+    pub fn border_focused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    pub fn border_unfocused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_unfocused()
+    }
+
+    pub fn normal_item_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    pub fn selected_item_style(theme: &ThemeConfig) -> Style {
+        theme
+            .standard_highlight_bg()
+            .fg(theme.text())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Labels of the action details (command, role, ...)
+    pub fn label_style(theme: &ThemeConfig) -> Style {
+        theme.standard_label()
+    }
+
+    pub fn value_style(theme: &ThemeConfig) -> Style {
+        theme.standard_value()
+    }
+
+    /// Role marker next to actions that need a token
+    pub fn role_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.modified())
+    }
+
+    pub fn output_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    pub fn status_style(theme: &ThemeConfig, status: JobStatus) -> Style {
+        match status {
+            JobStatus::Running => Style::default().fg(theme.accent()),
+            JobStatus::Succeeded => Style::default().fg(theme.success()),
+            JobStatus::Failed => Style::default()
+                .fg(theme.error())
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
+    pub fn empty_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+}
@@ -17,6 +17,10 @@ impl ContainerListTheme {
         theme.standard_label()
     }
 
+    pub fn service_url_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.accent())
+    }
+
     pub fn status_color(theme: &ThemeConfig, state: &str) -> Color {
         match state {
             "running" => theme.success(),
@@ -25,6 +29,26 @@ impl ContainerListTheme {
         }
     }
 
+    /// Badge of a health check verdict
+    pub fn health_style(theme: &ThemeConfig, health: &str) -> Style {
+        let color = match health {
+            "healthy" => theme.success(),
+            "unhealthy" => theme.error(),
+            _ => theme.modified(),
+        };
+        Style::default().fg(color).add_modifier(Modifier::BOLD)
+    }
+
+    /// Hidden container listed because "show hidden" is on
+    pub fn hidden_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+
+    /// Section header ("Pinned") above a group of containers
+    pub fn header_style(theme: &ThemeConfig) -> Style {
+        theme.standard_title()
+    }
+
     pub fn border_focused(theme: &ThemeConfig) -> Style {
         theme.standard_border_focused()
     }
@@ -0,0 +1,54 @@
+use super::ThemeConfig;
+use ratzilla::ratatui::style::{Modifier, Style};
+
+/// Theme styles for the single-container watch view
+pub struct ContainerWatchTheme;
+
+impl ContainerWatchTheme {
+    pub fn border_focused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    pub fn border_unfocused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_unfocused()
+    }
+
+    pub fn label_style(theme: &ThemeConfig) -> Style {
+        theme.standard_label()
+    }
+
+    pub fn value_style(theme: &ThemeConfig) -> Style {
+        theme.standard_value()
+    }
+
+    /// Health other than "healthy", restarts and a failed poll
+    pub fn warning_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.error())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    pub fn healthy_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.success())
+    }
+
+    /// "paused" marker in the title
+    pub fn paused_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.modified())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Log and event timestamps
+    pub fn time_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+
+    pub fn line_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    pub fn empty_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+}
@@ -0,0 +1,49 @@
+use super::ThemeConfig;
+use crate::api::AlertLevel;
+use ratzilla::ratatui::style::{Modifier, Style};
+
+/// Theme styles for the dashboard widgets
+pub struct DashboardTheme;
+
+impl DashboardTheme {
+    pub fn border_style(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    pub fn label_style(theme: &ThemeConfig) -> Style {
+        theme.standard_label()
+    }
+
+    pub fn value_style(theme: &ThemeConfig) -> Style {
+        theme.standard_value()
+    }
+
+    pub fn name_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    /// Number key in front of a quick action
+    pub fn shortcut_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.accent())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Placeholder text ("Loading...", "No alerts")
+    pub fn empty_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+
+    pub fn ok_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.success())
+    }
+
+    pub fn alert_style(theme: &ThemeConfig, level: AlertLevel) -> Style {
+        match level {
+            AlertLevel::Critical => Style::default()
+                .fg(theme.error())
+                .add_modifier(Modifier::BOLD),
+            AlertLevel::Warning => Style::default().fg(theme.modified()),
+        }
+    }
+}
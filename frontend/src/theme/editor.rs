@@ -1,6 +1,6 @@
 use super::ThemeConfig;
 use crate::state::VimMode;
-use ratzilla::ratatui::style::Style;
+use ratzilla::ratatui::style::{Modifier, Style};
 
 /// Theme styles for the text editor widget
 pub struct EditorTheme;
@@ -16,4 +16,89 @@ impl EditorTheme {
             theme.standard_border_unfocused()
         }
     }
+
+    /// Key column of the table mode
+    pub fn table_key_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.accent())
+    }
+
+    pub fn table_value_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    /// Row the cursor is on
+    pub fn table_selected_row_style(theme: &ThemeConfig) -> Style {
+        theme.standard_highlight_bg()
+    }
+
+    /// Cell the cursor is on, and the cell being edited
+    pub fn table_active_cell_style(theme: &ThemeConfig) -> Style {
+        theme
+            .standard_highlight_bg()
+            .fg(theme.selected())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// `[section]` headers of ini files
+    pub fn table_section_style(theme: &ThemeConfig) -> Style {
+        theme.standard_title()
+    }
+
+    /// Comments, shown for context but not editable
+    pub fn table_comment_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+
+    /// Border of the panel explaining a write the server was refused
+    pub fn write_denied_border_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.error())
+    }
+
+    /// Gutter mark and message of a broken lint rule
+    pub fn lint_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.accent())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Gutter mark and severity of a lint error, i.e. the file would be rejected
+    pub fn lint_error_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.error())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Empty or duplicate keys
+    pub fn table_invalid_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.error())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Values the server masked as secrets
+    pub fn secret_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.accent())
+            .add_modifier(Modifier::ITALIC)
+    }
+
+    /// Marks of spaces and tabs while whitespace is shown
+    pub fn whitespace_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+
+    /// Line numbers in the gutter
+    pub fn line_number_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+
+    /// The `:` in front of a command being typed
+    pub fn command_prompt_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.normal_mode())
+    }
+
+    /// Cursor of the wrapped view, drawn like the textarea's
+    pub fn wrapped_cursor_style() -> Style {
+        Style::default().add_modifier(Modifier::REVERSED)
+    }
 }
@@ -0,0 +1,50 @@
+use super::ThemeConfig;
+use ratzilla::ratatui::style::{Modifier, Style};
+
+/// Theme styles for the file history viewer
+pub struct FileHistoryTheme;
+
+impl FileHistoryTheme {
+    pub fn border_focused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    pub fn border_unfocused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_unfocused()
+    }
+
+    pub fn normal_item_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    pub fn selected_item_style(theme: &ThemeConfig) -> Style {
+        theme
+            .standard_highlight_bg()
+            .fg(theme.text())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Commit id and time next to the message
+    pub fn meta_style(theme: &ThemeConfig) -> Style {
+        theme.standard_label()
+    }
+
+    pub fn empty_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+
+    /// Color a line of unified diff output by its prefix
+    pub fn diff_line_style(theme: &ThemeConfig, line: &str) -> Style {
+        if line.starts_with("+++") || line.starts_with("---") || line.starts_with("diff ") {
+            Style::default().fg(theme.dim())
+        } else if line.starts_with("@@") {
+            Style::default().fg(theme.accent())
+        } else if line.starts_with('+') {
+            Style::default().fg(theme.success())
+        } else if line.starts_with('-') {
+            Style::default().fg(theme.error())
+        } else {
+            theme.standard_normal_item()
+        }
+    }
+}
@@ -21,6 +21,11 @@ impl FileListTheme {
         theme.standard_normal_item()
     }
 
+    /// Hidden file listed because "show hidden" is on
+    pub fn hidden_item_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+
     pub fn header_style(theme: &ThemeConfig) -> Style {
         theme.standard_title()
     }
@@ -0,0 +1,33 @@
+use super::ThemeConfig;
+use ratzilla::ratatui::style::Style;
+
+/// Theme styles for the hex viewer
+pub struct HexViewerTheme;
+
+impl HexViewerTheme {
+    pub fn border_focused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    /// File offset at the start of each row
+    pub fn offset_style(theme: &ThemeConfig) -> Style {
+        theme.standard_label()
+    }
+
+    pub fn hex_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    /// Zero bytes and non-printable characters
+    pub fn faint_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+
+    pub fn ascii_style(theme: &ThemeConfig) -> Style {
+        theme.standard_value()
+    }
+
+    pub fn empty_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+}
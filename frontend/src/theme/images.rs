@@ -0,0 +1,66 @@
+use super::ThemeConfig;
+use ratzilla::ratatui::style::{Modifier, Style};
+
+/// Theme styles for the Images pane
+pub struct ImagesTheme;
+
+impl ImagesTheme {
+    pub fn border_focused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    pub fn border_unfocused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_unfocused()
+    }
+
+    pub fn normal_item_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    pub fn selected_item_style(theme: &ThemeConfig) -> Style {
+        theme
+            .standard_highlight_bg()
+            .fg(theme.text())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Labels of the image details
+    pub fn label_style(theme: &ThemeConfig) -> Style {
+        theme.standard_label()
+    }
+
+    pub fn value_style(theme: &ThemeConfig) -> Style {
+        theme.standard_value()
+    }
+
+    /// Tag next to the repository, "not scanned" and package versions
+    pub fn hint_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+
+    /// Badge or label of a severity ("CRITICAL", "HIGH", ...)
+    pub fn severity_style(theme: &ThemeConfig, severity: &str) -> Style {
+        match severity {
+            "CRITICAL" => Style::default()
+                .fg(theme.error())
+                .add_modifier(Modifier::BOLD),
+            "HIGH" => Style::default().fg(theme.error()),
+            "MEDIUM" => Style::default().fg(theme.modified()),
+            _ => Style::default().fg(theme.dim()),
+        }
+    }
+
+    /// Badge of a scan without findings
+    pub fn clean_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.success())
+    }
+
+    /// Badge of the image being scanned
+    pub fn scanning_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.accent())
+    }
+
+    pub fn empty_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+}
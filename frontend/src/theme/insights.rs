@@ -0,0 +1,45 @@
+use super::ThemeConfig;
+use ratzilla::ratatui::style::{Modifier, Style};
+
+/// Theme styles for the Insights pane
+pub struct InsightsTheme;
+
+impl InsightsTheme {
+    pub fn border_focused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    pub fn border_unfocused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_unfocused()
+    }
+
+    pub fn normal_item_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    pub fn selected_item_style(theme: &ThemeConfig) -> Style {
+        theme
+            .standard_highlight_bg()
+            .fg(theme.text())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Column headings
+    pub fn header_style(theme: &ThemeConfig) -> Style {
+        theme.standard_label().add_modifier(Modifier::BOLD)
+    }
+
+    /// Bar of the changes within the window
+    pub fn bar_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.accent())
+    }
+
+    /// Totals and last change, secondary to the window's count
+    pub fn hint_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+
+    pub fn empty_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+}
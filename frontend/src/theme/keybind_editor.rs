@@ -0,0 +1,50 @@
+use super::ThemeConfig;
+use ratzilla::ratatui::style::{Modifier, Style};
+
+/// Theme styles for the keybind editor
+pub struct KeybindEditorTheme;
+
+impl KeybindEditorTheme {
+    pub fn border_focused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    pub fn normal_item_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    pub fn selected_item_style(theme: &ThemeConfig) -> Style {
+        theme
+            .standard_highlight_bg()
+            .fg(theme.text())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// keybinds.toml section in front of the action
+    pub fn section_style(theme: &ThemeConfig) -> Style {
+        theme.standard_label()
+    }
+
+    pub fn key_style(theme: &ThemeConfig) -> Style {
+        theme.standard_value()
+    }
+
+    /// Bindings changed in the editor
+    pub fn overridden_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.modified())
+    }
+
+    /// Bindings sharing a key with another one in the same scope
+    pub fn conflict_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.error())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Prompt shown while waiting for the new key
+    pub fn capture_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.insert_mode())
+            .add_modifier(Modifier::BOLD)
+    }
+}
@@ -1,13 +1,74 @@
-use super::types::ThemeConfig;
+use super::types::{ThemeConfig, ThemeVariant};
+use crate::api::ThemeFile;
 use crate::storage;
+use std::cell::RefCell;
 
-/// Get list of available theme names
-pub fn available_themes() -> Vec<&'static str> {
-    generated::THEME_NAMES.to_vec()
+thread_local! {
+    /// Themes served by the backend at startup, checked before embedded ones
+    static RUNTIME_THEMES: RefCell<Vec<ThemeFile>> = const { RefCell::new(Vec::new()) };
 }
 
-/// Load theme by name from embedded themes
+/// Replace the runtime themes fetched from `/api/themes`
+pub fn set_runtime_themes(themes: Vec<ThemeFile>) {
+    RUNTIME_THEMES.with(|t| *t.borrow_mut() = themes);
+}
+
+fn local_themes() -> Vec<ThemeFile> {
+    storage::generic::load(storage::keys::LOCAL_THEMES).unwrap_or_default()
+}
+
+/// Store an edited theme in localStorage, replacing any earlier edit of it
+pub fn save_local_theme(name: &str, content: String) {
+    let mut themes = local_themes();
+    themes.retain(|theme| theme.name != name);
+    themes.push(ThemeFile {
+        name: name.to_string(),
+        content,
+    });
+    storage::generic::save(storage::keys::LOCAL_THEMES, &themes);
+}
+
+/// Drop the local edit of a theme, falling back to the server or embedded one
+pub fn remove_local_theme(name: &str) {
+    let mut themes = local_themes();
+    themes.retain(|theme| theme.name != name);
+    storage::generic::save(storage::keys::LOCAL_THEMES, &themes);
+}
+
+/// Get list of available theme names (embedded + runtime + local, sorted)
+pub fn available_themes() -> Vec<String> {
+    let mut names: Vec<String> = generated::THEME_NAMES
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+    let runtime = RUNTIME_THEMES.with(|t| t.borrow().clone());
+    for theme in runtime.into_iter().chain(local_themes()) {
+        if !names.contains(&theme.name) {
+            names.push(theme.name);
+        }
+    }
+    names.sort();
+    names
+}
+
+/// Load theme by name: local edits first, then server themes, then embedded
 pub fn load_theme_by_name(name: &str) -> Result<ThemeConfig, String> {
+    let local = local_themes()
+        .into_iter()
+        .find(|theme| theme.name == name)
+        .map(|theme| theme.content);
+    let runtime = local.or_else(|| {
+        RUNTIME_THEMES.with(|t| {
+            t.borrow()
+                .iter()
+                .find(|theme| theme.name == name)
+                .map(|theme| theme.content.clone())
+        })
+    });
+    if let Some(content) = runtime {
+        return parse_theme_toml(&content);
+    }
+
     // [DEBUG_START] Theme loading diagnostics
     // web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!(
     // "[DEBUG] Available themes: {:?}",
@@ -58,6 +119,11 @@ pub fn parse_theme_toml(toml: &str) -> Result<ThemeConfig, String> {
     toml::from_str(toml).map_err(|e| format!("Failed to parse theme TOML: {}", e))
 }
 
+/// Serialize a theme back to the TOML format it was loaded from
+pub fn theme_to_toml(theme: &ThemeConfig) -> Result<String, String> {
+    toml::to_string(theme).map_err(|e| format!("Failed to serialize theme: {}", e))
+}
+
 /// Load theme preference from localStorage
 pub fn load_theme_preference() -> Option<String> {
     storage::load_theme_preference()
@@ -68,11 +134,61 @@ pub fn save_theme_preference(name: &str) {
     storage::save_theme_preference(name);
 }
 
-/// Load the current theme (from localStorage or default)
-pub fn load_current_theme() -> ThemeConfig {
-    if let Some(theme_name) = load_theme_preference()
-        && let Ok(theme) = load_theme_by_name(&theme_name)
+/// Themes used in auto mode when the preference does not fit the color scheme
+const DEFAULT_DARK_THEME: &str = "mocha";
+const DEFAULT_LIGHT_THEME: &str = "latte";
+
+/// Color scheme the browser asks for (`prefers-color-scheme`), dark if unknown
+pub fn system_variant() -> ThemeVariant {
+    let prefers_light = web_sys::window()
+        .and_then(|w| {
+            w.match_media("(prefers-color-scheme: light)")
+                .ok()
+                .flatten()
+        })
+        .is_some_and(|query| query.matches());
+    if prefers_light {
+        ThemeVariant::Light
+    } else {
+        ThemeVariant::Dark
+    }
+}
+
+/// Whether the theme follows the browser color scheme. On unless turned off,
+/// or a theme was picked before auto mode existed
+pub fn auto_theme_enabled() -> bool {
+    storage::load_theme_auto().unwrap_or_else(|| load_theme_preference().is_none())
+}
+
+/// Turn auto mode on or off (the user override of the color scheme)
+pub fn set_auto_theme(enabled: bool) {
+    storage::save_theme_auto(enabled);
+}
+
+/// Name of the theme to show: the preference, unless auto mode is on and
+/// the preference does not fit the browser color scheme
+pub fn current_theme_name() -> String {
+    let preference = load_theme_preference();
+    if !auto_theme_enabled() {
+        return preference.unwrap_or_else(|| DEFAULT_DARK_THEME.to_string());
+    }
+
+    let variant = system_variant();
+    if let Some(name) = preference
+        && load_theme_by_name(&name).is_ok_and(|theme| theme.fits(variant))
     {
+        return name;
+    }
+    match variant {
+        ThemeVariant::Light => DEFAULT_LIGHT_THEME,
+        ThemeVariant::Dark => DEFAULT_DARK_THEME,
+    }
+    .to_string()
+}
+
+/// Load the current theme (from localStorage, the color scheme or default)
+pub fn load_current_theme() -> ThemeConfig {
+    if let Ok(theme) = load_theme_by_name(&current_theme_name()) {
         return theme;
     }
 
@@ -80,16 +196,23 @@ pub fn load_current_theme() -> ThemeConfig {
     load_theme_by_name("mocha").expect("Default theme (mocha) must exist")
 }
 
-/// Get next theme name (for cycling)
+/// Get next theme name (for cycling), in auto mode only themes that fit
+/// the browser color scheme
 pub fn next_theme_name(current: &str) -> String {
-    let themes = available_themes();
+    let mut themes = available_themes();
+    if auto_theme_enabled() {
+        let variant = system_variant();
+        themes.retain(|name| {
+            name == current || load_theme_by_name(name).is_ok_and(|theme| theme.fits(variant))
+        });
+    }
     if themes.is_empty() {
         return "mocha".to_string();
     }
 
-    if let Some(idx) = themes.iter().position(|&t| t == current) {
-        themes[(idx + 1) % themes.len()].to_string()
+    if let Some(idx) = themes.iter().position(|t| t == current) {
+        themes[(idx + 1) % themes.len()].clone()
     } else {
-        themes[0].to_string()
+        themes[0].clone()
     }
 }
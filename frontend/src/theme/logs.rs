@@ -0,0 +1,58 @@
+use super::ThemeConfig;
+use crate::state::logs::Severity;
+use ratzilla::ratatui::style::{Modifier, Style};
+
+/// Theme styles for the Logs pane
+pub struct LogsTheme;
+
+impl LogsTheme {
+    pub fn border_focused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    pub fn border_unfocused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_unfocused()
+    }
+
+    pub fn normal_item_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    pub fn selected_item_style(theme: &ThemeConfig) -> Style {
+        theme
+            .standard_highlight_bg()
+            .fg(theme.text())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Marker of the followed log in the list
+    pub fn following_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.accent())
+    }
+
+    /// A log line, colored by how loud it is
+    pub fn line_style(theme: &ThemeConfig, severity: Severity) -> Style {
+        match severity {
+            Severity::Critical => Style::default()
+                .fg(theme.error())
+                .add_modifier(Modifier::BOLD),
+            Severity::Error => Style::default().fg(theme.error()),
+            Severity::Warning => Style::default().fg(theme.modified()),
+            Severity::Debug => Style::default().fg(theme.dim()),
+            Severity::Normal => Style::default().fg(theme.text()),
+        }
+    }
+
+    /// Search, pause and failure notes in the title
+    pub fn notice_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.accent())
+    }
+
+    pub fn failure_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.error())
+    }
+
+    pub fn empty_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+}
@@ -13,6 +13,11 @@ impl MenuTheme {
         theme.standard_ascii_art()
     }
 
+    /// Message of the day below the menu items
+    pub fn motd_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+
     pub fn border_style(theme: &ThemeConfig) -> Style {
         theme.standard_border_focused()
     }
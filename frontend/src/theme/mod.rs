@@ -15,14 +15,18 @@
 /// Themes are loaded from embedded TOML files at runtime:
 /// - Default themes embedded in WASM (mocha, latte, frappe, macchiato)
 /// - User custom themes scanned from `~/.config/sysrat/themes/` at build time
+/// - The same directory served by `GET /api/themes`, fetched on startup
+/// - Edits saved from the Theme Editor pane, kept in localStorage
 /// - Theme preference stored in browser localStorage
+/// - Auto mode: themes declare `variant = "light" | "dark"` and the one fitting
+///   the browser `prefers-color-scheme` is used (latte/mocha by default)
 /// - Fallback to Mocha theme if preference not found
 ///
 /// ## Adding Custom Themes
 ///
 /// 1. Create `~/.config/sysrat/themes/my-theme.toml`
-/// 2. Rebuild frontend: `just build-frontend` or `./rebuild.py`
-/// 3. Select theme from menu (automatically embedded in WASM)
+/// 2. Reload the page (served by the backend), or rebuild the frontend to embed it
+/// 3. Select theme from menu
 ///
 /// ## Design Principles
 ///
@@ -39,11 +43,24 @@
 /// - `selected_item_style(theme)` - Style for the selected/highlighted item
 /// - `selected_prefix()` - Text prefix for selected items (e.g., "> ")
 // Component theme modules
+pub mod actions;
 pub mod container_list;
+pub mod container_watch;
+pub mod dashboard;
 pub mod editor;
+pub mod file_history;
 pub mod file_list;
+pub mod hex_viewer;
+pub mod images;
+pub mod insights;
+pub mod keybind_editor;
+pub mod logs;
 pub mod menu;
+pub mod overlay;
+pub mod runbooks;
 pub mod status_line;
+pub mod theme_editor;
+pub mod toast;
 
 // Theme core modules
 mod builder;
@@ -52,10 +69,11 @@ mod types;
 
 // Public re-exports
 pub use loader::{
-    load_current_theme, load_theme_by_name, load_theme_preference, next_theme_name,
-    save_theme_preference,
+    auto_theme_enabled, available_themes, current_theme_name, load_current_theme,
+    load_theme_by_name, next_theme_name, remove_local_theme, save_local_theme,
+    save_theme_preference, set_auto_theme, set_runtime_themes, system_variant, theme_to_toml,
 };
-pub use types::{FontConfig, ThemeConfig};
+pub use types::{FontConfig, ThemeConfig, ThemeVariant};
 
 /// Common prefix for selected items in lists
 pub const SELECTED_PREFIX: &str = "> ";
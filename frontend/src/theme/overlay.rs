@@ -0,0 +1,48 @@
+use super::ThemeConfig;
+use ratzilla::ratatui::style::{Modifier, Style};
+
+/// Theme styles for modal overlays
+pub struct OverlayTheme;
+
+impl OverlayTheme {
+    pub fn border_style(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    /// Fills the modal so the pane below does not show through
+    pub fn background_style(theme: &ThemeConfig) -> Style {
+        Style::default().bg(theme.mantle())
+    }
+
+    /// Group headings, e.g. the pane name above its bindings
+    pub fn heading_style(theme: &ThemeConfig) -> Style {
+        theme.standard_title().add_modifier(Modifier::BOLD)
+    }
+
+    pub fn key_style(theme: &ThemeConfig) -> Style {
+        theme.standard_value()
+    }
+
+    pub fn label_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    /// Entry picked with j/k in a list overlay
+    pub fn selected_style(theme: &ThemeConfig) -> Style {
+        theme
+            .standard_highlight_bg()
+            .fg(theme.text())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Outcome of a check: passed, failed, or not configured (None)
+    pub fn verdict_style(theme: &ThemeConfig, passed: Option<bool>) -> Style {
+        match passed {
+            Some(true) => Style::default().fg(theme.success()),
+            Some(false) => Style::default()
+                .fg(theme.error())
+                .add_modifier(Modifier::BOLD),
+            None => Style::default().fg(theme.dim()),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use super::ThemeConfig;
+use crate::state::runbooks::StepStatus;
+use ratzilla::ratatui::style::{Modifier, Style};
+
+/// Theme styles for the Runbooks pane
+pub struct RunbooksTheme;
+
+impl RunbooksTheme {
+    pub fn border_focused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    pub fn border_unfocused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_unfocused()
+    }
+
+    pub fn normal_item_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    pub fn selected_item_style(theme: &ThemeConfig) -> Style {
+        theme
+            .standard_highlight_bg()
+            .fg(theme.text())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Labels of the runbook and step details
+    pub fn label_style(theme: &ThemeConfig) -> Style {
+        theme.standard_label()
+    }
+
+    pub fn value_style(theme: &ThemeConfig) -> Style {
+        theme.standard_value()
+    }
+
+    /// Step count next to runbooks and the kind next to steps
+    pub fn hint_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+
+    /// Status marker in front of a step
+    pub fn step_style(theme: &ThemeConfig, status: StepStatus) -> Style {
+        match status {
+            StepStatus::Pending => Style::default().fg(theme.dim()),
+            StepStatus::Running => Style::default().fg(theme.accent()),
+            StepStatus::Done => Style::default().fg(theme.success()),
+            StepStatus::Failed => Style::default()
+                .fg(theme.error())
+                .add_modifier(Modifier::BOLD),
+            StepStatus::Skipped => Style::default().fg(theme.modified()),
+        }
+    }
+
+    /// Filled part of the overall progress bar
+    pub fn progress_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.success()).bg(theme.dim())
+    }
+
+    pub fn output_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    pub fn empty_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.dim())
+    }
+}
@@ -0,0 +1,41 @@
+use super::ThemeConfig;
+use ratzilla::ratatui::style::{Color, Modifier, Style};
+
+/// Theme styles for the theme editor
+pub struct ThemeEditorTheme;
+
+impl ThemeEditorTheme {
+    pub fn border_focused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    pub fn border_unfocused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_unfocused()
+    }
+
+    pub fn normal_item_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    pub fn selected_item_style(theme: &ThemeConfig) -> Style {
+        theme.standard_selected_item()
+    }
+
+    /// RGB values and the base color a semantic role maps to
+    pub fn label_style(theme: &ThemeConfig) -> Style {
+        theme.standard_label()
+    }
+
+    /// Channel changed by the adjust keys on the selected color
+    pub fn active_channel_style(theme: &ThemeConfig) -> Style {
+        theme
+            .standard_highlight_bg()
+            .fg(theme.selected())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Block of the color itself next to its name
+    pub fn swatch_style(color: Color) -> Style {
+        Style::default().fg(color)
+    }
+}
@@ -0,0 +1,43 @@
+use super::ThemeConfig;
+use crate::state::notifications::Severity;
+use ratzilla::ratatui::style::{Color, Modifier, Style};
+
+/// Theme styles for the toast notifications
+pub struct ToastTheme;
+
+impl ToastTheme {
+    fn color(theme: &ThemeConfig, severity: Severity) -> Color {
+        match severity {
+            Severity::Info => theme.accent(),
+            Severity::Success => theme.success(),
+            Severity::Warning => theme.modified(),
+            Severity::Error => theme.error(),
+        }
+    }
+
+    pub fn border_style(theme: &ThemeConfig, severity: Severity) -> Style {
+        Style::default().fg(Self::color(theme, severity))
+    }
+
+    pub fn title_style(theme: &ThemeConfig, severity: Severity) -> Style {
+        Self::border_style(theme, severity).add_modifier(Modifier::BOLD)
+    }
+
+    /// Fills the toast so the pane below does not show through
+    pub fn background_style(theme: &ThemeConfig) -> Style {
+        Style::default().bg(theme.mantle())
+    }
+
+    pub fn message_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    /// Full-width line above the panes while the browser is offline, and
+    /// above the container list while the docker daemon is gone
+    pub fn offline_banner_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.mantle())
+            .bg(Self::color(theme, Severity::Warning))
+            .add_modifier(Modifier::BOLD)
+    }
+}
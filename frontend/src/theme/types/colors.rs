@@ -1,20 +1,32 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Base RGB color definitions (all optional to support different theme palettes)
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BaseColors {
     // Catppuccin-style colors (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lavender: Option<[u8; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mauve: Option<[u8; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sapphire: Option<[u8; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub green: Option<[u8; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub yellow: Option<[u8; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub peach: Option<[u8; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub red: Option<[u8; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<[u8; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtext0: Option<[u8; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub overlay1: Option<[u8; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub surface1: Option<[u8; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mantle: Option<[u8; 3]>,
 
     // Allow any additional colors from theme files
@@ -43,10 +55,60 @@ impl BaseColors {
         .or_else(|| self.extra.get(name).copied())
         .unwrap_or([128, 128, 128]) // Default gray if color not found
     }
+
+    /// Names of all colors the theme defines, named fields first
+    pub fn names(&self) -> Vec<String> {
+        let named = [
+            ("lavender", self.lavender),
+            ("mauve", self.mauve),
+            ("sapphire", self.sapphire),
+            ("green", self.green),
+            ("yellow", self.yellow),
+            ("peach", self.peach),
+            ("red", self.red),
+            ("text", self.text),
+            ("subtext0", self.subtext0),
+            ("overlay1", self.overlay1),
+            ("surface1", self.surface1),
+            ("mantle", self.mantle),
+        ];
+        let mut names: Vec<String> = named
+            .iter()
+            .filter(|(_, rgb)| rgb.is_some())
+            .map(|(name, _)| name.to_string())
+            .collect();
+        let mut extra: Vec<String> = self.extra.keys().cloned().collect();
+        extra.sort();
+        names.extend(extra);
+        names
+    }
+
+    /// Set a color by name (unknown names go into `extra`)
+    pub fn set(&mut self, name: &str, rgb: [u8; 3]) {
+        let field = match name {
+            "lavender" => &mut self.lavender,
+            "mauve" => &mut self.mauve,
+            "sapphire" => &mut self.sapphire,
+            "green" => &mut self.green,
+            "yellow" => &mut self.yellow,
+            "peach" => &mut self.peach,
+            "red" => &mut self.red,
+            "text" => &mut self.text,
+            "subtext0" => &mut self.subtext0,
+            "overlay1" => &mut self.overlay1,
+            "surface1" => &mut self.surface1,
+            "mantle" => &mut self.mantle,
+            _ => {
+                self.extra.insert(name.to_string(), rgb);
+                return;
+            }
+        };
+        *field = Some(rgb);
+    }
 }
 
 /// Semantic color mappings to base colors
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SemanticMappings {
     pub accent: String,
     pub selected: String,
@@ -57,3 +119,19 @@ pub struct SemanticMappings {
     pub insert_mode: String,
     pub dim: String,
 }
+
+impl SemanticMappings {
+    /// (role, base color name) pairs in display order
+    pub fn entries(&self) -> [(&'static str, &str); 8] {
+        [
+            ("accent", &self.accent),
+            ("selected", &self.selected),
+            ("modified", &self.modified),
+            ("success", &self.success),
+            ("error", &self.error),
+            ("normal_mode", &self.normal_mode),
+            ("insert_mode", &self.insert_mode),
+            ("dim", &self.dim),
+        ]
+    }
+}
@@ -1,5 +1,5 @@
 use ratzilla::ratatui::style::Color;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::{
     colors::{BaseColors, SemanticMappings},
@@ -7,13 +7,24 @@ use super::{
     icons::{IconConfig, default_icon_config},
 };
 
+/// Whether a theme is made for a light or a dark environment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeVariant {
+    Light,
+    Dark,
+}
+
 /// Runtime theme configuration
 ///
 /// Represents a theme loaded at runtime from TOML.
 /// Unlike the build-time `Theme` constants, this struct
 /// holds dynamically loaded color values.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ThemeConfig {
+    /// Themes without a variant fit both light and dark mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<ThemeVariant>,
     #[serde(rename = "colors")]
     pub base: BaseColors,
     pub semantic: SemanticMappings,
@@ -24,8 +35,13 @@ pub struct ThemeConfig {
 }
 
 impl ThemeConfig {
+    /// Whether the theme suits the given color scheme
+    pub fn fits(&self, variant: ThemeVariant) -> bool {
+        self.variant.is_none_or(|v| v == variant)
+    }
+
     /// Get base color by name
-    fn get_base_color(&self, name: &str) -> Color {
+    pub fn get_base_color(&self, name: &str) -> Color {
         let rgb = self.base.get(name);
         Color::Rgb(rgb[0], rgb[1], rgb[2])
     }
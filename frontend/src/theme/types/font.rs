@@ -1,12 +1,13 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Font configuration for the theme
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct FontConfig {
     pub family: String,
     pub fallback: String,
     pub size: u32,
     pub weight: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cdn_url: Option<String>,
 }
 
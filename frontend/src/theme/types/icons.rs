@@ -1,10 +1,13 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Icon configuration for menu items
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct IconConfig {
     pub config_files: String,
     pub container: String,
+    // Older user themes predate the dashboard
+    #[serde(default = "default_dashboard_icon")]
+    pub dashboard: String,
 }
 
 /// Default icon configuration (Unicode symbols)
@@ -12,5 +15,10 @@ pub fn default_icon_config() -> IconConfig {
     IconConfig {
         config_files: "▪".to_string(), // Black small square (U+25AA)
         container: "▪".to_string(),    // Black small square (U+25AA)
+        dashboard: default_dashboard_icon(),
     }
 }
+
+fn default_dashboard_icon() -> String {
+    "▪".to_string() // Black small square (U+25AA)
+}
@@ -3,5 +3,5 @@ mod config;
 mod font;
 mod icons;
 
-pub use config::ThemeConfig;
+pub use config::{ThemeConfig, ThemeVariant};
 pub use font::FontConfig;
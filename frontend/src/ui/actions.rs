@@ -0,0 +1,220 @@
+use crate::{
+    api::JobStatus,
+    state::{AppState, Pane},
+    theme::{actions::ActionsTheme, editor::EditorTheme},
+    utils::time::now_secs,
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Action list on the left; details of the selected action and the output
+/// of the last run on the right
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(35), // Action list
+            Constraint::Percentage(1),  // Empty gap
+            Constraint::Percentage(64), // Details + output
+        ])
+        .split(area);
+    let right = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(9), // Details
+            Constraint::Min(0),    // Output
+        ])
+        .split(chunks[2]);
+
+    render_list(f, state, chunks[0]);
+    render_details(f, state, right[0]);
+
+    // Parameters are asked for in place of the output of the last run
+    match &state.actions.params {
+        Some(prompt) => super::editor::render_form(
+            f,
+            &prompt.form,
+            &state.current_theme,
+            &format!(" Parameters of {} ", prompt.action),
+            EditorTheme::border_style(&state.current_theme, state.vim_mode, true),
+            right[1],
+        ),
+        None => render_output(f, state, right[1]),
+    }
+}
+
+fn render_list(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let actions = &state.actions;
+    let border_style = if state.is_focused(Pane::Actions) {
+        ActionsTheme::border_focused(theme)
+    } else {
+        ActionsTheme::border_unfocused(theme)
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Actions ")
+        .border_style(border_style);
+
+    if actions.actions.is_empty() {
+        let message = if actions.loaded {
+            "No [[actions]] in sysrat.toml"
+        } else {
+            "Loading..."
+        };
+        let paragraph = Paragraph::new(message)
+            .block(block)
+            .style(ActionsTheme::empty_style(theme));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let lines: Vec<Line> = actions
+        .actions
+        .iter()
+        .enumerate()
+        .map(|(i, action)| {
+            let mut spans = vec![Span::styled(
+                action.name.clone(),
+                ActionsTheme::normal_item_style(theme),
+            )];
+            if let Some(role) = &action.role {
+                spans.push(Span::styled(
+                    format!(" [{}]", role),
+                    ActionsTheme::role_style(theme),
+                ));
+            }
+            let line = Line::from(spans);
+            if i == actions.selected_index {
+                line.style(ActionsTheme::selected_item_style(theme))
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    // Keep the selection in view (2 rows of border)
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = (actions.selected_index + 1).saturating_sub(visible);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+fn render_details(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Details ")
+        .border_style(ActionsTheme::border_unfocused(theme));
+
+    let Some(action) = state.actions.selected() else {
+        f.render_widget(block, area);
+        return;
+    };
+
+    let row = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<9} ", label), ActionsTheme::label_style(theme)),
+            Span::styled(value, ActionsTheme::value_style(theme)),
+        ])
+    };
+    let lines = vec![
+        row("About", action.description.clone()),
+        row("Command", action.command.clone()),
+        row(
+            "Directory",
+            action
+                .working_dir
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        row("Timeout", format!("{}s", action.timeout_secs)),
+        row(
+            "Role",
+            action.role.clone().unwrap_or_else(|| "-".to_string()),
+        ),
+        row(
+            "Confirm",
+            action.confirm.clone().unwrap_or_else(|| "-".to_string()),
+        ),
+        row(
+            "Params",
+            if action.params.is_empty() {
+                "-".to_string()
+            } else {
+                action
+                    .params
+                    .iter()
+                    .map(|field| field.key.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            },
+        ),
+    ];
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn render_output(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let actions = &state.actions;
+
+    let Some(job) = &actions.job else {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Output ")
+            .border_style(ActionsTheme::border_unfocused(theme));
+        let paragraph = Paragraph::new("No action run yet")
+            .block(block)
+            .style(ActionsTheme::empty_style(theme));
+        f.render_widget(paragraph, area);
+        return;
+    };
+
+    let elapsed = job.finished.unwrap_or(now_secs() as i64) - job.started;
+    let status = match job.status {
+        JobStatus::Running => "running".to_string(),
+        JobStatus::Succeeded | JobStatus::Failed => job.message.clone(),
+    };
+    let title = Line::from(vec![
+        Span::raw(format!(" #{} {} ", job.id, job.target)),
+        Span::styled(
+            format!("{} ", status),
+            ActionsTheme::status_style(theme, job.status),
+        ),
+        Span::raw(format!("{}s ", elapsed.max(0))),
+    ]);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(ActionsTheme::border_unfocused(theme));
+
+    let lines: Vec<Line> = job
+        .output
+        .lines()
+        .map(|line| {
+            Line::from(Span::styled(
+                line.to_string(),
+                ActionsTheme::output_style(theme),
+            ))
+        })
+        .collect();
+
+    // Show the end of the output, minus what the user scrolled back
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = lines
+        .len()
+        .saturating_sub(visible)
+        .saturating_sub(actions.output_scroll as usize);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
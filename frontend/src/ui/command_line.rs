@@ -0,0 +1,20 @@
+use crate::{state::AppState, theme::editor::EditorTheme};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+/// The editor's `:` line being typed, one row above the status line
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let Some(line) = &state.command_line else {
+        return;
+    };
+    let theme = &state.current_theme;
+    let text = Line::from(vec![
+        Span::styled(":", EditorTheme::command_prompt_style(theme)),
+        Span::styled(format!("{}_", line.input), theme.standard_normal_item()),
+    ]);
+    f.render_widget(Paragraph::new(text), area);
+}
@@ -21,6 +21,12 @@ pub(super) fn add_basic_info(
         Span::styled("Image: ", Style::default().fg(theme.dim())),
         Span::styled(details.image.clone(), Style::default().fg(theme.accent())),
     ]));
+    if let Some(url) = &details.service_url {
+        lines.push(Line::from(vec![
+            Span::styled("URL: ", Style::default().fg(theme.dim())),
+            Span::styled(url.clone(), Style::default().fg(theme.accent())),
+        ]));
+    }
     lines.push(Line::from(""));
 
     let state_color = match details.state.as_str() {
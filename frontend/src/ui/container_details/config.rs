@@ -1,4 +1,7 @@
-use crate::{api::ContainerDetails, theme::ThemeConfig};
+use crate::{
+    api::{ContainerDetails, EnvSource},
+    theme::ThemeConfig,
+};
 use ratzilla::ratatui::{
     style::Style,
     text::{Line, Span},
@@ -33,6 +36,18 @@ pub(super) fn add_config_info(
 
     if !details.environment.is_empty() {
         lines.push(Line::from(""));
+        add_environment(lines, details, theme);
+    }
+}
+
+/// Variable names, overrides of image defaults first. Falls back to the plain
+/// list when the server could not compare with the image.
+fn add_environment(
+    lines: &mut Vec<Line<'static>>,
+    details: &ContainerDetails,
+    theme: &ThemeConfig,
+) {
+    if details.env_sources.is_empty() {
         lines.push(Line::from(Span::styled(
             "Environment:",
             Style::default().fg(theme.selected()),
@@ -48,5 +63,44 @@ pub(super) fn add_config_info(
                 Style::default().fg(theme.dim()),
             )));
         }
+        return;
+    }
+
+    let count = |source: EnvSource| {
+        details
+            .env_sources
+            .iter()
+            .filter(|env| env.source == source)
+            .count()
+    };
+    lines.push(Line::from(vec![
+        Span::styled("Environment: ", Style::default().fg(theme.selected())),
+        Span::styled(
+            format!(
+                "{} overridden, {} set at run time, {} from image",
+                count(EnvSource::Override),
+                count(EnvSource::Runtime),
+                count(EnvSource::Image)
+            ),
+            Style::default().fg(theme.dim()),
+        ),
+    ]));
+
+    let mut vars: Vec<_> = details.env_sources.iter().collect();
+    vars.sort_by_key(|env| match env.source {
+        EnvSource::Override => 0,
+        EnvSource::Runtime => 1,
+        EnvSource::Image => 2,
+    });
+    for env in vars {
+        let (marker, color) = match env.source {
+            EnvSource::Override => ("~", theme.modified()),
+            EnvSource::Runtime => ("+", theme.accent()),
+            EnvSource::Image => (" ", theme.dim()),
+        };
+        lines.push(Line::from(Span::styled(
+            format!("  {} {}", marker, env.key),
+            Style::default().fg(color),
+        )));
     }
 }
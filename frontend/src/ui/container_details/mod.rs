@@ -3,20 +3,31 @@ mod config;
 mod network;
 mod storage;
 
-use crate::state::AppState;
+use crate::state::{AppState, SubPane};
 use ratzilla::ratatui::{
     Frame,
-    layout::Rect,
+    layout::{Margin, Rect},
     style::Style,
-    widgets::{Block, Borders, Paragraph, Wrap},
+    text::Line,
+    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap},
 };
 
 pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
     let theme = &state.current_theme;
+    let focused = state.has_focus(state.focus, SubPane::ContainerDetails);
+    let border_style = if focused {
+        theme.standard_border_focused()
+    } else {
+        Style::default().fg(theme.dim())
+    };
     let block = Block::default()
         .borders(Borders::ALL)
         .title(" Container Details ")
-        .border_style(Style::default().fg(theme.dim()));
+        .border_style(border_style);
+
+    let mut layout = state.layout.borrow_mut();
+    layout.add_sub_pane(SubPane::ContainerDetails, area);
+    layout.details_max_offset = 0;
 
     if let Some(details) = &state.container_details {
         let mut lines = Vec::new();
@@ -25,8 +36,39 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
         storage::add_storage_info(&mut lines, details, theme);
         config::add_config_info(&mut lines, details, theme);
 
-        let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
+        // Long values wrap, so count rows rather than lines
+        let inner = block.inner(area);
+        let rows = wrapped_rows(&lines, inner.width);
+        let max_offset = rows.saturating_sub(inner.height as usize) as u16;
+        let offset = state.details_view.offset.min(max_offset);
+        layout.details_max_offset = max_offset;
+
+        let paragraph = Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: true })
+            .scroll((offset, 0));
         f.render_widget(paragraph, area);
+
+        if max_offset > 0 {
+            let mut scrollbar =
+                ScrollbarState::new(max_offset as usize + 1).position(offset as usize);
+            f.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(None)
+                    .end_symbol(None)
+                    .style(border_style),
+                area.inner(Margin {
+                    vertical: 1,
+                    horizontal: 0,
+                }),
+                &mut scrollbar,
+            );
+        }
+    } else if state.details_view.loading {
+        super::skeleton::render(f, theme, block, area);
+    } else if let Some(error) = &state.details_view.error {
+        let retry_key = &state.keybinds.container_details.refresh;
+        super::error_card::render(f, theme, block, area, error, retry_key);
     } else {
         let paragraph = Paragraph::new("No container selected")
             .block(block)
@@ -34,3 +76,12 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
         f.render_widget(paragraph, area);
     }
 }
+
+/// Rows `lines` take up when wrapped to `width` columns
+fn wrapped_rows(lines: &[Line], width: u16) -> usize {
+    let width = width.max(1) as usize;
+    lines
+        .iter()
+        .map(|line| line.width().div_ceil(width).max(1))
+        .sum()
+}
@@ -14,8 +14,9 @@ pub(super) fn add_network_info(
             "Ports:",
             Style::default().fg(theme.selected()),
         )));
+        let mut link_number = 0;
         for port in &details.ports {
-            lines.push(Line::from(vec![
+            let mut spans = vec![
                 Span::raw("  "),
                 Span::styled(port.host_port.clone(), Style::default().fg(theme.text())),
                 Span::raw(" → "),
@@ -27,7 +28,16 @@ pub(super) fn add_network_info(
                     format!("/{}", port.protocol),
                     Style::default().fg(theme.dim()),
                 ),
-            ]));
+            ];
+            // Numbered to match the 1-9 quick-open keys
+            if let Some(url) = &port.url {
+                link_number += 1;
+                spans.push(Span::styled(
+                    format!("  [{}] {}", link_number, url),
+                    Style::default().fg(theme.accent()),
+                ));
+            }
+            lines.push(Line::from(spans));
         }
         lines.push(Line::from(""));
     }
@@ -0,0 +1,126 @@
+use crate::{
+    state::{AppState, Pane, container_graph::NO_NETWORK},
+    theme::container_list::ContainerListTheme,
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Containers grouped by network, with dependency and shared-volume links
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let graph_state = &state.container_graph;
+    let is_focused = state.is_focused(Pane::ContainerGraph);
+
+    let border_style = if is_focused {
+        ContainerListTheme::border_focused(theme)
+    } else {
+        ContainerListTheme::border_unfocused(theme)
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Container Graph ")
+        .border_style(border_style);
+
+    if graph_state.entries.is_empty() {
+        let message = if graph_state.graph.is_some() {
+            "No containers"
+        } else {
+            "Loading..."
+        };
+        let paragraph = Paragraph::new(message)
+            .block(block)
+            .style(Style::default().fg(theme.dim()));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let mut lines: Vec<Line> = Vec::new();
+    let mut selected_line = 0;
+
+    for (i, entry) in graph_state.entries.iter().enumerate() {
+        // Network header whenever the group changes
+        let new_group = i == 0 || graph_state.entries[i - 1].network != entry.network;
+        if new_group {
+            if i > 0 {
+                lines.push(Line::from(""));
+            }
+            let count = graph_state
+                .entries
+                .iter()
+                .filter(|e| e.network == entry.network)
+                .count();
+            let header_style = if entry.network == NO_NETWORK {
+                Style::default().fg(theme.dim())
+            } else {
+                Style::default().fg(theme.selected())
+            };
+            lines.push(Line::from(Span::styled(
+                format!("{} ({})", entry.network, count),
+                header_style,
+            )));
+        }
+
+        let Some(node) = graph_state.node(&entry.node_id) else {
+            continue;
+        };
+        let is_last = graph_state
+            .entries
+            .get(i + 1)
+            .is_none_or(|next| next.network != entry.network);
+        let branch = if is_last { "  └─ " } else { "  ├─ " };
+
+        let mut spans = vec![
+            Span::styled(branch, Style::default().fg(theme.dim())),
+            Span::styled(node.name.clone(), ContainerListTheme::name_style(theme)),
+            Span::styled(
+                format!(" [{}]", node.state),
+                Style::default().fg(ContainerListTheme::status_color(theme, &node.state)),
+            ),
+        ];
+
+        let deps: Vec<String> = node
+            .depends_on
+            .iter()
+            .filter_map(|id| graph_state.node(id).map(|n| n.name.clone()))
+            .collect();
+        if !deps.is_empty() {
+            spans.push(Span::styled(
+                format!(" → {}", deps.join(", ")),
+                Style::default().fg(theme.accent()),
+            ));
+        }
+
+        for (volume, peer) in graph_state.shared_volume_peers(&node.id) {
+            spans.push(Span::styled(
+                format!(" ⇄ {} ({})", peer, short_volume(&volume)),
+                Style::default().fg(theme.dim()),
+            ));
+        }
+
+        let mut line = Line::from(spans);
+        if i == graph_state.selected_index {
+            line = line.style(ContainerListTheme::highlight_style(theme));
+            selected_line = lines.len();
+        }
+        lines.push(line);
+    }
+
+    // Keep the selection in view (2 rows of border)
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = (selected_line + 1).saturating_sub(visible);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+/// Bind mounts are host paths, show only the last component
+fn short_volume(volume: &str) -> &str {
+    volume.rsplit('/').find(|s| !s.is_empty()).unwrap_or(volume)
+}
@@ -1,48 +1,103 @@
+use super::viewport::{Row, item_indices, visible_rows};
 use crate::{
-    state::{AppState, Pane},
-    theme::container_list::ContainerListTheme,
+    api::ContainerInfo,
+    state::{AppState, Pane, marks::PINNED_HEADER},
+    theme::{ThemeConfig, container_list::ContainerListTheme, toast::ToastTheme},
+    utils::time,
 };
 use ratzilla::ratatui::{
     Frame,
-    layout::Rect,
-    text::Line,
-    widgets::{Block, Borders, List, ListItem, ListState},
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
 };
 
 pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
     let theme = &state.current_theme;
-    let is_focused = state.focus == Pane::ContainerList;
-
-    let items: Vec<ListItem> = state
-        .container_list
-        .containers
-        .iter()
-        .map(|container| {
-            let status_color = ContainerListTheme::status_color(theme, &container.state);
-
-            let short_id = &container.id[..12.min(container.id.len())];
-            let line = Line::from(vec![
-                ratzilla::ratatui::text::Span::styled(
-                    format!("{:<12} ", short_id),
-                    ContainerListTheme::id_style(theme),
-                ),
-                ratzilla::ratatui::text::Span::styled(
-                    format!("{:<15} ", container.name),
-                    ContainerListTheme::name_style(theme),
-                ),
-                ratzilla::ratatui::text::Span::styled(
-                    format!("[{}] ", container.state),
-                    ratzilla::ratatui::style::Style::default().fg(status_color),
-                ),
-                ratzilla::ratatui::text::Span::styled(
-                    &container.status,
-                    ContainerListTheme::status_info_style(theme),
-                ),
-            ]);
-
-            ListItem::new(line)
-        })
-        .collect();
+    let area = render_outage_banner(f, state, area);
+    let is_focused = state.is_focused(Pane::ContainerList);
+
+    let container_list = &state.container_list;
+    let mut rows: Vec<Row> = Vec::new();
+    let mut display_selected_index: Option<usize> = None;
+    let mut last_pinned: Option<bool> = None;
+
+    let has_pinned = !container_list.pinned_names().is_empty();
+    let now_secs = time::now_secs();
+
+    for (idx, container) in container_list.containers.iter().enumerate() {
+        // Section headers only when something is pinned (pinned sort first)
+        let pinned = container_list.is_pinned(container);
+        if has_pinned && last_pinned != Some(pinned) {
+            let header = if pinned { PINNED_HEADER } else { "Containers" };
+            rows.push(Row::Header(header));
+            last_pinned = Some(pinned);
+        }
+        if idx == container_list.selected_index {
+            display_selected_index = Some(rows.len());
+        }
+        rows.push(Row::Item(idx));
+    }
+
+    // Only the lines in view are built
+    let window = visible_rows(
+        rows.len(),
+        display_selected_index,
+        area.height.saturating_sub(2) as usize,
+    );
+    let mut items: Vec<ListItem> = Vec::with_capacity(window.len());
+    for row in &rows[window.clone()] {
+        let idx = match *row {
+            Row::Header(header) => {
+                items.push(ListItem::new(Line::from(
+                    ratzilla::ratatui::text::Span::styled(
+                        header,
+                        ContainerListTheme::header_style(theme),
+                    ),
+                )));
+                continue;
+            }
+            Row::Item(idx) => idx,
+        };
+        let container = &container_list.containers[idx];
+
+        let status_color = ContainerListTheme::status_color(theme, &container.state);
+
+        let name_style = if container_list.is_hidden(container) {
+            ContainerListTheme::hidden_style(theme)
+        } else {
+            ContainerListTheme::name_style(theme)
+        };
+        let short_id = &container.id[..12.min(container.id.len())];
+        let mut spans = vec![
+            ratzilla::ratatui::text::Span::styled(
+                format!("{:<12} ", short_id),
+                ContainerListTheme::id_style(theme),
+            ),
+            ratzilla::ratatui::text::Span::styled(format!("{:<15} ", container.name), name_style),
+            ratzilla::ratatui::text::Span::styled(
+                format!("[{}] ", container.state),
+                ratzilla::ratatui::style::Style::default().fg(status_color),
+            ),
+            ratzilla::ratatui::text::Span::styled(
+                status_text(container, now_secs),
+                ContainerListTheme::status_info_style(theme),
+            ),
+        ];
+        if let Some(badge) = health_badge(container, theme) {
+            spans.push(ratzilla::ratatui::text::Span::raw(" "));
+            spans.push(badge);
+        }
+        if let Some(url) = &container.service_url {
+            spans.push(ratzilla::ratatui::text::Span::styled(
+                format!(" {}", url),
+                ContainerListTheme::service_url_style(theme),
+            ));
+        }
+        let line = Line::from(spans);
+
+        items.push(ListItem::new(line));
+    }
 
     let border_style = if is_focused {
         ContainerListTheme::border_focused(theme)
@@ -52,15 +107,103 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(" Containers ")
+        .title(if container_list.show_hidden {
+            " Containers (+hidden) "
+        } else {
+            " Containers "
+        })
         .border_style(border_style);
+    let inner = block.inner(area);
+
+    if container_list.all.is_empty() {
+        if let Some(error) = &container_list.error {
+            // The restart key retries while there is nothing to restart
+            let retry_key = &state.keybinds.container_list.restart_container;
+            super::error_card::render(f, theme, block, area, error, retry_key);
+            return;
+        }
+        if container_list.loading {
+            super::skeleton::render(f, theme, block, area);
+            return;
+        }
+    }
 
     let list = List::new(items)
         .block(block)
         .highlight_style(ContainerListTheme::highlight_style(theme));
 
     let mut list_state = ListState::default();
-    list_state.select(Some(state.container_list.selected_index));
+    list_state.select(display_selected_index.map(|row| row.saturating_sub(window.start)));
 
     f.render_stateful_widget(list, area, &mut list_state);
+
+    state.layout.borrow_mut().add_list(
+        Pane::ContainerList,
+        inner,
+        window.start,
+        item_indices(&rows),
+    );
+}
+
+/// One line above the list while the docker daemon is gone, the list below
+/// keeps the containers from before. Returns the area left for the list.
+fn render_outage_banner(f: &mut Frame, state: &AppState, area: Rect) -> Rect {
+    let Some(outage) = &state.container_list.outage else {
+        return area;
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+    let lost = time::format_age(time::now_secs().saturating_sub(outage.since));
+    let banner = Paragraph::new(format!(
+        "docker daemon lost {} — reconnecting every {}s",
+        lost, outage.retry_secs
+    ))
+    .style(ToastTheme::offline_banner_style(&state.current_theme));
+    f.render_widget(banner, chunks[0]);
+    chunks[1]
+}
+
+/// `[healthy]`, `[unhealthy]` or `[starting]` in the verdict's color, None
+/// without a health check
+pub(super) fn health_badge(
+    container: &ContainerInfo,
+    theme: &ThemeConfig,
+) -> Option<Span<'static>> {
+    let health = container.health.as_deref()?;
+    Some(Span::styled(
+        format!("[{}]", health),
+        ContainerListTheme::health_style(theme, health),
+    ))
+}
+
+/// Uptime or last exit from the structured status fields, the age of a
+/// container that never ran, docker's own text where they say nothing
+/// (e.g. an older server)
+pub(super) fn status_text(container: &ContainerInfo, now_secs: u64) -> String {
+    if let Some(started_at) = container.started_at {
+        return format!(
+            "up {}",
+            time::format_uptime(now_secs.saturating_sub(started_at))
+        );
+    }
+    let Some(code) = container.exit_code else {
+        return match container.created_at {
+            Some(created_at) if container.state == "created" => format!(
+                "created {}",
+                time::format_age(now_secs.saturating_sub(created_at))
+            ),
+            _ => container.status.clone(),
+        };
+    };
+    let ago = container
+        .exited_at
+        .map(|exited_at| format!(" {}", time::format_age(now_secs.saturating_sub(exited_at))))
+        .unwrap_or_default();
+    if container.state == "restarting" {
+        format!("restarting, exited ({}){}", code, ago)
+    } else {
+        format!("exited ({}){}", code, ago)
+    }
 }
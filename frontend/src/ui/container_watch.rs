@@ -0,0 +1,256 @@
+use crate::{
+    api::ContainerWatchResponse,
+    state::{AppState, Pane},
+    theme::{
+        ThemeConfig, container_list::ContainerListTheme, container_watch::ContainerWatchTheme,
+    },
+    utils::time::{format_age, format_duration, now_secs},
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Header with state and stats above the recent events and the log tail
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let watch = &state.container_watch;
+    let border_style = if state.is_focused(Pane::ContainerWatch) {
+        ContainerWatchTheme::border_focused(theme)
+    } else {
+        ContainerWatchTheme::border_unfocused(theme)
+    };
+
+    let mut title = vec![Span::raw(format!(" Watch: {} ", watch.name))];
+    if watch.paused {
+        title.push(Span::styled(
+            "[paused] ",
+            ContainerWatchTheme::paused_style(theme),
+        ));
+    }
+    let header = Block::default()
+        .borders(Borders::ALL)
+        .title(Line::from(title))
+        .border_style(border_style);
+
+    let Some(data) = &watch.data else {
+        if let Some(error) = &watch.error {
+            render_error(f, theme, header, area, error);
+        } else {
+            super::skeleton::render(f, theme, header, area);
+        }
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5), // State + stats (+ poll error)
+            Constraint::Min(0),    // Events + logs
+        ])
+        .split(area);
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(30), // Events
+            Constraint::Percentage(1),  // Empty gap
+            Constraint::Percentage(69), // Logs
+        ])
+        .split(chunks[1]);
+
+    render_header(f, state, data, header, chunks[0]);
+    render_events(f, theme, data, border_style, body[0]);
+    render_logs(f, state, data, border_style, body[2]);
+}
+
+fn render_error(f: &mut Frame, theme: &ThemeConfig, block: Block, area: Rect, error: &str) {
+    let lines = vec![
+        Line::default(),
+        Line::from(Span::styled(
+            " Watching failed, retrying every second",
+            ContainerWatchTheme::warning_style(theme),
+        )),
+        Line::default(),
+        Line::from(Span::styled(
+            format!(" {}", error),
+            ContainerWatchTheme::line_style(theme),
+        )),
+    ];
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn render_header(
+    f: &mut Frame,
+    state: &AppState,
+    data: &ContainerWatchResponse,
+    block: Block,
+    area: Rect,
+) {
+    let theme = &state.current_theme;
+    let label =
+        |text: &str| Span::styled(text.to_string(), ContainerWatchTheme::label_style(theme));
+    let value = |text: String| Span::styled(text, ContainerWatchTheme::value_style(theme));
+
+    let mut status = vec![
+        label(" State: "),
+        Span::styled(
+            data.state.clone(),
+            Style::default().fg(ContainerListTheme::status_color(theme, &data.state)),
+        ),
+    ];
+    if let Some(health) = &data.health {
+        let style = if health == "healthy" {
+            ContainerWatchTheme::healthy_style(theme)
+        } else {
+            ContainerWatchTheme::warning_style(theme)
+        };
+        status.extend([label("  Health: "), Span::styled(health.clone(), style)]);
+    }
+    let restarts_style = if data.restart_count > 0 {
+        ContainerWatchTheme::warning_style(theme)
+    } else {
+        ContainerWatchTheme::value_style(theme)
+    };
+    status.extend([
+        label("  Restarts: "),
+        Span::styled(data.restart_count.to_string(), restarts_style),
+    ]);
+    if let Some(uptime) = uptime(data) {
+        status.extend([label("  Up: "), value(format_duration(uptime))]);
+    }
+
+    let stats = match &data.stats {
+        Some(stats) => Line::from(vec![
+            label(" CPU: "),
+            value(format!("{:.1}%", stats.cpu_percent)),
+            label("  Mem: "),
+            value(format!("{:.1}% ({})", stats.mem_percent, stats.mem_usage)),
+            label("  Net: "),
+            value(stats.net_io.clone()),
+            label("  Block: "),
+            value(stats.block_io.clone()),
+            label("  PIDs: "),
+            value(stats.pids.to_string()),
+        ]),
+        None => Line::from(Span::styled(
+            " No stats while the container is not running",
+            ContainerWatchTheme::empty_style(theme),
+        )),
+    };
+
+    let mut lines = vec![Line::from(status), stats];
+    // The last answer stays on screen, say that it is getting old
+    if let Some(error) = &state.container_watch.error {
+        lines.push(Line::from(Span::styled(
+            format!(" Update failed, retrying: {}", error),
+            ContainerWatchTheme::warning_style(theme),
+        )));
+    }
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Seconds since the last start, from docker's RFC 3339 StartedAt
+fn uptime(data: &ContainerWatchResponse) -> Option<u64> {
+    if data.state != "running" {
+        return None;
+    }
+    let started_ms = js_sys::Date::parse(&data.started_at);
+    if started_ms.is_nan() {
+        return None;
+    }
+    Some(now_secs().saturating_sub((started_ms / 1000.0) as u64))
+}
+
+/// Latest event first
+fn render_events(
+    f: &mut Frame,
+    theme: &ThemeConfig,
+    data: &ContainerWatchResponse,
+    border_style: Style,
+    area: Rect,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Events (last hour) ")
+        .border_style(border_style);
+
+    if data.events.is_empty() {
+        let paragraph = Paragraph::new("No events")
+            .block(block)
+            .style(ContainerWatchTheme::empty_style(theme));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let now = now_secs();
+    let lines: Vec<Line> = data
+        .events
+        .iter()
+        .rev()
+        .map(|event| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:>9} ", format_age(now.saturating_sub(event.time))),
+                    ContainerWatchTheme::time_style(theme),
+                ),
+                Span::styled(event.action.clone(), ContainerWatchTheme::line_style(theme)),
+            ])
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Log tail that follows new lines unless scrolled up
+fn render_logs(
+    f: &mut Frame,
+    state: &AppState,
+    data: &ContainerWatchResponse,
+    border_style: Style,
+    area: Rect,
+) {
+    let theme = &state.current_theme;
+    let scroll = state.container_watch.log_scroll;
+    let title = if scroll > 0 {
+        format!(" Logs ({} lines up) ", scroll)
+    } else {
+        " Logs ".to_string()
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(border_style);
+
+    if data.logs.is_empty() {
+        let paragraph = Paragraph::new("No log output")
+            .block(block)
+            .style(ContainerWatchTheme::empty_style(theme));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let lines: Vec<Line> = data.logs.iter().map(|line| log_line(theme, line)).collect();
+
+    // Bottom of the tail minus what was scrolled up (2 rows of border)
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = lines.len().saturating_sub(visible).saturating_sub(scroll);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+/// "HH:MM:SS message" from docker's "<RFC 3339 timestamp> message"
+fn log_line<'a>(theme: &ThemeConfig, line: &'a str) -> Line<'a> {
+    let (time, message) = match line.split_once(' ') {
+        Some((stamp, message)) => (stamp.get(11..19).unwrap_or(stamp), message),
+        None => ("", line),
+    };
+    Line::from(vec![
+        Span::styled(format!("{} ", time), ContainerWatchTheme::time_style(theme)),
+        Span::styled(message, ContainerWatchTheme::line_style(theme)),
+    ])
+}
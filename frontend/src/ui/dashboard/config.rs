@@ -0,0 +1,73 @@
+use ratzilla::ratatui::layout::Constraint;
+use serde::Deserialize;
+
+/// Dashboard layout from sys/layout/dashboard.toml
+#[derive(Debug, Clone, Deserialize)]
+pub struct DashboardLayout {
+    pub rows: Vec<RowConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RowConfig {
+    /// Height in lines, omitted rows share the remaining space
+    #[serde(default)]
+    pub height: Option<u16>,
+    pub widgets: Vec<WidgetConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WidgetConfig {
+    #[serde(rename = "type")]
+    pub kind: WidgetKind,
+    /// Width in percent of the row, omitted widgets share the remaining space
+    #[serde(default)]
+    pub width: Option<u16>,
+    /// Block title, defaults to the widget's name
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Maximum number of entries for list widgets
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WidgetKind {
+    HostStats,
+    UnhealthyContainers,
+    RecentChanges,
+    Alerts,
+    CertExpiries,
+    QuickActions,
+    Pinned,
+}
+
+impl RowConfig {
+    pub fn constraint(&self) -> Constraint {
+        match self.height {
+            Some(lines) => Constraint::Length(lines),
+            None => Constraint::Fill(1),
+        }
+    }
+}
+
+impl WidgetConfig {
+    pub fn constraint(&self) -> Constraint {
+        match self.width {
+            Some(percent) => Constraint::Percentage(percent),
+            None => Constraint::Fill(1),
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        self.title.as_deref().unwrap_or(match self.kind {
+            WidgetKind::HostStats => "Host",
+            WidgetKind::UnhealthyContainers => "Unhealthy Containers",
+            WidgetKind::RecentChanges => "Recent Changes",
+            WidgetKind::Alerts => "Alerts",
+            WidgetKind::CertExpiries => "Certificates",
+            WidgetKind::QuickActions => "Quick Actions",
+            WidgetKind::Pinned => "Pinned",
+        })
+    }
+}
@@ -0,0 +1,51 @@
+mod config;
+mod widgets;
+
+use crate::{state::AppState, theme::dashboard::DashboardTheme};
+use config::DashboardLayout;
+use ratzilla::ratatui::{
+    Frame,
+    layout::{Direction, Layout, Rect},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Dashboard composed of the widgets declared in dashboard.toml
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    // Layout file is selected at build time (see build_helpers/dashboard.rs)
+    let config_toml = include_str!(env!("DASHBOARD_CONFIG_PATH"));
+
+    let layout: DashboardLayout = match toml::from_str(config_toml) {
+        Ok(layout) => layout,
+        Err(e) => {
+            let paragraph = Paragraph::new(format!("dashboard.toml parse error: {}", e))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(" Dashboard ")
+                        .border_style(DashboardTheme::border_style(&state.current_theme)),
+                )
+                .style(DashboardTheme::alert_style(
+                    &state.current_theme,
+                    crate::api::AlertLevel::Critical,
+                ));
+            f.render_widget(paragraph, area);
+            return;
+        }
+    };
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(layout.rows.iter().map(|row| row.constraint()))
+        .split(area);
+
+    for (row, row_area) in layout.rows.iter().zip(rows.iter()) {
+        let cells = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(row.widgets.iter().map(|widget| widget.constraint()))
+            .split(*row_area);
+
+        for (widget, cell) in row.widgets.iter().zip(cells.iter()) {
+            widgets::render(f, state, widget, *cell);
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use super::config::{WidgetConfig, WidgetKind};
+use crate::{
+    api::{AlertLevel, DashboardResponse},
+    state::{AppState, dashboard::MAX_QUICK_ACTIONS},
+    theme::{ThemeConfig, container_list::ContainerListTheme, dashboard::DashboardTheme},
+    utils::time::{format_age, format_duration},
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+pub fn render(f: &mut Frame, state: &AppState, widget: &WidgetConfig, area: Rect) {
+    let theme = &state.current_theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {} ", widget.title()))
+        .border_style(DashboardTheme::border_style(theme));
+
+    let lines = match &state.dashboard.data {
+        Some(data) => {
+            let now_secs = (state.dashboard.fetched_at / 1000.0) as u64;
+            let lines = match widget.kind {
+                WidgetKind::HostStats => host_stats(data, theme),
+                WidgetKind::UnhealthyContainers => unhealthy_containers(data, theme, now_secs),
+                WidgetKind::RecentChanges => recent_changes(data, theme, now_secs),
+                WidgetKind::Alerts => alerts(data, theme),
+                WidgetKind::CertExpiries => cert_expiries(data, theme),
+                WidgetKind::QuickActions => quick_actions(data, theme),
+                WidgetKind::Pinned => pinned(state, theme),
+            };
+            match widget.limit {
+                Some(limit) => lines.into_iter().take(limit).collect(),
+                None => lines,
+            }
+        }
+        None => vec![Line::from(Span::styled(
+            "Loading...",
+            DashboardTheme::empty_style(theme),
+        ))],
+    };
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn host_stats(data: &DashboardResponse, theme: &ThemeConfig) -> Vec<Line<'static>> {
+    let Some(host) = &data.host else {
+        return vec![empty("Host stats unavailable", theme)];
+    };
+
+    let load = host
+        .load_avg
+        .iter()
+        .map(|l| format!("{:.2}", l))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mem_used_kb = host.mem_total_kb.saturating_sub(host.mem_available_kb);
+    let mem_percent = (mem_used_kb * 100)
+        .checked_div(host.mem_total_kb)
+        .unwrap_or(0);
+
+    vec![
+        field("host", host.hostname.clone(), theme),
+        field("uptime", format_duration(host.uptime_secs), theme),
+        field("load", format!("{} ({} CPUs)", load, host.cpu_count), theme),
+        field(
+            "memory",
+            format!(
+                "{} / {} MiB ({}%)",
+                mem_used_kb / 1024,
+                host.mem_total_kb / 1024,
+                mem_percent
+            ),
+            theme,
+        ),
+    ]
+}
+
+fn unhealthy_containers(
+    data: &DashboardResponse,
+    theme: &ThemeConfig,
+    now_secs: u64,
+) -> Vec<Line<'static>> {
+    if data.unhealthy_containers.is_empty() {
+        return vec![Line::from(Span::styled(
+            "All containers healthy",
+            DashboardTheme::ok_style(theme),
+        ))];
+    }
+
+    data.unhealthy_containers
+        .iter()
+        .map(|container| {
+            let mut spans = vec![
+                Span::styled(
+                    format!("{} ", container.name),
+                    DashboardTheme::name_style(theme),
+                ),
+                Span::styled(
+                    format!("[{}] ", container.state),
+                    Style::default().fg(ContainerListTheme::status_color(theme, &container.state)),
+                ),
+                Span::styled(
+                    super::super::container_list::status_text(container, now_secs),
+                    DashboardTheme::label_style(theme),
+                ),
+            ];
+            if let Some(badge) = super::super::container_list::health_badge(container, theme) {
+                spans.push(Span::raw(" "));
+                spans.push(badge);
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+fn recent_changes(
+    data: &DashboardResponse,
+    theme: &ThemeConfig,
+    now_secs: u64,
+) -> Vec<Line<'static>> {
+    if data.recent_changes.is_empty() {
+        return vec![empty("No managed files", theme)];
+    }
+
+    data.recent_changes
+        .iter()
+        .map(|change| {
+            let age = now_secs.saturating_sub(change.modified);
+            Line::from(vec![
+                Span::styled(
+                    format!("{:>8} ", format_age(age)),
+                    DashboardTheme::label_style(theme),
+                ),
+                Span::styled(change.name.clone(), DashboardTheme::name_style(theme)),
+            ])
+        })
+        .collect()
+}
+
+/// Single row of numbered shortcuts ("[1] Restart traefik  [2] ...")
+fn quick_actions(data: &DashboardResponse, theme: &ThemeConfig) -> Vec<Line<'static>> {
+    if data.quick_actions.is_empty() {
+        return vec![empty("No quick actions configured", theme)];
+    }
+
+    let spans = data
+        .quick_actions
+        .iter()
+        .take(MAX_QUICK_ACTIONS)
+        .enumerate()
+        .flat_map(|(i, action)| {
+            [
+                Span::styled(
+                    format!("[{}] ", i + 1),
+                    DashboardTheme::shortcut_style(theme),
+                ),
+                Span::styled(
+                    format!("{}  ", action.label),
+                    DashboardTheme::name_style(theme),
+                ),
+            ]
+        })
+        .collect::<Vec<_>>();
+    vec![Line::from(spans)]
+}
+
+/// Pinned files and containers, with the last known container state
+fn pinned(state: &AppState, theme: &ThemeConfig) -> Vec<Line<'static>> {
+    let files = state.file_list.pinned.names();
+    let containers = state.container_list.pinned_names();
+    if files.is_empty() && containers.is_empty() {
+        return vec![empty("Nothing pinned (* in file or container list)", theme)];
+    }
+
+    let file_lines = files.iter().map(|name| {
+        Line::from(vec![
+            Span::styled("file      ", DashboardTheme::label_style(theme)),
+            Span::styled(name.clone(), DashboardTheme::name_style(theme)),
+        ])
+    });
+    let container_lines = containers.iter().map(|name| {
+        let mut spans = vec![
+            Span::styled("container ", DashboardTheme::label_style(theme)),
+            Span::styled(format!("{} ", name), DashboardTheme::name_style(theme)),
+        ];
+        if let Some(container) = state.container_list.all.iter().find(|c| &c.name == name) {
+            spans.push(Span::styled(
+                format!("[{}]", container.state),
+                Style::default().fg(ContainerListTheme::status_color(theme, &container.state)),
+            ));
+        }
+        Line::from(spans)
+    });
+    file_lines.chain(container_lines).collect()
+}
+
+fn alerts(data: &DashboardResponse, theme: &ThemeConfig) -> Vec<Line<'static>> {
+    if data.alerts.is_empty() {
+        return vec![Line::from(Span::styled(
+            "No alerts",
+            DashboardTheme::ok_style(theme),
+        ))];
+    }
+
+    data.alerts
+        .iter()
+        .map(|alert| {
+            let marker = match alert.level {
+                AlertLevel::Critical => "!! ",
+                AlertLevel::Warning => " ! ",
+            };
+            Line::from(Span::styled(
+                format!("{}{}", marker, alert.message),
+                DashboardTheme::alert_style(theme, alert.level),
+            ))
+        })
+        .collect()
+}
+
+fn cert_expiries(data: &DashboardResponse, theme: &ThemeConfig) -> Vec<Line<'static>> {
+    if data.cert_expiries.is_empty() {
+        return vec![empty("No certificates configured", theme)];
+    }
+
+    data.cert_expiries
+        .iter()
+        .map(|cert| {
+            let (text, style) = match (cert.days_left, &cert.error) {
+                (Some(days), _) if days < 0 => (
+                    format!("expired {} days ago", -days),
+                    DashboardTheme::alert_style(theme, AlertLevel::Critical),
+                ),
+                (Some(days), _) => (
+                    format!("{} days left", days),
+                    DashboardTheme::value_style(theme),
+                ),
+                (None, Some(error)) => (
+                    error.clone(),
+                    DashboardTheme::alert_style(theme, AlertLevel::Warning),
+                ),
+                (None, None) => ("unknown".to_string(), DashboardTheme::label_style(theme)),
+            };
+            Line::from(vec![
+                Span::styled(format!("{} ", cert.name), DashboardTheme::name_style(theme)),
+                Span::styled(text, style),
+            ])
+        })
+        .collect()
+}
+
+fn field(label: &str, value: String, theme: &ThemeConfig) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("{:<8}", label), DashboardTheme::label_style(theme)),
+        Span::styled(value, DashboardTheme::value_style(theme)),
+    ])
+}
+
+fn empty(message: &'static str, theme: &ThemeConfig) -> Line<'static> {
+    Line::from(Span::styled(message, DashboardTheme::empty_style(theme)))
+}
@@ -1,33 +1,680 @@
 use crate::{
-    state::{AppState, Pane},
-    theme::editor::EditorTheme,
+    api::{LintSeverity, WritePermissionError},
+    state::{
+        AppState, EditorState, Pane,
+        kv_table::{KvColumn, KvLine, KvTable},
+        rendered::RenderedView,
+        schema_form::SchemaForm,
+    },
+    theme::{ThemeConfig, editor::EditorTheme},
 };
 use ratzilla::ratatui::{
     Frame,
-    layout::Rect,
-    widgets::{Block, Borders},
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
 };
+use std::ops::Range;
+use sysrat_api_types::SECRET_MASK;
 
 pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
     let theme = &state.current_theme;
-    let is_focused = state.focus == Pane::Editor;
+    let is_focused = state.is_focused(Pane::Editor);
     let border_style = EditorTheme::border_style(theme, state.vim_mode, is_focused);
 
     let title = if let Some(filename) = &state.editor.current_file {
         let dirty_marker = if state.dirty { " [+]" } else { "" };
-        format!("{}{}", filename, dirty_marker)
+        let secrets_marker = match (state.editor.redacted, state.editor.revealed) {
+            (true, _) => " [secrets masked]",
+            (false, true) => " [secrets revealed]",
+            _ => "",
+        };
+        let recording_marker = match state.macros.recording() {
+            Some(register) => format!(" [recording @{}]", register),
+            None => String::new(),
+        };
+        format!(
+            "{}{}{}{}",
+            filename, dirty_marker, secrets_marker, recording_marker
+        )
     } else {
         "No file loaded".to_string()
     };
 
-    let textarea_widget = &state.editor.textarea;
-    let mut widget_with_block = textarea_widget.clone();
-    widget_with_block.set_block(
-        Block::default()
-            .title(title)
-            .borders(Borders::ALL)
-            .border_style(border_style),
+    if let Some(table) = &state.editor.table {
+        render_table(f, table, theme, title, border_style, area);
+        return;
+    }
+
+    // Raw text stays visible next to the form and follows its edits
+    let (text_area, text_border) = if let Some(form) = &state.editor.form {
+        let chunks = split_half(area);
+        render_form(f, form, theme, "Form", border_style, chunks[1]);
+        (chunks[0], theme.standard_border_unfocused())
+    } else if state.editor.preview.is_some() {
+        let chunks = split_half(area);
+        // The frame sits above the grid, so it steps aside for modals
+        let covered = state.overlay.is_open() || state.confirms.current().is_some();
+        render_preview_frame(f, theme, chunks[1], covered);
+        (chunks[0], border_style)
+    } else if let Some(rendered) = &state.editor.rendered {
+        let chunks = split_half(area);
+        // Follows the raw text's scrolling as of the last frame
+        let top = state.editor.scroll_top.get();
+        render_rendered(f, rendered, state.dirty, theme, top, chunks[1]);
+        (chunks[0], border_style)
+    } else {
+        (area, border_style)
+    };
+
+    // A refused save is explained below the text until the next save or load
+    let text_area = match &state.editor.write_denied {
+        Some(denied) => {
+            let [text, panel] =
+                Layout::vertical([Constraint::Min(3), Constraint::Length(6)]).areas(text_area);
+            render_write_denied(f, denied, theme, panel);
+            text
+        }
+        None => text_area,
+    };
+
+    let mut block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(text_border);
+    if let Some(summary) = lint_summary(&state.editor, theme) {
+        block = block.title_bottom(summary);
+    }
+    if state.editor.current_file.is_some() {
+        block = block.title_bottom(format_indicator(&state.editor, theme));
+    }
+    let inner = block.inner(text_area);
+    f.render_widget(block, text_area);
+
+    // Line numbers and lint marks share the gutter left of the text
+    let editor = &state.editor;
+    let numbers = editor
+        .line_numbers
+        .then(|| number_width(editor.textarea.lines().len()));
+    let lint_width = if editor.lint.is_empty() { 0 } else { 2 };
+    let [gutter, text] = Layout::horizontal([
+        Constraint::Length(numbers.unwrap_or(0) + lint_width),
+        Constraint::Min(1),
+    ])
+    .areas(inner);
+
+    if editor.wrap {
+        render_wrapped(f, editor, theme, numbers, gutter, text);
+        return;
+    }
+
+    // The textarea is rendered in place so its scrolling persists and the
+    // gutter can follow it; it only draws the rows in view, long files cost
+    // no more
+    let top = editor.visible_top(text.height) as usize;
+    if !gutter.is_empty() {
+        let marks: Vec<Line> = (top..top + text.height as usize)
+            .map(|row| gutter_line(editor, theme, numbers, row, true))
+            .collect();
+        f.render_widget(Paragraph::new(marks), gutter);
+    }
+    f.render_widget(&editor.textarea, text);
+    mark_whitespace(f, editor, theme, text);
+    mark_secrets(f, editor, theme, text);
+}
+
+/// Columns of the line numbers: the widest one and a space
+fn number_width(lines: usize) -> u16 {
+    lines.max(1).to_string().len() as u16 + 1
+}
+
+/// Gutter of text row `row`: its number unless `first` is false (for the
+/// continuation rows of a wrapped line) and its lint mark
+fn gutter_line<'a>(
+    editor: &EditorState,
+    theme: &ThemeConfig,
+    numbers: Option<u16>,
+    row: usize,
+    first: bool,
+) -> Line<'a> {
+    let mut spans = Vec::new();
+    if let Some(width) = numbers {
+        let number = if first && row < editor.textarea.lines().len() {
+            format!("{:>1$} ", row + 1, width as usize - 1)
+        } else {
+            " ".repeat(width as usize)
+        };
+        spans.push(Span::styled(number, EditorTheme::line_number_style(theme)));
+    }
+    if !editor.lint.is_empty() {
+        let mut marked = editor
+            .lint
+            .iter()
+            .filter(|warning| first && warning.line == Some(row + 1))
+            .peekable();
+        spans.push(if marked.peek().is_none() {
+            Span::raw("  ")
+        } else if marked.any(|warning| warning.severity == LintSeverity::Error) {
+            Span::styled("! ", EditorTheme::lint_error_style(theme))
+        } else {
+            Span::styled("! ", EditorTheme::lint_style(theme))
+        });
+    }
+    Line::from(spans)
+}
+
+/// Long lines broken over as many rows as they need. The textarea cannot
+/// wrap, so the text is drawn here and the cursor placed by hand; the keys
+/// still edit the textarea
+fn render_wrapped(
+    f: &mut Frame,
+    editor: &EditorState,
+    theme: &ThemeConfig,
+    numbers: Option<u16>,
+    gutter: Rect,
+    area: Rect,
+) {
+    let width = area.width.max(1) as usize;
+    let height = area.height as usize;
+    let lines = editor.textarea.lines();
+    let (cursor_row, cursor_col) = editor.textarea.cursor();
+    let cursor_column = display_column(editor, &lines[cursor_row], cursor_col);
+    // The cursor past the end of a full row starts another one
+    let rows_of = |row: usize| {
+        let cells = display_column(editor, &lines[row], usize::MAX);
+        let cells = if row == cursor_row {
+            cells.max(cursor_column + 1)
+        } else {
+            cells
+        };
+        cells.div_ceil(width).max(1)
+    };
+
+    // Scroll by whole lines until the cursor's row is in view
+    let mut top = editor.visible_top(area.height) as usize;
+    while top < cursor_row && (top..=cursor_row).map(rows_of).sum::<usize>() > height {
+        top += 1;
+    }
+    editor.scroll_top.set(top as u16);
+
+    let mut text = Vec::new();
+    let mut marks = Vec::new();
+    let mut cursor = None;
+    for (row, line) in lines.iter().enumerate().skip(top) {
+        let cells = display_cells(editor, theme, line);
+        for chunk in 0..rows_of(row) {
+            if text.len() == height {
+                break;
+            }
+            if row == cursor_row && cursor_column / width == chunk {
+                cursor = Some((cursor_column % width, text.len()));
+            }
+            let start = (chunk * width).min(cells.len());
+            let end = (start + width).min(cells.len());
+            text.push(Line::from(cells[start..end].to_vec()));
+            marks.push(gutter_line(editor, theme, numbers, row, chunk == 0));
+        }
+        if text.len() == height {
+            break;
+        }
+    }
+
+    f.render_widget(Paragraph::new(marks), gutter);
+    f.render_widget(Paragraph::new(text), area);
+    if let Some((x, y)) = cursor {
+        f.buffer_mut()[(area.x + x as u16, area.y + y as u16)]
+            .set_style(EditorTheme::wrapped_cursor_style());
+    }
+}
+
+/// One span per screen column of `line`, tabs expanded to the next tab stop
+/// and whitespace marked while marks are shown
+fn display_cells<'a>(editor: &EditorState, theme: &ThemeConfig, line: &str) -> Vec<Span<'a>> {
+    let tab = editor.indent.width.max(1) as usize;
+    let style = EditorTheme::whitespace_style(theme);
+    let masks = masked_ranges(editor, line);
+    let mut cells = Vec::new();
+    for (i, c) in line.char_indices() {
+        if masks.iter().any(|range| range.contains(&i)) {
+            cells.push(Span::styled(
+                c.to_string(),
+                EditorTheme::secret_style(theme),
+            ));
+            continue;
+        }
+        match c {
+            '\t' => {
+                let width = tab - cells.len() % tab;
+                let mark = if editor.show_whitespace { "→" } else { " " };
+                cells.push(Span::styled(mark, style));
+                cells.extend((1..width).map(|_| Span::raw(" ")));
+            }
+            ' ' if editor.show_whitespace => cells.push(Span::styled("·", style)),
+            c => cells.push(Span::raw(c.to_string())),
+        }
+    }
+    cells
+}
+
+/// Screen column of character `col` of `line`
+fn display_column(editor: &EditorState, line: &str, col: usize) -> usize {
+    let tab = editor.indent.width.max(1) as usize;
+    line.chars().take(col).fold(0, |column, c| {
+        if c == '\t' {
+            column + tab - column % tab
+        } else {
+            column + 1
+        }
+    })
+}
+
+/// Draw spaces as `·` and tabs as `→` over the rendered text, which expands
+/// tabs to the next tab stop
+fn mark_whitespace(f: &mut Frame, editor: &EditorState, theme: &ThemeConfig, area: Rect) {
+    if !editor.show_whitespace {
+        return;
+    }
+    let top = editor.scroll_top.get() as usize;
+    let left = editor.visible_left(area.width) as usize;
+    let tab = editor.indent.width.max(1) as usize;
+    let style = EditorTheme::whitespace_style(theme);
+    let buffer = f.buffer_mut();
+
+    for (y, line) in (area.y..area.bottom()).zip(editor.textarea.lines().iter().skip(top)) {
+        let mut column = 0;
+        for c in line.chars() {
+            let width = if c == '\t' { tab - column % tab } else { 1 };
+            let mark = match c {
+                ' ' => Some("·"),
+                '\t' => Some("→"),
+                _ => None,
+            };
+            if let Some(mark) = mark
+                && column >= left
+                && column - left < area.width as usize
+            {
+                buffer[(area.x + (column - left) as u16, y)]
+                    .set_symbol(mark)
+                    .set_style(style);
+            }
+            column += width;
+        }
+    }
+}
+
+/// Byte ranges of the masks in `line` while the server masked secrets
+fn masked_ranges(editor: &EditorState, line: &str) -> Vec<Range<usize>> {
+    if !editor.redacted {
+        return Vec::new();
+    }
+    line.match_indices(SECRET_MASK)
+        .map(|(start, mask)| start..start + mask.len())
+        .collect()
+}
+
+/// Style the masked secrets over the rendered text
+fn mark_secrets(f: &mut Frame, editor: &EditorState, theme: &ThemeConfig, area: Rect) {
+    if !editor.redacted {
+        return;
+    }
+    let top = editor.scroll_top.get() as usize;
+    let left = editor.visible_left(area.width) as usize;
+    let style = EditorTheme::secret_style(theme);
+    let buffer = f.buffer_mut();
+
+    for (y, line) in (area.y..area.bottom()).zip(editor.textarea.lines().iter().skip(top)) {
+        for range in masked_ranges(editor, line) {
+            let start = display_column(editor, line, line[..range.start].chars().count());
+            let columns = start..start + SECRET_MASK.len();
+            for column in columns.filter(|&c| c >= left && c - left < area.width as usize) {
+                buffer[(area.x + (column - left) as u16, y)].set_style(style);
+            }
+        }
+    }
+}
+
+/// The warning on the cursor line, or how many there are for the file
+fn lint_summary<'a>(editor: &EditorState, theme: &ThemeConfig) -> Option<Line<'a>> {
+    let cursor_line = editor.textarea.cursor().0 + 1;
+    let text = match editor
+        .lint
+        .iter()
+        .find(|warning| warning.line == Some(cursor_line))
+    {
+        Some(warning) => format!(" {}/{}: {} ", warning.pack, warning.rule, warning.message),
+        None => match editor.lint.len() {
+            0 => return None,
+            1 => format!(" 1 lint warning: {} ", editor.lint[0].message),
+            n => format!(" {} lint warnings ", n),
+        },
+    };
+    Some(Line::from(Span::styled(
+        text,
+        EditorTheme::lint_style(theme),
+    )))
+}
+
+/// Encoding and line endings in the bottom right, marked with `*` while a
+/// conversion waits for the next save
+fn format_indicator<'a>(editor: &EditorState, theme: &ThemeConfig) -> Line<'a> {
+    let format = editor.format;
+    let (marker, style) = match editor.format_change() {
+        Some(_) => ("*", EditorTheme::lint_style(theme)),
+        None => ("", EditorTheme::table_comment_style(theme)),
+    };
+    Line::from(Span::styled(
+        format!(
+            " {} · {}{} · {} ",
+            format.encoding.label(),
+            format.line_ending.label(),
+            marker,
+            editor.indent.label()
+        ),
+        style,
+    ))
+    .right_aligned()
+}
+
+/// Owner, mode and the hint the server sent with a denied write
+fn render_write_denied(
+    f: &mut Frame,
+    denied: &WritePermissionError,
+    theme: &ThemeConfig,
+    area: Rect,
+) {
+    let label = EditorTheme::table_comment_style(theme);
+    let value = EditorTheme::table_value_style(theme);
+    let unknown = || "?".to_string();
+
+    let ownership = Line::from(vec![
+        Span::styled("owner ", label),
+        Span::styled(denied.owner.clone().unwrap_or_else(unknown), value),
+        Span::styled("  mode ", label),
+        Span::styled(
+            denied
+                .mode
+                .map(super::file_list::format_mode)
+                .unwrap_or_else(unknown),
+            value,
+        ),
+        Span::styled("  server runs as ", label),
+        Span::styled(denied.server_user.clone().unwrap_or_else(unknown), value),
+    ]);
+    let privileges = if denied.privileges_help {
+        "sudo or capabilities would let the write through"
+    } else {
+        "the server already runs as root"
+    };
+    let lines = vec![
+        ownership,
+        Line::from(Span::styled(privileges, label)),
+        Line::from(Span::styled(denied.hint.clone(), value)),
+    ];
+
+    let block = Block::default()
+        .title(format!("Write denied: {}", denied.path))
+        .borders(Borders::ALL)
+        .border_style(EditorTheme::write_denied_border_style(theme));
+    let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
+    f.render_widget(paragraph, area);
+}
+
+fn split_half(area: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area)
+}
+
+/// Saved content with its variables substituted, read-only
+fn render_rendered(
+    f: &mut Frame,
+    rendered: &RenderedView,
+    dirty: bool,
+    theme: &ThemeConfig,
+    top: u16,
+    area: Rect,
+) {
+    let mut block = Block::default()
+        .title(if dirty {
+            "Rendered [as saved]"
+        } else {
+            "Rendered"
+        })
+        .borders(Borders::ALL)
+        .border_style(theme.standard_border_unfocused());
+
+    let paragraph = match &rendered.result {
+        None => Paragraph::new("Rendering..."),
+        Some(Err(e)) => Paragraph::new(e.as_str())
+            .style(EditorTheme::lint_style(theme))
+            .wrap(Wrap { trim: true }),
+        Some(Ok(response)) => {
+            if let Some(vars_file) = &response.vars_file {
+                block = block.title_bottom(format!(" {} ", vars_file));
+            }
+            if !response.unresolved.is_empty() {
+                block = block.title_bottom(Line::styled(
+                    format!(" unresolved: {} ", response.unresolved.join(", ")),
+                    EditorTheme::lint_style(theme),
+                ));
+            }
+            Paragraph::new(response.content.as_str()).scroll((top, 0))
+        }
+    };
+    f.render_widget(paragraph.block(block), area);
+}
+
+/// Border of the preview; the page itself is an iframe laid over the inner area
+fn render_preview_frame(f: &mut Frame, theme: &ThemeConfig, area: Rect, covered: bool) {
+    let block = Block::default()
+        .title("Preview")
+        .borders(Borders::ALL)
+        .border_style(theme.standard_border_unfocused());
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let screen = f.area();
+    let (cols, rows) = (screen.width.max(1) as f64, screen.height.max(1) as f64);
+    let region = (
+        inner.x as f64 / cols,
+        inner.y as f64 / rows,
+        inner.width as f64 / cols,
+        inner.height as f64 / rows,
     );
+    if let Err(e) = crate::dom::place_preview(region, !covered) {
+        web_sys::console::error_1(&e);
+    }
+}
+
+/// Schema fields with their current value, validation error and description
+pub(super) fn render_form(
+    f: &mut Frame,
+    form: &SchemaForm,
+    theme: &ThemeConfig,
+    title: &str,
+    border_style: ratzilla::ratatui::style::Style,
+    area: Rect,
+) {
+    let errors = form.error_count();
+    let title = if errors == 0 {
+        title.to_string()
+    } else {
+        format!("{} [{} invalid]", title, errors)
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    let key_width = form
+        .fields
+        .iter()
+        .map(|field| field.key.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut lines: Vec<Line> = Vec::new();
+    let mut selected_display = 0;
+    for (i, field) in form.fields.iter().enumerate() {
+        let is_selected = i == form.selected_index;
+        if is_selected {
+            selected_display = lines.len();
+        }
+        let error = form.error(field);
+        let (value_text, value_style) = match (&form.edit_buffer, is_selected) {
+            (Some(buffer), true) => (
+                format!("{}█", buffer),
+                EditorTheme::table_active_cell_style(theme),
+            ),
+            _ => (
+                form.value(field).unwrap_or_else(|| "<unset>".to_string()),
+                EditorTheme::table_value_style(theme),
+            ),
+        };
+        let key_style = if error.is_some() {
+            EditorTheme::table_invalid_style(theme)
+        } else {
+            EditorTheme::table_key_style(theme)
+        };
+
+        let mut spans = vec![
+            Span::styled(format!("{:<key_width$}", field.key), key_style),
+            Span::styled(" = ", EditorTheme::table_comment_style(theme)),
+            Span::styled(value_text, value_style),
+        ];
+        if let Some(error) = error {
+            spans.push(Span::styled(
+                format!("  ({})", error),
+                EditorTheme::table_invalid_style(theme),
+            ));
+        }
+        let row = Line::from(spans);
+        lines.push(if is_selected {
+            row.style(EditorTheme::table_selected_row_style(theme))
+        } else {
+            row
+        });
+        if !field.description.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", field.description),
+                EditorTheme::table_comment_style(theme),
+            )));
+        }
+    }
+
+    // Keep the selection in view (2 rows of border)
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = (selected_display + 1).saturating_sub(visible);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+/// Key=value rows of the table mode, with sections and comments for context
+fn render_table(
+    f: &mut Frame,
+    table: &KvTable,
+    theme: &ThemeConfig,
+    title: String,
+    border_style: ratzilla::ratatui::style::Style,
+    area: Rect,
+) {
+    let invalid = table.invalid_lines();
+    let title = if invalid.is_empty() {
+        format!("{} [table]", title)
+    } else {
+        format!("{} [table, {} invalid keys]", title, invalid.len())
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    let selected_line = table.selected_line();
+    let key_width = table
+        .lines
+        .iter()
+        .filter_map(|line| match line {
+            KvLine::Entry { key, .. } => Some(key.chars().count()),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+
+    // Table lines shown (blank ones are left out); only those in view are
+    // built, big files have thousands
+    let shown: Vec<usize> = table
+        .lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, KvLine::Verbatim(text) if text.trim().is_empty()))
+        .map(|(i, _)| i)
+        .collect();
+    let selected_display = selected_line.and_then(|line| shown.iter().position(|&i| i == line));
+    // Keep the selection in view (2 rows of border)
+    let window = super::viewport::visible_rows(
+        shown.len(),
+        selected_display,
+        area.height.saturating_sub(2) as usize,
+    );
+
+    let mut lines: Vec<Line> = Vec::with_capacity(window.len());
+    for &i in &shown[window] {
+        match &table.lines[i] {
+            KvLine::Verbatim(text) => lines.push(Line::from(Span::styled(
+                text.clone(),
+                EditorTheme::table_comment_style(theme),
+            ))),
+            KvLine::Section { raw, .. } => lines.push(Line::from(Span::styled(
+                raw.trim().to_string(),
+                EditorTheme::table_section_style(theme),
+            ))),
+            KvLine::Entry { key, value, .. } => {
+                let is_selected = selected_line == Some(i);
+                let cell = |column: KvColumn, text: &String| -> (String, bool) {
+                    let active = is_selected && table.column == column;
+                    match (&table.edit_buffer, active) {
+                        (Some(buffer), true) => (format!("{}█", buffer), true),
+                        _ => (text.clone(), active),
+                    }
+                };
+
+                let (key_text, key_active) = cell(KvColumn::Key, key);
+                let (value_text, value_active) = cell(KvColumn::Value, value);
+                let key_style = if key_active {
+                    EditorTheme::table_active_cell_style(theme)
+                } else if invalid.contains(&i) {
+                    EditorTheme::table_invalid_style(theme)
+                } else {
+                    EditorTheme::table_key_style(theme)
+                };
+                let value_style = if value_active {
+                    EditorTheme::table_active_cell_style(theme)
+                } else {
+                    EditorTheme::table_value_style(theme)
+                };
+
+                let key_text = if key_text.is_empty() && !key_active {
+                    "<empty>".to_string()
+                } else {
+                    key_text
+                };
+                let row = Line::from(vec![
+                    Span::styled(format!("{:<key_width$}", key_text), key_style),
+                    Span::styled(" = ", EditorTheme::table_comment_style(theme)),
+                    Span::styled(value_text, value_style),
+                ]);
+                lines.push(if is_selected {
+                    row.style(EditorTheme::table_selected_row_style(theme))
+                } else {
+                    row
+                });
+            }
+        }
+    }
 
-    f.render_widget(&widget_with_block, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
 }
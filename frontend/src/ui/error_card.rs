@@ -0,0 +1,45 @@
+use crate::theme::ThemeConfig;
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Wrap},
+};
+
+/// Why a pane's data failed to load and the key that fetches it again, in
+/// place of the pane's content
+pub fn render(
+    f: &mut Frame,
+    theme: &ThemeConfig,
+    block: Block,
+    area: Rect,
+    error: &str,
+    retry_key: &str,
+) {
+    let lines = vec![
+        Line::default(),
+        Line::from(Span::styled(
+            " Loading failed",
+            Style::default()
+                .fg(theme.error())
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::default(),
+        Line::from(Span::styled(
+            format!(" {}", error),
+            Style::default().fg(theme.text()),
+        )),
+        Line::default(),
+        Line::from(vec![
+            Span::styled(" Press ", Style::default().fg(theme.dim())),
+            Span::styled(retry_key.to_string(), Style::default().fg(theme.accent())),
+            Span::styled(" to retry", Style::default().fg(theme.dim())),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, area);
+}
@@ -0,0 +1,127 @@
+use crate::{
+    state::{AppState, Pane},
+    theme::{ThemeConfig, file_history::FileHistoryTheme},
+    utils::time::{format_age, now_secs},
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Commit list of the open file on the left, diff of the selected commit on the right
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(35), // Commit list
+            Constraint::Percentage(1),  // Empty gap
+            Constraint::Percentage(64), // Diff
+        ])
+        .split(area);
+
+    render_commits(f, state, chunks[0]);
+    render_diff(f, state, chunks[2]);
+}
+
+fn render_commits(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let history = &state.file_history;
+    let border_style = if state.is_focused(Pane::FileHistory) {
+        FileHistoryTheme::border_focused(theme)
+    } else {
+        FileHistoryTheme::border_unfocused(theme)
+    };
+    let title = match &history.filename {
+        Some(name) => format!(" History: {} ", name),
+        None => " History ".to_string(),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(border_style);
+
+    if history.commits.is_empty() {
+        let message = if history.loaded {
+            "No commits for this file"
+        } else {
+            "Loading..."
+        };
+        let paragraph = Paragraph::new(message)
+            .block(block)
+            .style(FileHistoryTheme::empty_style(theme));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let now = now_secs();
+    let lines: Vec<Line> = history
+        .commits
+        .iter()
+        .enumerate()
+        .map(|(i, commit)| {
+            let age = now.saturating_sub(commit.time.max(0) as u64);
+            let subject = commit.message.lines().next().unwrap_or_default();
+            let line = Line::from(vec![
+                Span::styled(
+                    format!("{} {:>8} ", commit.short_id, format_age(age)),
+                    FileHistoryTheme::meta_style(theme),
+                ),
+                Span::styled(
+                    subject.to_string(),
+                    FileHistoryTheme::normal_item_style(theme),
+                ),
+            ]);
+            if i == history.selected_index {
+                line.style(FileHistoryTheme::selected_item_style(theme))
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    // Keep the selection in view (2 rows of border)
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = (history.selected_index + 1).saturating_sub(visible);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+fn render_diff(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let history = &state.file_history;
+
+    let Some(commit) = history.selected() else {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Diff ")
+            .border_style(FileHistoryTheme::border_unfocused(theme));
+        f.render_widget(block, area);
+        return;
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {} by {} ", commit.short_id, commit.author))
+        .border_style(FileHistoryTheme::border_unfocused(theme));
+
+    let paragraph = Paragraph::new(diff_lines(&commit.diff, theme))
+        .block(block)
+        .scroll((history.diff_scroll, 0));
+    f.render_widget(paragraph, area);
+}
+
+fn diff_lines(diff: &str, theme: &ThemeConfig) -> Vec<Line<'static>> {
+    diff.lines()
+        .map(|line| {
+            Line::from(Span::styled(
+                line.to_string(),
+                FileHistoryTheme::diff_line_style(theme, line),
+            ))
+        })
+        .collect()
+}
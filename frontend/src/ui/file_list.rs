@@ -1,6 +1,9 @@
+use super::viewport::{Row, item_indices, visible_rows};
 use crate::{
-    state::{AppState, Pane},
+    api::{FileMeta, FileOrder},
+    state::{AppState, Pane, marks::PINNED_HEADER},
     theme::file_list::FileListTheme,
+    utils::time,
 };
 use ratzilla::ratatui::{
     Frame,
@@ -11,7 +14,7 @@ use ratzilla::ratatui::{
 
 pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
     let theme = &state.current_theme;
-    let is_focused = state.focus == Pane::FileList;
+    let is_focused = state.is_focused(Pane::FileList);
 
     let border_style = if is_focused {
         FileListTheme::border_focused(theme)
@@ -19,49 +22,164 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
         FileListTheme::border_unfocused(theme)
     };
 
-    let mut items: Vec<ListItem> = Vec::new();
+    let mut rows: Vec<Row> = Vec::new();
     let mut display_selected_index: Option<usize> = None;
-    let mut last_category: Option<String> = None;
+    let mut last_category: Option<&str> = None;
+    let show_details = state.file_list.show_details;
+    let name_width = state
+        .file_list
+        .files
+        .iter()
+        .map(|f| f.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    let now = time::now_secs();
+    let order = state.file_list.order();
+    // Only these keep a category's files together, headers would repeat otherwise
+    let by_category = matches!(order, FileOrder::Config | FileOrder::Category);
 
     for (file_idx, file) in state.file_list.files.iter().enumerate() {
-        let category = file
-            .category
-            .as_deref()
-            .unwrap_or("Uncategorized")
-            .to_string();
+        // Pinned files are sorted first and get their own section
+        let category = if state.file_list.pinned.contains(&file.name) {
+            PINNED_HEADER
+        } else if by_category {
+            file.category.as_deref().unwrap_or("Uncategorized")
+        } else {
+            "Files"
+        };
 
         // Insert category header when it changes
-        if last_category.as_deref() != Some(category.as_str()) {
-            items.push(ListItem::new(Line::from(vec![Span::styled(
-                category.clone(),
-                FileListTheme::header_style(theme),
-            )])));
+        if last_category != Some(category) {
+            rows.push(Row::Header(category));
             last_category = Some(category);
         }
 
         // Track where the selected file sits in the rendered list
         if file_idx == state.file_list.selected_index {
-            display_selected_index = Some(items.len());
+            display_selected_index = Some(rows.len());
         }
+        rows.push(Row::Item(file_idx));
+    }
+
+    // Only the lines in view are built, lists can run into the thousands
+    let window = visible_rows(
+        rows.len(),
+        display_selected_index,
+        area.height.saturating_sub(2) as usize,
+    );
+    let mut items: Vec<ListItem> = Vec::with_capacity(window.len());
+    for row in &rows[window.clone()] {
+        let file_idx = match *row {
+            Row::Header(header) => {
+                items.push(ListItem::new(Line::from(vec![Span::styled(
+                    header,
+                    FileListTheme::header_style(theme),
+                )])));
+                continue;
+            }
+            Row::Item(file_idx) => file_idx,
+        };
+        let file = &state.file_list.files[file_idx];
+
+        let style = if state.file_list.hidden.contains(&file.name) {
+            FileListTheme::hidden_item_style(theme)
+        } else {
+            FileListTheme::normal_item_style(theme)
+        };
+        let mut spans = vec![Span::styled(format!("  - {}", file.name), style)];
+        if show_details && let Some(meta) = &file.meta {
+            let padding = name_width - file.name.chars().count();
+            spans.push(Span::styled(
+                format!("{}  {}", " ".repeat(padding), detail_columns(meta, now)),
+                FileListTheme::hidden_item_style(theme),
+            ));
+        }
+        items.push(ListItem::new(Line::from(spans)));
+    }
 
-        items.push(ListItem::new(Line::from(vec![Span::styled(
-            format!("  - {}", file.name),
-            FileListTheme::normal_item_style(theme),
-        )])));
+    let mut title = "Config Files".to_string();
+    if state.file_list.show_hidden {
+        title.push_str(" (+hidden)");
+    }
+    if order != FileOrder::Config {
+        title.push_str(&format!(" [{}]", order.label()));
+    }
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+    let inner = block.inner(area);
+
+    if state.file_list.all.is_empty() {
+        if let Some(error) = &state.file_list.error {
+            let retry_key = &state.keybinds.file_list.refresh;
+            super::error_card::render(f, theme, block, area, error, retry_key);
+            return;
+        }
+        if state.file_list.loading {
+            super::skeleton::render(f, theme, block, area);
+            return;
+        }
     }
 
     let list = List::new(items)
-        .block(
-            Block::default()
-                .title("Config Files")
-                .borders(Borders::ALL)
-                .border_style(border_style),
-        )
+        .block(block)
         .highlight_style(FileListTheme::selected_item_style(theme))
         .highlight_symbol(FileListTheme::selected_prefix());
 
     let mut list_state = ListState::default();
-    list_state.select(display_selected_index);
+    list_state.select(display_selected_index.map(|row| row.saturating_sub(window.start)));
 
     f.render_stateful_widget(list, area, &mut list_state);
+
+    state
+        .layout
+        .borrow_mut()
+        .add_list(Pane::FileList, inner, window.start, item_indices(&rows));
+}
+
+/// "  1.2K  3d ago  rw-r--r--  root" with fixed column widths
+fn detail_columns(meta: &FileMeta, now: u64) -> String {
+    let age = time::format_age(now.saturating_sub(meta.modified));
+    let mode = meta.mode.map(format_mode).unwrap_or_default();
+    let owner = meta.owner.as_deref().unwrap_or("");
+    format!(
+        "{:>6}  {:<8}  {:<9}  {}",
+        format_size(meta.size),
+        age,
+        mode,
+        owner
+    )
+}
+
+/// Byte count in 1024 steps ("512B", "1.2K", "34M")
+pub(super) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["K", "M", "G", "T"];
+    if bytes < 1024 {
+        return format!("{}B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if value < 10.0 {
+        format!("{:.1}{}", value, UNITS[unit])
+    } else {
+        format!("{:.0}{}", value, UNITS[unit])
+    }
+}
+
+/// Permission bits as `ls -l` shows them ("rw-r--r--")
+pub(super) fn format_mode(mode: u32) -> String {
+    (0..9)
+        .map(|bit| {
+            if mode & (0o400 >> bit) == 0 {
+                '-'
+            } else {
+                ['r', 'w', 'x'][bit % 3]
+            }
+        })
+        .collect()
 }
@@ -0,0 +1,46 @@
+use crate::{keybinds::KeybindEntry, state::AppState, theme::overlay::OverlayTheme};
+use ratzilla::ratatui::text::{Line, Span};
+
+/// Bindings of the focused pane and the global ones, grouped by section,
+/// read from the loaded keybinds so the list matches what the keys do
+pub fn lines(state: &AppState) -> Vec<Line<'static>> {
+    let theme = &state.current_theme;
+    let entries = state.keybinds.help_entries(state.keybind_section());
+    let key_width = entries.iter().map(|e| e.key.len()).max().unwrap_or(0);
+
+    let mut lines = Vec::new();
+    let mut section: Option<&str> = None;
+    for entry in &entries {
+        if section != Some(entry.section.as_str()) {
+            if section.is_some() {
+                lines.push(Line::default());
+            }
+            section = Some(entry.section.as_str());
+            lines.push(Line::from(Span::styled(
+                heading(entry),
+                OverlayTheme::heading_style(theme),
+            )));
+        }
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {:<key_width$}  ", entry.key),
+                OverlayTheme::key_style(theme),
+            ),
+            Span::styled(
+                entry.action.replace('_', " "),
+                OverlayTheme::label_style(theme),
+            ),
+        ]));
+    }
+    lines
+}
+
+/// `file_list` -> `File list`
+fn heading(entry: &KeybindEntry) -> String {
+    let words = entry.section.replace('_', " ");
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => words,
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    state::{
+        AppState,
+        hex_viewer::{BYTES_PER_ROW, PAGE_SIZE},
+    },
+    theme::{ThemeConfig, hex_viewer::HexViewerTheme},
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Offset, hex and ASCII columns of the loaded page of a binary file
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let viewer = &state.hex_viewer;
+
+    let name = viewer.filename.as_deref().unwrap_or_default();
+    let title = if viewer.size == 0 {
+        format!(" Hex: {} ", name)
+    } else {
+        let pages = viewer.size.div_ceil(PAGE_SIZE);
+        format!(
+            " Hex: {} ({} bytes, page {}/{}) ",
+            name,
+            viewer.size,
+            viewer.offset / PAGE_SIZE + 1,
+            pages
+        )
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(HexViewerTheme::border_focused(theme));
+
+    if viewer.data.is_empty() {
+        let message = if viewer.loaded {
+            "Empty file"
+        } else {
+            "Loading..."
+        };
+        let paragraph =
+            Paragraph::new(Span::styled(message, HexViewerTheme::empty_style(theme))).block(block);
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let lines: Vec<Line> = viewer
+        .data
+        .chunks(BYTES_PER_ROW)
+        .enumerate()
+        .map(|(row, bytes)| render_row(theme, viewer.offset + (row * BYTES_PER_ROW) as u64, bytes))
+        .collect();
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((viewer.scroll as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+/// `00000010  48 65 6c 6c 6f 00 ...  |Hello.|`
+fn render_row(theme: &ThemeConfig, offset: u64, bytes: &[u8]) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        format!("{:08x}  ", offset),
+        HexViewerTheme::offset_style(theme),
+    )];
+
+    for i in 0..BYTES_PER_ROW {
+        // Extra gap between the two halves of a row
+        let gap = if i == BYTES_PER_ROW / 2 { " " } else { "" };
+        match bytes.get(i) {
+            Some(&byte) => {
+                let style = if byte == 0 {
+                    HexViewerTheme::faint_style(theme)
+                } else {
+                    HexViewerTheme::hex_style(theme)
+                };
+                spans.push(Span::styled(format!("{}{:02x} ", gap, byte), style));
+            }
+            None => spans.push(Span::raw(format!("{}   ", gap))),
+        }
+    }
+
+    spans.push(Span::styled(" |", HexViewerTheme::faint_style(theme)));
+    for &byte in bytes {
+        if byte.is_ascii_graphic() || byte == b' ' {
+            spans.push(Span::styled(
+                (byte as char).to_string(),
+                HexViewerTheme::ascii_style(theme),
+            ));
+        } else {
+            spans.push(Span::styled(".", HexViewerTheme::faint_style(theme)));
+        }
+    }
+    spans.push(Span::styled("|", HexViewerTheme::faint_style(theme)));
+    Line::from(spans)
+}
@@ -0,0 +1,71 @@
+use crate::{api, state::AppState, storage, theme::overlay::OverlayTheme};
+use ratzilla::ratatui::text::{Line, Span};
+
+/// The serving server and its `[[hosts]]`, the one in use marked
+pub fn lines(state: &AppState) -> Vec<Line<'static>> {
+    let theme = &state.current_theme;
+    let Some(picker) = &state.host_picker else {
+        return Vec::new();
+    };
+    let active = storage::hosts::active().map(|host| host.name);
+
+    let mut rows = vec![(
+        active.is_none(),
+        "this server".to_string(),
+        home_label(active.is_some()),
+        String::new(),
+    )];
+    match &picker.hosts {
+        Some(Ok(hosts)) => rows.extend(hosts.iter().map(|host| {
+            (
+                active.as_deref() == Some(host.name.as_str()),
+                host.name.clone(),
+                host.url.clone(),
+                host.description.clone(),
+            )
+        })),
+        Some(Err(e)) => rows.push((false, String::new(), e.clone(), String::new())),
+        None => rows.push((
+            false,
+            String::new(),
+            "Loading...".to_string(),
+            String::new(),
+        )),
+    }
+
+    let mut lines: Vec<Line<'static>> = rows
+        .into_iter()
+        .enumerate()
+        .map(|(i, (in_use, name, url, description))| {
+            let line = Line::from(vec![
+                Span::styled(
+                    format!("{} {:<16} ", if in_use { "*" } else { " " }, name),
+                    OverlayTheme::key_style(theme),
+                ),
+                Span::styled(format!("{}  ", url), OverlayTheme::label_style(theme)),
+                Span::styled(description, OverlayTheme::label_style(theme)),
+            ]);
+            if i == picker.selected {
+                line.style(OverlayTheme::selected_style(theme))
+            } else {
+                line
+            }
+        })
+        .collect();
+    if matches!(&picker.hosts, Some(Ok(hosts)) if hosts.is_empty()) {
+        lines.push(Line::from(Span::styled(
+            "  Add [[hosts]] to sysrat.toml to manage other servers from here",
+            OverlayTheme::label_style(theme),
+        )));
+    }
+    lines
+}
+
+/// Where the page came from; its API host is only known while in use
+fn home_label(away: bool) -> String {
+    if away {
+        "the server this page was loaded from".to_string()
+    } else {
+        api::host()
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    state::AppState,
+    theme::{file_history::FileHistoryTheme, overlay::OverlayTheme},
+};
+use ratzilla::ratatui::text::{Line, Span};
+
+/// The whitespace fixes of the pending save followed by their diff
+pub fn lines(state: &AppState) -> Vec<Line<'static>> {
+    let theme = &state.current_theme;
+    let Some(review) = &state.hygiene else {
+        return Vec::new();
+    };
+
+    let row = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<10} ", label), OverlayTheme::key_style(theme)),
+            Span::styled(value, OverlayTheme::label_style(theme)),
+        ])
+    };
+
+    let mut lines = vec![row("File", review.filename.clone())];
+    for (index, fix) in review.check.fixes.iter().enumerate() {
+        lines.push(row(if index == 0 { "Fixes" } else { "" }, fix.clone()));
+    }
+    lines.push(Line::default());
+    lines.extend(review.check.diff.lines().map(|line| {
+        Line::from(Span::styled(
+            line.to_string(),
+            FileHistoryTheme::diff_line_style(theme, line),
+        ))
+    }));
+    lines
+}
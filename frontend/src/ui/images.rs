@@ -0,0 +1,247 @@
+use crate::{
+    api::{ImageInfo, SeverityCounts},
+    state::{AppState, Pane},
+    theme::{ThemeConfig, images::ImagesTheme},
+    utils::time::{format_age, now_secs},
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+/// Width of the repository:tag column, longer names are cut
+const NAME_WIDTH: usize = 40;
+
+/// Image list with severity badges next to the details of the selected image
+/// and its last scan
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(50), // Image list
+            Constraint::Percentage(1),  // Empty gap
+            Constraint::Percentage(49), // Details + scan
+        ])
+        .split(area);
+
+    render_list(f, state, chunks[0]);
+    render_details(f, state, chunks[2]);
+}
+
+fn render_list(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let images = &state.images;
+    let border_style = if state.is_focused(Pane::Images) {
+        ImagesTheme::border_focused(theme)
+    } else {
+        ImagesTheme::border_unfocused(theme)
+    };
+    let title = match &images.scanner {
+        Some(scanner) => format!(" Images (scanner: {}) ", scanner),
+        None => " Images (no scanner installed) ".to_string(),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(border_style);
+
+    if images.images.is_empty() {
+        let message = if images.loaded {
+            "No images"
+        } else {
+            "Loading..."
+        };
+        let paragraph = Paragraph::new(message)
+            .block(block)
+            .style(ImagesTheme::empty_style(theme));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let lines: Vec<Line> = images
+        .images
+        .iter()
+        .enumerate()
+        .map(|(i, image)| {
+            let name: String = image_name(image).chars().take(NAME_WIDTH).collect();
+            let mut spans = vec![Span::styled(
+                format!("{:<width$} ", name, width = NAME_WIDTH),
+                ImagesTheme::normal_item_style(theme),
+            )];
+            spans.extend(badges(theme, image, images.is_scanning(&image.id)));
+            let line = Line::from(spans);
+            if i == images.selected_index {
+                line.style(ImagesTheme::selected_item_style(theme))
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    // Keep the selection in view (2 rows of border)
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = (images.selected_index + 1).saturating_sub(visible);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+/// `repository:tag`, or the id of an untagged image
+fn image_name(image: &ImageInfo) -> String {
+    if image.repository == "<none>" {
+        image.id.clone()
+    } else {
+        format!("{}:{}", image.repository, image.tag)
+    }
+}
+
+/// Severity counts of the last scan, e.g. "C2 H5 M12 L30", "?" for unrated
+fn badges(theme: &ThemeConfig, image: &ImageInfo, scanning: bool) -> Vec<Span<'static>> {
+    if scanning {
+        return vec![Span::styled(
+            "scanning...",
+            ImagesTheme::scanning_style(theme),
+        )];
+    }
+    let Some(scan) = &image.scan else {
+        return vec![Span::styled("not scanned", ImagesTheme::hint_style(theme))];
+    };
+
+    let counts = severity_counts(&scan.counts);
+    if counts.iter().all(|(_, count)| *count == 0) && scan.counts.unknown == 0 {
+        return vec![Span::styled("clean", ImagesTheme::clean_style(theme))];
+    }
+    let mut spans: Vec<Span<'static>> = counts
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(severity, count)| {
+            Span::styled(
+                format!("{}{} ", &severity[..1], count),
+                ImagesTheme::severity_style(theme, severity),
+            )
+        })
+        .collect();
+    if scan.counts.unknown > 0 {
+        spans.push(Span::styled(
+            format!("?{}", scan.counts.unknown),
+            ImagesTheme::hint_style(theme),
+        ));
+    }
+    spans
+}
+
+fn severity_counts(counts: &SeverityCounts) -> [(&'static str, u64); 4] {
+    [
+        ("CRITICAL", counts.critical),
+        ("HIGH", counts.high),
+        ("MEDIUM", counts.medium),
+        ("LOW", counts.low),
+    ]
+}
+
+fn render_details(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Image Details ")
+        .border_style(ImagesTheme::border_unfocused(theme));
+
+    let Some(image) = state.images.selected() else {
+        f.render_widget(block, area);
+        return;
+    };
+
+    let row = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<9} ", label), ImagesTheme::label_style(theme)),
+            Span::styled(value, ImagesTheme::value_style(theme)),
+        ])
+    };
+    let mut lines = vec![
+        row("Image", image_name(image)),
+        row("ID", image.id.clone()),
+        row("Size", image.size.clone()),
+        row("Created", image.created.clone()),
+        Line::default(),
+    ];
+
+    match &image.scan {
+        None if state.images.is_scanning(&image.id) => {
+            lines.push(Line::from(Span::styled(
+                "Scanning...",
+                ImagesTheme::scanning_style(theme),
+            )));
+        }
+        None => {
+            let hint = match &state.images.scanner {
+                Some(_) => format!("Not scanned, press {} to scan", state.keybinds.images.scan),
+                None => "Not scanned, install trivy or grype on the server to scan".to_string(),
+            };
+            lines.push(Line::from(Span::styled(
+                hint,
+                ImagesTheme::hint_style(theme),
+            )));
+        }
+        Some(scan) => {
+            let age = format_age((now_secs() as i64 - scan.scanned).max(0) as u64);
+            lines.push(row("Scanned", format!("{} by {}", age, scan.scanner)));
+            let mut counts: Vec<Span> = vec![Span::styled(
+                format!("{:<9} ", "Found"),
+                ImagesTheme::label_style(theme),
+            )];
+            for (severity, count) in severity_counts(&scan.counts) {
+                counts.push(Span::styled(
+                    format!("{} {}  ", count, severity.to_lowercase()),
+                    ImagesTheme::severity_style(theme, severity),
+                ));
+            }
+            if scan.counts.unknown > 0 {
+                counts.push(Span::styled(
+                    format!("{} unrated", scan.counts.unknown),
+                    ImagesTheme::hint_style(theme),
+                ));
+            }
+            lines.push(Line::from(counts));
+
+            if !scan.top.is_empty() {
+                lines.push(Line::default());
+                lines.push(Line::from(Span::styled(
+                    "Top vulnerabilities",
+                    ImagesTheme::label_style(theme),
+                )));
+            }
+            for vulnerability in &scan.top {
+                let fix = vulnerability
+                    .fixed_version
+                    .as_ref()
+                    .map_or_else(|| "no fix".to_string(), |v| format!("fixed in {}", v));
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("{:<9} ", vulnerability.severity.to_lowercase()),
+                        ImagesTheme::severity_style(theme, &vulnerability.severity),
+                    ),
+                    Span::styled(
+                        format!("{} ", vulnerability.id),
+                        ImagesTheme::value_style(theme),
+                    ),
+                    Span::styled(
+                        format!(
+                            "{} {} ({})",
+                            vulnerability.package, vulnerability.installed_version, fix
+                        ),
+                        ImagesTheme::hint_style(theme),
+                    ),
+                ]));
+            }
+        }
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, area);
+}
@@ -0,0 +1,121 @@
+use crate::{
+    api::FileChangeStats,
+    state::{AppState, Pane},
+    theme::{ThemeConfig, insights::InsightsTheme},
+    utils::time::{format_age, now_secs},
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Width of the change bar at the most changed file
+const BAR_WIDTH: usize = 20;
+/// Width of the file name column, longer names are cut
+const NAME_WIDTH: usize = 32;
+
+/// Managed files ranked by how often they changed within the window, to spot
+/// configs that are edited often enough to be templated or automated
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let insights = &state.insights;
+    let border_style = if state.is_focused(Pane::Insights) {
+        InsightsTheme::border_focused(theme)
+    } else {
+        InsightsTheme::border_unfocused(theme)
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            " Most changed files (last {} days) ",
+            insights.days
+        ))
+        .border_style(border_style);
+
+    if let Some(error) = &insights.error {
+        let paragraph = Paragraph::new(error.as_str())
+            .block(block)
+            .style(InsightsTheme::empty_style(theme));
+        f.render_widget(paragraph, area);
+        return;
+    }
+    if insights.files.is_empty() {
+        let message = if insights.loaded {
+            "No managed files"
+        } else {
+            "Loading..."
+        };
+        let paragraph = Paragraph::new(message)
+            .block(block)
+            .style(InsightsTheme::empty_style(theme));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let max = insights.max_changes();
+    let now = now_secs() as i64;
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            "{:<name$} {:<bar$} {:>7} {:>7}  Last change",
+            "File",
+            "",
+            "Changes",
+            "Total",
+            name = NAME_WIDTH,
+            bar = BAR_WIDTH
+        ),
+        InsightsTheme::header_style(theme),
+    ))];
+    lines.extend(insights.files.iter().enumerate().map(|(i, file)| {
+        let line = file_line(theme, file, max, now);
+        if i == insights.selected_index {
+            line.style(InsightsTheme::selected_item_style(theme))
+        } else {
+            line
+        }
+    }));
+
+    // Keep the selection in view (2 rows of border, 1 of headings)
+    let visible = area.height.saturating_sub(3) as usize;
+    let offset = (insights.selected_index + 1).saturating_sub(visible);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+fn file_line(theme: &ThemeConfig, file: &FileChangeStats, max: u64, now: i64) -> Line<'static> {
+    let name: String = file.name.chars().take(NAME_WIDTH).collect();
+    let filled = if max == 0 {
+        0
+    } else {
+        // At least one cell for any change, so rare edits stay visible
+        ((file.changes * BAR_WIDTH as u64).div_ceil(max)) as usize
+    };
+    let last = file.last_changed.map_or_else(
+        || "never".to_string(),
+        |time| format_age((now - time).max(0) as u64),
+    );
+
+    Line::from(vec![
+        Span::styled(
+            format!("{:<width$} ", name, width = NAME_WIDTH),
+            InsightsTheme::normal_item_style(theme),
+        ),
+        Span::styled(
+            format!("{:<width$} ", "█".repeat(filled), width = BAR_WIDTH),
+            InsightsTheme::bar_style(theme),
+        ),
+        Span::styled(
+            format!("{:>7} ", file.changes),
+            InsightsTheme::normal_item_style(theme),
+        ),
+        Span::styled(
+            format!("{:>7}  {}", file.total_changes, last),
+            InsightsTheme::hint_style(theme),
+        ),
+    ])
+}
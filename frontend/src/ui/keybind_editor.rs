@@ -0,0 +1,99 @@
+use crate::{state::AppState, theme::keybind_editor::KeybindEditorTheme};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Every binding as `section action key`, marking overrides and conflicts
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let editor = &state.keybind_editor;
+
+    let title = if editor.conflicts.is_empty() {
+        " Keybinds ".to_string()
+    } else {
+        format!(" Keybinds ({} conflicting) ", editor.conflicts.len())
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(KeybindEditorTheme::border_focused(theme));
+
+    let section_width = editor
+        .entries
+        .iter()
+        .map(|e| e.section.len())
+        .max()
+        .unwrap_or(0);
+    let action_width = editor
+        .entries
+        .iter()
+        .map(|e| e.action.len())
+        .max()
+        .unwrap_or(0);
+
+    let lines: Vec<Line> = editor
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let is_selected = i == editor.selected_index;
+            let mut spans = vec![
+                Span::styled(
+                    format!("{:<section_width$}  ", entry.section),
+                    KeybindEditorTheme::section_style(theme),
+                ),
+                Span::styled(
+                    format!("{:<action_width$}  ", entry.action),
+                    KeybindEditorTheme::normal_item_style(theme),
+                ),
+            ];
+
+            if is_selected && editor.capturing {
+                spans.push(Span::styled(
+                    "press a key...",
+                    KeybindEditorTheme::capture_style(theme),
+                ));
+            } else {
+                let key_style = if editor.conflicts.contains(&i) {
+                    KeybindEditorTheme::conflict_style(theme)
+                } else if entry.overridden {
+                    KeybindEditorTheme::overridden_style(theme)
+                } else {
+                    KeybindEditorTheme::key_style(theme)
+                };
+                spans.push(Span::styled(entry.key.clone(), key_style));
+                if entry.overridden {
+                    spans.push(Span::styled(
+                        " (custom)",
+                        KeybindEditorTheme::overridden_style(theme),
+                    ));
+                }
+                if editor.conflicts.contains(&i) {
+                    spans.push(Span::styled(
+                        " (conflict)",
+                        KeybindEditorTheme::conflict_style(theme),
+                    ));
+                }
+            }
+
+            let line = Line::from(spans);
+            if is_selected {
+                line.style(KeybindEditorTheme::selected_item_style(theme))
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    // Keep the selection in view (2 rows of border)
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = (editor.selected_index + 1).saturating_sub(visible);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
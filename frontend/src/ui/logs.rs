@@ -0,0 +1,150 @@
+use crate::{
+    state::{AppState, Pane, logs::Severity},
+    theme::logs::LogsTheme,
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Configured log files next to the lines of the followed one
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(25), // Log list
+            Constraint::Percentage(1),  // Empty gap
+            Constraint::Percentage(74), // Lines
+        ])
+        .split(area);
+
+    render_list(f, state, chunks[0]);
+    render_lines(f, state, chunks[2]);
+}
+
+fn border_style(state: &AppState) -> ratzilla::ratatui::style::Style {
+    if state.is_focused(Pane::Logs) {
+        LogsTheme::border_focused(&state.current_theme)
+    } else {
+        LogsTheme::border_unfocused(&state.current_theme)
+    }
+}
+
+fn render_list(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let logs = &state.logs;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Logs ")
+        .border_style(border_style(state));
+
+    if logs.logs.is_empty() {
+        let message = match (&logs.error, logs.loaded) {
+            (Some(error), _) => error.as_str(),
+            (None, true) => "No [[logs]] in sysrat.toml",
+            (None, false) => "Loading...",
+        };
+        let paragraph = Paragraph::new(message)
+            .block(block)
+            .style(LogsTheme::empty_style(theme));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let lines: Vec<Line> = logs
+        .logs
+        .iter()
+        .enumerate()
+        .map(|(i, log)| {
+            let followed = logs.following.as_deref() == Some(log.name.as_str());
+            let marker = if followed && logs.stream.is_some() {
+                "● "
+            } else {
+                "  "
+            };
+            let line = Line::from(vec![
+                Span::styled(marker, LogsTheme::following_style(theme)),
+                Span::styled(log.name.clone(), LogsTheme::normal_item_style(theme)),
+            ]);
+            if i == logs.selected_index {
+                line.style(LogsTheme::selected_item_style(theme))
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    // Keep the selection in view (2 rows of border)
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = (logs.selected_index + 1).saturating_sub(visible);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+fn render_lines(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let logs = &state.logs;
+
+    let mut title = vec![Span::raw(format!(
+        " {} ",
+        logs.following.as_deref().unwrap_or("No log followed")
+    ))];
+    if logs.paused {
+        title.push(Span::styled(
+            format!("[paused, {} new] ", logs.pending.len()),
+            LogsTheme::notice_style(theme),
+        ));
+    }
+    if let Some(search) = &logs.search {
+        let cursor = if logs.search_input { "_" } else { "" };
+        title.push(Span::styled(
+            format!("/{}{} ", search, cursor),
+            LogsTheme::notice_style(theme),
+        ));
+    }
+    if let Some(failure) = &logs.failure {
+        title.push(Span::styled(
+            format!("[{}] ", failure),
+            LogsTheme::failure_style(theme),
+        ));
+    }
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Line::from(title))
+        .border_style(border_style(state));
+
+    let visible = logs.visible_lines();
+    if visible.is_empty() {
+        let message = match (&logs.following, &logs.search) {
+            (None, _) => "Select a log and press enter to follow it",
+            (Some(_), Some(_)) if !logs.lines.is_empty() => "No lines match the search",
+            (Some(_), _) => "Waiting for lines...",
+        };
+        let paragraph = Paragraph::new(message)
+            .block(block)
+            .style(LogsTheme::empty_style(theme));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    // The newest lines sit at the bottom, scrolling moves back from them
+    let height = area.height.saturating_sub(2) as usize;
+    let end = visible.len().saturating_sub(logs.scroll);
+    let start = end.saturating_sub(height);
+    let lines: Vec<Line> = visible[start..end]
+        .iter()
+        .map(|line| {
+            Line::styled(
+                line.as_str(),
+                LogsTheme::line_style(theme, Severity::of(line)),
+            )
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
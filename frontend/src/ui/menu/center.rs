@@ -1,4 +1,7 @@
-use crate::{state::AppState, theme::menu::MenuTheme};
+use crate::{
+    state::{AppState, Pane},
+    theme::menu::MenuTheme,
+};
 use ratzilla::ratatui::{
     Frame,
     layout::{Alignment, Rect},
@@ -6,10 +9,15 @@ use ratzilla::ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
-/// Renders the center menu column with logo and menu items
+/// Renders the center menu column with logo, menu items and the MOTD
 pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
     let theme = &state.current_theme;
-    let menu_text_ascii = include_str!("../../../assets/menu-text.ascii");
+    let branding = &state.branding;
+    // A configured banner takes the title's place unless it replaces the rat
+    let menu_text_ascii = match &branding.banner {
+        Some(banner) if !branding.replace_rat => banner.as_str(),
+        _ => include_str!("../../../assets/menu-text.ascii"),
+    };
 
     let mut lines = vec![];
 
@@ -27,6 +35,9 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
         MenuTheme::ascii_art_style(theme),
     )));
 
+    // Logo and spacing lines come first, then one line per item
+    let header_lines = lines.len();
+
     // Calculate max item length for padding (centered but aligned)
     let max_len = state
         .menu
@@ -35,8 +46,9 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
         .map(|item| {
             let prefix = MenuTheme::selected_prefix(); // Use longest prefix
             let icon = match item.as_str() {
+                "Dashboard" => format!("{} ", theme.icons.dashboard),
                 "Config Files" => format!("{} ", theme.icons.config_files),
-                "Container" => format!("{} ", theme.icons.container),
+                "Container" | "Container Graph" => format!("{} ", theme.icons.container),
                 _ => String::new(),
             };
             prefix.len() + icon.len() + item.len()
@@ -62,8 +74,9 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
 
         // Icons from theme configuration
         let icon = match item.as_str() {
+            "Dashboard" => format!("{} ", theme.icons.dashboard),
             "Config Files" => format!("{} ", theme.icons.config_files),
-            "Container" => format!("{} ", theme.icons.container),
+            "Container" | "Container Graph" => format!("{} ", theme.icons.container),
             _ => String::new(),
         };
 
@@ -74,11 +87,30 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
         lines.push(Line::from(Span::styled(padded_line, style)));
     }
 
-    let menu = Paragraph::new(lines).alignment(Alignment::Center).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(MenuTheme::border_style(theme)),
-    );
+    // The MOTD follows the items and is not clickable
+    if let Some(motd) = &branding.motd {
+        lines.push(Line::default());
+        for line in motd.lines() {
+            lines.push(Line::from(Span::styled(line, MenuTheme::motd_style(theme))));
+        }
+    }
+
+    let rows = std::iter::repeat_n(None, header_lines)
+        .chain((0..state.menu.items.len()).map(Some))
+        .collect();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(MenuTheme::border_style(theme));
+    let inner = block.inner(area);
+    let menu = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .block(block);
 
     f.render_widget(menu, area);
+
+    state
+        .layout
+        .borrow_mut()
+        .add_list(Pane::Menu, inner, 0, rows);
 }
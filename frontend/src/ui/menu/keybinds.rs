@@ -42,6 +42,10 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
             format!("{} - Cycle Theme", keybinds.global.cycle_theme),
             MenuTheme::normal_item_style(theme),
         )),
+        Line::from(Span::styled(
+            format!("{} - Animations", keybinds.global.toggle_animations),
+            MenuTheme::normal_item_style(theme),
+        )),
     ];
 
     let keybinds_widget = Paragraph::new(lines).alignment(Alignment::Left).block(
@@ -1,4 +1,4 @@
-use crate::{state::AppState, theme::menu::MenuTheme};
+use crate::{api::FrontendConfigResponse, state::AppState, theme::menu::MenuTheme};
 use ratzilla::ratatui::{
     Frame,
     layout::{Alignment, Rect},
@@ -6,10 +6,15 @@ use ratzilla::ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
-/// Renders the ASCII art rat logo in the left column
+/// Renders the ASCII art rat logo in the left column, or the configured
+/// banner or art pack when they replace the rat
 pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
     let theme = &state.current_theme;
-    let sysrat_ascii = include_str!("../../../assets/sysrat.ascii");
+    let branding = &state.branding;
+    let sysrat_ascii = match &branding.banner {
+        Some(banner) if branding.replace_rat => banner.as_str(),
+        _ => current_frame(branding).unwrap_or(include_str!("../../../assets/sysrat.ascii")),
+    };
 
     let lines: Vec<Line> = sysrat_ascii
         .lines()
@@ -24,3 +29,17 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
 
     f.render_widget(sysrat_widget, area);
 }
+
+/// Frame of the art pack for the current time, so the cycle needs no timer
+fn current_frame(branding: &FrontendConfigResponse) -> Option<&str> {
+    let frames = &branding.art_frames;
+    if frames.is_empty() {
+        return None;
+    }
+    let index = if branding.frame_ms == 0 {
+        0
+    } else {
+        (js_sys::Date::now() / branding.frame_ms as f64) as usize % frames.len()
+    };
+    Some(frames[index].as_str())
+}
@@ -1,20 +1,51 @@
+mod actions;
+mod command_line;
 mod container_details;
+mod container_graph;
 mod container_list;
+mod container_watch;
+mod dashboard;
 mod editor;
+mod error_card;
+mod file_history;
 mod file_list;
+mod help;
+mod hex_viewer;
+mod hosts;
+mod hygiene;
+mod images;
+mod insights;
+mod keybind_editor;
+mod logs;
 mod menu;
+mod outline;
+mod overlay;
+mod perf_hud;
+mod problems;
+mod runbooks;
+mod skeleton;
 mod splash;
 mod status_line;
+mod theme_editor;
+mod toasts;
+mod upstream;
+mod viewport;
 
-use crate::state::{AppState, Pane};
+pub use status_line::set_runtime_layout;
+
+use crate::state::{AppState, Pane, Split};
+use crate::theme::toast::ToastTheme;
 use ratzilla::ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::Style,
-    widgets::{Block, Widget},
+    widgets::{Block, Paragraph, Widget},
 };
+use tachyonfx::{Duration, EffectRenderer};
 
 pub fn render(f: &mut Frame, state: &AppState) {
+    state.layout.borrow_mut().reset(f.area());
+
     // Set global background to MANTLE
     let background = Block::default().style(Style::default().bg(state.current_theme.mantle()));
     background.render(f.area(), f.buffer_mut());
@@ -22,47 +53,147 @@ pub fn render(f: &mut Frame, state: &AppState) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Min(0),    // Main content
+            Constraint::Length(u16::from(state.offline)), // Offline banner
+            Constraint::Min(0),                           // Main content
             Constraint::Length(4), // Status line (4 rows: spacing + status + spacing + build info)
         ])
         .split(f.area());
+    render_offline_banner(f, state, chunks[0]);
+    let chunks = &chunks[1..];
+
+    // The editor's `:` line takes the bottom row of the main content
+    let main = if state.focus == Pane::Editor && state.command_line.is_some() {
+        let [main, command] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(chunks[0]);
+        command_line::render(f, state, command);
+        main
+    } else {
+        chunks[0]
+    };
 
     // Main content depends on current pane
     match state.focus {
         Pane::Splash => splash::render(f, state, chunks[0]),
         Pane::Menu => menu::render(f, state, chunks[0]),
         Pane::ContainerList => render_container_view(f, state, chunks[0]),
-        _ => render_main_content(f, state, chunks[0]),
+        Pane::ContainerGraph => render_graph_view(f, state, chunks[0]),
+        Pane::ContainerWatch => container_watch::render(f, state, chunks[0]),
+        Pane::Dashboard => dashboard::render(f, state, chunks[0]),
+        Pane::FileHistory => file_history::render(f, state, chunks[0]),
+        Pane::HexViewer => hex_viewer::render(f, state, chunks[0]),
+        Pane::ThemeEditor => theme_editor::render(f, state, chunks[0]),
+        Pane::KeybindEditor => keybind_editor::render(f, state, chunks[0]),
+        Pane::Actions => actions::render(f, state, chunks[0]),
+        Pane::Runbooks => runbooks::render(f, state, chunks[0]),
+        Pane::Insights => insights::render(f, state, chunks[0]),
+        Pane::Images => images::render(f, state, chunks[0]),
+        Pane::Logs => logs::render(f, state, chunks[0]),
+        _ => render_main_content(f, state, main),
     }
 
+    // Pane switch effect over the freshly drawn content
+    render_transition(f, state, chunks[0]);
+
     // Status line
     status_line::render(f, state, chunks[1]);
+
+    // Debug numbers above the panes, below anything modal
+    perf_hud::render(f, state);
+
+    // Modal overlay above panes and status line
+    overlay::render(f, state);
+
+    // Toasts stay visible above everything, out of the way in a corner
+    toasts::render(f, state);
+}
+
+/// Failed requests are not reported one by one while offline, this line
+/// says why nothing updates
+fn render_offline_banner(f: &mut Frame, state: &AppState, area: Rect) {
+    if !state.offline {
+        return;
+    }
+    let banner = Paragraph::new("offline — showing cached data")
+        .alignment(Alignment::Center)
+        .style(ToastTheme::offline_banner_style(&state.current_theme));
+    f.render_widget(banner, area);
 }
 
 fn render_main_content(f: &mut Frame, state: &AppState, area: ratzilla::ratatui::layout::Rect) {
+    // The problems drawer takes the bottom rows while it is open
+    let area = if state.problems.open {
+        let [area, drawer] = Layout::vertical([
+            Constraint::Min(1),
+            Constraint::Length(problems::DRAWER_HEIGHT),
+        ])
+        .areas(area);
+        problems::render(f, state, drawer);
+        area
+    } else {
+        area
+    };
+
+    let width = state.splits.get(Split::FileList);
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Percentage(25), // File list
-            Constraint::Percentage(1),  // Empty gap
-            Constraint::Percentage(74), // Editor
+            Constraint::Percentage(width),      // File list
+            Constraint::Percentage(1),          // Empty gap
+            Constraint::Percentage(99 - width), // Editor
         ])
         .split(area);
 
     file_list::render(f, state, chunks[0]);
     editor::render(f, state, chunks[2]);
+
+    let mut layout = state.layout.borrow_mut();
+    layout.add_pane(Pane::FileList, chunks[0]);
+    layout.add_pane(Pane::Editor, chunks[2]);
+    layout.add_divider(Split::FileList, chunks[1]);
 }
 
 fn render_container_view(f: &mut Frame, state: &AppState, area: ratzilla::ratatui::layout::Rect) {
+    let width = state.splits.get(Split::ContainerList);
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Percentage(40), // Container list
-            Constraint::Percentage(1),  // Empty gap
-            Constraint::Percentage(59), // Container details
+            Constraint::Percentage(width),      // Container list
+            Constraint::Percentage(1),          // Empty gap
+            Constraint::Percentage(99 - width), // Container details
         ])
         .split(area);
 
     container_list::render(f, state, chunks[0]);
     container_details::render(f, state, chunks[2]);
+
+    let mut layout = state.layout.borrow_mut();
+    layout.add_pane(Pane::ContainerList, chunks[0]);
+    layout.add_divider(Split::ContainerList, chunks[1]);
+}
+
+fn render_graph_view(f: &mut Frame, state: &AppState, area: ratzilla::ratatui::layout::Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(59), // Network graph
+            Constraint::Percentage(1),  // Empty gap
+            Constraint::Percentage(40), // Details of the selected node
+        ])
+        .split(area);
+
+    container_graph::render(f, state, chunks[0]);
+    container_details::render(f, state, chunks[2]);
+}
+
+/// Advance the effect of the last pane switch, dropped once it is done
+fn render_transition(f: &mut Frame, state: &AppState, area: Rect) {
+    let mut effect = state.transition.effect.borrow_mut();
+    let Some(active) = effect.as_mut() else {
+        return;
+    };
+    let delta = Duration::from_millis(state.transition.clock.delta_ms().into());
+    f.render_effect(&mut *active, area, delta);
+    if active.done() {
+        *effect = None;
+    }
 }
@@ -0,0 +1,39 @@
+use crate::{state::AppState, theme::overlay::OverlayTheme};
+use ratzilla::ratatui::text::{Line, Span};
+
+/// Headings of the open file with their line numbers, nested blocks indented
+pub fn lines(state: &AppState) -> Vec<Line<'static>> {
+    let theme = &state.current_theme;
+    let Some(outline) = &state.outline else {
+        return Vec::new();
+    };
+    if outline.sections.is_empty() {
+        return vec![Line::from(Span::styled(
+            "No sections found in this file",
+            OverlayTheme::label_style(theme),
+        ))];
+    }
+
+    outline
+        .sections
+        .iter()
+        .enumerate()
+        .map(|(i, section)| {
+            let line = Line::from(vec![
+                Span::styled(
+                    format!("{:>5}  ", section.row + 1),
+                    OverlayTheme::key_style(theme),
+                ),
+                Span::styled(
+                    format!("{}{}", "  ".repeat(section.depth), section.title),
+                    OverlayTheme::label_style(theme),
+                ),
+            ]);
+            if i == outline.selected {
+                line.style(OverlayTheme::selected_style(theme))
+            } else {
+                line
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,101 @@
+use crate::{
+    state::{AppState, Confirm, Overlay},
+    theme::overlay::OverlayTheme,
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Draw the open overlay and then the pending confirmation (if any) above everything else
+pub fn render(f: &mut Frame, state: &AppState) {
+    if let Some(overlay) = state.overlay.current {
+        let (title, lines) = match overlay {
+            Overlay::Help => (" Keybinds ", super::help::lines(state)),
+            Overlay::Upstream => (" Upstream ", super::upstream::lines(state)),
+            Overlay::Hygiene => (" Before saving ", super::hygiene::lines(state)),
+            Overlay::Outline => (" Outline ", super::outline::lines(state)),
+            Overlay::Hosts => (" Hosts ", super::hosts::lines(state)),
+        };
+        // The outline scrolls with its selection
+        let scroll = match (overlay, &state.outline) {
+            (Overlay::Outline, Some(outline)) => {
+                (outline.selected as u16).saturating_sub(f.area().height / 3)
+            }
+            _ => state.overlay.scroll,
+        };
+        render_modal(f, state, title, lines, scroll);
+    }
+
+    if let Some(confirm) = state.confirms.current() {
+        let theme = &state.current_theme;
+        let mut lines = vec![Line::from(Span::styled(
+            confirm.message.clone(),
+            OverlayTheme::label_style(theme),
+        ))];
+        if confirm.expected.is_some() {
+            lines.push(Line::from(Span::styled(
+                format!("> {}_", confirm.input),
+                OverlayTheme::verdict_style(theme, confirm.accepted().then_some(true)),
+            )));
+        }
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled(
+            confirm_help_text(state, confirm),
+            OverlayTheme::key_style(theme),
+        )));
+        render_modal(f, state, &format!(" {} ", confirm.title), lines, 0);
+    }
+}
+
+/// Answers of a dialog, save/discard/cancel for unsaved-changes prompts
+fn confirm_help_text(state: &AppState, confirm: &Confirm) -> String {
+    if confirm.unsaved {
+        state.keybinds.confirm.unsaved_help_text()
+    } else if confirm.expected.is_some() {
+        state.keybinds.confirm.typed_help_text()
+    } else {
+        state.keybinds.confirm.help_text()
+    }
+}
+
+/// Centered box with a border, scrolled down by `scroll` lines
+fn render_modal(
+    f: &mut Frame,
+    state: &AppState,
+    title: &str,
+    lines: Vec<Line<'static>>,
+    scroll: u16,
+) {
+    let theme = &state.current_theme;
+    let width = lines.iter().map(Line::width).max().unwrap_or(0) as u16 + 4;
+    let height = lines.len() as u16 + 2;
+    let area = centered(f.area(), width, height);
+
+    // Keep the last line reachable, however far the user scrolls
+    let visible = area.height.saturating_sub(2);
+    let scroll = scroll.min((lines.len() as u16).saturating_sub(visible));
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(OverlayTheme::border_style(theme))
+        .style(OverlayTheme::background_style(theme));
+    let paragraph = Paragraph::new(lines).block(block).scroll((scroll, 0));
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Rect of at most `width` x `height` in the middle of `area`
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let [row] = Layout::vertical([Constraint::Length(height.min(area.height))])
+        .flex(Flex::Center)
+        .areas(area);
+    let [rect] = Layout::horizontal([Constraint::Length(width.min(area.width))])
+        .flex(Flex::Center)
+        .areas(row);
+    rect
+}
@@ -0,0 +1,126 @@
+use super::file_list::format_size;
+use crate::{api, perf, state::AppState, theme::overlay::OverlayTheme};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// API calls listed, newest first
+const RECENT_REQUESTS: usize = 5;
+
+/// Rows kept free for the status line below the HUD
+const STATUS_LINE_HEIGHT: u16 = 4;
+
+/// Numbers to quote when the UI feels slow, in the bottom left corner
+/// above the status line. Keys keep going to the pane below.
+pub fn render(f: &mut Frame, state: &AppState) {
+    if !state.perf_hud {
+        return;
+    }
+    let theme = &state.current_theme;
+    let label = OverlayTheme::label_style(theme);
+    let value = OverlayTheme::key_style(theme);
+    let row = |name: &str, text: String, style: Style| {
+        Line::from(vec![
+            Span::styled(format!(" {:<8}", name), label),
+            Span::styled(text, style),
+        ])
+    };
+
+    let mut lines = Vec::new();
+
+    let frames = match perf::frame_stats() {
+        Some(stats) => format!(
+            "avg {:.1}ms  max {:.1}ms  {:.0} fps",
+            stats.avg_ms, stats.max_ms, stats.fps
+        ),
+        None => "no frames yet".to_string(),
+    };
+    lines.push(row("render", frames, value));
+
+    let size = |bytes: Option<u32>| bytes.map_or("?".to_string(), |b| format_size(u64::from(b)));
+    lines.push(row(
+        "memory",
+        format!(
+            "wasm {}  localStorage {}",
+            size(perf::wasm_memory_bytes()),
+            size(perf::local_storage_bytes())
+        ),
+        value,
+    ));
+
+    let editor_lines = state.editor.textarea.lines();
+    let editor_bytes: usize = editor_lines.iter().map(|l| l.len() + 1).sum();
+    lines.push(row(
+        "state",
+        format!(
+            "{} files  {} containers  editor {} lines ({})  {} toasts",
+            state.file_list.all.len(),
+            state.container_list.all.len(),
+            editor_lines.len(),
+            format_size(editor_bytes as u64),
+            state.notifications.iter().count()
+        ),
+        value,
+    ));
+
+    let rate = |rate: perf::HitRate| match rate.percent() {
+        Some(percent) => format!("{}% ({}/{})", percent, rate.hits, rate.hits + rate.misses),
+        None => "-".to_string(),
+    };
+    lines.push(row(
+        "cache",
+        format!(
+            "pane data {}  etag {}",
+            rate(perf::storage_rate()),
+            rate(perf::etag_rate())
+        ),
+        value,
+    ));
+
+    lines.push(row(
+        "api",
+        format!("{} in flight", api::timing::in_flight()),
+        value,
+    ));
+    for timing in api::timing::recent(RECENT_REQUESTS) {
+        let style = OverlayTheme::verdict_style(
+            theme,
+            Some(timing.ok && !api::timing::is_slow(timing.duration_ms)),
+        );
+        lines.push(row(
+            "",
+            format!(
+                "{} {}",
+                api::timing::format_duration(timing.duration_ms),
+                timing.label
+            ),
+            style,
+        ));
+    }
+
+    let area = f.area();
+    let width = (lines.iter().map(Line::width).max().unwrap_or(0) as u16 + 3)
+        .min(area.width.saturating_sub(2));
+    let height = lines.len() as u16 + 2;
+    if width < 10 || height + STATUS_LINE_HEIGHT > area.height {
+        return;
+    }
+    let rect = Rect::new(
+        area.x + 1,
+        area.bottom() - STATUS_LINE_HEIGHT - height,
+        width,
+        height,
+    );
+
+    let block = Block::default()
+        .title(" Performance ")
+        .borders(Borders::ALL)
+        .border_style(OverlayTheme::border_style(theme))
+        .style(OverlayTheme::background_style(theme));
+    f.render_widget(Clear, rect);
+    f.render_widget(Paragraph::new(lines).block(block), rect);
+}
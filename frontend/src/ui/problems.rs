@@ -0,0 +1,84 @@
+use crate::{
+    api::LintSeverity,
+    state::{AppState, SubPane},
+    theme::{SELECTED_PREFIX, editor::EditorTheme},
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+
+/// Rows of the drawer including its borders
+pub const DRAWER_HEIGHT: u16 = 8;
+
+/// Drawer with the problems of every checked file, below the file list and
+/// editor
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let problems = &state.problems;
+    let focused = state.has_focus(state.focus, SubPane::Problems);
+    let border_style = if focused {
+        theme.standard_border_focused()
+    } else {
+        Style::default().fg(theme.dim())
+    };
+    let (errors, warnings) = problems.counts();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            " Problems ({} errors, {} warnings) ",
+            errors, warnings
+        ))
+        .border_style(border_style);
+
+    state
+        .layout
+        .borrow_mut()
+        .add_sub_pane(SubPane::Problems, area);
+
+    if problems.is_empty() {
+        let empty = Paragraph::new(Line::from(Span::styled(
+            "No problems in the checked files",
+            Style::default().fg(theme.dim()),
+        )))
+        .block(block);
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = problems
+        .all()
+        .map(|problem| {
+            let (label, style) = match problem.severity {
+                LintSeverity::Error => ("error  ", EditorTheme::lint_error_style(theme)),
+                LintSeverity::Warning => ("warning", EditorTheme::lint_style(theme)),
+            };
+            let location = match problem.line {
+                Some(line) => format!("{}:{}", problem.file, line),
+                None => problem.file.clone(),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{} ", label), style),
+                Span::styled(format!("{}  ", location), theme.standard_normal_item()),
+                Span::styled(
+                    format!("{}  ", problem.source),
+                    Style::default().fg(theme.dim()),
+                ),
+                Span::styled(problem.message.clone(), theme.standard_normal_item()),
+            ]))
+        })
+        .collect();
+
+    let mut list = List::new(items).block(block);
+    if focused {
+        list = list
+            .highlight_style(theme.standard_selected_item())
+            .highlight_symbol(SELECTED_PREFIX);
+    }
+    let mut list_state = ListState::default();
+    list_state.select(Some(problems.selected));
+    f.render_stateful_widget(list, area, &mut list_state);
+}
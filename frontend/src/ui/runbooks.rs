@@ -0,0 +1,329 @@
+use crate::{
+    api::{JobStatus, RunbookStep, RunbookStepKind},
+    state::{AppState, Pane, runbooks::RunbookWalk},
+    theme::{ThemeConfig, actions::ActionsTheme, runbooks::RunbooksTheme},
+    utils::time::now_secs,
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Gauge, Paragraph},
+};
+
+/// Runbook list with the steps of the selected runbook; while walking through
+/// one, its steps with their progress, the current step and its output
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(35), // Runbook or step list
+            Constraint::Percentage(1),  // Empty gap
+            Constraint::Percentage(64), // Details + steps or output
+        ])
+        .split(area);
+
+    match &state.runbooks.walk {
+        Some(walk) => render_walk(f, state, walk, chunks[0], chunks[2]),
+        None => {
+            render_list(f, state, chunks[0]);
+            render_overview(f, state, chunks[2]);
+        }
+    }
+}
+
+fn border_style(state: &AppState) -> Style {
+    if state.is_focused(Pane::Runbooks) {
+        RunbooksTheme::border_focused(&state.current_theme)
+    } else {
+        RunbooksTheme::border_unfocused(&state.current_theme)
+    }
+}
+
+fn render_list(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let runbooks = &state.runbooks;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Runbooks ")
+        .border_style(border_style(state));
+
+    if runbooks.runbooks.is_empty() {
+        let message = if runbooks.loaded {
+            "No [[runbooks]] in sysrat.toml"
+        } else {
+            "Loading..."
+        };
+        let paragraph = Paragraph::new(message)
+            .block(block)
+            .style(RunbooksTheme::empty_style(theme));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let lines: Vec<Line> = runbooks
+        .runbooks
+        .iter()
+        .enumerate()
+        .map(|(i, runbook)| {
+            let line = Line::from(vec![
+                Span::styled(
+                    runbook.name.clone(),
+                    RunbooksTheme::normal_item_style(theme),
+                ),
+                Span::styled(
+                    format!(" ({} steps)", runbook.steps.len()),
+                    RunbooksTheme::hint_style(theme),
+                ),
+            ]);
+            if i == runbooks.selected_index {
+                line.style(RunbooksTheme::selected_item_style(theme))
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    // Keep the selection in view (2 rows of border)
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = (runbooks.selected_index + 1).saturating_sub(visible);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+/// Description and steps of the selected runbook
+fn render_overview(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Steps ")
+        .border_style(RunbooksTheme::border_unfocused(theme));
+
+    let Some(runbook) = state.runbooks.selected() else {
+        f.render_widget(block, area);
+        return;
+    };
+
+    let mut lines = Vec::new();
+    if !runbook.description.is_empty() {
+        lines.push(Line::from(Span::styled(
+            runbook.description.clone(),
+            RunbooksTheme::value_style(theme),
+        )));
+        lines.push(Line::default());
+    }
+    lines.extend(runbook.steps.iter().enumerate().map(|(i, step)| {
+        Line::from(vec![
+            Span::styled(
+                format!("{:>2}. {}", i + 1, step.title),
+                RunbooksTheme::normal_item_style(theme),
+            ),
+            Span::styled(
+                format!("  {}", step_summary(step)),
+                RunbooksTheme::hint_style(theme),
+            ),
+        ])
+    }));
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn render_walk(f: &mut Frame, state: &AppState, walk: &RunbookWalk, left: Rect, right: Rect) {
+    let left = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),    // Steps
+            Constraint::Length(3), // Progress
+        ])
+        .split(left);
+    let right = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(8), // Current step
+            Constraint::Min(0),    // Output
+        ])
+        .split(right);
+
+    render_steps(f, state, walk, left[0]);
+    render_progress(f, &state.current_theme, walk, left[1]);
+    render_step_details(f, &state.current_theme, walk, right[0]);
+    render_output(f, state, walk, right[1]);
+}
+
+fn render_steps(f: &mut Frame, state: &AppState, walk: &RunbookWalk, area: Rect) {
+    let theme = &state.current_theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {} ", walk.runbook.name))
+        .border_style(border_style(state));
+
+    let lines: Vec<Line> = walk
+        .runbook
+        .steps
+        .iter()
+        .zip(&walk.statuses)
+        .enumerate()
+        .map(|(i, (step, status))| {
+            let line = Line::from(vec![
+                Span::styled(
+                    format!("{} ", status.marker()),
+                    RunbooksTheme::step_style(theme, *status),
+                ),
+                Span::styled(step.title.clone(), RunbooksTheme::normal_item_style(theme)),
+            ]);
+            if i == walk.current {
+                line.style(RunbooksTheme::selected_item_style(theme))
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    // Keep the current step in view (2 rows of border)
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = (walk.current + 1).saturating_sub(visible);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+/// Steps through so far out of all steps
+fn render_progress(f: &mut Frame, theme: &ThemeConfig, walk: &RunbookWalk, area: Rect) {
+    let total = walk.runbook.steps.len();
+    let ratio = if total == 0 {
+        1.0
+    } else {
+        walk.current.min(total) as f64 / total as f64
+    };
+    let gauge = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Progress ")
+                .border_style(RunbooksTheme::border_unfocused(theme)),
+        )
+        .gauge_style(RunbooksTheme::progress_style(theme))
+        .ratio(ratio)
+        .label(format!("{}/{}", walk.current.min(total), total));
+    f.render_widget(gauge, area);
+}
+
+fn render_step_details(f: &mut Frame, theme: &ThemeConfig, walk: &RunbookWalk, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Step ")
+        .border_style(RunbooksTheme::border_unfocused(theme));
+
+    let Some(step) = walk.current_step() else {
+        let paragraph = Paragraph::new(walk.summary())
+            .block(block)
+            .style(RunbooksTheme::empty_style(theme));
+        f.render_widget(paragraph, area);
+        return;
+    };
+
+    let row = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<9} ", label), RunbooksTheme::label_style(theme)),
+            Span::styled(value, RunbooksTheme::value_style(theme)),
+        ])
+    };
+    let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+    let lines = vec![
+        row(
+            "Step",
+            format!(
+                "{}/{} {}",
+                walk.current + 1,
+                walk.runbook.steps.len(),
+                step.title
+            ),
+        ),
+        row("About", step.description.clone()),
+        row("Kind", step_summary(step)),
+        row("Role", or_dash(step.role.clone())),
+        row("Params", {
+            let mut values: Vec<String> = step
+                .params
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect();
+            values.sort();
+            or_dash((!values.is_empty()).then(|| values.join(", ")))
+        }),
+    ];
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn render_output(f: &mut Frame, state: &AppState, walk: &RunbookWalk, area: Rect) {
+    let theme = &state.current_theme;
+
+    let Some(job) = &walk.job else {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Output ")
+            .border_style(RunbooksTheme::border_unfocused(theme));
+        let paragraph = Paragraph::new("No action step run yet")
+            .block(block)
+            .style(RunbooksTheme::empty_style(theme));
+        f.render_widget(paragraph, area);
+        return;
+    };
+
+    let elapsed = job.finished.unwrap_or(now_secs() as i64) - job.started;
+    let status = match job.status {
+        JobStatus::Running => "running".to_string(),
+        JobStatus::Succeeded | JobStatus::Failed => job.message.clone(),
+    };
+    let title = Line::from(vec![
+        Span::raw(format!(" #{} {} ", job.id, job.target)),
+        Span::styled(
+            format!("{} ", status),
+            ActionsTheme::status_style(theme, job.status),
+        ),
+        Span::raw(format!("{}s ", elapsed.max(0))),
+    ]);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(RunbooksTheme::border_unfocused(theme));
+
+    let lines: Vec<Line> = job
+        .output
+        .lines()
+        .map(|line| {
+            Line::from(Span::styled(
+                line.to_string(),
+                RunbooksTheme::output_style(theme),
+            ))
+        })
+        .collect();
+
+    // Show the end of the output, minus what the user scrolled back
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = lines
+        .len()
+        .saturating_sub(visible)
+        .saturating_sub(state.runbooks.output_scroll as usize);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+/// What a step does, e.g. "run reload-nginx"
+fn step_summary(step: &RunbookStep) -> String {
+    let target = step.target.as_deref().unwrap_or("-");
+    match step.kind {
+        RunbookStepKind::Action => format!("run {}", target),
+        RunbookStepKind::Edit => format!("edit {}", target),
+        RunbookStepKind::Check => "manual check".to_string(),
+    }
+}
@@ -0,0 +1,36 @@
+use crate::theme::ThemeConfig;
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+};
+
+/// Bar widths in percent of the pane, repeated so the bars read like lines of
+/// text of different lengths
+const BAR_WIDTHS: [u16; 5] = [70, 45, 85, 55, 35];
+
+/// Dimmed placeholder bars inside `block`, shown while the first fetch of a
+/// pane is in flight and there is no cached data yet
+pub fn render(f: &mut Frame, theme: &ThemeConfig, block: Block, area: Rect) {
+    let inner = block.inner(area);
+    let style = Style::default().fg(theme.surface1());
+
+    // A bar on every other row, like a list with a little spacing
+    let lines: Vec<Line> = (0..inner.height)
+        .map(|row| {
+            if row % 2 == 1 {
+                return Line::default();
+            }
+            let percent = BAR_WIDTHS[(row / 2) as usize % BAR_WIDTHS.len()];
+            let width = inner.width.saturating_sub(2) * percent / 100;
+            Line::from(Span::styled(
+                format!(" {}", "▆".repeat(width as usize)),
+                style,
+            ))
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
@@ -12,22 +12,24 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
 
     // We want to center the text in the area
     let menu_ascii = include_str!("../../assets/menu-text.ascii");
+    let branding = &state.branding;
+    let mut text = branding.banner.as_deref().unwrap_or(menu_ascii).to_string();
+    if let Some(motd) = &branding.motd {
+        text.push_str("\n\n");
+        text.push_str(motd);
+    }
 
-    let widget = Paragraph::new(menu_ascii)
+    let widget = Paragraph::new(text)
         .alignment(Alignment::Center)
         .style(Style::default().fg(ratzilla::ratatui::style::Color::Red))
         .block(Block::default().borders(Borders::ALL).title(" Sysrat "));
 
     f.render_widget(widget, area);
 
-    // Apply rainbow effect
-    // We assume ~60fps for smoothness, passing 16ms delta
-    // web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(
-    //     "[DEBUG] Rendering Splash Screen",
-    // ));
+    // Advance the intro by the time since the last frame
     f.render_effect(
         &mut *state.splash.effect.borrow_mut(),
         area,
-        Duration::from_millis(16),
+        Duration::from_millis(state.splash.clock.delta_ms().into()),
     );
 }
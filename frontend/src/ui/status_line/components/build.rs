@@ -1,4 +1,9 @@
-use crate::theme::{ThemeConfig, status_line::StatusLineTheme};
+use crate::{
+    api,
+    state::AppState,
+    storage,
+    theme::{ThemeConfig, status_line::StatusLineTheme},
+};
 use ratzilla::ratatui::{style::Style, text::Span};
 
 pub fn render_build_date(style: Option<&str>, theme: &ThemeConfig) -> Option<Span<'static>> {
@@ -25,6 +30,38 @@ pub fn render_rust_edition(style: Option<&str>, theme: &ThemeConfig) -> Option<S
     Some(Span::styled(text, s))
 }
 
+/// Nothing until the server answered; a frontend built from another version
+/// may not understand it, so that is flagged until the page is reloaded
+pub fn render_server_version(
+    state: &AppState,
+    style: Option<&str>,
+    theme: &ThemeConfig,
+) -> Option<Span<'static>> {
+    let server = state.server_version.as_ref()?;
+    let frontend = env!("CARGO_PKG_VERSION");
+    if server.version == frontend {
+        return Some(Span::styled(
+            server.version.clone(),
+            get_style(style, theme),
+        ));
+    }
+    Some(Span::styled(
+        format!("{} != frontend v{} (reload)", server.version, frontend),
+        StatusLineTheme::error_message_style(theme),
+    ))
+}
+
+/// Name of the picked host, else the serving server's `host[:port]`
+pub fn render_host(style: Option<&str>, theme: &ThemeConfig) -> Option<Span<'static>> {
+    match storage::hosts::active() {
+        Some(host) => Some(Span::styled(
+            host.name,
+            StatusLineTheme::modified_style(theme),
+        )),
+        None => Some(Span::styled(api::host(), get_style(style, theme))),
+    }
+}
+
 pub fn render_dependency(
     name: &str,
     style: Option<&str>,
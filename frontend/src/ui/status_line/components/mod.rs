@@ -20,10 +20,18 @@ pub fn render_component(
 
         ComponentConfig::ModifiedIndicator => state::render_modified_indicator(state, theme),
 
+        ComponentConfig::ProblemCounts => state::render_problem_counts(state, theme),
+
         ComponentConfig::StatusMessage => state::render_status_message(state, theme),
 
+        ComponentConfig::ConnectionStatus => state::render_connection_status(state, theme),
+
+        ComponentConfig::LastRequest => state::render_last_request(theme),
+
         ComponentConfig::HelpText => state::render_help_text(state, theme),
 
+        ComponentConfig::RefreshStatus => state::render_refresh_status(state, theme),
+
         ComponentConfig::BuildDate { style } => build::render_build_date(style.as_deref(), theme),
 
         ComponentConfig::BuildHash { style } => build::render_build_hash(style.as_deref(), theme),
@@ -36,6 +44,12 @@ pub fn render_component(
             build::render_rust_edition(style.as_deref(), theme)
         }
 
+        ComponentConfig::ServerVersion { style } => {
+            build::render_server_version(state, style.as_deref(), theme)
+        }
+
+        ComponentConfig::Host { style } => build::render_host(style.as_deref(), theme),
+
         ComponentConfig::Dependency { name, style } => {
             build::render_dependency(name, style.as_deref(), theme)
         }
@@ -1,5 +1,6 @@
 use crate::{
-    state::{AppState, Pane, VimMode},
+    api,
+    state::{AppState, Overlay, Pane, SubPane, VimMode},
     theme::{ThemeConfig, status_line::StatusLineTheme},
 };
 use ratzilla::ratatui::text::Span;
@@ -53,6 +54,23 @@ pub fn render_modified_indicator(state: &AppState, theme: &ThemeConfig) -> Optio
     }
 }
 
+pub fn render_problem_counts(state: &AppState, theme: &ThemeConfig) -> Option<Span<'static>> {
+    if !matches!(state.focus, Pane::FileList | Pane::Editor) {
+        return None;
+    }
+
+    let (errors, warnings) = state.problems.counts();
+    if errors + warnings == 0 {
+        return None;
+    }
+    let style = if errors > 0 {
+        StatusLineTheme::error_message_style(theme)
+    } else {
+        StatusLineTheme::modified_style(theme)
+    };
+    Some(Span::styled(format!("[{}E {}W]", errors, warnings), style))
+}
+
 pub fn render_status_message(state: &AppState, theme: &ThemeConfig) -> Option<Span<'static>> {
     if let Some(ref msg) = state.status_message {
         let style = if msg.starts_with("[ERROR") {
@@ -66,18 +84,183 @@ pub fn render_status_message(state: &AppState, theme: &ThemeConfig) -> Option<Sp
     }
 }
 
+pub fn render_connection_status(state: &AppState, theme: &ThemeConfig) -> Option<Span<'static>> {
+    let backoff = &state.refresh_backoff;
+    // Offline the banner above the panes takes its place
+    if state.offline || !backoff.is_backing_off() || !state.focus.has_background_refresh() {
+        return None;
+    }
+
+    let text = if backoff.in_flight {
+        "[reconnecting...]".to_string()
+    } else {
+        format!(
+            "[reconnecting in {}s]",
+            backoff.seconds_until_retry(js_sys::Date::now())
+        )
+    };
+    Some(Span::styled(
+        text,
+        StatusLineTheme::error_message_style(theme),
+    ))
+}
+
+pub fn render_last_request(theme: &ThemeConfig) -> Option<Span<'static>> {
+    let timing = api::timing::last()?;
+    let style = if !timing.ok || api::timing::is_slow(timing.duration_ms) {
+        StatusLineTheme::error_message_style(theme)
+    } else {
+        StatusLineTheme::label_style(theme)
+    };
+    Some(Span::styled(
+        format!(
+            "{} {}",
+            timing.label,
+            api::timing::format_duration(timing.duration_ms)
+        ),
+        style,
+    ))
+}
+
+/// Frames of the refresh spinner, one every SPINNER_FRAME_MS
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const SPINNER_FRAME_MS: f64 = 100.0;
+/// The first request of a refresh is sent on the next tick, until then
+/// nothing is in flight yet
+const REFRESH_GRACE_MS: f64 = 500.0;
+/// How long the outcome of a refresh stays on screen
+const REFRESH_SUMMARY_MS: f64 = 4_000.0;
+
+pub fn render_refresh_status(state: &AppState, theme: &ThemeConfig) -> Option<Span<'static>> {
+    let refresh = state.manual_refresh?;
+    let now = js_sys::Date::now();
+    let timings = api::timing::since(refresh.started);
+
+    if api::timing::in_flight() > 0
+        || (timings.is_empty() && now - refresh.started < REFRESH_GRACE_MS)
+    {
+        let frame = ((now / SPINNER_FRAME_MS) as usize) % SPINNER.len();
+        return Some(Span::styled(
+            format!(" {} refreshing {}", SPINNER[frame], refresh.pane.as_str()),
+            StatusLineTheme::status_message_style(theme),
+        ));
+    }
+
+    let finished = timings.last()?.finished_at;
+    if now - finished > REFRESH_SUMMARY_MS {
+        return None;
+    }
+    let took = api::timing::format_duration(finished - refresh.started);
+    match timings.iter().find(|timing| !timing.ok) {
+        Some(failed) => Some(Span::styled(
+            format!(" [Refresh failed: {}]", failed.label),
+            StatusLineTheme::error_message_style(theme),
+        )),
+        None => Some(Span::styled(
+            format!(
+                " {} refreshed ({} request{}, {})",
+                refresh.pane.as_str(),
+                timings.len(),
+                if timings.len() == 1 { "" } else { "s" },
+                took
+            ),
+            StatusLineTheme::status_message_style(theme),
+        )),
+    }
+}
+
 pub fn render_help_text(state: &AppState, theme: &ThemeConfig) -> Option<Span<'static>> {
     // No help text in Menu pane
     let help_text = match (state.focus, state.vim_mode) {
+        _ if state.confirms.current().is_some_and(|c| c.unsaved) => {
+            state.keybinds.confirm.unsaved_help_text()
+        }
+        _ if state.confirms.current().is_some() => state.keybinds.confirm.help_text(),
+        _ if state.overlay.current == Some(Overlay::Upstream) => {
+            state.keybinds.global.upstream_help_text()
+        }
+        _ if state.overlay.current == Some(Overlay::Outline) => {
+            state.keybinds.global.outline_help_text()
+        }
+        _ if state.overlay.current == Some(Overlay::Hosts) => {
+            state.keybinds.global.hosts_help_text()
+        }
+        _ if state.overlay.current == Some(Overlay::Hygiene) => {
+            state.keybinds.global.hygiene_help_text()
+        }
+        _ if state.overlay.is_open() => state.keybinds.global.overlay_help_text(),
         (Pane::Menu, _) => String::new(), // Menu has no pane-specific help
         (Pane::Splash, _) => String::new(), // Splash has no pane-specific help
+        (Pane::FileList | Pane::Editor, _) if state.sub_focus == SubPane::Problems => {
+            state.keybinds.problems.help_text(&state.keybinds.global)
+        }
         (Pane::FileList, _) => state.keybinds.file_list.help_text(&state.keybinds.global),
+        (Pane::Editor, _) if state.command_line.is_some() => {
+            state.keybinds.global.editor_command_help_text()
+        }
+        (Pane::Editor, VimMode::Normal) if state.editor.form.is_some() => {
+            state.keybinds.global.editor_form_help_text()
+        }
+        (Pane::Editor, VimMode::Insert) if state.editor.form.is_some() => {
+            state.keybinds.global.editor_table_edit_help_text()
+        }
+        (Pane::Editor, VimMode::Normal) if state.editor.table.is_some() => {
+            state.keybinds.global.editor_table_help_text()
+        }
+        (Pane::Editor, VimMode::Insert) if state.editor.table.is_some() => {
+            state.keybinds.global.editor_table_edit_help_text()
+        }
         (Pane::Editor, VimMode::Normal) => state.keybinds.global.editor_normal_help_text(),
         (Pane::Editor, VimMode::Insert) => state.keybinds.global.editor_insert_help_text(),
+        (Pane::ContainerList | Pane::ContainerGraph, _)
+            if state.sub_focus == SubPane::ContainerDetails =>
+        {
+            state
+                .keybinds
+                .container_details
+                .help_text(&state.keybinds.global)
+        }
         (Pane::ContainerList, _) => state
             .keybinds
             .container_list
             .help_text(&state.keybinds.global),
+        (Pane::ContainerGraph, _) => state
+            .keybinds
+            .container_graph
+            .help_text(&state.keybinds.global),
+        (Pane::ContainerWatch, _) => state.keybinds.container_watch.help_text(),
+        (Pane::Dashboard, _) => state.keybinds.dashboard.help_text(&state.keybinds.global),
+        (Pane::FileHistory, _) => state
+            .keybinds
+            .file_history
+            .help_text(&state.keybinds.global),
+        (Pane::HexViewer, _) => state.keybinds.hex_viewer.help_text(&state.keybinds.global),
+        (Pane::Actions, VimMode::Insert) if state.actions.params.is_some() => {
+            state.keybinds.global.editor_table_edit_help_text()
+        }
+        (Pane::Actions, _) if state.actions.params.is_some() => {
+            state.keybinds.actions.params_help_text()
+        }
+        (Pane::Actions, _) => state.keybinds.actions.help_text(&state.keybinds.global),
+        (Pane::Runbooks, _) if state.runbooks.walk.is_some() => {
+            state.keybinds.runbooks.walk_help_text()
+        }
+        (Pane::Runbooks, _) => state.keybinds.runbooks.help_text(&state.keybinds.global),
+        (Pane::Insights, _) => state.keybinds.insights.help_text(&state.keybinds.global),
+        (Pane::Images, _) => state.keybinds.images.help_text(&state.keybinds.global),
+        (Pane::Logs, _) if state.logs.search_input => state.keybinds.logs.search_help_text(),
+        (Pane::Logs, _) => state.keybinds.logs.help_text(&state.keybinds.global),
+        (Pane::KeybindEditor, _) if state.keybind_editor.capturing => {
+            "press the new key ESC:cancel".to_string()
+        }
+        (Pane::KeybindEditor, _) => state
+            .keybinds
+            .keybind_editor
+            .help_text(&state.keybinds.global),
+        (Pane::ThemeEditor, _) => state
+            .keybinds
+            .theme_editor
+            .help_text(&state.keybinds.global),
     };
 
     if !help_text.is_empty() {
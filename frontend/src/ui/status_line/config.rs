@@ -1,5 +1,36 @@
-use crate::state::Pane;
+use crate::state::{AppState, Pane, VimMode};
 use serde::Deserialize;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Layout selected at build time (see build_helpers/statusline.rs)
+const EMBEDDED_LAYOUT: &str = include_str!(env!("STATUSLINE_CONFIG_PATH"));
+
+thread_local! {
+    /// Parsed layout, the embedded one until the server sends another
+    static LAYOUT: RefCell<Option<Rc<StatusLineConfig>>> = const { RefCell::new(None) };
+}
+
+/// Layout to render with, parsed once
+pub fn current() -> Result<Rc<StatusLineConfig>, toml::de::Error> {
+    LAYOUT.with(|layout| {
+        let mut layout = layout.borrow_mut();
+        if let Some(config) = layout.as_ref() {
+            return Ok(Rc::clone(config));
+        }
+        let config = Rc::new(toml::from_str::<StatusLineConfig>(EMBEDDED_LAYOUT)?);
+        *layout = Some(Rc::clone(&config));
+        Ok(config)
+    })
+}
+
+/// Replace the layout with one fetched from `/api/statusline`; one that
+/// does not parse leaves the current layout in place
+pub fn set_runtime_layout(content: &str) -> Result<(), String> {
+    let config: StatusLineConfig = toml::from_str(content).map_err(|e| e.to_string())?;
+    LAYOUT.with(|layout| *layout.borrow_mut() = Some(Rc::new(config)));
+    Ok(())
+}
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct StatusLineConfig {
@@ -16,18 +47,118 @@ pub struct PaneConfig {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct RowConfig {
-    pub components: Vec<ComponentConfig>,
+    pub components: Vec<ComponentEntry>,
+    /// Where the components before a spacer go, the ones after it are
+    /// always right-aligned
+    #[serde(default)]
+    pub align: RowAlign,
+    /// Row is left out unless this holds
+    #[serde(default)]
+    pub when: Option<Condition>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RowAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// A component plus the condition it is shown under
+#[derive(Debug, Clone, Deserialize)]
+pub struct ComponentEntry {
+    #[serde(flatten)]
+    pub component: ComponentConfig,
+    #[serde(default)]
+    pub when: Option<Condition>,
+}
+
+/// State a component or row can depend on
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Condition {
+    /// The open file has unsaved edits
+    Modified,
+    /// The open file has none
+    Saved,
+    FileOpen,
+    NoFile,
+    Online,
+    Offline,
+    /// A status message is shown
+    Message,
+    /// The status message is an error
+    Error,
+    Insert,
+    Normal,
+}
+
+impl Condition {
+    pub fn holds(self, state: &AppState) -> bool {
+        match self {
+            Condition::Modified => state.dirty,
+            Condition::Saved => !state.dirty,
+            Condition::FileOpen => state.editor.current_file.is_some(),
+            Condition::NoFile => state.editor.current_file.is_none(),
+            Condition::Online => !state.offline,
+            Condition::Offline => state.offline,
+            Condition::Message => state.status_message.is_some(),
+            Condition::Error => state
+                .status_message
+                .as_deref()
+                .is_some_and(|msg| msg.starts_with("[ERROR")),
+            Condition::Insert => state.vim_mode == VimMode::Insert,
+            Condition::Normal => state.vim_mode == VimMode::Normal,
+        }
+    }
+}
+
+impl RowConfig {
+    pub fn is_visible(&self, state: &AppState) -> bool {
+        self.when.is_none_or(|when| when.holds(state))
+    }
+
+    /// Components before and after the first spacer. A row holding nothing
+    /// but a spacer stays an empty line instead of being split.
+    pub fn split_at_spacer(&self) -> (&[ComponentEntry], &[ComponentEntry]) {
+        let spacer = self
+            .components
+            .iter()
+            .position(|entry| matches!(entry.component, ComponentConfig::Spacer));
+        match spacer {
+            Some(index) if self.components.len() > 1 => {
+                (&self.components[..index], &self.components[index + 1..])
+            }
+            _ => (&self.components, &[]),
+        }
+    }
+}
+
+impl ComponentEntry {
+    pub fn is_visible(&self, state: &AppState) -> bool {
+        self.when.is_none_or(|when| when.holds(state))
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ComponentConfig {
+    /// Empty line on its own, otherwise pushes the rest of the row to the
+    /// right edge
     Spacer,
     VimMode,
     Filename,
     ModifiedIndicator,
+    /// Error and warning counts of the problems drawer
+    ProblemCounts,
     StatusMessage,
+    ConnectionStatus,
+    LastRequest,
     HelpText,
+    /// Spinner while a refresh key press is answered, then its outcome
+    RefreshStatus,
     BuildDate {
         #[serde(default)]
         style: Option<String>,
@@ -44,6 +175,16 @@ pub enum ComponentConfig {
         #[serde(default)]
         style: Option<String>,
     },
+    /// Version of the connected server, a warning when it differs from ours
+    ServerVersion {
+        #[serde(default)]
+        style: Option<String>,
+    },
+    /// Server the UI talks to, stands out when another host is picked
+    Host {
+        #[serde(default)]
+        style: Option<String>,
+    },
     Dependency {
         name: String,
         #[serde(default)]
@@ -66,6 +207,14 @@ impl StatusLineConfig {
             Pane::FileList => &self.file_list,
             Pane::Editor => &self.editor,
             Pane::ContainerList => &self.container_list,
+            Pane::ContainerGraph | Pane::ContainerWatch => &self.container_list,
+            Pane::Dashboard => &self.container_list,
+            Pane::Actions | Pane::Runbooks | Pane::Insights | Pane::Images | Pane::Logs => {
+                &self.container_list
+            }
+            Pane::FileHistory => &self.editor,
+            Pane::HexViewer => &self.file_list,
+            Pane::ThemeEditor | Pane::KeybindEditor => &self.menu,
             Pane::Splash => &self.menu, // Splash uses same status line as Menu
         }
     }
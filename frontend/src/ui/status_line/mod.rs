@@ -2,58 +2,77 @@ mod components;
 mod config;
 mod rendering;
 
+pub use config::set_runtime_layout;
+
 use crate::{state::AppState, theme::status_line::StatusLineTheme};
-use config::StatusLineConfig;
+use config::RowAlign;
 use ratzilla::ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    text::Line,
+    text::{Line, Span},
     widgets::Paragraph,
 };
 
 pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
-    use ratzilla::ratatui::text::Span;
-
-    // Load embedded config from build time using include_str!
-    // Path is relative to this file's location
-    let config_toml = include_str!("../../../../sys/layout/statusline.toml");
-
-    // Try to parse, fall back to simple rendering on error
-    let config_result: Result<StatusLineConfig, _> = toml::from_str(config_toml);
-
-    if let Err(e) = config_result {
-        // Fallback: render error message
-        let error_line = Paragraph::new(Line::from(vec![Span::raw(format!(
-            "TOML parse error: {}",
-            e
-        ))]))
-        .style(StatusLineTheme::background(&state.current_theme))
-        .alignment(Alignment::Left);
-        f.render_widget(error_line, area);
-        return;
-    }
+    let theme = &state.current_theme;
+
+    let config = match config::current() {
+        Ok(config) => config,
+        Err(e) => {
+            // Fallback: render error message
+            let error_line = Paragraph::new(Line::from(vec![Span::raw(format!(
+                "TOML parse error: {}",
+                e
+            ))]))
+            .style(StatusLineTheme::background(theme))
+            .alignment(Alignment::Left);
+            f.render_widget(error_line, area);
+            return;
+        }
+    };
 
-    let config = config_result.unwrap();
     let pane_config = config.get_pane_config(&state.focus);
-    let theme = &state.current_theme;
+    let visible_rows: Vec<_> = pane_config
+        .rows
+        .iter()
+        .filter(|row| row.is_visible(state))
+        .collect();
 
     // Create row constraints dynamically based on config
-    let row_count = pane_config.rows.len();
-    let constraints: Vec<Constraint> = vec![Constraint::Length(1); row_count];
-
+    let constraints = vec![Constraint::Length(1); visible_rows.len()];
     let rows = Layout::default()
         .direction(Direction::Vertical)
         .constraints(constraints)
         .split(area);
 
+    // Background once, so the gap between the two sides of a row is filled
+    f.render_widget(
+        Paragraph::new("").style(StatusLineTheme::background(theme)),
+        area,
+    );
+
     // Render each row
-    for (row_idx, row_config) in pane_config.rows.iter().enumerate() {
-        let spans = rendering::render_row_with_spacing(row_config, state, theme);
+    for (row_idx, row_config) in visible_rows.iter().enumerate() {
+        let (start, end) = row_config.split_at_spacer();
 
+        let spans = rendering::render_row_with_spacing(start, state, theme);
+        let alignment = match row_config.align {
+            RowAlign::Left => Alignment::Left,
+            RowAlign::Center => Alignment::Center,
+            RowAlign::Right => Alignment::Right,
+        };
         let line = Paragraph::new(Line::from(spans))
             .style(StatusLineTheme::background(theme))
-            .alignment(Alignment::Left);
-
+            .alignment(alignment);
         f.render_widget(line, rows[row_idx]);
+
+        if !end.is_empty() {
+            let mut spans = rendering::render_row_with_spacing(end, state, theme);
+            spans.push(Span::raw(" "));
+            f.render_widget(
+                Paragraph::new(Line::from(spans)).alignment(Alignment::Right),
+                rows[row_idx],
+            );
+        }
     }
 }
@@ -3,15 +3,16 @@ use crate::{
     theme::ThemeConfig,
     ui::status_line::{
         components,
-        config::{ComponentConfig, RowConfig},
+        config::{ComponentConfig, ComponentEntry},
     },
 };
 use ratzilla::ratatui::text::Span;
 
 /// Render a row's components with intelligent spacing between them.
-/// Only adds spaces between components that actually render (return Some).
+/// Only adds spaces between components that actually render (return Some)
+/// and whose condition holds.
 pub fn render_row_with_spacing(
-    row_config: &RowConfig,
+    components: &[ComponentEntry],
     state: &AppState,
     theme: &ThemeConfig,
 ) -> Vec<Span<'static>> {
@@ -20,7 +21,8 @@ pub fn render_row_with_spacing(
     let mut last_was_opening_paren = false;
     let mut is_first_component = true;
 
-    for component_config in &row_config.components {
+    for entry in components.iter().filter(|entry| entry.is_visible(state)) {
+        let component_config = &entry.component;
         if let Some(span) = components::render_component(component_config, state, theme) {
             let is_spacing = is_spacing_component(component_config);
             let is_closing_paren = is_closing_parenthesis(component_config);
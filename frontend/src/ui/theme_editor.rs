@@ -0,0 +1,158 @@
+use crate::{
+    state::{AppState, theme_editor::CHANNELS},
+    theme::{ThemeConfig, theme_editor::ThemeEditorTheme},
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+const SWATCH: &str = "██ ";
+
+/// Base colors of the edited theme on the left, semantic roles and a preview on the right
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(45), // Base colors
+            Constraint::Percentage(1),  // Empty gap
+            Constraint::Percentage(54), // Semantic roles + preview
+        ])
+        .split(area);
+
+    render_colors(f, state, chunks[0]);
+    render_semantic(f, &state.current_theme, chunks[2]);
+}
+
+fn render_colors(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let editor = &state.theme_editor;
+    let title = format!(
+        " Theme Editor: {}{} ",
+        editor.name,
+        if editor.dirty { " [modified]" } else { "" }
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(ThemeEditorTheme::border_focused(theme));
+
+    let name_width = editor.colors.iter().map(String::len).max().unwrap_or(0);
+    let lines: Vec<Line> = editor
+        .colors
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let is_selected = i == editor.selected_index;
+            let rgb = theme.base.get(name);
+            let name_style = if is_selected {
+                ThemeEditorTheme::selected_item_style(theme)
+            } else {
+                ThemeEditorTheme::normal_item_style(theme)
+            };
+
+            let mut spans = vec![
+                Span::styled(
+                    SWATCH,
+                    ThemeEditorTheme::swatch_style(theme.get_base_color(name)),
+                ),
+                Span::styled(format!("{:<name_width$} ", name), name_style),
+            ];
+            for (channel, label) in CHANNELS.iter().enumerate() {
+                let style = if is_selected && channel == editor.channel {
+                    ThemeEditorTheme::active_channel_style(theme)
+                } else {
+                    ThemeEditorTheme::label_style(theme)
+                };
+                spans.push(Span::styled(
+                    format!("{}:{:>3}", label, rgb[channel]),
+                    style,
+                ));
+                spans.push(Span::raw(" "));
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    // Keep the selection in view (2 rows of border)
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = (editor.selected_index + 1).saturating_sub(visible);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((offset as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+fn render_semantic(f: &mut Frame, theme: &ThemeConfig, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Semantic Colors ")
+        .border_style(ThemeEditorTheme::border_unfocused(theme));
+
+    let role_width = theme
+        .semantic
+        .entries()
+        .iter()
+        .map(|(role, _)| role.len())
+        .max()
+        .unwrap_or(0);
+    let mut lines: Vec<Line> = theme
+        .semantic
+        .entries()
+        .iter()
+        .map(|(role, base)| {
+            Line::from(vec![
+                Span::styled(
+                    SWATCH,
+                    ThemeEditorTheme::swatch_style(theme.get_base_color(base)),
+                ),
+                Span::styled(
+                    format!("{:<role_width$} ", role),
+                    ThemeEditorTheme::normal_item_style(theme),
+                ),
+                Span::styled(format!("-> {}", base), ThemeEditorTheme::label_style(theme)),
+            ])
+        })
+        .collect();
+
+    // Sample of the standard styles, so edits show up in context
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("Preview", theme.standard_title())));
+    lines.push(Line::from(Span::styled(
+        "  normal item",
+        theme.standard_normal_item(),
+    )));
+    lines.push(Line::from(Span::styled(
+        "> selected item",
+        theme.standard_selected_item(),
+    )));
+    lines.push(Line::from(Span::styled(
+        "  highlighted row",
+        theme.standard_highlight_bg().fg(theme.text()),
+    )));
+    lines.push(Line::from(vec![
+        Span::styled("  label: ", theme.standard_label()),
+        Span::styled("value", theme.standard_value()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  ok ", ThemeEditorTheme::swatch_style(theme.success())),
+        Span::styled("error ", ThemeEditorTheme::swatch_style(theme.error())),
+        Span::styled(
+            "modified ",
+            ThemeEditorTheme::swatch_style(theme.modified()),
+        ),
+        Span::styled(
+            "NORMAL ",
+            ThemeEditorTheme::swatch_style(theme.normal_mode()),
+        ),
+        Span::styled(
+            "INSERT",
+            ThemeEditorTheme::swatch_style(theme.insert_mode()),
+        ),
+    ]));
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
@@ -0,0 +1,52 @@
+use crate::{state::AppState, theme::toast::ToastTheme};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// Widest a toast gets, longer messages wrap
+const MAX_WIDTH: u16 = 48;
+
+/// Stack the pending toasts in the top right corner, oldest on top
+pub fn render(f: &mut Frame, state: &AppState) {
+    let theme = &state.current_theme;
+    let area = f.area();
+    let mut y = area.y + 1;
+
+    for toast in state.notifications.iter() {
+        let width = (Line::raw(toast.message.as_str()).width() as u16 + 4)
+            .max(toast.severity.label().len() as u16 + 6)
+            .min(MAX_WIDTH)
+            .min(area.width.saturating_sub(2));
+        let inner = width.saturating_sub(4).max(1);
+        let height = (toast.message.chars().count() as u16)
+            .div_ceil(inner)
+            .max(1)
+            + 2;
+        if width < 5 || y + height > area.bottom() {
+            break;
+        }
+
+        let rect = Rect::new(area.right() - width - 1, y, width, height);
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" {} ", toast.severity.label()),
+                ToastTheme::title_style(theme, toast.severity),
+            ))
+            .borders(Borders::ALL)
+            .border_style(ToastTheme::border_style(theme, toast.severity))
+            .style(ToastTheme::background_style(theme));
+        let paragraph = Paragraph::new(Line::from(Span::styled(
+            format!(" {}", toast.message),
+            ToastTheme::message_style(theme),
+        )))
+        .block(block)
+        .wrap(Wrap { trim: false });
+
+        f.render_widget(Clear, rect);
+        f.render_widget(paragraph, rect);
+        y += height;
+    }
+}
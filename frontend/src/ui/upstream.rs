@@ -0,0 +1,96 @@
+use crate::{
+    state::AppState,
+    theme::{file_history::FileHistoryTheme, overlay::OverlayTheme},
+};
+use ratzilla::ratatui::text::{Line, Span};
+
+/// Verification results of the upstream check followed by its diff
+pub fn lines(state: &AppState) -> Vec<Line<'static>> {
+    let theme = &state.current_theme;
+    let Some(review) = &state.upstream else {
+        return Vec::new();
+    };
+
+    let mut lines = vec![row(state, "File", review.filename.clone(), None)];
+    let check = match &review.result {
+        None => {
+            lines.push(Line::default());
+            lines.push(Line::from(Span::styled(
+                "Fetching upstream...",
+                OverlayTheme::verdict_style(theme, None),
+            )));
+            return lines;
+        }
+        Some(Err(e)) => {
+            lines.push(Line::default());
+            lines.push(Line::from(Span::styled(
+                e.clone(),
+                OverlayTheme::verdict_style(theme, Some(false)),
+            )));
+            return lines;
+        }
+        Some(Ok(check)) => check,
+    };
+
+    let checksum = match (check.checksum_ok, &check.expected_sha256) {
+        (Some(true), _) => "matches pinned sha256".to_string(),
+        (Some(false), Some(expected)) => format!("MISMATCH, expected {}", expected),
+        _ => "not pinned".to_string(),
+    };
+    let signature = match check.signature_ok {
+        Some(true) => "verified",
+        Some(false) => "FAILED",
+        None => "not configured",
+    };
+    lines.push(row(state, "Source", check.url.clone(), None));
+    lines.push(row(state, "SHA-256", check.sha256.clone(), None));
+    lines.push(row(state, "Checksum", checksum, Some(check.checksum_ok)));
+    lines.push(row(
+        state,
+        "Signature",
+        signature.to_string(),
+        Some(check.signature_ok),
+    ));
+    lines.push(row(state, "Job", format!("#{}", check.job_id), None));
+    lines.push(Line::default());
+
+    if !check.changed {
+        lines.push(Line::from(Span::styled(
+            "Local file matches upstream",
+            OverlayTheme::verdict_style(theme, Some(true)),
+        )));
+        return lines;
+    }
+    if review.applicable().is_none() {
+        lines.push(Line::from(Span::styled(
+            "Verification failed, this version cannot be applied",
+            OverlayTheme::verdict_style(theme, Some(false)),
+        )));
+        lines.push(Line::default());
+    }
+    lines.extend(check.diff.lines().map(|line| {
+        Line::from(Span::styled(
+            line.to_string(),
+            FileHistoryTheme::diff_line_style(theme, line),
+        ))
+    }));
+    lines
+}
+
+/// `Label     value`, the value colored by its verdict when it has one
+fn row(
+    state: &AppState,
+    label: &str,
+    value: String,
+    verdict: Option<Option<bool>>,
+) -> Line<'static> {
+    let theme = &state.current_theme;
+    let value_style = match verdict {
+        Some(passed) => OverlayTheme::verdict_style(theme, passed),
+        None => OverlayTheme::label_style(theme),
+    };
+    Line::from(vec![
+        Span::styled(format!("{:<10} ", label), OverlayTheme::key_style(theme)),
+        Span::styled(value, value_style),
+    ])
+}
@@ -0,0 +1,29 @@
+use std::ops::Range;
+
+/// Rows of a list of `len` rows that fit in `height`, scrolled so
+/// `selected` is in view the way a fresh `ListState` scrolls (the selection
+/// sits on the last row once it is past the first screen). Long lists only
+/// build the widgets for these rows each frame.
+pub fn visible_rows(len: usize, selected: Option<usize>, height: usize) -> Range<usize> {
+    let offset = selected.map_or(0, |row| (row + 1).saturating_sub(height));
+    let offset = offset.min(len);
+    offset..len.min(offset + height)
+}
+
+/// Line of a list with section headers
+#[derive(Clone, Copy)]
+pub enum Row<'a> {
+    Header(&'a str),
+    /// Index into the list's items
+    Item(usize),
+}
+
+/// Item index of every line for mouse hit-testing, None for headers
+pub fn item_indices(rows: &[Row]) -> Vec<Option<usize>> {
+    rows.iter()
+        .map(|row| match row {
+            Row::Header(_) => None,
+            Row::Item(index) => Some(*index),
+        })
+        .collect()
+}
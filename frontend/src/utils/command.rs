@@ -0,0 +1,84 @@
+//! Commands typed on the editor's `:` line, vim style: `w`, `q`, `wq`,
+//! `e <file>`, `set [no]wrap`, `set [no]number` and `theme <name>`.
+
+/// A parsed `:` command
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Write,
+    /// Back to the file list; the buffer stays open, so unsaved edits are kept
+    Quit,
+    WriteQuit,
+    Edit(String),
+    Set(EditorOption, bool),
+    Theme(String),
+}
+
+/// Display options switched with `:set`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EditorOption {
+    /// Long lines continue on the next row instead of scrolling sideways
+    Wrap,
+    /// Line numbers in front of the text
+    Numbers,
+}
+
+/// What the argument being typed names, for Tab completion
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Completion {
+    File,
+    Theme,
+    Option,
+}
+
+/// Arguments of `:set`
+pub const OPTIONS: &[&str] = &["wrap", "nowrap", "number", "nonumber"];
+
+/// Parse the text after the `:`
+pub fn parse(input: &str) -> Result<Command, String> {
+    let (name, arg) = split(input.trim());
+    match (name, arg) {
+        ("w" | "write", "") => Ok(Command::Write),
+        ("q" | "q!" | "quit", "") => Ok(Command::Quit),
+        ("wq" | "x", "") => Ok(Command::WriteQuit),
+        ("e" | "edit", "") => Err("Missing file name".to_string()),
+        ("e" | "edit", file) => Ok(Command::Edit(file.to_string())),
+        ("set" | "se", option) => parse_option(option),
+        ("theme" | "colorscheme" | "colo", "") => Err("Missing theme name".to_string()),
+        ("theme" | "colorscheme" | "colo", theme) => Ok(Command::Theme(theme.to_string())),
+        _ => Err(format!("Not a command: {}", input.trim())),
+    }
+}
+
+fn parse_option(option: &str) -> Result<Command, String> {
+    let (name, on) = match option.strip_prefix("no") {
+        Some(name) => (name, false),
+        None => (option, true),
+    };
+    match name {
+        "wrap" => Ok(Command::Set(EditorOption::Wrap, on)),
+        "number" | "nu" => Ok(Command::Set(EditorOption::Numbers, on)),
+        "" => Err("Missing option name".to_string()),
+        _ => Err(format!("Unknown option: {}", option)),
+    }
+}
+
+/// The kind of argument being typed at the end of `input` and how much of it
+/// is typed, None while the command name itself is typed
+pub fn completing(input: &str) -> Option<(Completion, &str)> {
+    let (name, arg) = input.trim_start().split_once(' ')?;
+    let kind = match name {
+        "e" | "edit" => Completion::File,
+        "theme" | "colorscheme" | "colo" => Completion::Theme,
+        "set" | "se" => Completion::Option,
+        _ => return None,
+    };
+    Some((kind, arg.trim_start()))
+}
+
+/// Command name and its argument
+fn split(input: &str) -> (&str, &str) {
+    match input.split_once(char::is_whitespace) {
+        Some((name, arg)) => (name, arg.trim()),
+        None => (input, ""),
+    }
+}
@@ -0,0 +1,29 @@
+// Run something once input has been quiet for a while
+
+use gloo_timers::callback::Timeout;
+
+/// Runs the last scheduled callback after `delay_ms` without a new one
+pub struct Debounce {
+    delay_ms: u32,
+    timer: Option<Timeout>,
+}
+
+impl Debounce {
+    pub fn new(delay_ms: u32) -> Self {
+        Self {
+            delay_ms,
+            timer: None,
+        }
+    }
+
+    /// Run `callback` after the delay, dropping a callback still waiting
+    pub fn schedule(&mut self, callback: impl FnOnce() + 'static) {
+        self.timer = Some(Timeout::new(self.delay_ms, callback));
+    }
+
+    /// Drop the waiting callback; returns whether one was scheduled since
+    /// the last cancel, even if it has run already
+    pub fn cancel(&mut self) -> bool {
+        self.timer.take().is_some()
+    }
+}
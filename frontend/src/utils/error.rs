@@ -1,7 +1,33 @@
+use crate::api::ValidationErrorResponse;
 use wasm_bindgen::JsValue;
 
 /// Convert JsValue error to a readable string
-/// Extracts the inner string without the JsValue(...) wrapper
+/// Extracts the inner string without the JsValue(...) wrapper; a 422 body
+/// the message carries is shown as its rejected fields instead of raw JSON
 pub fn format_error(e: &JsValue) -> String {
-    e.as_string().unwrap_or_else(|| format!("{:?}", e))
+    let message = e.as_string().unwrap_or_else(|| format!("{:?}", e));
+    match validation_error(&message) {
+        Some((prefix, response)) => format!("{}{}", prefix, format_validation(&response)),
+        None => message,
+    }
+}
+
+/// The text before a `ValidationErrorResponse` and the response itself
+fn validation_error(message: &str) -> Option<(&str, ValidationErrorResponse)> {
+    let start = message.find('{')?;
+    let response = serde_json::from_str(&message[start..]).ok()?;
+    Some((&message[..start], response))
+}
+
+/// e.g. "Invalid request body: content contains NUL bytes; sha256 must be ..."
+fn format_validation(response: &ValidationErrorResponse) -> String {
+    if response.fields.is_empty() {
+        return response.error.clone();
+    }
+    let fields: Vec<String> = response
+        .fields
+        .iter()
+        .map(|field| format!("{} {}", field.field, field.message))
+        .collect();
+    format!("{}: {}", response.error, fields.join("; "))
 }
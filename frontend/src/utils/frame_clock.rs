@@ -0,0 +1,31 @@
+// Time between rendered frames, for effects that advance per frame
+
+use std::cell::Cell;
+
+/// Largest step reported between two frames, so an effect in a tab that was
+/// in the background does not jump to its end
+const MAX_FRAME_MS: f64 = 100.0;
+
+pub struct FrameClock {
+    last: Cell<f64>,
+}
+
+impl FrameClock {
+    pub fn new() -> Self {
+        Self {
+            last: Cell::new(js_sys::Date::now()),
+        }
+    }
+
+    /// Start counting from now, e.g. when an effect begins
+    pub fn reset(&self) {
+        self.last.set(js_sys::Date::now());
+    }
+
+    /// Milliseconds since the previous call (or reset)
+    pub fn delta_ms(&self) -> u32 {
+        let now = js_sys::Date::now();
+        let last = self.last.replace(now);
+        (now - last).clamp(0.0, MAX_FRAME_MS) as u32
+    }
+}
@@ -0,0 +1,89 @@
+//! Indentation of the text in the editor, detected from the content unless
+//! the `[hygiene]` rules of the file's category set it
+
+/// Columns of one level when neither the file nor its rules tell
+pub const DEFAULT_WIDTH: u8 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Indent {
+    pub tabs: bool,
+    /// Columns of one level, and of a tab
+    pub width: u8,
+}
+
+impl Default for Indent {
+    fn default() -> Self {
+        Self {
+            tabs: false,
+            width: DEFAULT_WIDTH,
+        }
+    }
+}
+
+impl Indent {
+    /// 2, 4 and 8 spaces, then tabs
+    pub fn next(self) -> Self {
+        let (tabs, width) = match (self.tabs, self.width) {
+            (true, _) => (false, 2),
+            (false, width) if width < 4 => (false, 4),
+            (false, width) if width < 8 => (false, 8),
+            (false, _) => (true, DEFAULT_WIDTH),
+        };
+        Self { tabs, width }
+    }
+
+    /// e.g. "spaces: 2" or "tabs"
+    pub fn label(&self) -> String {
+        if self.tabs {
+            "tabs".to_string()
+        } else {
+            format!("spaces: {}", self.width)
+        }
+    }
+}
+
+/// Indentation the lines mostly use, None when no line is indented
+pub fn detect(lines: &[String]) -> Option<Indent> {
+    let (mut tab_lines, mut space_lines) = (0, 0);
+    // How often the indentation grows by n spaces from one line to the next
+    let mut steps = [0usize; 9];
+    let mut previous = 0;
+    for line in lines.iter().filter(|line| !line.trim().is_empty()) {
+        if line.starts_with('\t') {
+            tab_lines += 1;
+            continue;
+        }
+        let spaces = line.len() - line.trim_start_matches(' ').len();
+        if spaces > 0 {
+            space_lines += 1;
+        }
+        let step = spaces.saturating_sub(previous);
+        // Single spaces are alignment (e.g. " * " in comments), not a level
+        if (2..=8).contains(&step) {
+            steps[step] += 1;
+        }
+        previous = spaces;
+    }
+
+    if tab_lines == 0 && space_lines == 0 {
+        return None;
+    }
+    if tab_lines > space_lines {
+        return Some(Indent {
+            tabs: true,
+            width: DEFAULT_WIDTH,
+        });
+    }
+    // The most frequent step, the smaller one on a tie
+    let width = (2..=8)
+        .rev()
+        .max_by_key(|step| steps[*step])
+        .filter(|step| steps[*step] > 0)
+        .map_or(DEFAULT_WIDTH, |step| step as u8);
+    Some(Indent { tabs: false, width })
+}
+
+/// Leading spaces and tabs of `line`
+pub fn leading(line: &str) -> &str {
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
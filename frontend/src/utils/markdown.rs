@@ -0,0 +1,197 @@
+//! Small Markdown to HTML converter for the file preview.
+//! Raw HTML in the source is escaped, never passed through.
+
+/// Render Markdown: headings, paragraphs, lists, quotes, code blocks,
+/// rules and the common inline markup (code, bold, italic, links)
+pub fn to_html(source: &str) -> String {
+    let mut html = String::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut list: Option<&str> = None;
+    let mut code_block: Option<Vec<&str>> = None;
+
+    for line in source.lines() {
+        // Fenced code is copied verbatim until the closing fence
+        if let Some(code) = code_block.as_mut() {
+            if line.trim_start().starts_with("```") {
+                html.push_str(&format!(
+                    "<pre><code>{}</code></pre>\n",
+                    escape(&code.join("\n"))
+                ));
+                code_block = None;
+            } else {
+                code.push(line);
+            }
+            continue;
+        }
+
+        let trimmed = line.trim();
+        let item = list_item(trimmed);
+        if trimmed.is_empty() || item.is_some() || is_block_start(trimmed) {
+            flush_paragraph(&mut html, &mut paragraph);
+        }
+        if item.map(|(tag, _)| tag) != list
+            && let Some(tag) = list.take()
+        {
+            html.push_str(&format!("</{}>\n", tag));
+        }
+
+        if trimmed.is_empty() {
+            continue;
+        }
+        if trimmed.starts_with("```") {
+            code_block = Some(Vec::new());
+        } else if let Some((tag, text)) = item {
+            if list.is_none() {
+                html.push_str(&format!("<{}>\n", tag));
+                list = Some(tag);
+            }
+            html.push_str(&format!("<li>{}</li>\n", inline(text)));
+        } else if let Some((level, text)) = heading(trimmed) {
+            html.push_str(&format!("<h{0}>{1}</h{0}>\n", level, inline(text)));
+        } else if let Some(text) = trimmed.strip_prefix('>') {
+            html.push_str(&format!(
+                "<blockquote>{}</blockquote>\n",
+                inline(text.trim())
+            ));
+        } else if is_rule(trimmed) {
+            html.push_str("<hr>\n");
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+
+    flush_paragraph(&mut html, &mut paragraph);
+    if let Some(tag) = list {
+        html.push_str(&format!("</{}>\n", tag));
+    }
+    if let Some(code) = code_block {
+        html.push_str(&format!(
+            "<pre><code>{}</code></pre>\n",
+            escape(&code.join("\n"))
+        ));
+    }
+    html
+}
+
+/// Escape text for use in HTML content and quoted attributes
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn flush_paragraph(html: &mut String, paragraph: &mut Vec<&str>) {
+    if !paragraph.is_empty() {
+        html.push_str(&format!("<p>{}</p>\n", inline(&paragraph.join(" "))));
+        paragraph.clear();
+    }
+}
+
+fn is_block_start(line: &str) -> bool {
+    line.starts_with("```") || line.starts_with('>') || heading(line).is_some() || is_rule(line)
+}
+
+fn is_rule(line: &str) -> bool {
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&c| compact.chars().all(|x| x == c))
+}
+
+/// `## Title` as (2, "Title")
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let text = line[level..].strip_prefix(' ')?;
+    (1..=6).contains(&level).then_some((level, text.trim()))
+}
+
+/// List tag and text of `- item`, `* item`, `+ item` or `1. item`
+fn list_item(line: &str) -> Option<(&'static str, &str)> {
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(text) = line.strip_prefix(marker)
+            && !is_rule(line)
+        {
+            return Some(("ul", text.trim()));
+        }
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0 {
+        let text = line[digits..].strip_prefix(". ")?;
+        return Some(("ol", text.trim()));
+    }
+    None
+}
+
+/// Inline markup, escaping everything else: `code`, **bold**, *italic*, [text](url)
+fn inline(text: &str) -> String {
+    let mut html = String::new();
+    let mut rest = text;
+    let mut bold = false;
+    let mut italic = false;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '`'
+            && let Some(end) = rest[1..].find('`')
+        {
+            html.push_str(&format!("<code>{}</code>", escape(&rest[1..1 + end])));
+            rest = &rest[end + 2..];
+        } else if let Some(after) = rest.strip_prefix("**") {
+            html.push_str(if bold { "</strong>" } else { "<strong>" });
+            bold = !bold;
+            rest = after;
+        } else if c == '*' && (italic || rest[1..].contains('*')) {
+            html.push_str(if italic { "</em>" } else { "<em>" });
+            italic = !italic;
+            rest = &rest[1..];
+        } else if let Some((label, url, len)) = link(rest) {
+            if safe_url(url) {
+                html.push_str(&format!(
+                    "<a href=\"{}\">{}</a>",
+                    escape(url),
+                    escape(label)
+                ));
+            } else {
+                html.push_str(&escape(label));
+            }
+            rest = &rest[len..];
+        } else {
+            html.push_str(&escape(&c.to_string()));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    if italic {
+        html.push_str("</em>");
+    }
+    if bold {
+        html.push_str("</strong>");
+    }
+    html
+}
+
+/// `[label](url)` at the start of `text`, with its length in bytes
+fn link(text: &str) -> Option<(&str, &str, usize)> {
+    let rest = text.strip_prefix('[')?;
+    let label_end = rest.find("](")?;
+    let url_start = label_end + 2;
+    let url_end = url_start + rest[url_start..].find(')')?;
+    Some((
+        &rest[..label_end],
+        &rest[url_start..url_end],
+        1 + url_end + 1,
+    ))
+}
+
+/// Only web and relative links, no `javascript:` or `data:` URLs
+fn safe_url(url: &str) -> bool {
+    let lower = url.trim().to_ascii_lowercase();
+    match lower.split_once(':') {
+        Some((scheme, _)) if !scheme.contains('/') => {
+            matches!(scheme, "http" | "https" | "mailto")
+        }
+        _ => true,
+    }
+}
@@ -1 +1,9 @@
+pub mod command;
+pub mod debounce;
 pub mod error;
+pub mod frame_clock;
+pub mod indent;
+pub mod markdown;
+pub mod outline;
+pub mod pretty;
+pub mod time;
@@ -0,0 +1,106 @@
+//! Headings of the open file for the outline overlay: `[sections]` of TOML
+//! and INI files, top-level keys of YAML and `name {` blocks of nginx-style
+//! configs. Line based, so it copes with files that do not parse.
+
+/// A heading and the line it is on
+#[derive(Debug, Clone, PartialEq)]
+pub struct Section {
+    pub title: String,
+    /// Zero-based line of the heading
+    pub row: usize,
+    /// Nesting, for blocks inside blocks
+    pub depth: usize,
+}
+
+/// Blocks nested deeper than this are left out of the outline
+const MAX_BLOCK_DEPTH: usize = 3;
+
+/// Headings of `lines`, YAML by `filename`'s extension, else sections and
+/// blocks
+pub fn sections(filename: &str, lines: &[String]) -> Vec<Section> {
+    let extension = filename
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("yaml" | "yml") => yaml_keys(lines),
+        _ => sections_and_blocks(lines),
+    }
+}
+
+/// Keys at the start of a line, and `---` document separators
+fn yaml_keys(lines: &[String]) -> Vec<Section> {
+    let mut sections = Vec::new();
+    for (row, line) in lines.iter().enumerate() {
+        if line.starts_with("---") {
+            sections.push(Section {
+                title: "---".to_string(),
+                row,
+                depth: 0,
+            });
+            continue;
+        }
+        if line.starts_with([' ', '\t', '#', '-']) {
+            continue;
+        }
+        if let Some((key, _)) = line.split_once(':') {
+            sections.push(Section {
+                title: key.trim().to_string(),
+                row,
+                depth: 0,
+            });
+        }
+    }
+    sections
+}
+
+/// `[section]` and `[[array.of.tables]]` headers plus `name {` blocks
+fn sections_and_blocks(lines: &[String]) -> Vec<Section> {
+    let mut sections = Vec::new();
+    let mut depth = 0usize;
+    for (row, line) in lines.iter().enumerate() {
+        let text = strip_comment(line.trim());
+        if text.is_empty() {
+            continue;
+        }
+
+        if depth == 0 && text.starts_with('[') && text.ends_with(']') {
+            sections.push(Section {
+                title: text.to_string(),
+                row,
+                depth: 0,
+            });
+            continue;
+        }
+
+        let opened = text.matches('{').count();
+        let closed = text.matches('}').count();
+        if text.ends_with('{') && depth < MAX_BLOCK_DEPTH {
+            let title = text
+                .trim_end_matches('{')
+                .trim_end()
+                .trim_end_matches([':', '='])
+                .trim_end();
+            if !title.is_empty() {
+                sections.push(Section {
+                    title: title.to_string(),
+                    row,
+                    depth,
+                });
+            }
+        }
+        depth = (depth + opened).saturating_sub(closed);
+    }
+    sections
+}
+
+/// `text` without a trailing `#`, `;` or `//` comment; a line that is only
+/// a comment comes back empty
+fn strip_comment(text: &str) -> &str {
+    if text.starts_with(['#', ';']) || text.starts_with("//") {
+        return "";
+    }
+    match text.find(" #").or_else(|| text.find(" //")) {
+        Some(i) => text[..i].trim_end(),
+        None => text,
+    }
+}
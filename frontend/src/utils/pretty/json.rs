@@ -0,0 +1,52 @@
+/// One value per line, keys in their order; strings are copied untouched
+pub(super) fn format(text: &str, unit: &str) -> Result<String, String> {
+    serde_json::from_str::<serde::de::IgnoredAny>(text).map_err(|e| e.to_string())?;
+
+    let mut out = String::with_capacity(text.len() * 2);
+    let mut depth = 0;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                out.push(c);
+                while let Some(c) = chars.next() {
+                    out.push(c);
+                    if c == '\\' {
+                        out.extend(chars.next());
+                    } else if c == '"' {
+                        break;
+                    }
+                }
+            }
+            '{' | '[' => {
+                out.push(c);
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                // Empty objects and arrays stay on one line
+                if let Some(close) = chars.next_if(|next| matches!(next, '}' | ']')) {
+                    out.push(close);
+                } else {
+                    depth += 1;
+                    newline(&mut out, depth, unit);
+                }
+            }
+            '}' | ']' => {
+                depth -= 1;
+                newline(&mut out, depth, unit);
+                out.push(c);
+            }
+            ',' => {
+                out.push(c);
+                newline(&mut out, depth, unit);
+            }
+            ':' => out.push_str(": "),
+            c if c.is_whitespace() => {}
+            c => out.push(c),
+        }
+    }
+    Ok(out)
+}
+
+fn newline(out: &mut String, depth: usize, unit: &str) {
+    out.push('\n');
+    out.push_str(&unit.repeat(depth));
+}
@@ -0,0 +1,54 @@
+//! Pretty-printing of JSON, TOML and YAML documents in the editor. Comments
+//! survive where the format has them (TOML and YAML); a document that does
+//! not parse is left alone and the reason returned.
+
+mod json;
+mod toml;
+mod yaml;
+
+use super::indent::Indent;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    Json,
+    Toml,
+    Yaml,
+}
+
+impl Language {
+    /// Language of `filename` by its extension
+    pub fn of(filename: &str) -> Option<Self> {
+        let (_, extension) = filename.rsplit_once('.')?;
+        match extension.to_ascii_lowercase().as_str() {
+            "json" => Some(Language::Json),
+            "toml" => Some(Language::Toml),
+            "yaml" | "yml" => Some(Language::Yaml),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Language::Json => "JSON",
+            Language::Toml => "TOML",
+            Language::Yaml => "YAML",
+        }
+    }
+}
+
+/// `text` pretty-printed, indented with `indent` where the format leaves
+/// the choice (YAML is always indented with spaces)
+pub fn format(language: Language, text: &str, indent: Indent) -> Result<String, String> {
+    let unit = if indent.tabs {
+        "\t".to_string()
+    } else {
+        " ".repeat(indent.width.max(1) as usize)
+    };
+    let formatted = match language {
+        Language::Json => json::format(text, &unit)?,
+        Language::Toml => toml::format(text, &unit)?,
+        Language::Yaml => yaml::format(text)?,
+    };
+    // The editor holds the text without the final newline
+    Ok(formatted.trim_end_matches('\n').to_string())
+}
@@ -0,0 +1,178 @@
+use toml_edit::{Array, Decor, DocumentMut, Item, Table, Value};
+
+/// `key = value` spacing, no indentation of keys, a blank line before each
+/// table header and one element per line in multi-line arrays. Comments and
+/// the text of values (e.g. multi-line strings) are kept as written.
+pub(super) fn format(text: &str, unit: &str) -> Result<String, String> {
+    let mut doc: DocumentMut = text
+        .parse()
+        .map_err(|e: toml_edit::TomlError| summary(&e.to_string()))?;
+    format_table(doc.as_table_mut(), unit);
+    let trailing = comments(doc.trailing().as_str().unwrap_or_default(), "");
+    doc.set_trailing(trailing);
+    Ok(doc.to_string().trim_start_matches('\n').to_string())
+}
+
+/// First and last line of a toml_edit error, which draws the offending
+/// line in between
+fn summary(error: &str) -> String {
+    let lines: Vec<&str> = error
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    match lines.as_slice() {
+        [] => error.to_string(),
+        [only] => only.to_string(),
+        [first, .., last] => format!("{}: {}", first, last),
+    }
+}
+
+fn format_table(table: &mut Table, unit: &str) {
+    // Keys of `a.b = 1` share the line of their parent key
+    if table.is_dotted() {
+        return;
+    }
+    let decor = table.decor_mut();
+    let header = comments(raw_prefix(decor), "");
+    decor.set_prefix(format!("\n{}", header.trim_start_matches('\n')));
+    let comment = trailing_comment(raw_suffix(decor));
+    decor.set_suffix(comment);
+
+    for (mut key, item) in table.iter_mut() {
+        match item {
+            Item::Value(value) => {
+                let decor = key.leaf_decor_mut();
+                let prefix = comments(raw_prefix(decor), "");
+                decor.set_prefix(prefix);
+                decor.set_suffix(" ");
+                format_value(value, unit);
+            }
+            Item::Table(table) => format_table(table, unit),
+            Item::ArrayOfTables(tables) => {
+                for table in tables.iter_mut() {
+                    format_table(table, unit);
+                }
+            }
+            Item::None => {}
+        }
+    }
+}
+
+fn format_value(value: &mut Value, unit: &str) {
+    let comment = trailing_comment(raw_suffix(value.decor()));
+    match value {
+        Value::Array(array) => format_array(array, unit, 1),
+        Value::InlineTable(table) => table.fmt(),
+        _ => {}
+    }
+    let decor = value.decor_mut();
+    decor.set_prefix(" ");
+    decor.set_suffix(comment);
+}
+
+/// Arrays written over several lines get one element per line at `depth`
+/// indentation, the others are put on one line
+fn format_array(array: &mut Array, unit: &str, depth: usize) {
+    for value in array.iter_mut() {
+        match value {
+            Value::Array(nested) => format_array(nested, unit, depth + 1),
+            Value::InlineTable(table) => table.fmt(),
+            _ => {}
+        }
+    }
+
+    let multiline = array.trailing().as_str().is_some_and(|t| t.contains('\n'))
+        || array
+            .iter()
+            .any(|value| raw_prefix(value.decor()).contains('\n'));
+    if !multiline || array.is_empty() {
+        array.fmt();
+        return;
+    }
+
+    let indent = unit.repeat(depth);
+    // A comment between an element and its comma moves behind the comma
+    let mut carried = String::new();
+    for value in array.iter_mut() {
+        let decor = value.decor_mut();
+        let prefix = line_start(&carried, raw_prefix(decor), &indent);
+        carried = trailing_comment(raw_suffix(decor));
+        decor.set_prefix(prefix);
+        decor.set_suffix("");
+    }
+    let trailing = line_start(
+        &carried,
+        array.trailing().as_str().unwrap_or_default(),
+        &unit.repeat(depth - 1),
+    );
+    array.set_trailing(trailing);
+    array.set_trailing_comma(true);
+}
+
+/// Whitespace after a comma of a multi-line array: comments on the line of
+/// the previous element stay there, what follows starts a line of its own
+/// behind `indent`
+fn line_start(carried: &str, raw: &str, indent: &str) -> String {
+    let (rest_of_line, below) = split_line(raw);
+    format!(
+        "{}{}\n{}{}",
+        carried,
+        trailing_comment(rest_of_line),
+        comments(below, indent),
+        indent
+    )
+}
+
+/// Text up to the first newline, and what follows it
+fn split_line(raw: &str) -> (&str, &str) {
+    raw.split_once('\n').unwrap_or((raw, ""))
+}
+
+/// Comment lines of a decor prefix, each on a line of its own behind
+/// `indent`, with at most one blank line in a row. The last piece is the
+/// indentation of what follows, or a comment at the end of the document.
+fn comments(raw: &str, indent: &str) -> String {
+    let pieces: Vec<&str> = raw.split('\n').map(str::trim).collect();
+    let mut out = String::new();
+    let mut blank = false;
+    for (i, piece) in pieces.iter().enumerate() {
+        let last = i + 1 == pieces.len();
+        if piece.is_empty() {
+            if !last && !blank {
+                out.push('\n');
+                blank = true;
+            }
+        } else {
+            out.push_str(indent);
+            out.push_str(piece);
+            if !last {
+                out.push('\n');
+            }
+            blank = false;
+        }
+    }
+    out
+}
+
+/// ` # comment` behind a value or header, nothing if there is none
+fn trailing_comment(raw: &str) -> String {
+    match raw.trim() {
+        "" => String::new(),
+        comment => format!(" {}", comment),
+    }
+}
+
+fn raw_prefix(decor: &Decor) -> &str {
+    decor
+        .prefix()
+        .and_then(|raw| raw.as_str())
+        .unwrap_or_default()
+}
+
+fn raw_suffix(decor: &Decor) -> &str {
+    decor
+        .suffix()
+        .and_then(|raw| raw.as_str())
+        .unwrap_or_default()
+}
@@ -0,0 +1,173 @@
+//! No YAML parser is part of the frontend, so this only rewrites what is
+//! safe without one: block indentation to two spaces per level, the space
+//! after `- ` and trailing whitespace. Block scalars (`|`, `>`) and flow
+//! collections spanning lines keep their inner layout, comments stay where
+//! they are. Indentation that matches no open level is reported instead.
+
+/// Columns of one level, the common YAML style
+const WIDTH: usize = 2;
+
+pub(super) fn format(text: &str) -> Result<String, String> {
+    let mut out = Vec::new();
+    // Original indentation of the open levels, innermost last
+    let mut levels: Vec<usize> = Vec::new();
+    let mut block: Option<BlockScalar> = None;
+    // Open brackets of a flow collection continuing on the next line, and
+    // how far its first line moved
+    let mut flow_depth = 0usize;
+    let mut flow_shift = 0isize;
+
+    for (number, line) in text.lines().enumerate().map(|(i, line)| (i + 1, line)) {
+        let line = line.trim_end();
+        if line.is_empty() {
+            out.push(String::new());
+            continue;
+        }
+        let spaces = line.len() - line.trim_start_matches(' ').len();
+        let content = &line[spaces..];
+
+        // Inside a block scalar the text is kept, shifted with its parent
+        if let Some(scalar) = &mut block {
+            if spaces > scalar.parent {
+                let base = *scalar.base.get_or_insert(spaces);
+                let indent = scalar.indent + WIDTH + spaces.saturating_sub(base);
+                out.push(format!("{}{}", " ".repeat(indent), content));
+                continue;
+            }
+            block = None;
+        }
+        if flow_depth > 0 {
+            flow_depth = flow_depth.saturating_add_signed(bracket_balance(content));
+            let indent = spaces.saturating_add_signed(flow_shift);
+            out.push(format!("{}{}", " ".repeat(indent), content));
+            continue;
+        }
+
+        if content.starts_with('\t') {
+            return Err(format!("Tab in the indentation of line {}", number));
+        }
+        if content.starts_with('#') {
+            // At the innermost open level it does not go past
+            let level = levels.iter().filter(|open| **open <= spaces).count();
+            let level = level.saturating_sub(1);
+            out.push(format!("{}{}", " ".repeat(level * WIDTH), content));
+            continue;
+        }
+        if spaces == 0 && (content.starts_with("---") || content.starts_with("...")) {
+            levels.clear();
+            out.push(content.to_string());
+            continue;
+        }
+
+        let mut popped = false;
+        while levels.last().is_some_and(|open| *open > spaces) {
+            levels.pop();
+            popped = true;
+        }
+        match levels.last() {
+            Some(open) if *open == spaces => {}
+            Some(_) if popped => {
+                return Err(format!(
+                    "Indentation of line {} matches no outer level",
+                    number
+                ));
+            }
+            _ => levels.push(spaces),
+        }
+        let indent = (levels.len() - 1) * WIDTH;
+        let content = tidy_dashes(content);
+
+        // `- ` items open a level for what follows them on later lines
+        let dashes = dash_count(&content);
+        if is_block_scalar(&content) {
+            block = Some(BlockScalar {
+                parent: spaces + dashes * WIDTH,
+                indent: indent + dashes * WIDTH,
+                base: None,
+            });
+        } else {
+            flow_depth = bracket_balance(&content).max(0) as usize;
+            flow_shift = indent as isize - spaces as isize;
+        }
+        out.push(format!("{}{}", " ".repeat(indent), content));
+    }
+    Ok(out.join("\n"))
+}
+
+struct BlockScalar {
+    /// Original indentation of the node the scalar belongs to
+    parent: usize,
+    /// Its new indentation
+    indent: usize,
+    /// Original indentation of the first line of text
+    base: Option<usize>,
+}
+
+/// One space after each leading `-`, so later lines of the item line up
+fn tidy_dashes(content: &str) -> String {
+    let mut out = String::new();
+    let mut rest = content;
+    while let Some(after) = rest.strip_prefix('-')
+        && after.starts_with(' ')
+    {
+        out.push_str("- ");
+        rest = after.trim_start_matches(' ');
+    }
+    out.push_str(rest);
+    out
+}
+
+fn dash_count(content: &str) -> usize {
+    content
+        .as_bytes()
+        .chunks(2)
+        .take_while(|pair| *pair == b"- ")
+        .count()
+}
+
+/// Whether the value of the line is a `|` or `>` block scalar header
+fn is_block_scalar(content: &str) -> bool {
+    let value = strip_comment(content).trim_end();
+    let token = value.rsplit([' ', ':']).next().unwrap_or_default();
+    let mut chars = token.chars();
+    matches!(chars.next(), Some('|' | '>'))
+        && token.len() <= 3
+        && chars.all(|c| c == '+' || c == '-' || c.is_ascii_digit())
+        && value.len() > token.len()
+        && (value[..value.len() - token.len()].ends_with(": ")
+            || value[..value.len() - token.len()].ends_with("- "))
+}
+
+/// Brackets opened minus closed outside quotes and comments
+fn bracket_balance(content: &str) -> isize {
+    let mut balance = 0;
+    let mut quote = None;
+    for c in strip_comment(content).chars() {
+        match (quote, c) {
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '[' | '{') => balance += 1,
+            (None, ']' | '}') => balance -= 1,
+            _ => {}
+        }
+    }
+    balance
+}
+
+/// `content` before a ` #` comment that is not inside quotes
+fn strip_comment(content: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (i, c) in content.char_indices() {
+        match (quote, c) {
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') if previous == ' ' => return &content[..i],
+            _ => {}
+        }
+        previous = c;
+    }
+    content
+}
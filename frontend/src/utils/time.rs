@@ -0,0 +1,59 @@
+// Human readable durations for the UI
+
+const SECS_PER_MINUTE: u64 = 60;
+const SECS_PER_HOUR: u64 = 3_600;
+const SECS_PER_DAY: u64 = 86_400;
+
+/// Current time in unix seconds (browser clock)
+pub fn now_secs() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
+
+/// "3d 4h 12m" style uptime
+pub fn format_duration(secs: u64) -> String {
+    let days = secs / SECS_PER_DAY;
+    let hours = secs % SECS_PER_DAY / SECS_PER_HOUR;
+    let minutes = secs % SECS_PER_HOUR / SECS_PER_MINUTE;
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else {
+        format!("{}h {}m", hours, minutes)
+    }
+}
+
+/// Two largest units ("3d 4h", "4h 12m", "12m"), for uptimes in a list
+pub fn format_uptime(secs: u64) -> String {
+    let days = secs / SECS_PER_DAY;
+    let hours = secs % SECS_PER_DAY / SECS_PER_HOUR;
+    let minutes = secs % SECS_PER_HOUR / SECS_PER_MINUTE;
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format_coarse(secs)
+    }
+}
+
+/// Coarse relative age ("5m ago", "2d ago")
+pub fn format_age(secs: u64) -> String {
+    if secs < SECS_PER_MINUTE {
+        "just now".to_string()
+    } else {
+        format!("{} ago", format_coarse(secs))
+    }
+}
+
+/// Largest unit only ("45s", "12m", "3h", "2d"), for durations docker
+/// already rounded
+pub fn format_coarse(secs: u64) -> String {
+    if secs < SECS_PER_MINUTE {
+        format!("{}s", secs)
+    } else if secs < SECS_PER_HOUR {
+        format!("{}m", secs / SECS_PER_MINUTE)
+    } else if secs < SECS_PER_DAY {
+        format!("{}h", secs / SECS_PER_HOUR)
+    } else {
+        format!("{}d", secs / SECS_PER_DAY)
+    }
+}
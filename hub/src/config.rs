@@ -0,0 +1,191 @@
+use regex::Regex;
+use serde::Deserialize;
+use std::path::Path;
+use sysrat_core::config::{SecretSettings, TlsSettings};
+
+/// hub.toml: where the hub listens and the agents it forwards to
+#[derive(Debug, Clone, Deserialize)]
+pub struct HubConfig {
+    #[serde(default = "default_port")]
+    pub port: u16,
+    /// Address to listen on. The hub holds the client certificate the agents
+    /// trust, so whoever reaches it reaches every node
+    #[serde(default = "default_bind")]
+    pub bind: String,
+    /// HTTPS of the hub itself (`[server_tls]`); with `client_ca` only
+    /// clients presenting a certificate it signed get in
+    #[serde(default)]
+    pub server_tls: Option<TlsSettings>,
+    /// Path prefix for all routes when running behind a reverse proxy
+    #[serde(default)]
+    pub base_path: String,
+    /// Largest request body forwarded to an agent
+    #[serde(default = "default_max_body_bytes")]
+    pub max_body_bytes: usize,
+    /// Seconds an agent gets to answer the status check of the node list
+    #[serde(default = "default_probe_timeout_secs")]
+    pub probe_timeout_secs: u64,
+    pub tls: ClientTls,
+    /// Values masked in the files of ssh nodes, agents mask their own
+    #[serde(default)]
+    pub secrets: SecretSettings,
+    /// `secrets` compiled
+    #[serde(skip)]
+    pub secret_patterns: Vec<Regex>,
+    /// Nodes in the order the UI lists them
+    #[serde(default)]
+    pub nodes: Vec<NodeConfig>,
+}
+
+/// Client certificate the hub presents to the agents (`[tls]`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClientTls {
+    /// PEM certificate chain, signed by the agents' `client_ca`
+    pub cert: String,
+    /// PEM private key of the certificate
+    pub key: String,
+    /// PEM CA certificates that signed the agents' server certificates;
+    /// other servers are not trusted
+    pub ca: String,
+}
+
+/// One managed machine (`[[nodes]]`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct NodeConfig {
+    pub name: String,
+    #[serde(default)]
+    pub transport: Transport,
+    /// HTTPS base URL of the agent, including its base_path
+    #[serde(default)]
+    pub url: String,
+    /// `[user@]host` for the ssh transport, a Host of ~/.ssh/config works too
+    #[serde(default)]
+    pub ssh: String,
+    /// Files the ssh transport lists, agents list their own
+    #[serde(default)]
+    pub files: Vec<SshFile>,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// How the hub reaches a node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Transport {
+    /// sysrat runs on the node and answers the whole API over mTLS
+    #[default]
+    Agent,
+    /// Nothing is installed: files are read and written and docker is run
+    /// over SSH, the API beyond that is unavailable
+    Ssh,
+}
+
+/// A config file of a node reached over SSH
+#[derive(Debug, Clone, Deserialize)]
+pub struct SshFile {
+    /// Name in the file list, defaults to the file name
+    #[serde(default)]
+    pub name: String,
+    /// Path on the node, relative ones start in the login's home
+    pub path: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub readonly: bool,
+}
+
+fn default_port() -> u16 {
+    3100
+}
+
+fn default_bind() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_max_body_bytes() -> usize {
+    4 * 1024 * 1024
+}
+
+fn default_probe_timeout_secs() -> u64 {
+    3
+}
+
+impl HubConfig {
+    pub fn load() -> Result<Self, String> {
+        let path = Self::config_path();
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read config file {}: {}", path, e))?;
+        let mut config: Self =
+            toml::from_str(&content).map_err(|e| format!("Failed to parse config: {}", e))?;
+
+        for node in &config.nodes {
+            match node.transport {
+                Transport::Agent if !node.url.starts_with("https://") => {
+                    return Err(format!(
+                        "Node {} needs an https:// url, agents only speak mTLS, got {:?}",
+                        node.name, node.url
+                    ));
+                }
+                Transport::Ssh if node.ssh.is_empty() => {
+                    return Err(format!("Node {} uses ssh but sets no ssh host", node.name));
+                }
+                _ => {}
+            }
+        }
+        if let Some(node) = config.nodes.iter().find(|node| node.name.contains('/')) {
+            return Err(format!("Node name {} must not contain '/'", node.name));
+        }
+        for node in &mut config.nodes {
+            node.url = node.url.trim_end_matches('/').to_string();
+            for file in &mut node.files {
+                if file.name.is_empty() {
+                    file.name = Path::new(&file.path)
+                        .file_name()
+                        .map(|name| name.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| file.path.clone());
+                }
+            }
+        }
+        config.secret_patterns = config
+            .secrets
+            .patterns
+            .iter()
+            .map(|p| Regex::new(p).map_err(|e| format!("Invalid secret pattern {}: {}", p, e)))
+            .collect::<Result<_, _>>()?;
+        config.base_path = config.base_path.trim_end_matches('/').to_string();
+        Ok(config)
+    }
+
+    /// SYSRAT_HUB_CONFIG, else hub.toml next to sysrat.toml in the config
+    /// directory, else ./hub.toml
+    pub fn config_path() -> String {
+        if let Ok(path) = std::env::var("SYSRAT_HUB_CONFIG") {
+            return path;
+        }
+        if let Ok(xdg_config) = std::env::var("XDG_CONFIG_HOME") {
+            let path = format!("{}/sysrat/hub.toml", xdg_config);
+            if Path::new(&path).exists() {
+                return path;
+            }
+        }
+        if let Ok(home) = std::env::var("HOME") {
+            let path = format!("{}/.config/sysrat/hub.toml", home);
+            if Path::new(&path).exists() {
+                return path;
+            }
+        }
+        "hub.toml".to_string()
+    }
+
+    pub fn node(&self, name: &str) -> Option<&NodeConfig> {
+        self.nodes.iter().find(|node| node.name == name)
+    }
+
+    /// The first node with sysrat installed, it answers the API calls that
+    /// name no node
+    pub fn first_agent(&self) -> Option<&NodeConfig> {
+        self.nodes
+            .iter()
+            .find(|node| node.transport == Transport::Agent)
+    }
+}
@@ -0,0 +1,209 @@
+mod config;
+mod nodes;
+mod proxy;
+mod ssh;
+
+use axum::{
+    Router,
+    extract::DefaultBodyLimit,
+    routing::{any, get},
+};
+use config::{HubConfig, Transport};
+use k_lib::config::Cookbook;
+use k_lib::logger;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use sysrat_core::fsutil::expand_tilde;
+use sysrat_core::serve;
+use sysrat_core::ssh::SshHost;
+use tokio::sync::Mutex;
+use tower_http::services::ServeDir;
+
+const SCOPE: &str = "HUB";
+const APP_NAME: &str = "sysrat-hub";
+
+/// Shared by all handlers: the config, the mTLS client to the agents and
+/// the SSH connections to the other nodes
+pub struct Hub {
+    pub config: HubConfig,
+    pub client: reqwest::Client,
+    pub probe_timeout: Duration,
+    /// Open connection of each ssh node, made on first use
+    pub ssh_hosts: HashMap<String, Mutex<Option<Arc<SshHost>>>>,
+}
+
+impl Hub {
+    /// API base of `node` on the hub, API paths are appended to it
+    pub fn node_url(&self, node: &str) -> String {
+        format!("{}/api/nodes/{}", self.config.base_path, node)
+    }
+}
+
+/// Log to terminal and file
+fn log(cookbook: &Cookbook, level: &str, msg: &str) {
+    logger::log_to_terminal(cookbook, level, SCOPE, msg);
+    let _ = logger::log_to_file(cookbook, level, SCOPE, msg, Some(APP_NAME));
+}
+
+/// Log an error and exit, before the hub serves anything
+fn fail(cookbook: Option<&Cookbook>, msg: &str) -> ! {
+    match cookbook {
+        Some(cb) => log(cb, "error", msg),
+        None => eprintln!("{}", msg),
+    }
+    std::process::exit(1);
+}
+
+#[tokio::main]
+async fn main() {
+    let cookbook = Cookbook::load().ok();
+    if let Some(ref cb) = cookbook {
+        log(cb, "info", "Initializing hub...");
+    }
+
+    let config = HubConfig::load().unwrap_or_else(|e| {
+        fail(
+            cookbook.as_ref(),
+            &format!("Failed to load configuration: {}", e),
+        )
+    });
+    let client = build_client(&config)
+        .unwrap_or_else(|e| fail(cookbook.as_ref(), &format!("Failed to set up mTLS: {}", e)));
+    // An unusable certificate stops the start, serving plain HTTP instead
+    // would bypass the client check
+    let tls_config = config.server_tls.as_ref().map(|settings| {
+        serve::tls_config(settings)
+            .unwrap_or_else(|e| fail(cookbook.as_ref(), &format!("Failed to set up TLS: {}", e)))
+    });
+    let client_auth = config
+        .server_tls
+        .as_ref()
+        .is_some_and(|tls| tls.client_ca.is_some());
+
+    let port = config.port;
+    let bind = config.bind.clone();
+    let base_path = config.base_path.clone();
+    let max_body_bytes = config.max_body_bytes;
+    let node_names: Vec<String> = config.nodes.iter().map(|node| node.name.clone()).collect();
+    let ssh_hosts = config
+        .nodes
+        .iter()
+        .filter(|node| node.transport == Transport::Ssh)
+        .map(|node| (node.name.clone(), Mutex::new(None)))
+        .collect();
+    let hub = Arc::new(Hub {
+        probe_timeout: Duration::from_secs(config.probe_timeout_secs),
+        config,
+        client,
+        ssh_hosts,
+    });
+
+    if let Some(ref cb) = cookbook {
+        log(cb, "info", "Registering API routes...");
+    }
+    let router = Router::new()
+        .route("/api/nodes", get(nodes::list_nodes))
+        .route("/api/hosts", get(nodes::list_hosts))
+        .route("/api/nodes/{node}/{*path}", any(proxy::forward_to_node))
+        .route("/api/{*path}", any(proxy::forward_to_default))
+        .layer(DefaultBodyLimit::max(max_body_bytes))
+        .with_state(hub)
+        // Static files (frontend)
+        .fallback_service(ServeDir::new("frontend/dist"));
+
+    // Mount everything under the reverse-proxy prefix if configured
+    let app = if base_path.is_empty() {
+        router
+    } else {
+        Router::new().nest(&base_path, router)
+    };
+
+    if let Some(ref cb) = cookbook {
+        log(cb, "success", "Routes registered");
+        if !base_path.is_empty() {
+            log(cb, "info", &format!("  Base path: {}", base_path));
+        }
+        log(cb, "info", "  GET  /api/nodes");
+        log(cb, "info", "  GET  /api/hosts");
+        log(cb, "info", "  ANY  /api/nodes/{node}/{*path}");
+        log(cb, "info", "  ANY  /api/{*path}");
+        for name in &node_names {
+            log(cb, "info", &format!("  Node: {}", name));
+        }
+    }
+
+    let bind_addr = format!("{}:{}", bind, port);
+    let listener = tokio::net::TcpListener::bind(&bind_addr)
+        .await
+        .unwrap_or_else(|e| {
+            fail(
+                cookbook.as_ref(),
+                &format!("Failed to bind {}: {}", bind_addr, e),
+            )
+        });
+
+    let scheme = if tls_config.is_some() {
+        "https"
+    } else {
+        "http"
+    };
+    let display_addr = format!("{}://{}:{}{}/", scheme, bind, port, base_path);
+    match cookbook {
+        Some(ref cb) => {
+            log(cb, "success", &format!("Hub running on {}", display_addr));
+            if client_auth {
+                log(cb, "info", "Client certificates required");
+            } else if !is_loopback(&bind) {
+                log(
+                    cb,
+                    "warn",
+                    "Reachable beyond localhost without client certificates, see [server_tls]",
+                );
+            }
+        }
+        None => println!("Hub running on {}", display_addr),
+    }
+
+    let service = app.into_make_service_with_connect_info::<SocketAddr>();
+    if let Err(e) = serve::serve(listener, tls_config, service, APP_NAME).await {
+        fail(cookbook.as_ref(), &format!("Server error: {}", e));
+    }
+
+    if let Some(ref cb) = cookbook {
+        log(cb, "success", "Hub stopped");
+    }
+}
+
+/// Client presenting the hub's certificate that trusts only the agents' CA
+fn build_client(config: &HubConfig) -> Result<reqwest::Client, String> {
+    let read = |path: &str| {
+        let path = expand_tilde(path);
+        std::fs::read(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+    };
+    let mut identity = read(&config.tls.cert)?;
+    identity.extend(read(&config.tls.key)?);
+    let identity = reqwest::Identity::from_pem(&identity)
+        .map_err(|e| format!("Invalid client certificate or key: {}", e))?;
+
+    let mut builder = reqwest::Client::builder()
+        .use_rustls_tls()
+        .tls_built_in_root_certs(false)
+        .identity(identity);
+    for ca in reqwest::Certificate::from_pem_bundle(&read(&config.tls.ca)?)
+        .map_err(|e| format!("Invalid CA certificate: {}", e))?
+    {
+        builder = builder.add_root_certificate(ca);
+    }
+    builder.build().map_err(|e| e.to_string())
+}
+
+/// `bind` only accepts connections from this machine
+fn is_loopback(bind: &str) -> bool {
+    bind == "localhost"
+        || bind
+            .trim_matches(['[', ']'])
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
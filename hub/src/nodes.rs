@@ -0,0 +1,77 @@
+use crate::{
+    Hub,
+    config::{NodeConfig, Transport},
+    ssh,
+};
+use axum::{Json, extract::State};
+use futures_util::future::join_all;
+use std::sync::Arc;
+use sysrat_api_types::{HostInfo, HostListResponse, NodeInfo, NodeListResponse, VersionResponse};
+
+/// Nodes and whether they answer, probed concurrently so one unreachable
+/// node delays the list by the probe timeout at most
+pub async fn list_nodes(State(hub): State<Arc<Hub>>) -> Json<NodeListResponse> {
+    let probes = hub.config.nodes.iter().map(|node| {
+        let hub = Arc::clone(&hub);
+        async move {
+            let probe = match node.transport {
+                Transport::Agent => probe_agent(&hub, node).await,
+                Transport::Ssh => {
+                    match tokio::time::timeout(hub.probe_timeout, ssh::session(&hub, node)).await {
+                        Ok(Ok(_)) => Ok(None),
+                        Ok(Err(e)) => Err(e.to_string()),
+                        Err(_) => Err("ssh connection timed out".to_string()),
+                    }
+                }
+            };
+            let (version, error) = match probe {
+                Ok(version) => (version, None),
+                Err(e) => (None, Some(e)),
+            };
+            NodeInfo {
+                name: node.name.clone(),
+                url: hub.node_url(&node.name),
+                description: node.description.clone(),
+                version,
+                error,
+            }
+        }
+    });
+    Json(NodeListResponse {
+        nodes: join_all(probes).await,
+    })
+}
+
+/// Server version of an agent
+async fn probe_agent(hub: &Hub, node: &NodeConfig) -> Result<Option<String>, String> {
+    let url = format!("{}/api/version", node.url);
+    let response = hub
+        .client
+        .get(&url)
+        .timeout(hub.probe_timeout)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| e.to_string())?;
+    let body: VersionResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Unexpected version response: {}", e))?;
+    Ok(Some(body.version))
+}
+
+/// The nodes as hosts, so the host switcher of the UI browses them
+/// through the hub
+pub async fn list_hosts(State(hub): State<Arc<Hub>>) -> Json<HostListResponse> {
+    let hosts = hub
+        .config
+        .nodes
+        .iter()
+        .map(|node| HostInfo {
+            name: node.name.clone(),
+            url: hub.node_url(&node.name),
+            description: node.description.clone(),
+        })
+        .collect();
+    Json(HostListResponse { hosts })
+}
@@ -0,0 +1,126 @@
+use crate::{
+    Hub,
+    config::{NodeConfig, Transport},
+    ssh,
+};
+use axum::{
+    body::{Body, to_bytes},
+    extract::{ConnectInfo, Path, Request, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+const FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// Headers that describe one connection and are not passed on
+const HOP_BY_HOP: [header::HeaderName; 8] = [
+    header::CONNECTION,
+    header::HOST,
+    header::PROXY_AUTHENTICATE,
+    header::PROXY_AUTHORIZATION,
+    header::TE,
+    header::TRAILER,
+    header::TRANSFER_ENCODING,
+    header::UPGRADE,
+];
+
+/// Forward `/api/nodes/{node}/{path}` to the agent `node`
+pub async fn forward_to_node(
+    State(hub): State<Arc<Hub>>,
+    Path((node, path)): Path<(String, String)>,
+    request: Request,
+) -> Response {
+    match hub.config.node(&node) {
+        Some(node) if node.transport == Transport::Ssh => {
+            ssh::handle(&hub, node, &path, request).await
+        }
+        Some(node) => forward(&hub, node, &path, request).await,
+        None => error(StatusCode::NOT_FOUND, format!("Unknown node: {}", node)),
+    }
+}
+
+/// Forward API calls naming no node to the first agent, so a UI that has
+/// not picked a node still gets a server to talk to
+pub async fn forward_to_default(
+    State(hub): State<Arc<Hub>>,
+    Path(path): Path<String>,
+    request: Request,
+) -> Response {
+    match hub.config.first_agent() {
+        Some(node) => forward(&hub, node, &format!("api/{}", path), request).await,
+        None => error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "No agent nodes configured in hub.toml".to_string(),
+        ),
+    }
+}
+
+pub(crate) async fn forward(
+    hub: &Hub,
+    node: &NodeConfig,
+    path: &str,
+    request: Request,
+) -> Response {
+    let (parts, body) = request.into_parts();
+    let mut url = format!("{}/{}", node.url, path);
+    if let Some(query) = parts.uri.query() {
+        url.push('?');
+        url.push_str(query);
+    }
+
+    let body = match to_bytes(body, hub.config.max_body_bytes).await {
+        Ok(body) => body,
+        Err(e) => return error(StatusCode::PAYLOAD_TOO_LARGE, e.to_string()),
+    };
+
+    // Agents that list the hub in trusted_proxies count writes per client
+    let mut headers = strip_hop_by_hop(parts.headers);
+    if let Some(ConnectInfo(peer)) = parts.extensions.get::<ConnectInfo<SocketAddr>>() {
+        let forwarded = match headers.get(FORWARDED_FOR).and_then(|v| v.to_str().ok()) {
+            Some(earlier) => format!("{}, {}", earlier, peer.ip()),
+            None => peer.ip().to_string(),
+        };
+        if let Ok(value) = HeaderValue::from_str(&forwarded) {
+            headers.insert(FORWARDED_FOR, value);
+        }
+    }
+
+    let upstream = hub
+        .client
+        .request(parts.method, &url)
+        .headers(headers)
+        .body(body)
+        .send()
+        .await;
+    let upstream = match upstream {
+        Ok(upstream) => upstream,
+        Err(e) => {
+            return error(
+                StatusCode::BAD_GATEWAY,
+                format!("Node {} unreachable: {}", node.name, e),
+            );
+        }
+    };
+
+    // Streamed, so followed logs and container watches pass through as the
+    // agent sends them
+    let status = upstream.status();
+    let headers = strip_hop_by_hop(upstream.headers().clone());
+    let mut response = Response::new(Body::from_stream(upstream.bytes_stream()));
+    *response.status_mut() = status;
+    *response.headers_mut() = headers;
+    response
+}
+
+fn strip_hop_by_hop(mut headers: HeaderMap) -> HeaderMap {
+    for name in HOP_BY_HOP {
+        headers.remove(name);
+    }
+    headers
+}
+
+fn error(status: StatusCode, message: String) -> Response {
+    (status, message).into_response()
+}
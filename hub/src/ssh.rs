@@ -0,0 +1,257 @@
+use crate::{
+    Hub,
+    config::{NodeConfig, SshFile},
+    proxy,
+};
+use axum::{
+    Json,
+    body::to_bytes,
+    extract::{Query, Request},
+    http::{Method, StatusCode},
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use std::{io, sync::Arc};
+use sysrat_api_types::{
+    ContainerActionResponse, ContainerListResponse, FileContentResponse, FileInfo,
+    FileListResponse, WriteConfigRequest, WriteConfigResponse,
+};
+use sysrat_core::{
+    configs::{audit, secrets},
+    ssh::SshHost,
+};
+
+#[derive(Deserialize)]
+struct ReadQuery {
+    /// Skip masking `[secrets]`, audit-logged
+    #[serde(default)]
+    reveal: bool,
+}
+
+/// Settings of the UI rather than of a node, answered by the first agent
+const UI_PATHS: [&str; 3] = ["api/frontend-config", "api/statusline", "api/themes"];
+
+/// Answer `path` of a node without sysrat from its files and docker over
+/// SSH, with the same responses an agent gives
+pub async fn handle(hub: &Hub, node: &NodeConfig, path: &str, request: Request) -> Response {
+    if UI_PATHS
+        .iter()
+        .any(|ui| path == *ui || path.starts_with(&format!("{}/", ui)))
+    {
+        return match hub.config.first_agent() {
+            Some(agent) => proxy::forward(hub, agent, path, request).await,
+            None => unavailable(path),
+        };
+    }
+
+    let method = request.method().clone();
+    if path == "api/configs" && method == Method::GET {
+        return list_files(node);
+    }
+    if path == "api/containers" && method == Method::GET {
+        return match connect(hub, node).await {
+            Ok(host) => result(host.list_containers().await, |containers| {
+                ContainerListResponse {
+                    containers,
+                    outage: None,
+                }
+            }),
+            Err(response) => response,
+        };
+    }
+    if let Some(filename) = path.strip_prefix("api/configs/") {
+        let Some(file) = node.files.iter().find(|file| file.name == filename) else {
+            // The file's history, linting and the like need the agent
+            return match node
+                .files
+                .iter()
+                .any(|file| filename.starts_with(&format!("{}/", file.name)))
+            {
+                true => unavailable(path),
+                false => error(
+                    StatusCode::NOT_FOUND,
+                    format!("File not found: {}", filename),
+                ),
+            };
+        };
+        return match method {
+            Method::GET => read_file(hub, node, file, request).await,
+            Method::POST => write_file(hub, node, file, request).await,
+            _ => unavailable(path),
+        };
+    }
+    if let Some(rest) = path.strip_prefix("api/containers/")
+        && let Some((id, action)) = rest.split_once('/')
+        && matches!(action, "start" | "stop" | "restart")
+        && method == Method::POST
+    {
+        return match connect(hub, node).await {
+            Ok(host) => result(host.container_action(id, action).await, |_| {
+                let past_tense = match action {
+                    "start" => "started",
+                    "stop" => "stopped",
+                    _ => "restarted",
+                };
+                ContainerActionResponse {
+                    success: true,
+                    message: format!("container {}", past_tense),
+                }
+            }),
+            Err(response) => response,
+        };
+    }
+    unavailable(path)
+}
+
+fn list_files(node: &NodeConfig) -> Response {
+    let files = node
+        .files
+        .iter()
+        .map(|file| FileInfo {
+            name: file.name.clone(),
+            description: file.description.clone(),
+            readonly: file.readonly,
+            theme: None,
+            category: None,
+            schema: None,
+            upstream_url: None,
+            meta: None,
+            comment: None,
+            editing: None,
+        })
+        .collect();
+    Json(FileListResponse {
+        files,
+        readonly_override: Default::default(),
+        order: Default::default(),
+    })
+    .into_response()
+}
+
+/// Values matching `[secrets]` are masked unless `reveal=true` is passed,
+/// which is audit-logged, as an agent does
+async fn read_file(hub: &Hub, node: &NodeConfig, file: &SshFile, request: Request) -> Response {
+    let reveal = Query::<ReadQuery>::try_from_uri(request.uri()).is_ok_and(|query| query.reveal);
+    let host = match connect(hub, node).await {
+        Ok(host) => host,
+        Err(response) => return response,
+    };
+    result(host.read_file(&file.path).await, |content| {
+        let (content, redacted) = if reveal {
+            audit::record(
+                "warn",
+                &format!("Revealed secrets of {} on {}", file.name, node.name),
+            );
+            (content, false)
+        } else {
+            match secrets::redact(&content, &hub.config.secret_patterns) {
+                Some(masked) => (masked, true),
+                None => (content, false),
+            }
+        };
+        FileContentResponse {
+            content,
+            format: Default::default(),
+            redacted,
+        }
+    })
+}
+
+async fn write_file(hub: &Hub, node: &NodeConfig, file: &SshFile, request: Request) -> Response {
+    if file.readonly {
+        return error(
+            StatusCode::FORBIDDEN,
+            format!("File is read-only: {}", file.name),
+        );
+    }
+    let body = match to_bytes(request.into_body(), hub.config.max_body_bytes).await {
+        Ok(body) => body,
+        Err(e) => return error(StatusCode::PAYLOAD_TOO_LARGE, e.to_string()),
+    };
+    let payload: WriteConfigRequest = match serde_json::from_slice(&body) {
+        Ok(payload) => payload,
+        Err(e) => return error(StatusCode::BAD_REQUEST, format!("Invalid request: {}", e)),
+    };
+    let host = match connect(hub, node).await {
+        Ok(host) => host,
+        Err(response) => return response,
+    };
+    // A masked read saved back would replace the secrets with the mask
+    if payload.content.contains(secrets::MASK)
+        && let Ok(current) = host.read_file(&file.path).await
+        && secrets::keeps_mask(&payload.content, &current, &hub.config.secret_patterns)
+    {
+        return error(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!(
+                "{} still holds masked secrets, reveal them before saving",
+                file.name
+            ),
+        );
+    }
+    result(host.write_file(&file.path, &payload.content).await, |_| {
+        WriteConfigResponse {
+            success: true,
+            mode: None,
+        }
+    })
+}
+
+/// The node's open connection, reconnecting when there is none or it dropped
+pub async fn session(hub: &Hub, node: &NodeConfig) -> io::Result<Arc<SshHost>> {
+    let slot = hub
+        .ssh_hosts
+        .get(&node.name)
+        .ok_or_else(|| io::Error::other(format!("{} is not an ssh node", node.name)))?;
+    // One connection attempt per node at a time, other nodes don't wait
+    let mut slot = slot.lock().await;
+    if let Some(host) = slot.as_ref()
+        && host.is_alive().await
+    {
+        return Ok(Arc::clone(host));
+    }
+    let host = SshHost::connect(&node.ssh).await?;
+    Ok(Arc::clone(slot.insert(Arc::new(host))))
+}
+
+async fn connect(hub: &Hub, node: &NodeConfig) -> Result<Arc<SshHost>, Response> {
+    session(hub, node).await.map_err(|e| {
+        error(
+            StatusCode::BAD_GATEWAY,
+            format!("Node {} unreachable: {}", node.name, e),
+        )
+    })
+}
+
+fn result<T, R: serde::Serialize>(
+    outcome: io::Result<T>,
+    to_response: impl FnOnce(T) -> R,
+) -> Response {
+    match outcome {
+        Ok(value) => Json(to_response(value)).into_response(),
+        Err(e) => {
+            let status = match e.kind() {
+                io::ErrorKind::NotFound => StatusCode::NOT_FOUND,
+                io::ErrorKind::PermissionDenied => StatusCode::FORBIDDEN,
+                io::ErrorKind::InvalidData => StatusCode::UNPROCESSABLE_ENTITY,
+                io::ErrorKind::TimedOut => StatusCode::REQUEST_TIMEOUT,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            error(status, e.to_string())
+        }
+    }
+}
+
+fn unavailable(path: &str) -> Response {
+    error(
+        StatusCode::NOT_IMPLEMENTED,
+        format!(
+            "/{} needs sysrat on the node, it is not available over SSH",
+            path
+        ),
+    )
+}
+
+fn error(status: StatusCode, message: String) -> Response {
+    (status, message).into_response()
+}
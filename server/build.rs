@@ -0,0 +1,35 @@
+use std::process::Command;
+
+/// Build metadata served by GET /api/version
+fn main() {
+    set_env("BUILD_HASH", git_hash());
+    set_env("BUILD_DATE", command_output("date", &["+%Y-%m-%d"]));
+    // "rustc 1.82.0 (f6e511eec 2024-10-15)" -> "1.82.0"
+    set_env(
+        "RUST_VERSION",
+        command_output("rustc", &["--version"])
+            .and_then(|v| v.split_whitespace().nth(1).map(str::to_string)),
+    );
+
+    println!("cargo:rerun-if-changed=../.git/HEAD");
+    println!("cargo:rerun-if-changed=../.git/refs/heads");
+}
+
+fn set_env(key: &str, value: Option<String>) {
+    let value = value.unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env={}={}", key, value);
+}
+
+fn git_hash() -> Option<String> {
+    command_output("git", &["rev-parse", "--short=8", "HEAD"])
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8(output.stdout).ok()?;
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
@@ -0,0 +1,37 @@
+use crate::routes::{OVERRIDE_HEADER, ROLE_TOKEN_HEADER};
+use axum::http::{HeaderName, HeaderValue, Method, header};
+use sysrat_core::config::CorsSettings;
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
+
+/// Build the CORS layer from sysrat.toml settings
+/// Returns None when CORS is disabled (same-origin deployments)
+pub fn build_layer(settings: &CorsSettings) -> Option<CorsLayer> {
+    if !settings.enabled {
+        return None;
+    }
+
+    let layer = CorsLayer::new()
+        .allow_methods([Method::GET, Method::POST, Method::PUT])
+        .allow_headers([
+            header::CONTENT_TYPE,
+            header::RANGE,
+            header::IF_NONE_MATCH,
+            HeaderName::from_static(ROLE_TOKEN_HEADER),
+            HeaderName::from_static(OVERRIDE_HEADER),
+        ])
+        // The hex viewer reads the file size from ranged /raw responses,
+        // config reads keep the ETag for the next If-None-Match
+        .expose_headers([header::CONTENT_RANGE, header::ETAG]);
+
+    if settings.allowed_origins.iter().any(|o| o == "*") {
+        return Some(layer.allow_origin(Any));
+    }
+
+    let origins: Vec<HeaderValue> = settings
+        .allowed_origins
+        .iter()
+        .filter_map(|o| HeaderValue::from_str(o).ok())
+        .collect();
+
+    Some(layer.allow_origin(AllowOrigin::list(origins)))
+}
@@ -0,0 +1,146 @@
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderValue, Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use sysrat_core::config::LimitSettings;
+
+/// Buckets kept before full ones are dropped, bounds memory under many clients
+const MAX_TRACKED_CLIENTS: usize = 1024;
+
+/// Token bucket per client IP: a full minute's worth of writes may come in a
+/// burst, then they refill evenly
+pub struct Limiter {
+    settings: LimitSettings,
+    buckets: Mutex<HashMap<IpAddr, Bucket>>,
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Limiter {
+    pub fn new(settings: LimitSettings) -> Arc<Self> {
+        Arc::new(Self {
+            settings,
+            buckets: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Take one write for `ip`, or the seconds until one is available
+    fn take(&self, ip: IpAddr) -> Result<(), u64> {
+        let capacity = f64::from(self.settings.writes_per_minute);
+        let per_sec = capacity / 60.0;
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+
+        if buckets.len() >= MAX_TRACKED_CLIENTS {
+            buckets.retain(|_, bucket| {
+                bucket.tokens + now.duration_since(bucket.updated).as_secs_f64() * per_sec
+                    < capacity
+            });
+        }
+
+        let bucket = buckets.entry(ip).or_insert(Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * per_sec).min(capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(((1.0 - bucket.tokens) / per_sec).ceil() as u64)
+        }
+    }
+}
+
+/// Every request but a read changes something or runs a command (the lint
+/// check runs the configured linters), except the in-process hygiene check
+/// before a save
+fn is_write(request: &Request) -> bool {
+    let method = request.method();
+    method != Method::GET
+        && method != Method::HEAD
+        && method != Method::OPTIONS
+        && !request.uri().path().ends_with("/hygiene")
+}
+
+/// The client a request counts for: the peer, or behind a trusted proxy the
+/// address it forwarded (the last X-Forwarded-For entry no trusted proxy
+/// added)
+fn client_ip(trusted_proxies: &[IpAddr], peer: IpAddr, request: &Request) -> IpAddr {
+    if !trusted_proxies.contains(&peer) {
+        return peer;
+    }
+    let forwarded: Vec<IpAddr> = request
+        .headers()
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|ip| ip.trim().parse().ok())
+        .collect();
+    forwarded
+        .into_iter()
+        .rev()
+        .find(|ip| !trusted_proxies.contains(ip))
+        .unwrap_or(peer)
+}
+
+/// Refuse bodies over `max_body_bytes` up front (413) and writes over the
+/// client's rate (429). Bodies without a Content-Length are capped by the
+/// DefaultBodyLimit layer instead.
+pub async fn limit(
+    State(limiter): State<Arc<Limiter>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let max_body = limiter.settings.max_body_bytes;
+    let length = request
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<usize>().ok());
+    if let Some(length) = length.filter(|&length| length > max_body) {
+        return (
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!(
+                "Request body is {} bytes, the limit is {} (max_body_bytes in [server.limits])",
+                length, max_body
+            ),
+        )
+            .into_response();
+    }
+
+    let ip = client_ip(&limiter.settings.trusted_proxies, addr.ip(), &request);
+    if is_write(&request)
+        && limiter.settings.writes_per_minute > 0
+        && let Err(retry_after) = limiter.take(ip)
+    {
+        let mut response = (
+            StatusCode::TOO_MANY_REQUESTS,
+            format!(
+                "Too many changes from {}, limit is {} per minute; retry in {}s",
+                ip, limiter.settings.writes_per_minute, retry_after
+            ),
+        )
+            .into_response();
+        response
+            .headers_mut()
+            .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+        return response;
+    }
+
+    next.run(request).await
+}
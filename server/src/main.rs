@@ -1,15 +1,22 @@
+mod cors;
+mod limits;
 mod routes;
+mod signals;
 mod version;
 
 use axum::{
     Router,
-    routing::{get, post},
+    extract::DefaultBodyLimit,
+    http::{HeaderName, HeaderValue},
+    middleware,
+    routing::{get, post, put},
 };
 use k_lib::config::Cookbook;
 use k_lib::logger;
+use std::net::SocketAddr;
 use std::sync::Arc;
-use sysrat_core::config;
-use tower_http::services::ServeDir;
+use sysrat_core::{config, serve};
+use tower_http::{services::ServeDir, set_header::SetResponseHeaderLayer};
 
 use tokio::sync::RwLock;
 
@@ -24,6 +31,9 @@ fn log(cookbook: &Cookbook, level: &str, msg: &str) {
 
 #[tokio::main]
 async fn main() {
+    // Uptime in /api/meta counts from here
+    routes::record_start();
+
     // Load k-lib config for logging (fallback to eprintln if unavailable)
     let cookbook = Cookbook::load().ok();
 
@@ -53,7 +63,7 @@ async fn main() {
     }
 
     // Load configuration (logging happens inside AppConfig::load)
-    let app_config = match config::AppConfig::load() {
+    let app_config = match config::AppConfig::load().await {
         Ok(cfg) => Arc::new(RwLock::new(cfg)),
         Err(e) => {
             if let Some(ref cb) = cookbook {
@@ -65,53 +75,221 @@ async fn main() {
         }
     };
 
+    // Reload sysrat.toml on SIGHUP
+    signals::spawn_reload_handler(Arc::clone(&app_config));
+
+    // Send the weekly digest if `[digest]` is enabled
+    routes::spawn_digest_scheduler(Arc::clone(&app_config));
+
+    // Retry change events the `[events]` webhook has not accepted yet
+    sysrat_core::events::spawn_forwarder(Arc::clone(&app_config));
+
+    // Read HTTP settings before the config is moved into router state
+    let (base_path, cors_settings, limit_settings, tls_settings) = {
+        let reader = app_config.read().await;
+        (
+            reader.base_path(),
+            reader.server().cors.clone(),
+            reader.server().limits.clone(),
+            reader.server().tls.clone(),
+        )
+    };
+
+    // An unusable certificate stops the start, serving plain HTTP instead
+    // would bypass the client check
+    let tls_config = match tls_settings.as_ref().map(serve::tls_config) {
+        Some(Ok(config)) => Some(config),
+        Some(Err(e)) => {
+            if let Some(ref cb) = cookbook {
+                log(cb, "error", &format!("Failed to set up TLS: {}", e));
+            } else {
+                eprintln!("Failed to set up TLS: {}", e);
+            }
+            std::process::exit(1);
+        }
+        None => None,
+    };
+    let max_body_bytes = limit_settings.max_body_bytes;
+    let limiter = limits::Limiter::new(limit_settings);
+
     // Setup routes
     if let Some(ref cb) = cookbook {
         log(cb, "info", "Registering API routes...");
     }
-    let app = Router::new()
+    let router = Router::new()
         // API routes
+        // Probes stay outside /api so proxies can route them separately
+        .route("/healthz", get(routes::healthz))
+        .route("/readyz", get(routes::readyz))
+        .route("/api/meta", get(routes::get_meta))
+        .route("/api/version", get(routes::get_version))
+        .route("/api/openapi.json", get(routes::openapi_json))
         .route("/api/configs", get(routes::list_configs))
         .route("/api/configs/{*filename}", get(routes::read_config))
         .route("/api/configs/{*filename}", post(routes::write_config))
         .route("/api/containers", get(routes::list_containers))
+        .route("/api/containers/graph", get(routes::get_container_graph))
         .route(
             "/api/containers/{id}/details",
             get(routes::get_container_details),
         )
+        .route(
+            "/api/containers/{id}/watch",
+            get(routes::get_container_watch),
+        )
         .route("/api/containers/{id}/start", post(routes::start_container))
         .route("/api/containers/{id}/stop", post(routes::stop_container))
         .route(
             "/api/containers/{id}/restart",
             post(routes::restart_container),
         )
+        .route("/api/images", get(routes::list_images))
+        .route("/api/images/{id}/scan", get(routes::get_image_scan))
+        .route("/api/images/{id}/scan", post(routes::scan_image))
+        .route("/api/dashboard", get(routes::get_dashboard))
+        .route("/api/digest", get(routes::get_digest))
+        .route("/api/digest/send", post(routes::send_digest))
+        .route("/api/jobs", get(routes::list_jobs))
+        .route("/api/jobs/{id}", get(routes::get_job))
+        .route("/api/actions", get(routes::list_actions))
+        .route("/api/actions/{name}/run", post(routes::run_action))
+        .route("/api/runbooks", get(routes::list_runbooks))
+        .route("/api/logs", get(routes::list_logs))
+        .route("/api/logs/{name}/tail", get(routes::tail_log))
+        .route("/api/hosts", get(routes::list_hosts))
+        .route("/api/profile", get(routes::get_profile))
+        .route("/api/profile", put(routes::put_profile))
+        .route(
+            "/api/runbooks/{name}/steps/{index}/run",
+            post(routes::run_runbook_step),
+        )
+        .route("/api/insights/changes", get(routes::get_change_stats))
+        .route("/api/metrics", get(routes::get_metrics))
+        .route("/api/themes", get(routes::list_themes))
+        .route("/api/themes/{name}", post(routes::save_theme))
+        .route("/api/frontend-config", get(routes::get_frontend_config))
+        .route("/api/statusline", get(routes::get_statusline))
+        .route("/api/export/blueprint", get(routes::export_blueprint))
+        .route("/api/import/blueprint", post(routes::import_blueprint))
+        // Oversized bodies and write floods are refused before any handler
+        .route_layer(middleware::from_fn_with_state(limiter, limits::limit))
+        .layer(DefaultBodyLimit::max(max_body_bytes))
         // Pass config as state
-        .with_state(app_config)
+        .with_state(app_config);
+
+    // Optional interactive API docs (cargo feature "swagger-ui")
+    #[cfg(feature = "swagger-ui")]
+    let router = router.merge(utoipa_swagger_ui::SwaggerUi::new("/api/docs").config(
+        utoipa_swagger_ui::Config::from(format!("{}/api/openapi.json", base_path)),
+    ));
+
+    let router = router
         // Static files (frontend)
         .fallback_service(ServeDir::new("frontend/dist"));
 
+    // Mount everything under the reverse-proxy prefix if configured
+    let app = if base_path.is_empty() {
+        router
+    } else {
+        Router::new().nest(&base_path, router)
+    };
+
+    // Let clients detect a server speaking a different wire format
+    let app = app.layer(SetResponseHeaderLayer::overriding(
+        HeaderName::from_static(routes::API_VERSION_HEADER),
+        HeaderValue::from(routes::API_VERSION),
+    ));
+
+    let app = match cors::build_layer(&cors_settings) {
+        Some(layer) => {
+            if let Some(ref cb) = cookbook {
+                log(cb, "info", "CORS enabled");
+            }
+            app.layer(layer)
+        }
+        None => app,
+    };
+
     if let Some(ref cb) = cookbook {
         log(cb, "success", "Routes registered");
+        if !base_path.is_empty() {
+            log(cb, "info", &format!("  Base path: {}", base_path));
+        }
+        log(cb, "info", "  GET  /healthz");
+        log(cb, "info", "  GET  /readyz");
+        log(cb, "info", "  GET  /api/meta");
+        log(cb, "info", "  GET  /api/version");
+        log(cb, "info", "  GET  /api/openapi.json");
         log(cb, "info", "  GET  /api/configs");
         log(cb, "info", "  GET  /api/configs/{*filename}");
+        log(cb, "info", "  GET  /api/configs/{*filename}/log");
+        log(cb, "info", "  GET  /api/configs/{*filename}/raw");
+        log(cb, "info", "  GET  /api/configs/{*filename}/upstream");
+        log(cb, "info", "  GET  /api/configs/{*filename}/rendered");
         log(cb, "info", "  POST /api/configs/{*filename}");
+        log(cb, "info", "  POST /api/configs/{*filename}/upload");
+        log(cb, "info", "  POST /api/configs/{*filename}/lint");
+        log(cb, "info", "  POST /api/configs/{*filename}/hygiene");
+        log(cb, "info", "  POST /api/configs/{*filename}/upstream");
         log(cb, "info", "  GET  /api/containers");
+        log(cb, "info", "  GET  /api/containers/graph");
+        log(cb, "info", "  GET  /api/containers/{id}/watch");
         log(cb, "info", "  POST /api/containers/{id}/start");
         log(cb, "info", "  POST /api/containers/{id}/stop");
         log(cb, "info", "  POST /api/containers/{id}/restart");
+        log(cb, "info", "  GET  /api/images");
+        log(cb, "info", "  GET  /api/images/{id}/scan");
+        log(cb, "info", "  POST /api/images/{id}/scan");
+        log(cb, "info", "  GET  /api/dashboard");
+        log(cb, "info", "  GET  /api/digest");
+        log(cb, "info", "  POST /api/digest/send");
+        log(cb, "info", "  GET  /api/jobs");
+        log(cb, "info", "  GET  /api/jobs/{id}");
+        log(cb, "info", "  GET  /api/actions");
+        log(cb, "info", "  POST /api/actions/{name}/run");
+        log(cb, "info", "  GET  /api/runbooks");
+        log(cb, "info", "  POST /api/runbooks/{name}/steps/{index}/run");
+        log(cb, "info", "  GET  /api/logs");
+        log(cb, "info", "  GET  /api/logs/{name}/tail");
+        log(cb, "info", "  GET  /api/hosts");
+        log(cb, "info", "  GET  /api/profile");
+        log(cb, "info", "  PUT  /api/profile");
+        log(cb, "info", "  GET  /api/insights/changes");
+        log(cb, "info", "  GET  /api/metrics");
+        log(cb, "info", "  GET  /api/themes");
+        log(cb, "info", "  POST /api/themes/{name}");
+        log(cb, "info", "  GET  /api/frontend-config");
+        log(cb, "info", "  GET  /api/statusline");
+        log(cb, "info", "  GET  /api/export/blueprint");
+        log(cb, "info", "  POST /api/import/blueprint");
     }
 
     // Read server configuration from environment or use defaults
     let server_port = std::env::var("SERVER_PORT").unwrap_or_else(|_| "3000".to_string());
     // Bind to 0.0.0.0 to ensure availability on all interfaces (needed for some setups/IPv6 dual stack)
     let bind_addr = format!("0.0.0.0:{}", server_port);
-    let display_addr = format!("http://localhost:{}", server_port);
+    let scheme = if tls_config.is_some() {
+        "https"
+    } else {
+        "http"
+    };
+    let display_addr = format!("{}://localhost:{}{}/", scheme, server_port, base_path);
 
     if let Some(ref cb) = cookbook {
         log(cb, "info", &format!("Binding to {}", bind_addr));
     }
 
-    let listener = tokio::net::TcpListener::bind(&bind_addr).await.unwrap();
+    let listener = match tokio::net::TcpListener::bind(&bind_addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            if let Some(ref cb) = cookbook {
+                log(cb, "error", &format!("Failed to bind {}: {}", bind_addr, e));
+            } else {
+                eprintln!("Failed to bind {}: {}", bind_addr, e);
+            }
+            std::process::exit(1);
+        }
+    };
 
     if let Some(ref cb) = cookbook {
         log(
@@ -119,10 +297,26 @@ async fn main() {
             "success",
             &format!("Server running on {}", display_addr),
         );
+        if tls_settings.is_some_and(|tls| tls.client_ca.is_some()) {
+            log(cb, "info", "Client certificates required (agent mode)");
+        }
         log(cb, "info", "Ready to accept connections");
     } else {
         println!("Server running on {}", display_addr);
     }
 
-    axum::serve(listener, app).await.unwrap();
+    // Client addresses key the per-IP write limit
+    let service = app.into_make_service_with_connect_info::<SocketAddr>();
+    if let Err(e) = serve::serve(listener, tls_config, service, APP_NAME).await {
+        if let Some(ref cb) = cookbook {
+            log(cb, "error", &format!("Server error: {}", e));
+        } else {
+            eprintln!("Server error: {}", e);
+        }
+        std::process::exit(1);
+    }
+
+    if let Some(ref cb) = cookbook {
+        log(cb, "success", "Server stopped");
+    }
 }
@@ -0,0 +1,66 @@
+use crate::routes::types::{
+    ActionListResponse, ROLE_TOKEN_HEADER, RunActionRequest, RunActionResponse,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+};
+use sysrat_core::config::SharedConfig;
+
+/// GET /api/actions - Scriptable actions from sysrat.toml
+#[utoipa::path(
+    get,
+    path = "/api/actions",
+    tag = "actions",
+    responses((status = 200, description = "Configured actions", body = ActionListResponse))
+)]
+pub async fn list_actions(State(config): State<SharedConfig>) -> Json<ActionListResponse> {
+    Json(ActionListResponse {
+        actions: sysrat_core::runbook::list(&config).await,
+    })
+}
+
+/// POST /api/actions/{name}/run - Start an action as a job
+///
+/// Returns as soon as the command is started; poll GET /api/jobs/{id} for
+/// its output and outcome. The body carries the action's parameter values
+/// and may be left out for actions without parameters.
+#[utoipa::path(
+    post,
+    path = "/api/actions/{name}/run",
+    tag = "actions",
+    params(
+        ("name" = String, Path, description = "Action name as listed by GET /api/actions"),
+        ("x-sysrat-token" = Option<String>, Header, description = "Token of the role the action requires"),
+    ),
+    request_body(content = Option<RunActionRequest>, description = "Parameter values"),
+    responses(
+        (status = 200, description = "Action started", body = RunActionResponse),
+        (status = 403, description = "Missing or wrong role token", body = String),
+        (status = 404, description = "No action with this name", body = String),
+        (status = 422, description = "Unknown or invalid parameter value", body = String),
+    )
+)]
+pub async fn run_action(
+    State(config): State<SharedConfig>,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+    request: Option<Json<RunActionRequest>>,
+) -> Result<Json<RunActionResponse>, (StatusCode, String)> {
+    let token = headers.get(ROLE_TOKEN_HEADER).and_then(|v| v.to_str().ok());
+    let Json(request) = request.unwrap_or_default();
+
+    match sysrat_core::runbook::start(&name, &request.params, token, &config).await {
+        Ok(job_id) => Ok(Json(RunActionResponse { job_id })),
+        Err(e) => {
+            let status: StatusCode = match e.kind() {
+                std::io::ErrorKind::NotFound => StatusCode::NOT_FOUND,
+                std::io::ErrorKind::PermissionDenied => StatusCode::FORBIDDEN,
+                std::io::ErrorKind::InvalidInput => StatusCode::UNPROCESSABLE_ENTITY,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            Err((status, format!("Action error: {}", e)))
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::routes::containers::blueprint_containers;
+use crate::routes::types::{
+    BLUEPRINT_VERSION, Blueprint, BlueprintConfig, BlueprintImportRequest, BlueprintImportResponse,
+    BlueprintImportResult, OVERRIDE_HEADER, ValidationErrorResponse,
+};
+use crate::routes::validate::Valid;
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+};
+use serde::Deserialize;
+use std::time::{SystemTime, UNIX_EPOCH};
+use sysrat_core::config::{AppConfig, SharedConfig};
+use sysrat_core::configs::{actions, audit, secrets};
+
+#[derive(Deserialize)]
+pub struct ExportQuery {
+    /// Skip masking `[secrets]`, audit-logged
+    #[serde(default)]
+    reveal: bool,
+}
+
+/// GET /api/export/blueprint - Managed configs, containers and sysrat.toml
+///
+/// Hosts without docker export no containers rather than failing. Values
+/// matching `[secrets]` are masked unless `reveal=true` is passed, which is
+/// audit-logged; masked configs are marked and cannot be imported. The
+/// role and override tokens in sysrat.toml stay masked unless the override
+/// token is sent as well.
+#[utoipa::path(
+    get,
+    path = "/api/export/blueprint",
+    tag = "blueprint",
+    params(
+        ("reveal" = Option<bool>, Query, description = "Export secrets unmasked, audit-logged"),
+        ("X-Sysrat-Override" = Option<String>, Header, description = "Override token, unmasks the tokens in sysrat.toml along with reveal"),
+    ),
+    responses(
+        (status = 200, description = "Blueprint of this host", body = Blueprint),
+        (status = 500, description = "sysrat.toml unreadable", body = String),
+    )
+)]
+pub async fn export_blueprint(
+    State(config): State<SharedConfig>,
+    headers: HeaderMap,
+    Query(query): Query<ExportQuery>,
+) -> Result<Json<Blueprint>, (StatusCode, String)> {
+    let settings = tokio::fs::read_to_string(AppConfig::config_path())
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to read sysrat.toml: {}", e),
+            )
+        })?;
+
+    let unmask_settings = query.reveal
+        && match headers.get(OVERRIDE_HEADER).and_then(|v| v.to_str().ok()) {
+            Some(value) => config.read().await.accepts_token(value),
+            None => false,
+        };
+    let (settings, settings_redacted) = match secrets::mask_settings(&settings) {
+        Some(_) if unmask_settings => {
+            audit::record("warn", "Revealed the tokens of sysrat.toml");
+            (settings, false)
+        }
+        Some(masked) => (masked, true),
+        None => (settings, false),
+    };
+
+    let files = config.read().await.files().to_vec();
+    let mut configs = Vec::with_capacity(files.len());
+    for file in files {
+        let (content, redacted) = match tokio::fs::read_to_string(&file.path).await {
+            Ok(content) => {
+                let (content, redacted) =
+                    secrets::for_read(&file.name, content, query.reveal, &config).await;
+                (Some(content), redacted)
+            }
+            Err(_) => (None, false),
+        };
+        configs.push(BlueprintConfig {
+            content,
+            redacted,
+            name: file.name,
+            path: file.path,
+            description: file.description,
+            readonly: file.readonly,
+            category: file.category,
+        });
+    }
+
+    Ok(Json(Blueprint {
+        version: BLUEPRINT_VERSION,
+        host: std::fs::read_to_string("/proc/sys/kernel/hostname")
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default(),
+        created: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0),
+        settings,
+        settings_redacted,
+        configs,
+        containers: blueprint_containers().await.unwrap_or_default(),
+    }))
+}
+
+/// POST /api/import/blueprint - Apply selected parts of a blueprint
+///
+/// sysrat.toml can add actions and roles, so replacing it takes the override
+/// token; a confirmation is not enough, nor are masked tokens. Readonly
+/// configs need an override the policy accepts, like any write. Each item is
+/// applied on its own; the results say which failed.
+#[utoipa::path(
+    post,
+    path = "/api/import/blueprint",
+    tag = "blueprint",
+    params(("X-Sysrat-Override" = Option<String>, Header, description = "Unlocks sysrat.toml and readonly configs")),
+    request_body = BlueprintImportRequest,
+    responses(
+        (status = 200, description = "Outcome per selected item", body = BlueprintImportResponse),
+        (status = 422, description = "Blueprint from a newer sysrat or an invalid selection", body = ValidationErrorResponse),
+    )
+)]
+pub async fn import_blueprint(
+    State(config): State<SharedConfig>,
+    headers: HeaderMap,
+    Valid(request): Valid<BlueprintImportRequest>,
+) -> Result<Json<BlueprintImportResponse>, (StatusCode, String)> {
+    let blueprint = &request.blueprint;
+    let override_value = headers.get(OVERRIDE_HEADER).and_then(|v| v.to_str().ok());
+    let mut results = Vec::new();
+
+    // Settings first, configs they add become writable below
+    if request.settings {
+        let unlocked = match override_value {
+            Some(value) => config.read().await.accepts_token(value),
+            None => false,
+        };
+        let result = if blueprint.settings_redacted {
+            Err(
+                "Exported with masked tokens, export with reveal=true and the override token"
+                    .to_string(),
+            )
+        } else if unlocked {
+            audit::record(
+                "warn",
+                &format!("Restoring sysrat.toml from blueprint of {}", blueprint.host),
+            );
+            AppConfig::restore(&config, &blueprint.settings).await
+        } else {
+            Err("Replacing sysrat.toml needs the override token".to_string())
+        };
+        results.push(BlueprintImportResult {
+            item: "sysrat.toml".to_string(),
+            error: result.err(),
+        });
+    }
+
+    for name in &request.configs {
+        let content = blueprint
+            .configs
+            .iter()
+            .find(|c| c.name == *name)
+            .ok_or("Not in the blueprint")
+            .and_then(|c| match c.redacted {
+                true => Err("Exported with masked secrets, export with reveal=true"),
+                false => c.content.as_deref().ok_or("Exported without content"),
+            });
+        let error = match content {
+            Ok(content) => actions::write_file(name, content, None, &config, override_value)
+                .await
+                .err()
+                .map(|e| e.to_string()),
+            Err(e) => Some(e.to_string()),
+        };
+        results.push(BlueprintImportResult {
+            item: name.clone(),
+            error,
+        });
+    }
+
+    Ok(Json(BlueprintImportResponse { results }))
+}
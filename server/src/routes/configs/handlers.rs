@@ -1,79 +1,616 @@
+use crate::routes::etag::json_with_etag;
 use crate::routes::types::{
-    FileContentResponse, FileInfo, FileListResponse, WriteConfigRequest, WriteConfigResponse,
+    FileContentResponse, FileListResponse, FileLogResponse, HygieneResponse, LintResponse,
+    OVERRIDE_HEADER, RenderedResponse, TextFormat, UpstreamApplyRequest, UpstreamCheckResponse,
+    ValidationErrorResponse, WriteConfigRequest, WriteConfigResponse, WritePermissionError,
 };
+use crate::routes::validate::Valid;
 use axum::{
     Json,
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{FromRequest, Multipart, Path, Query, Request, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
 };
+use serde::Deserialize;
 use sysrat_core::config::SharedConfig;
+use sysrat_core::configs::{encoding, hygiene, permissions, secrets};
+
+#[derive(Deserialize)]
+pub struct ReadQuery {
+    /// Skip masking `[secrets]`, audit-logged
+    #[serde(default)]
+    reveal: bool,
+}
 
 /// GET /api/configs - List all config files
-pub async fn list_configs(
-    State(config): State<SharedConfig>,
-) -> Result<Json<FileListResponse>, (StatusCode, String)> {
+///
+/// Answers 304 when If-None-Match carries the ETag of the current list.
+#[utoipa::path(
+    get,
+    path = "/api/configs",
+    tag = "configs",
+    params(("If-None-Match" = Option<String>, Header, description = "ETag of a list fetched before")),
+    responses(
+        (status = 200, description = "Managed config files", body = FileListResponse),
+        (status = 304, description = "List unchanged since the given ETag"),
+    )
+)]
+pub async fn list_configs(State(config): State<SharedConfig>, headers: HeaderMap) -> Response {
     let files = sysrat_core::configs::actions::list_files(&config).await;
-    // Convert core::types::FileInfo to routes::types::FileInfo if they are different,
-    // OR likely logic is: core types should replace routes types eventually.
-    // For now, let's assume we map or use the same types if I replace imports.
-    // But wait, I haven't replaced imports yet.
-    // Let's rely on mapping for safety or better: REPLACE the usage of FileInfo in routes with core::types::FileInfo
-
-    // Actually, let's map it clearly to avoid type errors if I haven't switched everything.
-    let mapped_files = files
-        .into_iter()
-        .map(|f| FileInfo {
-            name: f.name,
-            description: f.description,
-            readonly: f.readonly,
-            category: f.category,
-            theme: f.theme,
-        })
-        .collect();
-
-    Ok(Json(FileListResponse {
-        files: mapped_files,
-    }))
+    let (readonly_override, order) = {
+        let config = config.read().await;
+        (config.readonly_override(), config.file_order())
+    };
+    json_with_etag(
+        &headers,
+        &FileListResponse {
+            files,
+            readonly_override,
+            order,
+        },
+    )
 }
 
 /// GET /api/configs/*filename - Read a config file
+///
+/// Also serves `/api/configs/*filename/log`, `/raw`, `/upstream` and
+/// `/rendered`, since
+/// axum does not allow segments after a wildcard. The content answers 304
+/// when If-None-Match carries its current ETag. Values matching `[secrets]`
+/// are masked unless `reveal=true` is passed, which is audit-logged.
+#[utoipa::path(
+    get,
+    path = "/api/configs/{filename}",
+    tag = "configs",
+    params(
+        ("filename" = String, Path, description = "File name as listed by GET /api/configs"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of content fetched before"),
+        ("reveal" = Option<bool>, Query, description = "Return secrets unmasked, audit-logged"),
+    ),
+    responses(
+        (status = 200, description = "File content", body = FileContentResponse),
+        (status = 304, description = "Content unchanged since the given ETag"),
+        (status = 404, description = "File not managed by sysrat", body = String),
+        (status = 415, description = "Binary file, read it through /raw", body = String),
+    )
+)]
 pub async fn read_config(
     State(config): State<SharedConfig>,
     Path(filename): Path<String>,
-) -> Result<Json<FileContentResponse>, (StatusCode, String)> {
+    Query(query): Query<ReadQuery>,
+    headers: HeaderMap,
+) -> Response {
     // Wildcard routes include leading slash, strip it
     let filename = filename.strip_prefix('/').unwrap_or(&filename);
 
+    if let Some(name) = filename.strip_suffix("/log") {
+        return read_config_log(State(config), Path(name.to_string()), Query(query))
+            .await
+            .into_response();
+    }
+    if let Some(name) = filename.strip_suffix("/raw") {
+        return read_config_raw(State(config), Path(name.to_string()), Query(query), headers)
+            .await
+            .into_response();
+    }
+    if let Some(name) = filename.strip_suffix("/upstream") {
+        return check_upstream(State(config), Path(name.to_string()), Query(query))
+            .await
+            .into_response();
+    }
+    if let Some(name) = filename.strip_suffix("/rendered") {
+        return render_config(State(config), Path(name.to_string()))
+            .await
+            .into_response();
+    }
+
     match sysrat_core::configs::actions::read_file(filename, &config).await {
-        Ok(content) => Ok(Json(FileContentResponse { content })),
+        Ok((content, format)) => {
+            let (content, redacted) =
+                secrets::for_read(filename, content, query.reveal, &config).await;
+            let response = FileContentResponse {
+                content,
+                format,
+                redacted,
+            };
+            json_with_etag(&headers, &response)
+        }
+        Err(e) => {
+            let status: StatusCode = match e.kind() {
+                std::io::ErrorKind::NotFound => StatusCode::NOT_FOUND,
+                // Not text, the frontend falls back to the hex viewer
+                std::io::ErrorKind::InvalidData => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status, format!("Read error: {}", e)).into_response()
+        }
+    }
+}
+
+/// Largest chunk served for a single Range request
+const MAX_RANGE_BYTES: u64 = 1024 * 1024;
+
+/// GET /api/configs/*filename/raw - Download a config file
+///
+/// Serves the bytes as stored, so binary files work too. A `Range: bytes=a-b`
+/// (or `bytes=a-`) header returns just that part with 206 Partial Content,
+/// which the hex viewer uses for paging. Raw bytes cannot be masked, so a
+/// file holding `[secrets]` is refused unless `reveal=true` is passed, which
+/// is audit-logged.
+#[utoipa::path(
+    get,
+    path = "/api/configs/{filename}/raw",
+    tag = "configs",
+    params(
+        ("filename" = String, Path, description = "File name as listed by GET /api/configs"),
+        ("Range" = Option<String>, Header, description = "Byte range, e.g. `bytes=0-4095`"),
+        ("reveal" = Option<bool>, Query, description = "Serve a file holding secrets, audit-logged"),
+    ),
+    responses(
+        (status = 200, description = "File content as attachment", body = String, content_type = "application/octet-stream"),
+        (status = 206, description = "Requested byte range, size in Content-Range", body = String, content_type = "application/octet-stream"),
+        (status = 403, description = "File holds secrets and reveal was not passed", body = String),
+        (status = 404, description = "File not managed by sysrat", body = String),
+        (status = 416, description = "Range starts past the end of the file", body = String),
+    )
+)]
+pub async fn read_config_raw(
+    State(config): State<SharedConfig>,
+    Path(filename): Path<String>,
+    Query(query): Query<ReadQuery>,
+    headers: HeaderMap,
+) -> Result<Response, (StatusCode, String)> {
+    let range = headers
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_range);
+    let (offset, max_len) = match range {
+        Some((start, end)) => {
+            let len = end.map_or(MAX_RANGE_BYTES, |end| end.saturating_sub(start) + 1);
+            (start, Some(len.min(MAX_RANGE_BYTES)))
+        }
+        None => (0, None),
+    };
+
+    let (data, size) = sysrat_core::configs::actions::read_file_range(
+        &filename,
+        &config,
+        offset,
+        max_len,
+        query.reveal,
+    )
+    .await
+    .map_err(|e| {
+        let status: StatusCode = match e.kind() {
+            std::io::ErrorKind::NotFound => StatusCode::NOT_FOUND,
+            std::io::ErrorKind::PermissionDenied => StatusCode::FORBIDDEN,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (status, format!("Read error: {}", e))
+    })?;
+
+    if range.is_some() {
+        if offset >= size && size > 0 {
+            return Ok((
+                StatusCode::RANGE_NOT_SATISFIABLE,
+                [(header::CONTENT_RANGE, format!("bytes */{}", size))],
+                "Range starts past the end of the file".to_string(),
+            )
+                .into_response());
+        }
+        let last = (offset + data.len() as u64).saturating_sub(1);
+        return Ok((
+            StatusCode::PARTIAL_CONTENT,
+            [
+                (header::CONTENT_TYPE, "application/octet-stream".to_string()),
+                (
+                    header::CONTENT_RANGE,
+                    format!("bytes {}-{}/{}", offset, last, size),
+                ),
+            ],
+            data,
+        )
+            .into_response());
+    }
+
+    // Save under the bare file name, quotes would end the header value
+    let basename = filename.rsplit('/').next().unwrap_or(&filename);
+    let disposition = format!("attachment; filename=\"{}\"", basename.replace('"', "_"));
+    let content_type = if std::str::from_utf8(&data).is_ok() {
+        "text/plain; charset=utf-8"
+    } else {
+        "application/octet-stream"
+    };
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+            (header::ACCEPT_RANGES, "bytes".to_string()),
+        ],
+        data,
+    )
+        .into_response())
+}
+
+/// `bytes=a-b` or `bytes=a-` as (a, Some(b)) / (a, None); other forms
+/// (suffix and multiple ranges) are ignored and get the whole file
+fn parse_range(value: &str) -> Option<(u64, Option<u64>)> {
+    let (start, end) = value.strip_prefix("bytes=")?.split_once('-')?;
+    let start = start.trim().parse().ok()?;
+    let end = match end.trim() {
+        "" => None,
+        end => Some(end.parse().ok()?),
+    };
+    match end {
+        Some(end) if end < start => None,
+        _ => Some((start, end)),
+    }
+}
+
+/// GET /api/configs/*filename/log - Git history of a config file
+///
+/// Secrets in the diffs are masked unless `reveal=true` is passed, which is
+/// audit-logged.
+#[utoipa::path(
+    get,
+    path = "/api/configs/{filename}/log",
+    tag = "configs",
+    params(
+        ("filename" = String, Path, description = "File name as listed by GET /api/configs"),
+        ("reveal" = Option<bool>, Query, description = "Skip masking secrets, audit-logged"),
+    ),
+    responses(
+        (status = 200, description = "Commits touching the file, newest first", body = FileLogResponse),
+        (status = 404, description = "File not managed by sysrat", body = String),
+        (status = 501, description = "Git versioning is disabled", body = String),
+    )
+)]
+pub async fn read_config_log(
+    State(config): State<SharedConfig>,
+    Path(filename): Path<String>,
+    Query(query): Query<ReadQuery>,
+) -> Result<Json<FileLogResponse>, (StatusCode, String)> {
+    match sysrat_core::configs::actions::file_log(&filename, query.reveal, &config).await {
+        Ok(commits) => Ok(Json(FileLogResponse { commits })),
         Err(e) => {
             let status: StatusCode = match e.kind() {
                 std::io::ErrorKind::NotFound => StatusCode::NOT_FOUND,
+                std::io::ErrorKind::Unsupported => StatusCode::NOT_IMPLEMENTED,
                 _ => StatusCode::INTERNAL_SERVER_ERROR,
             };
-            Err((status, format!("Read error: {}", e)))
+            Err((status, format!("Log error: {}", e)))
         }
     }
 }
 
 /// POST /api/configs/*filename - Write a config file
+///
+/// Also serves the multipart `/api/configs/*filename/upload` and
+/// `/api/configs/*filename/upstream`.
+#[utoipa::path(
+    post,
+    path = "/api/configs/{filename}",
+    tag = "configs",
+    params(
+        ("filename" = String, Path, description = "File name as listed by GET /api/configs"),
+        ("x-sysrat-override" = Option<String>, Header, description = "Unlocks a readonly file when allow_readonly_override is set"),
+    ),
+    request_body = WriteConfigRequest,
+    responses(
+        (status = 200, description = "File saved", body = WriteConfigResponse),
+        (status = 403, description = "File is read-only, override rejected, or the server may not write it (JSON body)", body = WritePermissionError),
+        (status = 404, description = "File not managed by sysrat", body = String),
+        (status = 422, description = "Content is not text, does not fit the file's encoding or still holds masked secrets", body = ValidationErrorResponse),
+    )
+)]
 pub async fn write_config(
     State(config): State<SharedConfig>,
     Path(filename): Path<String>,
-    Json(payload): Json<WriteConfigRequest>,
-) -> Result<Json<WriteConfigResponse>, (StatusCode, String)> {
+    request: Request,
+) -> Result<Response, Response> {
     // Wildcard routes include leading slash, strip it
     let filename = filename.strip_prefix('/').unwrap_or(&filename);
+    let headers = request.headers().clone();
+
+    if let Some(name) = filename.strip_suffix("/upload") {
+        let multipart = Multipart::from_request(request, &())
+            .await
+            .map_err(IntoResponse::into_response)?;
+        return write_config_upload(State(config), Path(name.to_string()), headers, multipart)
+            .await
+            .map(IntoResponse::into_response);
+    }
+    if let Some(name) = filename.strip_suffix("/lint") {
+        let Valid(payload) = Valid::<WriteConfigRequest>::from_request(request, &()).await?;
+        return lint_config(State(config), Path(name.to_string()), Json(payload))
+            .await
+            .map(IntoResponse::into_response)
+            .map_err(IntoResponse::into_response);
+    }
+    if let Some(name) = filename.strip_suffix("/hygiene") {
+        let Valid(payload) = Valid::<WriteConfigRequest>::from_request(request, &()).await?;
+        return hygiene_config(State(config), Path(name.to_string()), Json(payload))
+            .await
+            .map(IntoResponse::into_response)
+            .map_err(IntoResponse::into_response);
+    }
+    if let Some(name) = filename.strip_suffix("/upstream") {
+        let Valid(payload) = Valid::<UpstreamApplyRequest>::from_request(request, &()).await?;
+        return apply_upstream(State(config), Path(name.to_string()), Json(payload))
+            .await
+            .map(IntoResponse::into_response)
+            .map_err(IntoResponse::into_response);
+    }
+
+    let Valid(payload) = Valid::<WriteConfigRequest>::from_request(request, &()).await?;
+    save(
+        &config,
+        filename,
+        &payload.content,
+        payload.format,
+        &headers,
+    )
+    .await
+    .map(IntoResponse::into_response)
+}
+
+/// POST /api/configs/*filename/upload - Replace a config file from a form upload
+#[utoipa::path(
+    post,
+    path = "/api/configs/{filename}/upload",
+    tag = "configs",
+    params(
+        ("filename" = String, Path, description = "File name as listed by GET /api/configs"),
+        ("x-sysrat-override" = Option<String>, Header, description = "Unlocks a readonly file when allow_readonly_override is set"),
+    ),
+    request_body(content = String, content_type = "multipart/form-data", description = "Form with a `file` field holding the new content"),
+    responses(
+        (status = 200, description = "File saved", body = WriteConfigResponse),
+        (status = 400, description = "Missing `file` field or content is not text", body = String),
+        (status = 403, description = "File is read-only, override rejected, or the server may not write it (JSON body)", body = WritePermissionError),
+        (status = 404, description = "File not managed by sysrat", body = String),
+    )
+)]
+pub async fn write_config_upload(
+    State(config): State<SharedConfig>,
+    Path(filename): Path<String>,
+    headers: HeaderMap,
+    mut multipart: Multipart,
+) -> Result<Json<WriteConfigResponse>, Response> {
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(IntoResponse::into_response)?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let bytes = field.bytes().await.map_err(IntoResponse::into_response)?;
+        // Stored with the uploaded file's encoding and line endings
+        let (content, format) = encoding::decode(&bytes).ok_or_else(|| {
+            (StatusCode::BAD_REQUEST, "Upload error: content is not text").into_response()
+        })?;
+        return save(&config, &filename, &content, Some(format), &headers).await;
+    }
+
+    Err((
+        StatusCode::BAD_REQUEST,
+        "Upload error: missing `file` field",
+    )
+        .into_response())
+}
+
+/// POST /api/configs/*filename/lint - Check content against the rule packs
+///
+/// Runs the packs enabled for the file's category in `[lint]` and the
+/// `[[lint.commands]]` matching the file on a temporary copy; the file
+/// itself is not written, so unsaved editor content can be checked.
+#[utoipa::path(
+    post,
+    path = "/api/configs/{filename}/lint",
+    tag = "configs",
+    params(("filename" = String, Path, description = "File name as listed by GET /api/configs")),
+    request_body = WriteConfigRequest,
+    responses(
+        (status = 200, description = "Warnings of packs and checkers, empty when none", body = LintResponse),
+        (status = 404, description = "File not managed by sysrat", body = String),
+    )
+)]
+pub async fn lint_config(
+    State(config): State<SharedConfig>,
+    Path(filename): Path<String>,
+    Json(payload): Json<WriteConfigRequest>,
+) -> Result<Json<LintResponse>, (StatusCode, String)> {
+    sysrat_core::configs::lint::lint_file(&filename, &payload.content, &config)
+        .await
+        .map(|warnings| Json(LintResponse { warnings }))
+        .map_err(|e| {
+            let status = match e.kind() {
+                std::io::ErrorKind::NotFound => StatusCode::NOT_FOUND,
+                std::io::ErrorKind::PermissionDenied => StatusCode::FORBIDDEN,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status, format!("Lint error: {}", e))
+        })
+}
+
+/// POST /api/configs/*filename/hygiene - Whitespace fixes for content about to be saved
+///
+/// Applies the `[hygiene]` rules of the file's category and diffs the result
+/// against the content sent; nothing is written, the editor saves the fixed
+/// content once the user accepts it.
+#[utoipa::path(
+    post,
+    path = "/api/configs/{filename}/hygiene",
+    tag = "configs",
+    params(("filename" = String, Path, description = "File name as listed by GET /api/configs")),
+    request_body = WriteConfigRequest,
+    responses(
+        (status = 200, description = "Fixed content, the fixes and their diff", body = HygieneResponse),
+        (status = 404, description = "File not managed by sysrat", body = String),
+    )
+)]
+pub async fn hygiene_config(
+    State(config): State<SharedConfig>,
+    Path(filename): Path<String>,
+    Json(payload): Json<WriteConfigRequest>,
+) -> Result<Json<HygieneResponse>, (StatusCode, String)> {
+    hygiene::check_file(&filename, &payload.content, &config)
+        .await
+        .map(Json)
+        .map_err(|e| {
+            let status = match e.kind() {
+                std::io::ErrorKind::NotFound => StatusCode::NOT_FOUND,
+                std::io::ErrorKind::PermissionDenied => StatusCode::FORBIDDEN,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status, format!("Hygiene error: {}", e))
+        })
+}
+
+/// GET /api/configs/*filename/upstream - Compare a file with its upstream source
+///
+/// Fetches `upstream_url`, checks the pinned checksum and detached signature
+/// when configured, and diffs the result against the local file. Nothing is
+/// written. Secrets in the diff are masked unless `reveal=true` is passed,
+/// which is audit-logged.
+#[utoipa::path(
+    get,
+    path = "/api/configs/{filename}/upstream",
+    tag = "configs",
+    params(
+        ("filename" = String, Path, description = "File name as listed by GET /api/configs"),
+        ("reveal" = Option<bool>, Query, description = "Skip masking secrets, audit-logged"),
+    ),
+    responses(
+        (status = 200, description = "Verification results and diff", body = UpstreamCheckResponse),
+        (status = 404, description = "File not managed by sysrat", body = String),
+        (status = 422, description = "Upstream content is not UTF-8 text", body = String),
+        (status = 501, description = "No upstream_url configured for the file", body = String),
+        (status = 502, description = "Fetching upstream failed", body = String),
+    )
+)]
+pub async fn check_upstream(
+    State(config): State<SharedConfig>,
+    Path(filename): Path<String>,
+    Query(query): Query<ReadQuery>,
+) -> Result<Json<UpstreamCheckResponse>, (StatusCode, String)> {
+    sysrat_core::configs::upstream::check(&filename, query.reveal, &config)
+        .await
+        .map(Json)
+        .map_err(|e| (upstream_status(&e), format!("Upstream error: {}", e)))
+}
+
+/// GET /api/configs/*filename/rendered - Preview a file with its variables
+///
+/// Substitutes `${NAME}` and `${NAME:-default}` in the saved content from
+/// the file's vars file and, if `[render] env` is set, the server's
+/// environment. Nothing is written.
+#[utoipa::path(
+    get,
+    path = "/api/configs/{filename}/rendered",
+    tag = "configs",
+    params(("filename" = String, Path, description = "File name as listed by GET /api/configs")),
+    responses(
+        (status = 200, description = "Rendered content", body = RenderedResponse),
+        (status = 404, description = "File or vars file not found", body = String),
+        (status = 415, description = "Binary file", body = String),
+    )
+)]
+pub async fn render_config(
+    State(config): State<SharedConfig>,
+    Path(filename): Path<String>,
+) -> Result<Json<RenderedResponse>, (StatusCode, String)> {
+    sysrat_core::configs::render::render_file(&filename, &config)
+        .await
+        .map(Json)
+        .map_err(|e| {
+            let status = match e.kind() {
+                std::io::ErrorKind::NotFound => StatusCode::NOT_FOUND,
+                std::io::ErrorKind::InvalidData => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status, format!("Render error: {}", e))
+        })
+}
+
+/// POST /api/configs/*filename/upstream - Apply the reviewed upstream version
+#[utoipa::path(
+    post,
+    path = "/api/configs/{filename}/upstream",
+    tag = "configs",
+    params(("filename" = String, Path, description = "File name as listed by GET /api/configs")),
+    request_body = UpstreamApplyRequest,
+    responses(
+        (status = 200, description = "Upstream version written", body = WriteConfigResponse),
+        (status = 403, description = "File is read-only", body = String),
+        (status = 404, description = "File not managed by sysrat", body = String),
+        (status = 422, description = "Verification failed or upstream changed since the check", body = String),
+        (status = 501, description = "No upstream_url configured for the file", body = String),
+        (status = 502, description = "Fetching upstream failed", body = String),
+    )
+)]
+pub async fn apply_upstream(
+    State(config): State<SharedConfig>,
+    Path(filename): Path<String>,
+    Json(payload): Json<UpstreamApplyRequest>,
+) -> Result<Json<WriteConfigResponse>, (StatusCode, String)> {
+    sysrat_core::configs::upstream::apply(&filename, &payload.sha256, &config)
+        .await
+        .map(|_| {
+            Json(WriteConfigResponse {
+                success: true,
+                mode: None,
+            })
+        })
+        .map_err(|e| (upstream_status(&e), format!("Upstream error: {}", e)))
+}
+
+fn upstream_status(e: &std::io::Error) -> StatusCode {
+    match e.kind() {
+        std::io::ErrorKind::NotFound => StatusCode::NOT_FOUND,
+        std::io::ErrorKind::PermissionDenied => StatusCode::FORBIDDEN,
+        std::io::ErrorKind::Unsupported => StatusCode::NOT_IMPLEMENTED,
+        std::io::ErrorKind::InvalidData => StatusCode::UNPROCESSABLE_ENTITY,
+        _ => StatusCode::BAD_GATEWAY,
+    }
+}
+
+async fn save(
+    config: &SharedConfig,
+    filename: &str,
+    content: &str,
+    format: Option<TextFormat>,
+    headers: &HeaderMap,
+) -> Result<Json<WriteConfigResponse>, Response> {
+    let override_value = headers.get(OVERRIDE_HEADER).and_then(|v| v.to_str().ok());
 
-    match sysrat_core::configs::actions::write_file(filename, &payload.content, &config).await {
-        Ok(_) => Ok(Json(WriteConfigResponse { success: true })),
+    match sysrat_core::configs::actions::write_file(
+        filename,
+        content,
+        format,
+        config,
+        override_value,
+    )
+    .await
+    {
+        Ok(mode) => Ok(Json(WriteConfigResponse {
+            success: true,
+            mode,
+        })),
         Err(e) => {
+            // The OS refused the write, say why so the client can show a fix
+            if let Some(denied) = permissions::write_denied(&e) {
+                return Err((StatusCode::FORBIDDEN, Json(denied.clone())).into_response());
+            }
             let status: StatusCode = match e.kind() {
                 std::io::ErrorKind::NotFound => StatusCode::NOT_FOUND,
                 std::io::ErrorKind::PermissionDenied => StatusCode::FORBIDDEN,
+                // Characters the file's encoding cannot store
+                std::io::ErrorKind::InvalidData => StatusCode::UNPROCESSABLE_ENTITY,
                 _ => StatusCode::INTERNAL_SERVER_ERROR,
             };
-            Err((status, format!("Write error: {}", e)))
+            Err((status, format!("Write error: {}", e)).into_response())
         }
     }
 }
@@ -1,3 +1,4 @@
-mod handlers;
+// Visible to the OpenAPI doc, which references the annotated handlers
+pub(super) mod handlers;
 
 pub use handlers::{list_configs, read_config, write_config};
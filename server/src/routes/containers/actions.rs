@@ -1,13 +1,29 @@
 use super::super::types::ContainerActionResponse;
 use axum::{Json, http::StatusCode};
+use sysrat_core::config::SharedConfig;
+use sysrat_core::events;
 
 /// Execute a docker action (start/stop/restart) on a container
 /// Timeout: 120 seconds for long-running operations
 pub(super) async fn execute_container_action(
+    config: &SharedConfig,
     container_id: &str,
     action: &str,
 ) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
-    match sysrat_core::containers::actions::execute_container_action(container_id, action).await {
+    let result =
+        sysrat_core::containers::actions::execute_container_action(container_id, action).await;
+
+    let settings = config.read().await.events().clone();
+    let error = result.as_ref().err().map(|e| e.to_string());
+    events::publish(
+        &settings,
+        &format!("container.{}", action),
+        container_id,
+        error,
+    )
+    .await;
+
+    match result {
         Ok(_) => {
             let past_tense = match action {
                 "start" => "started",
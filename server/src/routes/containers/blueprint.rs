@@ -0,0 +1,89 @@
+use super::super::types::{BlueprintContainer, EnvSource};
+use super::{graph, parser};
+use axum::http::StatusCode;
+use serde_json::Value;
+use std::collections::HashMap;
+use tokio::process::Command;
+
+/// All containers as blueprint entries, from one `docker inspect` for the
+/// containers and one `docker image inspect` for their images
+pub(crate) async fn blueprint_containers() -> Result<Vec<BlueprintContainer>, (StatusCode, String)>
+{
+    let ids = graph::list_container_ids().await?;
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let inspected = graph::inspect_all(&ids).await?;
+
+    let mut image_ids: Vec<&str> = inspected
+        .iter()
+        .filter_map(|c| c.get("Image")?.as_str())
+        .collect();
+    image_ids.sort_unstable();
+    image_ids.dedup();
+    let images = inspect_images(&image_ids).await;
+
+    let mut containers = Vec::new();
+    for container in &inspected {
+        let Ok(details) = parser::build_details(container) else {
+            continue;
+        };
+
+        // Pass only what the image does not set already; without the image
+        // there is nothing to compare with, so pass everything
+        let image = container
+            .get("Image")
+            .and_then(|i| i.as_str())
+            .and_then(|id| images.get(id));
+        let inherited: Vec<String> = image
+            .map(|image| parser::env_sources(&details.environment, image))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|var| var.source == EnvSource::Image)
+            .map(|var| var.key)
+            .collect();
+        let env: Vec<&str> = details
+            .environment
+            .iter()
+            .map(String::as_str)
+            .filter(|env| {
+                let key = env.split_once('=').map_or(*env, |(key, _)| key);
+                !inherited.iter().any(|k| k == key)
+            })
+            .collect();
+
+        let compose = parser::extract_compose(container);
+        containers.push(BlueprintContainer {
+            run: parser::run_command(container, &env),
+            name: details.name,
+            image: details.image,
+            compose_project: compose.project,
+            compose_service: compose.service,
+            compose_files: compose.config_files,
+        });
+    }
+
+    containers.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(containers)
+}
+
+/// Image inspect output by image id. Images that are gone are left out, docker
+/// still prints the others before failing.
+async fn inspect_images(ids: &[&str]) -> HashMap<String, Value> {
+    if ids.is_empty() {
+        return HashMap::new();
+    }
+    let output = Command::new("docker")
+        .args(["image", "inspect"])
+        .args(ids)
+        .output()
+        .await;
+
+    output
+        .ok()
+        .and_then(|output| serde_json::from_slice::<Vec<Value>>(&output.stdout).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|image| Some((image.get("Id")?.as_str()?.to_string(), image)))
+        .collect()
+}
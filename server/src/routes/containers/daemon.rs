@@ -0,0 +1,108 @@
+//! Docker daemon availability. A daemon that is gone (restarting, being
+//! upgraded) is told apart from commands that fail; while it is gone one
+//! background task polls it and the container list reports the outage
+//! instead of running docker on every request.
+
+use super::super::types::DaemonOutage;
+use k_lib::config::Cookbook;
+use k_lib::logger;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::process::Command;
+
+const SCOPE: &str = "DOCKER";
+const APP_NAME: &str = "sysrat";
+
+/// Time between reconnection attempts while the daemon is gone
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A reconnection attempt that takes longer than this counts as failed
+const POLL_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// What docker prints when it cannot reach the daemon, lowercased
+const UNREACHABLE_MARKERS: &[&str] = &[
+    "cannot connect to the docker daemon",
+    "is the docker daemon running",
+    "error during connect",
+    "docker.sock: connect",
+];
+
+/// Current outage, None while the daemon answers
+static OUTAGE: Mutex<Option<DaemonOutage>> = Mutex::new(None);
+
+fn log(level: &str, msg: &str) {
+    if let Ok(cookbook) = Cookbook::load() {
+        logger::log_to_terminal(&cookbook, level, SCOPE, msg);
+        let _ = logger::log_to_file(&cookbook, level, SCOPE, msg, Some(APP_NAME));
+    }
+}
+
+/// Whether docker's stderr says the daemon is unreachable rather than that
+/// the command itself failed
+pub(crate) fn is_unreachable(stderr: &str) -> bool {
+    let stderr = stderr.to_lowercase();
+    UNREACHABLE_MARKERS
+        .iter()
+        .any(|marker| stderr.contains(marker))
+}
+
+/// The outage in progress, if any
+pub(crate) fn outage() -> Option<DaemonOutage> {
+    OUTAGE.lock().ok()?.clone()
+}
+
+/// Record that the daemon did not answer; the first report of an outage
+/// starts polling for its return
+pub(crate) fn report_unreachable(stderr: &str) {
+    let Ok(mut outage) = OUTAGE.lock() else {
+        return;
+    };
+    if outage.is_some() {
+        return;
+    }
+    let since = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    *outage = Some(DaemonOutage {
+        since,
+        detail: stderr.trim().to_string(),
+        retry_secs: POLL_INTERVAL.as_secs(),
+    });
+    drop(outage);
+
+    log(
+        "warn",
+        &format!(
+            "Docker daemon unreachable, retrying every {}s",
+            POLL_INTERVAL.as_secs()
+        ),
+    );
+    tokio::spawn(poll_until_back());
+}
+
+/// Record that the daemon answered, ending an outage
+pub(crate) fn report_reachable() {
+    let ended = OUTAGE
+        .lock()
+        .map(|mut outage| outage.take().is_some())
+        .unwrap_or(false);
+    if ended {
+        log("success", "Docker daemon is back");
+    }
+}
+
+async fn poll_until_back() {
+    while outage().is_some() {
+        tokio::time::sleep(POLL_INTERVAL).await;
+        let check = Command::new("docker")
+            .args(["version", "--format", "{{.Server.Version}}"])
+            .kill_on_drop(true)
+            .output();
+        if let Ok(Ok(output)) = tokio::time::timeout(POLL_TIMEOUT, check).await
+            && output.status.success()
+        {
+            report_reachable();
+        }
+    }
+}
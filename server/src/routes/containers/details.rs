@@ -1,16 +1,44 @@
 use super::super::types::ContainerDetailsResponse;
-use super::parser;
-use axum::{Json, extract::Path, http::StatusCode};
+use super::{links, parser};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+};
 use serde_json::Value;
+use sysrat_core::config::SharedConfig;
 use tokio::process::Command;
 
 /// GET /api/containers/:id/details - Get detailed information about a container
+#[utoipa::path(
+    get,
+    path = "/api/containers/{id}/details",
+    tag = "containers",
+    params(("id" = String, Path, description = "Container ID or name")),
+    responses(
+        (status = 200, description = "Parsed docker inspect data", body = ContainerDetailsResponse),
+        (status = 404, description = "Container not found", body = String),
+    )
+)]
 pub async fn get_container_details(
+    State(config): State<SharedConfig>,
     Path(id): Path<String>,
+    headers: HeaderMap,
 ) -> Result<Json<ContainerDetailsResponse>, (StatusCode, String)> {
     let inspect_output = fetch_container_inspect(&id).await?;
     let container = parse_inspect_json(&inspect_output)?;
-    let details = parser::build_details(&container)?;
+    let mut details = parser::build_details(&container)?;
+
+    // The container's `Image` is the image id, inspecting it by id cannot
+    // pick up a retagged image. Without it the variables keep no source.
+    if let Some(image_id) = container.get("Image").and_then(|i| i.as_str())
+        && let Some(image) = fetch_image_inspect(image_id).await
+    {
+        details.env_sources = parser::env_sources(&details.environment, &image);
+    }
+
+    let reader = config.read().await;
+    links::apply(&mut details.ports, reader.links(), &headers);
 
     Ok(Json(ContainerDetailsResponse { details }))
 }
@@ -38,6 +66,17 @@ async fn fetch_container_inspect(id: &str) -> Result<String, (StatusCode, String
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
 }
 
+/// `docker image inspect` of an image, None if it is gone or unreadable
+async fn fetch_image_inspect(image_id: &str) -> Option<Value> {
+    let output = Command::new("docker")
+        .args(["image", "inspect", image_id])
+        .output()
+        .await
+        .ok()
+        .filter(|output| output.status.success())?;
+    parse_inspect_json(&String::from_utf8_lossy(&output.stdout)).ok()
+}
+
 fn parse_inspect_json(json_str: &str) -> Result<Value, (StatusCode, String)> {
     let json: Vec<Value> = serde_json::from_str(json_str).map_err(|e| {
         (
@@ -0,0 +1,130 @@
+use super::super::types::{ContainerGraphResponse, GraphGroup, GraphNode};
+use super::parser::{self, ComposeInfo};
+use axum::{Json, http::StatusCode};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use tokio::process::Command;
+
+/// GET /api/containers/graph - Containers grouped by network with dependency links
+#[utoipa::path(
+    get,
+    path = "/api/containers/graph",
+    tag = "containers",
+    responses(
+        (status = 200, description = "Networks, shared volumes and compose dependencies", body = ContainerGraphResponse),
+        (status = 500, description = "Docker unavailable", body = String),
+    )
+)]
+pub async fn get_container_graph() -> Result<Json<ContainerGraphResponse>, (StatusCode, String)> {
+    let ids = list_container_ids().await?;
+    if ids.is_empty() {
+        return Ok(Json(ContainerGraphResponse {
+            nodes: Vec::new(),
+            networks: Vec::new(),
+            shared_volumes: Vec::new(),
+        }));
+    }
+
+    let inspected = inspect_all(&ids).await?;
+    let parsed: Vec<(GraphNode, ComposeInfo)> =
+        inspected.iter().map(parser::build_graph_node).collect();
+
+    Ok(Json(build_graph(parsed)))
+}
+
+pub(super) async fn list_container_ids() -> Result<Vec<String>, (StatusCode, String)> {
+    let output = run_docker(&["ps", "-aq"]).await?;
+    Ok(output.lines().map(|l| l.trim().to_string()).collect())
+}
+
+/// One `docker inspect` for all containers instead of one per container
+pub(super) async fn inspect_all(ids: &[String]) -> Result<Vec<Value>, (StatusCode, String)> {
+    let mut args = vec!["inspect"];
+    args.extend(ids.iter().map(String::as_str));
+    let output = run_docker(&args).await?;
+
+    serde_json::from_str(&output).map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to parse docker inspect output: {}", e),
+        )
+    })
+}
+
+async fn run_docker(args: &[&str]) -> Result<String, (StatusCode, String)> {
+    let output = Command::new("docker")
+        .args(args)
+        .output()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to execute docker command: {}", e),
+            )
+        })?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Docker command failed: {}", error),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn build_graph(parsed: Vec<(GraphNode, ComposeInfo)>) -> ContainerGraphResponse {
+    // Compose dependencies name services, resolve them within the same project
+    let service_ids: HashMap<(String, String), String> = parsed
+        .iter()
+        .filter_map(|(node, compose)| {
+            Some((
+                (compose.project.clone()?, compose.service.clone()?),
+                node.id.clone(),
+            ))
+        })
+        .collect();
+
+    let mut nodes = Vec::with_capacity(parsed.len());
+    let mut networks: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut volumes: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for (mut node, compose) in parsed {
+        if let Some(project) = &compose.project {
+            node.depends_on = compose
+                .depends_on
+                .iter()
+                .filter_map(|svc| service_ids.get(&(project.clone(), svc.clone())).cloned())
+                .collect();
+        }
+        for net in &node.networks {
+            networks
+                .entry(net.clone())
+                .or_default()
+                .push(node.id.clone());
+        }
+        for vol in &node.volumes {
+            volumes
+                .entry(vol.clone())
+                .or_default()
+                .push(node.id.clone());
+        }
+        nodes.push(node);
+    }
+
+    nodes.sort_by(|a, b| a.name.cmp(&b.name));
+
+    ContainerGraphResponse {
+        nodes,
+        networks: into_groups(networks, 1),
+        shared_volumes: into_groups(volumes, 2),
+    }
+}
+
+fn into_groups(map: BTreeMap<String, Vec<String>>, min_members: usize) -> Vec<GraphGroup> {
+    map.into_iter()
+        .filter(|(_, members)| members.len() >= min_members)
+        .map(|(name, members)| GraphGroup { name, members })
+        .collect()
+}
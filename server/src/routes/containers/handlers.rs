@@ -1,8 +1,17 @@
-use super::super::types::{ContainerActionResponse, ContainerInfo, ContainerListResponse};
+use super::super::types::{
+    ContainerActionResponse, ContainerInfo, ContainerListResponse, DaemonOutage,
+};
 use super::actions::execute_container_action;
-use axum::{Json, extract::Path, http::StatusCode};
+use super::daemon;
+use super::{service_url, status};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
 use k_lib::config::Cookbook;
 use k_lib::logger;
+use sysrat_core::config::{ProtectionSettings, SharedConfig};
 use tokio::process::Command;
 
 const SCOPE: &str = "DOCKER";
@@ -14,19 +23,75 @@ fn log(cookbook: &Cookbook, level: &str, msg: &str) {
 }
 
 /// GET /api/containers - List all Docker containers
-pub async fn list_containers() -> Result<Json<ContainerListResponse>, (StatusCode, String)> {
+#[utoipa::path(
+    get,
+    path = "/api/containers",
+    tag = "containers",
+    responses(
+        (status = 200, description = "All containers (running and stopped), or the daemon outage", body = ContainerListResponse),
+        (status = 500, description = "Docker unavailable", body = String),
+    )
+)]
+pub async fn list_containers(
+    State(config): State<SharedConfig>,
+) -> Result<Json<ContainerListResponse>, (StatusCode, String)> {
     let cookbook = Cookbook::load().ok();
 
     if let Some(ref cb) = cookbook {
         log(cb, "info", "GET /api/containers - listing");
     }
 
+    // The reconnection poller finds out when the daemon is back
+    if let Some(outage) = daemon::outage() {
+        return Ok(outage_response(outage));
+    }
+
+    let protection = config.read().await.protection().clone();
+    let containers = match fetch_containers(&protection).await {
+        Ok(containers) => containers,
+        Err(e) => {
+            return match daemon::outage() {
+                Some(outage) => Ok(outage_response(outage)),
+                None => Err((StatusCode::INTERNAL_SERVER_ERROR, e)),
+            };
+        }
+    };
+
+    if let Some(ref cb) = cookbook {
+        log(
+            cb,
+            "success",
+            &format!("Found {} containers", containers.len()),
+        );
+    }
+
+    Ok(Json(ContainerListResponse {
+        containers,
+        outage: None,
+    }))
+}
+
+/// No containers while the daemon is gone, the frontend shows the outage
+fn outage_response(outage: DaemonOutage) -> Json<ContainerListResponse> {
+    Json(ContainerListResponse {
+        containers: Vec::new(),
+        outage: Some(outage),
+    })
+}
+
+/// Run `docker ps -a` and parse one ContainerInfo per line, flagging the
+/// containers `protection` covers
+pub(crate) async fn fetch_containers(
+    protection: &ProtectionSettings,
+) -> Result<Vec<ContainerInfo>, String> {
+    let cookbook = Cookbook::load().ok();
+
     let output = Command::new("docker")
         .args([
             "ps",
             "-a",
             "--format",
-            "{{.ID}}\t{{.Names}}\t{{.State}}\t{{.Status}}",
+            "{{.ID}}\t{{.Names}}\t{{.State}}\t{{.Status}}\t{{.RunningFor}}\t{{.Labels}}",
         ])
         .output()
         .await
@@ -34,22 +99,22 @@ pub async fn list_containers() -> Result<Json<ContainerListResponse>, (StatusCod
             if let Some(ref cb) = cookbook {
                 log(cb, "error", &format!("docker ps failed: {}", e));
             }
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to execute docker command: {}", e),
-            )
+            format!("Failed to execute docker command: {}", e)
         })?;
 
     if !output.status.success() {
         let error = String::from_utf8_lossy(&output.stderr);
+        // A lost daemon is logged once by the outage, not on every request
+        if daemon::is_unreachable(&error) {
+            daemon::report_unreachable(&error);
+            return Err(format!("Docker daemon unreachable: {}", error.trim()));
+        }
         if let Some(ref cb) = cookbook {
             log(cb, "error", &format!("docker ps failed: {}", error));
         }
-        return Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Docker command failed: {}", error),
-        ));
+        return Err(format!("Docker command failed: {}", error));
     }
+    daemon::report_reachable();
 
     let stdout = String::from_utf8_lossy(&output.stdout);
     let mut containers = Vec::new();
@@ -57,43 +122,83 @@ pub async fn list_containers() -> Result<Json<ContainerListResponse>, (StatusCod
     for line in stdout.lines() {
         let parts: Vec<&str> = line.split('\t').collect();
         if parts.len() >= 4 {
+            let labels = parts
+                .get(5)
+                .map(|raw| service_url::parse_label_list(raw))
+                .unwrap_or_default();
+            let parsed = status::parse(parts[3]);
             containers.push(ContainerInfo {
                 id: parts[0].to_string(),
                 name: parts[1].to_string(),
                 state: parts[2].to_string(),
                 status: parts[3].to_string(),
+                created_at: parts.get(4).and_then(|ago| status::parse_ago(ago)),
+                started_at: parsed.started_at,
+                exited_at: parsed.exited_at,
+                exit_code: parsed.exit_code,
+                health: parsed.health,
+                service_url: service_url::from_labels(&labels),
+                protected: protection.protects(parts[1], &labels),
             });
         }
     }
 
-    if let Some(ref cb) = cookbook {
-        log(
-            cb,
-            "success",
-            &format!("Found {} containers", containers.len()),
-        );
-    }
-
-    Ok(Json(ContainerListResponse { containers }))
+    Ok(containers)
 }
 
 /// POST /api/containers/:id/start - Start a container
+#[utoipa::path(
+    post,
+    path = "/api/containers/{id}/start",
+    tag = "containers",
+    params(("id" = String, Path, description = "Container ID or name")),
+    responses(
+        (status = 200, description = "Action completed", body = ContainerActionResponse),
+        (status = 408, description = "Docker timed out", body = String),
+        (status = 500, description = "Docker command failed", body = String),
+    )
+)]
 pub async fn start_container(
+    State(config): State<SharedConfig>,
     Path(id): Path<String>,
 ) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
-    execute_container_action(&id, "start").await
+    execute_container_action(&config, &id, "start").await
 }
 
 /// POST /api/containers/:id/stop - Stop a container
+#[utoipa::path(
+    post,
+    path = "/api/containers/{id}/stop",
+    tag = "containers",
+    params(("id" = String, Path, description = "Container ID or name")),
+    responses(
+        (status = 200, description = "Action completed", body = ContainerActionResponse),
+        (status = 408, description = "Docker timed out", body = String),
+        (status = 500, description = "Docker command failed", body = String),
+    )
+)]
 pub async fn stop_container(
+    State(config): State<SharedConfig>,
     Path(id): Path<String>,
 ) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
-    execute_container_action(&id, "stop").await
+    execute_container_action(&config, &id, "stop").await
 }
 
 /// POST /api/containers/:id/restart - Restart a container
+#[utoipa::path(
+    post,
+    path = "/api/containers/{id}/restart",
+    tag = "containers",
+    params(("id" = String, Path, description = "Container ID or name")),
+    responses(
+        (status = 200, description = "Action completed", body = ContainerActionResponse),
+        (status = 408, description = "Docker timed out", body = String),
+        (status = 500, description = "Docker command failed", body = String),
+    )
+)]
 pub async fn restart_container(
+    State(config): State<SharedConfig>,
     Path(id): Path<String>,
 ) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
-    execute_container_action(&id, "restart").await
+    execute_container_action(&config, &id, "restart").await
 }
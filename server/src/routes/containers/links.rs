@@ -0,0 +1,46 @@
+use crate::routes::types::PortMapping;
+use axum::http::{HeaderMap, header};
+use sysrat_core::config::LinkSettings;
+
+/// Fill in quick-open URLs for published TCP ports
+pub(super) fn apply(ports: &mut [PortMapping], settings: &LinkSettings, headers: &HeaderMap) {
+    let Some(host) = link_host(settings, headers) else {
+        return;
+    };
+
+    for port in ports.iter_mut() {
+        if port.protocol != "tcp" || port.host_port.is_empty() {
+            continue;
+        }
+        let scheme = if settings.https_ports.contains(&port.host_port) {
+            "https"
+        } else {
+            settings.scheme.as_str()
+        };
+        port.url = Some(format!("{}://{}:{}", scheme, host, port.host_port));
+    }
+}
+
+/// Configured host, or the host the browser used to reach sysrat
+fn link_host(settings: &LinkSettings, headers: &HeaderMap) -> Option<String> {
+    let configured = settings.host.trim();
+    if !configured.is_empty() {
+        return Some(configured.to_string());
+    }
+
+    // Prefer the proxy's original host when running behind a reverse proxy
+    let raw = headers
+        .get("x-forwarded-host")
+        .or_else(|| headers.get(header::HOST))
+        .and_then(|v| v.to_str().ok())?;
+    let host = strip_port(raw.split(',').next()?.trim());
+    (!host.is_empty()).then(|| host.to_string())
+}
+
+/// "example.com:3000" -> "example.com", "[::1]:3000" -> "[::1]"
+fn strip_port(host: &str) -> &str {
+    if let Some(end) = host.find(']') {
+        return &host[..=end];
+    }
+    host.split(':').next().unwrap_or(host)
+}
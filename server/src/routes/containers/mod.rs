@@ -1,7 +1,19 @@
 mod actions;
-mod details;
-mod handlers;
+mod blueprint;
+mod daemon;
+// Visible to the OpenAPI doc, which references the annotated handlers
+pub(super) mod details;
+pub(super) mod graph;
+pub(super) mod handlers;
+mod links;
 mod parser;
+mod service_url;
+mod status;
+pub(super) mod watch;
 
+pub(crate) use blueprint::blueprint_containers;
 pub use details::get_container_details;
+pub use graph::get_container_graph;
+pub(crate) use handlers::fetch_containers;
 pub use handlers::{list_containers, restart_container, start_container, stop_container};
+pub use watch::get_container_watch;
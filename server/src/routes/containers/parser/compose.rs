@@ -0,0 +1,50 @@
+use serde_json::Value;
+
+/// Docker compose metadata from container labels
+pub struct ComposeInfo {
+    pub project: Option<String>,
+    pub service: Option<String>,
+    /// Service names from `depends_on`
+    pub depends_on: Vec<String>,
+    /// Compose files the project was started from
+    pub config_files: Vec<String>,
+}
+
+pub(in super::super) fn extract_compose(c: &Value) -> ComposeInfo {
+    let labels = c.get("Config").and_then(|cfg| cfg.get("Labels"));
+    let label = |key: &str| {
+        labels
+            .and_then(|l| l.get(key))
+            .and_then(|v| v.as_str())
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_string())
+    };
+
+    // "db:service_started:false,redis:service_healthy:true"
+    let depends_on = label("com.docker.compose.depends_on")
+        .map(|raw| {
+            raw.split(',')
+                .filter_map(|dep| dep.split(':').next())
+                .map(|svc| svc.trim().to_string())
+                .filter(|svc| !svc.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // "/srv/app/compose.yml,/srv/app/compose.override.yml"
+    let config_files = label("com.docker.compose.project.config_files")
+        .map(|raw| {
+            raw.split(',')
+                .map(|file| file.trim().to_string())
+                .filter(|file| !file.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    ComposeInfo {
+        project: label("com.docker.compose.project"),
+        service: label("com.docker.compose.service"),
+        depends_on,
+        config_files,
+    }
+}
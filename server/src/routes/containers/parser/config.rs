@@ -1,4 +1,7 @@
+use super::super::super::types::{EnvSource, EnvVar};
+use super::super::service_url::{self, Labels};
 use serde_json::Value;
+use std::collections::HashMap;
 
 pub(super) fn extract_environment(c: &Value) -> Vec<String> {
     c.get("Config")
@@ -12,6 +15,37 @@ pub(super) fn extract_environment(c: &Value) -> Vec<String> {
         .unwrap_or_default()
 }
 
+/// Compare container variables with the image's `Config.Env` (from
+/// `docker image inspect`), in the order of `environment`
+pub(in super::super) fn env_sources(environment: &[String], image: &Value) -> Vec<EnvVar> {
+    let image_env: HashMap<&str, &str> = image
+        .get("Config")
+        .and_then(|cfg| cfg.get("Env"))
+        .and_then(|e| e.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str()?.split_once('='))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    environment
+        .iter()
+        .filter_map(|env| {
+            let (key, value) = env.split_once('=')?;
+            let source = match image_env.get(key) {
+                Some(default) if *default == value => EnvSource::Image,
+                Some(_) => EnvSource::Override,
+                None => EnvSource::Runtime,
+            };
+            Some(EnvVar {
+                key: key.to_string(),
+                source,
+            })
+        })
+        .collect()
+}
+
 pub(super) fn extract_restart_policy(c: &Value) -> String {
     c.get("HostConfig")
         .and_then(|h| h.get("RestartPolicy"))
@@ -28,3 +62,17 @@ pub(super) fn extract_health(c: &Value) -> Option<String> {
         .and_then(|s| s.as_str())
         .map(|s| s.to_string())
 }
+
+pub(super) fn extract_service_url(c: &Value) -> Option<String> {
+    let labels: Labels = c
+        .get("Config")
+        .and_then(|cfg| cfg.get("Labels"))
+        .and_then(|l| l.as_object())
+        .map(|obj| {
+            obj.iter()
+                .filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default();
+    service_url::from_labels(&labels)
+}
@@ -1,12 +1,18 @@
 mod basic;
+mod compose;
 mod config;
 mod network;
+mod run;
 mod storage;
 
-use super::super::types::ContainerDetails;
+use super::super::types::{ContainerDetails, GraphNode};
 use axum::http::StatusCode;
 use serde_json::Value;
 
+pub(super) use compose::{ComposeInfo, extract_compose};
+pub(super) use config::env_sources;
+pub(super) use run::run_command;
+
 pub(super) fn build_details(container: &Value) -> Result<ContainerDetails, (StatusCode, String)> {
     Ok(ContainerDetails {
         id: basic::extract_id(container),
@@ -20,7 +26,24 @@ pub(super) fn build_details(container: &Value) -> Result<ContainerDetails, (Stat
         volumes: storage::extract_volumes(container),
         networks: network::extract_networks(container),
         environment: config::extract_environment(container),
+        env_sources: Vec::new(),
         restart_policy: config::extract_restart_policy(container),
         health: config::extract_health(container),
+        service_url: config::extract_service_url(container),
     })
 }
+
+/// Graph node plus compose metadata; `depends_on` is resolved to IDs by the caller
+pub(super) fn build_graph_node(container: &Value) -> (GraphNode, ComposeInfo) {
+    let compose = compose::extract_compose(container);
+    let node = GraphNode {
+        id: basic::extract_id(container),
+        name: basic::extract_name(container),
+        state: basic::extract_state(container),
+        networks: network::extract_networks(container),
+        volumes: storage::extract_volume_names(container),
+        depends_on: Vec::new(),
+        compose_project: compose.project.clone(),
+    };
+    (node, compose)
+}
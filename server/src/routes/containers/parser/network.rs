@@ -17,6 +17,7 @@ pub(super) fn extract_ports(c: &Value) -> Vec<PortMapping> {
                             container_port: parts[0].to_string(),
                             host_port: host_port.to_string(),
                             protocol: parts.get(1).unwrap_or(&"tcp").to_string(),
+                            url: None,
                         });
                     }
                 }
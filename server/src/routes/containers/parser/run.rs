@@ -0,0 +1,71 @@
+use super::{basic, config, network};
+use serde_json::Value;
+
+/// `docker run` command recreating the container with its ports, mounts,
+/// networks and restart policy; `env` are the `KEY=VALUE` pairs to pass
+pub(in super::super) fn run_command(c: &Value, env: &[&str]) -> String {
+    let mut args = vec!["docker".to_string(), "run".to_string(), "-d".to_string()];
+    let mut push = |flag: &str, value: String| {
+        args.push(flag.to_string());
+        args.push(quote(&value));
+    };
+
+    push("--name", basic::extract_name(c));
+
+    let restart = config::extract_restart_policy(c);
+    if restart != "no" && !restart.is_empty() {
+        push("--restart", restart);
+    }
+
+    for port in network::extract_ports(c) {
+        push(
+            "-p",
+            format!(
+                "{}:{}/{}",
+                port.host_port, port.container_port, port.protocol
+            ),
+        );
+    }
+
+    // Named volumes by name, bind mounts by host path
+    let mounts = c.get("Mounts").and_then(|m| m.as_array());
+    for mount in mounts.into_iter().flatten() {
+        let source = mount
+            .get("Name")
+            .or_else(|| mount.get("Source"))
+            .and_then(|s| s.as_str());
+        let destination = mount.get("Destination").and_then(|d| d.as_str());
+        if let (Some(source), Some(destination)) = (source, destination) {
+            let readonly = mount.get("RW").and_then(|rw| rw.as_bool()) == Some(false);
+            let suffix = if readonly { ":ro" } else { "" };
+            push("-v", format!("{}:{}{}", source, destination, suffix));
+        }
+    }
+
+    for network in network::extract_networks(c) {
+        // Containers land on the default bridge anyway
+        if network != "bridge" {
+            push("--network", network);
+        }
+    }
+
+    for var in env {
+        push("-e", var.to_string());
+    }
+
+    args.push(quote(&basic::extract_image(c)));
+    args.join(" ")
+}
+
+/// Single-quote `value` for a POSIX shell unless it is safe as is
+fn quote(value: &str) -> String {
+    let safe = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=@,+%".contains(c));
+    if safe {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
@@ -26,3 +26,22 @@ pub(super) fn extract_volumes(c: &Value) -> Vec<VolumeMount> {
         })
         .unwrap_or_default()
 }
+
+/// Volume names for named volumes, host paths for bind mounts
+pub(super) fn extract_volume_names(c: &Value) -> Vec<String> {
+    c.get("Mounts")
+        .and_then(|m| m.as_array())
+        .map(|mounts| {
+            mounts
+                .iter()
+                .filter_map(|mount| {
+                    mount
+                        .get("Name")
+                        .or_else(|| mount.get("Source"))
+                        .and_then(|s| s.as_str())
+                        .map(|s| s.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
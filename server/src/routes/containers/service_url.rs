@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+
+pub(super) type Labels = BTreeMap<String, String>;
+
+/// Public URL of a service routed by a reverse proxy, derived from its labels
+///
+/// Traefik router rules take precedence over caddy-docker-proxy site labels.
+pub(super) fn from_labels(labels: &Labels) -> Option<String> {
+    traefik_url(labels).or_else(|| caddy_url(labels))
+}
+
+/// Parse the `{{.Labels}}` column of `docker ps` ("k=v,k2=v2")
+///
+/// Values may contain commas (e.g. `caddy=a.com, b.com`), so a segment only
+/// starts a new label when it looks like `key=`.
+pub(super) fn parse_label_list(raw: &str) -> Labels {
+    let mut labels = Labels::new();
+    let mut current: Option<String> = None;
+
+    for segment in raw.split(',') {
+        match segment.split_once('=') {
+            Some((key, value)) if is_label_key(key) => {
+                labels.insert(key.to_string(), value.to_string());
+                current = Some(key.to_string());
+            }
+            _ => {
+                if let Some(value) = current.as_ref().and_then(|k| labels.get_mut(k)) {
+                    value.push(',');
+                    value.push_str(segment);
+                }
+            }
+        }
+    }
+    labels
+}
+
+fn is_label_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '/'))
+}
+
+/// `traefik.http.routers.<name>.rule=Host(`app.example.com`)`
+fn traefik_url(labels: &Labels) -> Option<String> {
+    if labels.get("traefik.enable").map(String::as_str) == Some("false") {
+        return None;
+    }
+
+    let mut candidates: Vec<(bool, String)> = labels
+        .iter()
+        .filter_map(|(key, rule)| {
+            let router = key
+                .strip_prefix("traefik.http.routers.")?
+                .strip_suffix(".rule")?;
+            let host = rule_arg(rule, "Host(")?;
+            let path = rule_arg(rule, "PathPrefix(").unwrap_or_default();
+            let tls = traefik_router_tls(labels, router);
+            let scheme = if tls { "https" } else { "http" };
+            Some((tls, format!("{}://{}{}", scheme, host, path)))
+        })
+        .collect();
+
+    // Plain-http routers are often just redirects to the TLS one
+    candidates.sort_by_key(|(tls, _)| !tls);
+    candidates.into_iter().next().map(|(_, url)| url)
+}
+
+fn traefik_router_tls(labels: &Labels, router: &str) -> bool {
+    let prefix = format!("traefik.http.routers.{}.", router);
+    let get = |suffix: &str| labels.get(&format!("{}{}", prefix, suffix));
+
+    get("tls").is_some_and(|v| v == "true")
+        || labels
+            .keys()
+            .any(|k| k.starts_with(&format!("{}tls.", prefix)))
+        || get("entrypoints").is_some_and(|v| {
+            v.split(',')
+                .any(|ep| matches!(ep.trim(), "websecure" | "https"))
+        })
+}
+
+/// First backtick-quoted argument of a rule matcher, e.g. Host(`a.com`) -> a.com
+fn rule_arg(rule: &str, matcher: &str) -> Option<String> {
+    let start = rule.find(matcher)? + matcher.len();
+    let rest = &rule[start..];
+    let open = rest.find('`')? + 1;
+    let close = rest[open..].find('`')? + open;
+    let arg = rest[open..close].trim();
+    (!arg.is_empty()).then(|| arg.to_string())
+}
+
+/// caddy-docker-proxy: `caddy=app.example.com` (or `caddy_0=...`)
+fn caddy_url(labels: &Labels) -> Option<String> {
+    labels
+        .iter()
+        .filter(|(key, _)| is_caddy_site_key(key))
+        .find_map(|(_, sites)| sites.split([',', ' ']).find_map(caddy_site_url))
+}
+
+fn is_caddy_site_key(key: &str) -> bool {
+    key == "caddy"
+        || key
+            .strip_prefix("caddy_")
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn caddy_site_url(site: &str) -> Option<String> {
+    let site = site.trim();
+    // Skip wildcards, bare ports (":80") and template placeholders
+    if site.is_empty() || site.starts_with(':') || site.contains(['*', '{']) {
+        return None;
+    }
+    if site.starts_with("http://") || site.starts_with("https://") {
+        Some(site.to_string())
+    } else {
+        // Caddy serves bare domains over https by default
+        Some(format!("https://{}", site))
+    }
+}
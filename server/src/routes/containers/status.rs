@@ -0,0 +1,102 @@
+//! Docker's human status text ("Up 3 hours (healthy)", "Exited (137) 2 days
+//! ago") in structured form. Docker rounds the durations, so the times are
+//! as precise as its wording.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+
+/// What a status says about the container's last run
+#[derive(Default)]
+pub(crate) struct ParsedStatus {
+    pub started_at: Option<u64>,
+    pub exited_at: Option<u64>,
+    pub exit_code: Option<i32>,
+    pub health: Option<String>,
+}
+
+/// Parse a `docker ps` status as of now; unknown wording ("Created",
+/// "Dead") leaves every field None
+pub(crate) fn parse(status: &str) -> ParsedStatus {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    parse_at(status, now)
+}
+
+fn parse_at(status: &str, now: u64) -> ParsedStatus {
+    if let Some(rest) = status.strip_prefix("Up ") {
+        // "Up 2 hours (healthy)", "Up 5 minutes (Paused)"
+        let (duration, note) = match rest.split_once(" (") {
+            Some((duration, note)) => (duration, note.strip_suffix(')')),
+            None => (rest, None),
+        };
+        let health = note.and_then(|note| match note {
+            "healthy" | "unhealthy" => Some(note.to_string()),
+            "health: starting" => Some("starting".to_string()),
+            _ => None,
+        });
+        return ParsedStatus {
+            started_at: parse_duration(duration).map(|secs| now.saturating_sub(secs)),
+            health,
+            ..ParsedStatus::default()
+        };
+    }
+
+    // "Exited (137) 2 hours ago", "Restarting (1) 3 seconds ago"
+    let Some(rest) = status
+        .strip_prefix("Exited (")
+        .or_else(|| status.strip_prefix("Restarting ("))
+    else {
+        return ParsedStatus::default();
+    };
+    let Some((code, ago)) = rest.split_once(") ") else {
+        return ParsedStatus::default();
+    };
+    ParsedStatus {
+        exit_code: code.parse().ok(),
+        exited_at: ago
+            .strip_suffix(" ago")
+            .and_then(parse_duration)
+            .map(|secs| now.saturating_sub(secs)),
+        ..ParsedStatus::default()
+    }
+}
+
+/// Unix seconds of a docker "ago" text ("3 days ago", "About an hour ago")
+/// as of now, like the `RunningFor` column of `docker ps`
+pub(crate) fn parse_ago(text: &str) -> Option<u64> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let secs = parse_duration(text.strip_suffix(" ago")?)?;
+    Some(now.saturating_sub(secs))
+}
+
+/// Seconds in docker's human durations: "Less than a second", "About an
+/// hour", "3 weeks"
+fn parse_duration(text: &str) -> Option<u64> {
+    match text.to_lowercase().as_str() {
+        "less than a second" => return Some(0),
+        "about a minute" => return Some(MINUTE),
+        "about an hour" => return Some(HOUR),
+        _ => {}
+    }
+    let (count, unit) = text.split_once(' ')?;
+    let count: u64 = count.parse().ok()?;
+    let unit = match unit.trim_end_matches('s') {
+        "second" => 1,
+        "minute" => MINUTE,
+        "hour" => HOUR,
+        "day" => DAY,
+        "week" => 7 * DAY,
+        "month" => 30 * DAY,
+        "year" => 365 * DAY,
+        _ => return None,
+    };
+    Some(count * unit)
+}
@@ -0,0 +1,164 @@
+use super::super::types::{ContainerEvent, ContainerStats, ContainerWatchResponse};
+use axum::{Json, extract::Path, http::StatusCode};
+use serde_json::Value;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::process::Command;
+
+/// Log lines returned per poll
+const LOG_TAIL: usize = 100;
+/// How far back events are listed
+const EVENT_WINDOW_SECS: u64 = 3600;
+
+/// GET /api/containers/:id/watch - State, stats, log tail and recent events
+///
+/// Polled every second by the watch view, so the docker calls run concurrently.
+#[utoipa::path(
+    get,
+    path = "/api/containers/{id}/watch",
+    tag = "containers",
+    params(("id" = String, Path, description = "Container ID or name")),
+    responses(
+        (status = 200, description = "Live view of the container", body = ContainerWatchResponse),
+        (status = 404, description = "Container not found", body = String),
+    )
+)]
+pub async fn get_container_watch(
+    Path(id): Path<String>,
+) -> Result<Json<ContainerWatchResponse>, (StatusCode, String)> {
+    let (state, stats, logs, events) = tokio::join!(
+        fetch_state(&id),
+        fetch_stats(&id),
+        fetch_logs(&id),
+        fetch_events(&id)
+    );
+    let mut watch = state?;
+    // Stopped containers report zeros, leave them out
+    watch.stats = stats.filter(|_| watch.state == "running");
+    watch.logs = logs;
+    watch.events = events;
+    Ok(Json(watch))
+}
+
+async fn docker(args: &[&str]) -> Option<std::process::Output> {
+    Command::new("docker")
+        .args(args)
+        .output()
+        .await
+        .ok()
+        .filter(|output| output.status.success())
+}
+
+/// Name, state and restarts from `docker inspect`
+async fn fetch_state(id: &str) -> Result<ContainerWatchResponse, (StatusCode, String)> {
+    let output = docker(&["inspect", id]).await.ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            format!("Container not found: {}", id),
+        )
+    })?;
+    let inspect: Vec<Value> = serde_json::from_slice(&output.stdout).map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to parse docker inspect output: {}", e),
+        )
+    })?;
+    let container = inspect
+        .first()
+        .ok_or_else(|| (StatusCode::NOT_FOUND, "Container not found".to_string()))?;
+
+    let text = |pointer: &str| {
+        container
+            .pointer(pointer)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string()
+    };
+    Ok(ContainerWatchResponse {
+        name: text("/Name").trim_start_matches('/').to_string(),
+        state: text("/State/Status"),
+        health: container
+            .pointer("/State/Health/Status")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        restart_count: container
+            .get("RestartCount")
+            .and_then(Value::as_u64)
+            .unwrap_or(0),
+        started_at: text("/State/StartedAt"),
+        stats: None,
+        logs: Vec::new(),
+        events: Vec::new(),
+    })
+}
+
+/// One `docker stats` sample, None if docker has none
+async fn fetch_stats(id: &str) -> Option<ContainerStats> {
+    let output = docker(&["stats", "--no-stream", "--format", "{{json .}}", id]).await?;
+    let stats: Value = serde_json::from_slice(&output.stdout).ok()?;
+    let text = |key: &str| stats.get(key).and_then(Value::as_str).unwrap_or_default();
+    let percent = |key: &str| text(key).trim_end_matches('%').parse().unwrap_or(0.0);
+    Some(ContainerStats {
+        cpu_percent: percent("CPUPerc"),
+        mem_percent: percent("MemPerc"),
+        mem_usage: text("MemUsage").to_string(),
+        net_io: text("NetIO").to_string(),
+        block_io: text("BlockIO").to_string(),
+        pids: text("PIDs").parse().unwrap_or(0),
+    })
+}
+
+/// Last lines of both output streams, merged in time order
+async fn fetch_logs(id: &str) -> Vec<String> {
+    let tail = LOG_TAIL.to_string();
+    let Some(output) = docker(&["logs", "--tail", &tail, "--timestamps", id]).await else {
+        return Vec::new();
+    };
+    // RFC 3339 timestamps sort as text
+    let mut lines: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .chain(String::from_utf8_lossy(&output.stderr).lines())
+        .map(str::to_string)
+        .collect();
+    lines.sort();
+    let skip = lines.len().saturating_sub(LOG_TAIL);
+    lines.split_off(skip)
+}
+
+/// Events of the last hour; `--until` makes docker return instead of streaming
+async fn fetch_events(id: &str) -> Vec<ContainerEvent> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let since = now.saturating_sub(EVENT_WINDOW_SECS).to_string();
+    let until = now.to_string();
+    let filter = format!("container={}", id);
+    let Some(output) = docker(&[
+        "events",
+        "--since",
+        &since,
+        "--until",
+        &until,
+        "--filter",
+        &filter,
+        "--format",
+        "{{.Time}}\t{{.Action}}",
+    ])
+    .await
+    else {
+        return Vec::new();
+    };
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let (time, action) = line.split_once('\t')?;
+            Some(ContainerEvent {
+                time: time.parse().ok()?,
+                action: action.to_string(),
+            })
+        })
+        // exec_* events of health checks would drown the rest
+        .filter(|event| !event.action.starts_with("exec_"))
+        .collect()
+}
@@ -0,0 +1,100 @@
+use super::super::types::{Alert, AlertLevel, CertExpiry, ContainerInfo, HostStats};
+
+/// Alert when less than this share of memory is available
+const MEM_AVAILABLE_MIN_RATIO: f64 = 0.1;
+
+/// Whether a container needs attention: failing health check, crash-looping,
+/// dead, or exited with a non-zero code (a clean stop is not unhealthy)
+pub fn is_unhealthy(container: &ContainerInfo) -> bool {
+    match container.state.as_str() {
+        "restarting" | "dead" => true,
+        "exited" => container.exit_code.is_some_and(|code| code != 0),
+        _ => is_failing_health_check(container),
+    }
+}
+
+fn is_failing_health_check(container: &ContainerInfo) -> bool {
+    container.health.as_deref() == Some("unhealthy")
+}
+
+/// Derive alerts from the collected dashboard data, most severe first
+pub fn derive(
+    host: Option<&HostStats>,
+    unhealthy: &[ContainerInfo],
+    docker_error: Option<&str>,
+    certs: &[CertExpiry],
+    cert_warn_days: i64,
+) -> Vec<Alert> {
+    let mut alerts = Vec::new();
+
+    if let Some(error) = docker_error {
+        alerts.push(critical(format!("Docker unavailable: {}", error)));
+    }
+
+    for container in unhealthy {
+        let message = format!("{}: {}", container.name, container.status);
+        if is_failing_health_check(container) || container.state == "dead" {
+            alerts.push(critical(message));
+        } else {
+            alerts.push(warning(message));
+        }
+    }
+
+    for cert in certs {
+        match (cert.days_left, &cert.error) {
+            (Some(days), _) if days < 0 => {
+                alerts.push(critical(format!("Certificate {} has expired", cert.name)));
+            }
+            (Some(days), _) if days <= cert_warn_days => {
+                alerts.push(warning(format!(
+                    "Certificate {} expires in {} days",
+                    cert.name, days
+                )));
+            }
+            (None, Some(error)) => {
+                alerts.push(warning(format!("Certificate {}: {}", cert.name, error)));
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(host) = host {
+        if let Some(load) = host.load_avg.first()
+            && *load > host.cpu_count as f64
+        {
+            alerts.push(warning(format!(
+                "Load {:.2} exceeds {} CPUs",
+                load, host.cpu_count
+            )));
+        }
+        if host.mem_total_kb > 0
+            && (host.mem_available_kb as f64) < host.mem_total_kb as f64 * MEM_AVAILABLE_MIN_RATIO
+        {
+            alerts.push(warning(format!(
+                "Low memory: {} MiB available",
+                host.mem_available_kb / 1024
+            )));
+        }
+    }
+
+    // Stable sort keeps the source order within a level
+    alerts.sort_by_key(|a| match a.level {
+        AlertLevel::Critical => 0,
+        AlertLevel::Warning => 1,
+    });
+    alerts
+}
+
+fn critical(message: String) -> Alert {
+    Alert {
+        level: AlertLevel::Critical,
+        message,
+    }
+}
+
+fn warning(message: String) -> Alert {
+    Alert {
+        level: AlertLevel::Warning,
+        message,
+    }
+}
@@ -0,0 +1,78 @@
+use super::super::types::CertExpiry;
+use sysrat_core::config::CertFile;
+use tokio::process::Command;
+
+const SECS_PER_DAY: i64 = 86_400;
+
+/// Read the expiry of each configured certificate via `openssl x509`
+pub async fn read_cert_expiries(certs: &[CertFile], now: i64) -> Vec<CertExpiry> {
+    let mut expiries = Vec::with_capacity(certs.len());
+
+    for cert in certs {
+        let (expires, error) = match not_after(&cert.path).await {
+            Ok(ts) => (Some(ts), None),
+            Err(e) => (None, Some(e)),
+        };
+        expiries.push(CertExpiry {
+            name: cert.name.clone(),
+            path: cert.path.clone(),
+            expires,
+            days_left: expires.map(|ts| (ts - now).div_euclid(SECS_PER_DAY)),
+            error,
+        });
+    }
+
+    // Soonest expiry first, unreadable certificates last
+    expiries.sort_by_key(|c| c.expires.unwrap_or(i64::MAX));
+    expiries
+}
+
+async fn not_after(path: &str) -> Result<i64, String> {
+    let output = Command::new("openssl")
+        .args(["x509", "-noout", "-enddate", "-in", path])
+        .output()
+        .await
+        .map_err(|e| format!("Failed to execute openssl: {}", e))?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("openssl failed: {}", error.trim()));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let date = stdout
+        .trim()
+        .strip_prefix("notAfter=")
+        .ok_or_else(|| format!("Unexpected openssl output: {}", stdout.trim()))?;
+
+    parse_openssl_date(date).ok_or_else(|| format!("Unrecognized date: {}", date))
+}
+
+/// Parse openssl's `Jun  1 12:00:00 2025 GMT` into a Unix timestamp
+fn parse_openssl_date(date: &str) -> Option<i64> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let mut parts = date.split_whitespace();
+    let month_name = parts.next()?;
+    let month = MONTHS.iter().position(|m| *m == month_name)? as i64 + 1;
+    let day: i64 = parts.next()?.parse().ok()?;
+    let mut time = parts.next()?.split(':').map(|s| s.parse::<i64>().ok());
+    let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+    let year: i64 = parts.next()?.parse().ok()?;
+
+    let days = days_from_civil(year, month, day);
+    Some(days * SECS_PER_DAY + hour * 3600 + minute * 60 + second)
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's algorithm)
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
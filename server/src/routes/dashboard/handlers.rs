@@ -0,0 +1,122 @@
+use super::super::containers::fetch_containers;
+use super::super::types::{
+    Alert, CertExpiry, ConfigChange, ContainerInfo, DashboardResponse, HostStats,
+};
+use super::{alerts, certs, host};
+use axum::{Json, extract::State};
+use std::time::{SystemTime, UNIX_EPOCH};
+use sysrat_core::config::{CertFile, SharedConfig};
+
+/// Number of recently modified config files returned
+const RECENT_CHANGES_LIMIT: usize = 20;
+
+/// GET /api/dashboard - Data for all dashboard widgets
+///
+/// Sources that fail (docker, unreadable certificates) are reported as alerts
+/// instead of failing the whole request.
+#[utoipa::path(
+    get,
+    path = "/api/dashboard",
+    tag = "dashboard",
+    responses((status = 200, description = "Host stats, unhealthy containers, recent config changes, certificate expiries, alerts and quick actions", body = DashboardResponse))
+)]
+pub async fn get_dashboard(State(config): State<SharedConfig>) -> Json<DashboardResponse> {
+    let (recent_changes, cert_files, cert_warn_days, quick_actions) = {
+        let reader = config.read().await;
+        let paths: Vec<(String, String)> = reader
+            .files()
+            .iter()
+            .map(|f| (f.name.clone(), f.path.clone()))
+            .collect();
+        let dashboard = reader.dashboard();
+        (
+            recent_changes(paths),
+            dashboard.certs.clone(),
+            dashboard.cert_warn_days,
+            dashboard.quick_actions.clone(),
+        )
+    };
+
+    let health = collect_health(&cert_files, cert_warn_days).await;
+
+    Json(DashboardResponse {
+        host: health.host,
+        unhealthy_containers: health.unhealthy_containers,
+        recent_changes,
+        cert_expiries: health.cert_expiries,
+        alerts: health.alerts,
+        quick_actions,
+    })
+}
+
+/// Alerts as the dashboard shows them right now, most severe first
+pub(crate) async fn active_alerts(config: &SharedConfig) -> Vec<Alert> {
+    let (cert_files, cert_warn_days) = {
+        let reader = config.read().await;
+        let dashboard = reader.dashboard();
+        (dashboard.certs.clone(), dashboard.cert_warn_days)
+    };
+    collect_health(&cert_files, cert_warn_days).await.alerts
+}
+
+/// Sources the alerts are derived from, along with the alerts
+struct Health {
+    host: Option<HostStats>,
+    unhealthy_containers: Vec<ContainerInfo>,
+    cert_expiries: Vec<CertExpiry>,
+    alerts: Vec<Alert>,
+}
+
+async fn collect_health(cert_files: &[CertFile], cert_warn_days: i64) -> Health {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+
+    let host = host::read_host_stats();
+
+    // Protection only matters to the container list's stop/restart
+    let (unhealthy_containers, docker_error) = match fetch_containers(&Default::default()).await {
+        Ok(containers) => (
+            containers
+                .into_iter()
+                .filter(alerts::is_unhealthy)
+                .collect(),
+            None,
+        ),
+        Err(e) => (Vec::new(), Some(e)),
+    };
+
+    let cert_expiries = certs::read_cert_expiries(cert_files, now).await;
+
+    let alerts = alerts::derive(
+        host.as_ref(),
+        &unhealthy_containers,
+        docker_error.as_deref(),
+        &cert_expiries,
+        cert_warn_days,
+    );
+
+    Health {
+        host,
+        unhealthy_containers,
+        cert_expiries,
+        alerts,
+    }
+}
+
+/// Managed files by modification time, newest first
+fn recent_changes(paths: Vec<(String, String)>) -> Vec<ConfigChange> {
+    let mut changes: Vec<ConfigChange> = paths
+        .into_iter()
+        .filter_map(|(name, path)| {
+            let modified = std::fs::metadata(&path).ok()?.modified().ok()?;
+            let modified = modified.duration_since(UNIX_EPOCH).ok()?.as_secs();
+            Some(ConfigChange { name, modified })
+        })
+        .collect();
+
+    changes.sort_by_key(|c| std::cmp::Reverse(c.modified));
+    changes.truncate(RECENT_CHANGES_LIMIT);
+    changes
+}
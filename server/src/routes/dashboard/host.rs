@@ -0,0 +1,48 @@
+use super::super::types::HostStats;
+use std::fs;
+
+/// Read host metrics from /proc, None where /proc is unavailable (non-Linux)
+pub fn read_host_stats() -> Option<HostStats> {
+    let uptime = fs::read_to_string("/proc/uptime").ok()?;
+    let loadavg = fs::read_to_string("/proc/loadavg").ok()?;
+    let meminfo = fs::read_to_string("/proc/meminfo").ok()?;
+
+    let uptime_secs = uptime
+        .split_whitespace()
+        .next()
+        .and_then(|s| s.parse::<f64>().ok())
+        .map(|s| s as u64)
+        .unwrap_or(0);
+
+    let load_avg = loadavg
+        .split_whitespace()
+        .take(3)
+        .filter_map(|s| s.parse::<f64>().ok())
+        .collect();
+
+    let hostname = fs::read_to_string("/proc/sys/kernel/hostname")
+        .map(|s| s.trim().to_string())
+        .unwrap_or_default();
+
+    Some(HostStats {
+        hostname,
+        uptime_secs,
+        load_avg,
+        cpu_count: std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1),
+        mem_total_kb: meminfo_value(&meminfo, "MemTotal"),
+        mem_available_kb: meminfo_value(&meminfo, "MemAvailable"),
+    })
+}
+
+/// Value of a `Key:   1234 kB` line in /proc/meminfo
+fn meminfo_value(meminfo: &str, key: &str) -> u64 {
+    meminfo
+        .lines()
+        .find_map(|line| {
+            let rest = line.strip_prefix(key)?.strip_prefix(':')?;
+            rest.split_whitespace().next()?.parse().ok()
+        })
+        .unwrap_or(0)
+}
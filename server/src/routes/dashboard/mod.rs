@@ -0,0 +1,8 @@
+mod alerts;
+mod certs;
+// Visible to the OpenAPI doc, which references the annotated handlers
+pub(super) mod handlers;
+mod host;
+
+pub(crate) use handlers::active_alerts;
+pub use handlers::get_dashboard;
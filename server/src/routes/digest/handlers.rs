@@ -0,0 +1,53 @@
+use super::super::types::{DigestResponse, DigestSendResponse};
+use super::{render, report, schedule};
+use axum::{Json, extract::State, http::StatusCode};
+use std::time::{SystemTime, UNIX_EPOCH};
+use sysrat_core::config::SharedConfig;
+
+/// GET /api/digest - The digest as it would be sent now
+#[utoipa::path(
+    get,
+    path = "/api/digest",
+    tag = "digest",
+    responses((status = 200, description = "Report of the last 7 days with its plaintext and HTML renderings", body = DigestResponse))
+)]
+pub async fn get_digest(State(config): State<SharedConfig>) -> Json<DigestResponse> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+
+    let report = report::build(&config, now).await;
+    let message = render::message(&report);
+    Json(DigestResponse {
+        report,
+        subject: message.subject,
+        text: message.text,
+        html: message.html,
+    })
+}
+
+/// POST /api/digest/send - Send the digest now instead of waiting for its day
+#[utoipa::path(
+    post,
+    path = "/api/digest/send",
+    tag = "digest",
+    responses(
+        (status = 200, description = "Outcome per notifier channel", body = DigestSendResponse),
+        (status = 501, description = "No notifier channels configured", body = String),
+    )
+)]
+pub async fn send_digest(
+    State(config): State<SharedConfig>,
+) -> Result<Json<DigestSendResponse>, (StatusCode, String)> {
+    match schedule::send_now(&config).await {
+        Ok(deliveries) => Ok(Json(DigestSendResponse { deliveries })),
+        Err(e) if e.kind() == std::io::ErrorKind::Unsupported => {
+            Err((StatusCode::NOT_IMPLEMENTED, format!("Digest error: {}", e)))
+        }
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Digest error: {}", e),
+        )),
+    }
+}
@@ -0,0 +1,8 @@
+// Visible to the OpenAPI doc, which references the annotated handlers
+pub(super) mod handlers;
+mod render;
+mod report;
+mod schedule;
+
+pub use handlers::{get_digest, send_digest};
+pub use schedule::spawn_scheduler;
@@ -0,0 +1,194 @@
+use super::super::types::{AlertLevel, DigestChange, DigestReport};
+use sysrat_core::notify::Message;
+
+const SECS_PER_DAY: i64 = 86_400;
+
+/// Subject, plaintext and HTML of a digest
+pub(super) fn message(report: &DigestReport) -> Message {
+    Message {
+        subject: subject(report),
+        text: text(report),
+        html: html(report),
+    }
+}
+
+fn subject(report: &DigestReport) -> String {
+    format!(
+        "sysrat digest: {} changed files, {} restarts, {} alerts",
+        report.changes.len(),
+        report.restarts.iter().map(|r| r.restarts).sum::<u64>(),
+        report.alerts.len()
+    )
+}
+
+fn period(report: &DigestReport) -> String {
+    format!("{} to {}", date(report.since), date(report.until))
+}
+
+fn change_summary(change: &DigestChange) -> String {
+    match change.changes {
+        Some(1) => format!("1 change, last {}", date(change.last_changed)),
+        Some(n) => format!("{} changes, last {}", n, date(change.last_changed)),
+        None => format!("modified {}", date(change.last_changed)),
+    }
+}
+
+fn level_label(level: AlertLevel) -> &'static str {
+    match level {
+        AlertLevel::Critical => "critical",
+        AlertLevel::Warning => "warning",
+    }
+}
+
+fn text(report: &DigestReport) -> String {
+    let mut out = format!("sysrat digest {}\n", period(report));
+
+    out.push_str(&format!("\nConfig changes ({})\n", report.changes.len()));
+    for change in &report.changes {
+        out.push_str(&format!(
+            "  {:<30} {}\n",
+            change.name,
+            change_summary(change)
+        ));
+    }
+    if report.changes.is_empty() {
+        out.push_str("  None\n");
+    }
+
+    out.push_str(&format!(
+        "\nContainer restarts ({})\n",
+        report.restarts.len()
+    ));
+    for container in &report.restarts {
+        out.push_str(&format!(
+            "  {:<30} {}\n",
+            container.name, container.restarts
+        ));
+    }
+    if report.restarts.is_empty() {
+        out.push_str("  None\n");
+    }
+
+    out.push_str(&format!("\nActive alerts ({})\n", report.alerts.len()));
+    for alert in &report.alerts {
+        out.push_str(&format!(
+            "  [{}] {}\n",
+            level_label(alert.level),
+            alert.message
+        ));
+    }
+    if report.alerts.is_empty() {
+        out.push_str("  None\n");
+    }
+
+    if !report.notes.is_empty() {
+        out.push_str("\nNotes\n");
+        for note in &report.notes {
+            out.push_str(&format!("  {}\n", note));
+        }
+    }
+    out
+}
+
+fn html(report: &DigestReport) -> String {
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html><body style=\"font-family: sans-serif; color: #222;\">\n",
+    );
+    out.push_str(&format!(
+        "<h2>sysrat digest</h2>\n<p>{}</p>\n",
+        escape(&period(report))
+    ));
+
+    section(
+        &mut out,
+        "Config changes",
+        report
+            .changes
+            .iter()
+            .map(|c| [escape(&c.name), escape(&change_summary(c))])
+            .collect(),
+    );
+    section(
+        &mut out,
+        "Container restarts",
+        report
+            .restarts
+            .iter()
+            .map(|r| [escape(&r.name), r.restarts.to_string()])
+            .collect(),
+    );
+    section(
+        &mut out,
+        "Active alerts",
+        report
+            .alerts
+            .iter()
+            .map(|a| {
+                let color = match a.level {
+                    AlertLevel::Critical => "#c0392b",
+                    AlertLevel::Warning => "#d68910",
+                };
+                [
+                    format!(
+                        "<span style=\"color: {};\">{}</span>",
+                        color,
+                        level_label(a.level)
+                    ),
+                    escape(&a.message),
+                ]
+            })
+            .collect(),
+    );
+
+    if !report.notes.is_empty() {
+        out.push_str("<h3>Notes</h3>\n<ul>\n");
+        for note in &report.notes {
+            out.push_str(&format!("<li>{}</li>\n", escape(note)));
+        }
+        out.push_str("</ul>\n");
+    }
+
+    out.push_str("</body></html>\n");
+    out
+}
+
+/// Heading with a two-column table of already escaped cells
+fn section(out: &mut String, title: &str, rows: Vec<[String; 2]>) {
+    out.push_str(&format!("<h3>{} ({})</h3>\n", title, rows.len()));
+    if rows.is_empty() {
+        out.push_str("<p>None</p>\n");
+        return;
+    }
+    out.push_str("<table cellpadding=\"4\">\n");
+    for [left, right] in rows {
+        out.push_str(&format!("<tr><td>{}</td><td>{}</td></tr>\n", left, right));
+    }
+    out.push_str("</table>\n");
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// `YYYY-MM-DD` (UTC) of a Unix timestamp
+fn date(timestamp: i64) -> String {
+    let (year, month, day) = civil_from_days(timestamp.div_euclid(SECS_PER_DAY));
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Proleptic Gregorian date of days since 1970-01-01 (Howard Hinnant's algorithm)
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
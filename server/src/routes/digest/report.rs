@@ -0,0 +1,129 @@
+use super::super::dashboard::active_alerts;
+use super::super::types::{ContainerRestarts, DigestChange, DigestReport};
+use std::collections::HashMap;
+use std::time::UNIX_EPOCH;
+use sysrat_core::config::SharedConfig;
+use tokio::process::Command;
+
+/// Days a digest looks back
+pub(super) const PERIOD_DAYS: u32 = 7;
+
+/// Collect the digest of the `PERIOD_DAYS` up to `until`. Sources that fail
+/// are noted in the report rather than failing the digest.
+pub(super) async fn build(config: &SharedConfig, until: i64) -> DigestReport {
+    let since = until - i64::from(PERIOD_DAYS) * 86_400;
+    let mut notes = Vec::new();
+
+    let changes = match sysrat_core::configs::actions::change_stats(PERIOD_DAYS, config).await {
+        Ok(stats) => stats
+            .into_iter()
+            .filter(|s| s.changes > 0)
+            .map(|s| DigestChange {
+                name: s.name,
+                changes: Some(s.changes),
+                last_changed: s.last_changed.unwrap_or(until),
+            })
+            .collect(),
+        // Without git versioning only modification times are known
+        Err(e) if e.kind() == std::io::ErrorKind::Unsupported => {
+            modified_since(config, since).await
+        }
+        Err(e) => {
+            notes.push(format!("Config changes unavailable: {}", e));
+            Vec::new()
+        }
+    };
+
+    let restarts = match fetch_restarts(since, until).await {
+        Ok(restarts) => restarts,
+        Err(e) => {
+            notes.push(format!("Container restarts unavailable: {}", e));
+            Vec::new()
+        }
+    };
+
+    DigestReport {
+        since,
+        until,
+        changes,
+        restarts,
+        alerts: active_alerts(config).await,
+        notes,
+    }
+}
+
+/// Managed files modified since `since`, newest first
+async fn modified_since(config: &SharedConfig, since: i64) -> Vec<DigestChange> {
+    let paths: Vec<(String, String)> = config
+        .read()
+        .await
+        .files()
+        .iter()
+        .map(|f| (f.name.clone(), f.path.clone()))
+        .collect();
+
+    let mut changes: Vec<DigestChange> = paths
+        .into_iter()
+        .filter_map(|(name, path)| {
+            let modified = std::fs::metadata(&path).ok()?.modified().ok()?;
+            let modified = modified.duration_since(UNIX_EPOCH).ok()?.as_secs() as i64;
+            (modified >= since).then_some(DigestChange {
+                name,
+                changes: None,
+                last_changed: modified,
+            })
+        })
+        .collect();
+
+    changes.sort_by_key(|c| std::cmp::Reverse(c.last_changed));
+    changes
+}
+
+/// Count `restart` events per container from `docker events`, most restarted
+/// first. The daemon only keeps its recent events, so a busy host may report
+/// fewer restarts than happened.
+async fn fetch_restarts(since: i64, until: i64) -> Result<Vec<ContainerRestarts>, String> {
+    let output = Command::new("docker")
+        .args([
+            "events",
+            "--since",
+            &since.to_string(),
+            "--until",
+            &until.to_string(),
+            "--filter",
+            "type=container",
+            "--filter",
+            "event=restart",
+            "--format",
+            "{{.Actor.Attributes.name}}",
+        ])
+        .output()
+        .await
+        .map_err(|e| format!("Failed to execute docker command: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Docker command failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    for name in String::from_utf8_lossy(&output.stdout).lines() {
+        let name = name.trim();
+        if !name.is_empty() {
+            *counts.entry(name.to_string()).or_default() += 1;
+        }
+    }
+
+    let mut restarts: Vec<ContainerRestarts> = counts
+        .into_iter()
+        .map(|(name, restarts)| ContainerRestarts { name, restarts })
+        .collect();
+    restarts.sort_by(|a, b| {
+        b.restarts
+            .cmp(&a.restarts)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    Ok(restarts)
+}
@@ -0,0 +1,113 @@
+use super::super::types::DigestDelivery;
+use super::{render, report};
+use k_lib::config::Cookbook;
+use k_lib::logger;
+use std::io;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use sysrat_core::config::{DigestSettings, SharedConfig};
+use sysrat_core::notify;
+
+const SCOPE: &str = "DIGEST";
+const APP_NAME: &str = "sysrat";
+
+const SECS_PER_DAY: i64 = 86_400;
+/// How often the schedule is checked, also picks up a reloaded `[digest]`
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+/// A slot missed by longer (server down, digest disabled) is skipped
+const SEND_WINDOW_SECS: i64 = 3600;
+
+fn log(cookbook: &Cookbook, level: &str, msg: &str) {
+    logger::log_to_terminal(cookbook, level, SCOPE, msg);
+    let _ = logger::log_to_file(cookbook, level, SCOPE, msg, Some(APP_NAME));
+}
+
+fn now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Send the digest through every notifier channel. Fails with `Unsupported`
+/// when no channel is configured.
+pub(super) async fn send_now(config: &SharedConfig) -> io::Result<Vec<DigestDelivery>> {
+    let notifiers = config.read().await.notifiers().clone();
+    if !notify::has_channels(&notifiers) {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "No [notifiers] channels configured",
+        ));
+    }
+
+    let report = report::build(config, now_secs()).await;
+    let message = render::message(&report);
+    let deliveries = notify::send(&message, &notifiers).await;
+
+    let cookbook = Cookbook::load().ok();
+    Ok(deliveries
+        .into_iter()
+        .map(|delivery| {
+            let error = delivery.result.err().map(|e| e.to_string());
+            if let Some(ref cb) = cookbook {
+                match &error {
+                    None => log(
+                        cb,
+                        "success",
+                        &format!("Digest sent to {}", delivery.channel),
+                    ),
+                    Some(e) => log(
+                        cb,
+                        "error",
+                        &format!("Digest to {} failed: {}", delivery.channel, e),
+                    ),
+                }
+            }
+            DigestDelivery {
+                channel: delivery.channel,
+                error,
+            }
+        })
+        .collect())
+}
+
+/// Most recent scheduled time at or before `now`
+fn last_slot(settings: &DigestSettings, now: i64) -> i64 {
+    let today = now.div_euclid(SECS_PER_DAY);
+    // 1970-01-01 was a Thursday, three days after a Monday
+    let days_back = (today + 3 - settings.weekday.index()).rem_euclid(7);
+    let slot = (today - days_back) * SECS_PER_DAY + i64::from(settings.hour.min(23)) * 3600;
+    if slot > now {
+        slot - 7 * SECS_PER_DAY
+    } else {
+        slot
+    }
+}
+
+/// Send the digest once a week as set in `[digest]`
+pub fn spawn_scheduler(config: SharedConfig) {
+    tokio::spawn(async move {
+        let mut last_sent = None;
+        let mut ticker = tokio::time::interval(CHECK_INTERVAL);
+
+        loop {
+            ticker.tick().await;
+
+            let settings = config.read().await.digest().clone();
+            if !settings.enabled {
+                continue;
+            }
+            let now = now_secs();
+            let slot = last_slot(&settings, now);
+            if last_sent == Some(slot) || now - slot > SEND_WINDOW_SECS {
+                continue;
+            }
+            last_sent = Some(slot);
+
+            if let Err(e) = send_now(&config).await
+                && let Some(ref cb) = Cookbook::load().ok()
+            {
+                log(cb, "warn", &format!("Digest not sent: {}", e));
+            }
+        }
+    });
+}
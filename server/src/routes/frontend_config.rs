@@ -0,0 +1,93 @@
+use crate::routes::types::{FrontendConfigResponse, RefreshIntervals, RetryPolicy};
+use axum::{Json, extract::State};
+use std::path::Path;
+use sysrat_core::config::SharedConfig;
+use sysrat_core::fsutil::expand_tilde;
+
+/// Frames read from an art pack, the rest of the directory is ignored
+const MAX_FRAMES: usize = 64;
+
+/// Frames cycle no faster than this, the UI is not a video player
+const MIN_FRAME_MS: u64 = 100;
+
+/// Panes poll no more often than this
+const MIN_REFRESH_MS: u64 = 1_000;
+
+/// GET /api/frontend-config - Banner, MOTD, art pack, splash and retry policy from sysrat.toml
+///
+/// Lets branding change with a config reload instead of a new wasm bundle.
+/// The art directory is read on every request, like custom themes.
+#[utoipa::path(
+    get,
+    path = "/api/frontend-config",
+    tag = "frontend",
+    responses((status = 200, description = "Settings of the web UI", body = FrontendConfigResponse))
+)]
+pub async fn get_frontend_config(
+    State(config): State<SharedConfig>,
+) -> Json<FrontendConfigResponse> {
+    let (branding, network) = {
+        let config = config.read().await;
+        (config.branding().clone(), config.network().clone())
+    };
+
+    let art_frames = match branding.art_dir.as_deref().filter(|d| !d.is_empty()) {
+        Some(dir) => {
+            let dir = expand_tilde(dir);
+            tokio::task::spawn_blocking(move || read_frames(&dir))
+                .await
+                .unwrap_or_default()
+        }
+        None => Vec::new(),
+    };
+    let frame_ms = if branding.animate && art_frames.len() > 1 {
+        branding.frame_ms.max(MIN_FRAME_MS)
+    } else {
+        0
+    };
+
+    // Empty values count as unset, so commenting out the text is not needed
+    Json(FrontendConfigResponse {
+        banner: branding.banner.filter(|b| !b.trim().is_empty()),
+        replace_rat: branding.replace_rat,
+        motd: branding.motd.filter(|m| !m.trim().is_empty()),
+        art_frames,
+        frame_ms,
+        splash_ms: if branding.splash {
+            branding.splash_ms
+        } else {
+            0
+        },
+        retry: RetryPolicy {
+            attempts: network.retry_attempts.max(1),
+            base_ms: network.retry_base_ms,
+            max_ms: network.retry_max_ms.max(network.retry_base_ms),
+        },
+        refresh: RefreshIntervals {
+            default_ms: network.refresh_ms.max(MIN_REFRESH_MS),
+            panes: network
+                .pane_refresh_ms
+                .into_iter()
+                .map(|(pane, ms)| (pane, ms.max(MIN_REFRESH_MS)))
+                .collect(),
+        },
+    })
+}
+
+/// `*.ascii` files of `dir` in name order; a missing directory has none
+fn read_frames(dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<_> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ascii"))
+        .collect();
+    paths.sort();
+    paths
+        .iter()
+        .take(MAX_FRAMES)
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .collect()
+}
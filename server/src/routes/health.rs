@@ -0,0 +1,159 @@
+use crate::routes::types::{
+    API_VERSION, HealthResponse, MetaResponse, ReadinessCheck, ReadinessResponse, VersionResponse,
+};
+use axum::{Json, extract::State, http::StatusCode};
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use sysrat_core::config::{AppConfig, SharedConfig};
+use tokio::process::Command;
+
+/// A docker that does not answer within this is reported as unreachable
+const DOCKER_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// When the server started, as an instant for the uptime and unix seconds
+static STARTED: OnceLock<(Instant, u64)> = OnceLock::new();
+
+/// Remember the start time for `/api/meta`; call once at startup
+pub fn record_start() {
+    let unix = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let _ = STARTED.set((Instant::now(), unix));
+}
+
+/// GET /healthz - Liveness: the process is up
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    tag = "health",
+    responses((status = 200, description = "Process is up", body = HealthResponse))
+)]
+pub async fn healthz() -> Json<HealthResponse> {
+    Json(HealthResponse {
+        status: "ok".to_string(),
+    })
+}
+
+/// GET /readyz - Readiness: config loaded, docker reachable, config dir writable
+///
+/// Meant for Kubernetes readiness probes and systemd watchdogs, so every check
+/// is cheap and docker is given a short timeout.
+#[utoipa::path(
+    get,
+    path = "/readyz",
+    tag = "health",
+    responses(
+        (status = 200, description = "All checks passed", body = ReadinessResponse),
+        (status = 503, description = "At least one check failed", body = ReadinessResponse),
+    )
+)]
+pub async fn readyz(State(config): State<SharedConfig>) -> (StatusCode, Json<ReadinessResponse>) {
+    let file_count = config.read().await.file_count();
+    let config_path = AppConfig::config_path();
+    let (docker, config_dir) = tokio::join!(check_docker(), check_config_dir(&config_path));
+
+    let checks = vec![
+        ReadinessCheck {
+            name: "config".to_string(),
+            ok: true,
+            detail: format!("{} files managed from {}", file_count, config_path),
+        },
+        docker,
+        config_dir,
+    ];
+    let ready = checks.iter().all(|check| check.ok);
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(ReadinessResponse { ready, checks }))
+}
+
+/// GET /api/meta - Server version and uptime
+#[utoipa::path(
+    get,
+    path = "/api/meta",
+    tag = "health",
+    responses((status = 200, description = "Version and uptime", body = MetaResponse))
+)]
+pub async fn get_meta() -> Json<MetaResponse> {
+    let (uptime_secs, started_at) = STARTED
+        .get()
+        .map(|(instant, unix)| (instant.elapsed().as_secs(), *unix))
+        .unwrap_or_default();
+    Json(MetaResponse {
+        version: crate::version::SERVER_VERSION.to_string(),
+        api_version: API_VERSION,
+        started_at,
+        uptime_secs,
+    })
+}
+
+/// GET /api/version - What the server was built from, the frontend compares
+/// it with its own version
+#[utoipa::path(
+    get,
+    path = "/api/version",
+    tag = "health",
+    responses((status = 200, description = "Build information", body = VersionResponse))
+)]
+pub async fn get_version() -> Json<VersionResponse> {
+    Json(VersionResponse {
+        version: crate::version::SERVER_VERSION.to_string(),
+        git_commit: env!("BUILD_HASH").to_string(),
+        build_date: env!("BUILD_DATE").to_string(),
+        rustc_version: env!("RUST_VERSION").to_string(),
+    })
+}
+
+/// The docker daemon answers through the CLI every other route uses
+async fn check_docker() -> ReadinessCheck {
+    let output = Command::new("docker")
+        .args(["version", "--format", "{{.Server.Version}}"])
+        .kill_on_drop(true)
+        .output();
+    let (ok, detail) = match tokio::time::timeout(DOCKER_TIMEOUT, output).await {
+        Ok(Ok(output)) if output.status.success() => (
+            true,
+            format!("Docker {}", String::from_utf8_lossy(&output.stdout).trim()),
+        ),
+        Ok(Ok(output)) => (
+            false,
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ),
+        Ok(Err(e)) => (false, format!("Failed to run docker: {}", e)),
+        Err(_) => (
+            false,
+            format!("No answer within {}s", DOCKER_TIMEOUT.as_secs()),
+        ),
+    };
+    ReadinessCheck {
+        name: "docker".to_string(),
+        ok,
+        detail,
+    }
+}
+
+/// The directory of sysrat.toml takes a file, as a config restore would
+async fn check_config_dir(config_path: &str) -> ReadinessCheck {
+    let dir = Path::new(config_path)
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let probe = dir.join(format!(".sysrat-ready-{}", std::process::id()));
+
+    let result = tokio::fs::write(&probe, b"").await;
+    let _ = tokio::fs::remove_file(&probe).await;
+    let (ok, detail) = match result {
+        Ok(()) => (true, format!("{} is writable", dir.display())),
+        Err(e) => (false, format!("{} is not writable: {}", dir.display(), e)),
+    };
+    ReadinessCheck {
+        name: "config_dir".to_string(),
+        ok,
+        detail,
+    }
+}
@@ -0,0 +1,27 @@
+use crate::routes::types::{HostInfo, HostListResponse};
+use axum::{Json, extract::State};
+use sysrat_core::config::SharedConfig;
+
+/// GET /api/hosts - Other sysrat servers from sysrat.toml
+///
+/// The web UI switches to one by sending its API calls there directly, so
+/// that server needs `[server.cors]` to allow this one's origin.
+#[utoipa::path(
+    get,
+    path = "/api/hosts",
+    tag = "hosts",
+    responses((status = 200, description = "Configured hosts", body = HostListResponse))
+)]
+pub async fn list_hosts(State(config): State<SharedConfig>) -> Json<HostListResponse> {
+    let config = config.read().await;
+    let hosts = config
+        .hosts()
+        .iter()
+        .map(|host| HostInfo {
+            name: host.name.clone(),
+            url: host.url.trim_end_matches('/').to_string(),
+            description: host.description.clone(),
+        })
+        .collect();
+    Json(HostListResponse { hosts })
+}
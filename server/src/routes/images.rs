@@ -0,0 +1,128 @@
+use crate::routes::types::{ImageInfo, ImageListResponse, ImageScan, RunActionResponse};
+use axum::{Json, extract::Path, http::StatusCode};
+use sysrat_core::images::scan;
+use tokio::process::Command;
+
+/// GET /api/images - Local docker images with their last scan
+#[utoipa::path(
+    get,
+    path = "/api/images",
+    tag = "images",
+    responses(
+        (status = 200, description = "Images and the installed scanner", body = ImageListResponse),
+        (status = 500, description = "Docker unavailable", body = String),
+    )
+)]
+pub async fn list_images() -> Result<Json<ImageListResponse>, (StatusCode, String)> {
+    let images = fetch_images()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))?;
+    Ok(Json(ImageListResponse {
+        images,
+        scanner: scan::scanner().map(str::to_string),
+    }))
+}
+
+/// POST /api/images/{id}/scan - Scan an image for vulnerabilities as a job
+///
+/// Returns as soon as the scan is started; poll GET /api/jobs/{id} until it
+/// finishes, then GET /api/images/{id}/scan for the result.
+#[utoipa::path(
+    post,
+    path = "/api/images/{id}/scan",
+    tag = "images",
+    params(("id" = String, Path, description = "Image id as listed by GET /api/images")),
+    responses(
+        (status = 200, description = "Scan started", body = RunActionResponse),
+        (status = 404, description = "No image with this id", body = String),
+        (status = 501, description = "Neither trivy nor grype is installed", body = String),
+    )
+)]
+pub async fn scan_image(
+    Path(id): Path<String>,
+) -> Result<Json<RunActionResponse>, (StatusCode, String)> {
+    let image = find_image(&id).await?;
+
+    // Scan by name where there is one, scanners resolve it like docker does
+    let reference = if image.repository == "<none>" || image.tag == "<none>" {
+        image.id.clone()
+    } else {
+        format!("{}:{}", image.repository, image.tag)
+    };
+
+    match scan::start(&image.id, &reference) {
+        Ok(job_id) => Ok(Json(RunActionResponse { job_id })),
+        Err(e) => {
+            let status = match e.kind() {
+                std::io::ErrorKind::Unsupported => StatusCode::NOT_IMPLEMENTED,
+                std::io::ErrorKind::InvalidInput => StatusCode::UNPROCESSABLE_ENTITY,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            Err((status, format!("Scan error: {}", e)))
+        }
+    }
+}
+
+/// GET /api/images/{id}/scan - Result of the last scan of an image
+#[utoipa::path(
+    get,
+    path = "/api/images/{id}/scan",
+    tag = "images",
+    params(("id" = String, Path, description = "Image id as listed by GET /api/images")),
+    responses(
+        (status = 200, description = "Severity counts and the most severe vulnerabilities", body = ImageScan),
+        (status = 404, description = "Image not scanned since the server started", body = String),
+    )
+)]
+pub async fn get_image_scan(
+    Path(id): Path<String>,
+) -> Result<Json<ImageScan>, (StatusCode, String)> {
+    scan::result(&id)
+        .map(Json)
+        .ok_or_else(|| (StatusCode::NOT_FOUND, format!("Image {} not scanned", id)))
+}
+
+async fn find_image(id: &str) -> Result<ImageInfo, (StatusCode, String)> {
+    fetch_images()
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))?
+        .into_iter()
+        .find(|image| image.id == id)
+        .ok_or_else(|| (StatusCode::NOT_FOUND, format!("Image not found: {}", id)))
+}
+
+/// Run `docker images` and parse one ImageInfo per line
+async fn fetch_images() -> Result<Vec<ImageInfo>, String> {
+    let output = Command::new("docker")
+        .args([
+            "images",
+            "--format",
+            "{{.ID}}\t{{.Repository}}\t{{.Tag}}\t{{.Size}}\t{{.CreatedSince}}",
+        ])
+        .output()
+        .await
+        .map_err(|e| format!("Failed to execute docker command: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Docker command failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split('\t');
+            let id = parts.next()?.to_string();
+            Some(ImageInfo {
+                scan: scan::result(&id),
+                id,
+                repository: parts.next()?.to_string(),
+                tag: parts.next()?.to_string(),
+                size: parts.next()?.to_string(),
+                created: parts.next()?.to_string(),
+            })
+        })
+        .collect())
+}
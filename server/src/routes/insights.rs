@@ -0,0 +1,110 @@
+use crate::routes::types::{ChangeStatsResponse, FileChangeStats};
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use sysrat_core::config::SharedConfig;
+
+/// Window of `changes` when the request names none ("this month")
+const DEFAULT_DAYS: u32 = 30;
+const MAX_DAYS: u32 = 3650;
+
+#[derive(Deserialize)]
+pub struct ChangeStatsQuery {
+    days: Option<u32>,
+}
+
+fn error_status(e: &std::io::Error) -> StatusCode {
+    match e.kind() {
+        std::io::ErrorKind::Unsupported => StatusCode::NOT_IMPLEMENTED,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+/// GET /api/insights/changes - How often each managed file was changed
+#[utoipa::path(
+    get,
+    path = "/api/insights/changes",
+    tag = "insights",
+    params(("days" = Option<u32>, Query, description = "Window counted in `changes`, 30 days by default")),
+    responses(
+        (status = 200, description = "Change counts, most changed first", body = ChangeStatsResponse),
+        (status = 501, description = "Git versioning is disabled", body = String),
+    )
+)]
+pub async fn get_change_stats(
+    State(config): State<SharedConfig>,
+    Query(query): Query<ChangeStatsQuery>,
+) -> Result<Json<ChangeStatsResponse>, (StatusCode, String)> {
+    let days = query.days.unwrap_or(DEFAULT_DAYS).clamp(1, MAX_DAYS);
+
+    match sysrat_core::configs::actions::change_stats(days, &config).await {
+        Ok(files) => Ok(Json(ChangeStatsResponse { days, files })),
+        Err(e) => Err((error_status(&e), format!("Insights error: {}", e))),
+    }
+}
+
+/// GET /api/metrics - Config change counters in the Prometheus text format
+#[utoipa::path(
+    get,
+    path = "/api/metrics",
+    tag = "insights",
+    responses(
+        (status = 200, description = "Prometheus exposition format", body = String, content_type = "text/plain"),
+        (status = 501, description = "Git versioning is disabled", body = String),
+    )
+)]
+pub async fn get_metrics(
+    State(config): State<SharedConfig>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let files = sysrat_core::configs::actions::change_stats(DEFAULT_DAYS, &config)
+        .await
+        .map_err(|e| (error_status(&e), format!("Metrics error: {}", e)))?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        render_metrics(&files),
+    ))
+}
+
+fn render_metrics(files: &[FileChangeStats]) -> String {
+    let mut out = String::new();
+    out.push_str(
+        "# HELP sysrat_config_changes_total Saves of a managed file committed to the history\n",
+    );
+    out.push_str("# TYPE sysrat_config_changes_total counter\n");
+    for file in files {
+        out.push_str(&format!(
+            "sysrat_config_changes_total{{file=\"{}\"}} {}\n",
+            escape_label(&file.name),
+            file.total_changes
+        ));
+    }
+
+    out.push_str(
+        "# HELP sysrat_config_last_change_timestamp_seconds Time of the newest committed save\n",
+    );
+    out.push_str("# TYPE sysrat_config_last_change_timestamp_seconds gauge\n");
+    // Files never saved have no sample rather than a zero timestamp
+    for file in files {
+        if let Some(time) = file.last_changed {
+            out.push_str(&format!(
+                "sysrat_config_last_change_timestamp_seconds{{file=\"{}\"}} {}\n",
+                escape_label(&file.name),
+                time
+            ));
+        }
+    }
+    out
+}
+
+/// Label values escape backslash, double quote and newline
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,70 @@
+use crate::routes::types::{JobInfo, JobListResponse, ROLE_TOKEN_HEADER};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+};
+use sysrat_core::config::SharedConfig;
+use sysrat_core::jobs;
+
+/// GET /api/jobs - Recent upstream checks, applies and actions with their outcome
+///
+/// Runs of actions that require a role are listed without their message and
+/// output unless the role's token is sent.
+#[utoipa::path(
+    get,
+    path = "/api/jobs",
+    tag = "jobs",
+    params(("x-sysrat-token" = Option<String>, Header, description = "Role token, shows the output of that role's actions")),
+    responses((status = 200, description = "Jobs kept in memory, newest first", body = JobListResponse))
+)]
+pub async fn list_jobs(
+    State(config): State<SharedConfig>,
+    headers: HeaderMap,
+) -> Json<JobListResponse> {
+    let token = headers.get(ROLE_TOKEN_HEADER).and_then(|v| v.to_str().ok());
+    let reader = config.read().await;
+    let jobs = jobs::list()
+        .into_iter()
+        .map(|mut job| {
+            if !jobs::readable(&job, token, &reader) {
+                job.message.clear();
+                job.output.clear();
+            }
+            job
+        })
+        .collect();
+    Json(JobListResponse { jobs })
+}
+
+/// GET /api/jobs/{id} - One job with its output so far
+#[utoipa::path(
+    get,
+    path = "/api/jobs/{id}",
+    tag = "jobs",
+    params(
+        ("id" = u64, Path, description = "Job id"),
+        ("x-sysrat-token" = Option<String>, Header, description = "Token of the role the job's action requires"),
+    ),
+    responses(
+        (status = 200, description = "Job status and output", body = JobInfo),
+        (status = 403, description = "Missing or wrong role token", body = String),
+        (status = 404, description = "Unknown or forgotten job", body = String),
+    )
+)]
+pub async fn get_job(
+    State(config): State<SharedConfig>,
+    Path(id): Path<u64>,
+    headers: HeaderMap,
+) -> Result<Json<JobInfo>, (StatusCode, String)> {
+    let token = headers.get(ROLE_TOKEN_HEADER).and_then(|v| v.to_str().ok());
+    let job =
+        jobs::get(id).ok_or_else(|| (StatusCode::NOT_FOUND, format!("Job not found: {}", id)))?;
+    if !jobs::readable(&job, token, &*config.read().await) {
+        return Err((
+            StatusCode::FORBIDDEN,
+            format!("Job {} runs an action that requires a role", id),
+        ));
+    }
+    Ok(Json(job))
+}
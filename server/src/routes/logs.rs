@@ -0,0 +1,183 @@
+use crate::routes::types::{
+    LOG_ERROR_EVENT, LOG_LINES_EVENT, LOG_ROTATED_EVENT, LogInfo, LogListResponse,
+};
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures_util::StreamExt;
+use futures_util::stream::{self, Stream};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Deserialize;
+use std::convert::Infallible;
+use std::path::{Path as FsPath, PathBuf};
+use std::time::Duration;
+use sysrat_core::config::SharedConfig;
+use sysrat_core::fsutil::expand_tilde;
+use sysrat_core::logs::{self, TailPosition};
+use tokio::sync::mpsc;
+
+/// Lines sent before following when the request names no count
+const DEFAULT_LINES: usize = 200;
+const MAX_LINES: usize = 2_000;
+
+/// How often a followed file is checked for new lines when it cannot be
+/// watched
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Longest wait for a watched file, in case a change went unreported (e.g.
+/// on network filesystems)
+const WATCH_RECHECK: Duration = Duration::from_secs(5);
+
+#[derive(Deserialize)]
+pub struct TailQuery {
+    lines: Option<usize>,
+}
+
+/// GET /api/logs - Log files from sysrat.toml
+#[utoipa::path(
+    get,
+    path = "/api/logs",
+    tag = "logs",
+    responses((status = 200, description = "Configured log files", body = LogListResponse))
+)]
+pub async fn list_logs(State(config): State<SharedConfig>) -> Json<LogListResponse> {
+    let config = config.read().await;
+    let logs = config
+        .logs()
+        .iter()
+        .map(|log| LogInfo {
+            name: log.name.clone(),
+            path: log.path.clone(),
+            description: log.description.clone(),
+        })
+        .collect();
+    Json(LogListResponse { logs })
+}
+
+/// GET /api/logs/{name}/tail - Follow a log file as server-sent events
+///
+/// Sends the last lines first, then every line appended while the
+/// connection is open. Events: `lines` (one log line per data line),
+/// `rotated` (the file was truncated or replaced) and `failure` (it cannot
+/// be read right now).
+#[utoipa::path(
+    get,
+    path = "/api/logs/{name}/tail",
+    tag = "logs",
+    params(
+        ("name" = String, Path, description = "Log name as listed by GET /api/logs"),
+        ("lines" = Option<usize>, Query, description = "Lines sent before following, 200 by default"),
+    ),
+    responses(
+        (status = 200, description = "Event stream", content_type = "text/event-stream", body = String),
+        (status = 404, description = "No log with this name", body = String),
+        (status = 500, description = "The file cannot be read", body = String),
+    )
+)]
+pub async fn tail_log(
+    State(config): State<SharedConfig>,
+    Path(name): Path<String>,
+    Query(query): Query<TailQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, (StatusCode, String)> {
+    let path = {
+        let config = config.read().await;
+        let log = config
+            .get_log(&name)
+            .ok_or_else(|| (StatusCode::NOT_FOUND, format!("Unknown log: {}", name)))?;
+        expand_tilde(&log.path)
+    };
+
+    let max_lines = query.lines.unwrap_or(DEFAULT_LINES).min(MAX_LINES);
+    let (lines, position) = logs::tail(&path, max_lines).await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to read {}: {}", path.display(), e),
+        )
+    })?;
+
+    let first = lines_event(&lines);
+    let stream = stream::once(async move { Ok(first) }).chain(follow_stream(path, position));
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+/// Watch the directory of `path` (so a file replaced on rotation is seen
+/// too), the receiver gets a wake-up whenever the file changes. None when
+/// the platform or the directory cannot be watched.
+fn watch(path: &FsPath) -> Option<(RecommendedWatcher, mpsc::Receiver<()>)> {
+    let (tx, rx) = mpsc::channel(1);
+    let name = path.file_name()?.to_os_string();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        if let Ok(event) = event
+            && event.paths.iter().any(|p| p.file_name() == Some(&name))
+        {
+            // A wake-up already pending covers this change too
+            let _ = tx.try_send(());
+        }
+    })
+    .ok()?;
+    let dir = path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(FsPath::new("."));
+    watcher.watch(dir, RecursiveMode::NonRecursive).ok()?;
+    Some((watcher, rx))
+}
+
+/// Events for what is appended to `path` after `position`, until the
+/// client disconnects. Woken by file change notifications, polled where
+/// the file cannot be watched.
+fn follow_stream(
+    path: PathBuf,
+    position: TailPosition,
+) -> impl Stream<Item = Result<Event, Infallible>> {
+    let watched = watch(&path);
+    // Failed is set while the file cannot be read, so the reason is sent once
+    stream::unfold(
+        (path, position, false, watched),
+        |(path, mut position, mut failed, mut watched)| async move {
+            loop {
+                match watched.as_mut() {
+                    Some((_, changed)) => {
+                        let _ = tokio::time::timeout(WATCH_RECHECK, changed.recv()).await;
+                    }
+                    None => tokio::time::sleep(POLL_INTERVAL).await,
+                }
+                match logs::follow(&path, &mut position).await {
+                    Ok(chunk) => {
+                        failed = false;
+                        let event = if chunk.rotated {
+                            // The new file's first lines follow right after
+                            Event::default()
+                                .event(LOG_ROTATED_EVENT)
+                                .data(chunk.lines.join("\n"))
+                        } else if chunk.lines.is_empty() {
+                            continue;
+                        } else {
+                            lines_event(&chunk.lines)
+                        };
+                        return Some((Ok(event), (path, position, failed, watched)));
+                    }
+                    Err(e) if !failed => {
+                        failed = true;
+                        let event = Event::default().event(LOG_ERROR_EVENT).data(format!(
+                            "Failed to read {}: {}",
+                            path.display(),
+                            e
+                        ));
+                        return Some((Ok(event), (path, position, failed, watched)));
+                    }
+                    Err(_) => continue,
+                }
+            }
+        },
+    )
+}
+
+fn lines_event(lines: &[String]) -> Event {
+    Event::default()
+        .event(LOG_LINES_EVENT)
+        .data(lines.join("\n"))
+}
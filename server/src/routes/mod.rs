@@ -1,8 +1,44 @@
+mod actions;
+mod blueprint;
 mod configs;
 mod containers;
+mod dashboard;
+mod digest;
+mod etag;
+mod frontend_config;
+mod health;
+mod hosts;
+mod images;
+mod insights;
+mod jobs;
+mod logs;
+mod openapi;
+mod profile;
+mod runbooks;
+mod statusline;
+mod themes;
 mod types;
+mod validate;
 
+pub use actions::{list_actions, run_action};
+pub use blueprint::{export_blueprint, import_blueprint};
 pub use configs::{list_configs, read_config, write_config};
 pub use containers::{
-    get_container_details, list_containers, restart_container, start_container, stop_container,
+    get_container_details, get_container_graph, get_container_watch, list_containers,
+    restart_container, start_container, stop_container,
 };
+pub use dashboard::get_dashboard;
+pub use digest::{get_digest, send_digest, spawn_scheduler as spawn_digest_scheduler};
+pub use frontend_config::get_frontend_config;
+pub use health::{get_meta, get_version, healthz, readyz, record_start};
+pub use hosts::list_hosts;
+pub use images::{get_image_scan, list_images, scan_image};
+pub use insights::{get_change_stats, get_metrics};
+pub use jobs::{get_job, list_jobs};
+pub use logs::{list_logs, tail_log};
+pub use openapi::openapi_json;
+pub use profile::{get_profile, put_profile};
+pub use runbooks::{list_runbooks, run_runbook_step};
+pub use statusline::get_statusline;
+pub use themes::{list_themes, save_theme};
+pub use types::{API_VERSION, API_VERSION_HEADER, OVERRIDE_HEADER, ROLE_TOKEN_HEADER};
@@ -0,0 +1,210 @@
+use super::types::{
+    API_VERSION, ActionInfo, ActionListResponse, Alert, AlertLevel, Blueprint, BlueprintConfig,
+    BlueprintContainer, BlueprintImportRequest, BlueprintImportResponse, BlueprintImportResult,
+    CertExpiry, ChangeStatsResponse, CommitInfo, ConfigChange, ContainerActionResponse,
+    ContainerDetails, ContainerDetailsResponse, ContainerEvent, ContainerGraphResponse,
+    ContainerInfo, ContainerListResponse, ContainerRestarts, ContainerStats,
+    ContainerWatchResponse, DaemonOutage, DashboardResponse, DigestChange, DigestDelivery,
+    DigestReport, DigestResponse, DigestSendResponse, EditingOptions, EnvSource, EnvVar,
+    FieldError, FieldKind, FileChangeStats, FileContentResponse, FileInfo, FileListResponse,
+    FileLogResponse, FileMeta, FileOrder, FileSchema, FrontendConfigResponse, GraphGroup,
+    GraphNode, HealthResponse, HostInfo, HostListResponse, HostStats, HygieneResponse, ImageInfo,
+    ImageListResponse, ImageScan, JobInfo, JobListResponse, JobStatus, LineEnding, LintResponse,
+    LintSeverity, LintWarning, LogInfo, LogListResponse, MetaResponse, PortMapping, ProfileBuffer,
+    QuickAction, QuickActionKind, ReadinessCheck, ReadinessResponse, ReadonlyOverride,
+    RefreshIntervals, RenderedResponse, RetryPolicy, RunActionRequest, RunActionResponse,
+    RunbookInfo, RunbookListResponse, RunbookStep, RunbookStepKind, SaveThemeRequest, SchemaField,
+    SeverityCounts, StatusLineResponse, TextEncoding, TextFormat, ThemeFile, ThemeListResponse,
+    UpstreamApplyRequest, UpstreamCheckResponse, UserProfile, ValidationErrorResponse,
+    VersionResponse, VolumeMount, Vulnerability, WriteConfigRequest, WriteConfigResponse,
+    WritePermissionError,
+};
+use super::{
+    actions, blueprint, configs, containers, dashboard, digest, frontend_config, health, hosts,
+    images, insights, jobs, logs, profile, runbooks, statusline, themes,
+};
+use axum::Json;
+use utoipa::OpenApi;
+
+/// OpenAPI document for the REST API
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "sysrat", description = "Config file and container management API"),
+    paths(
+        configs::handlers::list_configs,
+        configs::handlers::read_config,
+        configs::handlers::read_config_log,
+        configs::handlers::read_config_raw,
+        configs::handlers::write_config,
+        configs::handlers::write_config_upload,
+        configs::handlers::check_upstream,
+        configs::handlers::apply_upstream,
+        configs::handlers::render_config,
+        configs::handlers::lint_config,
+        configs::handlers::hygiene_config,
+        containers::handlers::list_containers,
+        containers::graph::get_container_graph,
+        containers::details::get_container_details,
+        containers::watch::get_container_watch,
+        containers::handlers::start_container,
+        containers::handlers::stop_container,
+        containers::handlers::restart_container,
+        images::list_images,
+        images::scan_image,
+        images::get_image_scan,
+        dashboard::handlers::get_dashboard,
+        digest::handlers::get_digest,
+        digest::handlers::send_digest,
+        jobs::list_jobs,
+        jobs::get_job,
+        actions::list_actions,
+        actions::run_action,
+        runbooks::list_runbooks,
+        runbooks::run_runbook_step,
+        logs::list_logs,
+        logs::tail_log,
+        hosts::list_hosts,
+        profile::get_profile,
+        profile::put_profile,
+        insights::get_change_stats,
+        insights::get_metrics,
+        themes::list_themes,
+        themes::save_theme,
+        frontend_config::get_frontend_config,
+        statusline::get_statusline,
+        health::healthz,
+        health::readyz,
+        health::get_meta,
+        health::get_version,
+        blueprint::export_blueprint,
+        blueprint::import_blueprint,
+    ),
+    components(schemas(
+        FileInfo,
+        FileMeta,
+        EditingOptions,
+        WritePermissionError,
+        FieldKind,
+        SchemaField,
+        FileSchema,
+        FileListResponse,
+        FileOrder,
+        ReadonlyOverride,
+        FileContentResponse,
+        TextFormat,
+        TextEncoding,
+        LineEnding,
+        WriteConfigRequest,
+        WriteConfigResponse,
+        LintSeverity,
+        LintWarning,
+        LintResponse,
+        HygieneResponse,
+        CommitInfo,
+        FileLogResponse,
+        UpstreamCheckResponse,
+        UpstreamApplyRequest,
+        RenderedResponse,
+        JobStatus,
+        JobInfo,
+        JobListResponse,
+        ActionInfo,
+        ActionListResponse,
+        RunActionRequest,
+        RunActionResponse,
+        RunbookStepKind,
+        RunbookStep,
+        RunbookInfo,
+        RunbookListResponse,
+        LogInfo,
+        LogListResponse,
+        HostInfo,
+        HostListResponse,
+        UserProfile,
+        ProfileBuffer,
+        FileChangeStats,
+        ChangeStatsResponse,
+        ContainerInfo,
+        ContainerListResponse,
+        DaemonOutage,
+        ContainerActionResponse,
+        PortMapping,
+        VolumeMount,
+        EnvSource,
+        EnvVar,
+        ContainerDetails,
+        ContainerDetailsResponse,
+        ContainerStats,
+        ContainerEvent,
+        ContainerWatchResponse,
+        GraphNode,
+        GraphGroup,
+        ContainerGraphResponse,
+        SeverityCounts,
+        Vulnerability,
+        ImageScan,
+        ImageInfo,
+        ImageListResponse,
+        HostStats,
+        ConfigChange,
+        CertExpiry,
+        AlertLevel,
+        Alert,
+        QuickActionKind,
+        QuickAction,
+        DashboardResponse,
+        DigestChange,
+        ContainerRestarts,
+        DigestReport,
+        DigestResponse,
+        DigestDelivery,
+        DigestSendResponse,
+        ThemeFile,
+        ThemeListResponse,
+        SaveThemeRequest,
+        FrontendConfigResponse,
+        StatusLineResponse,
+        RetryPolicy,
+        RefreshIntervals,
+        HealthResponse,
+        ReadinessCheck,
+        ReadinessResponse,
+        MetaResponse,
+        FieldError,
+        ValidationErrorResponse,
+        VersionResponse,
+        BlueprintConfig,
+        BlueprintContainer,
+        Blueprint,
+        BlueprintImportRequest,
+        BlueprintImportResult,
+        BlueprintImportResponse,
+    )),
+    tags(
+        (name = "configs", description = "Managed config files"),
+        (name = "containers", description = "Docker containers"),
+        (name = "images", description = "Docker images and their vulnerability scans"),
+        (name = "dashboard", description = "Landing view summary"),
+        (name = "digest", description = "Weekly summary sent through the notifiers"),
+        (name = "jobs", description = "Background tasks started from the UI"),
+        (name = "actions", description = "Scriptable actions from sysrat.toml"),
+        (name = "runbooks", description = "Step-by-step procedures from sysrat.toml"),
+        (name = "logs", description = "Log files from sysrat.toml, followed as server-sent events"),
+        (name = "hosts", description = "Other sysrat servers the web UI can switch to"),
+        (name = "profile", description = "UI preferences of the user signed in at the reverse proxy"),
+        (name = "insights", description = "Config change statistics from the git history"),
+        (name = "themes", description = "Custom themes loaded at runtime"),
+        (name = "frontend", description = "Web UI settings from sysrat.toml"),
+        (name = "health", description = "Liveness, readiness and server metadata"),
+        (name = "blueprint", description = "Export and import of the whole host"),
+    )
+)]
+pub struct ApiDoc;
+
+/// GET /api/openapi.json - OpenAPI spec
+pub async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    let mut doc = ApiDoc::openapi();
+    // Version the schema by wire format rather than crate version
+    doc.info.version = API_VERSION.to_string();
+    Json(doc)
+}
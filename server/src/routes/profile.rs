@@ -0,0 +1,90 @@
+use crate::routes::types::UserProfile;
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+};
+use sysrat_core::config::{ProfileSettings, SharedConfig};
+
+/// GET /api/profile - UI preferences of the signed-in user
+#[utoipa::path(
+    get,
+    path = "/api/profile",
+    tag = "profile",
+    responses(
+        (status = 200, description = "The user's profile, empty before the first save", body = UserProfile),
+        (status = 401, description = "The proxy named no user", body = String),
+        (status = 404, description = "Profiles are disabled", body = String),
+    )
+)]
+pub async fn get_profile(
+    State(config): State<SharedConfig>,
+    headers: HeaderMap,
+) -> Result<Json<UserProfile>, (StatusCode, String)> {
+    let settings = config.read().await.profiles().clone();
+    let user = user(&settings, &headers)?;
+    sysrat_core::profiles::load(&settings.dir, &user)
+        .await
+        .map(Json)
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to read profile: {}", e),
+            )
+        })
+}
+
+/// PUT /api/profile - Replace the signed-in user's UI preferences
+///
+/// Answers with the saved profile, whose `updated_at` the UI compares on
+/// its next start to tell whether another browser saved since.
+#[utoipa::path(
+    put,
+    path = "/api/profile",
+    tag = "profile",
+    request_body = UserProfile,
+    responses(
+        (status = 200, description = "Profile saved", body = UserProfile),
+        (status = 401, description = "The proxy named no user", body = String),
+        (status = 404, description = "Profiles are disabled", body = String),
+    )
+)]
+pub async fn put_profile(
+    State(config): State<SharedConfig>,
+    headers: HeaderMap,
+    Json(profile): Json<UserProfile>,
+) -> Result<Json<UserProfile>, (StatusCode, String)> {
+    let settings = config.read().await.profiles().clone();
+    let user = user(&settings, &headers)?;
+    sysrat_core::profiles::save(&settings.dir, &user, profile)
+        .await
+        .map(Json)
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to save profile: {}", e),
+            )
+        })
+}
+
+/// The user the reverse proxy signed in
+fn user(settings: &ProfileSettings, headers: &HeaderMap) -> Result<String, (StatusCode, String)> {
+    if !settings.enabled {
+        return Err((
+            StatusCode::NOT_FOUND,
+            "Profiles are disabled ([profiles] in sysrat.toml)".to_string(),
+        ));
+    }
+    headers
+        .get(settings.user_header.as_str())
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .filter(|user| !user.is_empty())
+        .map(str::to_string)
+        .ok_or_else(|| {
+            (
+                StatusCode::UNAUTHORIZED,
+                format!("No user in the {} header", settings.user_header),
+            )
+        })
+}
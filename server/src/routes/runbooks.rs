@@ -0,0 +1,61 @@
+use crate::routes::types::{ROLE_TOKEN_HEADER, RunActionResponse, RunbookListResponse};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+};
+use sysrat_core::config::SharedConfig;
+
+/// GET /api/runbooks - Runbooks from sysrat.toml with their steps
+#[utoipa::path(
+    get,
+    path = "/api/runbooks",
+    tag = "runbooks",
+    responses((status = 200, description = "Configured runbooks", body = RunbookListResponse))
+)]
+pub async fn list_runbooks(State(config): State<SharedConfig>) -> Json<RunbookListResponse> {
+    Json(RunbookListResponse {
+        runbooks: sysrat_core::runbook::list_runbooks(&config).await,
+    })
+}
+
+/// POST /api/runbooks/{name}/steps/{index}/run - Run an action step as a job
+///
+/// Only action steps run on the server, edit and check steps are handled by
+/// the client. The parameter values come from sysrat.toml.
+#[utoipa::path(
+    post,
+    path = "/api/runbooks/{name}/steps/{index}/run",
+    tag = "runbooks",
+    params(
+        ("name" = String, Path, description = "Runbook name as listed by GET /api/runbooks"),
+        ("index" = usize, Path, description = "Step number, counting from 0"),
+        ("x-sysrat-token" = Option<String>, Header, description = "Token of the role the step's action requires"),
+    ),
+    responses(
+        (status = 200, description = "Step started", body = RunActionResponse),
+        (status = 403, description = "Missing or wrong role token", body = String),
+        (status = 404, description = "No runbook or step with this name or index", body = String),
+        (status = 422, description = "Step runs no action or has invalid parameters", body = String),
+    )
+)]
+pub async fn run_runbook_step(
+    State(config): State<SharedConfig>,
+    Path((name, index)): Path<(String, usize)>,
+    headers: HeaderMap,
+) -> Result<Json<RunActionResponse>, (StatusCode, String)> {
+    let token = headers.get(ROLE_TOKEN_HEADER).and_then(|v| v.to_str().ok());
+
+    match sysrat_core::runbook::start_step(&name, index, token, &config).await {
+        Ok(job_id) => Ok(Json(RunActionResponse { job_id })),
+        Err(e) => {
+            let status: StatusCode = match e.kind() {
+                std::io::ErrorKind::NotFound => StatusCode::NOT_FOUND,
+                std::io::ErrorKind::PermissionDenied => StatusCode::FORBIDDEN,
+                std::io::ErrorKind::InvalidInput => StatusCode::UNPROCESSABLE_ENTITY,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            Err((status, format!("Runbook error: {}", e)))
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::routes::types::StatusLineResponse;
+use axum::{Json, http::StatusCode};
+use sysrat_core::fsutil::expand_tilde;
+
+/// Same file the frontend build prefers over the built-in layout
+const DEFAULT_STATUSLINE_FILE: &str = "~/.config/sysrat/statusline.toml";
+
+/// GET /api/statusline - User status line layout, read from disk on every request
+///
+/// Lets the frontend pick up an edited statusline.toml on reload without
+/// rebuilding the wasm bundle. A file that is not valid TOML is rejected
+/// here so the built-in layout stays in place.
+#[utoipa::path(
+    get,
+    path = "/api/statusline",
+    tag = "frontend",
+    responses(
+        (status = 200, description = "statusline.toml from USER_STATUSLINE_FILE", body = StatusLineResponse),
+        (status = 422, description = "The file is not valid TOML", body = String),
+    )
+)]
+pub async fn get_statusline() -> Result<Json<StatusLineResponse>, (StatusCode, String)> {
+    let file = std::env::var("USER_STATUSLINE_FILE")
+        .unwrap_or_else(|_| DEFAULT_STATUSLINE_FILE.to_string());
+    let path = expand_tilde(&file);
+
+    // A missing file means no override, not an error
+    let Ok(content) = tokio::fs::read_to_string(&path).await else {
+        return Ok(Json(StatusLineResponse { content: None }));
+    };
+
+    toml::from_str::<toml::Value>(&content).map_err(|e| {
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("{}: {}", path.display(), e),
+        )
+    })?;
+
+    Ok(Json(StatusLineResponse {
+        content: Some(content),
+    }))
+}
@@ -0,0 +1,114 @@
+use crate::routes::types::{
+    SaveThemeRequest, ThemeFile, ThemeListResponse, ValidationErrorResponse, WriteConfigResponse,
+};
+use crate::routes::validate::Valid;
+use axum::{Json, extract::Path, http::StatusCode};
+use std::path::PathBuf;
+
+/// Same directory the frontend build embeds custom themes from
+const DEFAULT_THEME_DIR: &str = "~/.config/sysrat/themes";
+
+/// GET /api/themes - Custom themes, read from disk on every request
+///
+/// Lets the frontend pick up new or edited theme files on reload without
+/// rebuilding the wasm bundle.
+#[utoipa::path(
+    get,
+    path = "/api/themes",
+    tag = "themes",
+    responses((status = 200, description = "Theme TOML files from USER_THEME_DIR", body = ThemeListResponse))
+)]
+pub async fn list_themes() -> Json<ThemeListResponse> {
+    let dir = theme_dir();
+    let themes = tokio::task::spawn_blocking(move || read_themes(&dir))
+        .await
+        .unwrap_or_default();
+    Json(ThemeListResponse { themes })
+}
+
+/// POST /api/themes/{name} - Write a theme TOML into the user theme directory
+#[utoipa::path(
+    post,
+    path = "/api/themes/{name}",
+    tag = "themes",
+    params(("name" = String, Path, description = "Theme name, used as the file stem")),
+    request_body = SaveThemeRequest,
+    responses(
+        (status = 200, description = "Theme saved", body = WriteConfigResponse),
+        (status = 400, description = "Invalid theme name", body = String),
+        (status = 422, description = "Invalid theme TOML", body = ValidationErrorResponse),
+    )
+)]
+pub async fn save_theme(
+    Path(name): Path<String>,
+    Valid(payload): Valid<SaveThemeRequest>,
+) -> Result<Json<WriteConfigResponse>, (StatusCode, String)> {
+    // The name becomes a file name, keep it to a single plain path segment
+    let valid_name = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid_name {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Invalid theme name: {}", name),
+        ));
+    }
+
+    let dir = theme_dir();
+    let path = dir.join(format!("{}.toml", name));
+    tokio::fs::create_dir_all(&dir).await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Write error: {}", e),
+        )
+    })?;
+    sysrat_core::fsutil::write_atomic(&path, payload.content)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Write error: {}", e),
+            )
+        })?;
+
+    Ok(Json(WriteConfigResponse {
+        success: true,
+        mode: None,
+    }))
+}
+
+fn theme_dir() -> PathBuf {
+    let dir = std::env::var("USER_THEME_DIR").unwrap_or_else(|_| DEFAULT_THEME_DIR.to_string());
+    expand_tilde(&dir)
+}
+
+fn read_themes(dir: &std::path::Path) -> Vec<ThemeFile> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut themes: Vec<ThemeFile> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension()? != "toml" {
+                return None;
+            }
+            let name = path.file_stem()?.to_str()?.to_string();
+            let content = std::fs::read_to_string(&path).ok()?;
+            Some(ThemeFile { name, content })
+        })
+        .collect();
+    themes.sort_by(|a, b| a.name.cmp(&b.name));
+    themes
+}
+
+fn expand_tilde(path: &str) -> PathBuf {
+    if let Some(stripped) = path.strip_prefix("~/")
+        && let Ok(home) = std::env::var("HOME")
+    {
+        return PathBuf::from(home).join(stripped);
+    }
+    PathBuf::from(path)
+}
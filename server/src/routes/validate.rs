@@ -0,0 +1,136 @@
+//! JSON bodies checked field by field before a handler sees them
+
+use super::types::{
+    BLUEPRINT_VERSION, BlueprintImportRequest, FieldError, SaveThemeRequest, TextEncoding,
+    UpstreamApplyRequest, ValidationErrorResponse, WriteConfigRequest,
+};
+use axum::{
+    Json,
+    extract::{FromRequest, Request, rejection::JsonRejection},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::de::DeserializeOwned;
+
+/// Rules a request body must follow beyond its JSON shape
+pub trait Validate {
+    /// Every rejected field, empty when the body is acceptable
+    fn validate(&self) -> Vec<FieldError>;
+}
+
+/// `Json<T>` that answers 422 with a `ValidationErrorResponse` when the body
+/// does not fit `T` or breaks one of its rules
+pub struct Valid<T>(pub T);
+
+impl<S, T> FromRequest<S> for Valid<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + Validate,
+{
+    type Rejection = Response;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(request, state)
+            .await
+            .map_err(rejection)?;
+
+        let fields = value.validate();
+        if fields.is_empty() {
+            return Ok(Valid(value));
+        }
+        Err(unprocessable("Invalid request body", fields))
+    }
+}
+
+/// Shape errors keep their 422, syntax and content-type errors their status
+fn rejection(e: JsonRejection) -> Response {
+    match e {
+        JsonRejection::JsonDataError(e) => unprocessable(&e.body_text(), Vec::new()),
+        e => e.into_response(),
+    }
+}
+
+fn unprocessable(error: &str, fields: Vec<FieldError>) -> Response {
+    (
+        StatusCode::UNPROCESSABLE_ENTITY,
+        Json(ValidationErrorResponse {
+            error: error.to_string(),
+            fields,
+        }),
+    )
+        .into_response()
+}
+
+fn field(field: &str, message: impl Into<String>) -> FieldError {
+    FieldError {
+        field: field.to_string(),
+        message: message.into(),
+    }
+}
+
+impl Validate for WriteConfigRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        // The editor only saves text, binary files go through the upload
+        if self.content.contains('\0') {
+            return vec![field(
+                "content",
+                "contains NUL bytes, upload binary files instead",
+            )];
+        }
+        if let Some(format) = self.format
+            && format.encoding == TextEncoding::Latin1
+            && let Some(c) = self.content.chars().find(|c| u32::from(*c) > 0xFF)
+        {
+            return vec![field(
+                "content",
+                format!("'{}' cannot be stored as latin-1, convert to UTF-8", c),
+            )];
+        }
+        Vec::new()
+    }
+}
+
+impl Validate for UpstreamApplyRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let is_sha256 =
+            self.sha256.len() == 64 && self.sha256.chars().all(|c| c.is_ascii_hexdigit());
+        if !is_sha256 {
+            return vec![field("sha256", "must be 64 hex digits")];
+        }
+        Vec::new()
+    }
+}
+
+impl Validate for SaveThemeRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        match self.content.parse::<toml::Table>() {
+            Ok(_) => Vec::new(),
+            Err(e) => vec![field("content", format!("invalid TOML: {}", e.message()))],
+        }
+    }
+}
+
+impl Validate for BlueprintImportRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        if self.blueprint.version > BLUEPRINT_VERSION {
+            errors.push(field(
+                "blueprint.version",
+                format!(
+                    "{} is newer than supported ({})",
+                    self.blueprint.version, BLUEPRINT_VERSION
+                ),
+            ));
+        }
+        if self.configs.iter().any(|name| name.trim().is_empty()) {
+            errors.push(field("configs", "names must not be empty"));
+        }
+        if self.settings && self.blueprint.settings.trim().is_empty() {
+            errors.push(field(
+                "settings",
+                "the blueprint carries no sysrat.toml to apply",
+            ));
+        }
+        errors
+    }
+}
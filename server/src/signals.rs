@@ -0,0 +1,49 @@
+use k_lib::config::Cookbook;
+use k_lib::logger;
+use sysrat_core::config::{AppConfig, SharedConfig};
+use tokio::signal::unix::{SignalKind, signal};
+
+const SCOPE: &str = "SIGNAL";
+const APP_NAME: &str = "sysrat";
+
+fn log(cookbook: &Cookbook, level: &str, msg: &str) {
+    logger::log_to_terminal(cookbook, level, SCOPE, msg);
+    let _ = logger::log_to_file(cookbook, level, SCOPE, msg, Some(APP_NAME));
+}
+
+/// Reload sysrat.toml whenever SIGHUP is received (no restart needed)
+pub fn spawn_reload_handler(config: SharedConfig) {
+    tokio::spawn(async move {
+        let mut hangup = match signal(SignalKind::hangup()) {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("Failed to install SIGHUP handler: {}", e);
+                return;
+            }
+        };
+
+        while hangup.recv().await.is_some() {
+            let cookbook = Cookbook::load().ok();
+            if let Some(ref cb) = cookbook {
+                log(cb, "info", "SIGHUP received, reloading configuration");
+            }
+
+            let result = AppConfig::refresh(&config).await;
+            match result {
+                Ok(_) => {
+                    if let Some(ref cb) = cookbook {
+                        log(cb, "success", "Configuration reloaded");
+                    }
+                }
+                Err(e) => {
+                    // Keep serving with the previous configuration
+                    if let Some(ref cb) = cookbook {
+                        log(cb, "error", &format!("Reload failed: {}", e));
+                    } else {
+                        eprintln!("Reload failed: {}", e);
+                    }
+                }
+            }
+        }
+    });
+}